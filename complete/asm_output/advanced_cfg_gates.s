@@ -1,462 +1,5129 @@
-__rustc::rust_begin_unwind:
-	push	rax
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.0]
-	mov	edx, 7
-	mov	edi, 2
-	call	qword ptr [rip + write@GOTPCREL]
-	call	qword ptr [rip + abort@GOTPCREL]
-.Lfunc_end0:
-
-<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::parse_command:
+<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin0:
+	push	rbp
 	push	r15
 	push	r14
+	push	r13
+	push	r12
 	push	rbx
-	sub	rsp, 16
-	mov	r14, rdx
+	sub	rsp, 248
+	mov	r12, rcx
+	mov	rbx, rdx
 	mov	r15, rsi
-	mov	rbx, rdi
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.1]
+	movzx	r13d, byte ptr [rcx]
+	lea	rax, [r13 - 6]
+	xor	ebp, ebp
+	cmp	r13, 7
+	cmovae	rbp, rax
+	mov	r14, rdi
+	lea	rax, [rip + .LJTI0_0]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB0_1:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
 	mov	rcx, rsp
 	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 22
+	mov	qword ptr [rcx + 8], 15
 	#APP
 	#NO_APP
-	cmp	rdx, 1
-	jne	.LBB1_4
-	movzx	eax, byte ptr [r15]
-	cmp	eax, 45
-	je	.LBB1_18
-	cmp	eax, 43
-	jne	.LBB1_7
-	mov	word ptr [rbx], 1
-	jmp	.LBB1_26
-.LBB1_4:
-	cmp	r14, 2
-	jne	.LBB1_7
-	cmp	word ptr [r15], 11563
-	jne	.LBB1_7
-	mov	word ptr [rbx], 513
-	jmp	.LBB1_26
-.LBB1_7:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.2]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+	lea	rax, [rip + .LJTI0_1]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB0_2:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI0_2]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB0_3:
+	mov	rax, qword ptr [r15 + 296]
+	jmp	.LBB0_51
+.LBB0_4:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	rcx, rsp
 	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 19
+	mov	qword ptr [rcx + 8], 14
 	#APP
 	#NO_APP
-	cmp	r14, 2
-	jb	.LBB1_11
-	cmp	word ptr [r15], 8234
-	jne	.LBB1_11
-	lea	rsi, [r14 - 2]
-	lea	rdi, [r15 + 2]
-	call	optional_trait_methods::commands::parse_isize
-	test	al, 1
-	je	.LBB1_11
-	mov	byte ptr [rbx], 2
-	mov	qword ptr [rbx + 8], 0
-	jmp	.LBB1_24
-.LBB1_11:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.4]
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::undo
+	test	rax, rax
+	je	.LBB0_47
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], rdx
+	jmp	.LBB0_11
+.LBB0_6:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI0_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB0_7:
+	mov	rax, qword ptr [r15 + 296]
+	jmp	.LBB0_34
+.LBB0_8:
+	mov	r13, qword ptr [r12 + 8]
+	cmp	r13, 2
+	jne	.LBB0_13
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdx, qword ptr [r12 + 24]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds
+	test	rax, rax
+	je	.LBB0_47
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 26
+	jmp	.LBB0_11
+.LBB0_12:
+	mov	r13, qword ptr [r12 + 8]
+	jmp	.LBB0_14
+.LBB0_13:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
 	mov	rcx, rsp
 	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 27
+	mov	qword ptr [rcx + 8], 15
 	#APP
 	#NO_APP
-	cmp	r14, 3
-	jb	.LBB1_15
-	movzx	eax, word ptr [r15]
-	xor	eax, 32298
-	movzx	ecx, byte ptr [r15 + 2]
-	xor	ecx, 32
-	or	cx, ax
-	jne	.LBB1_20
-	lea	rsi, [r14 - 3]
-	lea	rdi, [r15 + 3]
-	call	optional_trait_methods::commands::parse_isize
-	test	al, 1
-	je	.LBB1_20
-	mov	byte ptr [rbx], 2
-	jmp	.LBB1_23
-.LBB1_15:
-	cmp	r14, 1
-	jne	.LBB1_19
-	cmp	byte ptr [r15], 112
-	jne	.LBB1_25
-	mov	byte ptr [rbx], 0
-	mov	qword ptr [rbx + 8], 0
-	jmp	.LBB1_26
-.LBB1_18:
-	mov	word ptr [rbx], 257
-	jmp	.LBB1_26
-.LBB1_19:
-	jbe	.LBB1_25
-.LBB1_20:
-	cmp	word ptr [r15], 8307
-	jne	.LBB1_25
-	add	r14, -2
-	add	r15, 2
 	mov	rdi, r15
-	mov	rsi, r14
-	call	optional_trait_methods::commands::parse_isize
-	test	al, 1
-	je	.LBB1_25
-	mov	byte ptr [rbx], 0
-.LBB1_23:
-	mov	qword ptr [rbx + 8], 1
-.LBB1_24:
-	mov	qword ptr [rbx + 16], rdx
-	jmp	.LBB1_26
-.LBB1_25:
-	mov	byte ptr [rbx], -1
-.LBB1_26:
-	mov	rax, rbx
-	add	rsp, 16
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+.LBB0_14:
+	test	r13, r13
+	je	.LBB0_23
+	cmp	r13d, 1
+	jne	.LBB0_24
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_factor
+	jmp	.LBB0_63
+.LBB0_17:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	cmp	byte ptr [r12 + 8], 0
+	je	.LBB0_25
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rem
+	jmp	.LBB0_26
+.LBB0_19:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 16
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [r12 + 1]
+	test	eax, eax
+	je	.LBB0_30
+	cmp	eax, 1
+	je	.LBB0_22
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
+.LBB0_22:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::dec
+	jmp	.LBB0_63
+.LBB0_23:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::mul
+	jmp	.LBB0_26
+.LBB0_24:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdx, qword ptr [r12 + 24]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds
+	jmp	.LBB0_26
+.LBB0_25:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::div
+.LBB0_26:
+	test	rax, rax
+	je	.LBB0_63
+	mov	r12, rax
+	mov	r13, rdx
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rollback
+	test	rax, rax
+	je	.LBB0_29
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 21
+.LBB0_11:
+	mov	byte ptr [rsp], -1
+	jmp	.LBB0_67
+.LBB0_29:
+	mov	cl, -1
+	xor	eax, eax
+	jmp	.LBB0_57
+.LBB0_30:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
+	jmp	.LBB0_63
+.LBB0_31:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	jmp	.LBB0_47
+.LBB0_32:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	byte ptr [rsp], 2
+	mov	byte ptr [rsp + 1], cl
+	jmp	.LBB0_67
+.LBB0_33:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+.LBB0_34:
+	mov	byte ptr [rsp], 1
+	mov	qword ptr [rsp + 8], rax
+	jmp	.LBB0_67
+.LBB0_35:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+.LBB0_36:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::set_state
+	jmp	.LBB0_63
+.LBB0_37:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 96], xmm0
+	movaps	xmmword ptr [rsp + 112], xmm0
+	movaps	xmmword ptr [rsp + 128], xmm0
+	mov	qword ptr [rsp + 144], 0
+	xor	r12d, r12d
+	xor	r13d, r13d
+.LBB0_38:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB0_40
+	mov	qword ptr [rsp + 8*r13 + 80], rax
+	mov	r13, qword ptr [rsp + 144]
+	inc	r13
+	mov	qword ptr [rsp + 144], r13
+.LBB0_40:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB0_38
+	lea	rdi, [rsp + 167]
+	lea	rsi, [rsp + 80]
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	mov	edx, 72
+	call	r15
+	lea	rdi, [rsp + 1]
+	mov	byte ptr [rdi - 1], 3
+	lea	rsi, [rsp + 160]
+	mov	edx, 79
+	call	r15
+	jmp	.LBB0_67
+.LBB0_42:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	movaps	xmmword ptr [rsp], xmm0
+	mov	qword ptr [rsp + 64], 0
+	xor	r13d, r13d
+	xor	ebp, ebp
+.LBB0_43:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	rbp, 7
+	ja	.LBB0_45
+	mov	qword ptr [rsp + 8*rbp], rax
+	mov	rbp, qword ptr [rsp + 64]
+	inc	rbp
+	mov	qword ptr [rsp + 64], rbp
+.LBB0_45:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB0_43
+	add	r12, 8
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB0_47:
+	mov	byte ptr [rsp], 0
+	jmp	.LBB0_67
+.LBB0_48:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	jmp	.LBB0_63
+.LBB0_49:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	edx, 63
+	cmovne	edx, eax
+	mov	cl, 2
+	jmp	.LBB0_64
+.LBB0_50:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+.LBB0_51:
+	mov	cl, 1
+	jmp	.LBB0_65
+.LBB0_52:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	movaps	xmmword ptr [rsp], xmm0
+	mov	qword ptr [rsp + 64], 0
+	xor	r12d, r12d
+	xor	r13d, r13d
+.LBB0_53:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB0_55
+	mov	qword ptr [rsp + 8*r13], rax
+	mov	r13, qword ptr [rsp + 64]
+	inc	r13
+	mov	qword ptr [rsp + 64], r13
+.LBB0_55:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB0_53
+	mov	rax, qword ptr [rsp]
+	mov	r12, qword ptr [rsp + 8]
+	mov	r13, qword ptr [rsp + 16]
+	movups	xmm0, xmmword ptr [rsp + 24]
+	movaps	xmmword ptr [rsp + 160], xmm0
+	movups	xmm0, xmmword ptr [rsp + 40]
+	movaps	xmmword ptr [rsp + 176], xmm0
+	movups	xmm0, xmmword ptr [rsp + 56]
+	movaps	xmmword ptr [rsp + 192], xmm0
+	mov	cl, 3
+.LBB0_57:
+	jmp	.LBB0_66
+.LBB0_58:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	movaps	xmmword ptr [rsp], xmm0
+	mov	qword ptr [rsp + 64], 0
+	xor	r13d, r13d
+	xor	ebp, ebp
+.LBB0_59:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	rbp, 7
+	ja	.LBB0_61
+	mov	qword ptr [rsp + 8*rbp], rax
+	mov	rbp, qword ptr [rsp + 64]
+	inc	rbp
+	mov	qword ptr [rsp + 64], rbp
+.LBB0_61:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB0_59
+	add	r12, 8
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB0_63:
+	xor	ecx, ecx
+.LBB0_64:
+.LBB0_65:
+.LBB0_66:
+	mov	byte ptr [rsp], cl
+	mov	byte ptr [rsp + 1], dl
+	mov	qword ptr [rsp + 8], rax
+	mov	qword ptr [rsp + 16], r12
+	mov	qword ptr [rsp + 24], r13
+	movaps	xmm0, xmmword ptr [rsp + 160]
+	movaps	xmm1, xmmword ptr [rsp + 176]
+	movaps	xmm2, xmmword ptr [rsp + 192]
+	movups	xmmword ptr [rsp + 32], xmm0
+	movups	xmmword ptr [rsp + 48], xmm1
+	movups	xmmword ptr [rsp + 64], xmm2
+.LBB0_67:
+	mov	rdx, rsp
+	mov	rdi, r14
+	mov	rsi, rbx
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	add	rsp, 248
 	pop	rbx
+	pop	r12
+	pop	r13
 	pop	r14
 	pop	r15
+	pop	rbp
 	ret
-.Lfunc_end1:
+.Lfunc_end0:
+.LJTI0_0:
+	.long	.LBB0_6-.LJTI0_0
+	.long	.LBB0_1-.LJTI0_0
+	.long	.LBB0_8-.LJTI0_0
+	.long	.LBB0_1-.LJTI0_0
+	.long	.LBB0_4-.LJTI0_0
+.LJTI0_1:
+	.long	.LBB0_2-.LJTI0_1
+	.long	.LBB0_19-.LJTI0_1
+	.long	.LBB0_12-.LJTI0_1
+	.long	.LBB0_17-.LJTI0_1
+.LJTI0_2:
+	.long	.LBB0_3-.LJTI0_2
+	.long	.LBB0_36-.LJTI0_2
+	.long	.LBB0_49-.LJTI0_2
+	.long	.LBB0_50-.LJTI0_2
+	.long	.LBB0_48-.LJTI0_2
+	.long	.LBB0_52-.LJTI0_2
+	.long	.LBB0_58-.LJTI0_2
+.LJTI0_3:
+	.long	.LBB0_7-.LJTI0_3
+	.long	.LBB0_35-.LJTI0_3
+	.long	.LBB0_32-.LJTI0_3
+	.long	.LBB0_33-.LJTI0_3
+	.long	.LBB0_31-.LJTI0_3
+	.long	.LBB0_37-.LJTI0_3
+	.long	.LBB0_42-.LJTI0_3
 
-<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::handle:
+<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin1:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
 	push	rbx
-	sub	rsp, 16
-	mov	rbx, rdi
-	movzx	eax, byte ptr [rsi]
-	test	eax, eax
-	je	.LBB2_6
-	cmp	eax, 1
-	jne	.LBB2_8
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.10]
+	sub	rsp, 248
+	mov	r12, rcx
+	mov	rbx, rdx
+	mov	r15, rsi
+	movzx	r13d, byte ptr [rcx]
+	lea	rax, [r13 - 6]
+	xor	ebp, ebp
+	cmp	r13, 7
+	cmovae	rbp, rax
+	mov	r14, rdi
+	lea	rax, [rip + .LJTI1_0]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB1_1:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+	lea	rax, [rip + .LJTI1_1]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB1_2:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI1_2]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB1_3:
+	mov	rax, qword ptr [r15 + 296]
+	jmp	.LBB1_51
+.LBB1_4:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 14
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::undo
+	test	rax, rax
+	je	.LBB1_47
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], rdx
+	jmp	.LBB1_11
+.LBB1_6:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI1_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB1_7:
+	mov	rax, qword ptr [r15 + 296]
+	jmp	.LBB1_34
+.LBB1_8:
+	mov	r13, qword ptr [r12 + 8]
+	cmp	r13, 2
+	jne	.LBB1_13
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdx, qword ptr [r12 + 24]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds
+	test	rax, rax
+	je	.LBB1_47
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 26
+	jmp	.LBB1_11
+.LBB1_12:
+	mov	r13, qword ptr [r12 + 8]
+	jmp	.LBB1_14
+.LBB1_13:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+.LBB1_14:
+	test	r13, r13
+	je	.LBB1_23
+	cmp	r13d, 1
+	jne	.LBB1_24
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_factor
+	jmp	.LBB1_63
+.LBB1_17:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	cmp	byte ptr [r12 + 8], 0
+	je	.LBB1_25
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rem
+	jmp	.LBB1_26
+.LBB1_19:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
 	mov	rcx, rsp
 	mov	qword ptr [rcx], rax
 	mov	qword ptr [rcx + 8], 16
 	#APP
 	#NO_APP
-	movzx	eax, byte ptr [rsi + 1]
+	movzx	eax, byte ptr [r12 + 1]
 	test	eax, eax
-	je	.LBB2_12
+	je	.LBB1_30
 	cmp	eax, 1
-	je	.LBB2_5
-	mov	rdi, rbx
+	je	.LBB1_22
+	mov	rdi, r15
 	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
-.LBB2_5:
-	mov	rdi, rbx
+.LBB1_22:
+	mov	rdi, r15
 	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::dec
-	jmp	.LBB2_13
-.LBB2_6:
-	cmp	byte ptr [rsi + 8], 0
-	je	.LBB2_10
-	mov	rsi, qword ptr [rsi + 16]
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::set_state
-	jmp	.LBB2_13
-.LBB2_8:
-	cmp	byte ptr [rsi + 8], 0
-	je	.LBB2_11
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.12]
+	jmp	.LBB1_63
+.LBB1_23:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::mul
+	jmp	.LBB1_26
+.LBB1_24:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
 	mov	rcx, rsp
 	mov	qword ptr [rcx], rax
 	mov	qword ptr [rcx + 8], 21
 	#APP
 	#NO_APP
-	mov	rsi, qword ptr [rsi + 16]
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_factor
-	jmp	.LBB2_13
-.LBB2_10:
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::get_state
-	lea	rdi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.9]
-	xor	ebx, ebx
-	mov	rsi, rax
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdx, qword ptr [r12 + 24]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds
+	jmp	.LBB1_26
+.LBB1_25:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::div
+.LBB1_26:
+	test	rax, rax
+	je	.LBB1_63
+	mov	r12, rax
+	mov	r13, rdx
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rollback
+	test	rax, rax
+	je	.LBB1_29
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 21
+.LBB1_11:
+	mov	byte ptr [rsp], -1
+	jmp	.LBB1_67
+.LBB1_29:
+	mov	cl, -1
 	xor	eax, eax
-	call	qword ptr [rip + printf@GOTPCREL]
-	jmp	.LBB2_14
-.LBB2_11:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.11]
+	jmp	.LBB1_57
+.LBB1_30:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
+	jmp	.LBB1_63
+.LBB1_31:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	jmp	.LBB1_47
+.LBB1_32:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	byte ptr [rsp], 2
+	mov	byte ptr [rsp + 1], cl
+	jmp	.LBB1_67
+.LBB1_33:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+.LBB1_34:
+	mov	byte ptr [rsp], 1
+	mov	qword ptr [rsp + 8], rax
+	jmp	.LBB1_67
+.LBB1_35:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
 	mov	rcx, rsp
 	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 13
+	mov	qword ptr [rcx + 8], 15
 	#APP
 	#NO_APP
-	mov	rsi, qword ptr [rsi + 16]
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::mul
-	mov	rbx, rax
-	jmp	.LBB2_14
-.LBB2_12:
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
-.LBB2_13:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+.LBB1_36:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::set_state
+	jmp	.LBB1_63
+.LBB1_37:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 96], xmm0
+	movaps	xmmword ptr [rsp + 112], xmm0
+	movaps	xmmword ptr [rsp + 128], xmm0
+	mov	qword ptr [rsp + 144], 0
+	xor	r12d, r12d
+	xor	r13d, r13d
+.LBB1_38:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB1_40
+	mov	qword ptr [rsp + 8*r13 + 80], rax
+	mov	r13, qword ptr [rsp + 144]
+	inc	r13
+	mov	qword ptr [rsp + 144], r13
+.LBB1_40:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB1_38
+	lea	rdi, [rsp + 167]
+	lea	rsi, [rsp + 80]
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	mov	edx, 72
+	call	r15
+	lea	rdi, [rsp + 1]
+	mov	byte ptr [rdi - 1], 3
+	lea	rsi, [rsp + 160]
+	mov	edx, 79
+	call	r15
+	jmp	.LBB1_67
+.LBB1_42:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	movaps	xmmword ptr [rsp], xmm0
+	mov	qword ptr [rsp + 64], 0
+	xor	r13d, r13d
+	xor	ebp, ebp
+.LBB1_43:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	rbp, 7
+	ja	.LBB1_45
+	mov	qword ptr [rsp + 8*rbp], rax
+	mov	rbp, qword ptr [rsp + 64]
+	inc	rbp
+	mov	qword ptr [rsp + 64], rbp
+.LBB1_45:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB1_43
+	add	r12, 8
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB1_47:
+	mov	byte ptr [rsp], 0
+	jmp	.LBB1_67
+.LBB1_48:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	jmp	.LBB1_63
+.LBB1_49:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	edx, 63
+	cmovne	edx, eax
+	mov	cl, 2
+	jmp	.LBB1_64
+.LBB1_50:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+.LBB1_51:
+	mov	cl, 1
+	jmp	.LBB1_65
+.LBB1_52:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	movaps	xmmword ptr [rsp], xmm0
+	mov	qword ptr [rsp + 64], 0
+	xor	r12d, r12d
+	xor	r13d, r13d
+.LBB1_53:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB1_55
+	mov	qword ptr [rsp + 8*r13], rax
+	mov	r13, qword ptr [rsp + 64]
+	inc	r13
+	mov	qword ptr [rsp + 64], r13
+.LBB1_55:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB1_53
+	mov	rax, qword ptr [rsp]
+	mov	r12, qword ptr [rsp + 8]
+	mov	r13, qword ptr [rsp + 16]
+	movups	xmm0, xmmword ptr [rsp + 24]
+	movaps	xmmword ptr [rsp + 160], xmm0
+	movups	xmm0, xmmword ptr [rsp + 40]
+	movaps	xmmword ptr [rsp + 176], xmm0
+	movups	xmm0, xmmword ptr [rsp + 56]
+	movaps	xmmword ptr [rsp + 192], xmm0
+	mov	cl, 3
+.LBB1_57:
+	jmp	.LBB1_66
+.LBB1_58:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	movaps	xmmword ptr [rsp], xmm0
+	mov	qword ptr [rsp + 64], 0
+	xor	r13d, r13d
+	xor	ebp, ebp
+.LBB1_59:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	rbp, 7
+	ja	.LBB1_61
+	mov	qword ptr [rsp + 8*rbp], rax
+	mov	rbp, qword ptr [rsp + 64]
+	inc	rbp
+	mov	qword ptr [rsp + 64], rbp
+.LBB1_61:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB1_59
+	add	r12, 8
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB1_63:
+	xor	ecx, ecx
+.LBB1_64:
+.LBB1_65:
+.LBB1_66:
+	mov	byte ptr [rsp], cl
+	mov	byte ptr [rsp + 1], dl
+	mov	qword ptr [rsp + 8], rax
+	mov	qword ptr [rsp + 16], r12
+	mov	qword ptr [rsp + 24], r13
+	movaps	xmm0, xmmword ptr [rsp + 160]
+	movaps	xmm1, xmmword ptr [rsp + 176]
+	movaps	xmm2, xmmword ptr [rsp + 192]
+	movups	xmmword ptr [rsp + 32], xmm0
+	movups	xmmword ptr [rsp + 48], xmm1
+	movups	xmmword ptr [rsp + 64], xmm2
+.LBB1_67:
+	mov	rdx, rsp
+	mov	rdi, r14
+	mov	rsi, rbx
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	add	rsp, 248
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end1:
+.LJTI1_0:
+	.long	.LBB1_6-.LJTI1_0
+	.long	.LBB1_1-.LJTI1_0
+	.long	.LBB1_8-.LJTI1_0
+	.long	.LBB1_1-.LJTI1_0
+	.long	.LBB1_4-.LJTI1_0
+.LJTI1_1:
+	.long	.LBB1_2-.LJTI1_1
+	.long	.LBB1_19-.LJTI1_1
+	.long	.LBB1_12-.LJTI1_1
+	.long	.LBB1_17-.LJTI1_1
+.LJTI1_2:
+	.long	.LBB1_3-.LJTI1_2
+	.long	.LBB1_36-.LJTI1_2
+	.long	.LBB1_49-.LJTI1_2
+	.long	.LBB1_50-.LJTI1_2
+	.long	.LBB1_48-.LJTI1_2
+	.long	.LBB1_52-.LJTI1_2
+	.long	.LBB1_58-.LJTI1_2
+.LJTI1_3:
+	.long	.LBB1_7-.LJTI1_3
+	.long	.LBB1_35-.LJTI1_3
+	.long	.LBB1_32-.LJTI1_3
+	.long	.LBB1_33-.LJTI1_3
+	.long	.LBB1_31-.LJTI1_3
+	.long	.LBB1_37-.LJTI1_3
+	.long	.LBB1_42-.LJTI1_3
+
+<optional_trait_methods::commands::Args>::num::<isize>:
+.Lfunc_begin2:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<isize>
+	and	al, 1
+	cmove	rdx, r15
+	neg	al
+	or	al, 2
+	mov	qword ptr [rbx], rdx
+	mov	byte ptr [rbx + 8], al
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end2:
+
+<optional_trait_methods::commands::Args>::regs::<isize>:
+.Lfunc_begin3:
+	push	r14
+	push	rbx
+	sub	rsp, 88
+	mov	rbx, rdi
+	lea	r14, [rsp + 16]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	rax, qword ptr [r14]
+	cmp	qword ptr [r14 + 16], 128
+	jne	.LBB3_20
+	mov	rcx, qword ptr [rsp + 24]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 80], 0
+	mov	edx, 128
+	xor	esi, esi
+.LBB3_2:
+	mov	rdi, rcx
+	add	rcx, 16
+	add	rdx, -16
+	movaps	xmmword ptr [rsp], xmm0
+	xor	r8d, r8d
+.LBB3_3:
+	mov	r10b, byte ptr [rdi + 2*r8]
+	lea	r9d, [r10 - 48]
+	cmp	r9b, 10
+	jb	.LBB3_13
+	lea	r9d, [r10 - 97]
+	cmp	r9b, 5
+	ja	.LBB3_10
+	add	r10b, -87
+	jmp	.LBB3_12
+.LBB3_10:
+	lea	r9d, [r10 - 71]
+	cmp	r9b, -6
+	jb	.LBB3_20
+	add	r10b, -55
+.LBB3_12:
+	mov	r9d, r10d
+.LBB3_13:
+	mov	r10b, byte ptr [rdi + 2*r8 + 1]
+	lea	r11d, [r10 - 48]
+	cmp	r11b, 10
+	jb	.LBB3_19
+	lea	r11d, [r10 - 97]
+	cmp	r11b, 5
+	ja	.LBB3_16
+	add	r10b, -87
+	jmp	.LBB3_18
+.LBB3_16:
+	lea	r11d, [r10 - 71]
+	cmp	r11b, -6
+	jb	.LBB3_20
+	add	r10b, -55
+.LBB3_18:
+	mov	r11d, r10d
+.LBB3_19:
+	shl	r9b, 4
+	or	r9b, r11b
+	mov	byte ptr [rsp + r8], r9b
+	inc	r8
+	cmp	r8, 8
+	jne	.LBB3_3
+	cmp	rsi, 7
+	ja	.LBB3_8
+	mov	rdi, qword ptr [rsp]
+	mov	qword ptr [rsp + 8*rsi + 16], rdi
+	mov	rsi, qword ptr [rsp + 80]
+	inc	rsi
+	mov	qword ptr [rsp + 80], rsi
+.LBB3_8:
+	test	rdx, rdx
+	jne	.LBB3_2
+	lea	rdi, [rbx + 8]
+	lea	rsi, [rsp + 16]
+	mov	ecx, 9
+	rep movsq es:[rdi], [rsi]
+	xor	eax, eax
+	jmp	.LBB3_21
+.LBB3_20:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB3_21:
+	mov	qword ptr [rbx], rax
+	add	rsp, 88
+	pop	rbx
+	pop	r14
+	ret
+.Lfunc_end3:
+
+<optional_trait_methods::commands::Args>::num_or::<usize>:
+.Lfunc_begin4:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	cmp	qword ptr [rsi + 8], 0
+	je	.LBB4_1
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	and	al, 1
+	cmove	rdx, r15
+	neg	al
+	or	al, 2
+	jmp	.LBB4_3
+.LBB4_1:
+	mov	al, -1
+	mov	edx, 1
+.LBB4_3:
+	mov	qword ptr [rbx], rdx
+	mov	byte ptr [rbx + 8], al
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end4:
+
+<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>:
+.Lfunc_begin5:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rax, qword ptr [rdi + 64]
+	xor	r15d, r15d
+	cmp	rax, 9
+	mov	r12d, 8
+	cmovb	r12, rdi
+	lea	rax, [8*rax]
+	cmovb	r15, rax
+	test	r15, r15
+	je	.LBB5_3
+	mov	rbx, rsi
+	xor	r14d, r14d
+.LBB5_2:
+	mov	rdx, qword ptr [r12 + 8*r14]
+	mov	rdi, rbx
+	mov	rsi, r14
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	inc	r14
+	add	r15, -8
+	jne	.LBB5_2
+.LBB5_3:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end5:
+
+optional_trait_methods::num::format_decimal::<isize>:
+.Lfunc_begin6:
+	push	rax
+	mov	eax, 39
+	movabs	r9, 7378697629483820647
+	mov	rcx, rdi
+.LBB6_1:
+	cmp	rax, 39
+	ja	.LBB6_7
+	mov	r8, rax
+	mov	rax, rcx
+	imul	r9
+	mov	rax, rdx
+	shr	rax, 63
+	sar	rdx, 2
+	add	rdx, rax
+	lea	rax, [rdx + rdx]
+	lea	rax, [rax + 4*rax]
+	mov	r10, rcx
+	sub	r10, rax
+	mov	rax, r10
+	neg	rax
+	cmovs	rax, r10
+	add	al, 48
+	mov	byte ptr [rsi + r8], al
+	add	rcx, 9
+	lea	rax, [r8 - 1]
+	cmp	rcx, 19
+	mov	rcx, rdx
+	jae	.LBB6_1
+	test	rdi, rdi
+	jns	.LBB6_6
+	inc	r8
+	add	r8, -2
+	cmp	r8, 39
+	ja	.LBB6_7
+	mov	byte ptr [rsi + r8], 45
+.LBB6_6:
+	mov	edx, 40
+	sub	rdx, r8
+	add	rsi, r8
+	mov	rax, rsi
+	pop	rcx
+	ret
+.LBB6_7:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end6:
+
+optional_trait_methods::num::format_decimal::<usize>:
+.Lfunc_begin7:
+	mov	eax, 39
+	movabs	r8, -3689348814741910323
+.LBB7_1:
+	cmp	rax, 39
+	ja	.LBB7_4
+	mov	rcx, rax
+	mov	rax, rdi
+	mul	r8
+	shr	rdx, 3
+	lea	eax, [rdx + rdx]
+	lea	eax, [rax + 4*rax]
+	mov	r9d, edi
+	sub	r9d, eax
+	or	r9b, 48
+	mov	byte ptr [rsi + rcx], r9b
+	lea	rax, [rcx - 1]
+	cmp	rdi, 10
+	mov	rdi, rdx
+	jae	.LBB7_1
+	add	rsi, rcx
+	inc	rcx
+	mov	edx, 41
+	sub	rdx, rcx
+	mov	rax, rsi
+	ret
+.LBB7_4:
+	push	rax
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end7:
+
+optional_trait_methods::num::parse_num::<u16>:
+.Lfunc_begin8:
+	test	rsi, rsi
+	je	.LBB8_54
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB8_4
+	cmp	ecx, 45
+	jne	.LBB8_6
+	mov	cl, 1
+	jmp	.LBB8_5
+.LBB8_4:
+	xor	ecx, ecx
+.LBB8_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB8_7
+.LBB8_6:
+	xor	ecx, ecx
+.LBB8_7:
+	cmp	rsi, 1
+	jbe	.LBB8_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB8_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB8_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB8_29
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB8_28
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB8_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB8_31
+.LBB8_14:
+	test	rsi, rsi
+	je	.LBB8_54
+	mov	al, byte ptr [rdi]
+.LBB8_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB8_54
+.LBB8_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB8_19
+	cmp	byte ptr [rax], 95
+	je	.LBB8_54
+.LBB8_19:
+	xor	r9d, r9d
+	mov	r8w, 10
+	xor	edx, edx
+.LBB8_20:
+	cmp	rdi, rsi
+	je	.LBB8_49
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB8_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB8_20
+	jmp	.LBB8_55
+.LBB8_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB8_54
+	mov	eax, edx
+	mul	r8w
+	jo	.LBB8_54
+	mov	edx, eax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB8_27
+	sub	dx, ax
+	jae	.LBB8_20
+	jmp	.LBB8_54
+.LBB8_27:
+	add	dx, ax
+	jae	.LBB8_20
+	jmp	.LBB8_54
+.LBB8_28:
+	mov	r8b, 8
+	jmp	.LBB8_30
+.LBB8_29:
+	mov	r8b, 2
+.LBB8_30:
+	xor	r9d, r9d
+.LBB8_31:
+	cmp	rsi, 2
+	je	.LBB8_54
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB8_51
+.LBB8_33:
+	cmp	dl, r8b
+	jae	.LBB8_54
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB8_36
+	cmp	byte ptr [rax], 95
+	je	.LBB8_54
+.LBB8_36:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB8_37:
+	cmp	r10, rdi
+	je	.LBB8_49
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB8_40
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	jne	.LBB8_37
+	jmp	.LBB8_55
+.LBB8_40:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB8_44
+	test	r9b, r9b
+	je	.LBB8_54
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB8_54
+	add	al, -87
+	mov	r11d, eax
+.LBB8_44:
+	cmp	r11b, r8b
+	jae	.LBB8_54
+	mov	eax, edx
+	mul	si
+	jo	.LBB8_54
+	mov	edx, eax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB8_48
+	sub	dx, ax
+	jae	.LBB8_37
+	jmp	.LBB8_54
+.LBB8_48:
+	add	dx, ax
+	jae	.LBB8_37
+	jmp	.LBB8_54
+.LBB8_49:
+	mov	ax, 1
+	ret
+.LBB8_51:
+	test	r9b, r9b
+	je	.LBB8_54
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB8_54
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB8_33
+.LBB8_54:
+	xor	eax, eax
+.LBB8_55:
+	ret
+.Lfunc_end8:
+
+optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin9:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB9_2
+	lea	rcx, [rip + .LJTI9_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB9_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB9_5
+.LBB9_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB9_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB9_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB9_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB9_18
+.LBB9_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB9_15
+	jmp	.LBB9_28
+.LBB9_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB9_14
+	jmp	.LBB9_28
+.LBB9_19:
+	lea	r14, [rbx + 8]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 72]
+	cmp	rcx, 9
+	mov	eax, 8
+	cmovb	rax, r14
+	lea	rcx, [8*rcx]
+	cmovae	rcx, r12
+	test	rcx, rcx
+	je	.LBB9_26
+	add	rcx, rax
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB9_21:
+	mov	qword ptr [rsp + 24], 0
+	mov	rsi, qword ptr [rax]
+	add	rax, 8
+	mov	qword ptr [rsp + 16], rsi
+	xor	esi, esi
+.LBB9_22:
+	cmp	r12, 256
+	jae	.LBB9_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB9_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 8
+	jne	.LBB9_22
+	cmp	rax, rcx
+	jne	.LBB9_21
+.LBB9_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB9_27
+.LBB9_11:
+	mov	rdi, qword ptr [rbx + 8]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<isize>
+.LBB9_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB9_27
+.LBB9_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI9_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB9_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB9_4
+.LBB9_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB9_4
+.LBB9_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB9_27
+.LBB9_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB9_12
+.LBB9_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB9_4:
+	mov	edx, 4
+.LBB9_5:
+	mov	rdi, r15
+.LBB9_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB9_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	ecx, 10
+	mov	rdi, rbp
+	mov	rsi, rbx
+	rep movsq es:[rdi], [rsi]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB9_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB9_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end9:
+.LJTI9_0:
+	.long	.LBB9_10-.LJTI9_0
+	.long	.LBB9_11-.LJTI9_0
+	.long	.LBB9_13-.LJTI9_0
+	.long	.LBB9_19-.LJTI9_0
+.LJTI9_1:
+	.long	.LBB9_3-.LJTI9_1
+	.long	.LBB9_6-.LJTI9_1
+	.long	.LBB9_7-.LJTI9_1
+	.long	.LBB9_8-.LJTI9_1
+	.long	.LBB9_9-.LJTI9_1
+
+optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin10:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB10_2
+	lea	rcx, [rip + .LJTI10_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB10_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB10_5
+.LBB10_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB10_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB10_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB10_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB10_18
+.LBB10_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB10_15
+	jmp	.LBB10_28
+.LBB10_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB10_14
+	jmp	.LBB10_28
+.LBB10_19:
+	lea	r14, [rbx + 8]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 72]
+	cmp	rcx, 9
+	mov	eax, 8
+	cmovb	rax, r14
+	lea	rcx, [8*rcx]
+	cmovae	rcx, r12
+	test	rcx, rcx
+	je	.LBB10_26
+	add	rcx, rax
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB10_21:
+	mov	qword ptr [rsp + 24], 0
+	mov	rsi, qword ptr [rax]
+	add	rax, 8
+	mov	qword ptr [rsp + 16], rsi
+	xor	esi, esi
+.LBB10_22:
+	cmp	r12, 256
+	jae	.LBB10_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB10_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 8
+	jne	.LBB10_22
+	cmp	rax, rcx
+	jne	.LBB10_21
+.LBB10_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB10_27
+.LBB10_11:
+	mov	rdi, qword ptr [rbx + 8]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<isize>
+.LBB10_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB10_27
+.LBB10_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI10_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB10_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB10_4
+.LBB10_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB10_4
+.LBB10_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB10_27
+.LBB10_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB10_12
+.LBB10_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB10_4:
+	mov	edx, 4
+.LBB10_5:
+	mov	rdi, r15
+.LBB10_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB10_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	ecx, 10
+	mov	rdi, rbp
+	mov	rsi, rbx
+	rep movsq es:[rdi], [rsi]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB10_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB10_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end10:
+.LJTI10_0:
+	.long	.LBB10_10-.LJTI10_0
+	.long	.LBB10_11-.LJTI10_0
+	.long	.LBB10_13-.LJTI10_0
+	.long	.LBB10_19-.LJTI10_0
+.LJTI10_1:
+	.long	.LBB10_3-.LJTI10_1
+	.long	.LBB10_6-.LJTI10_1
+	.long	.LBB10_7-.LJTI10_1
+	.long	.LBB10_8-.LJTI10_1
+	.long	.LBB10_9-.LJTI10_1
+
+__rustc::rust_begin_unwind:
+.Lfunc_begin11:
+	push	rax
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.30]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	call	qword ptr [rip + abort@GOTPCREL]
+.Lfunc_end11:
+
+optional_trait_methods::report_port:
+.Lfunc_begin12:
+	push	rbx
+	sub	rsp, 64
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 16]
+	movaps	xmmword ptr [rsi], xmm0
+	lea	rdx, [rsp + 12]
+	mov	dword ptr [rdx], 16
+	call	qword ptr [rip + getsockname@GOTPCREL]
+	test	eax, eax
+	js	.LBB12_1
+	xor	eax, eax
+	test	al, al
+	jne	.LBB12_7
+	movzx	ebx, word ptr [rsp + 18]
+	rol	bx, 8
+	shl	ebx, 16
+	shr	ebx, 16
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 48], 0
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.31]
+	mov	edx, 23
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	ecx, 39
+.LBB12_4:
+	cmp	rcx, 39
+	ja	.LBB12_8
+	mov	rax, rcx
+	movzx	edx, bx
+	imul	esi, edx, 52429
+	shr	esi, 19
+	lea	ecx, [rsi + rsi]
+	lea	ecx, [rcx + 4*rcx]
+	sub	ebx, ecx
+	or	bl, 48
+	mov	byte ptr [rsp + rax + 16], bl
+	lea	rcx, [rax - 1]
+	mov	ebx, esi
+	cmp	dx, 10
+	jae	.LBB12_4
+	lea	rsi, [rsp + rax]
+	add	rsi, 16
+	inc	rax
+	mov	edx, 41
+	sub	rdx, rax
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB12_7
+.LBB12_1:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB12_7:
+	add	rsp, 64
+	pop	rbx
+	ret
+.LBB12_8:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end12:
+
+optional_trait_methods::usage_error:
+.Lfunc_begin13:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.32]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	rax, qword ptr [rbx]
+	lea	rcx, [rip + .LJTI13_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB13_1:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.7]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 16
+	jmp	.LBB13_2
+.LBB13_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.12]
+	mov	edx, 39
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	jmp	.LBB13_7
+.LBB13_4:
+	mov	r14, qword ptr [rbx + 8]
+	mov	r15, qword ptr [rbx + 16]
+	mov	r12, qword ptr [rbx + 24]
+	mov	rbx, qword ptr [rbx + 32]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.9]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, r15
+	call	r13
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.10]
+	mov	edx, 2
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r12
+	mov	rdx, rbx
+	call	r13
+	jmp	.LBB13_7
+.LBB13_5:
+	mov	rsi, qword ptr [rbx + 8]
+	mov	rdx, qword ptr [rbx + 16]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.11]
+	mov	edx, 21
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB13_7
+.LBB13_3:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+.LBB13_2:
+	mov	edi, 2
+	call	r15
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, rbx
+	call	r15
+.LBB13_7:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 2
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	mov	edx, 823
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	mov	rax, rbx
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	jmp	rax
+.Lfunc_end13:
+.LJTI13_0:
+	.long	.LBB13_1-.LJTI13_0
+	.long	.LBB13_3-.LJTI13_0
+	.long	.LBB13_4-.LJTI13_0
+	.long	.LBB13_5-.LJTI13_0
+	.long	.LBB13_6-.LJTI13_0
+
+<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::parse_command:
+.Lfunc_begin14:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	sub	rsp, 176
+	mov	rbx, rdi
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.35]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 22
+	#APP
+	#NO_APP
+	lea	rdi, [rsi + 1]
+	movzx	eax, byte ptr [rsi]
+	cmp	eax, 45
+	je	.LBB14_4
+	cmp	eax, 43
+	jne	.LBB14_9
+	cmp	rdx, 1
+	jne	.LBB14_6
+	mov	byte ptr [rbx + 1], 0
+	jmp	.LBB14_20
+.LBB14_4:
+	cmp	rdx, 1
+	jne	.LBB14_6
+	mov	byte ptr [rbx + 1], 1
+	jmp	.LBB14_20
+.LBB14_6:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_10
+.LBB14_7:
+	mov	qword ptr [rbx + 8], 1
+.LBB14_8:
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+	jmp	.LBB14_21
+.LBB14_9:
+	cmp	rdx, 2
+	jb	.LBB14_11
+.LBB14_10:
+	cmp	word ptr [rsi], 11563
+	je	.LBB14_18
+.LBB14_11:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.39]
+	mov	r8, rsp
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	eax, 42
+	jne	.LBB14_28
+	mov	r15, rdx
+	dec	r15
+	jne	.LBB14_23
+	setne	al
+	movzx	ecx, al
+	sub	r15, rcx
+	add	rdi, rcx
+.LBB14_14:
+	mov	r14, r15
+.LBB14_15:
+	movzx	r12d, al
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB14_39
+	cmp	r15, r14
+	jne	.LBB14_63
+	mov	byte ptr [rbx], 8
+	mov	qword ptr [rbx + 8], 0
+	mov	qword ptr [rbx + 16], rdx
+	jmp	.LBB14_22
+.LBB14_18:
+	cmp	rdx, 2
+	jne	.LBB14_50
+	mov	byte ptr [rbx + 1], 2
+.LBB14_20:
+	mov	al, 7
+.LBB14_21:
+	mov	byte ptr [rbx], al
+	jmp	.LBB14_22
+.LBB14_23:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_28
+	xor	eax, eax
+	test	r15, r15
+	setne	al
+	sub	r15, rax
+	add	rdi, rax
+	cmp	rdx, 3
+	jb	.LBB14_14
+	xor	r14d, r14d
+.LBB14_26:
+	cmp	byte ptr [rdi + r14], 32
+	je	.LBB14_15
+	inc	r14
+	cmp	r15, r14
+	jne	.LBB14_26
+	jmp	.LBB14_14
+.LBB14_28:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.41]
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 2
+	jb	.LBB14_30
+	cmp	word ptr [rsi], 32298
+	je	.LBB14_52
+.LBB14_30:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.43]
+	mov	r8, rsp
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 3
+	jb	.LBB14_32
+	movzx	ecx, word ptr [rsi]
+	xor	ecx, 32298
+	movzx	r8d, byte ptr [rsi + 2]
+	xor	r8d, 126
+	or	r8w, cx
+	je	.LBB14_59
+.LBB14_32:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.45]
+	mov	r8, rsp
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	eax, 37
+	je	.LBB14_40
+	cmp	eax, 47
+	jne	.LBB14_45
+	mov	rax, rdx
+	dec	rax
+	je	.LBB14_36
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_64
+.LBB14_36:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 9
+	mov	qword ptr [rbx + 8], 0
+	jmp	.LBB14_58
+.LBB14_39:
+	inc	r12
+	mov	qword ptr [rbx + 8], r12
+	mov	byte ptr [rbx + 16], 2
+	jmp	.LBB14_67
+.LBB14_40:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB14_42
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_64
+.LBB14_42:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 9
+	jmp	.LBB14_57
+.LBB14_45:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 20
+	#APP
+	#NO_APP
+	cmp	eax, 111
+	jle	.LBB14_68
+	lea	ecx, [rax - 112]
+	cmp	ecx, 7
+	ja	.LBB14_65
+	lea	r8, [rip + .LJTI14_0]
+	movsxd	rcx, dword ptr [r8 + 4*rcx]
+	add	rcx, r8
+	jmp	rcx
+.LBB14_48:
+	cmp	rdx, 1
+	jne	.LBB14_101
+	xor	eax, eax
+	jmp	.LBB14_103
+.LBB14_50:
+	cmp	byte ptr [rsi + 2], 32
+	jne	.LBB14_11
+	mov	qword ptr [rbx + 8], 2
+	jmp	.LBB14_8
+.LBB14_52:
+	lea	rcx, [rsi + 2]
+	mov	r8, rdx
+	sub	r8, 2
+	je	.LBB14_54
+	cmp	byte ptr [rcx], 32
+	jne	.LBB14_30
+.LBB14_54:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rcx
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 2
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 8
+.LBB14_57:
+	mov	qword ptr [rbx + 8], 1
+.LBB14_58:
+	mov	qword ptr [rbx + 16], rax
+	jmp	.LBB14_22
+.LBB14_59:
+	lea	rcx, [rsi + 3]
+	mov	r8, rdx
+	sub	r8, 3
+	je	.LBB14_61
+	cmp	byte ptr [rcx], 32
+	jne	.LBB14_32
+.LBB14_61:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rcx
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 3
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	je	.LBB14_73
+.LBB14_62:
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB14_66
+.LBB14_63:
+	lea	rax, [r14 + r12]
+	inc	rax
+	jmp	.LBB14_125
+.LBB14_64:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 20
+	#APP
+	#NO_APP
+.LBB14_65:
+	mov	rdi, rsi
+	mov	rsi, rdx
+	call	optional_trait_methods::commands::unrecognized
+	mov	qword ptr [rbx + 8], 0
+.LBB14_66:
+	mov	byte ptr [rbx + 16], al
+	jmp	.LBB14_67
+.LBB14_68:
+	cmp	eax, 63
+	je	.LBB14_97
+	cmp	eax, 71
+	je	.LBB14_100
+	cmp	eax, 103
+	jne	.LBB14_65
+	cmp	rdx, 1
+	jne	.LBB14_104
+	mov	al, 5
+	jmp	.LBB14_21
+.LBB14_73:
+	lea	r14, [rsp + 80]
+	mov	rsi, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 8
+	mov	qword ptr [rbx + 8], 2
+	mov	qword ptr [rbx + 16], r15
+	mov	qword ptr [rbx + 24], rax
+	jmp	.LBB14_22
+.LBB14_76:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB14_78
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_65
+.LBB14_78:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num_or::<usize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 10
+	jmp	.LBB14_81
+.LBB14_82:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB14_84
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_65
+.LBB14_84:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 1
+	jmp	.LBB14_81
+.LBB14_87:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_89
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_107
+.LBB14_89:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 3
+.LBB14_81:
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB14_22
+.LBB14_92:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_94
+.LBB14_93:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_105
+.LBB14_94:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	jne	.LBB14_62
+	lea	r14, [rsp + 80]
+	mov	rsi, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	je	.LBB14_123
+.LBB14_96:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], cl
+	jmp	.LBB14_67
+.LBB14_97:
+	mov	rax, rdx
+	dec	rax
+	jne	.LBB14_116
+	mov	qword ptr [rsp], rdi
+	mov	qword ptr [rsp + 8], rax
+	mov	qword ptr [rsp + 16], 1
+	xor	ebp, ebp
+.LBB14_99:
+	mov	byte ptr [rbx], 2
+	mov	byte ptr [rbx + 1], bpl
+	mov	byte ptr [rbx + 2], al
+	jmp	.LBB14_22
+.LBB14_100:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB14_112
+	jmp	.LBB14_111
+.LBB14_101:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_65
+	mov	qword ptr [rbx + 8], 1
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+.LBB14_103:
+	mov	byte ptr [rbx], al
+	jmp	.LBB14_22
+.LBB14_104:
+	cmp	byte ptr [rdi], 32
+	je	.LBB14_7
+.LBB14_105:
+	cmp	eax, 63
+	je	.LBB14_115
+	cmp	eax, 71
+	jne	.LBB14_65
+	jmp	.LBB14_110
+.LBB14_107:
+	mov	r8d, eax
+	add	r8b, -63
+	je	.LBB14_115
+	rol	r8b, 5
+	movzx	r8d, r8b
+	cmp	r8d, 1
+	je	.LBB14_110
+	cmp	r8d, 7
+	jne	.LBB14_65
+	jmp	.LBB14_93
+.LBB14_110:
+	lea	rax, [rdx - 1]
+.LBB14_111:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_65
+.LBB14_112:
+	lea	rsi, [rsp + 152]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::regs::<isize>
+	mov	rcx, qword ptr [r14 + 8]
+	mov	al, byte ptr [r14 + 16]
+	cmp	byte ptr [r14], 0
+	jne	.LBB14_119
+	movups	xmm0, xmmword ptr [rsp + 17]
+	movups	xmm1, xmmword ptr [rsp + 33]
+	movups	xmm2, xmmword ptr [rsp + 49]
+	movups	xmm3, xmmword ptr [rsp + 64]
+	movups	xmmword ptr [rsp + 127], xmm3
+	movaps	xmmword ptr [rsp + 112], xmm2
+	movaps	xmmword ptr [rsp + 96], xmm1
+	movaps	xmmword ptr [rsp + 80], xmm0
+	cmp	qword ptr [rsp + 160], 0
+	je	.LBB14_120
+	mov	rax, qword ptr [rsp + 168]
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB14_8
+.LBB14_115:
+	lea	rax, [rdx - 1]
+.LBB14_116:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_65
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	test	rax, rax
+	setne	bpl
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::names
+	mov	al, byte ptr [r14 + 8]
+	cmp	al, -1
+	je	.LBB14_121
+	mov	rcx, qword ptr [rsp + 80]
+.LBB14_119:
+	mov	qword ptr [rbx + 8], rcx
+	jmp	.LBB14_66
+.LBB14_120:
+	mov	qword ptr [rbx + 8], rcx
+	mov	byte ptr [rbx + 16], al
+	movaps	xmm0, xmmword ptr [rsp + 80]
+	movaps	xmm1, xmmword ptr [rsp + 96]
+	movaps	xmm2, xmmword ptr [rsp + 112]
+	movups	xmmword ptr [rbx + 17], xmm0
+	movups	xmmword ptr [rbx + 33], xmm1
+	movups	xmmword ptr [rbx + 49], xmm2
+	movups	xmm0, xmmword ptr [rsp + 127]
+	movups	xmmword ptr [rbx + 64], xmm0
+	mov	al, 6
+	jmp	.LBB14_21
+.LBB14_121:
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	al, byte ptr [rsp + 80]
+	jmp	.LBB14_99
+.LBB14_123:
+	cmp	qword ptr [rsp + 8], 0
+	je	.LBB14_126
+.LBB14_124:
+	mov	rax, qword ptr [rsp + 16]
+.LBB14_125:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], 3
+.LBB14_67:
+	mov	byte ptr [rbx], -1
+.LBB14_22:
+	add	rsp, 176
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB14_126:
+	mov	byte ptr [rbx], 4
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB14_58
+.Lfunc_end14:
+.LJTI14_0:
+	.long	.LBB14_48-.LJTI14_0
+	.long	.LBB14_65-.LJTI14_0
+	.long	.LBB14_87-.LJTI14_0
+	.long	.LBB14_82-.LJTI14_0
+	.long	.LBB14_65-.LJTI14_0
+	.long	.LBB14_76-.LJTI14_0
+	.long	.LBB14_65-.LJTI14_0
+	.long	.LBB14_92-.LJTI14_0
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin15:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB15_1
+	xor	eax, eax
+	mov	qword ptr [r14 + 1024], rax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	cmovae	rdx, rax
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB15_3
+	mov	edi, 1
+	mov	rsi, r15
+	mov	rdx, rbx
+	xor	ecx, ecx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	jmp	optional_trait_methods::connection::write_fd
+.LBB15_1:
+	cmp	rax, rdx
+	jb	.LBB15_4
+.LBB15_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB15_4:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end15:
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin16:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB16_1
+	xor	eax, eax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	mov	qword ptr [r14 + 1024], rax
+	cmovae	rdx, rax
+	mov	ebp, dword ptr [r14 + 1032]
+	mov	edi, ebp
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB16_3
+	mov	edi, ebp
+	mov	rsi, r15
+	mov	rdx, rbx
+	mov	ecx, 1
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	optional_trait_methods::connection::write_fd
+.LBB16_1:
+	cmp	rax, rdx
+	jb	.LBB16_4
+.LBB16_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB16_4:
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end16:
+
+main:
+.Lfunc_begin17:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 3352
+	cmp	rdi, 2
+	jl	.LBB17_55
+	mov	rbp, rsi
+	mov	r13d, 1
+	lea	r12, [rsp + 392]
+	mov	qword ptr [rsp + 40], 0
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	dword ptr [rsp + 36], eax
+	mov	qword ptr [rsp + 80], 0
+	mov	qword ptr [rsp + 64], 0
+	mov	qword ptr [rsp + 72], 0
+	mov	qword ptr [rsp + 96], 0
+	mov	qword ptr [rsp + 88], 0
+	mov	qword ptr [rsp + 104], rsi
+	mov	qword ptr [rsp + 24], rdi
+.LBB17_2:
+	mov	qword ptr [rsp + 48], r15
+.LBB17_3:
+	mov	rbp, qword ptr [rbp + 8*r13]
+	mov	rdi, rbp
+	call	rcx
+	mov	rbx, rax
+	test	rax, rax
+	je	.LBB17_8
+	xor	r14d, r14d
+.LBB17_5:
+	cmp	byte ptr [rbp + r14], 61
+	je	.LBB17_10
+	inc	r14
+	cmp	rbx, r14
+	jne	.LBB17_5
+	mov	qword ptr [rsp + 16], 0
+	mov	r14, rbx
+	jmp	.LBB17_9
+.LBB17_8:
+	mov	qword ptr [rsp + 16], 0
+	xor	r14d, r14d
+.LBB17_9:
+	jmp	.LBB17_11
+.LBB17_10:
+	lea	rax, [r14 + 1]
+	mov	rcx, rbx
+	sub	rcx, rax
+	mov	qword ptr [rsp + 208], rcx
+	lea	rax, [r14 + rbp]
+	inc	rax
+	mov	qword ptr [rsp + 16], rax
+.LBB17_11:
+	mov	qword ptr [rsp + 8], r13
+	lea	rax, [r13 + 1]
+	mov	qword ptr [rsp + 56], rax
+	mov	qword ptr [rsp + 376], 0
+	mov	qword ptr [rsp + 384], 7
+	mov	edx, 168
+	mov	rdi, r12
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.24]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r15d, 32
+.LBB17_12:
+	cmp	qword ptr [rsp + r15 + 368], r14
+	jne	.LBB17_15
+	movzx	r13d, byte ptr [rsp + r15 + 376]
+	mov	r12, qword ptr [rsp + r15 + 360]
+	mov	rdi, r12
+	mov	rsi, rbp
+	mov	rdx, r14
+	call	qword ptr [rip + bcmp@GOTPCREL]
+	test	eax, eax
+	jne	.LBB17_15
+	cmp	r13d, 255
+	jne	.LBB17_16
+.LBB17_15:
+	add	r15, 24
+	cmp	r15, 200
+	jne	.LBB17_12
+	jmp	.LBB17_92
+.LBB17_16:
+	cmp	r13, 255
+	je	.LBB17_92
+	cmp	r13b, 4
+	jae	.LBB17_20
+	mov	rdx, qword ptr [rsp + 16]
+	test	rdx, rdx
+	mov	rbp, qword ptr [rsp + 104]
+	mov	rbx, qword ptr [rsp + 24]
+	je	.LBB17_22
+	mov	rax, qword ptr [rsp + 56]
+	mov	qword ptr [rsp + 8], rax
+	mov	rax, qword ptr [rsp + 208]
+	jmp	.LBB17_25
+.LBB17_20:
+	cmp	qword ptr [rsp + 16], 0
+	mov	rbp, qword ptr [rsp + 104]
+	mov	rbx, qword ptr [rsp + 24]
+	jne	.LBB17_101
+	mov	edx, 1
+	mov	rax, qword ptr [rsp + 56]
+	mov	qword ptr [rsp + 8], rax
+	xor	eax, eax
+	jmp	.LBB17_25
+.LBB17_22:
+	cmp	qword ptr [rsp + 56], rbx
+	mov	r15, qword ptr [rsp + 8]
+	jge	.LBB17_102
+	mov	rdi, qword ptr [rbp + 8*r15 + 8]
+	test	rdi, rdi
+	je	.LBB17_102
+	mov	qword ptr [rsp + 16], rdi
+	call	qword ptr [rip + strlen@GOTPCREL]
+	mov	rdx, qword ptr [rsp + 16]
+	add	r15, 2
+	mov	qword ptr [rsp + 8], r15
+.LBB17_25:
+	cmp	r13b, 5
+	jne	.LBB17_27
+	mov	al, 1
+	mov	qword ptr [rsp + 96], rax
+	mov	r13, qword ptr [rsp + 8]
+	cmp	r13, rbx
+	lea	r12, [rsp + 392]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB17_3
+	jmp	.LBB17_104
+.LBB17_27:
+	movzx	ecx, r13b
+	mov	esi, ecx
+	lea	rcx, [rip + .LJTI17_0]
+	movsxd	rsi, dword ptr [rcx + 4*rsi]
+	add	rsi, rcx
+	mov	rbx, qword ptr [rsp + 80]
+	jmp	rsi
+.LBB17_28:
+	test	rax, rax
+	je	.LBB17_119
+	mov	qword ptr [rsp + 192], rdx
+	mov	qword ptr [rsp + 200], rax
+	mov	dword ptr [rsp + 36], eax
+	cmp	word ptr [rsp + 40], 0
+	mov	ax, 1
+	mov	qword ptr [rsp + 40], rax
+	mov	r15, qword ptr [rsp + 48]
+	lea	r12, [rsp + 392]
+	mov	r13, qword ptr [rsp + 8]
+	je	.LBB17_45
+	jmp	.LBB17_112
+.LBB17_30:
+	mov	al, 1
+	mov	qword ptr [rsp + 72], rax
+	jmp	.LBB17_42
+.LBB17_31:
+	mov	r15, rax
+	mov	rbx, rdx
+	jmp	.LBB17_43
+.LBB17_32:
+	cmp	rax, 8
+	je	.LBB17_46
+	cmp	rax, 4
+	mov	r13, qword ptr [rsp + 8]
+	jne	.LBB17_124
+	mov	esi, 4
+	cmp	byte ptr [rdx], 101
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 1], 120
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 2], 105
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 3], 116
+	jne	.LBB17_125
+	jmp	.LBB17_54
+.LBB17_38:
+	mov	rdi, rdx
+	mov	r13, rax
+	mov	rsi, rax
+	mov	r15, rdx
+	call	optional_trait_methods::num::parse_num::<u16>
+	mov	dword ptr [rsp + 36], edx
+	test	al, 1
+	je	.LBB17_118
+	cmp	word ptr [rsp + 40], 0
+	mov	r15, qword ptr [rsp + 48]
+	lea	r12, [rsp + 392]
+	jne	.LBB17_112
+	mov	ax, 2
+	mov	qword ptr [rsp + 40], rax
+	jmp	.LBB17_44
+.LBB17_41:
+	mov	al, 1
+	mov	qword ptr [rsp + 88], rax
+.LBB17_42:
+	mov	r15, qword ptr [rsp + 48]
+.LBB17_43:
+	lea	r12, [rsp + 392]
+.LBB17_44:
+	mov	r13, qword ptr [rsp + 8]
+.LBB17_45:
+	mov	qword ptr [rsp + 80], rbx
+	mov	rbx, qword ptr [rsp + 24]
+	cmp	r13, rbx
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB17_2
+	jmp	.LBB17_103
+.LBB17_46:
+	mov	esi, 8
+	cmp	byte ptr [rdx], 99
+	mov	r13, qword ptr [rsp + 8]
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 1], 111
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 2], 110
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 3], 116
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 4], 105
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 5], 110
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 6], 117
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 7], 101
+	jne	.LBB17_125
+.LBB17_54:
+	cmp	rax, 8
+	setne	al
+	mov	qword ptr [rsp + 64], rax
+	mov	r15, qword ptr [rsp + 48]
+	lea	r12, [rsp + 392]
+	jmp	.LBB17_45
+.LBB17_55:
+	xor	r14d, r14d
+	xor	ebp, ebp
+.LBB17_56:
+	xorps	xmm0, xmm0
+	lea	r12, [rsp + 2976]
+	movups	xmmword ptr [r12 + 328], xmm0
+	movups	xmmword ptr [r12 + 312], xmm0
+	movups	xmmword ptr [r12 + 296], xmm0
+	xor	ebx, ebx
+	mov	qword ptr [r12 + 344], rbx
+	lea	rdi, [rsp + 392]
+	mov	r15, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 272
+	xor	esi, esi
+	call	r15
+	mov	qword ptr [r12 + 288], r14
+	mov	qword ptr [r12 + 352], 1
+	movabs	rax, 9223372036854775807
+	lea	rcx, [rax + 1]
+	mov	qword ptr [r12 + 360], rcx
+	mov	qword ptr [r12 + 368], rax
+	mov	qword ptr [r12 - 8], rbx
+	lea	rsi, [rsp + 376]
+	mov	edx, 288
+	mov	rdi, r12
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 1416]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r15
+	lea	rdi, [rsp + 376]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r15
+	lea	rdi, [rsp + 2456]
+	mov	edx, 512
+	xor	esi, esi
+	call	r15
+	mov	dword ptr [rsp + 56], ebp
+	shr	ebp, 8
+	mov	dword ptr [rsp + 8], ebp
+	mov	r12d, 1
+	mov	rbp, qword ptr [rip + read@GOTPCREL]
+	xor	ecx, ecx
+	mov	dword ptr [rsp + 16], eax
+.LBB17_57:
+	mov	rdi, qword ptr [rsp + 1400]
+	mov	rax, qword ptr [rsp + 1408]
+	xor	r15d, r15d
+	mov	rdx, rcx
+	xor	r13d, r13d
+.LBB17_58:
+	mov	rsi, rax
+	mov	rax, rdi
+.LBB17_59:
+	cmp	rsi, rax
+	jb	.LBB17_63
+	mov	qword ptr [rsp + 2440], rbx
+	cmp	rdx, 1025
+	mov	rsi, r14
+	cmovae	rsi, r12
+	cmovae	rdx, rbx
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 1024
+	xor	edi, edi
+	lea	rsi, [rsp + 376]
+	call	rbp
+	test	rax, rax
+	js	.LBB17_73
+	je	.LBB17_74
+	mov	qword ptr [rsp + 1400], rax
+	mov	qword ptr [rsp + 1408], 0
+	xor	ecx, ecx
+	xor	edx, edx
+	xor	esi, esi
+.LBB17_63:
+	cmp	rax, rsi
+	jb	.LBB17_96
+	cmp	rax, 1024
+	ja	.LBB17_96
+	cmp	rsi, rax
+	je	.LBB17_59
+	inc	rsi
+	mov	rdi, rax
+	neg	rdi
+.LBB17_67:
+	mov	r8b, byte ptr [rsp + rsi + 375]
+	mov	qword ptr [rsp + 1408], rsi
+	cmp	r8b, 10
+	je	.LBB17_77
+	cmp	r13, 511
+	jbe	.LBB17_70
+	mov	r15b, 1
+	jmp	.LBB17_71
+.LBB17_70:
+	mov	byte ptr [rsp + r13 + 2456], r8b
+	inc	r13
+.LBB17_71:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB17_67
+	mov	rdi, rax
+	jmp	.LBB17_58
+.LBB17_73:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB17_74:
+	test	r13, r13
+	setne	al
+	or	al, r15b
+	test	al, 1
+	je	.LBB17_100
+	test	r15b, 1
+	jne	.LBB17_81
+	mov	ecx, 0
+	mov	edx, 0
+	cmp	r13, 512
+	ja	.LBB17_97
+	jmp	.LBB17_79
+.LBB17_77:
+	test	r15b, 1
+	jne	.LBB17_81
+	cmp	r13, 512
+	ja	.LBB17_96
+.LBB17_79:
+	test	r13, r13
+	setne	al
+	test	byte ptr [rsp + 8], al
+	jne	.LBB17_82
+	test	r13, r13
+	je	.LBB17_57
+	jmp	.LBB17_83
+.LBB17_81:
+	mov	qword ptr [rsp + 120], 3
+	mov	qword ptr [rsp + 128], 512
+	mov	byte ptr [rsp + 136], 4
+	mov	byte ptr [rsp + 112], -1
+	lea	rdi, [rsp + 216]
+	mov	rsi, r14
+	lea	rdx, [rsp + 112]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	jmp	.LBB17_85
+.LBB17_82:
+	mov	rdi, r14
+	lea	rsi, [rsp + 2456]
+	mov	rdx, r13
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB17_83:
+	lea	r15, [rsp + 296]
+	mov	rdi, r15
+	lea	rsi, [rsp + 2456]
+	mov	rdx, r13
+	call	<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::parse_command
+	cmp	byte ptr [rsp + 296], -1
+	je	.LBB17_86
+	mov	ecx, 10
+	lea	rax, [rsp + 112]
+	mov	rdi, rax
+	mov	rsi, r15
+	rep movsq es:[rdi], [rsi]
+	lea	rdi, [rsp + 216]
+	lea	rsi, [rsp + 2968]
+	mov	rdx, r14
+	mov	rcx, rax
+	call	<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB17_85:
+	mov	r15d, dword ptr [rsp + 16]
+	mov	r13, qword ptr [rsp + 24]
+	jmp	.LBB17_87
+.LBB17_86:
+	mov	eax, dword ptr [rsp + 312]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	r13, qword ptr [rsp + 24]
+	cmovne	r13, qword ptr [rsp + 304]
+	sete	cl
+	mov	r15d, dword ptr [rsp + 16]
+	movzx	r15d, r15b
+	cmovne	r15d, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 120], rcx
+	mov	qword ptr [rsp + 128], r13
+	mov	byte ptr [rsp + 136], r15b
+	mov	byte ptr [rsp + 112], -1
+	lea	rdi, [rsp + 216]
+	mov	rsi, r14
+	lea	rdx, [rsp + 112]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB17_87:
+	cmp	byte ptr [rsp + 216], -1
+	jne	.LBB17_91
+	mov	rax, qword ptr [rsp + 224]
+	cmp	byte ptr [rsp + 56], 0
+	jne	.LBB17_106
+	cmp	eax, 1
+	je	.LBB17_109
+	cmp	eax, 4
+	je	.LBB17_108
+.LBB17_91:
+	mov	qword ptr [rsp + 24], r13
+	mov	dword ptr [rsp + 16], r15d
+	mov	rcx, qword ptr [rsp + 2440]
+	jmp	.LBB17_57
+.LBB17_92:
+	xor	ecx, ecx
+	mov	r12, rbp
+	mov	r14, rbx
+.LBB17_93:
+.LBB17_94:
+	lea	rdi, [rsp + 376]
+	mov	qword ptr [rdi], rcx
+	mov	qword ptr [rdi + 8], r12
+	mov	qword ptr [rdi + 16], r14
+	mov	qword ptr [rdi + 24], rdx
+	mov	qword ptr [rdi + 32], r13
+.LBB17_95:
+	call	optional_trait_methods::usage_error
+	mov	ebx, 1
+	jmp	.LBB17_99
+.LBB17_96:
+	mov	rdx, rcx
+.LBB17_97:
+	xor	eax, eax
+	mov	qword ptr [rsp + 2440], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, 1
+	mov	rsi, r14
+.LBB17_98:
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+.LBB17_99:
+	mov	rax, rbx
+	add	rsp, 3352
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB17_100:
+	xor	edx, edx
+	jmp	.LBB17_97
+.LBB17_101:
+	mov	ecx, 3
+	jmp	.LBB17_93
+.LBB17_102:
+	mov	ecx, 1
+	jmp	.LBB17_93
+.LBB17_103:
+	test	byte ptr [rsp + 96], 1
+	je	.LBB17_113
+.LBB17_104:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.73]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 31
+.LBB17_105:
+	mov	edi, 2
+	call	r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	ebx, 1
+	mov	edx, 1
+	mov	edi, 2
+	call	r14
+	jmp	.LBB17_99
+.LBB17_106:
+	cmp	eax, 1
+	je	.LBB17_109
+	cmp	eax, 4
+	jne	.LBB17_111
+.LBB17_108:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 1416]
+	mov	edx, 23
+	jmp	.LBB17_110
+.LBB17_109:
+	mov	rsi, qword ptr [rsp + 232]
+	mov	rdx, qword ptr [rsp + 240]
+	lea	rdi, [rsp + 1416]
+.LBB17_110:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 1416]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB17_111:
+	mov	ebx, 1
+	mov	rdx, qword ptr [rsp + 2440]
+	jmp	.LBB17_97
+.LBB17_112:
+	mov	ecx, 4
+	jmp	.LBB17_93
+.LBB17_113:
+	test	byte ptr [rsp + 88], 1
+	je	.LBB17_115
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	xor	ebx, ebx
+	mov	edx, 823
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 1
+	jmp	.LBB17_98
+.LBB17_115:
+	mov	rbx, qword ptr [rsp + 80]
+	test	rbx, rbx
+	je	.LBB17_120
+	mov	rdi, rbx
+	mov	rsi, r15
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB17_126
+	mov	r14, rdx
+	jmp	.LBB17_121
+.LBB17_118:
+	mov	ecx, 2
+	mov	rdx, r15
+	jmp	.LBB17_94
+.LBB17_119:
+	mov	ecx, 2
+	xor	r13d, r13d
+	jmp	.LBB17_94
+.LBB17_120:
+	xor	r14d, r14d
+.LBB17_121:
+	mov	rax, qword ptr [rsp + 40]
+	test	ax, ax
+	je	.LBB17_127
+	movzx	eax, ax
+	cmp	eax, 1
+	jne	.LBB17_128
+	mov	rdi, qword ptr [rsp + 192]
+	mov	rsi, qword ptr [rsp + 200]
+	call	<optional_trait_methods::connection::Listener>::unix
+	mov	ebx, edx
+	jmp	.LBB17_129
+.LBB17_124:
+	mov	rsi, rax
+.LBB17_125:
+	mov	ecx, 2
+	mov	r13, rsi
+	jmp	.LBB17_94
+.LBB17_126:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.19]
+	lea	rdi, [rsp + 376]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 15
+	mov	qword ptr [rdi + 24], rbx
+	mov	qword ptr [rdi + 32], r15
+	mov	qword ptr [rdi], 2
+	jmp	.LBB17_95
+.LBB17_127:
+	movzx	eax, byte ptr [rsp + 72]
+	shl	eax, 8
+	movzx	ebp, byte ptr [rsp + 64]
+	or	ebp, eax
+	jmp	.LBB17_56
+.LBB17_128:
+	mov	ebp, dword ptr [rsp + 36]
+	mov	edi, ebp
+	call	<optional_trait_methods::connection::Listener>::tcp_loopback
+	mov	ebx, edx
+	test	bp, bp
+	setne	cl
+	or	cl, al
+	test	cl, 1
+	je	.LBB17_131
+.LBB17_129:
+	cmp	eax, 1
+	jne	.LBB17_132
+	mov	r15d, 1
+	mov	ebp, ebx
+	jmp	.LBB17_133
+.LBB17_131:
+	mov	edi, ebx
+	call	optional_trait_methods::report_port
+.LBB17_132:
+	mov	edi, ebx
+	call	<optional_trait_methods::connection::Listener>::accept
+	mov	r15d, eax
+	mov	ebp, edx
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+.LBB17_133:
+	test	r15b, 1
+	je	.LBB17_135
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.72]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 29
+	jmp	.LBB17_105
+.LBB17_135:
+	xorps	xmm0, xmm0
+	lea	r12, [rsp + 2976]
+	movups	xmmword ptr [r12 + 328], xmm0
+	movups	xmmword ptr [r12 + 312], xmm0
+	movups	xmmword ptr [r12 + 296], xmm0
+	xor	ebx, ebx
+	mov	qword ptr [r12 + 344], rbx
+	lea	rdi, [rsp + 392]
+	mov	r15, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 272
+	xor	esi, esi
+	call	r15
+	mov	qword ptr [r12 + 288], r14
+	mov	qword ptr [r12 + 352], 1
+	movabs	rax, 9223372036854775807
+	lea	rcx, [rax + 1]
+	mov	qword ptr [r12 + 360], rcx
+	mov	qword ptr [r12 + 368], rax
+	mov	qword ptr [r12 - 8], rbx
+	lea	rsi, [rsp + 376]
+	mov	edx, 288
+	mov	rdi, r12
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 1416]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r15
+	mov	dword ptr [r14 + 1032], ebp
+	lea	rdi, [rsp + 376]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r15
+	lea	rdi, [rsp + 2456]
+	mov	edx, 512
+	xor	esi, esi
+	call	r15
+	lea	r13, [rsp + 112]
+	xor	edx, edx
+	mov	dword ptr [rsp + 8], eax
+.LBB17_136:
+	mov	rdi, qword ptr [rsp + 1400]
+	mov	rcx, qword ptr [rsp + 1408]
+	xor	r15d, r15d
+	mov	rax, rdx
+	xor	r12d, r12d
+.LBB17_137:
+	mov	rsi, rcx
+	mov	rcx, rdi
+.LBB17_138:
+	cmp	rsi, rcx
+	jb	.LBB17_142
+	mov	qword ptr [rsp + 2440], rbx
+	cmp	rax, 1025
+	mov	rsi, r14
+	mov	ecx, 1
+	cmovae	rsi, rcx
+	cmovae	rax, rbx
+	mov	edi, ebp
+	mov	rdx, rax
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	rdi, r13
+	mov	esi, ebp
+	lea	rdx, [rsp + 376]
+	call	optional_trait_methods::connection::read_fd
+	cmp	byte ptr [rsp + 112], 0
+	jne	.LBB17_152
+	mov	rcx, qword ptr [rsp + 120]
+	test	rcx, rcx
+	je	.LBB17_152
+	mov	qword ptr [rsp + 1400], rcx
+	mov	qword ptr [rsp + 1408], 0
+	xor	edx, edx
+	xor	eax, eax
+	xor	esi, esi
+.LBB17_142:
+	cmp	rcx, rsi
+	jb	.LBB17_171
+	cmp	rcx, 1024
+	ja	.LBB17_171
+	cmp	rsi, rcx
+	je	.LBB17_138
+	inc	rsi
+	mov	rdi, rcx
+	neg	rdi
+.LBB17_146:
+	mov	r8b, byte ptr [rsp + rsi + 375]
+	mov	qword ptr [rsp + 1408], rsi
+	cmp	r8b, 10
+	je	.LBB17_155
+	cmp	r12, 511
+	jbe	.LBB17_149
+	mov	r15b, 1
+	jmp	.LBB17_150
+.LBB17_149:
+	mov	byte ptr [rsp + r12 + 2456], r8b
+	inc	r12
+.LBB17_150:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB17_146
+	mov	rdi, rcx
+	jmp	.LBB17_137
+.LBB17_152:
+	test	r12, r12
+	setne	al
+	or	al, r15b
+	test	al, 1
+	je	.LBB17_170
+	test	r15b, 1
+	jne	.LBB17_159
+	mov	edx, 0
+	cmp	r12, 512
+	jbe	.LBB17_157
+	jmp	.LBB17_170
+.LBB17_155:
+	test	r15b, 1
+	jne	.LBB17_159
+	cmp	r12, 512
+	ja	.LBB17_171
+.LBB17_157:
+	test	r12, r12
+	setne	al
+	test	byte ptr [rsp + 72], al
+	jne	.LBB17_160
+	test	r12, r12
+	je	.LBB17_136
+	jmp	.LBB17_161
+.LBB17_159:
+	mov	qword ptr [rsp + 120], 3
+	mov	qword ptr [rsp + 128], 512
+	mov	byte ptr [rsp + 136], 4
+	jmp	.LBB17_164
+.LBB17_160:
+	mov	rdi, r14
+	lea	rsi, [rsp + 2456]
+	mov	rdx, r12
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB17_161:
+	lea	rdi, [rsp + 296]
+	lea	rsi, [rsp + 2456]
+	mov	rdx, r12
+	call	<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::parse_command
+	cmp	byte ptr [rsp + 296], -1
+	je	.LBB17_163
+	mov	ecx, 10
+	mov	rdi, r13
+	lea	rsi, [rsp + 296]
+	rep movsq es:[rdi], [rsi]
+	lea	rdi, [rsp + 216]
+	lea	rsi, [rsp + 2968]
+	mov	rdx, r14
+	mov	rcx, r13
+	call	<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	jmp	.LBB17_165
+.LBB17_163:
+	mov	eax, dword ptr [rsp + 312]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rdx, qword ptr [rsp + 16]
+	cmovne	rdx, qword ptr [rsp + 304]
+	sete	cl
+	mov	esi, dword ptr [rsp + 8]
+	movzx	esi, sil
+	cmovne	esi, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 120], rcx
+	mov	qword ptr [rsp + 16], rdx
+	mov	qword ptr [rsp + 128], rdx
+	mov	dword ptr [rsp + 8], esi
+	mov	byte ptr [rsp + 136], sil
+.LBB17_164:
+	mov	byte ptr [rsp + 112], -1
+	lea	rdi, [rsp + 216]
+	mov	rsi, r14
+	mov	rdx, r13
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+.LBB17_165:
+	cmp	byte ptr [rsp + 216], -1
+	jne	.LBB17_169
+	mov	rax, qword ptr [rsp + 224]
+	cmp	byte ptr [rsp + 64], 0
+	jne	.LBB17_172
+	cmp	eax, 1
+	je	.LBB17_175
+	cmp	eax, 4
+	je	.LBB17_174
+.LBB17_169:
+	mov	ebp, dword ptr [rsp + 2448]
+	mov	rdx, qword ptr [rsp + 2440]
+	jmp	.LBB17_136
+.LBB17_170:
+	xor	edx, edx
+.LBB17_171:
+	xor	eax, eax
+	mov	qword ptr [rsp + 2440], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, ebp
+	mov	rsi, r14
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	jmp	.LBB17_99
+.LBB17_172:
+	cmp	eax, 1
+	je	.LBB17_175
+	cmp	eax, 4
+	jne	.LBB17_177
+.LBB17_174:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 1416]
+	mov	edx, 23
+	jmp	.LBB17_176
+.LBB17_175:
+	mov	rsi, qword ptr [rsp + 232]
+	mov	rdx, qword ptr [rsp + 240]
+	lea	rdi, [rsp + 1416]
+.LBB17_176:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 1416]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB17_177:
+	mov	rdx, qword ptr [rsp + 2440]
+	mov	ebx, 1
+	mov	ebp, dword ptr [rsp + 2448]
+	jmp	.LBB17_171
+.LBB17_178:
+	ud2
+.Lfunc_end17:
+.LJTI17_0:
+	.long	.LBB17_28-.LJTI17_0
+	.long	.LBB17_38-.LJTI17_0
+	.long	.LBB17_31-.LJTI17_0
+	.long	.LBB17_32-.LJTI17_0
+	.long	.LBB17_30-.LJTI17_0
+	.long	.LBB17_178-.LJTI17_0
+	.long	.LBB17_41-.LJTI17_0
+
+rust_eh_personality:
+.Lfunc_begin18:
+	ret
+.Lfunc_end18:
+
+core::panicking::panic_fmt:
+.Lfunc_begin19:
+	.loc	1 60 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp0:
+	.loc	1 80 14 prologue_end
+	call	__rustc::rust_begin_unwind
+.Ltmp1:
+.Lfunc_end19:
+
+core::panicking::panic_bounds_check:
+.Lfunc_begin20:
+	.loc	1 266 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp2:
+	.loc	1 271 5 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp3:
+.Lfunc_end20:
+
+core::slice::index::slice_index_fail:
+.Lfunc_begin21:
+	.loc	2 38 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp4:
+	.loc	3 177 9 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp5:
+.Lfunc_end21:
+
+optional_trait_methods::num::parse_num::<isize>:
+.Lfunc_begin22:
+	test	rsi, rsi
+	je	.LBB22_56
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB22_4
+	cmp	ecx, 45
+	jne	.LBB22_6
+	mov	cl, 1
+	jmp	.LBB22_5
+.LBB22_4:
+	xor	ecx, ecx
+.LBB22_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB22_7
+.LBB22_6:
+	xor	ecx, ecx
+.LBB22_7:
+	cmp	rsi, 1
+	jbe	.LBB22_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB22_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB22_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB22_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB22_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB22_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB22_32
+.LBB22_14:
+	test	rsi, rsi
+	je	.LBB22_56
+	mov	al, byte ptr [rdi]
+.LBB22_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB22_56
+.LBB22_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB22_19
+	cmp	byte ptr [rax], 95
+	je	.LBB22_56
+.LBB22_19:
+	xor	r8d, r8d
+	xor	edx, edx
+.LBB22_20:
+	cmp	rdi, rsi
+	je	.LBB22_51
+	mov	al, byte ptr [rdi]
+	inc	rdi
+	cmp	al, 95
+	jne	.LBB22_23
+	xor	eax, eax
+	test	r8b, 1
+	mov	r8d, 0
+	jne	.LBB22_20
+	jmp	.LBB22_52
+.LBB22_23:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB22_57
+	imul	rdx, rdx, 10
+	jo	.LBB22_57
+	movzx	eax, al
+	test	cl, cl
+	je	.LBB22_27
+	sub	rdx, rax
+	jno	.LBB22_28
+	jmp	.LBB22_57
+.LBB22_27:
+	add	rdx, rax
+	jo	.LBB22_57
+.LBB22_28:
+	mov	r8b, 1
+	jmp	.LBB22_20
+.LBB22_29:
+	mov	r8b, 8
+	jmp	.LBB22_31
+.LBB22_30:
+	mov	r8b, 2
+.LBB22_31:
+	xor	r9d, r9d
+.LBB22_32:
+	cmp	rsi, 2
+	je	.LBB22_56
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB22_53
+.LBB22_34:
+	cmp	dl, r8b
+	jae	.LBB22_56
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB22_37
+	cmp	byte ptr [rax], 95
+	je	.LBB22_56
+.LBB22_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB22_38:
+	cmp	r10, rdi
+	je	.LBB22_51
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB22_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB22_52
+	jmp	.LBB22_38
+.LBB22_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB22_45
+	test	r9b, r9b
+	je	.LBB22_57
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB22_57
+	add	al, -87
+	mov	r11d, eax
+.LBB22_45:
+	cmp	r11b, r8b
+	jae	.LBB22_57
+	imul	rdx, rsi
+	jo	.LBB22_57
+	movzx	eax, r11b
+	test	cl, cl
+	je	.LBB22_49
+	sub	rdx, rax
+	jo	.LBB22_57
+	jmp	.LBB22_50
+.LBB22_49:
+	add	rdx, rax
+	jo	.LBB22_57
+.LBB22_50:
+	mov	r11b, 1
+	jmp	.LBB22_38
+.LBB22_51:
+	mov	eax, 1
+	ret
+.LBB22_53:
+	test	r9b, r9b
+	je	.LBB22_56
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB22_56
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB22_34
+.LBB22_56:
+	xor	eax, eax
+.LBB22_52:
+	ret
+.LBB22_57:
+	xor	eax, eax
+	ret
+.Lfunc_end22:
+
+optional_trait_methods::num::parse_num::<usize>:
+.Lfunc_begin23:
+	test	rsi, rsi
+	je	.LBB23_55
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB23_4
+	cmp	ecx, 45
+	jne	.LBB23_6
+	mov	cl, 1
+	jmp	.LBB23_5
+.LBB23_4:
+	xor	ecx, ecx
+.LBB23_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB23_7
+.LBB23_6:
+	xor	ecx, ecx
+.LBB23_7:
+	cmp	rsi, 1
+	jbe	.LBB23_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB23_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB23_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB23_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB23_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB23_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB23_32
+.LBB23_14:
+	test	rsi, rsi
+	je	.LBB23_55
+	mov	al, byte ptr [rdi]
+.LBB23_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB23_55
+.LBB23_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB23_19
+	cmp	byte ptr [rax], 95
+	je	.LBB23_55
+.LBB23_19:
+	mov	r8d, 10
+	xor	r9d, r9d
+	xor	edx, edx
+.LBB23_20:
+	cmp	rdi, rsi
+	je	.LBB23_50
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB23_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB23_20
+	jmp	.LBB23_51
+.LBB23_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB23_28
+	mov	rax, rdx
+	mul	r8
+	jo	.LBB23_28
+	mov	rdx, rax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB23_27
+	sub	rdx, rax
+	jae	.LBB23_20
+	jmp	.LBB23_28
+.LBB23_27:
+	add	rdx, rax
+	jae	.LBB23_20
+.LBB23_28:
+	xor	eax, eax
+	ret
+.LBB23_29:
+	mov	r8b, 8
+	jmp	.LBB23_31
+.LBB23_30:
+	mov	r8b, 2
+.LBB23_31:
+	xor	r9d, r9d
+.LBB23_32:
+	cmp	rsi, 2
+	je	.LBB23_55
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB23_52
+.LBB23_34:
+	cmp	dl, r8b
+	jae	.LBB23_55
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB23_37
+	cmp	byte ptr [rax], 95
+	je	.LBB23_55
+.LBB23_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB23_38:
+	cmp	r10, rdi
+	je	.LBB23_50
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB23_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB23_51
+	jmp	.LBB23_38
+.LBB23_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB23_45
+	test	r9b, r9b
+	je	.LBB23_28
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB23_28
+	add	al, -87
+	mov	r11d, eax
+.LBB23_45:
+	cmp	r11b, r8b
+	jae	.LBB23_28
+	mov	rax, rdx
+	mul	rsi
+	jo	.LBB23_28
+	mov	rdx, rax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB23_49
+	sub	rdx, rax
+	jae	.LBB23_38
+	jmp	.LBB23_28
+.LBB23_49:
+	add	rdx, rax
+	jae	.LBB23_38
+	jmp	.LBB23_28
+.LBB23_50:
+	mov	eax, 1
+	ret
+.LBB23_52:
+	test	r9b, r9b
+	je	.LBB23_55
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB23_55
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB23_34
+.LBB23_55:
+	xor	eax, eax
+.LBB23_51:
+	ret
+.Lfunc_end23:
+
+<optional_trait_methods::commands::Args>::reg:
+.Lfunc_begin24:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	cmp	rdx, 8
+	setb	cl
+	and	cl, al
+	cmovne	r15, rdx
+	neg	cl
+	or	cl, 2
+	mov	qword ptr [rbx], r15
+	mov	byte ptr [rbx + 8], cl
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end24:
+
+<optional_trait_methods::commands::Args>::next:
+.Lfunc_begin25:
+	mov	r10, qword ptr [rsi + 8]
+	mov	rax, qword ptr [rsi + 16]
+	xor	edx, edx
+	test	r10, r10
+	setne	dl
+	cmp	r10, 1
+	mov	rcx, r10
+	adc	rcx, -1
+	mov	r8, qword ptr [rsi]
+	add	r8, rdx
+	mov	r9, rcx
+	cmp	r10, 2
+	jb	.LBB25_5
+	xor	r9d, r9d
+.LBB25_2:
+	cmp	byte ptr [r8 + r9], 32
+	je	.LBB25_5
+	inc	r9
+	cmp	rcx, r9
+	jne	.LBB25_2
+	mov	r9, rcx
+.LBB25_5:
+	add	rax, rdx
+	sub	rcx, r9
+	lea	rdx, [r8 + r9]
+	mov	qword ptr [rsi], rdx
+	mov	qword ptr [rsi + 8], rcx
+	lea	rcx, [r9 + rax]
+	mov	qword ptr [rsi + 16], rcx
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], r8
+	mov	qword ptr [rdi + 16], r9
+	ret
+.Lfunc_end25:
+
+<optional_trait_methods::commands::Args>::names:
+.Lfunc_begin26:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 200
+	mov	qword ptr [rsp + 32], rdi
+	lea	r14, [rsp + 40]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r12, qword ptr [r14 + 8]
+	mov	rbx, qword ptr [r14 + 16]
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, qword ptr [rip + bcmp@GOTPCREL]
+.LBB26_1:
+	test	rbx, rbx
+	je	.LBB26_2
+	xor	r13d, r13d
+.LBB26_4:
+	cmp	byte ptr [r12 + r13], 59
+	je	.LBB26_7
+	inc	r13
+	cmp	rbx, r13
+	jne	.LBB26_4
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 24], r12
+	mov	r13, rbx
+	jmp	.LBB26_8
+.LBB26_2:
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	xor	ebx, ebx
+	mov	qword ptr [rsp + 24], r12
+	xor	r13d, r13d
+	jmp	.LBB26_8
+.LBB26_7:
+	lea	rax, [r13 + 1]
+	sub	rbx, rax
+	lea	rax, [r12 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 24], rax
+	mov	dword ptr [rsp + 12], 0
+.LBB26_8:
+	mov	edx, 144
+	lea	rbp, [rsp + 56]
+	mov	rdi, rbp
+	lea	rsi, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.6]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r14d, 6
+.LBB26_9:
+	cmp	qword ptr [rbp + 16], r13
+	jne	.LBB26_11
+	mov	rdi, qword ptr [rbp + 8]
+	mov	rsi, r12
+	mov	rdx, r13
+	call	r15
+	test	eax, eax
+	je	.LBB26_12
+.LBB26_11:
+	add	rbp, 24
+	dec	r14
+	jne	.LBB26_9
+	jmp	.LBB26_13
+.LBB26_12:
+	mov	rax, qword ptr [rsp + 16]
+	or	al, byte ptr [rbp]
+	mov	qword ptr [rsp + 16], rax
+.LBB26_13:
+	mov	r12, qword ptr [rsp + 24]
+	cmp	byte ptr [rsp + 12], 0
+	je	.LBB26_1
+	mov	rax, qword ptr [rsp + 32]
+	mov	rcx, qword ptr [rsp + 16]
+	mov	byte ptr [rax], cl
+	mov	byte ptr [rax + 8], -1
+	add	rsp, 200
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end26:
+
+<optional_trait_methods::connection::Listener>::tcp_loopback:
+.Lfunc_begin27:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	qword ptr [rsp + 20], 0
+	mov	word ptr [rsp + 12], 2
+	rol	di, 8
+	mov	word ptr [rsp + 14], di
+	mov	dword ptr [rsp + 16], 16777343
+	mov	edi, 2
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebx, edx
+	mov	ebp, 1
+	test	al, 1
+	jne	.LBB27_5
+	lea	rcx, [rsp + 28]
+	mov	dword ptr [rcx], 1
+	mov	edi, ebx
+	mov	esi, 1
+	mov	edx, 2
+	mov	r8d, 4
+	call	qword ptr [rip + setsockopt@GOTPCREL]
+	lea	rsi, [rsp + 12]
+	mov	edi, ebx
+	mov	edx, 16
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB27_3
+	mov	edi, ebx
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB27_3
+	xor	ebp, ebp
+	jmp	.LBB27_5
+.LBB27_3:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebx, r14d
+.LBB27_5:
+	mov	eax, ebp
+	mov	edx, ebx
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end27:
+
+<optional_trait_methods::connection::Listener>::socket:
+.Lfunc_begin28:
+	push	rbx
+	xor	ebx, ebx
+	mov	esi, 1
+	xor	edx, edx
+	call	qword ptr [rip + socket@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB28_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB28_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end28:
+
+<optional_trait_methods::connection::Listener>::unix:
+.Lfunc_begin29:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 112
+	mov	rdx, rsi
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [rsp + 96], xmm0
+	movups	xmmword ptr [rsp + 84], xmm0
+	movups	xmmword ptr [rsp + 68], xmm0
+	movups	xmmword ptr [rsp + 52], xmm0
+	movups	xmmword ptr [rsp + 36], xmm0
+	movups	xmmword ptr [rsp + 20], xmm0
+	movups	xmmword ptr [rsp + 4], xmm0
+	mov	word ptr [rsp + 2], 1
+	lea	rax, [rsi - 108]
+	cmp	rax, -107
+	jae	.LBB29_2
+	mov	ebx, 1
+	mov	ebp, 36
+	jmp	.LBB29_7
+.LBB29_2:
+	mov	rsi, rdi
+	lea	rbx, [rsp + 4]
+	mov	rdi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	rdi, rbx
+	call	qword ptr [rip + unlink@GOTPCREL]
+	mov	ebx, 1
+	mov	edi, 1
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebp, edx
+	test	al, 1
+	jne	.LBB29_7
+	lea	rsi, [rsp + 2]
+	mov	edi, ebp
+	mov	edx, 110
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB29_6
+	mov	edi, ebp
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB29_6
+	xor	ebx, ebx
+	jmp	.LBB29_7
+.LBB29_6:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebp, r14d
+.LBB29_7:
+	mov	eax, ebx
+	mov	edx, ebp
+	add	rsp, 112
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end29:
+
+<optional_trait_methods::connection::Listener>::accept:
+.Lfunc_begin30:
+	push	rbx
 	xor	ebx, ebx
-.LBB2_14:
-	mov	rax, rbx
-	add	rsp, 16
+	xor	esi, esi
+	xor	edx, edx
+	call	qword ptr [rip + accept@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB30_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB30_2:
+	mov	eax, ebx
 	pop	rbx
 	ret
-.Lfunc_end2:
+.Lfunc_end30:
 
-optional_trait_methods::commands::parse_isize:
-	test	rsi, rsi
-	je	.LBB3_12
-	movzx	eax, byte ptr [rdi]
-	cmp	eax, 45
-	je	.LBB3_3
-	xor	ecx, ecx
-	cmp	eax, 43
-	je	.LBB3_4
-	jmp	.LBB3_6
-.LBB3_3:
-	mov	cl, 1
-.LBB3_4:
-	dec	rsi
-	je	.LBB3_12
-	inc	rdi
-.LBB3_6:
+optional_trait_methods::commands::unrecognized:
+.Lfunc_begin31:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	xor	r14d, r14d
+.LBB31_1:
+	cmp	byte ptr [rbx + r14], 32
+	je	.LBB31_4
+	inc	r14
+	cmp	rsi, r14
+	jne	.LBB31_1
+	mov	r14, rsi
+.LBB31_4:
+	mov	r15d, 8
+	lea	r12, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.33]
+	mov	r13, qword ptr [rip + bcmp@GOTPCREL]
+.LBB31_5:
+	cmp	qword ptr [r15 + r12], r14
+	jne	.LBB31_8
+	mov	rdi, qword ptr [r15 + r12 - 8]
+	mov	rsi, rbx
+	mov	rdx, r14
+	call	r13
+	test	eax, eax
+	je	.LBB31_7
+.LBB31_8:
+	add	r15, 16
+	cmp	r15, 152
+	jne	.LBB31_5
 	xor	eax, eax
-	xor	r8d, r8d
-	xor	edx, edx
-.LBB3_7:
-	cmp	rsi, r8
-	je	.LBB3_13
-	mov	r9b, byte ptr [rdi + r8]
-	add	r9b, -48
-	cmp	r9b, 9
-	ja	.LBB3_11
-	imul	rdx, rdx, 10
-	jo	.LBB3_11
-	movzx	r9d, r9b
-	inc	r8
-	add	rdx, r9
-	jno	.LBB3_7
-.LBB3_11:
+	jmp	.LBB31_10
+.LBB31_7:
+	mov	al, 1
+.LBB31_10:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
 	ret
-.LBB3_12:
+.Lfunc_end31:
+
+optional_trait_methods::connection::read_fd:
+.Lfunc_begin32:
+	push	rbx
+	mov	rax, rdx
+	mov	rbx, rdi
+	mov	edx, 1024
+	mov	edi, esi
+	mov	rsi, rax
+	call	qword ptr [rip + read@GOTPCREL]
+	test	rax, rax
+	js	.LBB32_2
+	mov	qword ptr [rbx + 8], rax
 	xor	eax, eax
+	jmp	.LBB32_3
+.LBB32_2:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	eax, dword ptr [rax]
+	mov	dword ptr [rbx + 4], eax
+	mov	eax, 1
+.LBB32_3:
+	mov	dword ptr [rbx], eax
+	pop	rbx
 	ret
-.LBB3_13:
-	movzx	ecx, cl
-	neg	rcx
-	or	rcx, 1
+.Lfunc_end32:
+
+optional_trait_methods::connection::write_fd:
+.Lfunc_begin33:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	test	rdx, rdx
+	je	.LBB33_8
+	mov	ebp, ecx
+	mov	rbx, rdx
+	mov	r14, rsi
+	mov	r15d, edi
+	mov	r12, qword ptr [rip + send@GOTPCREL]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+.LBB33_2:
+	mov	edi, r15d
+	mov	rsi, r14
+	mov	rdx, rbx
+	test	bpl, bpl
+	je	.LBB33_3
+	mov	ecx, 16384
+	call	r12
+	jmp	.LBB33_5
+.LBB33_3:
+	call	r13
+.LBB33_5:
+	test	rax, rax
+	js	.LBB33_10
+	cmp	rbx, rax
+	jb	.LBB33_9
+	add	r14, rax
+	sub	rbx, rax
+	jne	.LBB33_2
+.LBB33_8:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB33_10:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB33_9:
+	mov	rdi, rax
+	mov	rsi, rbx
+	mov	rdx, rbx
+	call	core::slice::index::slice_index_fail
+.Lfunc_end33:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint:
+.Lfunc_begin34:
+	push	rax
+	mov	rcx, qword ptr [rdi + 296]
+	mov	rdx, qword ptr [rdi + 360]
+	mov	rax, qword ptr [rdi + 280]
+	lea	rsi, [rdi + 24]
+	cmp	qword ptr [rdi + 288], 16
+	jne	.LBB34_3
+	cmp	rax, 16
+	jae	.LBB34_5
+	mov	r8, rax
+	shl	r8, 4
+	movups	xmm0, xmmword ptr [rsi + r8]
+	mov	qword ptr [rdi], 1
+	movups	xmmword ptr [rdi + 8], xmm0
+	jmp	.LBB34_4
+.LBB34_3:
+	mov	qword ptr [rdi], 0
+	cmp	rax, 16
+	jae	.LBB34_5
+.LBB34_4:
+	shl	rax, 4
+	mov	qword ptr [rsi + rax], rcx
+	mov	qword ptr [rsi + rax + 8], rdx
+	mov	eax, dword ptr [rdi + 280]
+	inc	eax
+	and	eax, 15
+	mov	qword ptr [rdi + 280], rax
+	mov	rax, qword ptr [rdi + 288]
+	inc	rax
+	cmp	rax, 16
+	mov	ecx, 16
+	cmovb	rcx, rax
+	mov	qword ptr [rdi + 288], rcx
+	pop	rax
+	ret
+.LBB34_5:
+	mov	esi, 16
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end34:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds:
+.Lfunc_begin35:
+	cmp	rsi, rdx
+	jle	.LBB35_2
+	lea	rax, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.42]
+	jmp	.LBB35_3
+.LBB35_2:
+	mov	qword ptr [rdi + 368], rsi
+	mov	qword ptr [rdi + 376], rdx
+	mov	rax, qword ptr [rdi + 360]
+	cmp	rsi, rax
+	cmovg	rax, rsi
+	cmp	rdx, rax
+	cmovl	rax, rdx
+	mov	qword ptr [rdi + 360], rax
 	xor	eax, eax
-	imul	rdx, rcx
-	setno	al
+.LBB35_3:
+	mov	edx, 26
 	ret
-.Lfunc_end3:
+.Lfunc_end35:
 
 <optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_factor:
-	mov	qword ptr [rdi + 8], rsi
+.Lfunc_begin36:
+	mov	rax, qword ptr [rdi + 368]
+	mov	rcx, qword ptr [rdi + 376]
+	cmp	rax, rsi
+	cmovg	rsi, rax
+	cmp	rcx, rsi
+	cmovl	rsi, rcx
+	mov	qword ptr [rdi + 360], rsi
 	ret
-.Lfunc_end4:
+.Lfunc_end36:
 
 <optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::dec:
-	dec	qword ptr [rdi]
+.Lfunc_begin37:
+	dec	qword ptr [rdi + 296]
 	ret
-.Lfunc_end5:
+.Lfunc_end37:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::div:
+.Lfunc_begin38:
+	test	rsi, rsi
+	je	.LBB38_1
+	mov	rax, qword ptr [rdi + 296]
+	mov	rcx, rax
+	btc	rcx, 63
+	mov	rdx, rsi
+	not	rdx
+	or	rdx, rcx
+	je	.LBB38_3
+	cqo
+	idiv	rsi
+	mov	qword ptr [rdi + 296], rax
+	xor	eax, eax
+	ret
+.LBB38_1:
+	lea	rax, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.43]
+	mov	edx, 17
+	ret
+.LBB38_3:
+	lea	rax, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.44]
+	mov	edx, 29
+	ret
+.Lfunc_end38:
 
 <optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc:
-	inc	qword ptr [rdi]
+.Lfunc_begin39:
+	inc	qword ptr [rdi + 296]
 	ret
-.Lfunc_end6:
+.Lfunc_end39:
 
 <optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::mul:
+.Lfunc_begin40:
 	cmp	rsi, 7
-	jne	.LBB7_2
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.13]
-	ret
-.LBB7_2:
-	imul	rsi, qword ptr [rdi + 8]
-	imul	rsi, qword ptr [rdi]
-	mov	qword ptr [rdi], rsi
+	jne	.LBB40_2
+	lea	rax, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.45]
+	jmp	.LBB40_3
+.LBB40_2:
+	imul	rsi, qword ptr [rdi + 360]
+	imul	rsi, qword ptr [rdi + 296]
+	mov	qword ptr [rdi + 296], rsi
 	xor	eax, eax
+.LBB40_3:
+	mov	edx, 28
 	ret
-.Lfunc_end7:
+.Lfunc_end40:
 
-<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::get_state:
-	mov	rax, qword ptr [rdi]
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rem:
+.Lfunc_begin41:
+	test	rsi, rsi
+	je	.LBB41_1
+	mov	rax, qword ptr [rdi + 296]
+	mov	rcx, rax
+	btc	rcx, 63
+	mov	rdx, rsi
+	not	rdx
+	or	rdx, rcx
+	je	.LBB41_3
+	cqo
+	idiv	rsi
+	mov	qword ptr [rdi + 296], rdx
+	xor	eax, eax
 	ret
-.Lfunc_end8:
-
-<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::set_state:
-	mov	qword ptr [rdi], rsi
+.LBB41_1:
+	lea	rax, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.43]
+	mov	edx, 17
 	ret
-.Lfunc_end9:
+.LBB41_3:
+	lea	rax, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.44]
+	mov	edx, 29
+	ret
+.Lfunc_end41:
 
-main:
-	push	rbp
-	push	r15
-	push	r14
-	push	r13
-	push	r12
-	push	rbx
-	sub	rsp, 1224
-	lea	rbx, [rsp + 32]
-	mov	qword ptr [rbx], 0
-	mov	qword ptr [rbx + 8], 1
-	lea	r14, [rsp + 184]
-	xor	r13d, r13d
-	mov	edx, 1040
-	mov	rdi, r14
-	xor	esi, esi
-	call	qword ptr [rip + memset@GOTPCREL]
-	xorps	xmm0, xmm0
-	lea	r15, [rsp + 48]
-	movaps	xmmword ptr [r15 + 112], xmm0
-	movaps	xmmword ptr [r15 + 96], xmm0
-	movaps	xmmword ptr [r15 + 80], xmm0
-	movaps	xmmword ptr [r15 + 64], xmm0
-	movaps	xmmword ptr [r15 + 48], xmm0
-	movaps	xmmword ptr [r15 + 32], xmm0
-	movaps	xmmword ptr [r15 + 16], xmm0
-	movaps	xmmword ptr [r15], xmm0
-	mov	r12, qword ptr [rip + read@GOTPCREL]
-	xor	eax, eax
-.LBB10_1:
-	xor	ebp, ebp
-.LBB10_2:
-	cmp	rax, r13
-	jb	.LBB10_5
-	mov	edx, 1024
-	xor	edi, edi
-	mov	rsi, r14
-	call	r12
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::undo:
+.Lfunc_begin42:
+	mov	rax, qword ptr [rdi + 288]
+	lea	rcx, [rsi - 1]
+	cmp	rcx, rax
+	jae	.LBB42_2
+	mov	qword ptr [rdi], 0
+	mov	ecx, dword ptr [rdi + 280]
+	sub	ecx, esi
+	and	ecx, 15
+	mov	qword ptr [rdi + 280], rcx
+	sub	rax, rsi
+	mov	qword ptr [rdi + 288], rax
+	shl	ecx, 4
+	mov	rax, qword ptr [rdi + rcx + 24]
+	mov	rcx, qword ptr [rdi + rcx + 32]
+	mov	rdx, qword ptr [rdi + 368]
+	mov	rsi, qword ptr [rdi + 376]
+	mov	qword ptr [rdi + 296], rax
+	cmp	rdx, rcx
+	cmovg	rcx, rdx
+	cmp	rsi, rcx
+	cmovl	rcx, rsi
+	mov	qword ptr [rdi + 360], rcx
+	jmp	.LBB42_3
+.LBB42_2:
+	test	rsi, rsi
+	je	.LBB42_3
+	xor	ecx, ecx
 	test	rax, rax
-	jle	.LBB10_20
-	mov	r13, rax
-	mov	qword ptr [rsp + 1208], rax
-	mov	qword ptr [rsp + 1216], 0
+	setne	cl
+	lea	rdx, [rcx + 2*rcx + 16]
+	lea	rcx, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.47]
+	lea	rax, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.46]
+	cmove	rax, rcx
+	ret
+.LBB42_3:
 	xor	eax, eax
-.LBB10_5:
-	cmp	r13, rax
-	jb	.LBB10_19
-	cmp	r13, 1024
-	ja	.LBB10_19
-	cmp	rax, r13
-	je	.LBB10_2
-	inc	rax
-	mov	rcx, r13
-	neg	rcx
-.LBB10_9:
-	mov	dl, byte ptr [rsp + rax + 183]
-	mov	qword ptr [rsp + 1216], rax
-	cmp	dl, 10
-	je	.LBB10_10
-	cmp	rbp, 127
-	ja	.LBB10_14
-	mov	byte ptr [rsp + rbp + 48], dl
-	inc	rbp
-.LBB10_14:
-	lea	rdx, [rcx + rax]
-	inc	rdx
-	inc	rax
-	cmp	rdx, 1
-	jne	.LBB10_9
-	mov	rax, r13
-	jmp	.LBB10_2
-.LBB10_20:
-	lea	rax, [rbp - 129]
-	cmp	rax, -128
-	jb	.LBB10_19
-	jmp	.LBB10_21
-.LBB10_10:
-	cmp	rbp, 128
-	ja	.LBB10_19
-	test	rbp, rbp
-	je	.LBB10_1
-.LBB10_21:
-	lea	r13, [rsp + 8]
-	mov	rdi, r13
-	mov	rsi, r15
-	mov	rdx, rbp
-	call	<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::parse_command
-	cmp	byte ptr [rsp + 8], -1
-	je	.LBB10_22
-	mov	rdi, rbx
-	mov	rsi, r13
-	call	<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::handle
-	test	rax, rax
-	jne	.LBB10_18
-	jmp	.LBB10_17
-.LBB10_22:
-	mov	edx, 16
-	mov	edi, 1
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.7]
-	mov	r13, qword ptr [rip + write@GOTPCREL]
-	call	r13
-	mov	edx, 1
-	mov	edi, 1
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	call	r13
-.LBB10_17:
-	mov	r13, qword ptr [rsp + 1208]
-	mov	rax, qword ptr [rsp + 1216]
-	jmp	.LBB10_1
-.LBB10_18:
-	mov	edx, 28
-	mov	edi, 1
-	mov	rsi, rax
-	mov	rbx, qword ptr [rip + write@GOTPCREL]
-	call	rbx
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	mov	edx, 1
-	mov	edi, 1
-	call	rbx
-.LBB10_19:
+	ret
+.Lfunc_end42:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg:
+.Lfunc_begin43:
+	test	rsi, rsi
+	je	.LBB43_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB43_5
+	lea	rdi, [rdi + 8*rax]
+	add	rdi, 304
+	jmp	.LBB43_2
+.LBB43_1:
+	add	rdi, 296
+.LBB43_2:
+	mov	rax, qword ptr [rdi]
+	ret
+.LBB43_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end43:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rollback:
+.Lfunc_begin44:
+	mov	rsi, qword ptr [rdi]
+	mov	rax, qword ptr [rdi + 8]
+	mov	rcx, qword ptr [rdi + 16]
+	mov	qword ptr [rdi], 0
+	mov	rdx, qword ptr [rdi + 288]
+	test	rdx, rdx
+	je	.LBB44_1
+	mov	r8d, dword ptr [rdi + 280]
+	dec	r8d
+	and	r8d, 15
+	mov	qword ptr [rdi + 280], r8
+	lea	r9, [rdx - 1]
+	mov	qword ptr [rdi + 288], r9
+	shl	r8d, 4
+	mov	r9, qword ptr [rdi + r8 + 24]
+	mov	r10, qword ptr [rdi + r8 + 32]
+	cmp	rsi, 1
+	jne	.LBB44_4
+	lea	rsi, [rdi + r8]
+	add	rsi, 24
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rdi + 288], rdx
+.LBB44_4:
+	mov	qword ptr [rdi + 296], r9
+	mov	qword ptr [rdi + 360], r10
 	xor	eax, eax
-	add	rsp, 1224
-	pop	rbx
-	pop	r12
-	pop	r13
-	pop	r14
-	pop	r15
-	pop	rbp
 	ret
-.Lfunc_end10:
+.LBB44_1:
+	lea	rax, [rip + .Lanon.1b28226e36c7b7168e76b31b543423be.50]
+	ret
+.Lfunc_end44:
 
-rust_eh_personality:
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::set_state:
+.Lfunc_begin45:
+	mov	qword ptr [rdi + 296], rsi
 	ret
-.Lfunc_end11:
+.Lfunc_end45:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg:
+.Lfunc_begin46:
+	test	rsi, rsi
+	je	.LBB46_1
+	mov	rax, rsi
+	cmp	rsi, 8
+	jae	.LBB46_5
+	mov	qword ptr [rdi + 8*rax + 296], rdx
+	ret
+.LBB46_1:
+	mov	qword ptr [rdi + 296], rdx
+	ret
+.LBB46_5:
+	push	rax
+	dec	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end46:
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.0:
+	.byte	10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.1:
+	.byte	59
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.2:
+	.ascii	"E 01"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.3:
+	.ascii	"E 02"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.4:
+	.ascii	"E."
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.5:
+	.ascii	" at "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.6:
+	.ascii	"E 05"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.7:
+	.ascii	"unknown option: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.8:
+	.ascii	"missing value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.9:
+	.ascii	"invalid value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.10:
+	.ascii	": "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.11:
+	.ascii	" doesn't take a value"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.0:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.12:
+	.ascii	"only one of --unix / --tcp may be given"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.14:
+	.ascii	"0123456789abcdef"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.17:
+	.ascii	"--unix"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.18:
+	.ascii	"--tcp"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.19:
+	.ascii	"--initial-state"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.20:
+	.ascii	"--on-error"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.21:
+	.ascii	"--echo"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.22:
+	.ascii	"--repl"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.23:
+	.ascii	"--help"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.24:
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.17
+	.asciz	"\006\000\000\000\000\000\000\000"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.18
+	.ascii	"\005\000\000\000\000\000\000\000\001"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.19
+	.ascii	"\017\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.20
+	.ascii	"\n\000\000\000\000\000\000\000\003"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.21
+	.ascii	"\006\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.22
+	.ascii	"\006\000\000\000\000\000\000\000\005"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.23
+	.ascii	"\006\000\000\000\000\000\000\000\006"
+	.zero	7
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.28:
+	.ascii	"Invalid implementation!"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.29:
+	.ascii	"OK"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.30:
 	.ascii	"panic!\n"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.1:
-	.ascii	"Parse IncDec extension"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.31:
+	.ascii	"listening on 127.0.0.1:"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.2:
-	.ascii	"Parse Mul extension"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.32:
+	.ascii	"error: "
 
-.Lanon.cabb224323f7f456a7965482e4c09287.3:
-	.ascii	"* "
+.Lanon.62fb3b87f511695ce61fb365a19fad11.33:
+	.zero	2,10
 
-.Lanon.cabb224323f7f456a7965482e4c09287.4:
-	.ascii	"Parse ScaleFactor extension"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.34:
+	.ascii	"usage: optional-trait-methods [options]\n\n  --unix <path>              serve the first client to connect to a Unix domain socket\n  --tcp <port>               serve the first client to connect to 127.0.0.1:<port>\n                             (port 0 picks any free port, printed to stderr)\n                             (by default, serve stdin / stdout)\n  --initial-state <n>        start the target off in state <n> (by default, 0)\n  --on-error=continue|exit   whether to keep serving after a command fails\n  --echo                     repeat every command before its reply\n  --repl                     expect a human instead of a client (prompt, aliases, help)\n                             (needs the `repl` feature)\n  --help                     print this message\n\n  (`--name <value>` may also be written `--name=<value>`)"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.35:
+	.ascii	"Parse IncDec extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.5:
-	.ascii	"*~ "
+.Lanon.62fb3b87f511695ce61fb365a19fad11.39:
+	.ascii	"Parse Mul extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.6:
-	.ascii	"s "
+.Lanon.62fb3b87f511695ce61fb365a19fad11.41:
+	.ascii	"Parse ScaleFactor extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.7:
-	.ascii	"Unsupported cmd!"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.43:
+	.ascii	"Parse ScaleBounds extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.8:
-	.byte	10
+.Lanon.62fb3b87f511695ce61fb365a19fad11.45:
+	.ascii	"Parse Div extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.9:
-	.asciz	"%d\n"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.48:
+	.ascii	"Parse Undo extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.10:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.57:
 	.ascii	"IncDec extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.11:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.58:
 	.ascii	"Mul extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.12:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.59:
 	.ascii	"ScaleFactor extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.13:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.60:
+	.ascii	"ScaleBounds extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.61:
+	.ascii	"Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.62:
+	.ascii	"Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.63:
+	.ascii	"Undo checkpoint"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.64:
+	.ascii	"unknown command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.65:
+	.ascii	"unsupported command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.66:
+	.ascii	"bad number"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.67:
+	.ascii	"trailing garbage"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.68:
+	.ascii	"line too long"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.72:
+	.ascii	"failed to accept a connection"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.73:
+	.ascii	"--repl needs the `repl` feature"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.0:
+	.ascii	"incdec"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.1:
+	.ascii	"mul"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.2:
+	.ascii	"mul.scale_factor"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.3:
+	.ascii	"mul.scale_factor.scale_bounds"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.4:
+	.ascii	"div"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.5:
+	.ascii	"undo"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.6:
+	.byte	1
+	.zero	7
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.0
+	.ascii	"\006\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.1
+	.ascii	"\003\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.2
+	.ascii	"\020\000\000\000\000\000\000\000\b"
+	.zero	7
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.3
+	.ascii	"\035\000\000\000\000\000\000\000\020"
+	.zero	7
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.4
+	.ascii	"\003\000\000\000\000\000\000\000 "
+	.zero	7
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.5
+	.asciz	"\004\000\000\000\000\000\000"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.15:
+	.byte	43
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.17:
+	.byte	45
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.18:
+	.byte	42
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.20:
+	.ascii	"*~"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.28:
+	.ascii	"+-"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.29:
+	.ascii	"*~~"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.30:
+	.byte	47
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.31:
+	.byte	37
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.32:
+	.byte	117
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.33:
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.15
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.17
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.28
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.18
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.20
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.29
+	.asciz	"\003\000\000\000\000\000\000"
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.30
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.31
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.1b28226e36c7b7168e76b31b543423be.32
+	.asciz	"\001\000\000\000\000\000\000"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.42:
+	.ascii	"scale bounds are inverted!"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.43:
+	.ascii	"dividing by zero!"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.44:
+	.ascii	"dividing MIN by -1 overflows!"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.45:
 	.ascii	"multiplying by 7 is unlucky!"
 
-	.ident	"rustc version 1.99.0-nightly (6f72b5dd5 2026-07-22)"
+.Lanon.1b28226e36c7b7168e76b31b543423be.46:
+	.ascii	"not enough history!"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.47:
+	.ascii	"nothing to undo!"
+
+.Lanon.1b28226e36c7b7168e76b31b543423be.50:
+	.ascii	"nothing to roll back!"
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+
+	.byte	1
+	.byte	17
+	.byte	1
+	.byte	37
+	.byte	14
+	.byte	19
+	.byte	5
+	.byte	3
+	.byte	14
+	.byte	16
+	.byte	23
+	.byte	27
+	.byte	14
+	.byte	17
+	.byte	1
+	.byte	85
+	.byte	23
+	.byte	0
+	.byte	0
+	.byte	2
+	.byte	57
+	.byte	1
+	.byte	3
+	.byte	14
+	.byte	0
+	.byte	0
+	.byte	3
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	11
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	4
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	5
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	0
+.Lcu_begin0:
+	.long	.Ldebug_info_end0-.Ldebug_info_start0
+.Ldebug_info_start0:
+	.short	4
+	.long	.debug_abbrev
+	.byte	8
+	.byte	1
+	.long	.Linfo_string0
+	.short	28
+	.long	.Linfo_string1
+	.long	.Lline_table_start0
+	.long	.Linfo_string2
+	.quad	0
+	.long	.Ldebug_ranges0
+	.byte	2
+	.long	.Linfo_string3
+	.byte	2
+	.long	.Linfo_string4
+	.byte	3
+	.quad	.Lfunc_begin19
+	.long	.Lfunc_end19-.Lfunc_begin19
+	.byte	1
+	.byte	86
+	.long	.Linfo_string7
+	.long	.Linfo_string8
+	.byte	1
+	.byte	60
+	.byte	3
+
+
+	.byte	4
+	.quad	.Lfunc_begin20
+	.long	.Lfunc_end20-.Lfunc_begin20
+	.byte	1
+	.byte	86
+	.long	.Linfo_string9
+	.long	.Linfo_string10
+	.byte	1
+	.short	266
+	.byte	3
+
+
+	.byte	0
+	.byte	2
+	.long	.Linfo_string5
+	.byte	2
+	.long	.Linfo_string6
+	.byte	3
+	.quad	.Lfunc_begin21
+	.long	.Lfunc_end21-.Lfunc_begin21
+	.byte	1
+	.byte	86
+	.long	.Linfo_string11
+	.long	.Linfo_string12
+	.byte	2
+	.byte	38
+	.byte	3
+
+
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+.Ldebug_info_end0:
+.Lsec_end0:
+.Lsec_end1:
+.Lsec_end2:
+	.long	76
+	.short	2
+	.long	.Lcu_begin0
+	.byte	8
+	.byte	0
+	.zero	4,255
+	.quad	.Lfunc_begin19
+	.quad	.Lsec_end0-.Lfunc_begin19
+	.quad	.Lfunc_begin20
+	.quad	.Lsec_end1-.Lfunc_begin20
+	.quad	.Lfunc_begin21
+	.quad	.Lsec_end2-.Lfunc_begin21
+	.quad	0
+	.quad	0
+.Ldebug_ranges0:
+	.quad	.Lfunc_begin19
+	.quad	.Lfunc_end19
+	.quad	.Lfunc_begin20
+	.quad	.Lfunc_end20
+	.quad	.Lfunc_begin21
+	.quad	.Lfunc_end21
+	.quad	0
+	.quad	0
+.Linfo_string0:
+	.asciz	"clang LLVM (rustc version 1.97.0-nightly (e50aa6fba 2026-05-19))"
+.Linfo_string1:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a/library/core/src/lib.rs/@/core.667c7a611d73a360-cgu.0"
+.Linfo_string2:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a"
+.Linfo_string3:
+	.asciz	"core"
+.Linfo_string4:
+	.asciz	"panicking"
+.Linfo_string5:
+	.asciz	"slice"
+.Linfo_string6:
+	.asciz	"index"
+.Linfo_string7:
+	.asciz	"core::panicking::panic_fmt"
+.Linfo_string8:
+	.asciz	"panic_fmt"
+.Linfo_string9:
+	.asciz	"core::panicking::panic_bounds_check"
+.Linfo_string10:
+	.asciz	"panic_bounds_check"
+.Linfo_string11:
+	.asciz	"core::slice::index::slice_index_fail"
+.Linfo_string12:
+	.asciz	"slice_index_fail"
+	.ident	"rustc version 1.97.0-nightly (e50aa6fba 2026-05-19)"
+.Lline_table_start0:
@@ -1,576 +1,5307 @@
-__rustc::rust_begin_unwind:
-	push	rax
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.0]
-	mov	edx, 7
-	mov	edi, 2
-	call	qword ptr [rip + write@GOTPCREL]
-	call	qword ptr [rip + abort@GOTPCREL]
+<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin0:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	r12, rcx
+	mov	r13, rdx
+	mov	r15, rsi
+	movzx	ebp, byte ptr [rcx]
+	lea	rax, [rbp - 6]
+	xor	ebx, ebx
+	cmp	rbp, 7
+	cmovae	rbx, rax
+	mov	r14, rdi
+	lea	rax, [rip + .LJTI0_0]
+	movsxd	rcx, dword ptr [rax + 4*rbx]
+	add	rcx, rax
+	jmp	rcx
+.LBB0_1:
+	mov	qword ptr [rsp + 8], r13
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	lea	r13, [rsp + 16]
+	mov	rdi, r13
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::checkpoint
+	mov	r13, qword ptr [r13]
+	cmp	r13, 1
+	jne	.LBB0_4
+	mov	rax, qword ptr [rsp + 24]
+	test	rax, rax
+	je	.LBB0_4
+.LBB0_3:
+	mov	rcx, qword ptr [rsp + 32]
+	mov	qword ptr [rsp + 24], 0
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], rcx
+	mov	byte ptr [rsp + 16], -1
+	mov	r13, qword ptr [rsp + 8]
+	jmp	.LBB0_83
+.LBB0_4:
+	lea	rax, [rip + .LJTI0_2]
+	movsxd	rcx, dword ptr [rax + 4*rbx]
+	add	rcx, rax
+	jmp	rcx
+.LBB0_5:
+	mov	eax, ebp
+	lea	rcx, [rip + .LJTI0_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB0_6:
+	mov	r12, qword ptr [r15 + 296]
+	jmp	.LBB0_67
+.LBB0_7:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 14
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [r12 + 8]
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::undo
+	jmp	.LBB0_12
+.LBB0_8:
+	mov	eax, ebp
+	lea	rcx, [rip + .LJTI0_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB0_9:
+	mov	rax, qword ptr [r15 + 296]
+	jmp	.LBB0_44
+.LBB0_10:
+	cmp	dword ptr [r12 + 8], 2
+	jne	.LBB0_1
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rcx, qword ptr [r12 + 24]
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_bounds
+.LBB0_12:
+	cmp	dword ptr [r12], 1
+	jne	.LBB0_55
+	mov	rax, qword ptr [rsp + 24]
+	test	rax, rax
+	je	.LBB0_23
+	mov	rcx, qword ptr [rsp + 32]
+	mov	qword ptr [rsp + 24], 0
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], rcx
+	jmp	.LBB0_24
+.LBB0_15:
+	mov	rax, qword ptr [r12 + 8]
+	test	rax, rax
+	je	.LBB0_29
+	cmp	eax, 1
+	jne	.LBB0_31
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [r12 + 16]
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_factor
+	jmp	.LBB0_32
+.LBB0_18:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [r12 + 16]
+	cmp	byte ptr [r12 + 8], 0
+	je	.LBB0_25
+	lea	rdi, [rsp + 104]
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::rem
+	jmp	.LBB0_26
+.LBB0_20:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 16
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [r12 + 1]
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	test	eax, eax
+	je	.LBB0_30
+	cmp	eax, 1
+	jne	.LBB0_38
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
+	jmp	.LBB0_32
+.LBB0_23:
+	mov	qword ptr [rsp + 24], 2
+.LBB0_24:
+	mov	byte ptr [rsp + 16], -1
+	jmp	.LBB0_83
+.LBB0_25:
+	lea	rdi, [rsp + 104]
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::div
+.LBB0_26:
+	cmp	dword ptr [rsp + 104], 1
+	jne	.LBB0_79
+	mov	rbp, qword ptr [rsp + 112]
+	test	rbp, rbp
+	je	.LBB0_36
+	mov	rbx, qword ptr [rsp + 120]
+	jmp	.LBB0_35
+.LBB0_29:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [r12 + 16]
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::mul
+	jmp	.LBB0_32
+.LBB0_30:
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc
+	jmp	.LBB0_32
+.LBB0_31:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rcx, qword ptr [r12 + 24]
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_bounds
+.LBB0_32:
+	cmp	dword ptr [r12], 1
+	jne	.LBB0_79
+.LBB0_33:
+	mov	rbp, qword ptr [rsp + 24]
+	test	rbp, rbp
+	je	.LBB0_36
+.LBB0_34:
+	mov	rbx, qword ptr [rsp + 32]
+.LBB0_35:
+	xor	r12d, r12d
+	test	r13b, r13b
+	je	.LBB0_57
+	jmp	.LBB0_59
+.LBB0_36:
+	mov	r12d, 2
+	xor	ebx, ebx
+	test	r13b, r13b
+	je	.LBB0_56
+	mov	al, -1
+	jmp	.LBB0_81
+.LBB0_38:
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc
+	cmp	dword ptr [r12], 1
+	jne	.LBB0_60
+	mov	rbp, qword ptr [rsp + 24]
+	test	rbp, rbp
+	jne	.LBB0_34
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
+	cmp	byte ptr [r12], 0
+	jne	.LBB0_33
+	jmp	.LBB0_62
+.LBB0_41:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::write_reg
+	jmp	.LBB0_55
+.LBB0_42:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	byte ptr [rsp + 16], 2
+	mov	byte ptr [rsp + 17], cl
+	jmp	.LBB0_83
+.LBB0_43:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+.LBB0_44:
+	mov	byte ptr [rsp + 16], 1
+	mov	qword ptr [rsp + 24], rax
+	jmp	.LBB0_83
+.LBB0_45:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 128], xmm0
+	movaps	xmmword ptr [rsp + 144], xmm0
+	movaps	xmmword ptr [rsp + 160], xmm0
+	movaps	xmmword ptr [rsp + 176], xmm0
+	mov	qword ptr [rsp + 192], 0
+	xor	r12d, r12d
+	xor	ebx, ebx
+.LBB0_46:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	rbx, 7
+	ja	.LBB0_48
+	mov	qword ptr [rsp + 8*rbx + 128], rax
+	mov	rbx, qword ptr [rsp + 192]
+	inc	rbx
+	mov	qword ptr [rsp + 192], rbx
+.LBB0_48:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB0_46
+	lea	rdi, [rsp + 215]
+	lea	rsi, [rsp + 128]
+	mov	rbx, qword ptr [rip + memcpy@GOTPCREL]
+	mov	edx, 72
+	call	rbx
+	lea	rdi, [rsp + 17]
+	mov	byte ptr [rdi - 1], 3
+	lea	rsi, [rsp + 208]
+	mov	edx, 79
+	call	rbx
+	jmp	.LBB0_83
+.LBB0_50:
+	mov	rbp, r13
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 80], 0
+	xor	r13d, r13d
+	xor	ebx, ebx
+.LBB0_51:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	rbx, 7
+	ja	.LBB0_53
+	mov	qword ptr [rsp + 8*rbx + 16], rax
+	mov	rbx, qword ptr [rsp + 80]
+	inc	rbx
+	mov	qword ptr [rsp + 80], rbx
+.LBB0_53:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB0_51
+	add	r12, 8
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+	mov	r13, rbp
+.LBB0_55:
+	mov	byte ptr [rsp + 16], 0
+	jmp	.LBB0_83
+.LBB0_56:
+.LBB0_57:
+	lea	r13, [rsp + 16]
+	mov	rdi, r13
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::rollback
+	cmp	dword ptr [r13], 1
+	jne	.LBB0_59
+	mov	rax, qword ptr [rsp + 24]
+	test	rax, rax
+	jne	.LBB0_3
+.LBB0_59:
+	mov	al, -1
+	jmp	.LBB0_80
+.LBB0_60:
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
+	cmp	byte ptr [r12], 0
+	je	.LBB0_79
+	mov	rbp, qword ptr [rsp + 24]
+	test	rbp, rbp
+	jne	.LBB0_34
+.LBB0_62:
+	mov	r12d, 4
+	test	r13b, r13b
+	je	.LBB0_84
+	mov	al, -1
+	jmp	.LBB0_81
+.LBB0_64:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::write_reg
+	jmp	.LBB0_79
+.LBB0_65:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	al, 2
+	jmp	.LBB0_81
+.LBB0_66:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	mov	r12, rax
+.LBB0_67:
+	mov	al, 1
+	jmp	.LBB0_80
+.LBB0_68:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::set_state
+	jmp	.LBB0_79
+.LBB0_69:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 80], 0
+	xor	r12d, r12d
+	xor	ebx, ebx
+	mov	r13, qword ptr [rsp + 8]
+.LBB0_70:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	rbx, 7
+	ja	.LBB0_72
+	mov	qword ptr [rsp + 8*rbx + 16], rax
+	mov	rbx, qword ptr [rsp + 80]
+	inc	rbx
+	mov	qword ptr [rsp + 80], rbx
+.LBB0_72:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB0_70
+	mov	r12, qword ptr [rsp + 16]
+	mov	rbp, qword ptr [rsp + 24]
+	mov	rbx, qword ptr [rsp + 32]
+	movups	xmm0, xmmword ptr [rsp + 40]
+	movaps	xmmword ptr [rsp + 208], xmm0
+	movups	xmm0, xmmword ptr [rsp + 56]
+	movaps	xmmword ptr [rsp + 224], xmm0
+	movups	xmm0, xmmword ptr [rsp + 72]
+	movaps	xmmword ptr [rsp + 240], xmm0
+	mov	al, 3
+	jmp	.LBB0_82
+.LBB0_74:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 80], 0
+	xor	r13d, r13d
+	xor	ebx, ebx
+.LBB0_75:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	rbx, 7
+	ja	.LBB0_77
+	mov	qword ptr [rsp + 8*rbx + 16], rax
+	mov	rbx, qword ptr [rsp + 80]
+	inc	rbx
+	mov	qword ptr [rsp + 80], rbx
+.LBB0_77:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB0_75
+	add	r12, 8
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB0_79:
+	xor	eax, eax
+.LBB0_80:
+.LBB0_81:
+	mov	r13, qword ptr [rsp + 8]
+.LBB0_82:
+	mov	byte ptr [rsp + 16], al
+	mov	byte ptr [rsp + 17], cl
+	mov	qword ptr [rsp + 24], r12
+	mov	qword ptr [rsp + 32], rbp
+	mov	qword ptr [rsp + 40], rbx
+	movaps	xmm0, xmmword ptr [rsp + 208]
+	movaps	xmm1, xmmword ptr [rsp + 224]
+	movaps	xmm2, xmmword ptr [rsp + 240]
+	movups	xmmword ptr [rsp + 48], xmm0
+	movups	xmmword ptr [rsp + 64], xmm1
+	movups	xmmword ptr [rsp + 80], xmm2
+.LBB0_83:
+	lea	rdx, [rsp + 16]
+	mov	rdi, r14
+	mov	rsi, r13
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB0_84:
+	jmp	.LBB0_57
 .Lfunc_end0:
+.LJTI0_0:
+	.long	.LBB0_8-.LJTI0_0
+	.long	.LBB0_1-.LJTI0_0
+	.long	.LBB0_10-.LJTI0_0
+	.long	.LBB0_1-.LJTI0_0
+	.long	.LBB0_7-.LJTI0_0
+.LJTI0_1:
+	.long	.LBB0_9-.LJTI0_1
+	.long	.LBB0_1-.LJTI0_1
+	.long	.LBB0_42-.LJTI0_1
+	.long	.LBB0_43-.LJTI0_1
+	.long	.LBB0_41-.LJTI0_1
+	.long	.LBB0_45-.LJTI0_1
+	.long	.LBB0_50-.LJTI0_1
+.LJTI0_2:
+	.long	.LBB0_5-.LJTI0_2
+	.long	.LBB0_20-.LJTI0_2
+	.long	.LBB0_15-.LJTI0_2
+	.long	.LBB0_18-.LJTI0_2
+.LJTI0_3:
+	.long	.LBB0_6-.LJTI0_3
+	.long	.LBB0_68-.LJTI0_3
+	.long	.LBB0_65-.LJTI0_3
+	.long	.LBB0_66-.LJTI0_3
+	.long	.LBB0_64-.LJTI0_3
+	.long	.LBB0_69-.LJTI0_3
+	.long	.LBB0_74-.LJTI0_3
 
-<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::parse_command:
+<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin1:
+	push	rbp
 	push	r15
 	push	r14
+	push	r13
+	push	r12
 	push	rbx
-	sub	rsp, 16
-	mov	r14, rdx
+	sub	rsp, 296
+	mov	r12, rcx
+	mov	r13, rdx
 	mov	r15, rsi
-	mov	rbx, rdi
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.1]
-	mov	rcx, rsp
+	movzx	ebp, byte ptr [rcx]
+	lea	rax, [rbp - 6]
+	xor	ebx, ebx
+	cmp	rbp, 7
+	cmovae	rbx, rax
+	mov	r14, rdi
+	lea	rax, [rip + .LJTI1_0]
+	movsxd	rcx, dword ptr [rax + 4*rbx]
+	add	rcx, rax
+	jmp	rcx
+.LBB1_1:
+	mov	qword ptr [rsp + 8], r13
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	lea	rcx, [rsp + 16]
 	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 22
+	mov	qword ptr [rcx + 8], 15
 	#APP
 	#NO_APP
-	cmp	rdx, 1
+	lea	r13, [rsp + 16]
+	mov	rdi, r13
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::checkpoint
+	mov	r13, qword ptr [r13]
+	cmp	r13, 1
 	jne	.LBB1_4
-	movzx	eax, byte ptr [r15]
-	cmp	eax, 45
-	je	.LBB1_18
-	cmp	eax, 43
-	jne	.LBB1_7
-	mov	word ptr [rbx], 1
-	jmp	.LBB1_26
+	mov	rax, qword ptr [rsp + 24]
+	test	rax, rax
+	je	.LBB1_4
+.LBB1_3:
+	mov	rcx, qword ptr [rsp + 32]
+	mov	qword ptr [rsp + 24], 0
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], rcx
+	mov	byte ptr [rsp + 16], -1
+	mov	r13, qword ptr [rsp + 8]
+	jmp	.LBB1_83
 .LBB1_4:
-	cmp	r14, 2
-	jne	.LBB1_7
-	cmp	word ptr [r15], 11563
-	jne	.LBB1_7
-	mov	word ptr [rbx], 513
-	jmp	.LBB1_26
+	lea	rax, [rip + .LJTI1_2]
+	movsxd	rcx, dword ptr [rax + 4*rbx]
+	add	rcx, rax
+	jmp	rcx
+.LBB1_5:
+	mov	eax, ebp
+	lea	rcx, [rip + .LJTI1_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB1_6:
+	mov	r12, qword ptr [r15 + 296]
+	jmp	.LBB1_67
 .LBB1_7:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.2]
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	lea	rcx, [rsp + 16]
 	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 19
+	mov	qword ptr [rcx + 8], 14
 	#APP
 	#NO_APP
-	cmp	r14, 2
-	jb	.LBB1_11
-	cmp	word ptr [r15], 8234
-	jne	.LBB1_11
-	lea	rsi, [r14 - 2]
-	lea	rdi, [r15 + 2]
-	call	optional_trait_methods::commands::parse_isize
-	test	al, 1
-	je	.LBB1_11
-	mov	byte ptr [rbx], 2
-	mov	qword ptr [rbx + 8], 0
-	jmp	.LBB1_24
-.LBB1_11:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.4]
-	mov	rcx, rsp
+	mov	rdx, qword ptr [r12 + 8]
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::undo
+	jmp	.LBB1_12
+.LBB1_8:
+	mov	eax, ebp
+	lea	rcx, [rip + .LJTI1_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB1_9:
+	mov	rax, qword ptr [r15 + 296]
+	jmp	.LBB1_44
+.LBB1_10:
+	cmp	dword ptr [r12 + 8], 2
+	jne	.LBB1_1
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	lea	rcx, [rsp + 16]
 	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 27
+	mov	qword ptr [rcx + 8], 21
 	#APP
 	#NO_APP
-	cmp	r14, 3
-	jb	.LBB1_15
-	movzx	eax, word ptr [r15]
-	xor	eax, 32298
-	movzx	ecx, byte ptr [r15 + 2]
-	xor	ecx, 32
-	or	cx, ax
-	jne	.LBB1_20
-	lea	rsi, [r14 - 3]
-	lea	rdi, [r15 + 3]
-	call	optional_trait_methods::commands::parse_isize
-	test	al, 1
-	je	.LBB1_20
-	mov	byte ptr [rbx], 2
-	jmp	.LBB1_23
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rcx, qword ptr [r12 + 24]
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_bounds
+.LBB1_12:
+	cmp	dword ptr [r12], 1
+	jne	.LBB1_55
+	mov	rax, qword ptr [rsp + 24]
+	test	rax, rax
+	je	.LBB1_23
+	mov	rcx, qword ptr [rsp + 32]
+	mov	qword ptr [rsp + 24], 0
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], rcx
+	jmp	.LBB1_24
 .LBB1_15:
-	cmp	r14, 1
-	jne	.LBB1_19
-	cmp	byte ptr [r15], 112
-	jne	.LBB1_25
-	mov	byte ptr [rbx], 0
-	mov	qword ptr [rbx + 8], 0
-	jmp	.LBB1_26
+	mov	rax, qword ptr [r12 + 8]
+	test	rax, rax
+	je	.LBB1_29
+	cmp	eax, 1
+	jne	.LBB1_31
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [r12 + 16]
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_factor
+	jmp	.LBB1_32
 .LBB1_18:
-	mov	word ptr [rbx], 257
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [r12 + 16]
+	cmp	byte ptr [r12 + 8], 0
+	je	.LBB1_25
+	lea	rdi, [rsp + 104]
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::rem
 	jmp	.LBB1_26
-.LBB1_19:
-	jbe	.LBB1_25
 .LBB1_20:
-	cmp	word ptr [r15], 8307
-	jne	.LBB1_25
-	add	r14, -2
-	add	r15, 2
-	mov	rdi, r15
-	mov	rsi, r14
-	call	optional_trait_methods::commands::parse_isize
-	test	al, 1
-	je	.LBB1_25
-	mov	byte ptr [rbx], 0
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 16
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [r12 + 1]
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	test	eax, eax
+	je	.LBB1_30
+	cmp	eax, 1
+	jne	.LBB1_38
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
+	jmp	.LBB1_32
 .LBB1_23:
-	mov	qword ptr [rbx + 8], 1
+	mov	qword ptr [rsp + 24], 2
 .LBB1_24:
-	mov	qword ptr [rbx + 16], rdx
-	jmp	.LBB1_26
+	mov	byte ptr [rsp + 16], -1
+	jmp	.LBB1_83
 .LBB1_25:
-	mov	byte ptr [rbx], -1
+	lea	rdi, [rsp + 104]
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::div
 .LBB1_26:
-	mov	rax, rbx
-	add	rsp, 16
+	cmp	dword ptr [rsp + 104], 1
+	jne	.LBB1_79
+	mov	rbp, qword ptr [rsp + 112]
+	test	rbp, rbp
+	je	.LBB1_36
+	mov	rbx, qword ptr [rsp + 120]
+	jmp	.LBB1_35
+.LBB1_29:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [r12 + 16]
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::mul
+	jmp	.LBB1_32
+.LBB1_30:
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc
+	jmp	.LBB1_32
+.LBB1_31:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	lea	rcx, [rsp + 16]
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rcx, qword ptr [r12 + 24]
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_bounds
+.LBB1_32:
+	cmp	dword ptr [r12], 1
+	jne	.LBB1_79
+.LBB1_33:
+	mov	rbp, qword ptr [rsp + 24]
+	test	rbp, rbp
+	je	.LBB1_36
+.LBB1_34:
+	mov	rbx, qword ptr [rsp + 32]
+.LBB1_35:
+	xor	r12d, r12d
+	test	r13b, r13b
+	je	.LBB1_57
+	jmp	.LBB1_59
+.LBB1_36:
+	mov	r12d, 2
+	xor	ebx, ebx
+	test	r13b, r13b
+	je	.LBB1_56
+	mov	al, -1
+	jmp	.LBB1_81
+.LBB1_38:
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc
+	cmp	dword ptr [r12], 1
+	jne	.LBB1_60
+	mov	rbp, qword ptr [rsp + 24]
+	test	rbp, rbp
+	jne	.LBB1_34
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
+	cmp	byte ptr [r12], 0
+	jne	.LBB1_33
+	jmp	.LBB1_62
+.LBB1_41:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::write_reg
+	jmp	.LBB1_55
+.LBB1_42:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	byte ptr [rsp + 16], 2
+	mov	byte ptr [rsp + 17], cl
+	jmp	.LBB1_83
+.LBB1_43:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+.LBB1_44:
+	mov	byte ptr [rsp + 16], 1
+	mov	qword ptr [rsp + 24], rax
+	jmp	.LBB1_83
+.LBB1_45:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 128], xmm0
+	movaps	xmmword ptr [rsp + 144], xmm0
+	movaps	xmmword ptr [rsp + 160], xmm0
+	movaps	xmmword ptr [rsp + 176], xmm0
+	mov	qword ptr [rsp + 192], 0
+	xor	r12d, r12d
+	xor	ebx, ebx
+.LBB1_46:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	rbx, 7
+	ja	.LBB1_48
+	mov	qword ptr [rsp + 8*rbx + 128], rax
+	mov	rbx, qword ptr [rsp + 192]
+	inc	rbx
+	mov	qword ptr [rsp + 192], rbx
+.LBB1_48:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB1_46
+	lea	rdi, [rsp + 215]
+	lea	rsi, [rsp + 128]
+	mov	rbx, qword ptr [rip + memcpy@GOTPCREL]
+	mov	edx, 72
+	call	rbx
+	lea	rdi, [rsp + 17]
+	mov	byte ptr [rdi - 1], 3
+	lea	rsi, [rsp + 208]
+	mov	edx, 79
+	call	rbx
+	jmp	.LBB1_83
+.LBB1_50:
+	mov	rbp, r13
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 80], 0
+	xor	r13d, r13d
+	xor	ebx, ebx
+.LBB1_51:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	rbx, 7
+	ja	.LBB1_53
+	mov	qword ptr [rsp + 8*rbx + 16], rax
+	mov	rbx, qword ptr [rsp + 80]
+	inc	rbx
+	mov	qword ptr [rsp + 80], rbx
+.LBB1_53:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB1_51
+	add	r12, 8
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+	mov	r13, rbp
+.LBB1_55:
+	mov	byte ptr [rsp + 16], 0
+	jmp	.LBB1_83
+.LBB1_56:
+.LBB1_57:
+	lea	r13, [rsp + 16]
+	mov	rdi, r13
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::rollback
+	cmp	dword ptr [r13], 1
+	jne	.LBB1_59
+	mov	rax, qword ptr [rsp + 24]
+	test	rax, rax
+	jne	.LBB1_3
+.LBB1_59:
+	mov	al, -1
+	jmp	.LBB1_80
+.LBB1_60:
+	lea	r12, [rsp + 16]
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
+	cmp	byte ptr [r12], 0
+	je	.LBB1_79
+	mov	rbp, qword ptr [rsp + 24]
+	test	rbp, rbp
+	jne	.LBB1_34
+.LBB1_62:
+	mov	r12d, 4
+	test	r13b, r13b
+	je	.LBB1_84
+	mov	al, -1
+	jmp	.LBB1_81
+.LBB1_64:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::write_reg
+	jmp	.LBB1_79
+.LBB1_65:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	al, 2
+	jmp	.LBB1_81
+.LBB1_66:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	mov	r12, rax
+.LBB1_67:
+	mov	al, 1
+	jmp	.LBB1_80
+.LBB1_68:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::set_state
+	jmp	.LBB1_79
+.LBB1_69:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 80], 0
+	xor	r12d, r12d
+	xor	ebx, ebx
+	mov	r13, qword ptr [rsp + 8]
+.LBB1_70:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	rbx, 7
+	ja	.LBB1_72
+	mov	qword ptr [rsp + 8*rbx + 16], rax
+	mov	rbx, qword ptr [rsp + 80]
+	inc	rbx
+	mov	qword ptr [rsp + 80], rbx
+.LBB1_72:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB1_70
+	mov	r12, qword ptr [rsp + 16]
+	mov	rbp, qword ptr [rsp + 24]
+	mov	rbx, qword ptr [rsp + 32]
+	movups	xmm0, xmmword ptr [rsp + 40]
+	movaps	xmmword ptr [rsp + 208], xmm0
+	movups	xmm0, xmmword ptr [rsp + 56]
+	movaps	xmmword ptr [rsp + 224], xmm0
+	movups	xmm0, xmmword ptr [rsp + 72]
+	movaps	xmmword ptr [rsp + 240], xmm0
+	mov	al, 3
+	jmp	.LBB1_82
+.LBB1_74:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 80], 0
+	xor	r13d, r13d
+	xor	ebx, ebx
+.LBB1_75:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	rbx, 7
+	ja	.LBB1_77
+	mov	qword ptr [rsp + 8*rbx + 16], rax
+	mov	rbx, qword ptr [rsp + 80]
+	inc	rbx
+	mov	qword ptr [rsp + 80], rbx
+.LBB1_77:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB1_75
+	add	r12, 8
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB1_79:
+	xor	eax, eax
+.LBB1_80:
+.LBB1_81:
+	mov	r13, qword ptr [rsp + 8]
+.LBB1_82:
+	mov	byte ptr [rsp + 16], al
+	mov	byte ptr [rsp + 17], cl
+	mov	qword ptr [rsp + 24], r12
+	mov	qword ptr [rsp + 32], rbp
+	mov	qword ptr [rsp + 40], rbx
+	movaps	xmm0, xmmword ptr [rsp + 208]
+	movaps	xmm1, xmmword ptr [rsp + 224]
+	movaps	xmm2, xmmword ptr [rsp + 240]
+	movups	xmmword ptr [rsp + 48], xmm0
+	movups	xmmword ptr [rsp + 64], xmm1
+	movups	xmmword ptr [rsp + 80], xmm2
+.LBB1_83:
+	lea	rdx, [rsp + 16]
+	mov	rdi, r14
+	mov	rsi, r13
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	add	rsp, 296
 	pop	rbx
+	pop	r12
+	pop	r13
 	pop	r14
 	pop	r15
+	pop	rbp
 	ret
+.LBB1_84:
+	jmp	.LBB1_57
 .Lfunc_end1:
+.LJTI1_0:
+	.long	.LBB1_8-.LJTI1_0
+	.long	.LBB1_1-.LJTI1_0
+	.long	.LBB1_10-.LJTI1_0
+	.long	.LBB1_1-.LJTI1_0
+	.long	.LBB1_7-.LJTI1_0
+.LJTI1_1:
+	.long	.LBB1_9-.LJTI1_1
+	.long	.LBB1_1-.LJTI1_1
+	.long	.LBB1_42-.LJTI1_1
+	.long	.LBB1_43-.LJTI1_1
+	.long	.LBB1_41-.LJTI1_1
+	.long	.LBB1_45-.LJTI1_1
+	.long	.LBB1_50-.LJTI1_1
+.LJTI1_2:
+	.long	.LBB1_5-.LJTI1_2
+	.long	.LBB1_20-.LJTI1_2
+	.long	.LBB1_15-.LJTI1_2
+	.long	.LBB1_18-.LJTI1_2
+.LJTI1_3:
+	.long	.LBB1_6-.LJTI1_3
+	.long	.LBB1_68-.LJTI1_3
+	.long	.LBB1_65-.LJTI1_3
+	.long	.LBB1_66-.LJTI1_3
+	.long	.LBB1_64-.LJTI1_3
+	.long	.LBB1_69-.LJTI1_3
+	.long	.LBB1_74-.LJTI1_3
 
-<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::unsupported_cmd:
+<optional_trait_methods::commands::Args>::num::<isize>:
+.Lfunc_begin2:
+	push	r15
 	push	r14
 	push	rbx
-	push	rax
+	sub	rsp, 32
 	mov	rbx, rdi
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.7]
-	mov	r14, qword ptr [rip + write@GOTPCREL]
-	mov	edx, 16
-	mov	edi, 1
-	call	r14
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	mov	edx, 1
-	mov	edi, 1
-	call	r14
-	mov	qword ptr [rbx], 0
-	mov	rax, rbx
-	add	rsp, 8
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<isize>
+	and	al, 1
+	cmove	rdx, r15
+	neg	al
+	or	al, 2
+	mov	qword ptr [rbx], rdx
+	mov	byte ptr [rbx + 8], al
+	add	rsp, 32
 	pop	rbx
 	pop	r14
+	pop	r15
 	ret
 .Lfunc_end2:
 
-<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::handle:
-	push	r15
+<optional_trait_methods::commands::Args>::regs::<isize>:
+.Lfunc_begin3:
 	push	r14
 	push	rbx
-	sub	rsp, 32
-	mov	r14, rsi
+	sub	rsp, 88
 	mov	rbx, rdi
-	movzx	eax, byte ptr [rdx]
-	test	eax, eax
-	je	.LBB3_5
-	cmp	eax, 1
-	jne	.LBB3_7
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.10]
-	lea	rcx, [rsp + 8]
-	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 16
-	#APP
-	#NO_APP
-	movzx	eax, byte ptr [rdx + 1]
-	lea	r15, [rsp + 8]
-	mov	rdi, r15
-	test	eax, eax
-	je	.LBB3_11
-	cmp	eax, 1
-	jne	.LBB3_15
-	mov	rsi, r14
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
-	jmp	.LBB3_12
-.LBB3_5:
-	cmp	byte ptr [rdx + 8], 0
-	je	.LBB3_9
-	mov	rsi, qword ptr [rdx + 16]
+	lea	r14, [rsp + 16]
 	mov	rdi, r14
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::set_state
-	jmp	.LBB3_26
-.LBB3_7:
-	cmp	byte ptr [rdx + 8], 0
-	je	.LBB3_10
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.12]
-	lea	rcx, [rsp + 8]
-	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 21
-	#APP
-	#NO_APP
-	mov	rdx, qword ptr [rdx + 16]
-	lea	r15, [rsp + 8]
-	mov	rdi, r15
-	mov	rsi, r14
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_factor
+	call	<optional_trait_methods::commands::Args>::next
+	mov	rax, qword ptr [r14]
+	cmp	qword ptr [r14 + 16], 128
+	jne	.LBB3_20
+	mov	rcx, qword ptr [rsp + 24]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 80], 0
+	mov	edx, 128
+	xor	esi, esi
+.LBB3_2:
+	mov	rdi, rcx
+	add	rcx, 16
+	add	rdx, -16
+	movaps	xmmword ptr [rsp], xmm0
+	xor	r8d, r8d
+.LBB3_3:
+	mov	r10b, byte ptr [rdi + 2*r8]
+	lea	r9d, [r10 - 48]
+	cmp	r9b, 10
+	jb	.LBB3_13
+	lea	r9d, [r10 - 97]
+	cmp	r9b, 5
+	ja	.LBB3_10
+	add	r10b, -87
 	jmp	.LBB3_12
-.LBB3_9:
-	mov	rdi, r14
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::get_state
-	lea	rdi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.9]
-	xor	r14d, r14d
-	mov	rsi, rax
-	xor	eax, eax
-	call	qword ptr [rip + printf@GOTPCREL]
-	jmp	.LBB3_27
 .LBB3_10:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.11]
-	lea	rcx, [rsp + 8]
-	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 13
-	#APP
-	#NO_APP
-	mov	rdx, qword ptr [rdx + 16]
-	lea	r15, [rsp + 8]
+	lea	r9d, [r10 - 71]
+	cmp	r9b, -6
+	jb	.LBB3_20
+	add	r10b, -55
+.LBB3_12:
+	mov	r9d, r10d
+.LBB3_13:
+	mov	r10b, byte ptr [rdi + 2*r8 + 1]
+	lea	r11d, [r10 - 48]
+	cmp	r11b, 10
+	jb	.LBB3_19
+	lea	r11d, [r10 - 97]
+	cmp	r11b, 5
+	ja	.LBB3_16
+	add	r10b, -87
+	jmp	.LBB3_18
+.LBB3_16:
+	lea	r11d, [r10 - 71]
+	cmp	r11b, -6
+	jb	.LBB3_20
+	add	r10b, -55
+.LBB3_18:
+	mov	r11d, r10d
+.LBB3_19:
+	shl	r9b, 4
+	or	r9b, r11b
+	mov	byte ptr [rsp + r8], r9b
+	inc	r8
+	cmp	r8, 8
+	jne	.LBB3_3
+	cmp	rsi, 7
+	ja	.LBB3_8
+	mov	rdi, qword ptr [rsp]
+	mov	qword ptr [rsp + 8*rsi + 16], rdi
+	mov	rsi, qword ptr [rsp + 80]
+	inc	rsi
+	mov	qword ptr [rsp + 80], rsi
+.LBB3_8:
+	test	rdx, rdx
+	jne	.LBB3_2
+	lea	rdi, [rbx + 8]
+	lea	rsi, [rsp + 16]
+	mov	ecx, 9
+	rep movsq es:[rdi], [rsi]
+	xor	eax, eax
+	jmp	.LBB3_21
+.LBB3_20:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB3_21:
+	mov	qword ptr [rbx], rax
+	add	rsp, 88
+	pop	rbx
+	pop	r14
+	ret
+.Lfunc_end3:
+
+<optional_trait_methods::commands::Args>::num_or::<usize>:
+.Lfunc_begin4:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	cmp	qword ptr [rsi + 8], 0
+	je	.LBB4_1
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	and	al, 1
+	cmove	rdx, r15
+	neg	al
+	or	al, 2
+	jmp	.LBB4_3
+.LBB4_1:
+	mov	al, -1
+	mov	edx, 1
+.LBB4_3:
+	mov	qword ptr [rbx], rdx
+	mov	byte ptr [rbx + 8], al
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end4:
+
+<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::apply::{closure#0}>:
+.Lfunc_begin5:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rax, qword ptr [rdi + 64]
+	xor	r15d, r15d
+	cmp	rax, 9
+	mov	r12d, 8
+	cmovb	r12, rdi
+	lea	rax, [8*rax]
+	cmovb	r15, rax
+	test	r15, r15
+	je	.LBB5_3
+	mov	rbx, rsi
+	xor	r14d, r14d
+.LBB5_2:
+	mov	rdx, qword ptr [r12 + 8*r14]
+	mov	rdi, rbx
+	mov	rsi, r14
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::write_reg
+	inc	r14
+	add	r15, -8
+	jne	.LBB5_2
+.LBB5_3:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end5:
+
+optional_trait_methods::num::format_decimal::<isize>:
+.Lfunc_begin6:
+	push	rax
+	mov	eax, 39
+	movabs	r9, 7378697629483820647
+	mov	rcx, rdi
+.LBB6_1:
+	cmp	rax, 39
+	ja	.LBB6_7
+	mov	r8, rax
+	mov	rax, rcx
+	imul	r9
+	mov	rax, rdx
+	shr	rax, 63
+	sar	rdx, 2
+	add	rdx, rax
+	lea	rax, [rdx + rdx]
+	lea	rax, [rax + 4*rax]
+	mov	r10, rcx
+	sub	r10, rax
+	mov	rax, r10
+	neg	rax
+	cmovs	rax, r10
+	add	al, 48
+	mov	byte ptr [rsi + r8], al
+	add	rcx, 9
+	lea	rax, [r8 - 1]
+	cmp	rcx, 19
+	mov	rcx, rdx
+	jae	.LBB6_1
+	test	rdi, rdi
+	jns	.LBB6_6
+	inc	r8
+	add	r8, -2
+	cmp	r8, 39
+	ja	.LBB6_7
+	mov	byte ptr [rsi + r8], 45
+.LBB6_6:
+	mov	edx, 40
+	sub	rdx, r8
+	add	rsi, r8
+	mov	rax, rsi
+	pop	rcx
+	ret
+.LBB6_7:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end6:
+
+optional_trait_methods::num::format_decimal::<usize>:
+.Lfunc_begin7:
+	mov	eax, 39
+	movabs	r8, -3689348814741910323
+.LBB7_1:
+	cmp	rax, 39
+	ja	.LBB7_4
+	mov	rcx, rax
+	mov	rax, rdi
+	mul	r8
+	shr	rdx, 3
+	lea	eax, [rdx + rdx]
+	lea	eax, [rax + 4*rax]
+	mov	r9d, edi
+	sub	r9d, eax
+	or	r9b, 48
+	mov	byte ptr [rsi + rcx], r9b
+	lea	rax, [rcx - 1]
+	cmp	rdi, 10
+	mov	rdi, rdx
+	jae	.LBB7_1
+	add	rsi, rcx
+	inc	rcx
+	mov	edx, 41
+	sub	rdx, rcx
+	mov	rax, rsi
+	ret
+.LBB7_4:
+	push	rax
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end7:
+
+optional_trait_methods::num::parse_num::<u16>:
+.Lfunc_begin8:
+	test	rsi, rsi
+	je	.LBB8_54
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB8_4
+	cmp	ecx, 45
+	jne	.LBB8_6
+	mov	cl, 1
+	jmp	.LBB8_5
+.LBB8_4:
+	xor	ecx, ecx
+.LBB8_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB8_7
+.LBB8_6:
+	xor	ecx, ecx
+.LBB8_7:
+	cmp	rsi, 1
+	jbe	.LBB8_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB8_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB8_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB8_29
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB8_28
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB8_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB8_31
+.LBB8_14:
+	test	rsi, rsi
+	je	.LBB8_54
+	mov	al, byte ptr [rdi]
+.LBB8_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB8_54
+.LBB8_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB8_19
+	cmp	byte ptr [rax], 95
+	je	.LBB8_54
+.LBB8_19:
+	xor	r9d, r9d
+	mov	r8w, 10
+	xor	edx, edx
+.LBB8_20:
+	cmp	rdi, rsi
+	je	.LBB8_49
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB8_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB8_20
+	jmp	.LBB8_55
+.LBB8_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB8_54
+	mov	eax, edx
+	mul	r8w
+	jo	.LBB8_54
+	mov	edx, eax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB8_27
+	sub	dx, ax
+	jae	.LBB8_20
+	jmp	.LBB8_54
+.LBB8_27:
+	add	dx, ax
+	jae	.LBB8_20
+	jmp	.LBB8_54
+.LBB8_28:
+	mov	r8b, 8
+	jmp	.LBB8_30
+.LBB8_29:
+	mov	r8b, 2
+.LBB8_30:
+	xor	r9d, r9d
+.LBB8_31:
+	cmp	rsi, 2
+	je	.LBB8_54
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB8_51
+.LBB8_33:
+	cmp	dl, r8b
+	jae	.LBB8_54
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB8_36
+	cmp	byte ptr [rax], 95
+	je	.LBB8_54
+.LBB8_36:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB8_37:
+	cmp	r10, rdi
+	je	.LBB8_49
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB8_40
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	jne	.LBB8_37
+	jmp	.LBB8_55
+.LBB8_40:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB8_44
+	test	r9b, r9b
+	je	.LBB8_54
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB8_54
+	add	al, -87
+	mov	r11d, eax
+.LBB8_44:
+	cmp	r11b, r8b
+	jae	.LBB8_54
+	mov	eax, edx
+	mul	si
+	jo	.LBB8_54
+	mov	edx, eax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB8_48
+	sub	dx, ax
+	jae	.LBB8_37
+	jmp	.LBB8_54
+.LBB8_48:
+	add	dx, ax
+	jae	.LBB8_37
+	jmp	.LBB8_54
+.LBB8_49:
+	mov	ax, 1
+	ret
+.LBB8_51:
+	test	r9b, r9b
+	je	.LBB8_54
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB8_54
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB8_33
+.LBB8_54:
+	xor	eax, eax
+.LBB8_55:
+	ret
+.Lfunc_end8:
+
+optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin9:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB9_2
+	lea	rcx, [rip + .LJTI9_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB9_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB9_5
+.LBB9_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB9_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB9_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB9_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB9_18
+.LBB9_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB9_15
+	jmp	.LBB9_28
+.LBB9_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB9_14
+	jmp	.LBB9_28
+.LBB9_19:
+	lea	r14, [rbx + 8]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 72]
+	cmp	rcx, 9
+	mov	eax, 8
+	cmovb	rax, r14
+	lea	rcx, [8*rcx]
+	cmovae	rcx, r12
+	test	rcx, rcx
+	je	.LBB9_26
+	add	rcx, rax
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB9_21:
+	mov	qword ptr [rsp + 24], 0
+	mov	rsi, qword ptr [rax]
+	add	rax, 8
+	mov	qword ptr [rsp + 16], rsi
+	xor	esi, esi
+.LBB9_22:
+	cmp	r12, 256
+	jae	.LBB9_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB9_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 8
+	jne	.LBB9_22
+	cmp	rax, rcx
+	jne	.LBB9_21
+.LBB9_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB9_27
+.LBB9_11:
+	mov	rdi, qword ptr [rbx + 8]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<isize>
+.LBB9_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB9_27
+.LBB9_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI9_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB9_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB9_4
+.LBB9_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB9_4
+.LBB9_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB9_27
+.LBB9_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB9_12
+.LBB9_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB9_4:
+	mov	edx, 4
+.LBB9_5:
+	mov	rdi, r15
+.LBB9_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB9_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	ecx, 10
+	mov	rdi, rbp
+	mov	rsi, rbx
+	rep movsq es:[rdi], [rsi]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB9_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB9_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end9:
+.LJTI9_0:
+	.long	.LBB9_10-.LJTI9_0
+	.long	.LBB9_11-.LJTI9_0
+	.long	.LBB9_13-.LJTI9_0
+	.long	.LBB9_19-.LJTI9_0
+.LJTI9_1:
+	.long	.LBB9_3-.LJTI9_1
+	.long	.LBB9_6-.LJTI9_1
+	.long	.LBB9_7-.LJTI9_1
+	.long	.LBB9_8-.LJTI9_1
+	.long	.LBB9_9-.LJTI9_1
+
+optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin10:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB10_2
+	lea	rcx, [rip + .LJTI10_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB10_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB10_5
+.LBB10_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB10_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB10_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB10_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB10_18
+.LBB10_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB10_15
+	jmp	.LBB10_28
+.LBB10_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB10_14
+	jmp	.LBB10_28
+.LBB10_19:
+	lea	r14, [rbx + 8]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 72]
+	cmp	rcx, 9
+	mov	eax, 8
+	cmovb	rax, r14
+	lea	rcx, [8*rcx]
+	cmovae	rcx, r12
+	test	rcx, rcx
+	je	.LBB10_26
+	add	rcx, rax
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB10_21:
+	mov	qword ptr [rsp + 24], 0
+	mov	rsi, qword ptr [rax]
+	add	rax, 8
+	mov	qword ptr [rsp + 16], rsi
+	xor	esi, esi
+.LBB10_22:
+	cmp	r12, 256
+	jae	.LBB10_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB10_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 8
+	jne	.LBB10_22
+	cmp	rax, rcx
+	jne	.LBB10_21
+.LBB10_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB10_27
+.LBB10_11:
+	mov	rdi, qword ptr [rbx + 8]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<isize>
+.LBB10_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB10_27
+.LBB10_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI10_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB10_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB10_4
+.LBB10_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB10_4
+.LBB10_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB10_27
+.LBB10_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB10_12
+.LBB10_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB10_4:
+	mov	edx, 4
+.LBB10_5:
+	mov	rdi, r15
+.LBB10_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB10_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	ecx, 10
+	mov	rdi, rbp
+	mov	rsi, rbx
+	rep movsq es:[rdi], [rsi]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB10_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB10_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end10:
+.LJTI10_0:
+	.long	.LBB10_10-.LJTI10_0
+	.long	.LBB10_11-.LJTI10_0
+	.long	.LBB10_13-.LJTI10_0
+	.long	.LBB10_19-.LJTI10_0
+.LJTI10_1:
+	.long	.LBB10_3-.LJTI10_1
+	.long	.LBB10_6-.LJTI10_1
+	.long	.LBB10_7-.LJTI10_1
+	.long	.LBB10_8-.LJTI10_1
+	.long	.LBB10_9-.LJTI10_1
+
+optional_trait_methods::report_port:
+.Lfunc_begin11:
+	push	rbx
+	sub	rsp, 64
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 16]
+	movaps	xmmword ptr [rsi], xmm0
+	lea	rdx, [rsp + 12]
+	mov	dword ptr [rdx], 16
+	call	qword ptr [rip + getsockname@GOTPCREL]
+	test	eax, eax
+	js	.LBB11_1
+	xor	eax, eax
+	test	al, al
+	jne	.LBB11_7
+	movzx	ebx, word ptr [rsp + 18]
+	rol	bx, 8
+	shl	ebx, 16
+	shr	ebx, 16
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 48], 0
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.30]
+	mov	edx, 23
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	ecx, 39
+.LBB11_4:
+	cmp	rcx, 39
+	ja	.LBB11_8
+	mov	rax, rcx
+	movzx	edx, bx
+	imul	esi, edx, 52429
+	shr	esi, 19
+	lea	ecx, [rsi + rsi]
+	lea	ecx, [rcx + 4*rcx]
+	sub	ebx, ecx
+	or	bl, 48
+	mov	byte ptr [rsp + rax + 16], bl
+	lea	rcx, [rax - 1]
+	mov	ebx, esi
+	cmp	dx, 10
+	jae	.LBB11_4
+	lea	rsi, [rsp + rax]
+	add	rsi, 16
+	inc	rax
+	mov	edx, 41
+	sub	rdx, rax
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB11_7
+.LBB11_1:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB11_7:
+	add	rsp, 64
+	pop	rbx
+	ret
+.LBB11_8:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end11:
+
+optional_trait_methods::usage_error:
+.Lfunc_begin12:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.31]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	rax, qword ptr [rbx]
+	lea	rcx, [rip + .LJTI12_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB12_1:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.7]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 16
+	jmp	.LBB12_2
+.LBB12_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.12]
+	mov	edx, 39
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	jmp	.LBB12_7
+.LBB12_4:
+	mov	r14, qword ptr [rbx + 8]
+	mov	r15, qword ptr [rbx + 16]
+	mov	r12, qword ptr [rbx + 24]
+	mov	rbx, qword ptr [rbx + 32]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.9]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, r15
+	call	r13
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.10]
+	mov	edx, 2
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r12
+	mov	rdx, rbx
+	call	r13
+	jmp	.LBB12_7
+.LBB12_5:
+	mov	rsi, qword ptr [rbx + 8]
+	mov	rdx, qword ptr [rbx + 16]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.11]
+	mov	edx, 21
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB12_7
+.LBB12_3:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+.LBB12_2:
+	mov	edi, 2
+	call	r15
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, rbx
+	call	r15
+.LBB12_7:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.32]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 2
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	mov	edx, 823
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	mov	rax, rbx
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	jmp	rax
+.Lfunc_end12:
+.LJTI12_0:
+	.long	.LBB12_1-.LJTI12_0
+	.long	.LBB12_3-.LJTI12_0
+	.long	.LBB12_4-.LJTI12_0
+	.long	.LBB12_5-.LJTI12_0
+	.long	.LBB12_6-.LJTI12_0
+
+__rustc::rust_begin_unwind:
+.Lfunc_begin13:
+	push	rax
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	call	qword ptr [rip + abort@GOTPCREL]
+.Lfunc_end13:
+
+<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::parse_command:
+.Lfunc_begin14:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	sub	rsp, 176
+	mov	rbx, rdi
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.35]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 22
+	#APP
+	#NO_APP
+	lea	rdi, [rsi + 1]
+	movzx	eax, byte ptr [rsi]
+	cmp	eax, 45
+	je	.LBB14_4
+	cmp	eax, 43
+	jne	.LBB14_9
+	cmp	rdx, 1
+	jne	.LBB14_6
+	mov	byte ptr [rbx + 1], 0
+	jmp	.LBB14_20
+.LBB14_4:
+	cmp	rdx, 1
+	jne	.LBB14_6
+	mov	byte ptr [rbx + 1], 1
+	jmp	.LBB14_20
+.LBB14_6:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_10
+.LBB14_7:
+	mov	qword ptr [rbx + 8], 1
+.LBB14_8:
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+	jmp	.LBB14_21
+.LBB14_9:
+	cmp	rdx, 2
+	jb	.LBB14_11
+.LBB14_10:
+	cmp	word ptr [rsi], 11563
+	je	.LBB14_18
+.LBB14_11:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.39]
+	mov	r8, rsp
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	eax, 42
+	jne	.LBB14_28
+	mov	r15, rdx
+	dec	r15
+	jne	.LBB14_23
+	setne	al
+	movzx	ecx, al
+	sub	r15, rcx
+	add	rdi, rcx
+.LBB14_14:
+	mov	r14, r15
+.LBB14_15:
+	movzx	r12d, al
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB14_39
+	cmp	r15, r14
+	jne	.LBB14_63
+	mov	byte ptr [rbx], 8
+	mov	qword ptr [rbx + 8], 0
+	mov	qword ptr [rbx + 16], rdx
+	jmp	.LBB14_22
+.LBB14_18:
+	cmp	rdx, 2
+	jne	.LBB14_50
+	mov	byte ptr [rbx + 1], 2
+.LBB14_20:
+	mov	al, 7
+.LBB14_21:
+	mov	byte ptr [rbx], al
+	jmp	.LBB14_22
+.LBB14_23:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_28
+	xor	eax, eax
+	test	r15, r15
+	setne	al
+	sub	r15, rax
+	add	rdi, rax
+	cmp	rdx, 3
+	jb	.LBB14_14
+	xor	r14d, r14d
+.LBB14_26:
+	cmp	byte ptr [rdi + r14], 32
+	je	.LBB14_15
+	inc	r14
+	cmp	r15, r14
+	jne	.LBB14_26
+	jmp	.LBB14_14
+.LBB14_28:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.41]
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 2
+	jb	.LBB14_30
+	cmp	word ptr [rsi], 32298
+	je	.LBB14_52
+.LBB14_30:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.43]
+	mov	r8, rsp
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 3
+	jb	.LBB14_32
+	movzx	ecx, word ptr [rsi]
+	xor	ecx, 32298
+	movzx	r8d, byte ptr [rsi + 2]
+	xor	r8d, 126
+	or	r8w, cx
+	je	.LBB14_59
+.LBB14_32:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.45]
+	mov	r8, rsp
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	eax, 37
+	je	.LBB14_40
+	cmp	eax, 47
+	jne	.LBB14_45
+	mov	rax, rdx
+	dec	rax
+	je	.LBB14_36
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_64
+.LBB14_36:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 9
+	mov	qword ptr [rbx + 8], 0
+	jmp	.LBB14_58
+.LBB14_39:
+	inc	r12
+	mov	qword ptr [rbx + 8], r12
+	mov	byte ptr [rbx + 16], 2
+	jmp	.LBB14_67
+.LBB14_40:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB14_42
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_64
+.LBB14_42:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 9
+	jmp	.LBB14_57
+.LBB14_45:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 20
+	#APP
+	#NO_APP
+	cmp	eax, 111
+	jle	.LBB14_68
+	lea	ecx, [rax - 112]
+	cmp	ecx, 7
+	ja	.LBB14_65
+	lea	r8, [rip + .LJTI14_0]
+	movsxd	rcx, dword ptr [r8 + 4*rcx]
+	add	rcx, r8
+	jmp	rcx
+.LBB14_48:
+	cmp	rdx, 1
+	jne	.LBB14_101
+	xor	eax, eax
+	jmp	.LBB14_103
+.LBB14_50:
+	cmp	byte ptr [rsi + 2], 32
+	jne	.LBB14_11
+	mov	qword ptr [rbx + 8], 2
+	jmp	.LBB14_8
+.LBB14_52:
+	lea	rcx, [rsi + 2]
+	mov	r8, rdx
+	sub	r8, 2
+	je	.LBB14_54
+	cmp	byte ptr [rcx], 32
+	jne	.LBB14_30
+.LBB14_54:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rcx
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 2
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 8
+.LBB14_57:
+	mov	qword ptr [rbx + 8], 1
+.LBB14_58:
+	mov	qword ptr [rbx + 16], rax
+	jmp	.LBB14_22
+.LBB14_59:
+	lea	rcx, [rsi + 3]
+	mov	r8, rdx
+	sub	r8, 3
+	je	.LBB14_61
+	cmp	byte ptr [rcx], 32
+	jne	.LBB14_32
+.LBB14_61:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rcx
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 3
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	je	.LBB14_73
+.LBB14_62:
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB14_66
+.LBB14_63:
+	lea	rax, [r14 + r12]
+	inc	rax
+	jmp	.LBB14_125
+.LBB14_64:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 20
+	#APP
+	#NO_APP
+.LBB14_65:
+	mov	rdi, rsi
+	mov	rsi, rdx
+	call	optional_trait_methods::commands::unrecognized
+	mov	qword ptr [rbx + 8], 0
+.LBB14_66:
+	mov	byte ptr [rbx + 16], al
+	jmp	.LBB14_67
+.LBB14_68:
+	cmp	eax, 63
+	je	.LBB14_97
+	cmp	eax, 71
+	je	.LBB14_100
+	cmp	eax, 103
+	jne	.LBB14_65
+	cmp	rdx, 1
+	jne	.LBB14_104
+	mov	al, 5
+	jmp	.LBB14_21
+.LBB14_73:
+	lea	r14, [rsp + 80]
+	mov	rsi, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 8
+	mov	qword ptr [rbx + 8], 2
+	mov	qword ptr [rbx + 16], r15
+	mov	qword ptr [rbx + 24], rax
+	jmp	.LBB14_22
+.LBB14_76:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB14_78
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_65
+.LBB14_78:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num_or::<usize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 10
+	jmp	.LBB14_81
+.LBB14_82:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB14_84
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_65
+.LBB14_84:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 1
+	jmp	.LBB14_81
+.LBB14_87:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_89
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_107
+.LBB14_89:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	byte ptr [rbx], 3
+.LBB14_81:
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB14_22
+.LBB14_92:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_94
+.LBB14_93:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_105
+.LBB14_94:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	jne	.LBB14_62
+	lea	r14, [rsp + 80]
+	mov	rsi, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	je	.LBB14_123
+.LBB14_96:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], cl
+	jmp	.LBB14_67
+.LBB14_97:
+	mov	rax, rdx
+	dec	rax
+	jne	.LBB14_116
+	mov	qword ptr [rsp], rdi
+	mov	qword ptr [rsp + 8], rax
+	mov	qword ptr [rsp + 16], 1
+	xor	ebp, ebp
+.LBB14_99:
+	mov	byte ptr [rbx], 2
+	mov	byte ptr [rbx + 1], bpl
+	mov	byte ptr [rbx + 2], al
+	jmp	.LBB14_22
+.LBB14_100:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB14_112
+	jmp	.LBB14_111
+.LBB14_101:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_65
+	mov	qword ptr [rbx + 8], 1
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+.LBB14_103:
+	mov	byte ptr [rbx], al
+	jmp	.LBB14_22
+.LBB14_104:
+	cmp	byte ptr [rdi], 32
+	je	.LBB14_7
+.LBB14_105:
+	cmp	eax, 63
+	je	.LBB14_115
+	cmp	eax, 71
+	jne	.LBB14_65
+	jmp	.LBB14_110
+.LBB14_107:
+	mov	r8d, eax
+	add	r8b, -63
+	je	.LBB14_115
+	rol	r8b, 5
+	movzx	r8d, r8b
+	cmp	r8d, 1
+	je	.LBB14_110
+	cmp	r8d, 7
+	jne	.LBB14_65
+	jmp	.LBB14_93
+.LBB14_110:
+	lea	rax, [rdx - 1]
+.LBB14_111:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_65
+.LBB14_112:
+	lea	rsi, [rsp + 152]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::regs::<isize>
+	mov	rcx, qword ptr [r14 + 8]
+	mov	al, byte ptr [r14 + 16]
+	cmp	byte ptr [r14], 0
+	jne	.LBB14_119
+	movups	xmm0, xmmword ptr [rsp + 17]
+	movups	xmm1, xmmword ptr [rsp + 33]
+	movups	xmm2, xmmword ptr [rsp + 49]
+	movups	xmm3, xmmword ptr [rsp + 64]
+	movups	xmmword ptr [rsp + 127], xmm3
+	movaps	xmmword ptr [rsp + 112], xmm2
+	movaps	xmmword ptr [rsp + 96], xmm1
+	movaps	xmmword ptr [rsp + 80], xmm0
+	cmp	qword ptr [rsp + 160], 0
+	je	.LBB14_120
+	mov	rax, qword ptr [rsp + 168]
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB14_8
+.LBB14_115:
+	lea	rax, [rdx - 1]
+.LBB14_116:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_65
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	test	rax, rax
+	setne	bpl
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::names
+	mov	al, byte ptr [r14 + 8]
+	cmp	al, -1
+	je	.LBB14_121
+	mov	rcx, qword ptr [rsp + 80]
+.LBB14_119:
+	mov	qword ptr [rbx + 8], rcx
+	jmp	.LBB14_66
+.LBB14_120:
+	mov	qword ptr [rbx + 8], rcx
+	mov	byte ptr [rbx + 16], al
+	movaps	xmm0, xmmword ptr [rsp + 80]
+	movaps	xmm1, xmmword ptr [rsp + 96]
+	movaps	xmm2, xmmword ptr [rsp + 112]
+	movups	xmmword ptr [rbx + 17], xmm0
+	movups	xmmword ptr [rbx + 33], xmm1
+	movups	xmmword ptr [rbx + 49], xmm2
+	movups	xmm0, xmmword ptr [rsp + 127]
+	movups	xmmword ptr [rbx + 64], xmm0
+	mov	al, 6
+	jmp	.LBB14_21
+.LBB14_121:
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_124
+	mov	al, byte ptr [rsp + 80]
+	jmp	.LBB14_99
+.LBB14_123:
+	cmp	qword ptr [rsp + 8], 0
+	je	.LBB14_126
+.LBB14_124:
+	mov	rax, qword ptr [rsp + 16]
+.LBB14_125:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], 3
+.LBB14_67:
+	mov	byte ptr [rbx], -1
+.LBB14_22:
+	add	rsp, 176
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB14_126:
+	mov	byte ptr [rbx], 4
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB14_58
+.Lfunc_end14:
+.LJTI14_0:
+	.long	.LBB14_48-.LJTI14_0
+	.long	.LBB14_65-.LJTI14_0
+	.long	.LBB14_87-.LJTI14_0
+	.long	.LBB14_82-.LJTI14_0
+	.long	.LBB14_65-.LJTI14_0
+	.long	.LBB14_76-.LJTI14_0
+	.long	.LBB14_65-.LJTI14_0
+	.long	.LBB14_92-.LJTI14_0
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin15:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB15_1
+	xor	eax, eax
+	mov	qword ptr [r14 + 1024], rax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	cmovae	rdx, rax
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB15_3
+	mov	edi, 1
+	mov	rsi, r15
+	mov	rdx, rbx
+	xor	ecx, ecx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	jmp	optional_trait_methods::connection::write_fd
+.LBB15_1:
+	cmp	rax, rdx
+	jb	.LBB15_4
+.LBB15_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB15_4:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end15:
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin16:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB16_1
+	xor	eax, eax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	mov	qword ptr [r14 + 1024], rax
+	cmovae	rdx, rax
+	mov	ebp, dword ptr [r14 + 1032]
+	mov	edi, ebp
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB16_3
+	mov	edi, ebp
+	mov	rsi, r15
+	mov	rdx, rbx
+	mov	ecx, 1
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	optional_trait_methods::connection::write_fd
+.LBB16_1:
+	cmp	rax, rdx
+	jb	.LBB16_4
+.LBB16_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB16_4:
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end16:
+
+main:
+.Lfunc_begin17:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 3352
+	cmp	rdi, 2
+	jl	.LBB17_55
+	mov	rbp, rsi
+	mov	r13d, 1
+	lea	r12, [rsp + 392]
+	mov	qword ptr [rsp + 40], 0
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	dword ptr [rsp + 36], eax
+	mov	qword ptr [rsp + 80], 0
+	mov	qword ptr [rsp + 64], 0
+	mov	qword ptr [rsp + 72], 0
+	mov	qword ptr [rsp + 96], 0
+	mov	qword ptr [rsp + 88], 0
+	mov	qword ptr [rsp + 104], rsi
+	mov	qword ptr [rsp + 24], rdi
+.LBB17_2:
+	mov	qword ptr [rsp + 48], r15
+.LBB17_3:
+	mov	rbp, qword ptr [rbp + 8*r13]
+	mov	rdi, rbp
+	call	rcx
+	mov	rbx, rax
+	test	rax, rax
+	je	.LBB17_8
+	xor	r14d, r14d
+.LBB17_5:
+	cmp	byte ptr [rbp + r14], 61
+	je	.LBB17_10
+	inc	r14
+	cmp	rbx, r14
+	jne	.LBB17_5
+	mov	qword ptr [rsp + 16], 0
+	mov	r14, rbx
+	jmp	.LBB17_9
+.LBB17_8:
+	mov	qword ptr [rsp + 16], 0
+	xor	r14d, r14d
+.LBB17_9:
+	jmp	.LBB17_11
+.LBB17_10:
+	lea	rax, [r14 + 1]
+	mov	rcx, rbx
+	sub	rcx, rax
+	mov	qword ptr [rsp + 208], rcx
+	lea	rax, [r14 + rbp]
+	inc	rax
+	mov	qword ptr [rsp + 16], rax
+.LBB17_11:
+	mov	qword ptr [rsp + 8], r13
+	lea	rax, [r13 + 1]
+	mov	qword ptr [rsp + 56], rax
+	mov	qword ptr [rsp + 376], 0
+	mov	qword ptr [rsp + 384], 7
+	mov	edx, 168
+	mov	rdi, r12
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.24]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r15d, 32
+.LBB17_12:
+	cmp	qword ptr [rsp + r15 + 368], r14
+	jne	.LBB17_15
+	movzx	r13d, byte ptr [rsp + r15 + 376]
+	mov	r12, qword ptr [rsp + r15 + 360]
+	mov	rdi, r12
+	mov	rsi, rbp
+	mov	rdx, r14
+	call	qword ptr [rip + bcmp@GOTPCREL]
+	test	eax, eax
+	jne	.LBB17_15
+	cmp	r13d, 255
+	jne	.LBB17_16
+.LBB17_15:
+	add	r15, 24
+	cmp	r15, 200
+	jne	.LBB17_12
+	jmp	.LBB17_92
+.LBB17_16:
+	cmp	r13, 255
+	je	.LBB17_92
+	cmp	r13b, 4
+	jae	.LBB17_20
+	mov	rdx, qword ptr [rsp + 16]
+	test	rdx, rdx
+	mov	rbp, qword ptr [rsp + 104]
+	mov	rbx, qword ptr [rsp + 24]
+	je	.LBB17_22
+	mov	rax, qword ptr [rsp + 56]
+	mov	qword ptr [rsp + 8], rax
+	mov	rax, qword ptr [rsp + 208]
+	jmp	.LBB17_25
+.LBB17_20:
+	cmp	qword ptr [rsp + 16], 0
+	mov	rbp, qword ptr [rsp + 104]
+	mov	rbx, qword ptr [rsp + 24]
+	jne	.LBB17_101
+	mov	edx, 1
+	mov	rax, qword ptr [rsp + 56]
+	mov	qword ptr [rsp + 8], rax
+	xor	eax, eax
+	jmp	.LBB17_25
+.LBB17_22:
+	cmp	qword ptr [rsp + 56], rbx
+	mov	r15, qword ptr [rsp + 8]
+	jge	.LBB17_102
+	mov	rdi, qword ptr [rbp + 8*r15 + 8]
+	test	rdi, rdi
+	je	.LBB17_102
+	mov	qword ptr [rsp + 16], rdi
+	call	qword ptr [rip + strlen@GOTPCREL]
+	mov	rdx, qword ptr [rsp + 16]
+	add	r15, 2
+	mov	qword ptr [rsp + 8], r15
+.LBB17_25:
+	cmp	r13b, 5
+	jne	.LBB17_27
+	mov	al, 1
+	mov	qword ptr [rsp + 96], rax
+	mov	r13, qword ptr [rsp + 8]
+	cmp	r13, rbx
+	lea	r12, [rsp + 392]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB17_3
+	jmp	.LBB17_104
+.LBB17_27:
+	movzx	ecx, r13b
+	mov	esi, ecx
+	lea	rcx, [rip + .LJTI17_0]
+	movsxd	rsi, dword ptr [rcx + 4*rsi]
+	add	rsi, rcx
+	mov	rbx, qword ptr [rsp + 80]
+	jmp	rsi
+.LBB17_28:
+	test	rax, rax
+	je	.LBB17_119
+	mov	qword ptr [rsp + 192], rdx
+	mov	qword ptr [rsp + 200], rax
+	mov	dword ptr [rsp + 36], eax
+	cmp	word ptr [rsp + 40], 0
+	mov	ax, 1
+	mov	qword ptr [rsp + 40], rax
+	mov	r15, qword ptr [rsp + 48]
+	lea	r12, [rsp + 392]
+	mov	r13, qword ptr [rsp + 8]
+	je	.LBB17_45
+	jmp	.LBB17_112
+.LBB17_30:
+	mov	al, 1
+	mov	qword ptr [rsp + 72], rax
+	jmp	.LBB17_42
+.LBB17_31:
+	mov	r15, rax
+	mov	rbx, rdx
+	jmp	.LBB17_43
+.LBB17_32:
+	cmp	rax, 8
+	je	.LBB17_46
+	cmp	rax, 4
+	mov	r13, qword ptr [rsp + 8]
+	jne	.LBB17_124
+	mov	esi, 4
+	cmp	byte ptr [rdx], 101
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 1], 120
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 2], 105
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 3], 116
+	jne	.LBB17_125
+	jmp	.LBB17_54
+.LBB17_38:
+	mov	rdi, rdx
+	mov	r13, rax
+	mov	rsi, rax
+	mov	r15, rdx
+	call	optional_trait_methods::num::parse_num::<u16>
+	mov	dword ptr [rsp + 36], edx
+	test	al, 1
+	je	.LBB17_118
+	cmp	word ptr [rsp + 40], 0
+	mov	r15, qword ptr [rsp + 48]
+	lea	r12, [rsp + 392]
+	jne	.LBB17_112
+	mov	ax, 2
+	mov	qword ptr [rsp + 40], rax
+	jmp	.LBB17_44
+.LBB17_41:
+	mov	al, 1
+	mov	qword ptr [rsp + 88], rax
+.LBB17_42:
+	mov	r15, qword ptr [rsp + 48]
+.LBB17_43:
+	lea	r12, [rsp + 392]
+.LBB17_44:
+	mov	r13, qword ptr [rsp + 8]
+.LBB17_45:
+	mov	qword ptr [rsp + 80], rbx
+	mov	rbx, qword ptr [rsp + 24]
+	cmp	r13, rbx
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB17_2
+	jmp	.LBB17_103
+.LBB17_46:
+	mov	esi, 8
+	cmp	byte ptr [rdx], 99
+	mov	r13, qword ptr [rsp + 8]
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 1], 111
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 2], 110
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 3], 116
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 4], 105
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 5], 110
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 6], 117
+	jne	.LBB17_125
+	cmp	byte ptr [rdx + 7], 101
+	jne	.LBB17_125
+.LBB17_54:
+	cmp	rax, 8
+	setne	al
+	mov	qword ptr [rsp + 64], rax
+	mov	r15, qword ptr [rsp + 48]
+	lea	r12, [rsp + 392]
+	jmp	.LBB17_45
+.LBB17_55:
+	xor	r14d, r14d
+	xor	ebp, ebp
+.LBB17_56:
+	xorps	xmm0, xmm0
+	lea	r12, [rsp + 2976]
+	movups	xmmword ptr [r12 + 328], xmm0
+	movups	xmmword ptr [r12 + 312], xmm0
+	movups	xmmword ptr [r12 + 296], xmm0
+	xor	ebx, ebx
+	mov	qword ptr [r12 + 344], rbx
+	lea	rdi, [rsp + 392]
+	mov	r15, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 272
+	xor	esi, esi
+	call	r15
+	mov	qword ptr [r12 + 288], r14
+	mov	qword ptr [r12 + 352], 1
+	movabs	rax, 9223372036854775807
+	lea	rcx, [rax + 1]
+	mov	qword ptr [r12 + 360], rcx
+	mov	qword ptr [r12 + 368], rax
+	mov	qword ptr [r12 - 8], rbx
+	lea	rsi, [rsp + 376]
+	mov	edx, 288
+	mov	rdi, r12
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 1416]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r15
+	lea	rdi, [rsp + 376]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r15
+	lea	rdi, [rsp + 2456]
+	mov	edx, 512
+	xor	esi, esi
+	call	r15
+	mov	dword ptr [rsp + 56], ebp
+	shr	ebp, 8
+	mov	dword ptr [rsp + 8], ebp
+	mov	r12d, 1
+	mov	rbp, qword ptr [rip + read@GOTPCREL]
+	xor	ecx, ecx
+	mov	dword ptr [rsp + 16], eax
+.LBB17_57:
+	mov	rdi, qword ptr [rsp + 1400]
+	mov	rax, qword ptr [rsp + 1408]
+	xor	r15d, r15d
+	mov	rdx, rcx
+	xor	r13d, r13d
+.LBB17_58:
+	mov	rsi, rax
+	mov	rax, rdi
+.LBB17_59:
+	cmp	rsi, rax
+	jb	.LBB17_63
+	mov	qword ptr [rsp + 2440], rbx
+	cmp	rdx, 1025
+	mov	rsi, r14
+	cmovae	rsi, r12
+	cmovae	rdx, rbx
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 1024
+	xor	edi, edi
+	lea	rsi, [rsp + 376]
+	call	rbp
+	test	rax, rax
+	js	.LBB17_73
+	je	.LBB17_74
+	mov	qword ptr [rsp + 1400], rax
+	mov	qword ptr [rsp + 1408], 0
+	xor	ecx, ecx
+	xor	edx, edx
+	xor	esi, esi
+.LBB17_63:
+	cmp	rax, rsi
+	jb	.LBB17_96
+	cmp	rax, 1024
+	ja	.LBB17_96
+	cmp	rsi, rax
+	je	.LBB17_59
+	inc	rsi
+	mov	rdi, rax
+	neg	rdi
+.LBB17_67:
+	mov	r8b, byte ptr [rsp + rsi + 375]
+	mov	qword ptr [rsp + 1408], rsi
+	cmp	r8b, 10
+	je	.LBB17_77
+	cmp	r13, 511
+	jbe	.LBB17_70
+	mov	r15b, 1
+	jmp	.LBB17_71
+.LBB17_70:
+	mov	byte ptr [rsp + r13 + 2456], r8b
+	inc	r13
+.LBB17_71:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB17_67
+	mov	rdi, rax
+	jmp	.LBB17_58
+.LBB17_73:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB17_74:
+	test	r13, r13
+	setne	al
+	or	al, r15b
+	test	al, 1
+	je	.LBB17_100
+	test	r15b, 1
+	jne	.LBB17_81
+	mov	ecx, 0
+	mov	edx, 0
+	cmp	r13, 512
+	ja	.LBB17_97
+	jmp	.LBB17_79
+.LBB17_77:
+	test	r15b, 1
+	jne	.LBB17_81
+	cmp	r13, 512
+	ja	.LBB17_96
+.LBB17_79:
+	test	r13, r13
+	setne	al
+	test	byte ptr [rsp + 8], al
+	jne	.LBB17_82
+	test	r13, r13
+	je	.LBB17_57
+	jmp	.LBB17_83
+.LBB17_81:
+	mov	qword ptr [rsp + 120], 3
+	mov	qword ptr [rsp + 128], 512
+	mov	byte ptr [rsp + 136], 4
+	mov	byte ptr [rsp + 112], -1
+	lea	rdi, [rsp + 216]
+	mov	rsi, r14
+	lea	rdx, [rsp + 112]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	jmp	.LBB17_85
+.LBB17_82:
+	mov	rdi, r14
+	lea	rsi, [rsp + 2456]
+	mov	rdx, r13
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB17_83:
+	lea	r15, [rsp + 296]
 	mov	rdi, r15
+	lea	rsi, [rsp + 2456]
+	mov	rdx, r13
+	call	<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::parse_command
+	cmp	byte ptr [rsp + 296], -1
+	je	.LBB17_86
+	mov	ecx, 10
+	lea	rax, [rsp + 112]
+	mov	rdi, rax
+	mov	rsi, r15
+	rep movsq es:[rdi], [rsi]
+	lea	rdi, [rsp + 216]
+	lea	rsi, [rsp + 2968]
+	mov	rdx, r14
+	mov	rcx, rax
+	call	<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB17_85:
+	mov	r15d, dword ptr [rsp + 16]
+	mov	r13, qword ptr [rsp + 24]
+	jmp	.LBB17_87
+.LBB17_86:
+	mov	eax, dword ptr [rsp + 312]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	r13, qword ptr [rsp + 24]
+	cmovne	r13, qword ptr [rsp + 304]
+	sete	cl
+	mov	r15d, dword ptr [rsp + 16]
+	movzx	r15d, r15b
+	cmovne	r15d, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 120], rcx
+	mov	qword ptr [rsp + 128], r13
+	mov	byte ptr [rsp + 136], r15b
+	mov	byte ptr [rsp + 112], -1
+	lea	rdi, [rsp + 216]
 	mov	rsi, r14
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::mul
-	jmp	.LBB3_12
-.LBB3_11:
+	lea	rdx, [rsp + 112]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB17_87:
+	cmp	byte ptr [rsp + 216], -1
+	jne	.LBB17_91
+	mov	rax, qword ptr [rsp + 224]
+	cmp	byte ptr [rsp + 56], 0
+	jne	.LBB17_106
+	cmp	eax, 1
+	je	.LBB17_109
+	cmp	eax, 4
+	je	.LBB17_108
+.LBB17_91:
+	mov	qword ptr [rsp + 24], r13
+	mov	dword ptr [rsp + 16], r15d
+	mov	rcx, qword ptr [rsp + 2440]
+	jmp	.LBB17_57
+.LBB17_92:
+	xor	ecx, ecx
+	mov	r12, rbp
+	mov	r14, rbx
+.LBB17_93:
+.LBB17_94:
+	lea	rdi, [rsp + 376]
+	mov	qword ptr [rdi], rcx
+	mov	qword ptr [rdi + 8], r12
+	mov	qword ptr [rdi + 16], r14
+	mov	qword ptr [rdi + 24], rdx
+	mov	qword ptr [rdi + 32], r13
+.LBB17_95:
+	call	optional_trait_methods::usage_error
+	mov	ebx, 1
+	jmp	.LBB17_99
+.LBB17_96:
+	mov	rdx, rcx
+.LBB17_97:
+	xor	eax, eax
+	mov	qword ptr [rsp + 2440], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, 1
 	mov	rsi, r14
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc
-.LBB3_12:
-	cmp	byte ptr [r15], 0
-	je	.LBB3_26
+.LBB17_98:
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+.LBB17_99:
+	mov	rax, rbx
+	add	rsp, 3352
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB17_100:
+	xor	edx, edx
+	jmp	.LBB17_97
+.LBB17_101:
+	mov	ecx, 3
+	jmp	.LBB17_93
+.LBB17_102:
+	mov	ecx, 1
+	jmp	.LBB17_93
+.LBB17_103:
+	test	byte ptr [rsp + 96], 1
+	je	.LBB17_113
+.LBB17_104:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.73]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 31
+.LBB17_105:
+	mov	edi, 2
+	call	r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	ebx, 1
+	mov	edx, 1
+	mov	edi, 2
+	call	r14
+	jmp	.LBB17_99
+.LBB17_106:
+	cmp	eax, 1
+	je	.LBB17_109
+	cmp	eax, 4
+	jne	.LBB17_111
+.LBB17_108:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 1416]
+	mov	edx, 23
+	jmp	.LBB17_110
+.LBB17_109:
+	mov	rsi, qword ptr [rsp + 232]
+	mov	rdx, qword ptr [rsp + 240]
+	lea	rdi, [rsp + 1416]
+.LBB17_110:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 1416]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB17_111:
+	mov	ebx, 1
+	mov	rdx, qword ptr [rsp + 2440]
+	jmp	.LBB17_97
+.LBB17_112:
+	mov	ecx, 4
+	jmp	.LBB17_93
+.LBB17_113:
+	test	byte ptr [rsp + 88], 1
+	je	.LBB17_115
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	xor	ebx, ebx
+	mov	edx, 823
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 1
+	jmp	.LBB17_98
+.LBB17_115:
+	mov	rbx, qword ptr [rsp + 80]
+	test	rbx, rbx
+	je	.LBB17_120
+	mov	rdi, rbx
+	mov	rsi, r15
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB17_126
+	mov	r14, rdx
+	jmp	.LBB17_121
+.LBB17_118:
+	mov	ecx, 2
+	mov	rdx, r15
+	jmp	.LBB17_94
+.LBB17_119:
+	mov	ecx, 2
+	xor	r13d, r13d
+	jmp	.LBB17_94
+.LBB17_120:
+	xor	r14d, r14d
+.LBB17_121:
+	mov	rax, qword ptr [rsp + 40]
+	test	ax, ax
+	je	.LBB17_127
+	movzx	eax, ax
+	cmp	eax, 1
+	jne	.LBB17_128
+	mov	rdi, qword ptr [rsp + 192]
+	mov	rsi, qword ptr [rsp + 200]
+	call	<optional_trait_methods::connection::Listener>::unix
+	mov	ebx, edx
+	jmp	.LBB17_129
+.LBB17_124:
+	mov	rsi, rax
+.LBB17_125:
+	mov	ecx, 2
+	mov	r13, rsi
+	jmp	.LBB17_94
+.LBB17_126:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.19]
+	lea	rdi, [rsp + 376]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 15
+	mov	qword ptr [rdi + 24], rbx
+	mov	qword ptr [rdi + 32], r15
+	mov	qword ptr [rdi], 2
+	jmp	.LBB17_95
+.LBB17_127:
+	movzx	eax, byte ptr [rsp + 72]
+	shl	eax, 8
+	movzx	ebp, byte ptr [rsp + 64]
+	or	ebp, eax
+	jmp	.LBB17_56
+.LBB17_128:
+	mov	ebp, dword ptr [rsp + 36]
+	mov	edi, ebp
+	call	<optional_trait_methods::connection::Listener>::tcp_loopback
+	mov	ebx, edx
+	test	bp, bp
+	setne	cl
+	or	cl, al
+	test	cl, 1
+	je	.LBB17_131
+.LBB17_129:
+	cmp	eax, 1
+	jne	.LBB17_132
+	mov	r15d, 1
+	mov	ebp, ebx
+	jmp	.LBB17_133
+.LBB17_131:
+	mov	edi, ebx
+	call	optional_trait_methods::report_port
+.LBB17_132:
+	mov	edi, ebx
+	call	<optional_trait_methods::connection::Listener>::accept
+	mov	r15d, eax
+	mov	ebp, edx
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+.LBB17_133:
+	test	r15b, 1
+	je	.LBB17_135
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.72]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 29
+	jmp	.LBB17_105
+.LBB17_135:
+	xorps	xmm0, xmm0
+	lea	r12, [rsp + 2976]
+	movups	xmmword ptr [r12 + 328], xmm0
+	movups	xmmword ptr [r12 + 312], xmm0
+	movups	xmmword ptr [r12 + 296], xmm0
+	xor	ebx, ebx
+	mov	qword ptr [r12 + 344], rbx
+	lea	rdi, [rsp + 392]
+	mov	r15, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 272
+	xor	esi, esi
+	call	r15
+	mov	qword ptr [r12 + 288], r14
+	mov	qword ptr [r12 + 352], 1
+	movabs	rax, 9223372036854775807
+	lea	rcx, [rax + 1]
+	mov	qword ptr [r12 + 360], rcx
+	mov	qword ptr [r12 + 368], rax
+	mov	qword ptr [r12 - 8], rbx
+	lea	rsi, [rsp + 376]
+	mov	edx, 288
+	mov	rdi, r12
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 1416]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r15
+	mov	dword ptr [r14 + 1032], ebp
+	lea	rdi, [rsp + 376]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r15
+	lea	rdi, [rsp + 2456]
+	mov	edx, 512
+	xor	esi, esi
+	call	r15
+	lea	r13, [rsp + 112]
+	xor	edx, edx
+	mov	dword ptr [rsp + 8], eax
+.LBB17_136:
+	mov	rdi, qword ptr [rsp + 1400]
+	mov	rcx, qword ptr [rsp + 1408]
+	xor	r15d, r15d
+	mov	rax, rdx
+	xor	r12d, r12d
+.LBB17_137:
+	mov	rsi, rcx
+	mov	rcx, rdi
+.LBB17_138:
+	cmp	rsi, rcx
+	jb	.LBB17_142
+	mov	qword ptr [rsp + 2440], rbx
+	cmp	rax, 1025
+	mov	rsi, r14
+	mov	ecx, 1
+	cmovae	rsi, rcx
+	cmovae	rax, rbx
+	mov	edi, ebp
+	mov	rdx, rax
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	rdi, r13
+	mov	esi, ebp
+	lea	rdx, [rsp + 376]
+	call	optional_trait_methods::connection::read_fd
+	cmp	byte ptr [rsp + 112], 0
+	jne	.LBB17_152
+	mov	rcx, qword ptr [rsp + 120]
+	test	rcx, rcx
+	je	.LBB17_152
+	mov	qword ptr [rsp + 1400], rcx
+	mov	qword ptr [rsp + 1408], 0
+	xor	edx, edx
+	xor	eax, eax
+	xor	esi, esi
+.LBB17_142:
+	cmp	rcx, rsi
+	jb	.LBB17_171
+	cmp	rcx, 1024
+	ja	.LBB17_171
+	cmp	rsi, rcx
+	je	.LBB17_138
+	inc	rsi
+	mov	rdi, rcx
+	neg	rdi
+.LBB17_146:
+	mov	r8b, byte ptr [rsp + rsi + 375]
+	mov	qword ptr [rsp + 1408], rsi
+	cmp	r8b, 10
+	je	.LBB17_155
+	cmp	r12, 511
+	jbe	.LBB17_149
+	mov	r15b, 1
+	jmp	.LBB17_150
+.LBB17_149:
+	mov	byte ptr [rsp + r12 + 2456], r8b
+	inc	r12
+.LBB17_150:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB17_146
+	mov	rdi, rcx
+	jmp	.LBB17_137
+.LBB17_152:
+	test	r12, r12
+	setne	al
+	or	al, r15b
+	test	al, 1
+	je	.LBB17_170
+	test	r15b, 1
+	jne	.LBB17_159
+	mov	edx, 0
+	cmp	r12, 512
+	jbe	.LBB17_157
+	jmp	.LBB17_170
+.LBB17_155:
+	test	r15b, 1
+	jne	.LBB17_159
+	cmp	r12, 512
+	ja	.LBB17_171
+.LBB17_157:
+	test	r12, r12
+	setne	al
+	test	byte ptr [rsp + 72], al
+	jne	.LBB17_160
+	test	r12, r12
+	je	.LBB17_136
+	jmp	.LBB17_161
+.LBB17_159:
+	mov	qword ptr [rsp + 120], 3
+	mov	qword ptr [rsp + 128], 512
+	mov	byte ptr [rsp + 136], 4
+	jmp	.LBB17_164
+.LBB17_160:
+	mov	rdi, r14
+	lea	rsi, [rsp + 2456]
+	mov	rdx, r12
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB17_161:
+	lea	rdi, [rsp + 296]
+	lea	rsi, [rsp + 2456]
+	mov	rdx, r12
+	call	<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::parse_command
+	cmp	byte ptr [rsp + 296], -1
+	je	.LBB17_163
+	mov	ecx, 10
+	mov	rdi, r13
+	lea	rsi, [rsp + 296]
+	rep movsq es:[rdi], [rsi]
+	lea	rdi, [rsp + 216]
+	lea	rsi, [rsp + 2968]
+	mov	rdx, r14
+	mov	rcx, r13
+	call	<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	jmp	.LBB17_165
+.LBB17_163:
+	mov	eax, dword ptr [rsp + 312]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rdx, qword ptr [rsp + 16]
+	cmovne	rdx, qword ptr [rsp + 304]
+	sete	cl
+	mov	esi, dword ptr [rsp + 8]
+	movzx	esi, sil
+	cmovne	esi, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 120], rcx
+	mov	qword ptr [rsp + 16], rdx
+	mov	qword ptr [rsp + 128], rdx
+	mov	dword ptr [rsp + 8], esi
+	mov	byte ptr [rsp + 136], sil
+.LBB17_164:
+	mov	byte ptr [rsp + 112], -1
+	lea	rdi, [rsp + 216]
+	mov	rsi, r14
+	mov	rdx, r13
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+.LBB17_165:
+	cmp	byte ptr [rsp + 216], -1
+	jne	.LBB17_169
+	mov	rax, qword ptr [rsp + 224]
+	cmp	byte ptr [rsp + 64], 0
+	jne	.LBB17_172
+	cmp	eax, 1
+	je	.LBB17_175
+	cmp	eax, 4
+	je	.LBB17_174
+.LBB17_169:
+	mov	ebp, dword ptr [rsp + 2448]
+	mov	rdx, qword ptr [rsp + 2440]
+	jmp	.LBB17_136
+.LBB17_170:
+	xor	edx, edx
+.LBB17_171:
+	xor	eax, eax
+	mov	qword ptr [rsp + 2440], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, ebp
+	mov	rsi, r14
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	jmp	.LBB17_99
+.LBB17_172:
+	cmp	eax, 1
+	je	.LBB17_175
+	cmp	eax, 4
+	jne	.LBB17_177
+.LBB17_174:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 1416]
+	mov	edx, 23
+	jmp	.LBB17_176
+.LBB17_175:
+	mov	rsi, qword ptr [rsp + 232]
+	mov	rdx, qword ptr [rsp + 240]
+	lea	rdi, [rsp + 1416]
+.LBB17_176:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 1416]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB17_177:
+	mov	rdx, qword ptr [rsp + 2440]
+	mov	ebx, 1
+	mov	ebp, dword ptr [rsp + 2448]
+	jmp	.LBB17_171
+.LBB17_178:
+	ud2
+.Lfunc_end17:
+.LJTI17_0:
+	.long	.LBB17_28-.LJTI17_0
+	.long	.LBB17_38-.LJTI17_0
+	.long	.LBB17_31-.LJTI17_0
+	.long	.LBB17_32-.LJTI17_0
+	.long	.LBB17_30-.LJTI17_0
+	.long	.LBB17_178-.LJTI17_0
+	.long	.LBB17_41-.LJTI17_0
+
+rust_eh_personality:
+.Lfunc_begin18:
+	ret
+.Lfunc_end18:
+
+core::panicking::panic_fmt:
+.Lfunc_begin19:
+	.loc	1 60 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp0:
+	.loc	1 80 14 prologue_end
+	call	__rustc::rust_begin_unwind
+.Ltmp1:
+.Lfunc_end19:
+
+core::panicking::panic_bounds_check:
+.Lfunc_begin20:
+	.loc	1 266 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp2:
+	.loc	1 271 5 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp3:
+.Lfunc_end20:
+
+core::slice::index::slice_index_fail:
+.Lfunc_begin21:
+	.loc	2 38 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp4:
+	.loc	3 177 9 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp5:
+.Lfunc_end21:
+
+optional_trait_methods::num::parse_num::<isize>:
+.Lfunc_begin22:
+	test	rsi, rsi
+	je	.LBB22_56
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB22_4
+	cmp	ecx, 45
+	jne	.LBB22_6
+	mov	cl, 1
+	jmp	.LBB22_5
+.LBB22_4:
+	xor	ecx, ecx
+.LBB22_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB22_7
+.LBB22_6:
+	xor	ecx, ecx
+.LBB22_7:
+	cmp	rsi, 1
+	jbe	.LBB22_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB22_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB22_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB22_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB22_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB22_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB22_32
+.LBB22_14:
+	test	rsi, rsi
+	je	.LBB22_56
+	mov	al, byte ptr [rdi]
+.LBB22_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB22_56
+.LBB22_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB22_19
+	cmp	byte ptr [rax], 95
+	je	.LBB22_56
+.LBB22_19:
+	xor	r8d, r8d
+	xor	edx, edx
+.LBB22_20:
+	cmp	rdi, rsi
+	je	.LBB22_51
+	mov	al, byte ptr [rdi]
+	inc	rdi
+	cmp	al, 95
+	jne	.LBB22_23
+	xor	eax, eax
+	test	r8b, 1
+	mov	r8d, 0
+	jne	.LBB22_20
+	jmp	.LBB22_52
+.LBB22_23:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB22_57
+	imul	rdx, rdx, 10
+	jo	.LBB22_57
+	movzx	eax, al
+	test	cl, cl
+	je	.LBB22_27
+	sub	rdx, rax
+	jno	.LBB22_28
+	jmp	.LBB22_57
+.LBB22_27:
+	add	rdx, rax
+	jo	.LBB22_57
+.LBB22_28:
+	mov	r8b, 1
+	jmp	.LBB22_20
+.LBB22_29:
+	mov	r8b, 8
+	jmp	.LBB22_31
+.LBB22_30:
+	mov	r8b, 2
+.LBB22_31:
+	xor	r9d, r9d
+.LBB22_32:
+	cmp	rsi, 2
+	je	.LBB22_56
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB22_53
+.LBB22_34:
+	cmp	dl, r8b
+	jae	.LBB22_56
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB22_37
+	cmp	byte ptr [rax], 95
+	je	.LBB22_56
+.LBB22_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB22_38:
+	cmp	r10, rdi
+	je	.LBB22_51
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB22_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB22_52
+	jmp	.LBB22_38
+.LBB22_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB22_45
+	test	r9b, r9b
+	je	.LBB22_57
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB22_57
+	add	al, -87
+	mov	r11d, eax
+.LBB22_45:
+	cmp	r11b, r8b
+	jae	.LBB22_57
+	imul	rdx, rsi
+	jo	.LBB22_57
+	movzx	eax, r11b
+	test	cl, cl
+	je	.LBB22_49
+	sub	rdx, rax
+	jo	.LBB22_57
+	jmp	.LBB22_50
+.LBB22_49:
+	add	rdx, rax
+	jo	.LBB22_57
+.LBB22_50:
+	mov	r11b, 1
+	jmp	.LBB22_38
+.LBB22_51:
+	mov	eax, 1
+	ret
+.LBB22_53:
+	test	r9b, r9b
+	je	.LBB22_56
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB22_56
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB22_34
+.LBB22_56:
+	xor	eax, eax
+.LBB22_52:
+	ret
+.LBB22_57:
+	xor	eax, eax
+	ret
+.Lfunc_end22:
+
+optional_trait_methods::num::parse_num::<usize>:
+.Lfunc_begin23:
+	test	rsi, rsi
+	je	.LBB23_55
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB23_4
+	cmp	ecx, 45
+	jne	.LBB23_6
+	mov	cl, 1
+	jmp	.LBB23_5
+.LBB23_4:
+	xor	ecx, ecx
+.LBB23_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB23_7
+.LBB23_6:
+	xor	ecx, ecx
+.LBB23_7:
+	cmp	rsi, 1
+	jbe	.LBB23_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB23_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB23_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB23_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB23_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB23_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB23_32
+.LBB23_14:
+	test	rsi, rsi
+	je	.LBB23_55
+	mov	al, byte ptr [rdi]
+.LBB23_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB23_55
+.LBB23_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB23_19
+	cmp	byte ptr [rax], 95
+	je	.LBB23_55
+.LBB23_19:
+	mov	r8d, 10
+	xor	r9d, r9d
+	xor	edx, edx
+.LBB23_20:
+	cmp	rdi, rsi
+	je	.LBB23_50
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB23_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB23_20
+	jmp	.LBB23_51
+.LBB23_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB23_28
+	mov	rax, rdx
+	mul	r8
+	jo	.LBB23_28
+	mov	rdx, rax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB23_27
+	sub	rdx, rax
+	jae	.LBB23_20
+	jmp	.LBB23_28
+.LBB23_27:
+	add	rdx, rax
+	jae	.LBB23_20
+.LBB23_28:
+	xor	eax, eax
+	ret
+.LBB23_29:
+	mov	r8b, 8
+	jmp	.LBB23_31
+.LBB23_30:
+	mov	r8b, 2
+.LBB23_31:
+	xor	r9d, r9d
+.LBB23_32:
+	cmp	rsi, 2
+	je	.LBB23_55
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB23_52
+.LBB23_34:
+	cmp	dl, r8b
+	jae	.LBB23_55
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB23_37
+	cmp	byte ptr [rax], 95
+	je	.LBB23_55
+.LBB23_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB23_38:
+	cmp	r10, rdi
+	je	.LBB23_50
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB23_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB23_51
+	jmp	.LBB23_38
+.LBB23_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB23_45
+	test	r9b, r9b
+	je	.LBB23_28
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB23_28
+	add	al, -87
+	mov	r11d, eax
+.LBB23_45:
+	cmp	r11b, r8b
+	jae	.LBB23_28
+	mov	rax, rdx
+	mul	rsi
+	jo	.LBB23_28
+	mov	rdx, rax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB23_49
+	sub	rdx, rax
+	jae	.LBB23_38
+	jmp	.LBB23_28
+.LBB23_49:
+	add	rdx, rax
+	jae	.LBB23_38
+	jmp	.LBB23_28
+.LBB23_50:
+	mov	eax, 1
+	ret
+.LBB23_52:
+	test	r9b, r9b
+	je	.LBB23_55
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB23_55
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB23_34
+.LBB23_55:
+	xor	eax, eax
+.LBB23_51:
+	ret
+.Lfunc_end23:
+
+<optional_trait_methods::commands::Args>::reg:
+.Lfunc_begin24:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	cmp	rdx, 8
+	setb	cl
+	and	cl, al
+	cmovne	r15, rdx
+	neg	cl
+	or	cl, 2
+	mov	qword ptr [rbx], r15
+	mov	byte ptr [rbx + 8], cl
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end24:
+
+<optional_trait_methods::commands::Args>::next:
+.Lfunc_begin25:
+	mov	r10, qword ptr [rsi + 8]
+	mov	rax, qword ptr [rsi + 16]
+	xor	edx, edx
+	test	r10, r10
+	setne	dl
+	cmp	r10, 1
+	mov	rcx, r10
+	adc	rcx, -1
+	mov	r8, qword ptr [rsi]
+	add	r8, rdx
+	mov	r9, rcx
+	cmp	r10, 2
+	jb	.LBB25_5
+	xor	r9d, r9d
+.LBB25_2:
+	cmp	byte ptr [r8 + r9], 32
+	je	.LBB25_5
+	inc	r9
+	cmp	rcx, r9
+	jne	.LBB25_2
+	mov	r9, rcx
+.LBB25_5:
+	add	rax, rdx
+	sub	rcx, r9
+	lea	rdx, [r8 + r9]
+	mov	qword ptr [rsi], rdx
+	mov	qword ptr [rsi + 8], rcx
+	lea	rcx, [r9 + rax]
+	mov	qword ptr [rsi + 16], rcx
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], r8
+	mov	qword ptr [rdi + 16], r9
+	ret
+.Lfunc_end25:
+
+<optional_trait_methods::commands::Args>::names:
+.Lfunc_begin26:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 200
+	mov	qword ptr [rsp + 32], rdi
+	lea	r14, [rsp + 40]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r12, qword ptr [r14 + 8]
+	mov	rbx, qword ptr [r14 + 16]
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, qword ptr [rip + bcmp@GOTPCREL]
+.LBB26_1:
+	test	rbx, rbx
+	je	.LBB26_2
+	xor	r13d, r13d
+.LBB26_4:
+	cmp	byte ptr [r12 + r13], 59
+	je	.LBB26_7
+	inc	r13
+	cmp	rbx, r13
+	jne	.LBB26_4
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 24], r12
+	mov	r13, rbx
+	jmp	.LBB26_8
+.LBB26_2:
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	xor	ebx, ebx
+	mov	qword ptr [rsp + 24], r12
+	xor	r13d, r13d
+	jmp	.LBB26_8
+.LBB26_7:
+	lea	rax, [r13 + 1]
+	sub	rbx, rax
+	lea	rax, [r12 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 24], rax
+	mov	dword ptr [rsp + 12], 0
+.LBB26_8:
+	mov	edx, 144
+	lea	rbp, [rsp + 56]
+	mov	rdi, rbp
+	lea	rsi, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.6]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r14d, 6
+.LBB26_9:
+	cmp	qword ptr [rbp + 16], r13
+	jne	.LBB26_11
+	mov	rdi, qword ptr [rbp + 8]
+	mov	rsi, r12
+	mov	rdx, r13
+	call	r15
+	test	eax, eax
+	je	.LBB26_12
+.LBB26_11:
+	add	rbp, 24
+	dec	r14
+	jne	.LBB26_9
+	jmp	.LBB26_13
+.LBB26_12:
 	mov	rax, qword ptr [rsp + 16]
+	or	al, byte ptr [rbp]
+	mov	qword ptr [rsp + 16], rax
+.LBB26_13:
+	mov	r12, qword ptr [rsp + 24]
+	cmp	byte ptr [rsp + 12], 0
+	je	.LBB26_1
+	mov	rax, qword ptr [rsp + 32]
+	mov	rcx, qword ptr [rsp + 16]
+	mov	byte ptr [rax], cl
+	mov	byte ptr [rax + 8], -1
+	add	rsp, 200
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end26:
+
+<optional_trait_methods::connection::Listener>::tcp_loopback:
+.Lfunc_begin27:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	qword ptr [rsp + 20], 0
+	mov	word ptr [rsp + 12], 2
+	rol	di, 8
+	mov	word ptr [rsp + 14], di
+	mov	dword ptr [rsp + 16], 16777343
+	mov	edi, 2
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebx, edx
+	mov	ebp, 1
+	test	al, 1
+	jne	.LBB27_5
+	lea	rcx, [rsp + 28]
+	mov	dword ptr [rcx], 1
+	mov	edi, ebx
+	mov	esi, 1
+	mov	edx, 2
+	mov	r8d, 4
+	call	qword ptr [rip + setsockopt@GOTPCREL]
+	lea	rsi, [rsp + 12]
+	mov	edi, ebx
+	mov	edx, 16
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB27_3
+	mov	edi, ebx
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB27_3
+	xor	ebp, ebp
+	jmp	.LBB27_5
+.LBB27_3:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebx, r14d
+.LBB27_5:
+	mov	eax, ebp
+	mov	edx, ebx
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end27:
+
+<optional_trait_methods::connection::Listener>::socket:
+.Lfunc_begin28:
+	push	rbx
+	xor	ebx, ebx
+	mov	esi, 1
+	xor	edx, edx
+	call	qword ptr [rip + socket@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB28_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB28_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end28:
+
+<optional_trait_methods::connection::Listener>::unix:
+.Lfunc_begin29:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 112
+	mov	rdx, rsi
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [rsp + 96], xmm0
+	movups	xmmword ptr [rsp + 84], xmm0
+	movups	xmmword ptr [rsp + 68], xmm0
+	movups	xmmword ptr [rsp + 52], xmm0
+	movups	xmmword ptr [rsp + 36], xmm0
+	movups	xmmword ptr [rsp + 20], xmm0
+	movups	xmmword ptr [rsp + 4], xmm0
+	mov	word ptr [rsp + 2], 1
+	lea	rax, [rsi - 108]
+	cmp	rax, -107
+	jae	.LBB29_2
+	mov	ebx, 1
+	mov	ebp, 36
+	jmp	.LBB29_7
+.LBB29_2:
+	mov	rsi, rdi
+	lea	rbx, [rsp + 4]
+	mov	rdi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	rdi, rbx
+	call	qword ptr [rip + unlink@GOTPCREL]
+	mov	ebx, 1
+	mov	edi, 1
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebp, edx
+	test	al, 1
+	jne	.LBB29_7
+	lea	rsi, [rsp + 2]
+	mov	edi, ebp
+	mov	edx, 110
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB29_6
+	mov	edi, ebp
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB29_6
+	xor	ebx, ebx
+	jmp	.LBB29_7
+.LBB29_6:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebp, r14d
+.LBB29_7:
+	mov	eax, ebx
+	mov	edx, ebp
+	add	rsp, 112
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end29:
+
+<optional_trait_methods::connection::Listener>::accept:
+.Lfunc_begin30:
+	push	rbx
+	xor	ebx, ebx
+	xor	esi, esi
+	xor	edx, edx
+	call	qword ptr [rip + accept@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB30_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB30_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end30:
+
+optional_trait_methods::commands::unrecognized:
+.Lfunc_begin31:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	xor	r14d, r14d
+.LBB31_1:
+	cmp	byte ptr [rbx + r14], 32
+	je	.LBB31_4
+	inc	r14
+	cmp	rsi, r14
+	jne	.LBB31_1
+	mov	r14, rsi
+.LBB31_4:
+	mov	r15d, 8
+	lea	r12, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.33]
+	mov	r13, qword ptr [rip + bcmp@GOTPCREL]
+.LBB31_5:
+	cmp	qword ptr [r15 + r12], r14
+	jne	.LBB31_8
+	mov	rdi, qword ptr [r15 + r12 - 8]
+	mov	rsi, rbx
+	mov	rdx, r14
+	call	r13
+	test	eax, eax
+	je	.LBB31_7
+.LBB31_8:
+	add	r15, 16
+	cmp	r15, 152
+	jne	.LBB31_5
+	xor	eax, eax
+	jmp	.LBB31_10
+.LBB31_7:
+	mov	al, 1
+.LBB31_10:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end31:
+
+optional_trait_methods::connection::read_fd:
+.Lfunc_begin32:
+	push	rbx
+	mov	rax, rdx
+	mov	rbx, rdi
+	mov	edx, 1024
+	mov	edi, esi
+	mov	rsi, rax
+	call	qword ptr [rip + read@GOTPCREL]
 	test	rax, rax
-	je	.LBB3_21
-.LBB3_14:
-	mov	rcx, qword ptr [rsp + 24]
+	js	.LBB32_2
 	mov	qword ptr [rbx + 8], rax
-	mov	qword ptr [rbx + 16], rcx
-.LBB3_25:
-	mov	r14d, 1
-	jmp	.LBB3_27
-.LBB3_15:
-	mov	rsi, r14
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc
-	cmp	dword ptr [r15], 1
-	jne	.LBB3_22
-	mov	rax, qword ptr [rsp + 16]
-	test	rax, rax
-	jne	.LBB3_14
-	lea	r15, [rsp + 8]
-	mov	rdi, r15
-	mov	rsi, r14
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
-	cmp	byte ptr [r15], 0
-	je	.LBB3_24
-	mov	rax, qword ptr [rsp + 16]
-	test	rax, rax
-	jne	.LBB3_14
-	lea	r14, [rsp + 8]
-	mov	rdi, r14
-	call	<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::unsupported_cmd
-	cmp	byte ptr [r14], 0
-	je	.LBB3_26
-	movups	xmm0, xmmword ptr [rsp + 16]
-	movups	xmmword ptr [rbx + 8], xmm0
-	jmp	.LBB3_25
-.LBB3_21:
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.7]
-	mov	r14, qword ptr [rip + write@GOTPCREL]
-	mov	edx, 16
-	mov	edi, 1
-	call	r14
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	mov	edx, 1
-	mov	edi, 1
-	call	r14
-	jmp	.LBB3_26
-.LBB3_22:
-	lea	r15, [rsp + 8]
-	mov	rdi, r15
+	xor	eax, eax
+	jmp	.LBB32_3
+.LBB32_2:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	eax, dword ptr [rax]
+	mov	dword ptr [rbx + 4], eax
+	mov	eax, 1
+.LBB32_3:
+	mov	dword ptr [rbx], eax
+	pop	rbx
+	ret
+.Lfunc_end32:
+
+optional_trait_methods::connection::write_fd:
+.Lfunc_begin33:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	test	rdx, rdx
+	je	.LBB33_8
+	mov	ebp, ecx
+	mov	rbx, rdx
+	mov	r14, rsi
+	mov	r15d, edi
+	mov	r12, qword ptr [rip + send@GOTPCREL]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+.LBB33_2:
+	mov	edi, r15d
 	mov	rsi, r14
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
-	cmp	byte ptr [r15], 0
-	je	.LBB3_26
-	mov	rax, qword ptr [rsp + 16]
+	mov	rdx, rbx
+	test	bpl, bpl
+	je	.LBB33_3
+	mov	ecx, 16384
+	call	r12
+	jmp	.LBB33_5
+.LBB33_3:
+	call	r13
+.LBB33_5:
 	test	rax, rax
-	jne	.LBB3_14
-.LBB3_24:
-	mov	qword ptr [rbx + 8], 0
-	jmp	.LBB3_25
-.LBB3_26:
-	xor	r14d, r14d
-.LBB3_27:
-	mov	qword ptr [rbx], r14
-	mov	rax, rbx
-	add	rsp, 32
+	js	.LBB33_10
+	cmp	rbx, rax
+	jb	.LBB33_9
+	add	r14, rax
+	sub	rbx, rax
+	jne	.LBB33_2
+.LBB33_8:
+	add	rsp, 8
 	pop	rbx
+	pop	r12
+	pop	r13
 	pop	r14
 	pop	r15
+	pop	rbp
 	ret
-.Lfunc_end3:
+.LBB33_10:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB33_9:
+	mov	rdi, rax
+	mov	rsi, rbx
+	mov	rdx, rbx
+	call	core::slice::index::slice_index_fail
+.Lfunc_end33:
 
-optional_trait_methods::commands::parse_isize:
-	test	rsi, rsi
-	je	.LBB4_12
-	movzx	eax, byte ptr [rdi]
-	cmp	eax, 45
-	je	.LBB4_3
-	xor	ecx, ecx
-	cmp	eax, 43
-	je	.LBB4_4
-	jmp	.LBB4_6
-.LBB4_3:
-	mov	cl, 1
-.LBB4_4:
-	dec	rsi
-	je	.LBB4_12
-	inc	rdi
-.LBB4_6:
-	xor	eax, eax
-	xor	r8d, r8d
-	xor	edx, edx
-.LBB4_7:
-	cmp	rsi, r8
-	je	.LBB4_13
-	mov	r9b, byte ptr [rdi + r8]
-	add	r9b, -48
-	cmp	r9b, 9
-	ja	.LBB4_11
-	imul	rdx, rdx, 10
-	jo	.LBB4_11
-	movzx	r9d, r9b
-	inc	r8
-	add	rdx, r9
-	jno	.LBB4_7
-.LBB4_11:
-	ret
-.LBB4_12:
-	xor	eax, eax
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::checkpoint:
+.Lfunc_begin34:
+	push	rax
+	mov	rcx, qword ptr [rsi + 296]
+	mov	rdx, qword ptr [rsi + 360]
+	mov	rax, qword ptr [rsi + 280]
+	lea	r8, [rsi + 24]
+	cmp	qword ptr [rsi + 288], 16
+	jne	.LBB34_3
+	cmp	rax, 16
+	jae	.LBB34_5
+	mov	r9, rax
+	shl	r9, 4
+	movups	xmm0, xmmword ptr [r8 + r9]
+	mov	qword ptr [rsi], 1
+	movups	xmmword ptr [rsi + 8], xmm0
+	jmp	.LBB34_4
+.LBB34_3:
+	mov	qword ptr [rsi], 0
+	cmp	rax, 16
+	jae	.LBB34_5
+.LBB34_4:
+	shl	rax, 4
+	mov	qword ptr [r8 + rax], rcx
+	mov	qword ptr [r8 + rax + 8], rdx
+	mov	eax, dword ptr [rsi + 280]
+	inc	eax
+	and	eax, 15
+	mov	qword ptr [rsi + 280], rax
+	mov	rax, qword ptr [rsi + 288]
+	inc	rax
+	cmp	rax, 16
+	mov	ecx, 16
+	cmovb	rcx, rax
+	mov	qword ptr [rsi + 288], rcx
+	mov	qword ptr [rdi], 0
+	pop	rax
 	ret
-.LBB4_13:
-	movzx	ecx, cl
-	neg	rcx
-	or	rcx, 1
+.LBB34_5:
+	mov	esi, 16
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end34:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_bounds:
+.Lfunc_begin35:
+	cmp	rdx, rcx
+	jle	.LBB35_1
+	lea	rax, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.42]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 26
+	mov	eax, 1
+	jmp	.LBB35_3
+.LBB35_1:
+	mov	qword ptr [rsi + 368], rdx
+	mov	qword ptr [rsi + 376], rcx
+	mov	rax, qword ptr [rsi + 360]
+	cmp	rdx, rax
+	cmovg	rax, rdx
+	cmp	rcx, rax
+	cmovl	rax, rcx
+	mov	qword ptr [rsi + 360], rax
 	xor	eax, eax
-	imul	rdx, rcx
-	setno	al
+.LBB35_3:
+	mov	qword ptr [rdi], rax
 	ret
-.Lfunc_end4:
+.Lfunc_end35:
 
 <optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_factor:
-	mov	rax, rdi
-	mov	qword ptr [rsi + 8], rdx
+.Lfunc_begin36:
+	mov	rax, qword ptr [rsi + 368]
+	mov	rcx, qword ptr [rsi + 376]
+	cmp	rax, rdx
+	cmovg	rdx, rax
+	cmp	rcx, rdx
+	cmovl	rdx, rcx
+	mov	qword ptr [rsi + 360], rdx
 	mov	qword ptr [rdi], 0
 	ret
-.Lfunc_end5:
+.Lfunc_end36:
 
 <optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec:
-	dec	qword ptr [rsi]
-	mov	rax, rdi
+.Lfunc_begin37:
+	dec	qword ptr [rsi + 296]
 	mov	qword ptr [rdi], 0
 	ret
-.Lfunc_end6:
+.Lfunc_end37:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::div:
+.Lfunc_begin38:
+	test	rdx, rdx
+	je	.LBB38_3
+	mov	rcx, rdx
+	mov	rax, qword ptr [rsi + 296]
+	mov	rdx, rax
+	btc	rdx, 63
+	mov	r8, rcx
+	not	r8
+	or	r8, rdx
+	je	.LBB38_4
+	cqo
+	idiv	rcx
+	mov	qword ptr [rsi + 296], rax
+	xor	eax, eax
+.LBB38_6:
+	mov	qword ptr [rdi], rax
+	ret
+.LBB38_3:
+	lea	rax, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.43]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 17
+	jmp	.LBB38_5
+.LBB38_4:
+	lea	rax, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.44]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 29
+.LBB38_5:
+	mov	eax, 1
+	jmp	.LBB38_6
+.Lfunc_end38:
 
 <optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc:
-	inc	qword ptr [rsi]
-	mov	rax, rdi
+.Lfunc_begin39:
+	inc	qword ptr [rsi + 296]
 	mov	qword ptr [rdi], 0
 	ret
-.Lfunc_end7:
+.Lfunc_end39:
 
 <optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::mul:
-	mov	rax, rdi
+.Lfunc_begin40:
 	cmp	rdx, 7
-	jne	.LBB8_2
-	lea	rcx, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.13]
-	mov	qword ptr [rax + 8], rcx
-	mov	qword ptr [rax + 16], 28
-	mov	ecx, 1
-	jmp	.LBB8_3
-.LBB8_2:
-	imul	rdx, qword ptr [rsi + 8]
-	imul	rdx, qword ptr [rsi]
-	mov	qword ptr [rsi], rdx
-	xor	ecx, ecx
-.LBB8_3:
-	mov	qword ptr [rax], rcx
-	ret
-.Lfunc_end8:
-
-<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::get_state:
-	mov	rax, qword ptr [rdi]
+	jne	.LBB40_2
+	lea	rax, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.45]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 28
+	mov	eax, 1
+	jmp	.LBB40_3
+.LBB40_2:
+	imul	rdx, qword ptr [rsi + 360]
+	imul	rdx, qword ptr [rsi + 296]
+	mov	qword ptr [rsi + 296], rdx
+	xor	eax, eax
+.LBB40_3:
+	mov	qword ptr [rdi], rax
 	ret
-.Lfunc_end9:
+.Lfunc_end40:
 
-<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::set_state:
-	mov	qword ptr [rdi], rsi
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::rem:
+.Lfunc_begin41:
+	test	rdx, rdx
+	je	.LBB41_3
+	mov	rcx, rdx
+	mov	rax, qword ptr [rsi + 296]
+	mov	rdx, rax
+	btc	rdx, 63
+	mov	r8, rcx
+	not	r8
+	or	r8, rdx
+	je	.LBB41_4
+	cqo
+	idiv	rcx
+	mov	qword ptr [rsi + 296], rdx
+	xor	eax, eax
+.LBB41_6:
+	mov	qword ptr [rdi], rax
 	ret
-.Lfunc_end10:
+.LBB41_3:
+	lea	rax, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.43]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 17
+	jmp	.LBB41_5
+.LBB41_4:
+	lea	rax, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.44]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 29
+.LBB41_5:
+	mov	eax, 1
+	jmp	.LBB41_6
+.Lfunc_end41:
 
-main:
-	push	rbp
-	push	r15
-	push	r14
-	push	r13
-	push	r12
-	push	rbx
-	sub	rsp, 1240
-	lea	rbx, [rsp + 48]
-	mov	qword ptr [rbx], 0
-	mov	qword ptr [rbx + 8], 1
-	lea	r14, [rsp + 200]
-	xor	ebp, ebp
-	mov	edx, 1040
-	mov	rdi, r14
-	xor	esi, esi
-	call	qword ptr [rip + memset@GOTPCREL]
-	xorps	xmm0, xmm0
-	lea	r15, [rsp + 64]
-	movaps	xmmword ptr [r15 + 112], xmm0
-	movaps	xmmword ptr [r15 + 96], xmm0
-	movaps	xmmword ptr [r15 + 80], xmm0
-	movaps	xmmword ptr [r15 + 64], xmm0
-	movaps	xmmword ptr [r15 + 48], xmm0
-	movaps	xmmword ptr [r15 + 32], xmm0
-	movaps	xmmword ptr [r15 + 16], xmm0
-	movaps	xmmword ptr [r15], xmm0
-	mov	r12, qword ptr [rip + read@GOTPCREL]
-	xor	eax, eax
-.LBB11_1:
-	xor	r13d, r13d
-.LBB11_2:
-	cmp	rax, rbp
-	jb	.LBB11_5
-	mov	edx, 1024
-	xor	edi, edi
-	mov	rsi, r14
-	call	r12
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::undo:
+.Lfunc_begin42:
+	mov	rax, qword ptr [rsi + 288]
+	lea	rcx, [rdx - 1]
+	cmp	rcx, rax
+	jae	.LBB42_2
+	mov	qword ptr [rsi], 0
+	mov	ecx, dword ptr [rsi + 280]
+	sub	ecx, edx
+	and	ecx, 15
+	mov	qword ptr [rsi + 280], rcx
+	sub	rax, rdx
+	mov	qword ptr [rsi + 288], rax
+	shl	ecx, 4
+	mov	rax, qword ptr [rsi + rcx + 24]
+	mov	rcx, qword ptr [rsi + rcx + 32]
+	mov	rdx, qword ptr [rsi + 368]
+	mov	r8, qword ptr [rsi + 376]
+	mov	qword ptr [rsi + 296], rax
+	cmp	rdx, rcx
+	cmovg	rcx, rdx
+	cmp	r8, rcx
+	cmovl	rcx, r8
+	mov	qword ptr [rsi + 360], rcx
+	jmp	.LBB42_6
+.LBB42_2:
+	test	rdx, rdx
+	je	.LBB42_6
 	test	rax, rax
-	jle	.LBB11_22
-	mov	rbp, rax
-	mov	qword ptr [rsp + 1224], rax
-	mov	qword ptr [rsp + 1232], 0
+	je	.LBB42_8
+	lea	rax, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.46]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 19
+	jmp	.LBB42_5
+.LBB42_6:
 	xor	eax, eax
-.LBB11_5:
-	cmp	rbp, rax
-	jb	.LBB11_21
-	cmp	rbp, 1024
-	ja	.LBB11_21
-	cmp	rax, rbp
-	je	.LBB11_2
-	inc	rax
-	mov	rcx, rbp
-	neg	rcx
-.LBB11_9:
-	mov	dl, byte ptr [rsp + rax + 199]
-	mov	qword ptr [rsp + 1232], rax
-	cmp	dl, 10
-	je	.LBB11_10
-	cmp	r13, 127
-	ja	.LBB11_14
-	mov	byte ptr [rsp + r13 + 64], dl
-	inc	r13
-.LBB11_14:
-	lea	rdx, [rcx + rax]
-	inc	rdx
-	inc	rax
-	cmp	rdx, 1
-	jne	.LBB11_9
-	mov	rax, rbp
-	jmp	.LBB11_2
-.LBB11_22:
-	lea	rax, [r13 - 129]
-	cmp	rax, -128
-	jb	.LBB11_21
-	jmp	.LBB11_23
-.LBB11_10:
-	cmp	r13, 128
-	ja	.LBB11_21
-	test	r13, r13
-	je	.LBB11_1
-.LBB11_23:
-	lea	rbp, [rsp + 24]
-	mov	rdi, rbp
-	mov	rsi, r15
-	mov	rdx, r13
-	call	<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::parse_command
-	cmp	byte ptr [rsp + 24], -1
-	je	.LBB11_24
-	mov	rdi, rsp
-	mov	rsi, rbx
-	mov	rdx, rbp
-	call	<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::handle
-	cmp	dword ptr [rsp], 1
-	je	.LBB11_17
-	jmp	.LBB11_25
-.LBB11_24:
-	mov	edx, 16
-	mov	edi, 1
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.7]
-	mov	r13, qword ptr [rip + write@GOTPCREL]
-	call	r13
-	mov	edx, 1
-	mov	edi, 1
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	call	r13
-.LBB11_25:
-	mov	rbp, qword ptr [rsp + 1224]
-	mov	rax, qword ptr [rsp + 1232]
-	jmp	.LBB11_1
-.LBB11_17:
-	mov	rsi, qword ptr [rsp + 8]
+	jmp	.LBB42_7
+.LBB42_8:
+	lea	rax, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.47]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 16
+.LBB42_5:
+	mov	eax, 1
+.LBB42_7:
+	mov	qword ptr [rdi], rax
+	ret
+.Lfunc_end42:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg:
+.Lfunc_begin43:
 	test	rsi, rsi
-	je	.LBB11_19
-	mov	rdx, qword ptr [rsp + 16]
-	jmp	.LBB11_20
-.LBB11_19:
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.14]
-	mov	edx, 23
-.LBB11_20:
-	mov	edi, 1
-	call	qword ptr [rip + write@GOTPCREL]
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	mov	edx, 1
-	mov	edi, 1
-	call	qword ptr [rip + write@GOTPCREL]
-.LBB11_21:
+	je	.LBB43_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB43_5
+	lea	rdi, [rdi + 8*rax]
+	add	rdi, 304
+	jmp	.LBB43_2
+.LBB43_1:
+	add	rdi, 296
+.LBB43_2:
+	mov	rax, qword ptr [rdi]
+	ret
+.LBB43_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end43:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::rollback:
+.Lfunc_begin44:
+	mov	r8, qword ptr [rsi]
+	mov	rax, qword ptr [rsi + 8]
+	mov	rcx, qword ptr [rsi + 16]
+	mov	qword ptr [rsi], 0
+	mov	rdx, qword ptr [rsi + 288]
+	test	rdx, rdx
+	je	.LBB44_4
+	mov	r9d, dword ptr [rsi + 280]
+	dec	r9d
+	and	r9d, 15
+	mov	qword ptr [rsi + 280], r9
+	lea	r10, [rdx - 1]
+	mov	qword ptr [rsi + 288], r10
+	shl	r9d, 4
+	mov	r10, qword ptr [rsi + r9 + 24]
+	mov	r11, qword ptr [rsi + r9 + 32]
+	cmp	r8, 1
+	jne	.LBB44_3
+	lea	r8, [rsi + r9]
+	add	r8, 24
+	mov	qword ptr [r8], rax
+	mov	qword ptr [r8 + 8], rcx
+	mov	qword ptr [rsi + 288], rdx
+.LBB44_3:
+	mov	qword ptr [rsi + 296], r10
+	mov	qword ptr [rsi + 360], r11
 	xor	eax, eax
-	add	rsp, 1240
-	pop	rbx
-	pop	r12
-	pop	r13
-	pop	r14
-	pop	r15
-	pop	rbp
+	jmp	.LBB44_5
+.LBB44_4:
+	lea	rax, [rip + .Lanon.17bb4e29cf56e55d7b5405b6b7c71851.50]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 21
+	mov	eax, 1
+.LBB44_5:
+	mov	qword ptr [rdi], rax
 	ret
-.Lfunc_end11:
+.Lfunc_end44:
 
-rust_eh_personality:
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::set_state:
+.Lfunc_begin45:
+	mov	qword ptr [rdi + 296], rsi
 	ret
-.Lfunc_end12:
+.Lfunc_end45:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::write_reg:
+.Lfunc_begin46:
+	test	rsi, rsi
+	je	.LBB46_1
+	mov	rax, rsi
+	cmp	rsi, 8
+	jae	.LBB46_5
+	mov	qword ptr [rdi + 8*rax + 296], rdx
+	ret
+.LBB46_1:
+	mov	qword ptr [rdi + 296], rdx
+	ret
+.LBB46_5:
+	push	rax
+	dec	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end46:
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.0:
+	.byte	10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.1:
+	.byte	59
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.2:
+	.ascii	"E 01"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.3:
+	.ascii	"E 02"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.4:
+	.ascii	"E."
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.5:
+	.ascii	" at "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.6:
+	.ascii	"E 05"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.7:
+	.ascii	"unknown option: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.8:
+	.ascii	"missing value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.9:
+	.ascii	"invalid value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.10:
+	.ascii	": "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.11:
+	.ascii	" doesn't take a value"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.12:
+	.ascii	"only one of --unix / --tcp may be given"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.0:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.14:
+	.ascii	"0123456789abcdef"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.17:
+	.ascii	"--unix"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.18:
+	.ascii	"--tcp"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.19:
+	.ascii	"--initial-state"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.20:
+	.ascii	"--on-error"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.21:
+	.ascii	"--echo"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.22:
+	.ascii	"--repl"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.23:
+	.ascii	"--help"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.24:
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.17
+	.asciz	"\006\000\000\000\000\000\000\000"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.18
+	.ascii	"\005\000\000\000\000\000\000\000\001"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.19
+	.ascii	"\017\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.20
+	.ascii	"\n\000\000\000\000\000\000\000\003"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.21
+	.ascii	"\006\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.22
+	.ascii	"\006\000\000\000\000\000\000\000\005"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.23
+	.ascii	"\006\000\000\000\000\000\000\000\006"
+	.zero	7
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.28:
+	.ascii	"Invalid implementation!"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.29:
+	.ascii	"OK"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.30:
+	.ascii	"listening on 127.0.0.1:"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.31:
+	.ascii	"error: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.32:
+	.zero	2,10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.33:
+	.ascii	"usage: optional-trait-methods [options]\n\n  --unix <path>              serve the first client to connect to a Unix domain socket\n  --tcp <port>               serve the first client to connect to 127.0.0.1:<port>\n                             (port 0 picks any free port, printed to stderr)\n                             (by default, serve stdin / stdout)\n  --initial-state <n>        start the target off in state <n> (by default, 0)\n  --on-error=continue|exit   whether to keep serving after a command fails\n  --echo                     repeat every command before its reply\n  --repl                     expect a human instead of a client (prompt, aliases, help)\n                             (needs the `repl` feature)\n  --help                     print this message\n\n  (`--name <value>` may also be written `--name=<value>`)"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.34:
 	.ascii	"panic!\n"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.1:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.35:
 	.ascii	"Parse IncDec extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.2:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.39:
 	.ascii	"Parse Mul extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.3:
-	.ascii	"* "
-
-.Lanon.cabb224323f7f456a7965482e4c09287.4:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.41:
 	.ascii	"Parse ScaleFactor extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.5:
-	.ascii	"*~ "
-
-.Lanon.cabb224323f7f456a7965482e4c09287.6:
-	.ascii	"s "
+.Lanon.62fb3b87f511695ce61fb365a19fad11.43:
+	.ascii	"Parse ScaleBounds extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.7:
-	.ascii	"Unsupported cmd!"
-
-.Lanon.cabb224323f7f456a7965482e4c09287.8:
-	.byte	10
+.Lanon.62fb3b87f511695ce61fb365a19fad11.45:
+	.ascii	"Parse Div extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.9:
-	.asciz	"%d\n"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.48:
+	.ascii	"Parse Undo extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.10:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.57:
 	.ascii	"IncDec extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.11:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.58:
 	.ascii	"Mul extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.12:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.59:
 	.ascii	"ScaleFactor extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.13:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.60:
+	.ascii	"ScaleBounds extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.61:
+	.ascii	"Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.62:
+	.ascii	"Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.63:
+	.ascii	"Undo checkpoint"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.64:
+	.ascii	"unknown command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.65:
+	.ascii	"unsupported command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.66:
+	.ascii	"bad number"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.67:
+	.ascii	"trailing garbage"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.68:
+	.ascii	"line too long"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.72:
+	.ascii	"failed to accept a connection"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.73:
+	.ascii	"--repl needs the `repl` feature"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.0:
+	.ascii	"incdec"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.1:
+	.ascii	"mul"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.2:
+	.ascii	"mul.scale_factor"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.3:
+	.ascii	"mul.scale_factor.scale_bounds"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.4:
+	.ascii	"div"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.5:
+	.ascii	"undo"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.6:
+	.byte	1
+	.zero	7
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.0
+	.ascii	"\006\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.1
+	.ascii	"\003\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.2
+	.ascii	"\020\000\000\000\000\000\000\000\b"
+	.zero	7
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.3
+	.ascii	"\035\000\000\000\000\000\000\000\020"
+	.zero	7
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.4
+	.ascii	"\003\000\000\000\000\000\000\000 "
+	.zero	7
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.5
+	.asciz	"\004\000\000\000\000\000\000"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.15:
+	.byte	43
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.17:
+	.byte	45
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.18:
+	.byte	42
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.20:
+	.ascii	"*~"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.28:
+	.ascii	"+-"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.29:
+	.ascii	"*~~"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.30:
+	.byte	47
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.31:
+	.byte	37
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.32:
+	.byte	117
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.33:
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.15
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.17
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.28
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.18
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.20
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.29
+	.asciz	"\003\000\000\000\000\000\000"
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.30
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.31
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.32
+	.asciz	"\001\000\000\000\000\000\000"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.42:
+	.ascii	"scale bounds are inverted!"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.43:
+	.ascii	"dividing by zero!"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.44:
+	.ascii	"dividing MIN by -1 overflows!"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.45:
 	.ascii	"multiplying by 7 is unlucky!"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.14:
-	.ascii	"Invalid implementation!"
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.46:
+	.ascii	"not enough history!"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.47:
+	.ascii	"nothing to undo!"
+
+.Lanon.17bb4e29cf56e55d7b5405b6b7c71851.50:
+	.ascii	"nothing to roll back!"
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+
+	.byte	1
+	.byte	17
+	.byte	1
+	.byte	37
+	.byte	14
+	.byte	19
+	.byte	5
+	.byte	3
+	.byte	14
+	.byte	16
+	.byte	23
+	.byte	27
+	.byte	14
+	.byte	17
+	.byte	1
+	.byte	85
+	.byte	23
+	.byte	0
+	.byte	0
+	.byte	2
+	.byte	57
+	.byte	1
+	.byte	3
+	.byte	14
+	.byte	0
+	.byte	0
+	.byte	3
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	11
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	4
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	5
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	0
+.Lcu_begin0:
+	.long	.Ldebug_info_end0-.Ldebug_info_start0
+.Ldebug_info_start0:
+	.short	4
+	.long	.debug_abbrev
+	.byte	8
+	.byte	1
+	.long	.Linfo_string0
+	.short	28
+	.long	.Linfo_string1
+	.long	.Lline_table_start0
+	.long	.Linfo_string2
+	.quad	0
+	.long	.Ldebug_ranges0
+	.byte	2
+	.long	.Linfo_string3
+	.byte	2
+	.long	.Linfo_string4
+	.byte	3
+	.quad	.Lfunc_begin19
+	.long	.Lfunc_end19-.Lfunc_begin19
+	.byte	1
+	.byte	86
+	.long	.Linfo_string7
+	.long	.Linfo_string8
+	.byte	1
+	.byte	60
+	.byte	3
+
+
+	.byte	4
+	.quad	.Lfunc_begin20
+	.long	.Lfunc_end20-.Lfunc_begin20
+	.byte	1
+	.byte	86
+	.long	.Linfo_string9
+	.long	.Linfo_string10
+	.byte	1
+	.short	266
+	.byte	3
+
+
+	.byte	0
+	.byte	2
+	.long	.Linfo_string5
+	.byte	2
+	.long	.Linfo_string6
+	.byte	3
+	.quad	.Lfunc_begin21
+	.long	.Lfunc_end21-.Lfunc_begin21
+	.byte	1
+	.byte	86
+	.long	.Linfo_string11
+	.long	.Linfo_string12
+	.byte	2
+	.byte	38
+	.byte	3
+
 
-	.ident	"rustc version 1.99.0-nightly (6f72b5dd5 2026-07-22)"
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+.Ldebug_info_end0:
+.Lsec_end0:
+.Lsec_end1:
+.Lsec_end2:
+	.long	76
+	.short	2
+	.long	.Lcu_begin0
+	.byte	8
+	.byte	0
+	.zero	4,255
+	.quad	.Lfunc_begin19
+	.quad	.Lsec_end0-.Lfunc_begin19
+	.quad	.Lfunc_begin20
+	.quad	.Lsec_end1-.Lfunc_begin20
+	.quad	.Lfunc_begin21
+	.quad	.Lsec_end2-.Lfunc_begin21
+	.quad	0
+	.quad	0
+.Ldebug_ranges0:
+	.quad	.Lfunc_begin19
+	.quad	.Lfunc_end19
+	.quad	.Lfunc_begin20
+	.quad	.Lfunc_end20
+	.quad	.Lfunc_begin21
+	.quad	.Lfunc_end21
+	.quad	0
+	.quad	0
+.Linfo_string0:
+	.asciz	"clang LLVM (rustc version 1.97.0-nightly (e50aa6fba 2026-05-19))"
+.Linfo_string1:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a/library/core/src/lib.rs/@/core.667c7a611d73a360-cgu.0"
+.Linfo_string2:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a"
+.Linfo_string3:
+	.asciz	"core"
+.Linfo_string4:
+	.asciz	"panicking"
+.Linfo_string5:
+	.asciz	"slice"
+.Linfo_string6:
+	.asciz	"index"
+.Linfo_string7:
+	.asciz	"core::panicking::panic_fmt"
+.Linfo_string8:
+	.asciz	"panic_fmt"
+.Linfo_string9:
+	.asciz	"core::panicking::panic_bounds_check"
+.Linfo_string10:
+	.asciz	"panic_bounds_check"
+.Linfo_string11:
+	.asciz	"core::slice::index::slice_index_fail"
+.Linfo_string12:
+	.asciz	"slice_index_fail"
+	.ident	"rustc version 1.97.0-nightly (e50aa6fba 2026-05-19)"
+.Lline_table_start0:
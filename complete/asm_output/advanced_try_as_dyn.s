@@ -1,477 +1,5229 @@
-__rustc::rust_begin_unwind:
-	push	rax
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.0]
-	mov	edx, 7
-	mov	edi, 2
-	call	qword ptr [rip + write@GOTPCREL]
-	call	qword ptr [rip + abort@GOTPCREL]
+<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin0:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 248
+	mov	r12, rcx
+	mov	rbx, rdx
+	mov	r15, rsi
+	movzx	r13d, byte ptr [rcx]
+	lea	rax, [r13 - 6]
+	xor	ebp, ebp
+	cmp	r13, 7
+	cmovae	rbp, rax
+	mov	r14, rdi
+	lea	rax, [rip + .LJTI0_0]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB0_1:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint
+	lea	rax, [rip + .LJTI0_1]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB0_2:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI0_2]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB0_3:
+	mov	rax, qword ptr [r15 + 296]
+	jmp	.LBB0_52
+.LBB0_4:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 14
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::undo
+	test	rax, rax
+	je	.LBB0_48
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], rdx
+	jmp	.LBB0_11
+.LBB0_6:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI0_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB0_7:
+	mov	rax, qword ptr [r15 + 296]
+	jmp	.LBB0_35
+.LBB0_8:
+	mov	r13, qword ptr [r12 + 8]
+	cmp	r13, 2
+	jne	.LBB0_13
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdx, qword ptr [r12 + 24]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleBounds>::scale_bounds
+	test	rax, rax
+	je	.LBB0_48
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 26
+	jmp	.LBB0_11
+.LBB0_12:
+	mov	r13, qword ptr [r12 + 8]
+	jmp	.LBB0_14
+.LBB0_13:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint
+.LBB0_14:
+	test	r13, r13
+	je	.LBB0_22
+	cmp	r13d, 1
+	jne	.LBB0_23
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleFactor>::scale_factor
+	jmp	.LBB0_63
+.LBB0_17:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	cmp	dword ptr [r12 + 8], 1
+	jne	.LBB0_24
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtDiv>::rem
+	jmp	.LBB0_25
+.LBB0_19:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 16
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [r12 + 1]
+	cmp	eax, 2
+	je	.LBB0_30
+	cmp	eax, 1
+	je	.LBB0_31
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc
+	jmp	.LBB0_63
+.LBB0_22:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtMul>::mul
+	jmp	.LBB0_25
+.LBB0_23:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdx, qword ptr [r12 + 24]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleBounds>::scale_bounds
+	jmp	.LBB0_25
+.LBB0_24:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtDiv>::div
+.LBB0_25:
+	test	rax, rax
+	je	.LBB0_63
+	mov	r12, rax
+	mov	r13, rdx
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::rollback
+	test	rax, rax
+	je	.LBB0_28
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 21
+.LBB0_11:
+	mov	byte ptr [rsp], -1
+	jmp	.LBB0_67
+.LBB0_28:
+	mov	cl, -1
+	xor	eax, eax
+	jmp	.LBB0_29
+.LBB0_30:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc
+.LBB0_31:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::dec
+	jmp	.LBB0_63
+.LBB0_32:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::write_reg
+	jmp	.LBB0_48
+.LBB0_33:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	byte ptr [rsp], 2
+	mov	byte ptr [rsp + 1], cl
+	jmp	.LBB0_67
+.LBB0_34:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+.LBB0_35:
+	mov	byte ptr [rsp], 1
+	mov	qword ptr [rsp + 8], rax
+	jmp	.LBB0_67
+.LBB0_36:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint
+.LBB0_37:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::set_state
+	jmp	.LBB0_63
+.LBB0_38:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 96], xmm0
+	movaps	xmmword ptr [rsp + 112], xmm0
+	movaps	xmmword ptr [rsp + 128], xmm0
+	mov	qword ptr [rsp + 144], 0
+	xor	r12d, r12d
+	xor	r13d, r13d
+.LBB0_39:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	r13, 7
+	ja	.LBB0_41
+	mov	qword ptr [rsp + 8*r13 + 80], rax
+	mov	r13, qword ptr [rsp + 144]
+	inc	r13
+	mov	qword ptr [rsp + 144], r13
+.LBB0_41:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB0_39
+	lea	rdi, [rsp + 167]
+	lea	rsi, [rsp + 80]
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	mov	edx, 72
+	call	r15
+	lea	rdi, [rsp + 1]
+	mov	byte ptr [rdi - 1], 3
+	lea	rsi, [rsp + 160]
+	mov	edx, 79
+	call	r15
+	jmp	.LBB0_67
+.LBB0_43:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	movaps	xmmword ptr [rsp], xmm0
+	mov	qword ptr [rsp + 64], 0
+	xor	r13d, r13d
+	xor	ebp, ebp
+.LBB0_44:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	rbp, 7
+	ja	.LBB0_46
+	mov	qword ptr [rsp + 8*rbp], rax
+	mov	rbp, qword ptr [rsp + 64]
+	inc	rbp
+	mov	qword ptr [rsp + 64], rbp
+.LBB0_46:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB0_44
+	add	r12, 8
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB0_48:
+	mov	byte ptr [rsp], 0
+	jmp	.LBB0_67
+.LBB0_49:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::write_reg
+	jmp	.LBB0_63
+.LBB0_50:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	edx, 63
+	cmovne	edx, eax
+	mov	cl, 2
+	jmp	.LBB0_64
+.LBB0_51:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+.LBB0_52:
+	mov	cl, 1
+	jmp	.LBB0_65
+.LBB0_53:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	movaps	xmmword ptr [rsp], xmm0
+	mov	qword ptr [rsp + 64], 0
+	xor	r12d, r12d
+	xor	r13d, r13d
+.LBB0_54:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	r13, 7
+	ja	.LBB0_56
+	mov	qword ptr [rsp + 8*r13], rax
+	mov	r13, qword ptr [rsp + 64]
+	inc	r13
+	mov	qword ptr [rsp + 64], r13
+.LBB0_56:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB0_54
+	mov	rax, qword ptr [rsp]
+	mov	r12, qword ptr [rsp + 8]
+	mov	r13, qword ptr [rsp + 16]
+	movups	xmm0, xmmword ptr [rsp + 24]
+	movaps	xmmword ptr [rsp + 160], xmm0
+	movups	xmm0, xmmword ptr [rsp + 40]
+	movaps	xmmword ptr [rsp + 176], xmm0
+	movups	xmm0, xmmword ptr [rsp + 56]
+	movaps	xmmword ptr [rsp + 192], xmm0
+	mov	cl, 3
+.LBB0_29:
+	jmp	.LBB0_66
+.LBB0_58:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	movaps	xmmword ptr [rsp], xmm0
+	mov	qword ptr [rsp + 64], 0
+	xor	r13d, r13d
+	xor	ebp, ebp
+.LBB0_59:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	rbp, 7
+	ja	.LBB0_61
+	mov	qword ptr [rsp + 8*rbp], rax
+	mov	rbp, qword ptr [rsp + 64]
+	inc	rbp
+	mov	qword ptr [rsp + 64], rbp
+.LBB0_61:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB0_59
+	add	r12, 8
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB0_63:
+	xor	ecx, ecx
+.LBB0_64:
+.LBB0_65:
+.LBB0_66:
+	mov	byte ptr [rsp], cl
+	mov	byte ptr [rsp + 1], dl
+	mov	qword ptr [rsp + 8], rax
+	mov	qword ptr [rsp + 16], r12
+	mov	qword ptr [rsp + 24], r13
+	movaps	xmm0, xmmword ptr [rsp + 160]
+	movaps	xmm1, xmmword ptr [rsp + 176]
+	movaps	xmm2, xmmword ptr [rsp + 192]
+	movups	xmmword ptr [rsp + 32], xmm0
+	movups	xmmword ptr [rsp + 48], xmm1
+	movups	xmmword ptr [rsp + 64], xmm2
+.LBB0_67:
+	mov	rdx, rsp
+	mov	rdi, r14
+	mov	rsi, rbx
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	add	rsp, 248
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
 .Lfunc_end0:
+.LJTI0_0:
+	.long	.LBB0_6-.LJTI0_0
+	.long	.LBB0_1-.LJTI0_0
+	.long	.LBB0_8-.LJTI0_0
+	.long	.LBB0_1-.LJTI0_0
+	.long	.LBB0_4-.LJTI0_0
+.LJTI0_1:
+	.long	.LBB0_2-.LJTI0_1
+	.long	.LBB0_19-.LJTI0_1
+	.long	.LBB0_12-.LJTI0_1
+	.long	.LBB0_17-.LJTI0_1
+.LJTI0_2:
+	.long	.LBB0_3-.LJTI0_2
+	.long	.LBB0_37-.LJTI0_2
+	.long	.LBB0_50-.LJTI0_2
+	.long	.LBB0_51-.LJTI0_2
+	.long	.LBB0_49-.LJTI0_2
+	.long	.LBB0_53-.LJTI0_2
+	.long	.LBB0_58-.LJTI0_2
+.LJTI0_3:
+	.long	.LBB0_7-.LJTI0_3
+	.long	.LBB0_36-.LJTI0_3
+	.long	.LBB0_33-.LJTI0_3
+	.long	.LBB0_34-.LJTI0_3
+	.long	.LBB0_32-.LJTI0_3
+	.long	.LBB0_38-.LJTI0_3
+	.long	.LBB0_43-.LJTI0_3
 
-<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::parse_command:
+<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin1:
+	push	rbp
 	push	r15
 	push	r14
+	push	r13
+	push	r12
 	push	rbx
-	sub	rsp, 16
-	mov	r15, rdx
-	mov	r14, rsi
-	mov	rbx, rdi
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.1]
+	sub	rsp, 248
+	mov	r12, rcx
+	mov	rbx, rdx
+	mov	r15, rsi
+	movzx	r13d, byte ptr [rcx]
+	lea	rax, [r13 - 6]
+	xor	ebp, ebp
+	cmp	r13, 7
+	cmovae	rbp, rax
+	mov	r14, rdi
+	lea	rax, [rip + .LJTI1_0]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB1_1:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
 	mov	rcx, rsp
 	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 22
+	mov	qword ptr [rcx + 8], 15
 	#APP
 	#NO_APP
-	cmp	rdx, 2
-	je	.LBB1_7
-	cmp	r15, 1
-	jne	.LBB1_8
-	movzx	eax, byte ptr [r14]
-	cmp	eax, 45
-	je	.LBB1_5
-	cmp	eax, 43
-	jne	.LBB1_8
-	mov	word ptr [rbx], 1
-	jmp	.LBB1_13
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint
+	lea	rax, [rip + .LJTI1_1]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB1_2:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI1_2]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB1_3:
+	mov	rax, qword ptr [r15 + 296]
+	jmp	.LBB1_52
+.LBB1_4:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 14
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::undo
+	test	rax, rax
+	je	.LBB1_48
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], rdx
+	jmp	.LBB1_11
+.LBB1_6:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI1_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
 .LBB1_7:
-	cmp	word ptr [r14], 11563
-	jne	.LBB1_8
-	mov	word ptr [rbx], 513
-	jmp	.LBB1_13
+	mov	rax, qword ptr [r15 + 296]
+	jmp	.LBB1_35
 .LBB1_8:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.2]
+	mov	r13, qword ptr [r12 + 8]
+	cmp	r13, 2
+	jne	.LBB1_13
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
 	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 19
+	mov	qword ptr [rcx + 8], 28
 	#APP
 	#NO_APP
-	cmp	r15, 2
-	jb	.LBB1_14
-	cmp	word ptr [r14], 8234
-	jne	.LBB1_14
-	lea	rsi, [r15 - 2]
-	lea	rdi, [r14 + 2]
-	call	optional_trait_methods::commands::parse_isize
-	cmp	rax, 1
-	jne	.LBB1_14
-	mov	byte ptr [rbx], 2
-	mov	qword ptr [rbx + 8], 0
-	jmp	.LBB1_12
-.LBB1_14:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.4]
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdx, qword ptr [r12 + 24]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleBounds>::scale_bounds
+	test	rax, rax
+	je	.LBB1_48
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 26
+	jmp	.LBB1_11
+.LBB1_12:
+	mov	r13, qword ptr [r12 + 8]
+	jmp	.LBB1_14
+.LBB1_13:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
 	mov	rcx, rsp
 	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 27
+	mov	qword ptr [rcx + 8], 15
 	#APP
 	#NO_APP
-	cmp	r15, 3
-	jb	.LBB1_19
-	movzx	eax, word ptr [r14]
-	xor	eax, 32298
-	movzx	ecx, byte ptr [r14 + 2]
-	xor	ecx, 32
-	or	cx, ax
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint
+.LBB1_14:
+	test	r13, r13
+	je	.LBB1_22
+	cmp	r13d, 1
 	jne	.LBB1_23
-	lea	rsi, [r15 - 3]
-	lea	rdi, [r14 + 3]
-	call	optional_trait_methods::commands::parse_isize
-	test	al, 1
-	je	.LBB1_23
-	mov	byte ptr [rbx], 2
-	jmp	.LBB1_18
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleFactor>::scale_factor
+	jmp	.LBB1_63
+.LBB1_17:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	cmp	dword ptr [r12 + 8], 1
+	jne	.LBB1_24
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtDiv>::rem
+	jmp	.LBB1_25
 .LBB1_19:
-	cmp	r15, 2
-	je	.LBB1_23
-	cmp	r15, 1
-	jne	.LBB1_26
-	cmp	byte ptr [r14], 112
-	jne	.LBB1_26
-	mov	byte ptr [rbx], 0
-	mov	qword ptr [rbx + 8], 0
-	jmp	.LBB1_13
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 16
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [r12 + 1]
+	cmp	eax, 2
+	je	.LBB1_30
+	cmp	eax, 1
+	je	.LBB1_31
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc
+	jmp	.LBB1_63
+.LBB1_22:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtMul>::mul
+	jmp	.LBB1_25
 .LBB1_23:
-	cmp	word ptr [r14], 8307
-	jne	.LBB1_26
-	add	r15, -2
-	add	r14, 2
-	mov	rdi, r14
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdx, qword ptr [r12 + 24]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleBounds>::scale_bounds
+	jmp	.LBB1_25
+.LBB1_24:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtDiv>::div
+.LBB1_25:
+	test	rax, rax
+	je	.LBB1_63
+	mov	r12, rax
+	mov	r13, rdx
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::rollback
+	test	rax, rax
+	je	.LBB1_28
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 21
+.LBB1_11:
+	mov	byte ptr [rsp], -1
+	jmp	.LBB1_67
+.LBB1_28:
+	mov	cl, -1
+	xor	eax, eax
+	jmp	.LBB1_29
+.LBB1_30:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc
+.LBB1_31:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::dec
+	jmp	.LBB1_63
+.LBB1_32:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::write_reg
+	jmp	.LBB1_48
+.LBB1_33:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	byte ptr [rsp], 2
+	mov	byte ptr [rsp + 1], cl
+	jmp	.LBB1_67
+.LBB1_34:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+.LBB1_35:
+	mov	byte ptr [rsp], 1
+	mov	qword ptr [rsp + 8], rax
+	jmp	.LBB1_67
+.LBB1_36:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint
+.LBB1_37:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::set_state
+	jmp	.LBB1_63
+.LBB1_38:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 96], xmm0
+	movaps	xmmword ptr [rsp + 112], xmm0
+	movaps	xmmword ptr [rsp + 128], xmm0
+	mov	qword ptr [rsp + 144], 0
+	xor	r12d, r12d
+	xor	r13d, r13d
+.LBB1_39:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	r13, 7
+	ja	.LBB1_41
+	mov	qword ptr [rsp + 8*r13 + 80], rax
+	mov	r13, qword ptr [rsp + 144]
+	inc	r13
+	mov	qword ptr [rsp + 144], r13
+.LBB1_41:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB1_39
+	lea	rdi, [rsp + 167]
+	lea	rsi, [rsp + 80]
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	mov	edx, 72
+	call	r15
+	lea	rdi, [rsp + 1]
+	mov	byte ptr [rdi - 1], 3
+	lea	rsi, [rsp + 160]
+	mov	edx, 79
+	call	r15
+	jmp	.LBB1_67
+.LBB1_43:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	movaps	xmmword ptr [rsp], xmm0
+	mov	qword ptr [rsp + 64], 0
+	xor	r13d, r13d
+	xor	ebp, ebp
+.LBB1_44:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	rbp, 7
+	ja	.LBB1_46
+	mov	qword ptr [rsp + 8*rbp], rax
+	mov	rbp, qword ptr [rsp + 64]
+	inc	rbp
+	mov	qword ptr [rsp + 64], rbp
+.LBB1_46:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB1_44
+	add	r12, 8
+	mov	rdi, r12
 	mov	rsi, r15
-	call	optional_trait_methods::commands::parse_isize
-	test	al, 1
-	je	.LBB1_26
-	mov	byte ptr [rbx], 0
-.LBB1_18:
-	mov	qword ptr [rbx + 8], 1
-.LBB1_12:
-	mov	qword ptr [rbx + 16], rdx
-	jmp	.LBB1_13
-.LBB1_26:
-	mov	byte ptr [rbx], -1
-	jmp	.LBB1_13
-.LBB1_5:
-	mov	word ptr [rbx], 257
-.LBB1_13:
-	mov	rax, rbx
-	add	rsp, 16
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB1_48:
+	mov	byte ptr [rsp], 0
+	jmp	.LBB1_67
+.LBB1_49:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::write_reg
+	jmp	.LBB1_63
+.LBB1_50:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	edx, 63
+	cmovne	edx, eax
+	mov	cl, 2
+	jmp	.LBB1_64
+.LBB1_51:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+.LBB1_52:
+	mov	cl, 1
+	jmp	.LBB1_65
+.LBB1_53:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	movaps	xmmword ptr [rsp], xmm0
+	mov	qword ptr [rsp + 64], 0
+	xor	r12d, r12d
+	xor	r13d, r13d
+.LBB1_54:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	r13, 7
+	ja	.LBB1_56
+	mov	qword ptr [rsp + 8*r13], rax
+	mov	r13, qword ptr [rsp + 64]
+	inc	r13
+	mov	qword ptr [rsp + 64], r13
+.LBB1_56:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB1_54
+	mov	rax, qword ptr [rsp]
+	mov	r12, qword ptr [rsp + 8]
+	mov	r13, qword ptr [rsp + 16]
+	movups	xmm0, xmmword ptr [rsp + 24]
+	movaps	xmmword ptr [rsp + 160], xmm0
+	movups	xmm0, xmmword ptr [rsp + 40]
+	movaps	xmmword ptr [rsp + 176], xmm0
+	movups	xmm0, xmmword ptr [rsp + 56]
+	movaps	xmmword ptr [rsp + 192], xmm0
+	mov	cl, 3
+.LBB1_29:
+	jmp	.LBB1_66
+.LBB1_58:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	movaps	xmmword ptr [rsp], xmm0
+	mov	qword ptr [rsp + 64], 0
+	xor	r13d, r13d
+	xor	ebp, ebp
+.LBB1_59:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	rbp, 7
+	ja	.LBB1_61
+	mov	qword ptr [rsp + 8*rbp], rax
+	mov	rbp, qword ptr [rsp + 64]
+	inc	rbp
+	mov	qword ptr [rsp + 64], rbp
+.LBB1_61:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB1_59
+	add	r12, 8
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB1_63:
+	xor	ecx, ecx
+.LBB1_64:
+.LBB1_65:
+.LBB1_66:
+	mov	byte ptr [rsp], cl
+	mov	byte ptr [rsp + 1], dl
+	mov	qword ptr [rsp + 8], rax
+	mov	qword ptr [rsp + 16], r12
+	mov	qword ptr [rsp + 24], r13
+	movaps	xmm0, xmmword ptr [rsp + 160]
+	movaps	xmm1, xmmword ptr [rsp + 176]
+	movaps	xmm2, xmmword ptr [rsp + 192]
+	movups	xmmword ptr [rsp + 32], xmm0
+	movups	xmmword ptr [rsp + 48], xmm1
+	movups	xmmword ptr [rsp + 64], xmm2
+.LBB1_67:
+	mov	rdx, rsp
+	mov	rdi, r14
+	mov	rsi, rbx
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	add	rsp, 248
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end1:
+.LJTI1_0:
+	.long	.LBB1_6-.LJTI1_0
+	.long	.LBB1_1-.LJTI1_0
+	.long	.LBB1_8-.LJTI1_0
+	.long	.LBB1_1-.LJTI1_0
+	.long	.LBB1_4-.LJTI1_0
+.LJTI1_1:
+	.long	.LBB1_2-.LJTI1_1
+	.long	.LBB1_19-.LJTI1_1
+	.long	.LBB1_12-.LJTI1_1
+	.long	.LBB1_17-.LJTI1_1
+.LJTI1_2:
+	.long	.LBB1_3-.LJTI1_2
+	.long	.LBB1_37-.LJTI1_2
+	.long	.LBB1_50-.LJTI1_2
+	.long	.LBB1_51-.LJTI1_2
+	.long	.LBB1_49-.LJTI1_2
+	.long	.LBB1_53-.LJTI1_2
+	.long	.LBB1_58-.LJTI1_2
+.LJTI1_3:
+	.long	.LBB1_7-.LJTI1_3
+	.long	.LBB1_36-.LJTI1_3
+	.long	.LBB1_33-.LJTI1_3
+	.long	.LBB1_34-.LJTI1_3
+	.long	.LBB1_32-.LJTI1_3
+	.long	.LBB1_38-.LJTI1_3
+	.long	.LBB1_43-.LJTI1_3
+
+<optional_trait_methods::commands::Args>::num::<isize>:
+.Lfunc_begin2:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<isize>
+	and	al, 1
+	cmove	rdx, r15
+	neg	al
+	or	al, 2
+	mov	qword ptr [rbx], rdx
+	mov	byte ptr [rbx + 8], al
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end2:
+
+<optional_trait_methods::commands::Args>::regs::<isize>:
+.Lfunc_begin3:
+	push	r14
+	push	rbx
+	sub	rsp, 88
+	mov	rbx, rdi
+	lea	r14, [rsp + 16]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	rax, qword ptr [r14]
+	cmp	qword ptr [r14 + 16], 128
+	jne	.LBB3_20
+	mov	rcx, qword ptr [rsp + 24]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 80], 0
+	mov	edx, 128
+	xor	esi, esi
+.LBB3_2:
+	mov	rdi, rcx
+	add	rcx, 16
+	add	rdx, -16
+	movaps	xmmword ptr [rsp], xmm0
+	xor	r8d, r8d
+.LBB3_3:
+	mov	r10b, byte ptr [rdi + 2*r8]
+	lea	r9d, [r10 - 48]
+	cmp	r9b, 10
+	jb	.LBB3_13
+	lea	r9d, [r10 - 97]
+	cmp	r9b, 5
+	ja	.LBB3_10
+	add	r10b, -87
+	jmp	.LBB3_12
+.LBB3_10:
+	lea	r9d, [r10 - 71]
+	cmp	r9b, -6
+	jb	.LBB3_20
+	add	r10b, -55
+.LBB3_12:
+	mov	r9d, r10d
+.LBB3_13:
+	mov	r10b, byte ptr [rdi + 2*r8 + 1]
+	lea	r11d, [r10 - 48]
+	cmp	r11b, 10
+	jb	.LBB3_19
+	lea	r11d, [r10 - 97]
+	cmp	r11b, 5
+	ja	.LBB3_16
+	add	r10b, -87
+	jmp	.LBB3_18
+.LBB3_16:
+	lea	r11d, [r10 - 71]
+	cmp	r11b, -6
+	jb	.LBB3_20
+	add	r10b, -55
+.LBB3_18:
+	mov	r11d, r10d
+.LBB3_19:
+	shl	r9b, 4
+	or	r9b, r11b
+	mov	byte ptr [rsp + r8], r9b
+	inc	r8
+	cmp	r8, 8
+	jne	.LBB3_3
+	cmp	rsi, 7
+	ja	.LBB3_8
+	mov	rdi, qword ptr [rsp]
+	mov	qword ptr [rsp + 8*rsi + 16], rdi
+	mov	rsi, qword ptr [rsp + 80]
+	inc	rsi
+	mov	qword ptr [rsp + 80], rsi
+.LBB3_8:
+	test	rdx, rdx
+	jne	.LBB3_2
+	lea	rdi, [rbx + 8]
+	lea	rsi, [rsp + 16]
+	mov	ecx, 9
+	rep movsq es:[rdi], [rsi]
+	xor	eax, eax
+	jmp	.LBB3_21
+.LBB3_20:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB3_21:
+	mov	qword ptr [rbx], rax
+	add	rsp, 88
+	pop	rbx
+	pop	r14
+	ret
+.Lfunc_end3:
+
+<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>:
+.Lfunc_begin4:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rax, qword ptr [rdi + 64]
+	xor	r15d, r15d
+	cmp	rax, 9
+	mov	r12d, 8
+	cmovb	r12, rdi
+	lea	rax, [8*rax]
+	cmovb	r15, rax
+	test	r15, r15
+	je	.LBB4_3
+	mov	rbx, rsi
+	xor	r14d, r14d
+.LBB4_2:
+	mov	rdx, qword ptr [r12 + 8*r14]
+	mov	rdi, rbx
+	mov	rsi, r14
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::write_reg
+	inc	r14
+	add	r15, -8
+	jne	.LBB4_2
+.LBB4_3:
+	add	rsp, 8
 	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end4:
+
+optional_trait_methods::num::format_decimal::<isize>:
+.Lfunc_begin5:
+	push	rax
+	mov	eax, 39
+	movabs	r9, 7378697629483820647
+	mov	rcx, rdi
+.LBB5_1:
+	cmp	rax, 39
+	ja	.LBB5_7
+	mov	r8, rax
+	mov	rax, rcx
+	imul	r9
+	mov	rax, rdx
+	shr	rax, 63
+	sar	rdx, 2
+	add	rdx, rax
+	lea	rax, [rdx + rdx]
+	lea	rax, [rax + 4*rax]
+	mov	r10, rcx
+	sub	r10, rax
+	mov	rax, r10
+	neg	rax
+	cmovs	rax, r10
+	add	al, 48
+	mov	byte ptr [rsi + r8], al
+	add	rcx, 9
+	lea	rax, [r8 - 1]
+	cmp	rcx, 19
+	mov	rcx, rdx
+	jae	.LBB5_1
+	test	rdi, rdi
+	jns	.LBB5_6
+	inc	r8
+	add	r8, -2
+	cmp	r8, 39
+	ja	.LBB5_7
+	mov	byte ptr [rsi + r8], 45
+.LBB5_6:
+	mov	edx, 40
+	sub	rdx, r8
+	add	rsi, r8
+	mov	rax, rsi
+	pop	rcx
+	ret
+.LBB5_7:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end5:
+
+optional_trait_methods::num::format_decimal::<usize>:
+.Lfunc_begin6:
+	mov	eax, 39
+	movabs	r8, -3689348814741910323
+.LBB6_1:
+	cmp	rax, 39
+	ja	.LBB6_4
+	mov	rcx, rax
+	mov	rax, rdi
+	mul	r8
+	shr	rdx, 3
+	lea	eax, [rdx + rdx]
+	lea	eax, [rax + 4*rax]
+	mov	r9d, edi
+	sub	r9d, eax
+	or	r9b, 48
+	mov	byte ptr [rsi + rcx], r9b
+	lea	rax, [rcx - 1]
+	cmp	rdi, 10
+	mov	rdi, rdx
+	jae	.LBB6_1
+	add	rsi, rcx
+	inc	rcx
+	mov	edx, 41
+	sub	rdx, rcx
+	mov	rax, rsi
+	ret
+.LBB6_4:
+	push	rax
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end6:
+
+optional_trait_methods::num::parse_num::<u16>:
+.Lfunc_begin7:
+	test	rsi, rsi
+	je	.LBB7_54
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB7_4
+	cmp	ecx, 45
+	jne	.LBB7_6
+	mov	cl, 1
+	jmp	.LBB7_5
+.LBB7_4:
+	xor	ecx, ecx
+.LBB7_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB7_7
+.LBB7_6:
+	xor	ecx, ecx
+.LBB7_7:
+	cmp	rsi, 1
+	jbe	.LBB7_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB7_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB7_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB7_29
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB7_28
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB7_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB7_31
+.LBB7_14:
+	test	rsi, rsi
+	je	.LBB7_54
+	mov	al, byte ptr [rdi]
+.LBB7_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB7_54
+.LBB7_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB7_19
+	cmp	byte ptr [rax], 95
+	je	.LBB7_54
+.LBB7_19:
+	xor	r9d, r9d
+	mov	r8w, 10
+	xor	edx, edx
+.LBB7_20:
+	cmp	rdi, rsi
+	je	.LBB7_49
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB7_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB7_20
+	jmp	.LBB7_55
+.LBB7_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB7_54
+	mov	eax, edx
+	mul	r8w
+	jo	.LBB7_54
+	mov	edx, eax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB7_27
+	sub	dx, ax
+	jae	.LBB7_20
+	jmp	.LBB7_54
+.LBB7_27:
+	add	dx, ax
+	jae	.LBB7_20
+	jmp	.LBB7_54
+.LBB7_28:
+	mov	r8b, 8
+	jmp	.LBB7_30
+.LBB7_29:
+	mov	r8b, 2
+.LBB7_30:
+	xor	r9d, r9d
+.LBB7_31:
+	cmp	rsi, 2
+	je	.LBB7_54
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB7_51
+.LBB7_33:
+	cmp	dl, r8b
+	jae	.LBB7_54
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB7_36
+	cmp	byte ptr [rax], 95
+	je	.LBB7_54
+.LBB7_36:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB7_37:
+	cmp	r10, rdi
+	je	.LBB7_49
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB7_40
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	jne	.LBB7_37
+	jmp	.LBB7_55
+.LBB7_40:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB7_44
+	test	r9b, r9b
+	je	.LBB7_54
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB7_54
+	add	al, -87
+	mov	r11d, eax
+.LBB7_44:
+	cmp	r11b, r8b
+	jae	.LBB7_54
+	mov	eax, edx
+	mul	si
+	jo	.LBB7_54
+	mov	edx, eax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB7_48
+	sub	dx, ax
+	jae	.LBB7_37
+	jmp	.LBB7_54
+.LBB7_48:
+	add	dx, ax
+	jae	.LBB7_37
+	jmp	.LBB7_54
+.LBB7_49:
+	mov	ax, 1
+	ret
+.LBB7_51:
+	test	r9b, r9b
+	je	.LBB7_54
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB7_54
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB7_33
+.LBB7_54:
+	xor	eax, eax
+.LBB7_55:
+	ret
+.Lfunc_end7:
+
+optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin8:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB8_2
+	lea	rcx, [rip + .LJTI8_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB8_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.21]
+	mov	edx, 2
+	jmp	.LBB8_5
+.LBB8_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.5da3227578d25710a3a77221381aa472.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB8_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB8_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB8_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB8_18
+.LBB8_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB8_15
+	jmp	.LBB8_28
+.LBB8_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB8_14
+	jmp	.LBB8_28
+.LBB8_19:
+	lea	r14, [rbx + 8]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 72]
+	cmp	rcx, 9
+	mov	eax, 8
+	cmovb	rax, r14
+	lea	rcx, [8*rcx]
+	cmovae	rcx, r12
+	test	rcx, rcx
+	je	.LBB8_26
+	add	rcx, rax
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+.LBB8_21:
+	mov	qword ptr [rsp + 24], 0
+	mov	rsi, qword ptr [rax]
+	add	rax, 8
+	mov	qword ptr [rsp + 16], rsi
+	xor	esi, esi
+.LBB8_22:
+	cmp	r12, 256
+	jae	.LBB8_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB8_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 8
+	jne	.LBB8_22
+	cmp	rax, rcx
+	jne	.LBB8_21
+.LBB8_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB8_27
+.LBB8_11:
+	mov	rdi, qword ptr [rbx + 8]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<isize>
+.LBB8_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB8_27
+.LBB8_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI8_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB8_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB8_4
+.LBB8_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB8_4
+.LBB8_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB8_27
+.LBB8_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB8_12
+.LBB8_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB8_4:
+	mov	edx, 4
+.LBB8_5:
+	mov	rdi, r15
+.LBB8_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB8_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	ecx, 10
+	mov	rdi, rbp
+	mov	rsi, rbx
+	rep movsq es:[rdi], [rsi]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB8_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB8_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end8:
+.LJTI8_0:
+	.long	.LBB8_10-.LJTI8_0
+	.long	.LBB8_11-.LJTI8_0
+	.long	.LBB8_13-.LJTI8_0
+	.long	.LBB8_19-.LJTI8_0
+.LJTI8_1:
+	.long	.LBB8_3-.LJTI8_1
+	.long	.LBB8_6-.LJTI8_1
+	.long	.LBB8_7-.LJTI8_1
+	.long	.LBB8_8-.LJTI8_1
+	.long	.LBB8_9-.LJTI8_1
+
+optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin9:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB9_2
+	lea	rcx, [rip + .LJTI9_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB9_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.21]
+	mov	edx, 2
+	jmp	.LBB9_5
+.LBB9_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.5da3227578d25710a3a77221381aa472.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB9_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB9_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB9_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB9_18
+.LBB9_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB9_15
+	jmp	.LBB9_28
+.LBB9_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB9_14
+	jmp	.LBB9_28
+.LBB9_19:
+	lea	r14, [rbx + 8]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 72]
+	cmp	rcx, 9
+	mov	eax, 8
+	cmovb	rax, r14
+	lea	rcx, [8*rcx]
+	cmovae	rcx, r12
+	test	rcx, rcx
+	je	.LBB9_26
+	add	rcx, rax
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+.LBB9_21:
+	mov	qword ptr [rsp + 24], 0
+	mov	rsi, qword ptr [rax]
+	add	rax, 8
+	mov	qword ptr [rsp + 16], rsi
+	xor	esi, esi
+.LBB9_22:
+	cmp	r12, 256
+	jae	.LBB9_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB9_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 8
+	jne	.LBB9_22
+	cmp	rax, rcx
+	jne	.LBB9_21
+.LBB9_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB9_27
+.LBB9_11:
+	mov	rdi, qword ptr [rbx + 8]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<isize>
+.LBB9_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB9_27
+.LBB9_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI9_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB9_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB9_4
+.LBB9_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB9_4
+.LBB9_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB9_27
+.LBB9_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB9_12
+.LBB9_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB9_4:
+	mov	edx, 4
+.LBB9_5:
+	mov	rdi, r15
+.LBB9_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB9_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	ecx, 10
+	mov	rdi, rbp
+	mov	rsi, rbx
+	rep movsq es:[rdi], [rsi]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB9_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB9_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end9:
+.LJTI9_0:
+	.long	.LBB9_10-.LJTI9_0
+	.long	.LBB9_11-.LJTI9_0
+	.long	.LBB9_13-.LJTI9_0
+	.long	.LBB9_19-.LJTI9_0
+.LJTI9_1:
+	.long	.LBB9_3-.LJTI9_1
+	.long	.LBB9_6-.LJTI9_1
+	.long	.LBB9_7-.LJTI9_1
+	.long	.LBB9_8-.LJTI9_1
+	.long	.LBB9_9-.LJTI9_1
+
+__rustc::rust_begin_unwind:
+.Lfunc_begin10:
+	push	rax
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.30]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	call	qword ptr [rip + abort@GOTPCREL]
+.Lfunc_end10:
+
+optional_trait_methods::report_port:
+.Lfunc_begin11:
+	push	rbx
+	sub	rsp, 64
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 16]
+	movaps	xmmword ptr [rsi], xmm0
+	lea	rdx, [rsp + 12]
+	mov	dword ptr [rdx], 16
+	call	qword ptr [rip + getsockname@GOTPCREL]
+	test	eax, eax
+	js	.LBB11_1
+	xor	eax, eax
+	test	al, al
+	jne	.LBB11_7
+	movzx	ebx, word ptr [rsp + 18]
+	rol	bx, 8
+	shl	ebx, 16
+	shr	ebx, 16
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 48], 0
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.31]
+	mov	edx, 23
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	ecx, 39
+.LBB11_4:
+	cmp	rcx, 39
+	ja	.LBB11_8
+	mov	rax, rcx
+	movzx	edx, bx
+	imul	esi, edx, 52429
+	shr	esi, 19
+	lea	ecx, [rsi + rsi]
+	lea	ecx, [rcx + 4*rcx]
+	sub	ebx, ecx
+	or	bl, 48
+	mov	byte ptr [rsp + rax + 16], bl
+	lea	rcx, [rax - 1]
+	mov	ebx, esi
+	cmp	dx, 10
+	jae	.LBB11_4
+	lea	rsi, [rsp + rax]
+	add	rsi, 16
+	inc	rax
+	mov	edx, 41
+	sub	rdx, rax
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB11_7
+.LBB11_1:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB11_7:
+	add	rsp, 64
+	pop	rbx
+	ret
+.LBB11_8:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end11:
+
+optional_trait_methods::usage_error:
+.Lfunc_begin12:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.32]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	rax, qword ptr [rbx]
+	lea	rcx, [rip + .LJTI12_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB12_1:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.11]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 16
+	jmp	.LBB12_2
+.LBB12_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.16]
+	mov	edx, 39
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	jmp	.LBB12_7
+.LBB12_4:
+	mov	r14, qword ptr [rbx + 8]
+	mov	r15, qword ptr [rbx + 16]
+	mov	r12, qword ptr [rbx + 24]
+	mov	rbx, qword ptr [rbx + 32]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.13]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, r15
+	call	r13
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+	mov	edx, 2
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r12
+	mov	rdx, rbx
+	call	r13
+	jmp	.LBB12_7
+.LBB12_5:
+	mov	rsi, qword ptr [rbx + 8]
+	mov	rdx, qword ptr [rbx + 16]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.15]
+	mov	edx, 21
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB12_7
+.LBB12_3:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.12]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+.LBB12_2:
+	mov	edi, 2
+	call	r15
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, rbx
+	call	r15
+.LBB12_7:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 2
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	mov	edx, 823
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	mov	rax, rbx
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	jmp	rax
+.Lfunc_end12:
+.LJTI12_0:
+	.long	.LBB12_1-.LJTI12_0
+	.long	.LBB12_3-.LJTI12_0
+	.long	.LBB12_4-.LJTI12_0
+	.long	.LBB12_5-.LJTI12_0
+	.long	.LBB12_6-.LJTI12_0
+
+<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::parse_command:
+.Lfunc_begin13:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 168
+	mov	rbx, rdi
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.35]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 22
+	#APP
+	#NO_APP
+	lea	rdi, [rsi + 1]
+	movzx	ecx, byte ptr [rsi]
+	cmp	ecx, 45
+	je	.LBB13_4
+	cmp	ecx, 43
+	jne	.LBB13_9
+	cmp	rdx, 1
+	jne	.LBB13_6
+	mov	byte ptr [rbx + 1], 0
+	jmp	.LBB13_20
+.LBB13_4:
+	cmp	rdx, 1
+	jne	.LBB13_6
+	mov	byte ptr [rbx + 1], 1
+	jmp	.LBB13_20
+.LBB13_6:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_10
+.LBB13_7:
+	mov	qword ptr [rbx + 8], 1
+.LBB13_8:
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+	jmp	.LBB13_21
+.LBB13_9:
+	cmp	rdx, 2
+	jb	.LBB13_11
+.LBB13_10:
+	cmp	word ptr [rsi], 11563
+	je	.LBB13_18
+.LBB13_11:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.39]
+	mov	r8, rsp
+	mov	qword ptr [r8], rax
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	ecx, 42
+	jne	.LBB13_27
+	mov	r15, rdx
+	dec	r15
+	jne	.LBB13_22
+	setne	al
+	movzx	ecx, al
+	sub	r15, rcx
+	add	rdi, rcx
+.LBB13_14:
+	mov	r14, r15
+.LBB13_15:
+	movzx	r12d, al
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB13_47
+	cmp	r15, r14
+	jne	.LBB13_57
+	mov	byte ptr [rbx], 8
+	jmp	.LBB13_39
+.LBB13_18:
+	cmp	rdx, 2
+	jne	.LBB13_55
+	mov	byte ptr [rbx + 1], 2
+.LBB13_20:
+	mov	al, 7
+.LBB13_21:
+	mov	byte ptr [rbx], al
+	jmp	.LBB13_152
+.LBB13_22:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_27
+	xor	eax, eax
+	test	r15, r15
+	setne	al
+	sub	r15, rax
+	add	rdi, rax
+	cmp	rdx, 3
+	jb	.LBB13_14
+	xor	r14d, r14d
+.LBB13_25:
+	cmp	byte ptr [rdi + r14], 32
+	je	.LBB13_15
+	inc	r14
+	cmp	r15, r14
+	jne	.LBB13_25
+	jmp	.LBB13_14
+.LBB13_27:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.41]
+	mov	qword ptr [r8], rax
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 2
+	jb	.LBB13_29
+	cmp	word ptr [rsi], 32298
+	je	.LBB13_60
+.LBB13_29:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.43]
+	mov	qword ptr [r8], rax
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 3
+	jb	.LBB13_31
+	movzx	eax, word ptr [rsi]
+	xor	eax, 32298
+	movzx	r8d, byte ptr [rsi + 2]
+	xor	r8d, 126
+	or	r8w, ax
+	je	.LBB13_62
+.LBB13_31:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.45]
+	mov	r8, rsp
+	mov	qword ptr [r8], rax
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	ecx, 37
+	je	.LBB13_41
+	cmp	ecx, 47
+	jne	.LBB13_50
+	mov	r15, rdx
+	dec	r15
+	jne	.LBB13_64
+	setne	al
+	movzx	ecx, al
+	sub	r15, rcx
+	add	rdi, rcx
+.LBB13_35:
+	mov	r14, r15
+.LBB13_36:
+	movzx	r12d, al
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB13_47
+	cmp	r15, r14
+	jne	.LBB13_57
+	mov	byte ptr [rbx], 9
+.LBB13_39:
+	mov	qword ptr [rbx + 8], 0
+	jmp	.LBB13_40
+.LBB13_41:
+	mov	r15, rdx
+	dec	r15
+	jne	.LBB13_69
+	setne	al
+	movzx	ecx, al
+	sub	r15, rcx
+	add	rdi, rcx
+.LBB13_43:
+	mov	r14, r15
+.LBB13_44:
+	movzx	r12d, al
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB13_47
+	cmp	r15, r14
+	jne	.LBB13_57
+	mov	byte ptr [rbx], 9
+	jmp	.LBB13_91
+.LBB13_47:
+	inc	r12
+.LBB13_48:
+	mov	qword ptr [rbx + 8], r12
+.LBB13_49:
+	mov	byte ptr [rbx + 16], 2
+	jmp	.LBB13_151
+.LBB13_50:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	qword ptr [r8], rax
+	mov	qword ptr [r8 + 8], 20
+	#APP
+	#NO_APP
+	cmp	ecx, 111
+	jle	.LBB13_77
+	lea	eax, [rcx - 112]
+	cmp	eax, 7
+	ja	.LBB13_75
+	lea	r8, [rip + .LJTI13_0]
+	movsxd	rax, dword ptr [r8 + 4*rax]
+	add	rax, r8
+	jmp	rax
+.LBB13_53:
+	cmp	rdx, 1
+	jne	.LBB13_143
+	xor	eax, eax
+	jmp	.LBB13_145
+.LBB13_55:
+	cmp	byte ptr [rsi + 2], 32
+	jne	.LBB13_11
+	mov	qword ptr [rbx + 8], 2
+	jmp	.LBB13_8
+.LBB13_57:
+	lea	rax, [r14 + r12]
+	inc	rax
+.LBB13_58:
+	mov	qword ptr [rbx + 8], rax
+.LBB13_59:
+	mov	byte ptr [rbx + 16], 3
+	jmp	.LBB13_151
+.LBB13_60:
+	lea	rax, [rsi + 2]
+	mov	r14, rdx
+	sub	r14, 2
+	jne	.LBB13_82
+	setne	cl
+	movzx	edx, cl
+	sub	r14, rdx
+	add	rax, rdx
+	mov	r15, r14
+	jmp	.LBB13_88
+.LBB13_62:
+	lea	r14, [rsi + 3]
+	mov	r15, rdx
+	sub	r15, 3
+	jne	.LBB13_92
+	setne	al
+	movzx	ecx, al
+	sub	r15, rcx
+	add	r14, rcx
+	mov	rbp, r15
+	jmp	.LBB13_98
+.LBB13_64:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_74
+	xor	eax, eax
+	test	r15, r15
+	setne	al
+	sub	r15, rax
+	add	rdi, rax
+	cmp	rdx, 3
+	jb	.LBB13_35
+	xor	r14d, r14d
+.LBB13_67:
+	cmp	byte ptr [rdi + r14], 32
+	je	.LBB13_36
+	inc	r14
+	cmp	r15, r14
+	jne	.LBB13_67
+	jmp	.LBB13_35
+.LBB13_69:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_74
+	xor	eax, eax
+	test	r15, r15
+	setne	al
+	sub	r15, rax
+	add	rdi, rax
+	cmp	rdx, 3
+	jb	.LBB13_43
+	xor	r14d, r14d
+.LBB13_72:
+	cmp	byte ptr [rdi + r14], 32
+	je	.LBB13_44
+	inc	r14
+	cmp	r15, r14
+	jne	.LBB13_72
+	jmp	.LBB13_43
+.LBB13_74:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 20
+	#APP
+	#NO_APP
+.LBB13_75:
+	mov	rdi, rsi
+	mov	rsi, rdx
+	call	optional_trait_methods::commands::unrecognized
+	mov	qword ptr [rbx + 8], 0
+.LBB13_76:
+	mov	byte ptr [rbx + 16], al
+.LBB13_151:
+	mov	byte ptr [rbx], -1
+.LBB13_152:
+	add	rsp, 168
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB13_77:
+	cmp	ecx, 63
+	je	.LBB13_129
+	cmp	ecx, 71
+	je	.LBB13_132
+	cmp	ecx, 103
+	jne	.LBB13_75
+	cmp	rdx, 1
+	jne	.LBB13_146
+	mov	al, 5
+	jmp	.LBB13_21
+.LBB13_82:
+	cmp	byte ptr [rax], 32
+	jne	.LBB13_29
+	xor	ecx, ecx
+	test	r14, r14
+	setne	cl
+	mov	r15, r14
+	sub	r15, rcx
+	add	rax, rcx
+	cmp	r14, 2
+	jb	.LBB13_87
+	xor	r14d, r14d
+.LBB13_85:
+	cmp	byte ptr [rax + r14], 32
+	je	.LBB13_88
+	inc	r14
+	cmp	r15, r14
+	jne	.LBB13_85
+.LBB13_87:
+	mov	r14, r15
+.LBB13_88:
+	movzx	r12d, cl
+	mov	rdi, rax
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB13_107
+	cmp	r15, r14
+	jne	.LBB13_109
+	mov	byte ptr [rbx], 8
+.LBB13_91:
+	mov	qword ptr [rbx + 8], 1
+.LBB13_40:
+	mov	qword ptr [rbx + 16], rdx
+	jmp	.LBB13_152
+.LBB13_92:
+	cmp	byte ptr [r14], 32
+	jne	.LBB13_31
+	xor	eax, eax
+	test	r15, r15
+	setne	al
+	mov	rbp, r15
+	sub	rbp, rax
+	add	r14, rax
+	cmp	r15, 2
+	jb	.LBB13_97
+	xor	r15d, r15d
+.LBB13_95:
+	cmp	byte ptr [r14 + r15], 32
+	je	.LBB13_98
+	inc	r15
+	cmp	rbp, r15
+	jne	.LBB13_95
+.LBB13_97:
+	mov	r15, rbp
+.LBB13_98:
+	movzx	r13d, al
+	mov	rdi, r14
+	mov	rsi, r15
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB13_108
+	add	r14, r15
+	xor	eax, eax
+	mov	rcx, rbp
+	sub	rcx, r15
+	setne	al
+	cmp	rcx, 1
+	mov	r12, rcx
+	adc	r12, -1
+	lea	rsi, [r15 + r13]
+	add	rsi, 3
+	add	r14, rax
+	mov	r13, r12
+	cmp	rcx, 2
+	jb	.LBB13_104
+	not	r15
+	add	r15, rbp
+	xor	r13d, r13d
+.LBB13_101:
+	cmp	byte ptr [r14 + r13], 32
+	je	.LBB13_104
+	inc	r13
+	cmp	r15, r13
+	jne	.LBB13_101
+	mov	r13, r12
+.LBB13_104:
+	mov	r15, rdx
+	add	rsi, rax
+	mov	rdi, r14
+	mov	r14, rsi
+	mov	rsi, r13
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB13_110
+	cmp	r12, r13
+	jne	.LBB13_133
+	mov	byte ptr [rbx], 8
+	mov	qword ptr [rbx + 8], 2
+	mov	qword ptr [rbx + 16], r15
+	mov	qword ptr [rbx + 24], rdx
+	jmp	.LBB13_152
+.LBB13_107:
+	or	r12, 2
+	jmp	.LBB13_48
+.LBB13_108:
+	add	r13, 3
+	mov	qword ptr [rbx + 8], r13
+	jmp	.LBB13_49
+.LBB13_109:
+	lea	rax, [r14 + r12]
+	add	rax, 2
+	jmp	.LBB13_58
+.LBB13_110:
+	mov	qword ptr [rbx + 8], r14
+	jmp	.LBB13_49
+.LBB13_111:
+	mov	rax, rdx
+	dec	rax
+	jne	.LBB13_134
+	mov	edx, 1
+.LBB13_113:
+	mov	byte ptr [rbx], 10
+	mov	qword ptr [rbx + 8], rdx
+	jmp	.LBB13_152
+.LBB13_114:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB13_116
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_75
+.LBB13_116:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB13_150
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB13_171
+	mov	byte ptr [rbx], 1
+	jmp	.LBB13_124
+.LBB13_119:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB13_121
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_153
+.LBB13_121:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB13_150
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB13_171
+	mov	byte ptr [rbx], 3
+.LBB13_124:
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB13_152
+.LBB13_125:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB13_127
+.LBB13_126:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_147
+.LBB13_127:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	je	.LBB13_149
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB13_76
+.LBB13_129:
+	mov	rax, rdx
+	dec	rax
+	jne	.LBB13_162
+	mov	qword ptr [rsp], rdi
+	mov	qword ptr [rsp + 8], rax
+	mov	qword ptr [rsp + 16], 1
+	xor	ebp, ebp
+.LBB13_131:
+	mov	byte ptr [rbx], 2
+	mov	byte ptr [rbx + 1], bpl
+	mov	byte ptr [rbx + 2], al
+	jmp	.LBB13_152
+.LBB13_132:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB13_158
+	jmp	.LBB13_157
+.LBB13_133:
+	add	r13, r14
+	mov	qword ptr [rbx + 8], r13
+	jmp	.LBB13_59
+.LBB13_134:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_75
+	lea	r15, [rdx - 2]
+	add	rsi, 2
+	mov	r14, r15
+	cmp	rax, 1
+	je	.LBB13_140
+	add	rdx, -2
+	xor	r14d, r14d
+.LBB13_137:
+	cmp	byte ptr [rsi + r14], 32
+	je	.LBB13_140
+	inc	r14
+	cmp	rdx, r14
+	jne	.LBB13_137
+	mov	r14, r15
+.LBB13_140:
+	mov	rdi, rsi
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<usize>
+	test	al, 1
+	je	.LBB13_169
+	sub	r15, r14
+	je	.LBB13_113
+	add	r14, 2
+	mov	qword ptr [rbx + 8], r14
+	jmp	.LBB13_59
+.LBB13_143:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_75
+	mov	qword ptr [rbx + 8], 1
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+.LBB13_145:
+	mov	byte ptr [rbx], al
+	jmp	.LBB13_152
+.LBB13_146:
+	cmp	byte ptr [rdi], 32
+	je	.LBB13_7
+.LBB13_147:
+	cmp	ecx, 63
+	je	.LBB13_161
+	cmp	ecx, 71
+	jne	.LBB13_75
+	jmp	.LBB13_156
+.LBB13_149:
+	lea	r14, [rsp + 80]
+	mov	rsi, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	je	.LBB13_170
+.LBB13_150:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], cl
+	jmp	.LBB13_151
+.LBB13_153:
+	mov	r8d, ecx
+	add	r8b, -63
+	je	.LBB13_161
+	rol	r8b, 5
+	movzx	r8d, r8b
+	cmp	r8d, 1
+	je	.LBB13_156
+	cmp	r8d, 7
+	jne	.LBB13_75
+	jmp	.LBB13_126
+.LBB13_156:
+	lea	rax, [rdx - 1]
+.LBB13_157:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_75
+.LBB13_158:
+	lea	rsi, [rsp + 144]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::regs::<isize>
+	mov	rcx, qword ptr [r14 + 8]
+	mov	al, byte ptr [r14 + 16]
+	cmp	byte ptr [r14], 0
+	jne	.LBB13_165
+	movups	xmm0, xmmword ptr [rsp + 17]
+	movups	xmm1, xmmword ptr [rsp + 33]
+	movups	xmm2, xmmword ptr [rsp + 49]
+	movups	xmm3, xmmword ptr [rsp + 64]
+	movups	xmmword ptr [rsp + 127], xmm3
+	movaps	xmmword ptr [rsp + 112], xmm2
+	movaps	xmmword ptr [rsp + 96], xmm1
+	movaps	xmmword ptr [rsp + 80], xmm0
+	cmp	qword ptr [rsp + 152], 0
+	je	.LBB13_166
+	mov	rax, qword ptr [rsp + 160]
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB13_8
+.LBB13_161:
+	lea	rax, [rdx - 1]
+.LBB13_162:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_75
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	test	rax, rax
+	setne	bpl
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::names
+	mov	al, byte ptr [r14 + 8]
+	cmp	al, -1
+	je	.LBB13_167
+	mov	rcx, qword ptr [rsp + 80]
+.LBB13_165:
+	mov	qword ptr [rbx + 8], rcx
+	jmp	.LBB13_76
+.LBB13_166:
+	mov	qword ptr [rbx + 8], rcx
+	mov	byte ptr [rbx + 16], al
+	movaps	xmm0, xmmword ptr [rsp + 80]
+	movaps	xmm1, xmmword ptr [rsp + 96]
+	movaps	xmm2, xmmword ptr [rsp + 112]
+	movups	xmmword ptr [rbx + 17], xmm0
+	movups	xmmword ptr [rbx + 33], xmm1
+	movups	xmmword ptr [rbx + 49], xmm2
+	movups	xmm0, xmmword ptr [rsp + 127]
+	movups	xmmword ptr [rbx + 64], xmm0
+	mov	al, 6
+	jmp	.LBB13_21
+.LBB13_167:
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB13_171
+	mov	al, byte ptr [rsp + 80]
+	jmp	.LBB13_131
+.LBB13_169:
+	mov	qword ptr [rbx + 8], 2
+	jmp	.LBB13_49
+.LBB13_170:
+	cmp	qword ptr [rsp + 8], 0
+	je	.LBB13_172
+.LBB13_171:
+	mov	rax, qword ptr [rsp + 16]
+	jmp	.LBB13_58
+.LBB13_172:
+	mov	byte ptr [rbx], 4
+	mov	qword ptr [rbx + 8], r15
+	mov	qword ptr [rbx + 16], rax
+	jmp	.LBB13_152
+.Lfunc_end13:
+.LJTI13_0:
+	.long	.LBB13_53-.LJTI13_0
+	.long	.LBB13_75-.LJTI13_0
+	.long	.LBB13_119-.LJTI13_0
+	.long	.LBB13_114-.LJTI13_0
+	.long	.LBB13_75-.LJTI13_0
+	.long	.LBB13_111-.LJTI13_0
+	.long	.LBB13_75-.LJTI13_0
+	.long	.LBB13_125-.LJTI13_0
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin14:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB14_1
+	xor	eax, eax
+	mov	qword ptr [r14 + 1024], rax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	cmovae	rdx, rax
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB14_3
+	mov	edi, 1
+	mov	rsi, r15
+	mov	rdx, rbx
+	xor	ecx, ecx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	jmp	optional_trait_methods::connection::write_fd
+.LBB14_1:
+	cmp	rax, rdx
+	jb	.LBB14_4
+.LBB14_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB14_4:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end14:
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin15:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB15_1
+	xor	eax, eax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	mov	qword ptr [r14 + 1024], rax
+	cmovae	rdx, rax
+	mov	ebp, dword ptr [r14 + 1032]
+	mov	edi, ebp
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB15_3
+	mov	edi, ebp
+	mov	rsi, r15
+	mov	rdx, rbx
+	mov	ecx, 1
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	optional_trait_methods::connection::write_fd
+.LBB15_1:
+	cmp	rax, rdx
+	jb	.LBB15_4
+.LBB15_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB15_4:
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end15:
+
+main:
+.Lfunc_begin16:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 3352
+	cmp	rdi, 2
+	jl	.LBB16_55
+	mov	rbp, rsi
+	mov	r13d, 1
+	lea	r12, [rsp + 392]
+	mov	qword ptr [rsp + 40], 0
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	dword ptr [rsp + 36], eax
+	mov	qword ptr [rsp + 80], 0
+	mov	qword ptr [rsp + 64], 0
+	mov	qword ptr [rsp + 72], 0
+	mov	qword ptr [rsp + 96], 0
+	mov	qword ptr [rsp + 88], 0
+	mov	qword ptr [rsp + 104], rsi
+	mov	qword ptr [rsp + 24], rdi
+.LBB16_2:
+	mov	qword ptr [rsp + 48], r15
+.LBB16_3:
+	mov	rbp, qword ptr [rbp + 8*r13]
+	mov	rdi, rbp
+	call	rcx
+	mov	rbx, rax
+	test	rax, rax
+	je	.LBB16_8
+	xor	r14d, r14d
+.LBB16_5:
+	cmp	byte ptr [rbp + r14], 61
+	je	.LBB16_10
+	inc	r14
+	cmp	rbx, r14
+	jne	.LBB16_5
+	mov	qword ptr [rsp + 16], 0
+	mov	r14, rbx
+	jmp	.LBB16_9
+.LBB16_8:
+	mov	qword ptr [rsp + 16], 0
+	xor	r14d, r14d
+.LBB16_9:
+	jmp	.LBB16_11
+.LBB16_10:
+	lea	rax, [r14 + 1]
+	mov	rcx, rbx
+	sub	rcx, rax
+	mov	qword ptr [rsp + 208], rcx
+	lea	rax, [r14 + rbp]
+	inc	rax
+	mov	qword ptr [rsp + 16], rax
+.LBB16_11:
+	mov	qword ptr [rsp + 8], r13
+	lea	rax, [r13 + 1]
+	mov	qword ptr [rsp + 56], rax
+	mov	qword ptr [rsp + 376], 0
+	mov	qword ptr [rsp + 384], 7
+	mov	edx, 168
+	mov	rdi, r12
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r15d, 32
+.LBB16_12:
+	cmp	qword ptr [rsp + r15 + 368], r14
+	jne	.LBB16_15
+	movzx	r13d, byte ptr [rsp + r15 + 376]
+	mov	r12, qword ptr [rsp + r15 + 360]
+	mov	rdi, r12
+	mov	rsi, rbp
+	mov	rdx, r14
+	call	qword ptr [rip + bcmp@GOTPCREL]
+	test	eax, eax
+	jne	.LBB16_15
+	cmp	r13d, 255
+	jne	.LBB16_16
+.LBB16_15:
+	add	r15, 24
+	cmp	r15, 200
+	jne	.LBB16_12
+	jmp	.LBB16_92
+.LBB16_16:
+	cmp	r13, 255
+	je	.LBB16_92
+	cmp	r13b, 4
+	jae	.LBB16_20
+	mov	rdx, qword ptr [rsp + 16]
+	test	rdx, rdx
+	mov	rbp, qword ptr [rsp + 104]
+	mov	rbx, qword ptr [rsp + 24]
+	je	.LBB16_22
+	mov	rax, qword ptr [rsp + 56]
+	mov	qword ptr [rsp + 8], rax
+	mov	rax, qword ptr [rsp + 208]
+	jmp	.LBB16_25
+.LBB16_20:
+	cmp	qword ptr [rsp + 16], 0
+	mov	rbp, qword ptr [rsp + 104]
+	mov	rbx, qword ptr [rsp + 24]
+	jne	.LBB16_101
+	mov	edx, 1
+	mov	rax, qword ptr [rsp + 56]
+	mov	qword ptr [rsp + 8], rax
+	xor	eax, eax
+	jmp	.LBB16_25
+.LBB16_22:
+	cmp	qword ptr [rsp + 56], rbx
+	mov	r15, qword ptr [rsp + 8]
+	jge	.LBB16_102
+	mov	rdi, qword ptr [rbp + 8*r15 + 8]
+	test	rdi, rdi
+	je	.LBB16_102
+	mov	qword ptr [rsp + 16], rdi
+	call	qword ptr [rip + strlen@GOTPCREL]
+	mov	rdx, qword ptr [rsp + 16]
+	add	r15, 2
+	mov	qword ptr [rsp + 8], r15
+.LBB16_25:
+	cmp	r13b, 5
+	jne	.LBB16_27
+	mov	al, 1
+	mov	qword ptr [rsp + 96], rax
+	mov	r13, qword ptr [rsp + 8]
+	cmp	r13, rbx
+	lea	r12, [rsp + 392]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB16_3
+	jmp	.LBB16_104
+.LBB16_27:
+	movzx	ecx, r13b
+	mov	esi, ecx
+	lea	rcx, [rip + .LJTI16_0]
+	movsxd	rsi, dword ptr [rcx + 4*rsi]
+	add	rsi, rcx
+	mov	rbx, qword ptr [rsp + 80]
+	jmp	rsi
+.LBB16_28:
+	test	rax, rax
+	je	.LBB16_119
+	mov	qword ptr [rsp + 192], rdx
+	mov	qword ptr [rsp + 200], rax
+	mov	dword ptr [rsp + 36], eax
+	cmp	word ptr [rsp + 40], 0
+	mov	ax, 1
+	mov	qword ptr [rsp + 40], rax
+	mov	r15, qword ptr [rsp + 48]
+	lea	r12, [rsp + 392]
+	mov	r13, qword ptr [rsp + 8]
+	je	.LBB16_45
+	jmp	.LBB16_112
+.LBB16_30:
+	mov	al, 1
+	mov	qword ptr [rsp + 72], rax
+	jmp	.LBB16_42
+.LBB16_31:
+	mov	r15, rax
+	mov	rbx, rdx
+	jmp	.LBB16_43
+.LBB16_32:
+	cmp	rax, 8
+	je	.LBB16_46
+	cmp	rax, 4
+	mov	r13, qword ptr [rsp + 8]
+	jne	.LBB16_124
+	mov	esi, 4
+	cmp	byte ptr [rdx], 101
+	jne	.LBB16_125
+	cmp	byte ptr [rdx + 1], 120
+	jne	.LBB16_125
+	cmp	byte ptr [rdx + 2], 105
+	jne	.LBB16_125
+	cmp	byte ptr [rdx + 3], 116
+	jne	.LBB16_125
+	jmp	.LBB16_54
+.LBB16_38:
+	mov	rdi, rdx
+	mov	r13, rax
+	mov	rsi, rax
+	mov	r15, rdx
+	call	optional_trait_methods::num::parse_num::<u16>
+	mov	dword ptr [rsp + 36], edx
+	test	al, 1
+	je	.LBB16_118
+	cmp	word ptr [rsp + 40], 0
+	mov	r15, qword ptr [rsp + 48]
+	lea	r12, [rsp + 392]
+	jne	.LBB16_112
+	mov	ax, 2
+	mov	qword ptr [rsp + 40], rax
+	jmp	.LBB16_44
+.LBB16_41:
+	mov	al, 1
+	mov	qword ptr [rsp + 88], rax
+.LBB16_42:
+	mov	r15, qword ptr [rsp + 48]
+.LBB16_43:
+	lea	r12, [rsp + 392]
+.LBB16_44:
+	mov	r13, qword ptr [rsp + 8]
+.LBB16_45:
+	mov	qword ptr [rsp + 80], rbx
+	mov	rbx, qword ptr [rsp + 24]
+	cmp	r13, rbx
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB16_2
+	jmp	.LBB16_103
+.LBB16_46:
+	mov	esi, 8
+	cmp	byte ptr [rdx], 99
+	mov	r13, qword ptr [rsp + 8]
+	jne	.LBB16_125
+	cmp	byte ptr [rdx + 1], 111
+	jne	.LBB16_125
+	cmp	byte ptr [rdx + 2], 110
+	jne	.LBB16_125
+	cmp	byte ptr [rdx + 3], 116
+	jne	.LBB16_125
+	cmp	byte ptr [rdx + 4], 105
+	jne	.LBB16_125
+	cmp	byte ptr [rdx + 5], 110
+	jne	.LBB16_125
+	cmp	byte ptr [rdx + 6], 117
+	jne	.LBB16_125
+	cmp	byte ptr [rdx + 7], 101
+	jne	.LBB16_125
+.LBB16_54:
+	cmp	rax, 8
+	setne	al
+	mov	qword ptr [rsp + 64], rax
+	mov	r15, qword ptr [rsp + 48]
+	lea	r12, [rsp + 392]
+	jmp	.LBB16_45
+.LBB16_55:
+	xor	r14d, r14d
+	xor	ebp, ebp
+.LBB16_56:
+	xorps	xmm0, xmm0
+	lea	r12, [rsp + 2976]
+	movups	xmmword ptr [r12 + 328], xmm0
+	movups	xmmword ptr [r12 + 312], xmm0
+	movups	xmmword ptr [r12 + 296], xmm0
+	xor	ebx, ebx
+	mov	qword ptr [r12 + 344], rbx
+	lea	rdi, [rsp + 392]
+	mov	r15, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 272
+	xor	esi, esi
+	call	r15
+	mov	qword ptr [r12 + 288], r14
+	mov	qword ptr [r12 + 352], 1
+	movabs	rax, 9223372036854775807
+	lea	rcx, [rax + 1]
+	mov	qword ptr [r12 + 360], rcx
+	mov	qword ptr [r12 + 368], rax
+	mov	qword ptr [r12 - 8], rbx
+	lea	rsi, [rsp + 376]
+	mov	edx, 288
+	mov	rdi, r12
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 1416]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r15
+	lea	rdi, [rsp + 376]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r15
+	lea	rdi, [rsp + 2456]
+	mov	edx, 512
+	xor	esi, esi
+	call	r15
+	mov	dword ptr [rsp + 56], ebp
+	shr	ebp, 8
+	mov	dword ptr [rsp + 8], ebp
+	mov	r12d, 1
+	mov	rbp, qword ptr [rip + read@GOTPCREL]
+	xor	ecx, ecx
+	mov	dword ptr [rsp + 16], eax
+.LBB16_57:
+	mov	rdi, qword ptr [rsp + 1400]
+	mov	rax, qword ptr [rsp + 1408]
+	xor	r15d, r15d
+	mov	rdx, rcx
+	xor	r13d, r13d
+.LBB16_58:
+	mov	rsi, rax
+	mov	rax, rdi
+.LBB16_59:
+	cmp	rsi, rax
+	jb	.LBB16_63
+	mov	qword ptr [rsp + 2440], rbx
+	cmp	rdx, 1025
+	mov	rsi, r14
+	cmovae	rsi, r12
+	cmovae	rdx, rbx
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 1024
+	xor	edi, edi
+	lea	rsi, [rsp + 376]
+	call	rbp
+	test	rax, rax
+	js	.LBB16_73
+	je	.LBB16_74
+	mov	qword ptr [rsp + 1400], rax
+	mov	qword ptr [rsp + 1408], 0
+	xor	ecx, ecx
+	xor	edx, edx
+	xor	esi, esi
+.LBB16_63:
+	cmp	rax, rsi
+	jb	.LBB16_96
+	cmp	rax, 1024
+	ja	.LBB16_96
+	cmp	rsi, rax
+	je	.LBB16_59
+	inc	rsi
+	mov	rdi, rax
+	neg	rdi
+.LBB16_67:
+	mov	r8b, byte ptr [rsp + rsi + 375]
+	mov	qword ptr [rsp + 1408], rsi
+	cmp	r8b, 10
+	je	.LBB16_77
+	cmp	r13, 511
+	jbe	.LBB16_70
+	mov	r15b, 1
+	jmp	.LBB16_71
+.LBB16_70:
+	mov	byte ptr [rsp + r13 + 2456], r8b
+	inc	r13
+.LBB16_71:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB16_67
+	mov	rdi, rax
+	jmp	.LBB16_58
+.LBB16_73:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB16_74:
+	test	r13, r13
+	setne	al
+	or	al, r15b
+	test	al, 1
+	je	.LBB16_100
+	test	r15b, 1
+	jne	.LBB16_81
+	mov	ecx, 0
+	mov	edx, 0
+	cmp	r13, 512
+	ja	.LBB16_97
+	jmp	.LBB16_79
+.LBB16_77:
+	test	r15b, 1
+	jne	.LBB16_81
+	cmp	r13, 512
+	ja	.LBB16_96
+.LBB16_79:
+	test	r13, r13
+	setne	al
+	test	byte ptr [rsp + 8], al
+	jne	.LBB16_82
+	test	r13, r13
+	je	.LBB16_57
+	jmp	.LBB16_83
+.LBB16_81:
+	mov	qword ptr [rsp + 120], 3
+	mov	qword ptr [rsp + 128], 512
+	mov	byte ptr [rsp + 136], 4
+	mov	byte ptr [rsp + 112], -1
+	lea	rdi, [rsp + 216]
+	mov	rsi, r14
+	lea	rdx, [rsp + 112]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	jmp	.LBB16_85
+.LBB16_82:
+	mov	rdi, r14
+	lea	rsi, [rsp + 2456]
+	mov	rdx, r13
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB16_83:
+	lea	r15, [rsp + 296]
+	mov	rdi, r15
+	lea	rsi, [rsp + 2456]
+	mov	rdx, r13
+	call	<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::parse_command
+	cmp	byte ptr [rsp + 296], -1
+	je	.LBB16_86
+	mov	ecx, 10
+	lea	rax, [rsp + 112]
+	mov	rdi, rax
+	mov	rsi, r15
+	rep movsq es:[rdi], [rsi]
+	lea	rdi, [rsp + 216]
+	lea	rsi, [rsp + 2968]
+	mov	rdx, r14
+	mov	rcx, rax
+	call	<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB16_85:
+	mov	r15d, dword ptr [rsp + 16]
+	mov	r13, qword ptr [rsp + 24]
+	jmp	.LBB16_87
+.LBB16_86:
+	mov	eax, dword ptr [rsp + 312]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	r13, qword ptr [rsp + 24]
+	cmovne	r13, qword ptr [rsp + 304]
+	sete	cl
+	mov	r15d, dword ptr [rsp + 16]
+	movzx	r15d, r15b
+	cmovne	r15d, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 120], rcx
+	mov	qword ptr [rsp + 128], r13
+	mov	byte ptr [rsp + 136], r15b
+	mov	byte ptr [rsp + 112], -1
+	lea	rdi, [rsp + 216]
+	mov	rsi, r14
+	lea	rdx, [rsp + 112]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB16_87:
+	cmp	byte ptr [rsp + 216], -1
+	jne	.LBB16_91
+	mov	rax, qword ptr [rsp + 224]
+	cmp	byte ptr [rsp + 56], 0
+	jne	.LBB16_106
+	cmp	eax, 1
+	je	.LBB16_109
+	cmp	eax, 4
+	je	.LBB16_108
+.LBB16_91:
+	mov	qword ptr [rsp + 24], r13
+	mov	dword ptr [rsp + 16], r15d
+	mov	rcx, qword ptr [rsp + 2440]
+	jmp	.LBB16_57
+.LBB16_92:
+	xor	ecx, ecx
+	mov	r12, rbp
+	mov	r14, rbx
+.LBB16_93:
+.LBB16_94:
+	lea	rdi, [rsp + 376]
+	mov	qword ptr [rdi], rcx
+	mov	qword ptr [rdi + 8], r12
+	mov	qword ptr [rdi + 16], r14
+	mov	qword ptr [rdi + 24], rdx
+	mov	qword ptr [rdi + 32], r13
+.LBB16_95:
+	call	optional_trait_methods::usage_error
+	mov	ebx, 1
+	jmp	.LBB16_99
+.LBB16_96:
+	mov	rdx, rcx
+.LBB16_97:
+	xor	eax, eax
+	mov	qword ptr [rsp + 2440], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, 1
+	mov	rsi, r14
+.LBB16_98:
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+.LBB16_99:
+	mov	rax, rbx
+	add	rsp, 3352
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB16_100:
+	xor	edx, edx
+	jmp	.LBB16_97
+.LBB16_101:
+	mov	ecx, 3
+	jmp	.LBB16_93
+.LBB16_102:
+	mov	ecx, 1
+	jmp	.LBB16_93
+.LBB16_103:
+	test	byte ptr [rsp + 96], 1
+	je	.LBB16_113
+.LBB16_104:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.73]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 31
+.LBB16_105:
+	mov	edi, 2
+	call	r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	ebx, 1
+	mov	edx, 1
+	mov	edi, 2
+	call	r14
+	jmp	.LBB16_99
+.LBB16_106:
+	cmp	eax, 1
+	je	.LBB16_109
+	cmp	eax, 4
+	jne	.LBB16_111
+.LBB16_108:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.20]
+	lea	rdi, [rsp + 1416]
+	mov	edx, 23
+	jmp	.LBB16_110
+.LBB16_109:
+	mov	rsi, qword ptr [rsp + 232]
+	mov	rdx, qword ptr [rsp + 240]
+	lea	rdi, [rsp + 1416]
+.LBB16_110:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 1416]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB16_111:
+	mov	ebx, 1
+	mov	rdx, qword ptr [rsp + 2440]
+	jmp	.LBB16_97
+.LBB16_112:
+	mov	ecx, 4
+	jmp	.LBB16_93
+.LBB16_113:
+	test	byte ptr [rsp + 88], 1
+	je	.LBB16_115
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	xor	ebx, ebx
+	mov	edx, 823
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 1
+	jmp	.LBB16_98
+.LBB16_115:
+	mov	rbx, qword ptr [rsp + 80]
+	test	rbx, rbx
+	je	.LBB16_120
+	mov	rdi, rbx
+	mov	rsi, r15
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB16_126
+	mov	r14, rdx
+	jmp	.LBB16_121
+.LBB16_118:
+	mov	ecx, 2
+	mov	rdx, r15
+	jmp	.LBB16_94
+.LBB16_119:
+	mov	ecx, 2
+	xor	r13d, r13d
+	jmp	.LBB16_94
+.LBB16_120:
+	xor	r14d, r14d
+.LBB16_121:
+	mov	rax, qword ptr [rsp + 40]
+	test	ax, ax
+	je	.LBB16_127
+	movzx	eax, ax
+	cmp	eax, 1
+	jne	.LBB16_128
+	mov	rdi, qword ptr [rsp + 192]
+	mov	rsi, qword ptr [rsp + 200]
+	call	<optional_trait_methods::connection::Listener>::unix
+	mov	ebx, edx
+	jmp	.LBB16_129
+.LBB16_124:
+	mov	rsi, rax
+.LBB16_125:
+	mov	ecx, 2
+	mov	r13, rsi
+	jmp	.LBB16_94
+.LBB16_126:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.24]
+	lea	rdi, [rsp + 376]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 15
+	mov	qword ptr [rdi + 24], rbx
+	mov	qword ptr [rdi + 32], r15
+	mov	qword ptr [rdi], 2
+	jmp	.LBB16_95
+.LBB16_127:
+	movzx	eax, byte ptr [rsp + 72]
+	shl	eax, 8
+	movzx	ebp, byte ptr [rsp + 64]
+	or	ebp, eax
+	jmp	.LBB16_56
+.LBB16_128:
+	mov	ebp, dword ptr [rsp + 36]
+	mov	edi, ebp
+	call	<optional_trait_methods::connection::Listener>::tcp_loopback
+	mov	ebx, edx
+	test	bp, bp
+	setne	cl
+	or	cl, al
+	test	cl, 1
+	je	.LBB16_131
+.LBB16_129:
+	cmp	eax, 1
+	jne	.LBB16_132
+	mov	r15d, 1
+	mov	ebp, ebx
+	jmp	.LBB16_133
+.LBB16_131:
+	mov	edi, ebx
+	call	optional_trait_methods::report_port
+.LBB16_132:
+	mov	edi, ebx
+	call	<optional_trait_methods::connection::Listener>::accept
+	mov	r15d, eax
+	mov	ebp, edx
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+.LBB16_133:
+	test	r15b, 1
+	je	.LBB16_135
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.72]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 29
+	jmp	.LBB16_105
+.LBB16_135:
+	xorps	xmm0, xmm0
+	lea	r12, [rsp + 2976]
+	movups	xmmword ptr [r12 + 328], xmm0
+	movups	xmmword ptr [r12 + 312], xmm0
+	movups	xmmword ptr [r12 + 296], xmm0
+	xor	ebx, ebx
+	mov	qword ptr [r12 + 344], rbx
+	lea	rdi, [rsp + 392]
+	mov	r15, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 272
+	xor	esi, esi
+	call	r15
+	mov	qword ptr [r12 + 288], r14
+	mov	qword ptr [r12 + 352], 1
+	movabs	rax, 9223372036854775807
+	lea	rcx, [rax + 1]
+	mov	qword ptr [r12 + 360], rcx
+	mov	qword ptr [r12 + 368], rax
+	mov	qword ptr [r12 - 8], rbx
+	lea	rsi, [rsp + 376]
+	mov	edx, 288
+	mov	rdi, r12
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 1416]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r15
+	mov	dword ptr [r14 + 1032], ebp
+	lea	rdi, [rsp + 376]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r15
+	lea	rdi, [rsp + 2456]
+	mov	edx, 512
+	xor	esi, esi
+	call	r15
+	lea	r13, [rsp + 112]
+	xor	edx, edx
+	mov	dword ptr [rsp + 8], eax
+.LBB16_136:
+	mov	rdi, qword ptr [rsp + 1400]
+	mov	rcx, qword ptr [rsp + 1408]
+	xor	r15d, r15d
+	mov	rax, rdx
+	xor	r12d, r12d
+.LBB16_137:
+	mov	rsi, rcx
+	mov	rcx, rdi
+.LBB16_138:
+	cmp	rsi, rcx
+	jb	.LBB16_142
+	mov	qword ptr [rsp + 2440], rbx
+	cmp	rax, 1025
+	mov	rsi, r14
+	mov	ecx, 1
+	cmovae	rsi, rcx
+	cmovae	rax, rbx
+	mov	edi, ebp
+	mov	rdx, rax
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	rdi, r13
+	mov	esi, ebp
+	lea	rdx, [rsp + 376]
+	call	optional_trait_methods::connection::read_fd
+	cmp	byte ptr [rsp + 112], 0
+	jne	.LBB16_152
+	mov	rcx, qword ptr [rsp + 120]
+	test	rcx, rcx
+	je	.LBB16_152
+	mov	qword ptr [rsp + 1400], rcx
+	mov	qword ptr [rsp + 1408], 0
+	xor	edx, edx
+	xor	eax, eax
+	xor	esi, esi
+.LBB16_142:
+	cmp	rcx, rsi
+	jb	.LBB16_171
+	cmp	rcx, 1024
+	ja	.LBB16_171
+	cmp	rsi, rcx
+	je	.LBB16_138
+	inc	rsi
+	mov	rdi, rcx
+	neg	rdi
+.LBB16_146:
+	mov	r8b, byte ptr [rsp + rsi + 375]
+	mov	qword ptr [rsp + 1408], rsi
+	cmp	r8b, 10
+	je	.LBB16_155
+	cmp	r12, 511
+	jbe	.LBB16_149
+	mov	r15b, 1
+	jmp	.LBB16_150
+.LBB16_149:
+	mov	byte ptr [rsp + r12 + 2456], r8b
+	inc	r12
+.LBB16_150:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB16_146
+	mov	rdi, rcx
+	jmp	.LBB16_137
+.LBB16_152:
+	test	r12, r12
+	setne	al
+	or	al, r15b
+	test	al, 1
+	je	.LBB16_170
+	test	r15b, 1
+	jne	.LBB16_159
+	mov	edx, 0
+	cmp	r12, 512
+	jbe	.LBB16_157
+	jmp	.LBB16_170
+.LBB16_155:
+	test	r15b, 1
+	jne	.LBB16_159
+	cmp	r12, 512
+	ja	.LBB16_171
+.LBB16_157:
+	test	r12, r12
+	setne	al
+	test	byte ptr [rsp + 72], al
+	jne	.LBB16_160
+	test	r12, r12
+	je	.LBB16_136
+	jmp	.LBB16_161
+.LBB16_159:
+	mov	qword ptr [rsp + 120], 3
+	mov	qword ptr [rsp + 128], 512
+	mov	byte ptr [rsp + 136], 4
+	jmp	.LBB16_164
+.LBB16_160:
+	mov	rdi, r14
+	lea	rsi, [rsp + 2456]
+	mov	rdx, r12
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB16_161:
+	lea	rdi, [rsp + 296]
+	lea	rsi, [rsp + 2456]
+	mov	rdx, r12
+	call	<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::parse_command
+	cmp	byte ptr [rsp + 296], -1
+	je	.LBB16_163
+	mov	ecx, 10
+	mov	rdi, r13
+	lea	rsi, [rsp + 296]
+	rep movsq es:[rdi], [rsi]
+	lea	rdi, [rsp + 216]
+	lea	rsi, [rsp + 2968]
+	mov	rdx, r14
+	mov	rcx, r13
+	call	<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	jmp	.LBB16_165
+.LBB16_163:
+	mov	eax, dword ptr [rsp + 312]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rdx, qword ptr [rsp + 16]
+	cmovne	rdx, qword ptr [rsp + 304]
+	sete	cl
+	mov	esi, dword ptr [rsp + 8]
+	movzx	esi, sil
+	cmovne	esi, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 120], rcx
+	mov	qword ptr [rsp + 16], rdx
+	mov	qword ptr [rsp + 128], rdx
+	mov	dword ptr [rsp + 8], esi
+	mov	byte ptr [rsp + 136], sil
+.LBB16_164:
+	mov	byte ptr [rsp + 112], -1
+	lea	rdi, [rsp + 216]
+	mov	rsi, r14
+	mov	rdx, r13
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+.LBB16_165:
+	cmp	byte ptr [rsp + 216], -1
+	jne	.LBB16_169
+	mov	rax, qword ptr [rsp + 224]
+	cmp	byte ptr [rsp + 64], 0
+	jne	.LBB16_172
+	cmp	eax, 1
+	je	.LBB16_175
+	cmp	eax, 4
+	je	.LBB16_174
+.LBB16_169:
+	mov	ebp, dword ptr [rsp + 2448]
+	mov	rdx, qword ptr [rsp + 2440]
+	jmp	.LBB16_136
+.LBB16_170:
+	xor	edx, edx
+.LBB16_171:
+	xor	eax, eax
+	mov	qword ptr [rsp + 2440], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, ebp
+	mov	rsi, r14
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	jmp	.LBB16_99
+.LBB16_172:
+	cmp	eax, 1
+	je	.LBB16_175
+	cmp	eax, 4
+	jne	.LBB16_177
+.LBB16_174:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.20]
+	lea	rdi, [rsp + 1416]
+	mov	edx, 23
+	jmp	.LBB16_176
+.LBB16_175:
+	mov	rsi, qword ptr [rsp + 232]
+	mov	rdx, qword ptr [rsp + 240]
+	lea	rdi, [rsp + 1416]
+.LBB16_176:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 1416]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB16_177:
+	mov	rdx, qword ptr [rsp + 2440]
+	mov	ebx, 1
+	mov	ebp, dword ptr [rsp + 2448]
+	jmp	.LBB16_171
+.LBB16_178:
+	ud2
+.Lfunc_end16:
+.LJTI16_0:
+	.long	.LBB16_28-.LJTI16_0
+	.long	.LBB16_38-.LJTI16_0
+	.long	.LBB16_31-.LJTI16_0
+	.long	.LBB16_32-.LJTI16_0
+	.long	.LBB16_30-.LJTI16_0
+	.long	.LBB16_178-.LJTI16_0
+	.long	.LBB16_41-.LJTI16_0
+
+rust_eh_personality:
+.Lfunc_begin17:
+	ret
+.Lfunc_end17:
+
+core::panicking::panic_fmt:
+.Lfunc_begin18:
+	.loc	1 60 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp0:
+	.loc	1 80 14 prologue_end
+	call	__rustc::rust_begin_unwind
+.Ltmp1:
+.Lfunc_end18:
+
+core::panicking::panic_bounds_check:
+.Lfunc_begin19:
+	.loc	1 266 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp2:
+	.loc	1 271 5 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp3:
+.Lfunc_end19:
+
+core::slice::index::slice_index_fail:
+.Lfunc_begin20:
+	.loc	2 38 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp4:
+	.loc	3 177 9 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp5:
+.Lfunc_end20:
+
+optional_trait_methods::num::parse_num::<isize>:
+.Lfunc_begin21:
+	test	rsi, rsi
+	je	.LBB21_56
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB21_4
+	cmp	ecx, 45
+	jne	.LBB21_6
+	mov	cl, 1
+	jmp	.LBB21_5
+.LBB21_4:
+	xor	ecx, ecx
+.LBB21_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB21_7
+.LBB21_6:
+	xor	ecx, ecx
+.LBB21_7:
+	cmp	rsi, 1
+	jbe	.LBB21_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB21_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB21_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB21_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB21_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB21_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB21_32
+.LBB21_14:
+	test	rsi, rsi
+	je	.LBB21_56
+	mov	al, byte ptr [rdi]
+.LBB21_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB21_56
+.LBB21_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB21_19
+	cmp	byte ptr [rax], 95
+	je	.LBB21_56
+.LBB21_19:
+	xor	r8d, r8d
+	xor	edx, edx
+.LBB21_20:
+	cmp	rdi, rsi
+	je	.LBB21_51
+	mov	al, byte ptr [rdi]
+	inc	rdi
+	cmp	al, 95
+	jne	.LBB21_23
+	xor	eax, eax
+	test	r8b, 1
+	mov	r8d, 0
+	jne	.LBB21_20
+	jmp	.LBB21_52
+.LBB21_23:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB21_57
+	imul	rdx, rdx, 10
+	jo	.LBB21_57
+	movzx	eax, al
+	test	cl, cl
+	je	.LBB21_27
+	sub	rdx, rax
+	jno	.LBB21_28
+	jmp	.LBB21_57
+.LBB21_27:
+	add	rdx, rax
+	jo	.LBB21_57
+.LBB21_28:
+	mov	r8b, 1
+	jmp	.LBB21_20
+.LBB21_29:
+	mov	r8b, 8
+	jmp	.LBB21_31
+.LBB21_30:
+	mov	r8b, 2
+.LBB21_31:
+	xor	r9d, r9d
+.LBB21_32:
+	cmp	rsi, 2
+	je	.LBB21_56
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB21_53
+.LBB21_34:
+	cmp	dl, r8b
+	jae	.LBB21_56
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB21_37
+	cmp	byte ptr [rax], 95
+	je	.LBB21_56
+.LBB21_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB21_38:
+	cmp	r10, rdi
+	je	.LBB21_51
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB21_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB21_52
+	jmp	.LBB21_38
+.LBB21_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB21_45
+	test	r9b, r9b
+	je	.LBB21_57
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB21_57
+	add	al, -87
+	mov	r11d, eax
+.LBB21_45:
+	cmp	r11b, r8b
+	jae	.LBB21_57
+	imul	rdx, rsi
+	jo	.LBB21_57
+	movzx	eax, r11b
+	test	cl, cl
+	je	.LBB21_49
+	sub	rdx, rax
+	jo	.LBB21_57
+	jmp	.LBB21_50
+.LBB21_49:
+	add	rdx, rax
+	jo	.LBB21_57
+.LBB21_50:
+	mov	r11b, 1
+	jmp	.LBB21_38
+.LBB21_51:
+	mov	eax, 1
+	ret
+.LBB21_53:
+	test	r9b, r9b
+	je	.LBB21_56
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB21_56
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB21_34
+.LBB21_56:
+	xor	eax, eax
+.LBB21_52:
+	ret
+.LBB21_57:
+	xor	eax, eax
+	ret
+.Lfunc_end21:
+
+optional_trait_methods::num::parse_num::<usize>:
+.Lfunc_begin22:
+	test	rsi, rsi
+	je	.LBB22_55
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB22_4
+	cmp	ecx, 45
+	jne	.LBB22_6
+	mov	cl, 1
+	jmp	.LBB22_5
+.LBB22_4:
+	xor	ecx, ecx
+.LBB22_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB22_7
+.LBB22_6:
+	xor	ecx, ecx
+.LBB22_7:
+	cmp	rsi, 1
+	jbe	.LBB22_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB22_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB22_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB22_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB22_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB22_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB22_32
+.LBB22_14:
+	test	rsi, rsi
+	je	.LBB22_55
+	mov	al, byte ptr [rdi]
+.LBB22_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB22_55
+.LBB22_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB22_19
+	cmp	byte ptr [rax], 95
+	je	.LBB22_55
+.LBB22_19:
+	mov	r8d, 10
+	xor	r9d, r9d
+	xor	edx, edx
+.LBB22_20:
+	cmp	rdi, rsi
+	je	.LBB22_50
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB22_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB22_20
+	jmp	.LBB22_51
+.LBB22_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB22_28
+	mov	rax, rdx
+	mul	r8
+	jo	.LBB22_28
+	mov	rdx, rax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB22_27
+	sub	rdx, rax
+	jae	.LBB22_20
+	jmp	.LBB22_28
+.LBB22_27:
+	add	rdx, rax
+	jae	.LBB22_20
+.LBB22_28:
+	xor	eax, eax
+	ret
+.LBB22_29:
+	mov	r8b, 8
+	jmp	.LBB22_31
+.LBB22_30:
+	mov	r8b, 2
+.LBB22_31:
+	xor	r9d, r9d
+.LBB22_32:
+	cmp	rsi, 2
+	je	.LBB22_55
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB22_52
+.LBB22_34:
+	cmp	dl, r8b
+	jae	.LBB22_55
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB22_37
+	cmp	byte ptr [rax], 95
+	je	.LBB22_55
+.LBB22_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB22_38:
+	cmp	r10, rdi
+	je	.LBB22_50
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB22_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB22_51
+	jmp	.LBB22_38
+.LBB22_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB22_45
+	test	r9b, r9b
+	je	.LBB22_28
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB22_28
+	add	al, -87
+	mov	r11d, eax
+.LBB22_45:
+	cmp	r11b, r8b
+	jae	.LBB22_28
+	mov	rax, rdx
+	mul	rsi
+	jo	.LBB22_28
+	mov	rdx, rax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB22_49
+	sub	rdx, rax
+	jae	.LBB22_38
+	jmp	.LBB22_28
+.LBB22_49:
+	add	rdx, rax
+	jae	.LBB22_38
+	jmp	.LBB22_28
+.LBB22_50:
+	mov	eax, 1
+	ret
+.LBB22_52:
+	test	r9b, r9b
+	je	.LBB22_55
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB22_55
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB22_34
+.LBB22_55:
+	xor	eax, eax
+.LBB22_51:
+	ret
+.Lfunc_end22:
+
+<optional_trait_methods::commands::Args>::reg:
+.Lfunc_begin23:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	cmp	rdx, 8
+	setb	cl
+	and	cl, al
+	cmovne	r15, rdx
+	neg	cl
+	or	cl, 2
+	mov	qword ptr [rbx], r15
+	mov	byte ptr [rbx + 8], cl
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end23:
+
+<optional_trait_methods::commands::Args>::next:
+.Lfunc_begin24:
+	mov	r10, qword ptr [rsi + 8]
+	mov	rax, qword ptr [rsi + 16]
+	xor	edx, edx
+	test	r10, r10
+	setne	dl
+	cmp	r10, 1
+	mov	rcx, r10
+	adc	rcx, -1
+	mov	r8, qword ptr [rsi]
+	add	r8, rdx
+	mov	r9, rcx
+	cmp	r10, 2
+	jb	.LBB24_5
+	xor	r9d, r9d
+.LBB24_2:
+	cmp	byte ptr [r8 + r9], 32
+	je	.LBB24_5
+	inc	r9
+	cmp	rcx, r9
+	jne	.LBB24_2
+	mov	r9, rcx
+.LBB24_5:
+	add	rax, rdx
+	sub	rcx, r9
+	lea	rdx, [r8 + r9]
+	mov	qword ptr [rsi], rdx
+	mov	qword ptr [rsi + 8], rcx
+	lea	rcx, [r9 + rax]
+	mov	qword ptr [rsi + 16], rcx
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], r8
+	mov	qword ptr [rdi + 16], r9
+	ret
+.Lfunc_end24:
+
+<optional_trait_methods::commands::Args>::names:
+.Lfunc_begin25:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 200
+	mov	qword ptr [rsp + 32], rdi
+	lea	r14, [rsp + 40]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r12, qword ptr [r14 + 8]
+	mov	rbx, qword ptr [r14 + 16]
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, qword ptr [rip + bcmp@GOTPCREL]
+.LBB25_1:
+	test	rbx, rbx
+	je	.LBB25_2
+	xor	r13d, r13d
+.LBB25_4:
+	cmp	byte ptr [r12 + r13], 59
+	je	.LBB25_7
+	inc	r13
+	cmp	rbx, r13
+	jne	.LBB25_4
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 24], r12
+	mov	r13, rbx
+	jmp	.LBB25_8
+.LBB25_2:
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	xor	ebx, ebx
+	mov	qword ptr [rsp + 24], r12
+	xor	r13d, r13d
+	jmp	.LBB25_8
+.LBB25_7:
+	lea	rax, [r13 + 1]
+	sub	rbx, rax
+	lea	rax, [r12 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 24], rax
+	mov	dword ptr [rsp + 12], 0
+.LBB25_8:
+	mov	edx, 144
+	lea	rbp, [rsp + 56]
+	mov	rdi, rbp
+	lea	rsi, [rip + .Lanon.5da3227578d25710a3a77221381aa472.6]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r14d, 6
+.LBB25_9:
+	cmp	qword ptr [rbp + 16], r13
+	jne	.LBB25_11
+	mov	rdi, qword ptr [rbp + 8]
+	mov	rsi, r12
+	mov	rdx, r13
+	call	r15
+	test	eax, eax
+	je	.LBB25_12
+.LBB25_11:
+	add	rbp, 24
+	dec	r14
+	jne	.LBB25_9
+	jmp	.LBB25_13
+.LBB25_12:
+	mov	rax, qword ptr [rsp + 16]
+	or	al, byte ptr [rbp]
+	mov	qword ptr [rsp + 16], rax
+.LBB25_13:
+	mov	r12, qword ptr [rsp + 24]
+	cmp	byte ptr [rsp + 12], 0
+	je	.LBB25_1
+	mov	rax, qword ptr [rsp + 32]
+	mov	rcx, qword ptr [rsp + 16]
+	mov	byte ptr [rax], cl
+	mov	byte ptr [rax + 8], -1
+	add	rsp, 200
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end25:
+
+<optional_trait_methods::connection::Listener>::tcp_loopback:
+.Lfunc_begin26:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	qword ptr [rsp + 20], 0
+	mov	word ptr [rsp + 12], 2
+	rol	di, 8
+	mov	word ptr [rsp + 14], di
+	mov	dword ptr [rsp + 16], 16777343
+	mov	edi, 2
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebx, edx
+	mov	ebp, 1
+	test	al, 1
+	jne	.LBB26_5
+	lea	rcx, [rsp + 28]
+	mov	dword ptr [rcx], 1
+	mov	edi, ebx
+	mov	esi, 1
+	mov	edx, 2
+	mov	r8d, 4
+	call	qword ptr [rip + setsockopt@GOTPCREL]
+	lea	rsi, [rsp + 12]
+	mov	edi, ebx
+	mov	edx, 16
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB26_3
+	mov	edi, ebx
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB26_3
+	xor	ebp, ebp
+	jmp	.LBB26_5
+.LBB26_3:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebx, r14d
+.LBB26_5:
+	mov	eax, ebp
+	mov	edx, ebx
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end26:
+
+<optional_trait_methods::connection::Listener>::socket:
+.Lfunc_begin27:
+	push	rbx
+	xor	ebx, ebx
+	mov	esi, 1
+	xor	edx, edx
+	call	qword ptr [rip + socket@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB27_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB27_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end27:
+
+<optional_trait_methods::connection::Listener>::unix:
+.Lfunc_begin28:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 112
+	mov	rdx, rsi
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [rsp + 96], xmm0
+	movups	xmmword ptr [rsp + 84], xmm0
+	movups	xmmword ptr [rsp + 68], xmm0
+	movups	xmmword ptr [rsp + 52], xmm0
+	movups	xmmword ptr [rsp + 36], xmm0
+	movups	xmmword ptr [rsp + 20], xmm0
+	movups	xmmword ptr [rsp + 4], xmm0
+	mov	word ptr [rsp + 2], 1
+	lea	rax, [rsi - 108]
+	cmp	rax, -107
+	jae	.LBB28_2
+	mov	ebx, 1
+	mov	ebp, 36
+	jmp	.LBB28_7
+.LBB28_2:
+	mov	rsi, rdi
+	lea	rbx, [rsp + 4]
+	mov	rdi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	rdi, rbx
+	call	qword ptr [rip + unlink@GOTPCREL]
+	mov	ebx, 1
+	mov	edi, 1
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebp, edx
+	test	al, 1
+	jne	.LBB28_7
+	lea	rsi, [rsp + 2]
+	mov	edi, ebp
+	mov	edx, 110
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB28_6
+	mov	edi, ebp
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB28_6
+	xor	ebx, ebx
+	jmp	.LBB28_7
+.LBB28_6:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebp, r14d
+.LBB28_7:
+	mov	eax, ebx
+	mov	edx, ebp
+	add	rsp, 112
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end28:
+
+<optional_trait_methods::connection::Listener>::accept:
+.Lfunc_begin29:
+	push	rbx
+	xor	ebx, ebx
+	xor	esi, esi
+	xor	edx, edx
+	call	qword ptr [rip + accept@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB29_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB29_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end29:
+
+optional_trait_methods::commands::unrecognized:
+.Lfunc_begin30:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	xor	r14d, r14d
+.LBB30_1:
+	cmp	byte ptr [rbx + r14], 32
+	je	.LBB30_4
+	inc	r14
+	cmp	rsi, r14
+	jne	.LBB30_1
+	mov	r14, rsi
+.LBB30_4:
+	mov	r15d, 8
+	lea	r12, [rip + .Lanon.5da3227578d25710a3a77221381aa472.33]
+	mov	r13, qword ptr [rip + bcmp@GOTPCREL]
+.LBB30_5:
+	cmp	qword ptr [r15 + r12], r14
+	jne	.LBB30_8
+	mov	rdi, qword ptr [r15 + r12 - 8]
+	mov	rsi, rbx
+	mov	rdx, r14
+	call	r13
+	test	eax, eax
+	je	.LBB30_7
+.LBB30_8:
+	add	r15, 16
+	cmp	r15, 152
+	jne	.LBB30_5
+	xor	eax, eax
+	jmp	.LBB30_10
+.LBB30_7:
+	mov	al, 1
+.LBB30_10:
+	pop	rbx
+	pop	r12
+	pop	r13
 	pop	r14
 	pop	r15
 	ret
-.Lfunc_end1:
+.Lfunc_end30:
 
-<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::handle:
-	push	r14
+optional_trait_methods::connection::read_fd:
+.Lfunc_begin31:
 	push	rbx
-	sub	rsp, 24
-	mov	r14, rdi
-	movzx	eax, byte ptr [rsi]
-	test	eax, eax
-	je	.LBB2_5
-	cmp	eax, 1
-	jne	.LBB2_2
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.10]
-	lea	rcx, [rsp + 8]
-	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 16
-	#APP
-	#NO_APP
-	movzx	eax, byte ptr [rsi + 1]
-	cmp	eax, 2
-	je	.LBB2_14
-	mov	rdi, r14
-	cmp	eax, 1
-	je	.LBB2_17
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc
-	jmp	.LBB2_12
-.LBB2_5:
-	cmp	byte ptr [rsi + 8], 0
-	je	.LBB2_6
-	mov	rsi, qword ptr [rsi + 16]
-	mov	rdi, r14
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::set_state
-	jmp	.LBB2_15
-.LBB2_2:
-	cmp	byte ptr [rsi + 8], 0
-	je	.LBB2_3
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.12]
-	lea	rcx, [rsp + 8]
-	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 28
-	#APP
-	#NO_APP
-	mov	rsi, qword ptr [rsi + 16]
-	mov	rdi, r14
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleFactor>::scale_factor
-	jmp	.LBB2_15
-.LBB2_6:
-	mov	rdi, r14
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::get_state
-	lea	rdi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.9]
-	xor	ebx, ebx
+	mov	rax, rdx
+	mov	rbx, rdi
+	mov	edx, 1024
+	mov	edi, esi
 	mov	rsi, rax
-	xor	eax, eax
-	call	qword ptr [rip + printf@GOTPCREL]
-	jmp	.LBB2_7
-.LBB2_3:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.11]
-	lea	rcx, [rsp + 8]
-	mov	qword ptr [rcx], rax
-	mov	qword ptr [rcx + 8], 13
-	#APP
-	#NO_APP
-	mov	rsi, qword ptr [rsi + 16]
-	mov	rdi, r14
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtMul>::mul
-	jmp	.LBB2_15
-.LBB2_14:
-	mov	rdi, r14
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc
+	call	qword ptr [rip + read@GOTPCREL]
 	test	rax, rax
-	jne	.LBB2_15
-	mov	rdi, r14
-.LBB2_17:
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::dec
-.LBB2_12:
+	js	.LBB31_2
+	mov	qword ptr [rbx + 8], rax
+	xor	eax, eax
+	jmp	.LBB31_3
+.LBB31_2:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	eax, dword ptr [rax]
+	mov	dword ptr [rbx + 4], eax
+	mov	eax, 1
+.LBB31_3:
+	mov	dword ptr [rbx], eax
+	pop	rbx
+	ret
+.Lfunc_end31:
+
+optional_trait_methods::connection::write_fd:
+.Lfunc_begin32:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	test	rdx, rdx
+	je	.LBB32_8
+	mov	ebp, ecx
+	mov	rbx, rdx
+	mov	r14, rsi
+	mov	r15d, edi
+	mov	r12, qword ptr [rip + send@GOTPCREL]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+.LBB32_2:
+	mov	edi, r15d
+	mov	rsi, r14
+	mov	rdx, rbx
+	test	bpl, bpl
+	je	.LBB32_3
+	mov	ecx, 16384
+	call	r12
+	jmp	.LBB32_5
+.LBB32_3:
+	call	r13
+.LBB32_5:
 	test	rax, rax
-	je	.LBB2_13
-.LBB2_15:
-	mov	rbx, rax
-	jmp	.LBB2_8
-.LBB2_13:
-	xor	ebx, ebx
-.LBB2_7:
-.LBB2_8:
-	mov	rax, rbx
-	add	rsp, 24
+	js	.LBB32_10
+	cmp	rbx, rax
+	jb	.LBB32_9
+	add	r14, rax
+	sub	rbx, rax
+	jne	.LBB32_2
+.LBB32_8:
+	add	rsp, 8
 	pop	rbx
+	pop	r12
+	pop	r13
 	pop	r14
+	pop	r15
+	pop	rbp
 	ret
-.Lfunc_end2:
+.LBB32_10:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB32_9:
+	mov	rdi, rax
+	mov	rsi, rbx
+	mov	rdx, rbx
+	call	core::slice::index::slice_index_fail
+.Lfunc_end32:
 
-optional_trait_methods::commands::parse_isize:
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg:
+.Lfunc_begin33:
 	test	rsi, rsi
-	je	.LBB3_12
-	movzx	eax, byte ptr [rdi]
-	cmp	eax, 45
-	je	.LBB3_3
-	xor	ecx, ecx
-	cmp	eax, 43
-	je	.LBB3_4
-	jmp	.LBB3_6
-.LBB3_3:
-	mov	cl, 1
-.LBB3_4:
-	dec	rsi
-	je	.LBB3_12
-	inc	rdi
-.LBB3_6:
-	xor	eax, eax
-	xor	r8d, r8d
-	xor	edx, edx
-.LBB3_7:
-	cmp	rsi, r8
-	je	.LBB3_13
-	mov	r9b, byte ptr [rdi + r8]
-	add	r9b, -48
-	cmp	r9b, 9
-	ja	.LBB3_11
-	imul	rdx, rdx, 10
-	jo	.LBB3_11
-	movzx	r9d, r9b
-	inc	r8
-	add	rdx, r9
-	jno	.LBB3_7
-.LBB3_11:
-	ret
-.LBB3_12:
-	xor	eax, eax
-	ret
-.LBB3_13:
-	movzx	ecx, cl
-	neg	rcx
-	or	rcx, 1
-	xor	eax, eax
-	imul	rdx, rcx
-	setno	al
-	ret
-.Lfunc_end3:
-
-<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::get_state:
+	je	.LBB33_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB33_5
+	lea	rdi, [rdi + 8*rax]
+	add	rdi, 304
+	jmp	.LBB33_2
+.LBB33_1:
+	add	rdi, 296
+.LBB33_2:
 	mov	rax, qword ptr [rdi]
 	ret
-.Lfunc_end4:
+.LBB33_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end33:
 
 <optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::set_state:
-	mov	qword ptr [rdi], rsi
-	xor	eax, eax
+.Lfunc_begin34:
+	mov	qword ptr [rdi + 296], rsi
 	ret
-.Lfunc_end5:
+.Lfunc_end34:
+
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::write_reg:
+.Lfunc_begin35:
+	test	rsi, rsi
+	je	.LBB35_1
+	mov	rax, rsi
+	cmp	rsi, 8
+	jae	.LBB35_5
+	mov	qword ptr [rdi + 8*rax + 296], rdx
+	ret
+.LBB35_1:
+	mov	qword ptr [rdi + 296], rdx
+	ret
+.LBB35_5:
+	push	rax
+	dec	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end35:
 
 <optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::dec:
-	dec	qword ptr [rdi]
-	xor	eax, eax
+.Lfunc_begin36:
+	dec	qword ptr [rdi + 296]
 	ret
-.Lfunc_end6:
+.Lfunc_end36:
 
 <optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc:
-	inc	qword ptr [rdi]
-	xor	eax, eax
+.Lfunc_begin37:
+	inc	qword ptr [rdi + 296]
 	ret
-.Lfunc_end7:
+.Lfunc_end37:
 
 <optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtMul>::mul:
+.Lfunc_begin38:
 	cmp	rsi, 7
-	jne	.LBB8_2
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.13]
-	jmp	.LBB8_3
-.LBB8_2:
-	imul	rsi, qword ptr [rdi + 8]
-	imul	rsi, qword ptr [rdi]
-	mov	qword ptr [rdi], rsi
+	jne	.LBB38_2
+	lea	rax, [rip + .Lanon.5da3227578d25710a3a77221381aa472.45]
+	jmp	.LBB38_3
+.LBB38_2:
+	imul	rsi, qword ptr [rdi + 360]
+	imul	rsi, qword ptr [rdi + 296]
+	mov	qword ptr [rdi + 296], rsi
 	xor	eax, eax
-.LBB8_3:
+.LBB38_3:
 	mov	edx, 28
 	ret
-.Lfunc_end8:
+.Lfunc_end38:
 
 <optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleFactor>::scale_factor:
-	mov	qword ptr [rdi + 8], rsi
+.Lfunc_begin39:
+	mov	rax, qword ptr [rdi + 368]
+	mov	rcx, qword ptr [rdi + 376]
+	cmp	rax, rsi
+	cmovg	rsi, rax
+	cmp	rcx, rsi
+	cmovl	rsi, rcx
+	mov	qword ptr [rdi + 360], rsi
+	ret
+.Lfunc_end39:
+
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleBounds>::scale_bounds:
+.Lfunc_begin40:
+	cmp	rsi, rdx
+	jle	.LBB40_2
+	lea	rax, [rip + .Lanon.5da3227578d25710a3a77221381aa472.42]
+	jmp	.LBB40_3
+.LBB40_2:
+	mov	qword ptr [rdi + 368], rsi
+	mov	qword ptr [rdi + 376], rdx
+	mov	rax, qword ptr [rdi + 360]
+	cmp	rsi, rax
+	cmovg	rax, rsi
+	cmp	rdx, rax
+	cmovl	rax, rdx
+	mov	qword ptr [rdi + 360], rax
 	xor	eax, eax
+.LBB40_3:
+	mov	edx, 26
 	ret
-.Lfunc_end9:
+.Lfunc_end40:
 
-main:
-	push	rbp
-	push	r15
-	push	r14
-	push	r13
-	push	r12
-	push	rbx
-	sub	rsp, 1224
-	lea	rbx, [rsp + 32]
-	mov	qword ptr [rbx], 0
-	mov	qword ptr [rbx + 8], 1
-	lea	r14, [rsp + 184]
-	xor	r13d, r13d
-	mov	edx, 1040
-	mov	rdi, r14
-	xor	esi, esi
-	call	qword ptr [rip + memset@GOTPCREL]
-	xorps	xmm0, xmm0
-	lea	r15, [rsp + 48]
-	movaps	xmmword ptr [r15 + 112], xmm0
-	movaps	xmmword ptr [r15 + 96], xmm0
-	movaps	xmmword ptr [r15 + 80], xmm0
-	movaps	xmmword ptr [r15 + 64], xmm0
-	movaps	xmmword ptr [r15 + 48], xmm0
-	movaps	xmmword ptr [r15 + 32], xmm0
-	movaps	xmmword ptr [r15 + 16], xmm0
-	movaps	xmmword ptr [r15], xmm0
-	mov	r12, qword ptr [rip + read@GOTPCREL]
-	xor	eax, eax
-.LBB10_1:
-	xor	ebp, ebp
-.LBB10_2:
-	cmp	rax, r13
-	jb	.LBB10_5
-	mov	edx, 1024
-	xor	edi, edi
-	mov	rsi, r14
-	call	r12
-	test	rax, rax
-	jle	.LBB10_20
-	mov	r13, rax
-	mov	qword ptr [rsp + 1208], rax
-	mov	qword ptr [rsp + 1216], 0
-	xor	eax, eax
-.LBB10_5:
-	cmp	r13, rax
-	jb	.LBB10_19
-	cmp	r13, 1024
-	ja	.LBB10_19
-	cmp	rax, r13
-	je	.LBB10_2
-	inc	rax
-	mov	rcx, r13
-	neg	rcx
-.LBB10_9:
-	mov	dl, byte ptr [rsp + rax + 183]
-	mov	qword ptr [rsp + 1216], rax
-	cmp	dl, 10
-	je	.LBB10_10
-	cmp	rbp, 127
-	ja	.LBB10_14
-	mov	byte ptr [rsp + rbp + 48], dl
-	inc	rbp
-.LBB10_14:
-	lea	rdx, [rcx + rax]
-	inc	rdx
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtDiv>::div:
+.Lfunc_begin41:
+	test	rsi, rsi
+	je	.LBB41_1
+	mov	rax, qword ptr [rdi + 296]
+	mov	rcx, rax
+	btc	rcx, 63
+	mov	rdx, rsi
+	not	rdx
+	or	rdx, rcx
+	je	.LBB41_3
+	cqo
+	idiv	rsi
+	mov	qword ptr [rdi + 296], rax
+	xor	eax, eax
+	ret
+.LBB41_1:
+	lea	rax, [rip + .Lanon.5da3227578d25710a3a77221381aa472.43]
+	mov	edx, 17
+	ret
+.LBB41_3:
+	lea	rax, [rip + .Lanon.5da3227578d25710a3a77221381aa472.44]
+	mov	edx, 29
+	ret
+.Lfunc_end41:
+
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtDiv>::rem:
+.Lfunc_begin42:
+	test	rsi, rsi
+	je	.LBB42_1
+	mov	rax, qword ptr [rdi + 296]
+	mov	rcx, rax
+	btc	rcx, 63
+	mov	rdx, rsi
+	not	rdx
+	or	rdx, rcx
+	je	.LBB42_3
+	cqo
+	idiv	rsi
+	mov	qword ptr [rdi + 296], rdx
+	xor	eax, eax
+	ret
+.LBB42_1:
+	lea	rax, [rip + .Lanon.5da3227578d25710a3a77221381aa472.43]
+	mov	edx, 17
+	ret
+.LBB42_3:
+	lea	rax, [rip + .Lanon.5da3227578d25710a3a77221381aa472.44]
+	mov	edx, 29
+	ret
+.Lfunc_end42:
+
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint:
+.Lfunc_begin43:
+	push	rax
+	mov	rcx, qword ptr [rdi + 296]
+	mov	rdx, qword ptr [rdi + 360]
+	mov	rax, qword ptr [rdi + 280]
+	lea	rsi, [rdi + 24]
+	cmp	qword ptr [rdi + 288], 16
+	jne	.LBB43_3
+	cmp	rax, 16
+	jae	.LBB43_5
+	mov	r8, rax
+	shl	r8, 4
+	movups	xmm0, xmmword ptr [rsi + r8]
+	mov	qword ptr [rdi], 1
+	movups	xmmword ptr [rdi + 8], xmm0
+	jmp	.LBB43_4
+.LBB43_3:
+	mov	qword ptr [rdi], 0
+	cmp	rax, 16
+	jae	.LBB43_5
+.LBB43_4:
+	shl	rax, 4
+	mov	qword ptr [rsi + rax], rcx
+	mov	qword ptr [rsi + rax + 8], rdx
+	mov	eax, dword ptr [rdi + 280]
+	inc	eax
+	and	eax, 15
+	mov	qword ptr [rdi + 280], rax
+	mov	rax, qword ptr [rdi + 288]
 	inc	rax
-	cmp	rdx, 1
-	jne	.LBB10_9
-	mov	rax, r13
-	jmp	.LBB10_2
-.LBB10_20:
-	lea	rax, [rbp - 129]
-	cmp	rax, -128
-	jb	.LBB10_19
-	jmp	.LBB10_21
-.LBB10_10:
-	cmp	rbp, 128
-	ja	.LBB10_19
-	test	rbp, rbp
-	je	.LBB10_1
-.LBB10_21:
-	lea	r13, [rsp + 8]
-	mov	rdi, r13
-	mov	rsi, r15
-	mov	rdx, rbp
-	call	<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::parse_command
-	cmp	byte ptr [rsp + 8], -1
-	je	.LBB10_22
-	mov	rdi, rbx
-	mov	rsi, r13
-	call	<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::handle
+	cmp	rax, 16
+	mov	ecx, 16
+	cmovb	rcx, rax
+	mov	qword ptr [rdi + 288], rcx
+	pop	rax
+	ret
+.LBB43_5:
+	mov	esi, 16
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end43:
+
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::undo:
+.Lfunc_begin44:
+	mov	rax, qword ptr [rdi + 288]
+	lea	rcx, [rsi - 1]
+	cmp	rcx, rax
+	jae	.LBB44_2
+	mov	qword ptr [rdi], 0
+	mov	ecx, dword ptr [rdi + 280]
+	sub	ecx, esi
+	and	ecx, 15
+	mov	qword ptr [rdi + 280], rcx
+	sub	rax, rsi
+	mov	qword ptr [rdi + 288], rax
+	shl	ecx, 4
+	mov	rax, qword ptr [rdi + rcx + 24]
+	mov	rcx, qword ptr [rdi + rcx + 32]
+	mov	rdx, qword ptr [rdi + 368]
+	mov	rsi, qword ptr [rdi + 376]
+	mov	qword ptr [rdi + 296], rax
+	cmp	rdx, rcx
+	cmovg	rcx, rdx
+	cmp	rsi, rcx
+	cmovl	rcx, rsi
+	mov	qword ptr [rdi + 360], rcx
+	jmp	.LBB44_3
+.LBB44_2:
+	test	rsi, rsi
+	je	.LBB44_3
+	xor	ecx, ecx
 	test	rax, rax
-	jne	.LBB10_18
-	jmp	.LBB10_17
-.LBB10_22:
-	mov	edx, 16
-	mov	edi, 1
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.7]
-	mov	r13, qword ptr [rip + write@GOTPCREL]
-	call	r13
-	mov	edx, 1
-	mov	edi, 1
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	call	r13
-.LBB10_17:
-	mov	r13, qword ptr [rsp + 1208]
-	mov	rax, qword ptr [rsp + 1216]
-	jmp	.LBB10_1
-.LBB10_18:
-	mov	edi, 1
-	mov	rsi, rax
-	mov	rbx, qword ptr [rip + write@GOTPCREL]
-	call	rbx
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	mov	edx, 1
-	mov	edi, 1
-	call	rbx
-.LBB10_19:
+	setne	cl
+	lea	rdx, [rcx + 2*rcx + 16]
+	lea	rcx, [rip + .Lanon.5da3227578d25710a3a77221381aa472.47]
+	lea	rax, [rip + .Lanon.5da3227578d25710a3a77221381aa472.46]
+	cmove	rax, rcx
+	ret
+.LBB44_3:
 	xor	eax, eax
-	add	rsp, 1224
-	pop	rbx
-	pop	r12
-	pop	r13
-	pop	r14
-	pop	r15
-	pop	rbp
 	ret
-.Lfunc_end10:
+.Lfunc_end44:
 
-rust_eh_personality:
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::rollback:
+.Lfunc_begin45:
+	mov	rsi, qword ptr [rdi]
+	mov	rax, qword ptr [rdi + 8]
+	mov	rcx, qword ptr [rdi + 16]
+	mov	qword ptr [rdi], 0
+	mov	rdx, qword ptr [rdi + 288]
+	test	rdx, rdx
+	je	.LBB45_1
+	mov	r8d, dword ptr [rdi + 280]
+	dec	r8d
+	and	r8d, 15
+	mov	qword ptr [rdi + 280], r8
+	lea	r9, [rdx - 1]
+	mov	qword ptr [rdi + 288], r9
+	shl	r8d, 4
+	mov	r9, qword ptr [rdi + r8 + 24]
+	mov	r10, qword ptr [rdi + r8 + 32]
+	cmp	rsi, 1
+	jne	.LBB45_4
+	lea	rsi, [rdi + r8]
+	add	rsi, 24
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rdi + 288], rdx
+.LBB45_4:
+	mov	qword ptr [rdi + 296], r9
+	mov	qword ptr [rdi + 360], r10
+	xor	eax, eax
 	ret
-.Lfunc_end11:
+.LBB45_1:
+	lea	rax, [rip + .Lanon.5da3227578d25710a3a77221381aa472.50]
+	ret
+.Lfunc_end45:
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.0:
+	.byte	10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.1:
+	.byte	59
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.2:
+	.ascii	"E 01"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.3:
+	.ascii	"E 02"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.4:
+	.ascii	"E."
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.5:
+	.ascii	" at "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.6:
+	.ascii	"E 05"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.8:
+	.ascii	"0123456789abcdef"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.11:
+	.ascii	"unknown option: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.12:
+	.ascii	"missing value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.13:
+	.ascii	"invalid value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.14:
+	.ascii	": "
 
-.Lanon.cabb224323f7f456a7965482e4c09287.0:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.15:
+	.ascii	" doesn't take a value"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.16:
+	.ascii	"only one of --unix / --tcp may be given"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.20:
+	.ascii	"Invalid implementation!"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.21:
+	.ascii	"OK"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.22:
+	.ascii	"--unix"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.23:
+	.ascii	"--tcp"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.24:
+	.ascii	"--initial-state"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.25:
+	.ascii	"--on-error"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.26:
+	.ascii	"--echo"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.27:
+	.ascii	"--repl"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.28:
+	.ascii	"--help"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.29:
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.22
+	.asciz	"\006\000\000\000\000\000\000\000"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.23
+	.ascii	"\005\000\000\000\000\000\000\000\001"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.24
+	.ascii	"\017\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.25
+	.ascii	"\n\000\000\000\000\000\000\000\003"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.26
+	.ascii	"\006\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.27
+	.ascii	"\006\000\000\000\000\000\000\000\005"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.28
+	.ascii	"\006\000\000\000\000\000\000\000\006"
+	.zero	7
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.30:
 	.ascii	"panic!\n"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.1:
-	.ascii	"Parse IncDec extension"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.31:
+	.ascii	"listening on 127.0.0.1:"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.2:
-	.ascii	"Parse Mul extension"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.32:
+	.ascii	"error: "
 
-.Lanon.cabb224323f7f456a7965482e4c09287.3:
-	.ascii	"* "
+.Lanon.62fb3b87f511695ce61fb365a19fad11.33:
+	.zero	2,10
 
-.Lanon.cabb224323f7f456a7965482e4c09287.4:
-	.ascii	"Parse ScaleFactor extension"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.34:
+	.ascii	"usage: optional-trait-methods [options]\n\n  --unix <path>              serve the first client to connect to a Unix domain socket\n  --tcp <port>               serve the first client to connect to 127.0.0.1:<port>\n                             (port 0 picks any free port, printed to stderr)\n                             (by default, serve stdin / stdout)\n  --initial-state <n>        start the target off in state <n> (by default, 0)\n  --on-error=continue|exit   whether to keep serving after a command fails\n  --echo                     repeat every command before its reply\n  --repl                     expect a human instead of a client (prompt, aliases, help)\n                             (needs the `repl` feature)\n  --help                     print this message\n\n  (`--name <value>` may also be written `--name=<value>`)"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.35:
+	.ascii	"Parse IncDec extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.5:
-	.ascii	"*~ "
+.Lanon.62fb3b87f511695ce61fb365a19fad11.39:
+	.ascii	"Parse Mul extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.6:
-	.ascii	"s "
+.Lanon.62fb3b87f511695ce61fb365a19fad11.41:
+	.ascii	"Parse ScaleFactor extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.7:
-	.ascii	"Unsupported cmd!"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.43:
+	.ascii	"Parse ScaleBounds extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.8:
-	.byte	10
+.Lanon.62fb3b87f511695ce61fb365a19fad11.45:
+	.ascii	"Parse Div extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.9:
-	.asciz	"%d\n"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.48:
+	.ascii	"Parse Undo extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.10:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.57:
 	.ascii	"IncDec extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.11:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.58:
 	.ascii	"Mul extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.12:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.59:
 	.ascii	"ScaleFactor nested extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.13:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.60:
+	.ascii	"ScaleBounds nested extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.61:
+	.ascii	"Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.62:
+	.ascii	"Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.63:
+	.ascii	"Undo checkpoint"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.64:
+	.ascii	"unknown command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.65:
+	.ascii	"unsupported command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.66:
+	.ascii	"bad number"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.67:
+	.ascii	"trailing garbage"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.68:
+	.ascii	"line too long"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.72:
+	.ascii	"failed to accept a connection"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.73:
+	.ascii	"--repl needs the `repl` feature"
+
+.Lanon.5da3227578d25710a3a77221381aa472.0:
+	.ascii	"incdec"
+
+.Lanon.5da3227578d25710a3a77221381aa472.1:
+	.ascii	"mul"
+
+.Lanon.5da3227578d25710a3a77221381aa472.2:
+	.ascii	"mul.scale_factor"
+
+.Lanon.5da3227578d25710a3a77221381aa472.3:
+	.ascii	"mul.scale_factor.scale_bounds"
+
+.Lanon.5da3227578d25710a3a77221381aa472.4:
+	.ascii	"div"
+
+.Lanon.5da3227578d25710a3a77221381aa472.5:
+	.ascii	"undo"
+
+.Lanon.5da3227578d25710a3a77221381aa472.6:
+	.byte	1
+	.zero	7
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.0
+	.ascii	"\006\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.1
+	.ascii	"\003\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.2
+	.ascii	"\020\000\000\000\000\000\000\000\b"
+	.zero	7
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.3
+	.ascii	"\035\000\000\000\000\000\000\000\020"
+	.zero	7
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.4
+	.ascii	"\003\000\000\000\000\000\000\000 "
+	.zero	7
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.5
+	.asciz	"\004\000\000\000\000\000\000"
+
+.Lanon.5da3227578d25710a3a77221381aa472.15:
+	.byte	43
+
+.Lanon.5da3227578d25710a3a77221381aa472.17:
+	.byte	45
+
+.Lanon.5da3227578d25710a3a77221381aa472.18:
+	.byte	42
+
+.Lanon.5da3227578d25710a3a77221381aa472.20:
+	.ascii	"*~"
+
+.Lanon.5da3227578d25710a3a77221381aa472.28:
+	.ascii	"+-"
+
+.Lanon.5da3227578d25710a3a77221381aa472.29:
+	.ascii	"*~~"
+
+.Lanon.5da3227578d25710a3a77221381aa472.30:
+	.byte	47
+
+.Lanon.5da3227578d25710a3a77221381aa472.31:
+	.byte	37
+
+.Lanon.5da3227578d25710a3a77221381aa472.32:
+	.byte	117
+
+.Lanon.5da3227578d25710a3a77221381aa472.33:
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.15
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.17
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.28
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.18
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.20
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.29
+	.asciz	"\003\000\000\000\000\000\000"
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.30
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.31
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.5da3227578d25710a3a77221381aa472.32
+	.asciz	"\001\000\000\000\000\000\000"
+
+.Lanon.5da3227578d25710a3a77221381aa472.42:
+	.ascii	"scale bounds are inverted!"
+
+.Lanon.5da3227578d25710a3a77221381aa472.43:
+	.ascii	"dividing by zero!"
+
+.Lanon.5da3227578d25710a3a77221381aa472.44:
+	.ascii	"dividing MIN by -1 overflows!"
+
+.Lanon.5da3227578d25710a3a77221381aa472.45:
 	.ascii	"multiplying by 7 is unlucky!"
 
-	.ident	"rustc version 1.99.0-nightly (6f72b5dd5 2026-07-22)"
+.Lanon.5da3227578d25710a3a77221381aa472.46:
+	.ascii	"not enough history!"
+
+.Lanon.5da3227578d25710a3a77221381aa472.47:
+	.ascii	"nothing to undo!"
+
+.Lanon.5da3227578d25710a3a77221381aa472.50:
+	.ascii	"nothing to roll back!"
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+
+	.byte	1
+	.byte	17
+	.byte	1
+	.byte	37
+	.byte	14
+	.byte	19
+	.byte	5
+	.byte	3
+	.byte	14
+	.byte	16
+	.byte	23
+	.byte	27
+	.byte	14
+	.byte	17
+	.byte	1
+	.byte	85
+	.byte	23
+	.byte	0
+	.byte	0
+	.byte	2
+	.byte	57
+	.byte	1
+	.byte	3
+	.byte	14
+	.byte	0
+	.byte	0
+	.byte	3
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	11
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	4
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	5
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	0
+.Lcu_begin0:
+	.long	.Ldebug_info_end0-.Ldebug_info_start0
+.Ldebug_info_start0:
+	.short	4
+	.long	.debug_abbrev
+	.byte	8
+	.byte	1
+	.long	.Linfo_string0
+	.short	28
+	.long	.Linfo_string1
+	.long	.Lline_table_start0
+	.long	.Linfo_string2
+	.quad	0
+	.long	.Ldebug_ranges0
+	.byte	2
+	.long	.Linfo_string3
+	.byte	2
+	.long	.Linfo_string4
+	.byte	3
+	.quad	.Lfunc_begin18
+	.long	.Lfunc_end18-.Lfunc_begin18
+	.byte	1
+	.byte	86
+	.long	.Linfo_string7
+	.long	.Linfo_string8
+	.byte	1
+	.byte	60
+	.byte	3
+
+
+	.byte	4
+	.quad	.Lfunc_begin19
+	.long	.Lfunc_end19-.Lfunc_begin19
+	.byte	1
+	.byte	86
+	.long	.Linfo_string9
+	.long	.Linfo_string10
+	.byte	1
+	.short	266
+	.byte	3
+
+
+	.byte	0
+	.byte	2
+	.long	.Linfo_string5
+	.byte	2
+	.long	.Linfo_string6
+	.byte	3
+	.quad	.Lfunc_begin20
+	.long	.Lfunc_end20-.Lfunc_begin20
+	.byte	1
+	.byte	86
+	.long	.Linfo_string11
+	.long	.Linfo_string12
+	.byte	2
+	.byte	38
+	.byte	3
+
+
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+.Ldebug_info_end0:
+.Lsec_end0:
+.Lsec_end1:
+.Lsec_end2:
+	.long	76
+	.short	2
+	.long	.Lcu_begin0
+	.byte	8
+	.byte	0
+	.zero	4,255
+	.quad	.Lfunc_begin18
+	.quad	.Lsec_end0-.Lfunc_begin18
+	.quad	.Lfunc_begin19
+	.quad	.Lsec_end1-.Lfunc_begin19
+	.quad	.Lfunc_begin20
+	.quad	.Lsec_end2-.Lfunc_begin20
+	.quad	0
+	.quad	0
+.Ldebug_ranges0:
+	.quad	.Lfunc_begin18
+	.quad	.Lfunc_end18
+	.quad	.Lfunc_begin19
+	.quad	.Lfunc_end19
+	.quad	.Lfunc_begin20
+	.quad	.Lfunc_end20
+	.quad	0
+	.quad	0
+.Linfo_string0:
+	.asciz	"clang LLVM (rustc version 1.97.0-nightly (e50aa6fba 2026-05-19))"
+.Linfo_string1:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a/library/core/src/lib.rs/@/core.667c7a611d73a360-cgu.0"
+.Linfo_string2:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a"
+.Linfo_string3:
+	.asciz	"core"
+.Linfo_string4:
+	.asciz	"panicking"
+.Linfo_string5:
+	.asciz	"slice"
+.Linfo_string6:
+	.asciz	"index"
+.Linfo_string7:
+	.asciz	"core::panicking::panic_fmt"
+.Linfo_string8:
+	.asciz	"panic_fmt"
+.Linfo_string9:
+	.asciz	"core::panicking::panic_bounds_check"
+.Linfo_string10:
+	.asciz	"panic_bounds_check"
+.Linfo_string11:
+	.asciz	"core::slice::index::slice_index_fail"
+.Linfo_string12:
+	.asciz	"slice_index_fail"
+	.ident	"rustc version 1.97.0-nightly (e50aa6fba 2026-05-19)"
+.Lline_table_start0:
@@ -0,0 +1,5396 @@
+.LCPI0_0:
+	.long	2
+	.long	0
+	.long	0
+	.long	0
+<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin0:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 472
+	mov	r12, rcx
+	mov	rbx, rdx
+	mov	r15, rsi
+	movzx	r13d, byte ptr [rcx]
+	lea	rax, [r13 - 6]
+	xor	ebp, ebp
+	cmp	r13, 7
+	cmovae	rbp, rax
+	mov	r14, rdi
+	lea	rax, [rip + .LJTI0_0]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB0_1:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+	lea	rax, [rip + .LJTI0_1]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB0_2:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI0_2]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB0_3:
+	mov	r12, qword ptr [r15 + 576]
+	mov	r13, qword ptr [r15 + 584]
+	jmp	.LBB0_50
+.LBB0_4:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 14
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::undo
+	test	rax, rax
+	je	.LBB0_46
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], rdx
+	jmp	.LBB0_11
+.LBB0_6:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI0_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB0_7:
+	movdqa	xmm0, xmmword ptr [r15 + 576]
+	mov	byte ptr [rsp], 1
+	movdqa	xmmword ptr [rsp + 16], xmm0
+	jmp	.LBB0_65
+.LBB0_8:
+	movdqa	xmm0, xmmword ptr [r12 + 16]
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI0_0]
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	jne	.LBB0_13
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	r8, qword ptr [r12 + 56]
+	mov	rcx, qword ptr [r12 + 48]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds
+	test	rax, rax
+	je	.LBB0_46
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 26
+	jmp	.LBB0_11
+.LBB0_12:
+	mov	r13, qword ptr [r12 + 16]
+	jmp	.LBB0_14
+.LBB0_13:
+	mov	r13, qword ptr [r12 + 16]
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+.LBB0_14:
+	test	r13, r13
+	je	.LBB0_23
+	cmp	r13, 1
+	jne	.LBB0_24
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_factor
+	jmp	.LBB0_62
+.LBB0_17:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	test	byte ptr [r12 + 16], 1
+	je	.LBB0_25
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rem
+	jmp	.LBB0_26
+.LBB0_19:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 16
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [r12 + 1]
+	test	eax, eax
+	je	.LBB0_30
+	cmp	eax, 1
+	je	.LBB0_22
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
+.LBB0_22:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::dec
+	jmp	.LBB0_62
+.LBB0_23:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::mul
+	jmp	.LBB0_26
+.LBB0_24:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	r8, qword ptr [r12 + 56]
+	mov	rcx, qword ptr [r12 + 48]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds
+	jmp	.LBB0_26
+.LBB0_25:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::div
+.LBB0_26:
+	test	rax, rax
+	je	.LBB0_62
+	mov	r12, rax
+	mov	r13, rdx
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rollback
+	test	rax, rax
+	je	.LBB0_29
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 21
+.LBB0_11:
+	mov	byte ptr [rsp], -1
+	jmp	.LBB0_65
+.LBB0_29:
+	mov	al, -1
+	jmp	.LBB0_56
+.LBB0_30:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
+	jmp	.LBB0_62
+.LBB0_31:
+	mov	rcx, qword ptr [r12 + 24]
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	jmp	.LBB0_46
+.LBB0_32:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	byte ptr [rsp], 2
+	mov	byte ptr [rsp + 1], cl
+	jmp	.LBB0_65
+.LBB0_33:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	mov	byte ptr [rsp], 1
+	mov	qword ptr [rsp + 24], rdx
+	mov	qword ptr [rsp + 16], rax
+	jmp	.LBB0_65
+.LBB0_34:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+.LBB0_35:
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdx, qword ptr [r12 + 24]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::set_state
+	jmp	.LBB0_62
+.LBB0_36:
+	lea	rdi, [rsp + 160]
+	xor	r12d, r12d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+.LBB0_37:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB0_39
+	shl	r13, 4
+	mov	qword ptr [rsp + r13 + 160], rax
+	mov	qword ptr [rsp + r13 + 168], rdx
+	mov	r13, qword ptr [rsp + 288]
+	inc	r13
+	mov	qword ptr [rsp + 288], r13
+.LBB0_39:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB0_37
+	lea	rdi, [rsp + 319]
+	lea	rsi, [rsp + 160]
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	mov	edx, 144
+	call	r15
+	lea	rdi, [rsp + 1]
+	mov	byte ptr [rdi - 1], 3
+	lea	rsi, [rsp + 304]
+	mov	edx, 159
+	call	r15
+	jmp	.LBB0_65
+.LBB0_41:
+	mov	rdi, rsp
+	xor	r13d, r13d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	ebp, ebp
+.LBB0_42:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	rbp, 7
+	ja	.LBB0_44
+	shl	rbp, 4
+	mov	qword ptr [rsp + rbp], rax
+	mov	qword ptr [rsp + rbp + 8], rdx
+	mov	rbp, qword ptr [rsp + 128]
+	inc	rbp
+	mov	qword ptr [rsp + 128], rbp
+.LBB0_44:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB0_42
+	add	r12, 16
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB0_46:
+	mov	byte ptr [rsp], 0
+	jmp	.LBB0_65
+.LBB0_47:
+	mov	rcx, qword ptr [r12 + 24]
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	jmp	.LBB0_62
+.LBB0_48:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	al, 2
+	jmp	.LBB0_63
+.LBB0_49:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	mov	r12, rax
+	mov	r13, rdx
+.LBB0_50:
+	mov	al, 1
+	jmp	.LBB0_56
+.LBB0_51:
+	mov	rdi, rsp
+	xor	r12d, r12d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+.LBB0_52:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB0_54
+	shl	r13, 4
+	mov	qword ptr [rsp + r13], rax
+	mov	qword ptr [rsp + r13 + 8], rdx
+	mov	r13, qword ptr [rsp + 128]
+	inc	r13
+	mov	qword ptr [rsp + 128], r13
+.LBB0_54:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB0_52
+	lea	rsi, [rsp + 16]
+	mov	r12, qword ptr [rsi - 16]
+	mov	r13, qword ptr [rsi - 8]
+	lea	rdi, [rsp + 304]
+	mov	ecx, 16
+	rep movsq es:[rdi], [rsi]
+	mov	al, 3
+.LBB0_56:
+	jmp	.LBB0_64
+.LBB0_57:
+	mov	rdi, rsp
+	xor	r13d, r13d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	ebp, ebp
+.LBB0_58:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	rbp, 7
+	ja	.LBB0_60
+	shl	rbp, 4
+	mov	qword ptr [rsp + rbp], rax
+	mov	qword ptr [rsp + rbp + 8], rdx
+	mov	rbp, qword ptr [rsp + 128]
+	inc	rbp
+	mov	qword ptr [rsp + 128], rbp
+.LBB0_60:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB0_58
+	add	r12, 16
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB0_62:
+	xor	eax, eax
+.LBB0_63:
+.LBB0_64:
+	lea	rdi, [rsp + 32]
+	mov	byte ptr [rdi - 32], al
+	mov	byte ptr [rdi - 31], cl
+	mov	qword ptr [rdi - 24], 0
+	mov	qword ptr [rdi - 8], r13
+	mov	qword ptr [rdi - 16], r12
+	lea	rsi, [rsp + 304]
+	mov	ecx, 16
+	rep movsq es:[rdi], [rsi]
+.LBB0_65:
+	mov	rdx, rsp
+	mov	rdi, r14
+	mov	rsi, rbx
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	add	rsp, 472
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end0:
+.LJTI0_0:
+	.long	.LBB0_6-.LJTI0_0
+	.long	.LBB0_1-.LJTI0_0
+	.long	.LBB0_8-.LJTI0_0
+	.long	.LBB0_1-.LJTI0_0
+	.long	.LBB0_4-.LJTI0_0
+.LJTI0_1:
+	.long	.LBB0_2-.LJTI0_1
+	.long	.LBB0_19-.LJTI0_1
+	.long	.LBB0_12-.LJTI0_1
+	.long	.LBB0_17-.LJTI0_1
+.LJTI0_2:
+	.long	.LBB0_3-.LJTI0_2
+	.long	.LBB0_35-.LJTI0_2
+	.long	.LBB0_48-.LJTI0_2
+	.long	.LBB0_49-.LJTI0_2
+	.long	.LBB0_47-.LJTI0_2
+	.long	.LBB0_51-.LJTI0_2
+	.long	.LBB0_57-.LJTI0_2
+.LJTI0_3:
+	.long	.LBB0_7-.LJTI0_3
+	.long	.LBB0_34-.LJTI0_3
+	.long	.LBB0_32-.LJTI0_3
+	.long	.LBB0_33-.LJTI0_3
+	.long	.LBB0_31-.LJTI0_3
+	.long	.LBB0_36-.LJTI0_3
+	.long	.LBB0_41-.LJTI0_3
+
+.LCPI1_0:
+	.long	2
+	.long	0
+	.long	0
+	.long	0
+<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin1:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 472
+	mov	r12, rcx
+	mov	rbx, rdx
+	mov	r15, rsi
+	movzx	r13d, byte ptr [rcx]
+	lea	rax, [r13 - 6]
+	xor	ebp, ebp
+	cmp	r13, 7
+	cmovae	rbp, rax
+	mov	r14, rdi
+	lea	rax, [rip + .LJTI1_0]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB1_1:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+	lea	rax, [rip + .LJTI1_1]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB1_2:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI1_2]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB1_3:
+	mov	r12, qword ptr [r15 + 576]
+	mov	r13, qword ptr [r15 + 584]
+	jmp	.LBB1_50
+.LBB1_4:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 14
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::undo
+	test	rax, rax
+	je	.LBB1_46
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], rdx
+	jmp	.LBB1_11
+.LBB1_6:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI1_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB1_7:
+	movdqa	xmm0, xmmword ptr [r15 + 576]
+	mov	byte ptr [rsp], 1
+	movdqa	xmmword ptr [rsp + 16], xmm0
+	jmp	.LBB1_65
+.LBB1_8:
+	movdqa	xmm0, xmmword ptr [r12 + 16]
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI1_0]
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	jne	.LBB1_13
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	r8, qword ptr [r12 + 56]
+	mov	rcx, qword ptr [r12 + 48]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds
+	test	rax, rax
+	je	.LBB1_46
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 26
+	jmp	.LBB1_11
+.LBB1_12:
+	mov	r13, qword ptr [r12 + 16]
+	jmp	.LBB1_14
+.LBB1_13:
+	mov	r13, qword ptr [r12 + 16]
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+.LBB1_14:
+	test	r13, r13
+	je	.LBB1_23
+	cmp	r13, 1
+	jne	.LBB1_24
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_factor
+	jmp	.LBB1_62
+.LBB1_17:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	test	byte ptr [r12 + 16], 1
+	je	.LBB1_25
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rem
+	jmp	.LBB1_26
+.LBB1_19:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 16
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [r12 + 1]
+	test	eax, eax
+	je	.LBB1_30
+	cmp	eax, 1
+	je	.LBB1_22
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
+.LBB1_22:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::dec
+	jmp	.LBB1_62
+.LBB1_23:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::mul
+	jmp	.LBB1_26
+.LBB1_24:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	r8, qword ptr [r12 + 56]
+	mov	rcx, qword ptr [r12 + 48]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds
+	jmp	.LBB1_26
+.LBB1_25:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::div
+.LBB1_26:
+	test	rax, rax
+	je	.LBB1_62
+	mov	r12, rax
+	mov	r13, rdx
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rollback
+	test	rax, rax
+	je	.LBB1_29
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 21
+.LBB1_11:
+	mov	byte ptr [rsp], -1
+	jmp	.LBB1_65
+.LBB1_29:
+	mov	al, -1
+	jmp	.LBB1_56
+.LBB1_30:
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
+	jmp	.LBB1_62
+.LBB1_31:
+	mov	rcx, qword ptr [r12 + 24]
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	jmp	.LBB1_46
+.LBB1_32:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	byte ptr [rsp], 2
+	mov	byte ptr [rsp + 1], cl
+	jmp	.LBB1_65
+.LBB1_33:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	mov	byte ptr [rsp], 1
+	mov	qword ptr [rsp + 24], rdx
+	mov	qword ptr [rsp + 16], rax
+	jmp	.LBB1_65
+.LBB1_34:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+.LBB1_35:
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdx, qword ptr [r12 + 24]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::set_state
+	jmp	.LBB1_62
+.LBB1_36:
+	lea	rdi, [rsp + 160]
+	xor	r12d, r12d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+.LBB1_37:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB1_39
+	shl	r13, 4
+	mov	qword ptr [rsp + r13 + 160], rax
+	mov	qword ptr [rsp + r13 + 168], rdx
+	mov	r13, qword ptr [rsp + 288]
+	inc	r13
+	mov	qword ptr [rsp + 288], r13
+.LBB1_39:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB1_37
+	lea	rdi, [rsp + 319]
+	lea	rsi, [rsp + 160]
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	mov	edx, 144
+	call	r15
+	lea	rdi, [rsp + 1]
+	mov	byte ptr [rdi - 1], 3
+	lea	rsi, [rsp + 304]
+	mov	edx, 159
+	call	r15
+	jmp	.LBB1_65
+.LBB1_41:
+	mov	rdi, rsp
+	xor	r13d, r13d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	ebp, ebp
+.LBB1_42:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	rbp, 7
+	ja	.LBB1_44
+	shl	rbp, 4
+	mov	qword ptr [rsp + rbp], rax
+	mov	qword ptr [rsp + rbp + 8], rdx
+	mov	rbp, qword ptr [rsp + 128]
+	inc	rbp
+	mov	qword ptr [rsp + 128], rbp
+.LBB1_44:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB1_42
+	add	r12, 16
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB1_46:
+	mov	byte ptr [rsp], 0
+	jmp	.LBB1_65
+.LBB1_47:
+	mov	rcx, qword ptr [r12 + 24]
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	jmp	.LBB1_62
+.LBB1_48:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	al, 2
+	jmp	.LBB1_63
+.LBB1_49:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	mov	r12, rax
+	mov	r13, rdx
+.LBB1_50:
+	mov	al, 1
+	jmp	.LBB1_56
+.LBB1_51:
+	mov	rdi, rsp
+	xor	r12d, r12d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+.LBB1_52:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB1_54
+	shl	r13, 4
+	mov	qword ptr [rsp + r13], rax
+	mov	qword ptr [rsp + r13 + 8], rdx
+	mov	r13, qword ptr [rsp + 128]
+	inc	r13
+	mov	qword ptr [rsp + 128], r13
+.LBB1_54:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB1_52
+	lea	rsi, [rsp + 16]
+	mov	r12, qword ptr [rsi - 16]
+	mov	r13, qword ptr [rsi - 8]
+	lea	rdi, [rsp + 304]
+	mov	ecx, 16
+	rep movsq es:[rdi], [rsi]
+	mov	al, 3
+.LBB1_56:
+	jmp	.LBB1_64
+.LBB1_57:
+	mov	rdi, rsp
+	xor	r13d, r13d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	ebp, ebp
+.LBB1_58:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	rbp, 7
+	ja	.LBB1_60
+	shl	rbp, 4
+	mov	qword ptr [rsp + rbp], rax
+	mov	qword ptr [rsp + rbp + 8], rdx
+	mov	rbp, qword ptr [rsp + 128]
+	inc	rbp
+	mov	qword ptr [rsp + 128], rbp
+.LBB1_60:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB1_58
+	add	r12, 16
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB1_62:
+	xor	eax, eax
+.LBB1_63:
+.LBB1_64:
+	lea	rdi, [rsp + 32]
+	mov	byte ptr [rdi - 32], al
+	mov	byte ptr [rdi - 31], cl
+	mov	qword ptr [rdi - 24], 0
+	mov	qword ptr [rdi - 8], r13
+	mov	qword ptr [rdi - 16], r12
+	lea	rsi, [rsp + 304]
+	mov	ecx, 16
+	rep movsq es:[rdi], [rsi]
+.LBB1_65:
+	mov	rdx, rsp
+	mov	rdi, r14
+	mov	rsi, rbx
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	add	rsp, 472
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end1:
+.LJTI1_0:
+	.long	.LBB1_6-.LJTI1_0
+	.long	.LBB1_1-.LJTI1_0
+	.long	.LBB1_8-.LJTI1_0
+	.long	.LBB1_1-.LJTI1_0
+	.long	.LBB1_4-.LJTI1_0
+.LJTI1_1:
+	.long	.LBB1_2-.LJTI1_1
+	.long	.LBB1_19-.LJTI1_1
+	.long	.LBB1_12-.LJTI1_1
+	.long	.LBB1_17-.LJTI1_1
+.LJTI1_2:
+	.long	.LBB1_3-.LJTI1_2
+	.long	.LBB1_35-.LJTI1_2
+	.long	.LBB1_48-.LJTI1_2
+	.long	.LBB1_49-.LJTI1_2
+	.long	.LBB1_47-.LJTI1_2
+	.long	.LBB1_51-.LJTI1_2
+	.long	.LBB1_57-.LJTI1_2
+.LJTI1_3:
+	.long	.LBB1_7-.LJTI1_3
+	.long	.LBB1_34-.LJTI1_3
+	.long	.LBB1_32-.LJTI1_3
+	.long	.LBB1_33-.LJTI1_3
+	.long	.LBB1_31-.LJTI1_3
+	.long	.LBB1_36-.LJTI1_3
+	.long	.LBB1_41-.LJTI1_3
+
+<optional_trait_methods::commands::Args>::num::<i128>:
+.Lfunc_begin2:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rsi, qword ptr [r14 + 8]
+	mov	rdx, qword ptr [r14 + 16]
+	mov	r14, rsp
+	mov	rdi, r14
+	call	optional_trait_methods::num::parse_num::<i128>
+	test	byte ptr [r14], 1
+	je	.LBB2_2
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	qword ptr [rbx + 16], rax
+	mov	qword ptr [rbx + 24], rcx
+	xor	eax, eax
+	jmp	.LBB2_3
+.LBB2_2:
+	mov	qword ptr [rbx + 8], r15
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB2_3:
+	mov	qword ptr [rbx], rax
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end2:
+
+<optional_trait_methods::commands::Args>::regs::<i128>:
+.Lfunc_begin3:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	sub	rsp, 168
+	mov	rbx, rdi
+	lea	r14, [rsp + 16]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	cmp	qword ptr [r14 + 16], 256
+	jne	.LBB3_20
+	lea	rdi, [rsp + 16]
+	mov	r12, qword ptr [rdi + 8]
+	xor	r14d, r14d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	eax, 256
+	xorps	xmm0, xmm0
+.LBB3_2:
+	mov	rcx, r12
+	add	r12, 32
+	add	rax, -32
+	movaps	xmmword ptr [rsp], xmm0
+	xor	edx, edx
+.LBB3_3:
+	mov	dil, byte ptr [rcx + 2*rdx]
+	lea	esi, [rdi - 48]
+	cmp	sil, 10
+	jb	.LBB3_13
+	lea	esi, [rdi - 97]
+	cmp	sil, 5
+	ja	.LBB3_10
+	add	dil, -87
+	jmp	.LBB3_12
+.LBB3_10:
+	lea	esi, [rdi - 71]
+	cmp	sil, -6
+	jb	.LBB3_20
+	add	dil, -55
+.LBB3_12:
+	mov	esi, edi
+.LBB3_13:
+	mov	dil, byte ptr [rcx + 2*rdx + 1]
+	lea	r8d, [rdi - 48]
+	cmp	r8b, 10
+	jb	.LBB3_19
+	lea	r8d, [rdi - 97]
+	cmp	r8b, 5
+	ja	.LBB3_16
+	add	dil, -87
+	jmp	.LBB3_18
+.LBB3_16:
+	lea	r8d, [rdi - 71]
+	cmp	r8b, -6
+	jb	.LBB3_20
+	add	dil, -55
+.LBB3_18:
+	mov	r8d, edi
+.LBB3_19:
+	shl	sil, 4
+	or	sil, r8b
+	mov	byte ptr [rsp + rdx], sil
+	inc	rdx
+	cmp	rdx, 16
+	jne	.LBB3_3
+	cmp	r14, 7
+	ja	.LBB3_8
+	movaps	xmm1, xmmword ptr [rsp]
+	shl	r14, 4
+	movaps	xmmword ptr [rsp + r14 + 16], xmm1
+	mov	r14, qword ptr [rsp + 144]
+	inc	r14
+	mov	qword ptr [rsp + 144], r14
+.LBB3_8:
+	test	rax, rax
+	jne	.LBB3_2
+	lea	rdi, [rbx + 16]
+	lea	rsi, [rsp + 16]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	xor	eax, eax
+	jmp	.LBB3_21
+.LBB3_20:
+	mov	qword ptr [rbx + 8], r15
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB3_21:
+	mov	qword ptr [rbx], rax
+	add	rsp, 168
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end3:
+
+<optional_trait_methods::commands::Args>::num_or::<usize>:
+.Lfunc_begin4:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	cmp	qword ptr [rsi + 8], 0
+	je	.LBB4_1
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	and	al, 1
+	cmove	rdx, r15
+	neg	al
+	or	al, 2
+	jmp	.LBB4_3
+.LBB4_1:
+	mov	al, -1
+	mov	edx, 1
+.LBB4_3:
+	mov	qword ptr [rbx], rdx
+	mov	byte ptr [rbx + 8], al
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end4:
+
+<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>:
+.Lfunc_begin5:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rax, qword ptr [rdi + 128]
+	mov	rcx, rax
+	shl	rcx, 4
+	xor	r15d, r15d
+	cmp	rax, 9
+	mov	r12d, 16
+	cmovb	r12, rdi
+	cmovb	r15, rcx
+	test	r15, r15
+	je	.LBB5_3
+	mov	rbx, rsi
+	xor	r14d, r14d
+	xor	r13d, r13d
+.LBB5_2:
+	mov	rdx, qword ptr [r12 + r13]
+	mov	rcx, qword ptr [r12 + r13 + 8]
+	mov	rdi, rbx
+	mov	rsi, r14
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	inc	r14
+	add	r13, 16
+	cmp	r15, r13
+	jne	.LBB5_2
+.LBB5_3:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end5:
+
+optional_trait_methods::num::format_decimal::<usize>:
+.Lfunc_begin6:
+	mov	eax, 39
+	movabs	r8, -3689348814741910323
+.LBB6_1:
+	cmp	rax, 39
+	ja	.LBB6_4
+	mov	rcx, rax
+	mov	rax, rdi
+	mul	r8
+	shr	rdx, 3
+	lea	eax, [rdx + rdx]
+	lea	eax, [rax + 4*rax]
+	mov	r9d, edi
+	sub	r9d, eax
+	or	r9b, 48
+	mov	byte ptr [rsi + rcx], r9b
+	lea	rax, [rcx - 1]
+	cmp	rdi, 10
+	mov	rdi, rdx
+	jae	.LBB6_1
+	add	rsi, rcx
+	inc	rcx
+	mov	edx, 41
+	sub	rdx, rcx
+	mov	rax, rsi
+	ret
+.LBB6_4:
+	push	rax
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end6:
+
+optional_trait_methods::num::format_decimal::<i128>:
+.Lfunc_begin7:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r12, rsi
+	mov	r15, rdi
+	mov	eax, 39
+	mov	ebp, 10
+	mov	r14, qword ptr [rip + __divti3@GOTPCREL]
+	mov	qword ptr [rsp], rsi
+.LBB7_1:
+	cmp	rax, 39
+	ja	.LBB7_9
+	mov	r13, rax
+	mov	edx, 10
+	mov	rdi, r15
+	mov	rsi, r12
+	xor	ecx, ecx
+	call	r14
+	mov	rcx, rax
+	mov	rsi, rdx
+	mul	rbp
+	lea	rdi, [rsi + 4*rsi]
+	lea	rdx, [rdx + 2*rdi]
+	mov	rdi, r15
+	sub	rdi, rax
+	mov	rax, r12
+	sbb	rax, rdx
+	mov	edx, edi
+	neg	dl
+	test	rax, rax
+	movzx	eax, dl
+	cmovns	eax, edi
+	mov	edx, 48
+	cmp	al, 10
+	jb	.LBB7_4
+	mov	edx, 87
+.LBB7_4:
+	add	dl, al
+	mov	byte ptr [rbx + r13], dl
+	add	r15, 9
+	adc	r12, 0
+	lea	rax, [r13 - 1]
+	cmp	r15, 19
+	sbb	r12, 0
+	mov	r15, rcx
+	mov	r12, rsi
+	jae	.LBB7_1
+	cmp	qword ptr [rsp], 0
+	jns	.LBB7_8
+	inc	r13
+	add	r13, -2
+	cmp	r13, 39
+	ja	.LBB7_9
+	mov	byte ptr [rbx + r13], 45
+.LBB7_8:
+	mov	edx, 40
+	sub	rdx, r13
+	add	rbx, r13
+	mov	rax, rbx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB7_9:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end7:
+
+optional_trait_methods::num::parse_num::<i128>:
+.Lfunc_begin8:
+	push	rbp
+	push	r15
+	push	r14
+	push	rbx
+	test	rdx, rdx
+	je	.LBB8_1
+	mov	rcx, rdx
+	lea	rax, [rsi + 1]
+	dec	rdx
+	movzx	r8d, byte ptr [rsi]
+	cmp	r8d, 43
+	je	.LBB8_6
+	cmp	r8d, 45
+	jne	.LBB8_4
+	mov	r11b, 1
+	jmp	.LBB8_7
+.LBB8_6:
+	xor	r11d, r11d
+.LBB8_7:
+	mov	rcx, rdx
+	mov	rsi, rax
+	jmp	.LBB8_8
+.LBB8_4:
+	xor	r11d, r11d
+.LBB8_8:
+	cmp	rcx, 1
+	jbe	.LBB8_9
+	mov	al, byte ptr [rsi]
+	cmp	al, 48
+	jne	.LBB8_11
+	movzx	eax, byte ptr [rsi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB8_12
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB8_31
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB8_30
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB8_12
+	mov	r10b, 16
+	mov	bl, 1
+	jmp	.LBB8_33
+.LBB8_9:
+	test	rcx, rcx
+	je	.LBB8_1
+	mov	al, byte ptr [rsi]
+.LBB8_11:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB8_1
+.LBB8_12:
+	add	rcx, rsi
+	mov	rax, rcx
+	dec	rax
+	je	.LBB8_14
+	cmp	byte ptr [rax], 95
+	je	.LBB8_1
+.LBB8_14:
+	mov	ebx, 10
+	xor	edx, edx
+	xor	r8d, r8d
+	xor	r9d, r9d
+.LBB8_15:
+	cmp	rsi, rcx
+	je	.LBB8_55
+	mov	bpl, byte ptr [rsi]
+	inc	rsi
+	cmp	bpl, 95
+	jne	.LBB8_18
+	xor	eax, eax
+	test	dl, 1
+	mov	edx, 0
+	jne	.LBB8_15
+	jmp	.LBB8_56
+.LBB8_18:
+	add	bpl, -48
+	cmp	bpl, 9
+	ja	.LBB8_1
+	mov	rax, r8
+	mul	rbx
+	mov	r10, rdx
+	mov	r8, rax
+	mov	rax, r9
+	imul	rbx
+	mov	r9, rax
+	add	r9, r10
+	adc	rdx, 0
+	mov	rax, r9
+	sar	rax, 63
+	mov	r10, rdx
+	xor	r10, rax
+	sar	rdx, 63
+	xor	rdx, rax
+	or	rdx, r10
+	jne	.LBB8_1
+	movzx	eax, bpl
+	test	r11b, r11b
+	je	.LBB8_21
+	sub	r8, rax
+	sbb	r9, 0
+	jo	.LBB8_1
+	jmp	.LBB8_23
+.LBB8_21:
+	add	r8, rax
+	adc	r9, 0
+	jo	.LBB8_1
+.LBB8_23:
+	mov	dl, 1
+	jmp	.LBB8_15
+.LBB8_30:
+	mov	r10b, 8
+	jmp	.LBB8_32
+.LBB8_31:
+	mov	r10b, 2
+.LBB8_32:
+	xor	ebx, ebx
+.LBB8_33:
+	cmp	rcx, 2
+	je	.LBB8_1
+	lea	r14, [rsi + 2]
+	mov	al, byte ptr [r14]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB8_35
+.LBB8_38:
+	cmp	dl, r10b
+	jae	.LBB8_1
+	add	rsi, rcx
+	mov	rax, rsi
+	dec	rax
+	je	.LBB8_41
+	cmp	byte ptr [rax], 95
+	je	.LBB8_1
+.LBB8_41:
+	movzx	r15d, r10b
+	xor	ecx, ecx
+	xor	r8d, r8d
+	xor	r9d, r9d
+.LBB8_42:
+	cmp	r14, rsi
+	je	.LBB8_55
+	mov	al, byte ptr [r14]
+	inc	r14
+	cmp	al, 95
+	jne	.LBB8_45
+	xor	eax, eax
+	test	cl, 1
+	mov	ecx, 0
+	jne	.LBB8_42
+	jmp	.LBB8_56
+.LBB8_45:
+	lea	ebp, [rax - 48]
+	cmp	bpl, 10
+	jb	.LBB8_49
+	test	bl, bl
+	je	.LBB8_1
+	or	al, 32
+	lea	ecx, [rax - 97]
+	cmp	cl, 5
+	ja	.LBB8_1
+	add	al, -87
+	mov	ebp, eax
+.LBB8_49:
+	cmp	bpl, r10b
+	jae	.LBB8_1
+	mov	rax, r8
+	mul	r15
+	mov	rcx, rdx
+	mov	r8, rax
+	mov	rax, r9
+	imul	r15
+	mov	r9, rax
+	add	r9, rcx
+	adc	rdx, 0
+	mov	rax, r9
+	sar	rax, 63
+	mov	rcx, rdx
+	xor	rcx, rax
+	sar	rdx, 63
+	xor	rdx, rax
+	or	rdx, rcx
+	jne	.LBB8_1
+	movzx	eax, bpl
+	test	r11b, r11b
+	je	.LBB8_52
+	sub	r8, rax
+	sbb	r9, 0
+	jo	.LBB8_1
+	jmp	.LBB8_54
+.LBB8_52:
+	add	r8, rax
+	adc	r9, 0
+	jo	.LBB8_1
+.LBB8_54:
+	mov	cl, 1
+	jmp	.LBB8_42
+.LBB8_55:
+	mov	qword ptr [rdi + 16], r8
+	mov	qword ptr [rdi + 24], r9
+	mov	eax, 1
+	jmp	.LBB8_56
+.LBB8_35:
+	test	bl, bl
+	je	.LBB8_1
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB8_1
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB8_38
+.LBB8_1:
+	xor	eax, eax
+.LBB8_56:
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], 0
+	pop	rbx
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end8:
+
+optional_trait_methods::num::parse_num::<u16>:
+.Lfunc_begin9:
+	test	rsi, rsi
+	je	.LBB9_54
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB9_4
+	cmp	ecx, 45
+	jne	.LBB9_6
+	mov	cl, 1
+	jmp	.LBB9_5
+.LBB9_4:
+	xor	ecx, ecx
+.LBB9_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB9_7
+.LBB9_6:
+	xor	ecx, ecx
+.LBB9_7:
+	cmp	rsi, 1
+	jbe	.LBB9_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB9_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB9_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB9_29
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB9_28
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB9_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB9_31
+.LBB9_14:
+	test	rsi, rsi
+	je	.LBB9_54
+	mov	al, byte ptr [rdi]
+.LBB9_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB9_54
+.LBB9_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB9_19
+	cmp	byte ptr [rax], 95
+	je	.LBB9_54
+.LBB9_19:
+	xor	r9d, r9d
+	mov	r8w, 10
+	xor	edx, edx
+.LBB9_20:
+	cmp	rdi, rsi
+	je	.LBB9_49
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB9_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB9_20
+	jmp	.LBB9_55
+.LBB9_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB9_54
+	mov	eax, edx
+	mul	r8w
+	jo	.LBB9_54
+	mov	edx, eax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB9_27
+	sub	dx, ax
+	jae	.LBB9_20
+	jmp	.LBB9_54
+.LBB9_27:
+	add	dx, ax
+	jae	.LBB9_20
+	jmp	.LBB9_54
+.LBB9_28:
+	mov	r8b, 8
+	jmp	.LBB9_30
+.LBB9_29:
+	mov	r8b, 2
+.LBB9_30:
+	xor	r9d, r9d
+.LBB9_31:
+	cmp	rsi, 2
+	je	.LBB9_54
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB9_51
+.LBB9_33:
+	cmp	dl, r8b
+	jae	.LBB9_54
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB9_36
+	cmp	byte ptr [rax], 95
+	je	.LBB9_54
+.LBB9_36:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB9_37:
+	cmp	r10, rdi
+	je	.LBB9_49
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB9_40
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	jne	.LBB9_37
+	jmp	.LBB9_55
+.LBB9_40:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB9_44
+	test	r9b, r9b
+	je	.LBB9_54
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB9_54
+	add	al, -87
+	mov	r11d, eax
+.LBB9_44:
+	cmp	r11b, r8b
+	jae	.LBB9_54
+	mov	eax, edx
+	mul	si
+	jo	.LBB9_54
+	mov	edx, eax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB9_48
+	sub	dx, ax
+	jae	.LBB9_37
+	jmp	.LBB9_54
+.LBB9_48:
+	add	dx, ax
+	jae	.LBB9_37
+	jmp	.LBB9_54
+.LBB9_49:
+	mov	ax, 1
+	ret
+.LBB9_51:
+	test	r9b, r9b
+	je	.LBB9_54
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB9_54
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB9_33
+.LBB9_54:
+	xor	eax, eax
+.LBB9_55:
+	ret
+.Lfunc_end9:
+
+optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin10:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB10_2
+	lea	rcx, [rip + .LJTI10_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB10_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB10_5
+.LBB10_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB10_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB10_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB10_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB10_18
+.LBB10_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB10_15
+	jmp	.LBB10_28
+.LBB10_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB10_14
+	jmp	.LBB10_28
+.LBB10_19:
+	lea	r14, [rbx + 16]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 144]
+	mov	rax, rcx
+	shl	rax, 4
+	cmp	rcx, 9
+	mov	ecx, 16
+	cmovb	rcx, r14
+	cmovae	rax, r12
+	test	rax, rax
+	je	.LBB10_26
+	add	rax, rcx
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB10_21:
+	movaps	xmm0, xmmword ptr [rcx]
+	add	rcx, 16
+	movaps	xmmword ptr [rsp + 16], xmm0
+	xor	esi, esi
+.LBB10_22:
+	cmp	r12, 256
+	jae	.LBB10_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB10_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 16
+	jne	.LBB10_22
+	cmp	rcx, rax
+	jne	.LBB10_21
+.LBB10_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB10_27
+.LBB10_11:
+	mov	rdi, qword ptr [rbx + 16]
+	mov	rsi, qword ptr [rbx + 24]
+	xorps	xmm0, xmm0
+	lea	rdx, [rsp + 32]
+	movaps	xmmword ptr [rdx + 16], xmm0
+	movaps	xmmword ptr [rdx], xmm0
+	mov	qword ptr [rdx + 32], 0
+	call	optional_trait_methods::num::format_decimal::<i128>
+.LBB10_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB10_27
+.LBB10_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI10_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB10_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB10_4
+.LBB10_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB10_4
+.LBB10_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB10_27
+.LBB10_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB10_12
+.LBB10_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB10_4:
+	mov	edx, 4
+.LBB10_5:
+	mov	rdi, r15
+.LBB10_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB10_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 160
+	mov	rdi, rbp
+	mov	rsi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB10_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB10_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end10:
+.LJTI10_0:
+	.long	.LBB10_10-.LJTI10_0
+	.long	.LBB10_11-.LJTI10_0
+	.long	.LBB10_13-.LJTI10_0
+	.long	.LBB10_19-.LJTI10_0
+.LJTI10_1:
+	.long	.LBB10_3-.LJTI10_1
+	.long	.LBB10_6-.LJTI10_1
+	.long	.LBB10_7-.LJTI10_1
+	.long	.LBB10_8-.LJTI10_1
+	.long	.LBB10_9-.LJTI10_1
+
+optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin11:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB11_2
+	lea	rcx, [rip + .LJTI11_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB11_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB11_5
+.LBB11_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB11_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB11_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB11_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB11_18
+.LBB11_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB11_15
+	jmp	.LBB11_28
+.LBB11_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB11_14
+	jmp	.LBB11_28
+.LBB11_19:
+	lea	r14, [rbx + 16]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 144]
+	mov	rax, rcx
+	shl	rax, 4
+	cmp	rcx, 9
+	mov	ecx, 16
+	cmovb	rcx, r14
+	cmovae	rax, r12
+	test	rax, rax
+	je	.LBB11_26
+	add	rax, rcx
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB11_21:
+	movaps	xmm0, xmmword ptr [rcx]
+	add	rcx, 16
+	movaps	xmmword ptr [rsp + 16], xmm0
+	xor	esi, esi
+.LBB11_22:
+	cmp	r12, 256
+	jae	.LBB11_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB11_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 16
+	jne	.LBB11_22
+	cmp	rcx, rax
+	jne	.LBB11_21
+.LBB11_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB11_27
+.LBB11_11:
+	mov	rdi, qword ptr [rbx + 16]
+	mov	rsi, qword ptr [rbx + 24]
+	xorps	xmm0, xmm0
+	lea	rdx, [rsp + 32]
+	movaps	xmmword ptr [rdx + 16], xmm0
+	movaps	xmmword ptr [rdx], xmm0
+	mov	qword ptr [rdx + 32], 0
+	call	optional_trait_methods::num::format_decimal::<i128>
+.LBB11_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB11_27
+.LBB11_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI11_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB11_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB11_4
+.LBB11_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB11_4
+.LBB11_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB11_27
+.LBB11_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB11_12
+.LBB11_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB11_4:
+	mov	edx, 4
+.LBB11_5:
+	mov	rdi, r15
+.LBB11_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB11_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 160
+	mov	rdi, rbp
+	mov	rsi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB11_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB11_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end11:
+.LJTI11_0:
+	.long	.LBB11_10-.LJTI11_0
+	.long	.LBB11_11-.LJTI11_0
+	.long	.LBB11_13-.LJTI11_0
+	.long	.LBB11_19-.LJTI11_0
+.LJTI11_1:
+	.long	.LBB11_3-.LJTI11_1
+	.long	.LBB11_6-.LJTI11_1
+	.long	.LBB11_7-.LJTI11_1
+	.long	.LBB11_8-.LJTI11_1
+	.long	.LBB11_9-.LJTI11_1
+
+optional_trait_methods::report_port:
+.Lfunc_begin12:
+	push	rbx
+	sub	rsp, 64
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 16]
+	movaps	xmmword ptr [rsi], xmm0
+	lea	rdx, [rsp + 12]
+	mov	dword ptr [rdx], 16
+	call	qword ptr [rip + getsockname@GOTPCREL]
+	test	eax, eax
+	js	.LBB12_1
+	xor	eax, eax
+	test	al, al
+	jne	.LBB12_7
+	movzx	ebx, word ptr [rsp + 18]
+	rol	bx, 8
+	shl	ebx, 16
+	shr	ebx, 16
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 48], 0
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.30]
+	mov	edx, 23
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	ecx, 39
+.LBB12_4:
+	cmp	rcx, 39
+	ja	.LBB12_8
+	mov	rax, rcx
+	movzx	edx, bx
+	imul	esi, edx, 52429
+	shr	esi, 19
+	lea	ecx, [rsi + rsi]
+	lea	ecx, [rcx + 4*rcx]
+	sub	ebx, ecx
+	or	bl, 48
+	mov	byte ptr [rsp + rax + 16], bl
+	lea	rcx, [rax - 1]
+	mov	ebx, esi
+	cmp	dx, 10
+	jae	.LBB12_4
+	lea	rsi, [rsp + rax]
+	add	rsi, 16
+	inc	rax
+	mov	edx, 41
+	sub	rdx, rax
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB12_7
+.LBB12_1:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB12_7:
+	add	rsp, 64
+	pop	rbx
+	ret
+.LBB12_8:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end12:
+
+optional_trait_methods::usage_error:
+.Lfunc_begin13:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.31]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	rax, qword ptr [rbx]
+	lea	rcx, [rip + .LJTI13_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB13_1:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.7]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 16
+	jmp	.LBB13_2
+.LBB13_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.12]
+	mov	edx, 39
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	jmp	.LBB13_7
+.LBB13_4:
+	mov	r14, qword ptr [rbx + 8]
+	mov	r15, qword ptr [rbx + 16]
+	mov	r12, qword ptr [rbx + 24]
+	mov	rbx, qword ptr [rbx + 32]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.9]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, r15
+	call	r13
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.10]
+	mov	edx, 2
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r12
+	mov	rdx, rbx
+	call	r13
+	jmp	.LBB13_7
+.LBB13_5:
+	mov	rsi, qword ptr [rbx + 8]
+	mov	rdx, qword ptr [rbx + 16]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.11]
+	mov	edx, 21
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB13_7
+.LBB13_3:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+.LBB13_2:
+	mov	edi, 2
+	call	r15
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, rbx
+	call	r15
+.LBB13_7:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.32]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 2
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	mov	edx, 823
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	mov	rax, rbx
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	jmp	rax
+.Lfunc_end13:
+.LJTI13_0:
+	.long	.LBB13_1-.LJTI13_0
+	.long	.LBB13_3-.LJTI13_0
+	.long	.LBB13_4-.LJTI13_0
+	.long	.LBB13_5-.LJTI13_0
+	.long	.LBB13_6-.LJTI13_0
+
+__rustc::rust_begin_unwind:
+.Lfunc_begin14:
+	push	rax
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	call	qword ptr [rip + abort@GOTPCREL]
+.Lfunc_end14:
+
+<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::parse_command:
+.Lfunc_begin15:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	sub	rsp, 336
+	mov	rbx, rdi
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.35]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 22
+	#APP
+	#NO_APP
+	lea	rax, [rsi + 1]
+	movzx	ecx, byte ptr [rsi]
+	cmp	ecx, 45
+	je	.LBB15_4
+	cmp	ecx, 43
+	jne	.LBB15_9
+	cmp	rdx, 1
+	jne	.LBB15_6
+	mov	byte ptr [rbx + 1], 0
+	jmp	.LBB15_31
+.LBB15_4:
+	cmp	rdx, 1
+	jne	.LBB15_6
+	mov	byte ptr [rbx + 1], 1
+	jmp	.LBB15_31
+.LBB15_6:
+	cmp	byte ptr [rax], 32
+	jne	.LBB15_10
+.LBB15_7:
+	mov	qword ptr [rbx + 8], 1
+.LBB15_8:
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+	jmp	.LBB15_32
+.LBB15_9:
+	cmp	rdx, 2
+	jb	.LBB15_11
+.LBB15_10:
+	cmp	word ptr [rsi], 11563
+	je	.LBB15_29
+.LBB15_11:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.39]
+	mov	r8, rsp
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	ecx, 42
+	jne	.LBB15_17
+	mov	rdi, rdx
+	dec	rdi
+	je	.LBB15_14
+	cmp	byte ptr [rax], 32
+	jne	.LBB15_17
+.LBB15_14:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rdi
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB15_106
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB15_98
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 8
+	jmp	.LBB15_28
+.LBB15_17:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.41]
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 2
+	jb	.LBB15_19
+	cmp	word ptr [rsi], 32298
+	je	.LBB15_45
+.LBB15_19:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.43]
+	mov	r8, rsp
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 3
+	jb	.LBB15_21
+	movzx	edi, word ptr [rsi]
+	xor	edi, 32298
+	movzx	r8d, byte ptr [rsi + 2]
+	xor	r8d, 126
+	or	r8w, di
+	je	.LBB15_52
+.LBB15_21:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.45]
+	mov	r8, rsp
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	ecx, 37
+	je	.LBB15_33
+	cmp	ecx, 47
+	jne	.LBB15_38
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB15_25
+	cmp	byte ptr [rax], 32
+	jne	.LBB15_58
+.LBB15_25:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB15_106
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB15_98
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 9
+.LBB15_28:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rbx + 16], xmm0
+	jmp	.LBB15_51
+.LBB15_29:
+	cmp	rdx, 2
+	jne	.LBB15_43
+	mov	byte ptr [rbx + 1], 2
+.LBB15_31:
+	mov	al, 7
+.LBB15_32:
+	mov	byte ptr [rbx], al
+	jmp	.LBB15_109
+.LBB15_33:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB15_35
+	cmp	byte ptr [rax], 32
+	jne	.LBB15_58
+.LBB15_35:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB15_106
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB15_98
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 9
+	jmp	.LBB15_50
+.LBB15_38:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 20
+	#APP
+	#NO_APP
+	cmp	ecx, 111
+	jle	.LBB15_61
+	lea	edi, [rcx - 112]
+	cmp	edi, 7
+	ja	.LBB15_59
+	lea	r8, [rip + .LJTI15_0]
+	movsxd	rdi, dword ptr [r8 + 4*rdi]
+	add	rdi, r8
+	jmp	rdi
+.LBB15_41:
+	cmp	rdx, 1
+	jne	.LBB15_90
+	xor	eax, eax
+	jmp	.LBB15_92
+.LBB15_43:
+	cmp	byte ptr [rsi + 2], 32
+	jne	.LBB15_11
+	mov	qword ptr [rbx + 8], 2
+	jmp	.LBB15_8
+.LBB15_45:
+	lea	rdi, [rsi + 2]
+	mov	r8, rdx
+	sub	r8, 2
+	je	.LBB15_47
+	cmp	byte ptr [rdi], 32
+	jne	.LBB15_19
+.LBB15_47:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 2
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB15_106
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB15_98
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 8
+.LBB15_50:
+	mov	qword ptr [rbx + 24], 0
+	mov	qword ptr [rbx + 16], 1
+.LBB15_51:
+	mov	qword ptr [rbx + 32], rax
+	mov	qword ptr [rbx + 40], rcx
+	jmp	.LBB15_109
+.LBB15_52:
+	lea	rdi, [rsi + 3]
+	mov	r8, rdx
+	sub	r8, 3
+	je	.LBB15_54
+	cmp	byte ptr [rdi], 32
+	jne	.LBB15_21
+.LBB15_54:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 3
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB15_106
+	mov	r14, rsp
+	mov	r15, qword ptr [r14 + 16]
+	mov	r12, qword ptr [r14 + 24]
+	lea	rsi, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB15_106
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB15_98
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 8
+	mov	qword ptr [rbx + 24], 0
+	mov	qword ptr [rbx + 16], 2
+	mov	qword ptr [rbx + 32], r15
+	mov	qword ptr [rbx + 40], r12
+	mov	qword ptr [rbx + 48], rax
+	mov	qword ptr [rbx + 56], rcx
+	jmp	.LBB15_109
+.LBB15_58:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 20
+	#APP
+	#NO_APP
+.LBB15_59:
+	mov	rdi, rsi
+	mov	rsi, rdx
+	call	optional_trait_methods::commands::unrecognized
+	mov	qword ptr [rbx + 8], 0
+.LBB15_60:
+	mov	byte ptr [rbx + 16], al
+	jmp	.LBB15_108
+.LBB15_61:
+	cmp	ecx, 63
+	je	.LBB15_86
+	cmp	ecx, 71
+	je	.LBB15_89
+	cmp	ecx, 103
+	jne	.LBB15_59
+	cmp	rdx, 1
+	jne	.LBB15_93
+	mov	al, 5
+	jmp	.LBB15_32
+.LBB15_66:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB15_68
+	cmp	byte ptr [rax], 32
+	jne	.LBB15_59
+.LBB15_68:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num_or::<usize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB15_107
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB15_118
+	mov	byte ptr [rbx], 10
+	jmp	.LBB15_71
+.LBB15_72:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB15_74
+	cmp	byte ptr [rax], 32
+	jne	.LBB15_59
+.LBB15_74:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB15_106
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB15_98
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 1
+	jmp	.LBB15_120
+.LBB15_77:
+	mov	rdi, rdx
+	dec	rdi
+	je	.LBB15_79
+	cmp	byte ptr [rax], 32
+	jne	.LBB15_100
+.LBB15_79:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rdi
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB15_107
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB15_118
+	mov	byte ptr [rbx], 3
+.LBB15_71:
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB15_109
+.LBB15_82:
+	mov	rdi, rdx
+	dec	rdi
+	je	.LBB15_84
+.LBB15_83:
+	cmp	byte ptr [rax], 32
+	jne	.LBB15_94
+.LBB15_84:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rdi
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	je	.LBB15_96
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB15_60
+.LBB15_86:
+	mov	rcx, rdx
+	dec	rcx
+	jne	.LBB15_113
+	mov	qword ptr [rsp], rax
+	mov	qword ptr [rsp + 8], rcx
+	mov	qword ptr [rsp + 16], 1
+	xor	ebp, ebp
+.LBB15_88:
+	mov	byte ptr [rbx], 2
+	mov	byte ptr [rbx + 1], bpl
+	mov	byte ptr [rbx + 2], al
+	jmp	.LBB15_109
+.LBB15_89:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB15_105
+	jmp	.LBB15_104
+.LBB15_90:
+	cmp	byte ptr [rax], 32
+	jne	.LBB15_59
+	mov	qword ptr [rbx + 8], 1
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+.LBB15_92:
+	mov	byte ptr [rbx], al
+	jmp	.LBB15_109
+.LBB15_93:
+	cmp	byte ptr [rax], 32
+	je	.LBB15_7
+.LBB15_94:
+	cmp	ecx, 63
+	je	.LBB15_112
+	cmp	ecx, 71
+	jne	.LBB15_59
+	jmp	.LBB15_103
+.LBB15_96:
+	mov	r14, rsp
+	lea	rsi, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB15_106
+	cmp	qword ptr [rsp + 176], 0
+	je	.LBB15_119
+.LBB15_98:
+	mov	rax, qword ptr [rsp + 184]
+.LBB15_99:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], 3
+	jmp	.LBB15_108
+.LBB15_100:
+	mov	r8d, ecx
+	add	r8b, -63
+	je	.LBB15_112
+	rol	r8b, 5
+	movzx	r8d, r8b
+	cmp	r8d, 1
+	je	.LBB15_103
+	cmp	r8d, 7
+	jne	.LBB15_59
+	jmp	.LBB15_83
+.LBB15_103:
+	lea	rcx, [rdx - 1]
+.LBB15_104:
+	cmp	byte ptr [rax], 32
+	jne	.LBB15_59
+.LBB15_105:
+	lea	rsi, [rsp + 312]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::regs::<i128>
+	cmp	byte ptr [r14], 0
+	je	.LBB15_110
+.LBB15_106:
+	mov	rax, qword ptr [rsp + 8]
+	mov	cl, byte ptr [rsp + 16]
+.LBB15_107:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], cl
+.LBB15_108:
+	mov	byte ptr [rbx], -1
+.LBB15_109:
+	add	rsp, 336
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB15_110:
+	lea	rsi, [rsp + 17]
+	mov	bpl, byte ptr [rsi - 1]
+	lea	rdi, [rsp + 168]
+	mov	edx, 143
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	cmp	qword ptr [rsp + 320], 0
+	je	.LBB15_116
+	mov	rax, qword ptr [rsp + 328]
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB15_8
+.LBB15_112:
+	lea	rcx, [rdx - 1]
+.LBB15_113:
+	cmp	byte ptr [rax], 32
+	jne	.LBB15_59
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	test	rcx, rcx
+	setne	bpl
+	lea	r14, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::names
+	mov	al, byte ptr [r14 + 8]
+	cmp	al, -1
+	je	.LBB15_117
+	mov	rcx, qword ptr [rsp + 168]
+	mov	qword ptr [rbx + 8], rcx
+	jmp	.LBB15_60
+.LBB15_116:
+	mov	byte ptr [rbx + 16], bpl
+	lea	rdi, [rbx + 17]
+	lea	rsi, [rsp + 168]
+	mov	edx, 143
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	al, 6
+	jmp	.LBB15_32
+.LBB15_117:
+	cmp	qword ptr [rsp + 8], 0
+	je	.LBB15_121
+.LBB15_118:
+	mov	rax, qword ptr [rsp + 16]
+	jmp	.LBB15_99
+.LBB15_119:
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 4
+	mov	qword ptr [rbx + 8], r15
+.LBB15_120:
+	mov	qword ptr [rbx + 16], rax
+	mov	qword ptr [rbx + 24], rcx
+	jmp	.LBB15_109
+.LBB15_121:
+	mov	al, byte ptr [rsp + 168]
+	jmp	.LBB15_88
+.Lfunc_end15:
+.LJTI15_0:
+	.long	.LBB15_41-.LJTI15_0
+	.long	.LBB15_59-.LJTI15_0
+	.long	.LBB15_77-.LJTI15_0
+	.long	.LBB15_72-.LJTI15_0
+	.long	.LBB15_59-.LJTI15_0
+	.long	.LBB15_66-.LJTI15_0
+	.long	.LBB15_59-.LJTI15_0
+	.long	.LBB15_82-.LJTI15_0
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin16:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB16_1
+	xor	eax, eax
+	mov	qword ptr [r14 + 1024], rax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	cmovae	rdx, rax
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB16_3
+	mov	edi, 1
+	mov	rsi, r15
+	mov	rdx, rbx
+	xor	ecx, ecx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	jmp	optional_trait_methods::connection::write_fd
+.LBB16_1:
+	cmp	rax, rdx
+	jb	.LBB16_4
+.LBB16_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB16_4:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end16:
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin17:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB17_1
+	xor	eax, eax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	mov	qword ptr [r14 + 1024], rax
+	cmovae	rdx, rax
+	mov	ebp, dword ptr [r14 + 1032]
+	mov	edi, ebp
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB17_3
+	mov	edi, ebp
+	mov	rsi, r15
+	mov	rdx, rbx
+	mov	ecx, 1
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	optional_trait_methods::connection::write_fd
+.LBB17_1:
+	cmp	rax, rdx
+	jb	.LBB17_4
+.LBB17_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB17_4:
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end17:
+
+main:
+.Lfunc_begin18:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 3960
+	cmp	rdi, 2
+	jl	.LBB18_55
+	mov	r12, rsi
+	mov	ebp, 1
+	lea	r13, [rsp + 624]
+	mov	qword ptr [rsp + 24], 0
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	mov	dword ptr [rsp + 20], eax
+	mov	qword ptr [rsp + 72], 0
+	mov	qword ptr [rsp + 56], 0
+	mov	qword ptr [rsp + 64], 0
+	mov	qword ptr [rsp + 88], 0
+	mov	qword ptr [rsp + 80], 0
+	mov	qword ptr [rsp + 32], rsi
+	mov	qword ptr [rsp + 96], rdi
+.LBB18_2:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [r12 + 8*rbp]
+	mov	rdi, rbp
+	call	rcx
+	mov	rbx, rax
+	test	rax, rax
+	je	.LBB18_7
+	xor	r14d, r14d
+.LBB18_4:
+	cmp	byte ptr [rbp + r14], 61
+	je	.LBB18_9
+	inc	r14
+	cmp	rbx, r14
+	jne	.LBB18_4
+	mov	qword ptr [rsp], 0
+	mov	r14, rbx
+	jmp	.LBB18_8
+.LBB18_7:
+	mov	qword ptr [rsp], 0
+	xor	r14d, r14d
+.LBB18_8:
+	jmp	.LBB18_10
+.LBB18_9:
+	lea	rax, [r14 + 1]
+	mov	rcx, rbx
+	sub	rcx, rax
+	mov	qword ptr [rsp + 48], rcx
+	lea	rax, [r14 + rbp]
+	inc	rax
+	mov	qword ptr [rsp], rax
+.LBB18_10:
+	mov	rax, qword ptr [rsp + 8]
+	inc	rax
+	mov	qword ptr [rsp + 40], rax
+	mov	qword ptr [rsp + 608], 0
+	mov	qword ptr [rsp + 616], 7
+	mov	edx, 168
+	mov	rdi, r13
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.24]
+	call	r15
+	mov	r12d, 32
+.LBB18_11:
+	cmp	qword ptr [rsp + r12 + 600], r14
+	jne	.LBB18_14
+	movzx	r13d, byte ptr [rsp + r12 + 608]
+	mov	r15, qword ptr [rsp + r12 + 592]
+	mov	rdi, r15
+	mov	rsi, rbp
+	mov	rdx, r14
+	call	qword ptr [rip + bcmp@GOTPCREL]
+	test	eax, eax
+	jne	.LBB18_14
+	cmp	r13d, 255
+	jne	.LBB18_15
+.LBB18_14:
+	add	r12, 24
+	cmp	r12, 200
+	jne	.LBB18_11
+	jmp	.LBB18_92
+.LBB18_15:
+	cmp	r13, 255
+	je	.LBB18_92
+	cmp	r13b, 4
+	jae	.LBB18_19
+	mov	rdx, qword ptr [rsp]
+	test	rdx, rdx
+	mov	r12, qword ptr [rsp + 32]
+	mov	rbx, qword ptr [rsp + 96]
+	je	.LBB18_21
+	mov	rbp, qword ptr [rsp + 40]
+	mov	rax, qword ptr [rsp + 48]
+	jmp	.LBB18_24
+.LBB18_19:
+	cmp	qword ptr [rsp], 0
+	mov	r12, qword ptr [rsp + 32]
+	mov	rbx, qword ptr [rsp + 96]
+	jne	.LBB18_101
+	mov	edx, 1
+	mov	rbp, qword ptr [rsp + 40]
+	xor	eax, eax
+	jmp	.LBB18_24
+.LBB18_21:
+	cmp	qword ptr [rsp + 40], rbx
+	mov	rbp, qword ptr [rsp + 8]
+	jge	.LBB18_102
+	mov	rdi, qword ptr [r12 + 8*rbp + 8]
+	test	rdi, rdi
+	je	.LBB18_102
+	mov	qword ptr [rsp], rdi
+	call	qword ptr [rip + strlen@GOTPCREL]
+	mov	rdx, qword ptr [rsp]
+	add	rbp, 2
+.LBB18_24:
+	cmp	r13b, 5
+	jne	.LBB18_26
+	mov	al, 1
+	mov	qword ptr [rsp + 88], rax
+	cmp	rbp, rbx
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r13, [rsp + 624]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB18_2
+	jmp	.LBB18_53
+.LBB18_26:
+	movzx	ecx, r13b
+	mov	esi, ecx
+	lea	rcx, [rip + .LJTI18_0]
+	movsxd	rsi, dword ptr [rcx + 4*rsi]
+	add	rsi, rcx
+	jmp	rsi
+.LBB18_27:
+	test	rax, rax
+	lea	r13, [rsp + 624]
+	je	.LBB18_116
+	mov	qword ptr [rsp + 104], rdx
+	mov	qword ptr [rsp + 112], rax
+	mov	dword ptr [rsp + 20], eax
+	cmp	word ptr [rsp + 24], 0
+	mov	ax, 1
+	mov	qword ptr [rsp + 24], rax
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	je	.LBB18_51
+	jmp	.LBB18_109
+.LBB18_29:
+	mov	al, 1
+	mov	qword ptr [rsp + 64], rax
+	jmp	.LBB18_41
+.LBB18_30:
+	mov	qword ptr [rsp + 120], rax
+	mov	qword ptr [rsp + 72], rdx
+	jmp	.LBB18_41
+.LBB18_31:
+	cmp	rax, 8
+	lea	r13, [rsp + 624]
+	je	.LBB18_42
+	cmp	rax, 4
+	jne	.LBB18_121
+	mov	esi, 4
+	cmp	byte ptr [rdx], 101
+	jne	.LBB18_122
+	cmp	byte ptr [rdx + 1], 120
+	jne	.LBB18_122
+	cmp	byte ptr [rdx + 2], 105
+	jne	.LBB18_122
+	cmp	byte ptr [rdx + 3], 116
+	jne	.LBB18_122
+	jmp	.LBB18_50
+.LBB18_37:
+	mov	rdi, rdx
+	mov	rsi, rax
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp], rdx
+	call	optional_trait_methods::num::parse_num::<u16>
+	mov	dword ptr [rsp + 20], edx
+	test	al, 1
+	lea	r13, [rsp + 624]
+	je	.LBB18_115
+	cmp	word ptr [rsp + 24], 0
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	jne	.LBB18_109
+	mov	ax, 2
+	mov	qword ptr [rsp + 24], rax
+	jmp	.LBB18_51
+.LBB18_40:
+	mov	al, 1
+	mov	qword ptr [rsp + 80], rax
+.LBB18_41:
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r13, [rsp + 624]
+	jmp	.LBB18_51
+.LBB18_42:
+	mov	esi, 8
+	cmp	byte ptr [rdx], 99
+	jne	.LBB18_122
+	cmp	byte ptr [rdx + 1], 111
+	jne	.LBB18_122
+	cmp	byte ptr [rdx + 2], 110
+	jne	.LBB18_122
+	cmp	byte ptr [rdx + 3], 116
+	jne	.LBB18_122
+	cmp	byte ptr [rdx + 4], 105
+	jne	.LBB18_122
+	cmp	byte ptr [rdx + 5], 110
+	jne	.LBB18_122
+	cmp	byte ptr [rdx + 6], 117
+	jne	.LBB18_122
+	cmp	byte ptr [rdx + 7], 101
+	jne	.LBB18_122
+.LBB18_50:
+	cmp	rax, 8
+	setne	al
+	mov	qword ptr [rsp + 56], rax
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+.LBB18_51:
+	cmp	rbp, rbx
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB18_2
+	test	byte ptr [rsp + 88], 1
+	je	.LBB18_110
+.LBB18_53:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.73]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 31
+.LBB18_54:
+	mov	edi, 2
+	call	r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	ebx, 1
+	mov	edx, 1
+	mov	edi, 2
+	call	r14
+	jmp	.LBB18_99
+.LBB18_55:
+	xor	r15d, r15d
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB18_56:
+	xorps	xmm0, xmm0
+	lea	r14, [rsp + 3216]
+	movaps	xmmword ptr [r14 + 672], xmm0
+	movaps	xmmword ptr [r14 + 656], xmm0
+	movaps	xmmword ptr [r14 + 640], xmm0
+	movaps	xmmword ptr [r14 + 624], xmm0
+	movaps	xmmword ptr [r14 + 608], xmm0
+	movaps	xmmword ptr [r14 + 592], xmm0
+	movaps	xmmword ptr [r14 + 576], xmm0
+	lea	rdi, [rsp + 640]
+	xor	ebx, ebx
+	mov	r13, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 528
+	xor	esi, esi
+	call	r13
+	mov	qword ptr [r14 + 568], r12
+	mov	qword ptr [r14 + 560], r15
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [r14 + 696], xmm0
+	mov	qword ptr [r14 + 688], 1
+	movabs	rax, -9223372036854775808
+	mov	qword ptr [r14 + 712], rax
+	movabs	rax, 9223372036854775807
+	mov	qword ptr [r14 + 728], rax
+	mov	qword ptr [r14 + 720], -1
+	movaps	xmmword ptr [r14 - 16], xmm0
+	lea	rsi, [rsp + 608]
+	mov	edx, 560
+	mov	rdi, r14
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 1648]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 608]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 2688]
+	mov	edx, 512
+	xor	esi, esi
+	call	r13
+	mov	dword ptr [rsp + 40], ebp
+	shr	ebp, 8
+	mov	dword ptr [rsp + 8], ebp
+	mov	r12d, 1
+	mov	rbp, qword ptr [rip + read@GOTPCREL]
+	xor	ecx, ecx
+	mov	dword ptr [rsp], eax
+.LBB18_57:
+	mov	rdi, qword ptr [rsp + 1632]
+	mov	rax, qword ptr [rsp + 1640]
+	xor	r15d, r15d
+	mov	rdx, rcx
+	xor	r13d, r13d
+.LBB18_58:
+	mov	rsi, rax
+	mov	rax, rdi
+.LBB18_59:
+	cmp	rsi, rax
+	jb	.LBB18_63
+	mov	qword ptr [rsp + 2672], rbx
+	cmp	rdx, 1025
+	mov	rsi, r14
+	cmovae	rsi, r12
+	cmovae	rdx, rbx
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 1024
+	xor	edi, edi
+	lea	rsi, [rsp + 608]
+	call	rbp
+	test	rax, rax
+	js	.LBB18_73
+	je	.LBB18_74
+	mov	qword ptr [rsp + 1632], rax
+	mov	qword ptr [rsp + 1640], 0
+	xor	ecx, ecx
+	xor	edx, edx
+	xor	esi, esi
+.LBB18_63:
+	cmp	rax, rsi
+	jb	.LBB18_96
+	cmp	rax, 1024
+	ja	.LBB18_96
+	cmp	rsi, rax
+	je	.LBB18_59
+	inc	rsi
+	mov	rdi, rax
+	neg	rdi
+.LBB18_67:
+	mov	r8b, byte ptr [rsp + rsi + 607]
+	mov	qword ptr [rsp + 1640], rsi
+	cmp	r8b, 10
+	je	.LBB18_77
+	cmp	r13, 511
+	jbe	.LBB18_70
+	mov	r15b, 1
+	jmp	.LBB18_71
+.LBB18_70:
+	mov	byte ptr [rsp + r13 + 2688], r8b
+	inc	r13
+.LBB18_71:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB18_67
+	mov	rdi, rax
+	jmp	.LBB18_58
+.LBB18_73:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB18_74:
+	test	r13, r13
+	setne	al
+	or	al, r15b
+	test	al, 1
+	je	.LBB18_100
+	test	r15b, 1
+	jne	.LBB18_81
+	mov	ecx, 0
+	mov	edx, 0
+	cmp	r13, 512
+	ja	.LBB18_97
+	jmp	.LBB18_79
+.LBB18_77:
+	test	r15b, 1
+	jne	.LBB18_81
+	cmp	r13, 512
+	ja	.LBB18_96
+.LBB18_79:
+	test	r13, r13
+	setne	al
+	test	byte ptr [rsp + 8], al
+	jne	.LBB18_82
+	test	r13, r13
+	je	.LBB18_57
+	jmp	.LBB18_83
+.LBB18_81:
+	mov	qword ptr [rsp + 136], 3
+	mov	qword ptr [rsp + 144], 512
+	mov	byte ptr [rsp + 152], 4
+	mov	byte ptr [rsp + 128], -1
+	lea	rdi, [rsp + 288]
+	mov	rsi, r14
+	lea	rdx, [rsp + 128]
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	jmp	.LBB18_85
+.LBB18_82:
+	mov	rdi, r14
+	lea	rsi, [rsp + 2688]
+	mov	rdx, r13
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB18_83:
+	lea	r15, [rsp + 448]
+	mov	rdi, r15
+	lea	rsi, [rsp + 2688]
+	mov	rdx, r13
+	call	<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::parse_command
+	cmp	byte ptr [rsp + 448], -1
+	je	.LBB18_86
+	mov	edx, 160
+	mov	rsi, r15
+	lea	r15, [rsp + 128]
+	mov	rdi, r15
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	rdi, [rsp + 288]
+	lea	rsi, [rsp + 3200]
+	mov	rdx, r14
+	mov	rcx, r15
+	call	<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB18_85:
+	mov	r15d, dword ptr [rsp]
+	mov	r13, qword ptr [rsp + 32]
+	jmp	.LBB18_87
+.LBB18_86:
+	mov	eax, dword ptr [rsp + 464]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	r13, qword ptr [rsp + 32]
+	cmovne	r13, qword ptr [rsp + 456]
+	sete	cl
+	mov	r15d, dword ptr [rsp]
+	movzx	r15d, r15b
+	cmovne	r15d, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 136], rcx
+	mov	qword ptr [rsp + 144], r13
+	mov	byte ptr [rsp + 152], r15b
+	mov	byte ptr [rsp + 128], -1
+	lea	rdi, [rsp + 288]
+	mov	rsi, r14
+	lea	rdx, [rsp + 128]
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB18_87:
+	cmp	byte ptr [rsp + 288], -1
+	jne	.LBB18_91
+	mov	rax, qword ptr [rsp + 296]
+	cmp	byte ptr [rsp + 40], 0
+	jne	.LBB18_103
+	cmp	eax, 1
+	je	.LBB18_106
+	cmp	eax, 4
+	je	.LBB18_105
+.LBB18_91:
+	mov	qword ptr [rsp + 32], r13
+	mov	dword ptr [rsp], r15d
+	mov	rcx, qword ptr [rsp + 2672]
+	jmp	.LBB18_57
+.LBB18_92:
+	xor	ecx, ecx
+	mov	r15, rbp
+	mov	r14, rbx
+.LBB18_93:
+.LBB18_94:
+	lea	rdi, [rsp + 608]
+	mov	qword ptr [rdi], rcx
+	mov	qword ptr [rdi + 8], r15
+	mov	qword ptr [rdi + 16], r14
+	mov	qword ptr [rdi + 24], rdx
+	mov	qword ptr [rdi + 32], rax
+.LBB18_95:
+	call	optional_trait_methods::usage_error
+	mov	ebx, 1
+	jmp	.LBB18_99
+.LBB18_96:
+	mov	rdx, rcx
+.LBB18_97:
+	xor	eax, eax
+	mov	qword ptr [rsp + 2672], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, 1
+	mov	rsi, r14
+.LBB18_98:
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+.LBB18_99:
+	mov	rax, rbx
+	add	rsp, 3960
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB18_100:
+	xor	edx, edx
+	jmp	.LBB18_97
+.LBB18_101:
+	mov	ecx, 3
+	jmp	.LBB18_93
+.LBB18_102:
+	mov	ecx, 1
+	jmp	.LBB18_93
+.LBB18_103:
+	cmp	eax, 1
+	je	.LBB18_106
+	cmp	eax, 4
+	jne	.LBB18_108
+.LBB18_105:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 1648]
+	mov	edx, 23
+	jmp	.LBB18_107
+.LBB18_106:
+	mov	rsi, qword ptr [rsp + 304]
+	mov	rdx, qword ptr [rsp + 312]
+	lea	rdi, [rsp + 1648]
+.LBB18_107:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 1648]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB18_108:
+	mov	ebx, 1
+	mov	rdx, qword ptr [rsp + 2672]
+	jmp	.LBB18_97
+.LBB18_109:
+	mov	ecx, 4
+	jmp	.LBB18_93
+.LBB18_110:
+	test	byte ptr [rsp + 80], 1
+	je	.LBB18_112
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	xor	ebx, ebx
+	mov	edx, 823
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 1
+	jmp	.LBB18_98
+.LBB18_112:
+	mov	r15, qword ptr [rsp + 72]
+	test	r15, r15
+	je	.LBB18_117
+	lea	rbx, [rsp + 1648]
+	mov	rdi, rbx
+	mov	rsi, r15
+	mov	r14, qword ptr [rsp + 120]
+	mov	rdx, r14
+	call	optional_trait_methods::num::parse_num::<i128>
+	test	byte ptr [rbx], 1
+	je	.LBB18_123
+	mov	r15, qword ptr [rsp + 1664]
+	mov	r12, qword ptr [rsp + 1672]
+	jmp	.LBB18_118
+.LBB18_115:
+	mov	ecx, 2
+	mov	rdx, qword ptr [rsp]
+	mov	rax, qword ptr [rsp + 48]
+	jmp	.LBB18_94
+.LBB18_116:
+	mov	ecx, 2
+	xor	eax, eax
+	jmp	.LBB18_94
+.LBB18_117:
+	xor	r15d, r15d
+	xor	r12d, r12d
+.LBB18_118:
+	mov	rax, qword ptr [rsp + 24]
+	test	ax, ax
+	je	.LBB18_124
+	movzx	eax, ax
+	cmp	eax, 1
+	jne	.LBB18_125
+	mov	rdi, qword ptr [rsp + 104]
+	mov	rsi, qword ptr [rsp + 112]
+	call	<optional_trait_methods::connection::Listener>::unix
+	mov	ebx, edx
+	jmp	.LBB18_126
+.LBB18_121:
+	mov	rsi, rax
+.LBB18_122:
+	mov	ecx, 2
+	mov	rax, rsi
+	jmp	.LBB18_94
+.LBB18_123:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.19]
+	lea	rdi, [rsp + 608]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 15
+	mov	qword ptr [rdi + 24], r15
+	mov	qword ptr [rdi + 32], r14
+	mov	qword ptr [rdi], 2
+	jmp	.LBB18_95
+.LBB18_124:
+	movzx	eax, byte ptr [rsp + 64]
+	shl	eax, 8
+	movzx	ebp, byte ptr [rsp + 56]
+	or	ebp, eax
+	jmp	.LBB18_56
+.LBB18_125:
+	mov	ebp, dword ptr [rsp + 20]
+	mov	edi, ebp
+	call	<optional_trait_methods::connection::Listener>::tcp_loopback
+	mov	ebx, edx
+	test	bp, bp
+	setne	cl
+	or	cl, al
+	test	cl, 1
+	je	.LBB18_128
+.LBB18_126:
+	cmp	eax, 1
+	jne	.LBB18_129
+	mov	r14d, 1
+	mov	ebp, ebx
+	jmp	.LBB18_130
+.LBB18_128:
+	mov	edi, ebx
+	call	optional_trait_methods::report_port
+.LBB18_129:
+	mov	edi, ebx
+	call	<optional_trait_methods::connection::Listener>::accept
+	mov	r14d, eax
+	mov	ebp, edx
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+.LBB18_130:
+	test	r14b, 1
+	je	.LBB18_132
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.72]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 29
+	jmp	.LBB18_54
+.LBB18_132:
+	xorps	xmm0, xmm0
+	lea	r14, [rsp + 3216]
+	movaps	xmmword ptr [r14 + 672], xmm0
+	movaps	xmmword ptr [r14 + 656], xmm0
+	movaps	xmmword ptr [r14 + 640], xmm0
+	movaps	xmmword ptr [r14 + 624], xmm0
+	movaps	xmmword ptr [r14 + 608], xmm0
+	movaps	xmmword ptr [r14 + 592], xmm0
+	movaps	xmmword ptr [r14 + 576], xmm0
+	lea	rdi, [rsp + 640]
+	xor	ebx, ebx
+	mov	r13, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 528
+	xor	esi, esi
+	call	r13
+	mov	qword ptr [r14 + 568], r12
+	mov	qword ptr [r14 + 560], r15
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [r14 + 696], xmm0
+	mov	qword ptr [r14 + 688], 1
+	movabs	rax, -9223372036854775808
+	mov	qword ptr [r14 + 712], rax
+	movabs	rax, 9223372036854775807
+	mov	qword ptr [r14 + 728], rax
+	mov	qword ptr [r14 + 720], -1
+	movaps	xmmword ptr [r14 - 16], xmm0
+	lea	rsi, [rsp + 608]
+	mov	edx, 560
+	mov	rdi, r14
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 1648]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r13
+	mov	dword ptr [r14 + 1032], ebp
+	lea	rdi, [rsp + 608]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 2688]
+	mov	edx, 512
+	xor	esi, esi
+	call	r13
+	lea	r13, [rsp + 128]
+	xor	edx, edx
+	mov	dword ptr [rsp + 8], eax
+.LBB18_133:
+	mov	rdi, qword ptr [rsp + 1632]
+	mov	rcx, qword ptr [rsp + 1640]
+	xor	r15d, r15d
+	mov	rax, rdx
+	xor	r12d, r12d
+.LBB18_134:
+	mov	rsi, rcx
+	mov	rcx, rdi
+.LBB18_135:
+	cmp	rsi, rcx
+	jb	.LBB18_139
+	mov	qword ptr [rsp + 2672], rbx
+	cmp	rax, 1025
+	mov	rsi, r14
+	mov	ecx, 1
+	cmovae	rsi, rcx
+	cmovae	rax, rbx
+	mov	edi, ebp
+	mov	rdx, rax
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	rdi, r13
+	mov	esi, ebp
+	lea	rdx, [rsp + 608]
+	call	optional_trait_methods::connection::read_fd
+	cmp	byte ptr [rsp + 128], 0
+	jne	.LBB18_149
+	mov	rcx, qword ptr [rsp + 136]
+	test	rcx, rcx
+	je	.LBB18_149
+	mov	qword ptr [rsp + 1632], rcx
+	mov	qword ptr [rsp + 1640], 0
+	xor	edx, edx
+	xor	eax, eax
+	xor	esi, esi
+.LBB18_139:
+	cmp	rcx, rsi
+	jb	.LBB18_168
+	cmp	rcx, 1024
+	ja	.LBB18_168
+	cmp	rsi, rcx
+	je	.LBB18_135
+	inc	rsi
+	mov	rdi, rcx
+	neg	rdi
+.LBB18_143:
+	mov	r8b, byte ptr [rsp + rsi + 607]
+	mov	qword ptr [rsp + 1640], rsi
+	cmp	r8b, 10
+	je	.LBB18_152
+	cmp	r12, 511
+	jbe	.LBB18_146
+	mov	r15b, 1
+	jmp	.LBB18_147
+.LBB18_146:
+	mov	byte ptr [rsp + r12 + 2688], r8b
+	inc	r12
+.LBB18_147:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB18_143
+	mov	rdi, rcx
+	jmp	.LBB18_134
+.LBB18_149:
+	test	r12, r12
+	setne	al
+	or	al, r15b
+	test	al, 1
+	je	.LBB18_167
+	test	r15b, 1
+	jne	.LBB18_156
+	mov	edx, 0
+	cmp	r12, 512
+	jbe	.LBB18_154
+	jmp	.LBB18_167
+.LBB18_152:
+	test	r15b, 1
+	jne	.LBB18_156
+	cmp	r12, 512
+	ja	.LBB18_168
+.LBB18_154:
+	test	r12, r12
+	setne	al
+	test	byte ptr [rsp + 64], al
+	jne	.LBB18_157
+	test	r12, r12
+	je	.LBB18_133
+	jmp	.LBB18_158
+.LBB18_156:
+	mov	qword ptr [rsp + 136], 3
+	mov	qword ptr [rsp + 144], 512
+	mov	byte ptr [rsp + 152], 4
+	jmp	.LBB18_161
+.LBB18_157:
+	mov	rdi, r14
+	lea	rsi, [rsp + 2688]
+	mov	rdx, r12
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB18_158:
+	lea	rdi, [rsp + 448]
+	lea	rsi, [rsp + 2688]
+	mov	rdx, r12
+	call	<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::parse_command
+	cmp	byte ptr [rsp + 448], -1
+	je	.LBB18_160
+	mov	edx, 160
+	mov	rdi, r13
+	lea	rsi, [rsp + 448]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	rdi, [rsp + 288]
+	lea	rsi, [rsp + 3200]
+	mov	rdx, r14
+	mov	rcx, r13
+	call	<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	jmp	.LBB18_162
+.LBB18_160:
+	mov	eax, dword ptr [rsp + 464]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rdx, qword ptr [rsp]
+	cmovne	rdx, qword ptr [rsp + 456]
+	sete	cl
+	mov	esi, dword ptr [rsp + 8]
+	movzx	esi, sil
+	cmovne	esi, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 136], rcx
+	mov	qword ptr [rsp], rdx
+	mov	qword ptr [rsp + 144], rdx
+	mov	dword ptr [rsp + 8], esi
+	mov	byte ptr [rsp + 152], sil
+.LBB18_161:
+	mov	byte ptr [rsp + 128], -1
+	lea	rdi, [rsp + 288]
+	mov	rsi, r14
+	mov	rdx, r13
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+.LBB18_162:
+	cmp	byte ptr [rsp + 288], -1
+	jne	.LBB18_166
+	mov	rax, qword ptr [rsp + 296]
+	cmp	byte ptr [rsp + 56], 0
+	jne	.LBB18_169
+	cmp	eax, 1
+	je	.LBB18_172
+	cmp	eax, 4
+	je	.LBB18_171
+.LBB18_166:
+	mov	ebp, dword ptr [rsp + 2680]
+	mov	rdx, qword ptr [rsp + 2672]
+	jmp	.LBB18_133
+.LBB18_167:
+	xor	edx, edx
+.LBB18_168:
+	xor	eax, eax
+	mov	qword ptr [rsp + 2672], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, ebp
+	mov	rsi, r14
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	jmp	.LBB18_99
+.LBB18_169:
+	cmp	eax, 1
+	je	.LBB18_172
+	cmp	eax, 4
+	jne	.LBB18_174
+.LBB18_171:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 1648]
+	mov	edx, 23
+	jmp	.LBB18_173
+.LBB18_172:
+	mov	rsi, qword ptr [rsp + 304]
+	mov	rdx, qword ptr [rsp + 312]
+	lea	rdi, [rsp + 1648]
+.LBB18_173:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 1648]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB18_174:
+	mov	rdx, qword ptr [rsp + 2672]
+	mov	ebx, 1
+	mov	ebp, dword ptr [rsp + 2680]
+	jmp	.LBB18_168
+.LBB18_175:
+	ud2
+.Lfunc_end18:
+.LJTI18_0:
+	.long	.LBB18_27-.LJTI18_0
+	.long	.LBB18_37-.LJTI18_0
+	.long	.LBB18_30-.LJTI18_0
+	.long	.LBB18_31-.LJTI18_0
+	.long	.LBB18_29-.LJTI18_0
+	.long	.LBB18_175-.LJTI18_0
+	.long	.LBB18_40-.LJTI18_0
+
+rust_eh_personality:
+.Lfunc_begin19:
+	ret
+.Lfunc_end19:
+
+core::panicking::panic_fmt:
+.Lfunc_begin20:
+	.loc	1 60 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp0:
+	.loc	1 80 14 prologue_end
+	call	__rustc::rust_begin_unwind
+.Ltmp1:
+.Lfunc_end20:
+
+core::panicking::panic_bounds_check:
+.Lfunc_begin21:
+	.loc	1 266 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp2:
+	.loc	1 271 5 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp3:
+.Lfunc_end21:
+
+core::slice::index::slice_index_fail:
+.Lfunc_begin22:
+	.loc	2 38 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp4:
+	.loc	3 177 9 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp5:
+.Lfunc_end22:
+
+optional_trait_methods::num::parse_num::<usize>:
+.Lfunc_begin23:
+	test	rsi, rsi
+	je	.LBB23_55
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB23_4
+	cmp	ecx, 45
+	jne	.LBB23_6
+	mov	cl, 1
+	jmp	.LBB23_5
+.LBB23_4:
+	xor	ecx, ecx
+.LBB23_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB23_7
+.LBB23_6:
+	xor	ecx, ecx
+.LBB23_7:
+	cmp	rsi, 1
+	jbe	.LBB23_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB23_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB23_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB23_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB23_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB23_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB23_32
+.LBB23_14:
+	test	rsi, rsi
+	je	.LBB23_55
+	mov	al, byte ptr [rdi]
+.LBB23_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB23_55
+.LBB23_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB23_19
+	cmp	byte ptr [rax], 95
+	je	.LBB23_55
+.LBB23_19:
+	mov	r8d, 10
+	xor	r9d, r9d
+	xor	edx, edx
+.LBB23_20:
+	cmp	rdi, rsi
+	je	.LBB23_50
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB23_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB23_20
+	jmp	.LBB23_51
+.LBB23_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB23_28
+	mov	rax, rdx
+	mul	r8
+	jo	.LBB23_28
+	mov	rdx, rax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB23_27
+	sub	rdx, rax
+	jae	.LBB23_20
+	jmp	.LBB23_28
+.LBB23_27:
+	add	rdx, rax
+	jae	.LBB23_20
+.LBB23_28:
+	xor	eax, eax
+	ret
+.LBB23_29:
+	mov	r8b, 8
+	jmp	.LBB23_31
+.LBB23_30:
+	mov	r8b, 2
+.LBB23_31:
+	xor	r9d, r9d
+.LBB23_32:
+	cmp	rsi, 2
+	je	.LBB23_55
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB23_52
+.LBB23_34:
+	cmp	dl, r8b
+	jae	.LBB23_55
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB23_37
+	cmp	byte ptr [rax], 95
+	je	.LBB23_55
+.LBB23_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB23_38:
+	cmp	r10, rdi
+	je	.LBB23_50
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB23_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB23_51
+	jmp	.LBB23_38
+.LBB23_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB23_45
+	test	r9b, r9b
+	je	.LBB23_28
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB23_28
+	add	al, -87
+	mov	r11d, eax
+.LBB23_45:
+	cmp	r11b, r8b
+	jae	.LBB23_28
+	mov	rax, rdx
+	mul	rsi
+	jo	.LBB23_28
+	mov	rdx, rax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB23_49
+	sub	rdx, rax
+	jae	.LBB23_38
+	jmp	.LBB23_28
+.LBB23_49:
+	add	rdx, rax
+	jae	.LBB23_38
+	jmp	.LBB23_28
+.LBB23_50:
+	mov	eax, 1
+	ret
+.LBB23_52:
+	test	r9b, r9b
+	je	.LBB23_55
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB23_55
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB23_34
+.LBB23_55:
+	xor	eax, eax
+.LBB23_51:
+	ret
+.Lfunc_end23:
+
+<optional_trait_methods::commands::Args>::reg:
+.Lfunc_begin24:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	cmp	rdx, 8
+	setb	cl
+	and	cl, al
+	cmovne	r15, rdx
+	neg	cl
+	or	cl, 2
+	mov	qword ptr [rbx], r15
+	mov	byte ptr [rbx + 8], cl
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end24:
+
+<optional_trait_methods::commands::Args>::next:
+.Lfunc_begin25:
+	mov	r10, qword ptr [rsi + 8]
+	mov	rax, qword ptr [rsi + 16]
+	xor	edx, edx
+	test	r10, r10
+	setne	dl
+	cmp	r10, 1
+	mov	rcx, r10
+	adc	rcx, -1
+	mov	r8, qword ptr [rsi]
+	add	r8, rdx
+	mov	r9, rcx
+	cmp	r10, 2
+	jb	.LBB25_5
+	xor	r9d, r9d
+.LBB25_2:
+	cmp	byte ptr [r8 + r9], 32
+	je	.LBB25_5
+	inc	r9
+	cmp	rcx, r9
+	jne	.LBB25_2
+	mov	r9, rcx
+.LBB25_5:
+	add	rax, rdx
+	sub	rcx, r9
+	lea	rdx, [r8 + r9]
+	mov	qword ptr [rsi], rdx
+	mov	qword ptr [rsi + 8], rcx
+	lea	rcx, [r9 + rax]
+	mov	qword ptr [rsi + 16], rcx
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], r8
+	mov	qword ptr [rdi + 16], r9
+	ret
+.Lfunc_end25:
+
+<optional_trait_methods::commands::Args>::names:
+.Lfunc_begin26:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 200
+	mov	qword ptr [rsp + 32], rdi
+	lea	r14, [rsp + 40]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r12, qword ptr [r14 + 8]
+	mov	rbx, qword ptr [r14 + 16]
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, qword ptr [rip + bcmp@GOTPCREL]
+.LBB26_1:
+	test	rbx, rbx
+	je	.LBB26_2
+	xor	r13d, r13d
+.LBB26_4:
+	cmp	byte ptr [r12 + r13], 59
+	je	.LBB26_7
+	inc	r13
+	cmp	rbx, r13
+	jne	.LBB26_4
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 24], r12
+	mov	r13, rbx
+	jmp	.LBB26_8
+.LBB26_2:
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	xor	ebx, ebx
+	mov	qword ptr [rsp + 24], r12
+	xor	r13d, r13d
+	jmp	.LBB26_8
+.LBB26_7:
+	lea	rax, [r13 + 1]
+	sub	rbx, rax
+	lea	rax, [r12 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 24], rax
+	mov	dword ptr [rsp + 12], 0
+.LBB26_8:
+	mov	edx, 144
+	lea	rbp, [rsp + 56]
+	mov	rdi, rbp
+	lea	rsi, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.6]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r14d, 6
+.LBB26_9:
+	cmp	qword ptr [rbp + 16], r13
+	jne	.LBB26_11
+	mov	rdi, qword ptr [rbp + 8]
+	mov	rsi, r12
+	mov	rdx, r13
+	call	r15
+	test	eax, eax
+	je	.LBB26_12
+.LBB26_11:
+	add	rbp, 24
+	dec	r14
+	jne	.LBB26_9
+	jmp	.LBB26_13
+.LBB26_12:
+	mov	rax, qword ptr [rsp + 16]
+	or	al, byte ptr [rbp]
+	mov	qword ptr [rsp + 16], rax
+.LBB26_13:
+	mov	r12, qword ptr [rsp + 24]
+	cmp	byte ptr [rsp + 12], 0
+	je	.LBB26_1
+	mov	rax, qword ptr [rsp + 32]
+	mov	rcx, qword ptr [rsp + 16]
+	mov	byte ptr [rax], cl
+	mov	byte ptr [rax + 8], -1
+	add	rsp, 200
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end26:
+
+<optional_trait_methods::connection::Listener>::tcp_loopback:
+.Lfunc_begin27:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	qword ptr [rsp + 20], 0
+	mov	word ptr [rsp + 12], 2
+	rol	di, 8
+	mov	word ptr [rsp + 14], di
+	mov	dword ptr [rsp + 16], 16777343
+	mov	edi, 2
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebx, edx
+	mov	ebp, 1
+	test	al, 1
+	jne	.LBB27_5
+	lea	rcx, [rsp + 28]
+	mov	dword ptr [rcx], 1
+	mov	edi, ebx
+	mov	esi, 1
+	mov	edx, 2
+	mov	r8d, 4
+	call	qword ptr [rip + setsockopt@GOTPCREL]
+	lea	rsi, [rsp + 12]
+	mov	edi, ebx
+	mov	edx, 16
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB27_3
+	mov	edi, ebx
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB27_3
+	xor	ebp, ebp
+	jmp	.LBB27_5
+.LBB27_3:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebx, r14d
+.LBB27_5:
+	mov	eax, ebp
+	mov	edx, ebx
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end27:
+
+<optional_trait_methods::connection::Listener>::socket:
+.Lfunc_begin28:
+	push	rbx
+	xor	ebx, ebx
+	mov	esi, 1
+	xor	edx, edx
+	call	qword ptr [rip + socket@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB28_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB28_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end28:
+
+<optional_trait_methods::connection::Listener>::unix:
+.Lfunc_begin29:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 112
+	mov	rdx, rsi
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [rsp + 96], xmm0
+	movups	xmmword ptr [rsp + 84], xmm0
+	movups	xmmword ptr [rsp + 68], xmm0
+	movups	xmmword ptr [rsp + 52], xmm0
+	movups	xmmword ptr [rsp + 36], xmm0
+	movups	xmmword ptr [rsp + 20], xmm0
+	movups	xmmword ptr [rsp + 4], xmm0
+	mov	word ptr [rsp + 2], 1
+	lea	rax, [rsi - 108]
+	cmp	rax, -107
+	jae	.LBB29_2
+	mov	ebx, 1
+	mov	ebp, 36
+	jmp	.LBB29_7
+.LBB29_2:
+	mov	rsi, rdi
+	lea	rbx, [rsp + 4]
+	mov	rdi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	rdi, rbx
+	call	qword ptr [rip + unlink@GOTPCREL]
+	mov	ebx, 1
+	mov	edi, 1
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebp, edx
+	test	al, 1
+	jne	.LBB29_7
+	lea	rsi, [rsp + 2]
+	mov	edi, ebp
+	mov	edx, 110
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB29_6
+	mov	edi, ebp
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB29_6
+	xor	ebx, ebx
+	jmp	.LBB29_7
+.LBB29_6:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebp, r14d
+.LBB29_7:
+	mov	eax, ebx
+	mov	edx, ebp
+	add	rsp, 112
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end29:
+
+<optional_trait_methods::connection::Listener>::accept:
+.Lfunc_begin30:
+	push	rbx
+	xor	ebx, ebx
+	xor	esi, esi
+	xor	edx, edx
+	call	qword ptr [rip + accept@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB30_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB30_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end30:
+
+optional_trait_methods::commands::unrecognized:
+.Lfunc_begin31:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	xor	r14d, r14d
+.LBB31_1:
+	cmp	byte ptr [rbx + r14], 32
+	je	.LBB31_4
+	inc	r14
+	cmp	rsi, r14
+	jne	.LBB31_1
+	mov	r14, rsi
+.LBB31_4:
+	mov	r15d, 8
+	lea	r12, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.33]
+	mov	r13, qword ptr [rip + bcmp@GOTPCREL]
+.LBB31_5:
+	cmp	qword ptr [r15 + r12], r14
+	jne	.LBB31_8
+	mov	rdi, qword ptr [r15 + r12 - 8]
+	mov	rsi, rbx
+	mov	rdx, r14
+	call	r13
+	test	eax, eax
+	je	.LBB31_7
+.LBB31_8:
+	add	r15, 16
+	cmp	r15, 152
+	jne	.LBB31_5
+	xor	eax, eax
+	jmp	.LBB31_10
+.LBB31_7:
+	mov	al, 1
+.LBB31_10:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end31:
+
+optional_trait_methods::connection::read_fd:
+.Lfunc_begin32:
+	push	rbx
+	mov	rax, rdx
+	mov	rbx, rdi
+	mov	edx, 1024
+	mov	edi, esi
+	mov	rsi, rax
+	call	qword ptr [rip + read@GOTPCREL]
+	test	rax, rax
+	js	.LBB32_2
+	mov	qword ptr [rbx + 8], rax
+	xor	eax, eax
+	jmp	.LBB32_3
+.LBB32_2:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	eax, dword ptr [rax]
+	mov	dword ptr [rbx + 4], eax
+	mov	eax, 1
+.LBB32_3:
+	mov	dword ptr [rbx], eax
+	pop	rbx
+	ret
+.Lfunc_end32:
+
+optional_trait_methods::connection::write_fd:
+.Lfunc_begin33:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	test	rdx, rdx
+	je	.LBB33_8
+	mov	ebp, ecx
+	mov	rbx, rdx
+	mov	r14, rsi
+	mov	r15d, edi
+	mov	r12, qword ptr [rip + send@GOTPCREL]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+.LBB33_2:
+	mov	edi, r15d
+	mov	rsi, r14
+	mov	rdx, rbx
+	test	bpl, bpl
+	je	.LBB33_3
+	mov	ecx, 16384
+	call	r12
+	jmp	.LBB33_5
+.LBB33_3:
+	call	r13
+.LBB33_5:
+	test	rax, rax
+	js	.LBB33_10
+	cmp	rbx, rax
+	jb	.LBB33_9
+	add	r14, rax
+	sub	rbx, rax
+	jne	.LBB33_2
+.LBB33_8:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB33_10:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB33_9:
+	mov	rdi, rax
+	mov	rsi, rbx
+	mov	rdx, rbx
+	call	core::slice::index::slice_index_fail
+.Lfunc_end33:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint:
+.Lfunc_begin34:
+	push	r14
+	push	rbx
+	push	rax
+	mov	rdx, qword ptr [rdi + 584]
+	mov	rcx, qword ptr [rdi + 576]
+	mov	r8, qword ptr [rdi + 712]
+	mov	rsi, qword ptr [rdi + 704]
+	lea	r9, [rdi + 48]
+	cmp	qword ptr [rdi + 568], 16
+	jne	.LBB34_3
+	mov	rax, qword ptr [rdi + 560]
+	cmp	rax, 16
+	jae	.LBB34_5
+	mov	r10, rax
+	shl	r10, 5
+	mov	r11, qword ptr [r9 + r10]
+	mov	rbx, qword ptr [r9 + r10 + 8]
+	mov	r14, qword ptr [r9 + r10 + 24]
+	mov	r10, qword ptr [r9 + r10 + 16]
+	mov	qword ptr [rdi], 1
+	mov	qword ptr [rdi + 8], 0
+	mov	qword ptr [rdi + 16], r11
+	mov	qword ptr [rdi + 24], rbx
+	mov	qword ptr [rdi + 32], r10
+	mov	qword ptr [rdi + 40], r14
+	jmp	.LBB34_4
+.LBB34_3:
+	mov	rax, qword ptr [rdi + 560]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rdi], xmm0
+	cmp	rax, 16
+	jae	.LBB34_5
+.LBB34_4:
+	shl	rax, 5
+	mov	qword ptr [r9 + rax + 8], rdx
+	mov	qword ptr [r9 + rax], rcx
+	mov	qword ptr [r9 + rax + 24], r8
+	mov	qword ptr [r9 + rax + 16], rsi
+	mov	eax, dword ptr [rdi + 560]
+	inc	eax
+	and	eax, 15
+	mov	qword ptr [rdi + 560], rax
+	mov	rax, qword ptr [rdi + 568]
+	inc	rax
+	cmp	rax, 16
+	mov	ecx, 16
+	cmovb	rcx, rax
+	mov	qword ptr [rdi + 568], rcx
+	add	rsp, 8
+	pop	rbx
+	pop	r14
+	ret
+.LBB34_5:
+	mov	esi, 16
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end34:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds:
+.Lfunc_begin35:
+	cmp	rcx, rsi
+	mov	rax, r8
+	sbb	rax, rdx
+	jge	.LBB35_2
+	lea	rax, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.42]
+	jmp	.LBB35_3
+.LBB35_2:
+	mov	qword ptr [rdi + 720], rsi
+	mov	qword ptr [rdi + 728], rdx
+	mov	qword ptr [rdi + 736], rcx
+	mov	qword ptr [rdi + 744], r8
+	mov	rax, qword ptr [rdi + 704]
+	mov	r9, qword ptr [rdi + 712]
+	cmp	rax, rsi
+	mov	r10, r9
+	sbb	r10, rdx
+	cmovl	r9, rdx
+	cmovl	rax, rsi
+	cmp	rcx, rax
+	mov	rdx, r8
+	sbb	rdx, r9
+	cmovl	r9, r8
+	cmovl	rax, rcx
+	mov	qword ptr [rdi + 704], rax
+	mov	qword ptr [rdi + 712], r9
+	xor	eax, eax
+.LBB35_3:
+	mov	edx, 26
+	ret
+.Lfunc_end35:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_factor:
+.Lfunc_begin36:
+	mov	rax, qword ptr [rdi + 720]
+	mov	rcx, qword ptr [rdi + 728]
+	mov	r8, qword ptr [rdi + 744]
+	mov	r9, qword ptr [rdi + 736]
+	cmp	rsi, rax
+	mov	r10, rdx
+	sbb	r10, rcx
+	cmovl	rdx, rcx
+	cmovl	rsi, rax
+	cmp	r9, rsi
+	mov	rax, r8
+	sbb	rax, rdx
+	cmovl	rsi, r9
+	cmovl	rdx, r8
+	mov	qword ptr [rdi + 712], rdx
+	mov	qword ptr [rdi + 704], rsi
+	ret
+.Lfunc_end36:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::dec:
+.Lfunc_begin37:
+	add	qword ptr [rdi + 576], -1
+	adc	qword ptr [rdi + 584], -1
+	ret
+.Lfunc_end37:
+
+.LCPI38_0:
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	128
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::div:
+.Lfunc_begin38:
+	mov	rcx, rdx
+	mov	rax, rsi
+	or	rax, rdx
+	je	.LBB38_1
+	push	rbx
+	mov	rdx, rsi
+	mov	rbx, rdi
+	mov	rdi, qword ptr [rdi + 576]
+	mov	rsi, qword ptr [rbx + 584]
+	movq	xmm0, rdi
+	movq	xmm1, rsi
+	punpcklqdq	xmm0, xmm1
+	movq	xmm1, rdx
+	movq	xmm2, rcx
+	punpcklqdq	xmm1, xmm2
+	pcmpeqd	xmm2, xmm2
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI38_0]
+	pcmpeqb	xmm2, xmm1
+	pand	xmm0, xmm2
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	je	.LBB38_4
+	call	qword ptr [rip + __divti3@GOTPCREL]
+	mov	qword ptr [rbx + 584], rdx
+	mov	qword ptr [rbx + 576], rax
+	xor	eax, eax
+.LBB38_6:
+	pop	rbx
+	ret
+.LBB38_1:
+	lea	rax, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.43]
+	mov	edx, 17
+	ret
+.LBB38_4:
+	lea	rax, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.44]
+	mov	edx, 29
+	jmp	.LBB38_6
+.Lfunc_end38:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc:
+.Lfunc_begin39:
+	add	qword ptr [rdi + 576], 1
+	adc	qword ptr [rdi + 584], 0
+	ret
+.Lfunc_end39:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::mul:
+.Lfunc_begin40:
+	mov	rax, rsi
+	xor	rax, 7
+	or	rax, rdx
+	jne	.LBB40_2
+	lea	rax, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.45]
+	jmp	.LBB40_3
+.LBB40_2:
+	mov	rcx, rdx
+	mov	rax, qword ptr [rdi + 704]
+	imul	rcx, rax
+	mul	rsi
+	mov	r8, qword ptr [rdi + 576]
+	add	rdx, rcx
+	mov	rcx, qword ptr [rdi + 712]
+	imul	rcx, rsi
+	add	rcx, rdx
+	mov	rsi, qword ptr [rdi + 584]
+	imul	rsi, rax
+	mul	r8
+	imul	rcx, r8
+	add	rcx, rdx
+	add	rcx, rsi
+	mov	qword ptr [rdi + 576], rax
+	mov	qword ptr [rdi + 584], rcx
+	xor	eax, eax
+.LBB40_3:
+	mov	edx, 28
+	ret
+.Lfunc_end40:
+
+.LCPI41_0:
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	128
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rem:
+.Lfunc_begin41:
+	mov	rcx, rdx
+	mov	rax, rsi
+	or	rax, rdx
+	je	.LBB41_1
+	push	rbx
+	mov	rdx, rsi
+	mov	rbx, rdi
+	mov	rdi, qword ptr [rdi + 576]
+	mov	rsi, qword ptr [rbx + 584]
+	movq	xmm0, rdi
+	movq	xmm1, rsi
+	punpcklqdq	xmm0, xmm1
+	movq	xmm1, rdx
+	movq	xmm2, rcx
+	punpcklqdq	xmm1, xmm2
+	pcmpeqd	xmm2, xmm2
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI41_0]
+	pcmpeqb	xmm2, xmm1
+	pand	xmm0, xmm2
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	je	.LBB41_4
+	call	qword ptr [rip + __modti3@GOTPCREL]
+	mov	qword ptr [rbx + 584], rdx
+	mov	qword ptr [rbx + 576], rax
+	xor	eax, eax
+.LBB41_6:
+	pop	rbx
+	ret
+.LBB41_1:
+	lea	rax, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.43]
+	mov	edx, 17
+	ret
+.LBB41_4:
+	lea	rax, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.44]
+	mov	edx, 29
+	jmp	.LBB41_6
+.Lfunc_end41:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::undo:
+.Lfunc_begin42:
+	mov	rax, qword ptr [rdi + 568]
+	lea	rcx, [rsi - 1]
+	cmp	rcx, rax
+	jae	.LBB42_2
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rdi], xmm0
+	mov	ecx, dword ptr [rdi + 560]
+	sub	ecx, esi
+	and	ecx, 15
+	mov	qword ptr [rdi + 560], rcx
+	sub	rax, rsi
+	mov	qword ptr [rdi + 568], rax
+	shl	ecx, 5
+	mov	rax, qword ptr [rdi + rcx + 56]
+	mov	rdx, qword ptr [rdi + rcx + 72]
+	mov	rsi, qword ptr [rdi + rcx + 48]
+	mov	rcx, qword ptr [rdi + rcx + 64]
+	mov	r8, qword ptr [rdi + 728]
+	mov	r9, qword ptr [rdi + 720]
+	mov	r10, qword ptr [rdi + 744]
+	mov	r11, qword ptr [rdi + 736]
+	mov	qword ptr [rdi + 576], rsi
+	cmp	rcx, r9
+	mov	rsi, rdx
+	sbb	rsi, r8
+	cmovl	rdx, r8
+	cmovl	rcx, r9
+	cmp	r11, rcx
+	mov	rsi, r10
+	sbb	rsi, rdx
+	cmovl	rcx, r11
+	cmovl	rdx, r10
+	mov	qword ptr [rdi + 584], rax
+	mov	qword ptr [rdi + 712], rdx
+	mov	qword ptr [rdi + 704], rcx
+	jmp	.LBB42_3
+.LBB42_2:
+	test	rsi, rsi
+	je	.LBB42_3
+	xor	ecx, ecx
+	test	rax, rax
+	setne	cl
+	lea	rdx, [rcx + 2*rcx + 16]
+	lea	rcx, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.47]
+	lea	rax, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.46]
+	cmove	rax, rcx
+	ret
+.LBB42_3:
+	xor	eax, eax
+	ret
+.Lfunc_end42:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg:
+.Lfunc_begin43:
+	test	rsi, rsi
+	je	.LBB43_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB43_5
+	shl	rax, 4
+	add	rdi, rax
+	add	rdi, 592
+	jmp	.LBB43_2
+.LBB43_1:
+	add	rdi, 576
+.LBB43_2:
+	mov	rax, qword ptr [rdi]
+	mov	rdx, qword ptr [rdi + 8]
+	ret
+.LBB43_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end43:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rollback:
+.Lfunc_begin44:
+	mov	r9, qword ptr [rdi]
+	mov	rax, qword ptr [rdi + 24]
+	mov	rdx, qword ptr [rdi + 16]
+	mov	rcx, qword ptr [rdi + 40]
+	mov	rsi, qword ptr [rdi + 32]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rdi], xmm0
+	mov	r8, qword ptr [rdi + 568]
+	test	r8, r8
+	je	.LBB44_1
+	push	r15
+	push	r14
+	push	rbx
+	mov	r10d, dword ptr [rdi + 560]
+	dec	r10d
+	and	r10d, 15
+	mov	qword ptr [rdi + 560], r10
+	lea	r11, [r8 - 1]
+	mov	qword ptr [rdi + 568], r11
+	shl	r10d, 5
+	mov	rbx, qword ptr [rdi + r10 + 56]
+	mov	r11, qword ptr [rdi + r10 + 72]
+	mov	r15, qword ptr [rdi + r10 + 48]
+	mov	r14, qword ptr [rdi + r10 + 64]
+	test	r9b, 1
+	je	.LBB44_4
+	lea	r9, [rdi + r10]
+	add	r9, 48
+	mov	qword ptr [r9], rdx
+	mov	qword ptr [r9 + 8], rax
+	mov	qword ptr [r9 + 16], rsi
+	mov	qword ptr [r9 + 24], rcx
+	mov	qword ptr [rdi + 568], r8
+.LBB44_4:
+	mov	qword ptr [rdi + 576], r15
+	mov	qword ptr [rdi + 584], rbx
+	mov	qword ptr [rdi + 704], r14
+	mov	qword ptr [rdi + 712], r11
+	xor	eax, eax
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.LBB44_1:
+	lea	rax, [rip + .Lanon.5f5efd4235af2d479d54e58ba0a6f598.50]
+	ret
+.Lfunc_end44:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::set_state:
+.Lfunc_begin45:
+	mov	qword ptr [rdi + 584], rdx
+	mov	qword ptr [rdi + 576], rsi
+	ret
+.Lfunc_end45:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg:
+.Lfunc_begin46:
+	test	rsi, rsi
+	je	.LBB46_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB46_5
+	shl	rax, 4
+	mov	qword ptr [rdi + rax + 600], rcx
+	mov	qword ptr [rdi + rax + 592], rdx
+	ret
+.LBB46_1:
+	mov	qword ptr [rdi + 576], rdx
+	mov	qword ptr [rdi + 584], rcx
+	ret
+.LBB46_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end46:
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.0:
+	.byte	10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.1:
+	.byte	59
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.2:
+	.ascii	"E 01"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.3:
+	.ascii	"E 02"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.4:
+	.ascii	"E."
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.5:
+	.ascii	" at "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.6:
+	.ascii	"E 05"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.7:
+	.ascii	"unknown option: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.8:
+	.ascii	"missing value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.9:
+	.ascii	"invalid value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.10:
+	.ascii	": "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.11:
+	.ascii	" doesn't take a value"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.12:
+	.ascii	"only one of --unix / --tcp may be given"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.14:
+	.ascii	"0123456789abcdef"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.17:
+	.ascii	"--unix"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.18:
+	.ascii	"--tcp"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.19:
+	.ascii	"--initial-state"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.20:
+	.ascii	"--on-error"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.21:
+	.ascii	"--echo"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.22:
+	.ascii	"--repl"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.23:
+	.ascii	"--help"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.24:
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.17
+	.asciz	"\006\000\000\000\000\000\000\000"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.18
+	.ascii	"\005\000\000\000\000\000\000\000\001"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.19
+	.ascii	"\017\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.20
+	.ascii	"\n\000\000\000\000\000\000\000\003"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.21
+	.ascii	"\006\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.22
+	.ascii	"\006\000\000\000\000\000\000\000\005"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.23
+	.ascii	"\006\000\000\000\000\000\000\000\006"
+	.zero	7
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.28:
+	.ascii	"Invalid implementation!"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.29:
+	.ascii	"OK"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.30:
+	.ascii	"listening on 127.0.0.1:"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.31:
+	.ascii	"error: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.32:
+	.zero	2,10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.33:
+	.ascii	"usage: optional-trait-methods [options]\n\n  --unix <path>              serve the first client to connect to a Unix domain socket\n  --tcp <port>               serve the first client to connect to 127.0.0.1:<port>\n                             (port 0 picks any free port, printed to stderr)\n                             (by default, serve stdin / stdout)\n  --initial-state <n>        start the target off in state <n> (by default, 0)\n  --on-error=continue|exit   whether to keep serving after a command fails\n  --echo                     repeat every command before its reply\n  --repl                     expect a human instead of a client (prompt, aliases, help)\n                             (needs the `repl` feature)\n  --help                     print this message\n\n  (`--name <value>` may also be written `--name=<value>`)"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.34:
+	.ascii	"panic!\n"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.35:
+	.ascii	"Parse IncDec extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.39:
+	.ascii	"Parse Mul extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.41:
+	.ascii	"Parse ScaleFactor extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.43:
+	.ascii	"Parse ScaleBounds extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.45:
+	.ascii	"Parse Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.48:
+	.ascii	"Parse Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.57:
+	.ascii	"IncDec extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.58:
+	.ascii	"Mul extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.59:
+	.ascii	"ScaleFactor extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.60:
+	.ascii	"ScaleBounds extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.61:
+	.ascii	"Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.62:
+	.ascii	"Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.63:
+	.ascii	"Undo checkpoint"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.64:
+	.ascii	"unknown command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.65:
+	.ascii	"unsupported command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.66:
+	.ascii	"bad number"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.67:
+	.ascii	"trailing garbage"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.68:
+	.ascii	"line too long"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.72:
+	.ascii	"failed to accept a connection"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.73:
+	.ascii	"--repl needs the `repl` feature"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.0:
+	.ascii	"incdec"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.1:
+	.ascii	"mul"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.2:
+	.ascii	"mul.scale_factor"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.3:
+	.ascii	"mul.scale_factor.scale_bounds"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.4:
+	.ascii	"div"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.5:
+	.ascii	"undo"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.6:
+	.byte	1
+	.zero	7
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.0
+	.ascii	"\006\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.1
+	.ascii	"\003\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.2
+	.ascii	"\020\000\000\000\000\000\000\000\b"
+	.zero	7
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.3
+	.ascii	"\035\000\000\000\000\000\000\000\020"
+	.zero	7
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.4
+	.ascii	"\003\000\000\000\000\000\000\000 "
+	.zero	7
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.5
+	.asciz	"\004\000\000\000\000\000\000"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.15:
+	.byte	43
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.17:
+	.byte	45
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.18:
+	.byte	42
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.20:
+	.ascii	"*~"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.28:
+	.ascii	"+-"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.29:
+	.ascii	"*~~"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.30:
+	.byte	47
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.31:
+	.byte	37
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.32:
+	.byte	117
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.33:
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.15
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.17
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.28
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.18
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.20
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.29
+	.asciz	"\003\000\000\000\000\000\000"
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.30
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.31
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.5f5efd4235af2d479d54e58ba0a6f598.32
+	.asciz	"\001\000\000\000\000\000\000"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.42:
+	.ascii	"scale bounds are inverted!"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.43:
+	.ascii	"dividing by zero!"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.44:
+	.ascii	"dividing MIN by -1 overflows!"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.45:
+	.ascii	"multiplying by 7 is unlucky!"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.46:
+	.ascii	"not enough history!"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.47:
+	.ascii	"nothing to undo!"
+
+.Lanon.5f5efd4235af2d479d54e58ba0a6f598.50:
+	.ascii	"nothing to roll back!"
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+
+	.byte	1
+	.byte	17
+	.byte	1
+	.byte	37
+	.byte	14
+	.byte	19
+	.byte	5
+	.byte	3
+	.byte	14
+	.byte	16
+	.byte	23
+	.byte	27
+	.byte	14
+	.byte	17
+	.byte	1
+	.byte	85
+	.byte	23
+	.byte	0
+	.byte	0
+	.byte	2
+	.byte	57
+	.byte	1
+	.byte	3
+	.byte	14
+	.byte	0
+	.byte	0
+	.byte	3
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	11
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	4
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	5
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	0
+.Lcu_begin0:
+	.long	.Ldebug_info_end0-.Ldebug_info_start0
+.Ldebug_info_start0:
+	.short	4
+	.long	.debug_abbrev
+	.byte	8
+	.byte	1
+	.long	.Linfo_string0
+	.short	28
+	.long	.Linfo_string1
+	.long	.Lline_table_start0
+	.long	.Linfo_string2
+	.quad	0
+	.long	.Ldebug_ranges0
+	.byte	2
+	.long	.Linfo_string3
+	.byte	2
+	.long	.Linfo_string4
+	.byte	3
+	.quad	.Lfunc_begin20
+	.long	.Lfunc_end20-.Lfunc_begin20
+	.byte	1
+	.byte	86
+	.long	.Linfo_string7
+	.long	.Linfo_string8
+	.byte	1
+	.byte	60
+	.byte	3
+
+
+	.byte	4
+	.quad	.Lfunc_begin21
+	.long	.Lfunc_end21-.Lfunc_begin21
+	.byte	1
+	.byte	86
+	.long	.Linfo_string9
+	.long	.Linfo_string10
+	.byte	1
+	.short	266
+	.byte	3
+
+
+	.byte	0
+	.byte	2
+	.long	.Linfo_string5
+	.byte	2
+	.long	.Linfo_string6
+	.byte	3
+	.quad	.Lfunc_begin22
+	.long	.Lfunc_end22-.Lfunc_begin22
+	.byte	1
+	.byte	86
+	.long	.Linfo_string11
+	.long	.Linfo_string12
+	.byte	2
+	.byte	38
+	.byte	3
+
+
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+.Ldebug_info_end0:
+.Lsec_end0:
+.Lsec_end1:
+.Lsec_end2:
+	.long	76
+	.short	2
+	.long	.Lcu_begin0
+	.byte	8
+	.byte	0
+	.zero	4,255
+	.quad	.Lfunc_begin20
+	.quad	.Lsec_end0-.Lfunc_begin20
+	.quad	.Lfunc_begin21
+	.quad	.Lsec_end1-.Lfunc_begin21
+	.quad	.Lfunc_begin22
+	.quad	.Lsec_end2-.Lfunc_begin22
+	.quad	0
+	.quad	0
+.Ldebug_ranges0:
+	.quad	.Lfunc_begin20
+	.quad	.Lfunc_end20
+	.quad	.Lfunc_begin21
+	.quad	.Lfunc_end21
+	.quad	.Lfunc_begin22
+	.quad	.Lfunc_end22
+	.quad	0
+	.quad	0
+.Linfo_string0:
+	.asciz	"clang LLVM (rustc version 1.97.0-nightly (e50aa6fba 2026-05-19))"
+.Linfo_string1:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a/library/core/src/lib.rs/@/core.667c7a611d73a360-cgu.0"
+.Linfo_string2:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a"
+.Linfo_string3:
+	.asciz	"core"
+.Linfo_string4:
+	.asciz	"panicking"
+.Linfo_string5:
+	.asciz	"slice"
+.Linfo_string6:
+	.asciz	"index"
+.Linfo_string7:
+	.asciz	"core::panicking::panic_fmt"
+.Linfo_string8:
+	.asciz	"panic_fmt"
+.Linfo_string9:
+	.asciz	"core::panicking::panic_bounds_check"
+.Linfo_string10:
+	.asciz	"panic_bounds_check"
+.Linfo_string11:
+	.asciz	"core::slice::index::slice_index_fail"
+.Linfo_string12:
+	.asciz	"slice_index_fail"
+	.ident	"rustc version 1.97.0-nightly (e50aa6fba 2026-05-19)"
+.Lline_table_start0:
@@ -0,0 +1,5381 @@
+.LCPI0_0:
+	.long	2
+	.long	0
+	.long	0
+	.long	0
+<optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin0:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 472
+	mov	r12, rcx
+	mov	rbx, rdx
+	mov	r15, rsi
+	movzx	r13d, byte ptr [rcx]
+	lea	rax, [r13 - 6]
+	xor	ebp, ebp
+	cmp	r13, 7
+	cmovae	rbp, rax
+	mov	r14, rdi
+	lea	rax, [rip + .LJTI0_0]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB0_1:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::checkpoint
+	lea	rax, [rip + .LJTI0_1]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB0_2:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI0_2]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB0_3:
+	mov	r12, qword ptr [r15 + 576]
+	mov	r13, qword ptr [r15 + 584]
+	jmp	.LBB0_50
+.LBB0_4:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 14
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::undo
+	test	rax, rax
+	je	.LBB0_46
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], rdx
+	jmp	.LBB0_11
+.LBB0_6:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI0_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB0_7:
+	movdqa	xmm0, xmmword ptr [r15 + 576]
+	mov	byte ptr [rsp], 1
+	movdqa	xmmword ptr [rsp + 16], xmm0
+	jmp	.LBB0_65
+.LBB0_8:
+	movdqa	xmm0, xmmword ptr [r12 + 16]
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI0_0]
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	jne	.LBB0_13
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	r8, qword ptr [r12 + 56]
+	mov	rcx, qword ptr [r12 + 48]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::scale_bounds
+	test	rax, rax
+	je	.LBB0_46
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 26
+	jmp	.LBB0_11
+.LBB0_12:
+	mov	r13, qword ptr [r12 + 16]
+	jmp	.LBB0_14
+.LBB0_13:
+	mov	r13, qword ptr [r12 + 16]
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::checkpoint
+.LBB0_14:
+	test	r13, r13
+	je	.LBB0_23
+	cmp	r13, 1
+	jne	.LBB0_24
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::scale_factor
+	jmp	.LBB0_62
+.LBB0_17:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	test	byte ptr [r12 + 16], 1
+	je	.LBB0_25
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::rem
+	jmp	.LBB0_26
+.LBB0_19:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 16
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [r12 + 1]
+	test	eax, eax
+	je	.LBB0_30
+	cmp	eax, 1
+	je	.LBB0_22
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::inc
+.LBB0_22:
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::dec
+	jmp	.LBB0_62
+.LBB0_23:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::mul
+	jmp	.LBB0_26
+.LBB0_24:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	r8, qword ptr [r12 + 56]
+	mov	rcx, qword ptr [r12 + 48]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::scale_bounds
+	jmp	.LBB0_26
+.LBB0_25:
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::div
+.LBB0_26:
+	test	rax, rax
+	je	.LBB0_62
+	mov	r12, rax
+	mov	r13, rdx
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::rollback
+	test	rax, rax
+	je	.LBB0_29
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 21
+.LBB0_11:
+	mov	byte ptr [rsp], -1
+	jmp	.LBB0_65
+.LBB0_29:
+	mov	al, -1
+	jmp	.LBB0_56
+.LBB0_30:
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::inc
+	jmp	.LBB0_62
+.LBB0_31:
+	mov	rcx, qword ptr [r12 + 24]
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::write_reg
+	jmp	.LBB0_46
+.LBB0_32:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	byte ptr [rsp], 2
+	mov	byte ptr [rsp + 1], cl
+	jmp	.LBB0_65
+.LBB0_33:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	mov	byte ptr [rsp], 1
+	mov	qword ptr [rsp + 24], rdx
+	mov	qword ptr [rsp + 16], rax
+	jmp	.LBB0_65
+.LBB0_34:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::checkpoint
+.LBB0_35:
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdx, qword ptr [r12 + 24]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::set_state
+	jmp	.LBB0_62
+.LBB0_36:
+	lea	rdi, [rsp + 160]
+	xor	r12d, r12d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+.LBB0_37:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	r13, 7
+	ja	.LBB0_39
+	shl	r13, 4
+	mov	qword ptr [rsp + r13 + 160], rax
+	mov	qword ptr [rsp + r13 + 168], rdx
+	mov	r13, qword ptr [rsp + 288]
+	inc	r13
+	mov	qword ptr [rsp + 288], r13
+.LBB0_39:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB0_37
+	lea	rdi, [rsp + 319]
+	lea	rsi, [rsp + 160]
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	mov	edx, 144
+	call	r15
+	lea	rdi, [rsp + 1]
+	mov	byte ptr [rdi - 1], 3
+	lea	rsi, [rsp + 304]
+	mov	edx, 159
+	call	r15
+	jmp	.LBB0_65
+.LBB0_41:
+	mov	rdi, rsp
+	xor	r13d, r13d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	ebp, ebp
+.LBB0_42:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	rbp, 7
+	ja	.LBB0_44
+	shl	rbp, 4
+	mov	qword ptr [rsp + rbp], rax
+	mov	qword ptr [rsp + rbp + 8], rdx
+	mov	rbp, qword ptr [rsp + 128]
+	inc	rbp
+	mov	qword ptr [rsp + 128], rbp
+.LBB0_44:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB0_42
+	add	r12, 16
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB0_46:
+	mov	byte ptr [rsp], 0
+	jmp	.LBB0_65
+.LBB0_47:
+	mov	rcx, qword ptr [r12 + 24]
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::write_reg
+	jmp	.LBB0_62
+.LBB0_48:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	al, 2
+	jmp	.LBB0_63
+.LBB0_49:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	mov	r12, rax
+	mov	r13, rdx
+.LBB0_50:
+	mov	al, 1
+	jmp	.LBB0_56
+.LBB0_51:
+	mov	rdi, rsp
+	xor	r12d, r12d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+.LBB0_52:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	r13, 7
+	ja	.LBB0_54
+	shl	r13, 4
+	mov	qword ptr [rsp + r13], rax
+	mov	qword ptr [rsp + r13 + 8], rdx
+	mov	r13, qword ptr [rsp + 128]
+	inc	r13
+	mov	qword ptr [rsp + 128], r13
+.LBB0_54:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB0_52
+	lea	rsi, [rsp + 16]
+	mov	r12, qword ptr [rsi - 16]
+	mov	r13, qword ptr [rsi - 8]
+	lea	rdi, [rsp + 304]
+	mov	ecx, 16
+	rep movsq es:[rdi], [rsi]
+	mov	al, 3
+.LBB0_56:
+	jmp	.LBB0_64
+.LBB0_57:
+	mov	rdi, rsp
+	xor	r13d, r13d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	ebp, ebp
+.LBB0_58:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	rbp, 7
+	ja	.LBB0_60
+	shl	rbp, 4
+	mov	qword ptr [rsp + rbp], rax
+	mov	qword ptr [rsp + rbp + 8], rdx
+	mov	rbp, qword ptr [rsp + 128]
+	inc	rbp
+	mov	qword ptr [rsp + 128], rbp
+.LBB0_60:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB0_58
+	add	r12, 16
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB0_62:
+	xor	eax, eax
+.LBB0_63:
+.LBB0_64:
+	lea	rdi, [rsp + 32]
+	mov	byte ptr [rdi - 32], al
+	mov	byte ptr [rdi - 31], cl
+	mov	qword ptr [rdi - 24], 0
+	mov	qword ptr [rdi - 8], r13
+	mov	qword ptr [rdi - 16], r12
+	lea	rsi, [rsp + 304]
+	mov	ecx, 16
+	rep movsq es:[rdi], [rsi]
+.LBB0_65:
+	mov	rdx, rsp
+	mov	rdi, r14
+	mov	rsi, rbx
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	add	rsp, 472
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end0:
+.LJTI0_0:
+	.long	.LBB0_6-.LJTI0_0
+	.long	.LBB0_1-.LJTI0_0
+	.long	.LBB0_8-.LJTI0_0
+	.long	.LBB0_1-.LJTI0_0
+	.long	.LBB0_4-.LJTI0_0
+.LJTI0_1:
+	.long	.LBB0_2-.LJTI0_1
+	.long	.LBB0_19-.LJTI0_1
+	.long	.LBB0_12-.LJTI0_1
+	.long	.LBB0_17-.LJTI0_1
+.LJTI0_2:
+	.long	.LBB0_3-.LJTI0_2
+	.long	.LBB0_35-.LJTI0_2
+	.long	.LBB0_48-.LJTI0_2
+	.long	.LBB0_49-.LJTI0_2
+	.long	.LBB0_47-.LJTI0_2
+	.long	.LBB0_51-.LJTI0_2
+	.long	.LBB0_57-.LJTI0_2
+.LJTI0_3:
+	.long	.LBB0_7-.LJTI0_3
+	.long	.LBB0_34-.LJTI0_3
+	.long	.LBB0_32-.LJTI0_3
+	.long	.LBB0_33-.LJTI0_3
+	.long	.LBB0_31-.LJTI0_3
+	.long	.LBB0_36-.LJTI0_3
+	.long	.LBB0_41-.LJTI0_3
+
+.LCPI1_0:
+	.long	2
+	.long	0
+	.long	0
+	.long	0
+<optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin1:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 472
+	mov	r12, rcx
+	mov	rbx, rdx
+	mov	r15, rsi
+	movzx	r13d, byte ptr [rcx]
+	lea	rax, [r13 - 6]
+	xor	ebp, ebp
+	cmp	r13, 7
+	cmovae	rbp, rax
+	mov	r14, rdi
+	lea	rax, [rip + .LJTI1_0]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB1_1:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::checkpoint
+	lea	rax, [rip + .LJTI1_1]
+	movsxd	rcx, dword ptr [rax + 4*rbp]
+	add	rcx, rax
+	jmp	rcx
+.LBB1_2:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI1_2]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB1_3:
+	mov	r12, qword ptr [r15 + 576]
+	mov	r13, qword ptr [r15 + 584]
+	jmp	.LBB1_50
+.LBB1_4:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 14
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::undo
+	test	rax, rax
+	je	.LBB1_46
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], rdx
+	jmp	.LBB1_11
+.LBB1_6:
+	mov	eax, r13d
+	lea	rcx, [rip + .LJTI1_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB1_7:
+	movdqa	xmm0, xmmword ptr [r15 + 576]
+	mov	byte ptr [rsp], 1
+	movdqa	xmmword ptr [rsp + 16], xmm0
+	jmp	.LBB1_65
+.LBB1_8:
+	movdqa	xmm0, xmmword ptr [r12 + 16]
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI1_0]
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	jne	.LBB1_13
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	r8, qword ptr [r12 + 56]
+	mov	rcx, qword ptr [r12 + 48]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::scale_bounds
+	test	rax, rax
+	je	.LBB1_46
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 26
+	jmp	.LBB1_11
+.LBB1_12:
+	mov	r13, qword ptr [r12 + 16]
+	jmp	.LBB1_14
+.LBB1_13:
+	mov	r13, qword ptr [r12 + 16]
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::checkpoint
+.LBB1_14:
+	test	r13, r13
+	je	.LBB1_23
+	cmp	r13, 1
+	jne	.LBB1_24
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::scale_factor
+	jmp	.LBB1_62
+.LBB1_17:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	test	byte ptr [r12 + 16], 1
+	je	.LBB1_25
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::rem
+	jmp	.LBB1_26
+.LBB1_19:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 16
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [r12 + 1]
+	test	eax, eax
+	je	.LBB1_30
+	cmp	eax, 1
+	je	.LBB1_22
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::inc
+.LBB1_22:
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::dec
+	jmp	.LBB1_62
+.LBB1_23:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::mul
+	jmp	.LBB1_26
+.LBB1_24:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [r12 + 32]
+	mov	rdx, qword ptr [r12 + 40]
+	mov	r8, qword ptr [r12 + 56]
+	mov	rcx, qword ptr [r12 + 48]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::scale_bounds
+	jmp	.LBB1_26
+.LBB1_25:
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::div
+.LBB1_26:
+	test	rax, rax
+	je	.LBB1_62
+	mov	r12, rax
+	mov	r13, rdx
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::rollback
+	test	rax, rax
+	je	.LBB1_29
+	mov	qword ptr [rsp + 8], 0
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 24], 21
+.LBB1_11:
+	mov	byte ptr [rsp], -1
+	jmp	.LBB1_65
+.LBB1_29:
+	mov	al, -1
+	jmp	.LBB1_56
+.LBB1_30:
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::inc
+	jmp	.LBB1_62
+.LBB1_31:
+	mov	rcx, qword ptr [r12 + 24]
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::write_reg
+	jmp	.LBB1_46
+.LBB1_32:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	byte ptr [rsp], 2
+	mov	byte ptr [rsp + 1], cl
+	jmp	.LBB1_65
+.LBB1_33:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	mov	byte ptr [rsp], 1
+	mov	qword ptr [rsp + 24], rdx
+	mov	qword ptr [rsp + 16], rax
+	jmp	.LBB1_65
+.LBB1_34:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 15
+	#APP
+	#NO_APP
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::checkpoint
+.LBB1_35:
+	mov	rsi, qword ptr [r12 + 16]
+	mov	rdx, qword ptr [r12 + 24]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::set_state
+	jmp	.LBB1_62
+.LBB1_36:
+	lea	rdi, [rsp + 160]
+	xor	r12d, r12d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+.LBB1_37:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	r13, 7
+	ja	.LBB1_39
+	shl	r13, 4
+	mov	qword ptr [rsp + r13 + 160], rax
+	mov	qword ptr [rsp + r13 + 168], rdx
+	mov	r13, qword ptr [rsp + 288]
+	inc	r13
+	mov	qword ptr [rsp + 288], r13
+.LBB1_39:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB1_37
+	lea	rdi, [rsp + 319]
+	lea	rsi, [rsp + 160]
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	mov	edx, 144
+	call	r15
+	lea	rdi, [rsp + 1]
+	mov	byte ptr [rdi - 1], 3
+	lea	rsi, [rsp + 304]
+	mov	edx, 159
+	call	r15
+	jmp	.LBB1_65
+.LBB1_41:
+	mov	rdi, rsp
+	xor	r13d, r13d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	ebp, ebp
+.LBB1_42:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	rbp, 7
+	ja	.LBB1_44
+	shl	rbp, 4
+	mov	qword ptr [rsp + rbp], rax
+	mov	qword ptr [rsp + rbp + 8], rdx
+	mov	rbp, qword ptr [rsp + 128]
+	inc	rbp
+	mov	qword ptr [rsp + 128], rbp
+.LBB1_44:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB1_42
+	add	r12, 16
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB1_46:
+	mov	byte ptr [rsp], 0
+	jmp	.LBB1_65
+.LBB1_47:
+	mov	rcx, qword ptr [r12 + 24]
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdx, qword ptr [r12 + 16]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::write_reg
+	jmp	.LBB1_62
+.LBB1_48:
+	mov	al, byte ptr [r12 + 2]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [r12 + 1], 0
+	mov	ecx, 63
+	cmovne	ecx, eax
+	mov	al, 2
+	jmp	.LBB1_63
+.LBB1_49:
+	mov	rsi, qword ptr [r12 + 8]
+	mov	rdi, r15
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	mov	r12, rax
+	mov	r13, rdx
+.LBB1_50:
+	mov	al, 1
+	jmp	.LBB1_56
+.LBB1_51:
+	mov	rdi, rsp
+	xor	r12d, r12d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+.LBB1_52:
+	mov	rdi, r15
+	mov	rsi, r12
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	r13, 7
+	ja	.LBB1_54
+	shl	r13, 4
+	mov	qword ptr [rsp + r13], rax
+	mov	qword ptr [rsp + r13 + 8], rdx
+	mov	r13, qword ptr [rsp + 128]
+	inc	r13
+	mov	qword ptr [rsp + 128], r13
+.LBB1_54:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB1_52
+	lea	rsi, [rsp + 16]
+	mov	r12, qword ptr [rsi - 16]
+	mov	r13, qword ptr [rsi - 8]
+	lea	rdi, [rsp + 304]
+	mov	ecx, 16
+	rep movsq es:[rdi], [rsi]
+	mov	al, 3
+.LBB1_56:
+	jmp	.LBB1_64
+.LBB1_57:
+	mov	rdi, rsp
+	xor	r13d, r13d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	ebp, ebp
+.LBB1_58:
+	mov	rdi, r15
+	mov	rsi, r13
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	rbp, 7
+	ja	.LBB1_60
+	shl	rbp, 4
+	mov	qword ptr [rsp + rbp], rax
+	mov	qword ptr [rsp + rbp + 8], rdx
+	mov	rbp, qword ptr [rsp + 128]
+	inc	rbp
+	mov	qword ptr [rsp + 128], rbp
+.LBB1_60:
+	inc	r13
+	cmp	r13, 8
+	jne	.LBB1_58
+	add	r12, 16
+	mov	rdi, r12
+	mov	rsi, r15
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB1_62:
+	xor	eax, eax
+.LBB1_63:
+.LBB1_64:
+	lea	rdi, [rsp + 32]
+	mov	byte ptr [rdi - 32], al
+	mov	byte ptr [rdi - 31], cl
+	mov	qword ptr [rdi - 24], 0
+	mov	qword ptr [rdi - 8], r13
+	mov	qword ptr [rdi - 16], r12
+	lea	rsi, [rsp + 304]
+	mov	ecx, 16
+	rep movsq es:[rdi], [rsi]
+.LBB1_65:
+	mov	rdx, rsp
+	mov	rdi, r14
+	mov	rsi, rbx
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	add	rsp, 472
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end1:
+.LJTI1_0:
+	.long	.LBB1_6-.LJTI1_0
+	.long	.LBB1_1-.LJTI1_0
+	.long	.LBB1_8-.LJTI1_0
+	.long	.LBB1_1-.LJTI1_0
+	.long	.LBB1_4-.LJTI1_0
+.LJTI1_1:
+	.long	.LBB1_2-.LJTI1_1
+	.long	.LBB1_19-.LJTI1_1
+	.long	.LBB1_12-.LJTI1_1
+	.long	.LBB1_17-.LJTI1_1
+.LJTI1_2:
+	.long	.LBB1_3-.LJTI1_2
+	.long	.LBB1_35-.LJTI1_2
+	.long	.LBB1_48-.LJTI1_2
+	.long	.LBB1_49-.LJTI1_2
+	.long	.LBB1_47-.LJTI1_2
+	.long	.LBB1_51-.LJTI1_2
+	.long	.LBB1_57-.LJTI1_2
+.LJTI1_3:
+	.long	.LBB1_7-.LJTI1_3
+	.long	.LBB1_34-.LJTI1_3
+	.long	.LBB1_32-.LJTI1_3
+	.long	.LBB1_33-.LJTI1_3
+	.long	.LBB1_31-.LJTI1_3
+	.long	.LBB1_36-.LJTI1_3
+	.long	.LBB1_41-.LJTI1_3
+
+<optional_trait_methods::commands::Args>::num::<i128>:
+.Lfunc_begin2:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rsi, qword ptr [r14 + 8]
+	mov	rdx, qword ptr [r14 + 16]
+	mov	r14, rsp
+	mov	rdi, r14
+	call	optional_trait_methods::num::parse_num::<i128>
+	test	byte ptr [r14], 1
+	je	.LBB2_2
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	qword ptr [rbx + 16], rax
+	mov	qword ptr [rbx + 24], rcx
+	xor	eax, eax
+	jmp	.LBB2_3
+.LBB2_2:
+	mov	qword ptr [rbx + 8], r15
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB2_3:
+	mov	qword ptr [rbx], rax
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end2:
+
+<optional_trait_methods::commands::Args>::regs::<i128>:
+.Lfunc_begin3:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	sub	rsp, 168
+	mov	rbx, rdi
+	lea	r14, [rsp + 16]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	cmp	qword ptr [r14 + 16], 256
+	jne	.LBB3_20
+	lea	rdi, [rsp + 16]
+	mov	r12, qword ptr [rdi + 8]
+	xor	r14d, r14d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	eax, 256
+	xorps	xmm0, xmm0
+.LBB3_2:
+	mov	rcx, r12
+	add	r12, 32
+	add	rax, -32
+	movaps	xmmword ptr [rsp], xmm0
+	xor	edx, edx
+.LBB3_3:
+	mov	dil, byte ptr [rcx + 2*rdx]
+	lea	esi, [rdi - 48]
+	cmp	sil, 10
+	jb	.LBB3_13
+	lea	esi, [rdi - 97]
+	cmp	sil, 5
+	ja	.LBB3_10
+	add	dil, -87
+	jmp	.LBB3_12
+.LBB3_10:
+	lea	esi, [rdi - 71]
+	cmp	sil, -6
+	jb	.LBB3_20
+	add	dil, -55
+.LBB3_12:
+	mov	esi, edi
+.LBB3_13:
+	mov	dil, byte ptr [rcx + 2*rdx + 1]
+	lea	r8d, [rdi - 48]
+	cmp	r8b, 10
+	jb	.LBB3_19
+	lea	r8d, [rdi - 97]
+	cmp	r8b, 5
+	ja	.LBB3_16
+	add	dil, -87
+	jmp	.LBB3_18
+.LBB3_16:
+	lea	r8d, [rdi - 71]
+	cmp	r8b, -6
+	jb	.LBB3_20
+	add	dil, -55
+.LBB3_18:
+	mov	r8d, edi
+.LBB3_19:
+	shl	sil, 4
+	or	sil, r8b
+	mov	byte ptr [rsp + rdx], sil
+	inc	rdx
+	cmp	rdx, 16
+	jne	.LBB3_3
+	cmp	r14, 7
+	ja	.LBB3_8
+	movaps	xmm1, xmmword ptr [rsp]
+	shl	r14, 4
+	movaps	xmmword ptr [rsp + r14 + 16], xmm1
+	mov	r14, qword ptr [rsp + 144]
+	inc	r14
+	mov	qword ptr [rsp + 144], r14
+.LBB3_8:
+	test	rax, rax
+	jne	.LBB3_2
+	lea	rdi, [rbx + 16]
+	lea	rsi, [rsp + 16]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	xor	eax, eax
+	jmp	.LBB3_21
+.LBB3_20:
+	mov	qword ptr [rbx + 8], r15
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB3_21:
+	mov	qword ptr [rbx], rax
+	add	rsp, 168
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end3:
+
+<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>:
+.Lfunc_begin4:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rax, qword ptr [rdi + 128]
+	mov	rcx, rax
+	shl	rcx, 4
+	xor	r15d, r15d
+	cmp	rax, 9
+	mov	r12d, 16
+	cmovb	r12, rdi
+	cmovb	r15, rcx
+	test	r15, r15
+	je	.LBB4_3
+	mov	rbx, rsi
+	xor	r14d, r14d
+	xor	r13d, r13d
+.LBB4_2:
+	mov	rdx, qword ptr [r12 + r13]
+	mov	rcx, qword ptr [r12 + r13 + 8]
+	mov	rdi, rbx
+	mov	rsi, r14
+	call	optional_trait_methods::using_fn::targets::advanced::write_reg
+	inc	r14
+	add	r13, 16
+	cmp	r15, r13
+	jne	.LBB4_2
+.LBB4_3:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end4:
+
+optional_trait_methods::num::format_decimal::<usize>:
+.Lfunc_begin5:
+	mov	eax, 39
+	movabs	r8, -3689348814741910323
+.LBB5_1:
+	cmp	rax, 39
+	ja	.LBB5_4
+	mov	rcx, rax
+	mov	rax, rdi
+	mul	r8
+	shr	rdx, 3
+	lea	eax, [rdx + rdx]
+	lea	eax, [rax + 4*rax]
+	mov	r9d, edi
+	sub	r9d, eax
+	or	r9b, 48
+	mov	byte ptr [rsi + rcx], r9b
+	lea	rax, [rcx - 1]
+	cmp	rdi, 10
+	mov	rdi, rdx
+	jae	.LBB5_1
+	add	rsi, rcx
+	inc	rcx
+	mov	edx, 41
+	sub	rdx, rcx
+	mov	rax, rsi
+	ret
+.LBB5_4:
+	push	rax
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end5:
+
+optional_trait_methods::num::format_decimal::<i128>:
+.Lfunc_begin6:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r12, rsi
+	mov	r15, rdi
+	mov	eax, 39
+	mov	ebp, 10
+	mov	r14, qword ptr [rip + __divti3@GOTPCREL]
+	mov	qword ptr [rsp], rsi
+.LBB6_1:
+	cmp	rax, 39
+	ja	.LBB6_9
+	mov	r13, rax
+	mov	edx, 10
+	mov	rdi, r15
+	mov	rsi, r12
+	xor	ecx, ecx
+	call	r14
+	mov	rcx, rax
+	mov	rsi, rdx
+	mul	rbp
+	lea	rdi, [rsi + 4*rsi]
+	lea	rdx, [rdx + 2*rdi]
+	mov	rdi, r15
+	sub	rdi, rax
+	mov	rax, r12
+	sbb	rax, rdx
+	mov	edx, edi
+	neg	dl
+	test	rax, rax
+	movzx	eax, dl
+	cmovns	eax, edi
+	mov	edx, 48
+	cmp	al, 10
+	jb	.LBB6_4
+	mov	edx, 87
+.LBB6_4:
+	add	dl, al
+	mov	byte ptr [rbx + r13], dl
+	add	r15, 9
+	adc	r12, 0
+	lea	rax, [r13 - 1]
+	cmp	r15, 19
+	sbb	r12, 0
+	mov	r15, rcx
+	mov	r12, rsi
+	jae	.LBB6_1
+	cmp	qword ptr [rsp], 0
+	jns	.LBB6_8
+	inc	r13
+	add	r13, -2
+	cmp	r13, 39
+	ja	.LBB6_9
+	mov	byte ptr [rbx + r13], 45
+.LBB6_8:
+	mov	edx, 40
+	sub	rdx, r13
+	add	rbx, r13
+	mov	rax, rbx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB6_9:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end6:
+
+optional_trait_methods::num::parse_num::<i128>:
+.Lfunc_begin7:
+	push	rbp
+	push	r15
+	push	r14
+	push	rbx
+	test	rdx, rdx
+	je	.LBB7_1
+	mov	rcx, rdx
+	lea	rax, [rsi + 1]
+	dec	rdx
+	movzx	r8d, byte ptr [rsi]
+	cmp	r8d, 43
+	je	.LBB7_6
+	cmp	r8d, 45
+	jne	.LBB7_4
+	mov	r11b, 1
+	jmp	.LBB7_7
+.LBB7_6:
+	xor	r11d, r11d
+.LBB7_7:
+	mov	rcx, rdx
+	mov	rsi, rax
+	jmp	.LBB7_8
+.LBB7_4:
+	xor	r11d, r11d
+.LBB7_8:
+	cmp	rcx, 1
+	jbe	.LBB7_9
+	mov	al, byte ptr [rsi]
+	cmp	al, 48
+	jne	.LBB7_11
+	movzx	eax, byte ptr [rsi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB7_12
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB7_31
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB7_30
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB7_12
+	mov	r10b, 16
+	mov	bl, 1
+	jmp	.LBB7_33
+.LBB7_9:
+	test	rcx, rcx
+	je	.LBB7_1
+	mov	al, byte ptr [rsi]
+.LBB7_11:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB7_1
+.LBB7_12:
+	add	rcx, rsi
+	mov	rax, rcx
+	dec	rax
+	je	.LBB7_14
+	cmp	byte ptr [rax], 95
+	je	.LBB7_1
+.LBB7_14:
+	mov	ebx, 10
+	xor	edx, edx
+	xor	r8d, r8d
+	xor	r9d, r9d
+.LBB7_15:
+	cmp	rsi, rcx
+	je	.LBB7_55
+	mov	bpl, byte ptr [rsi]
+	inc	rsi
+	cmp	bpl, 95
+	jne	.LBB7_18
+	xor	eax, eax
+	test	dl, 1
+	mov	edx, 0
+	jne	.LBB7_15
+	jmp	.LBB7_56
+.LBB7_18:
+	add	bpl, -48
+	cmp	bpl, 9
+	ja	.LBB7_1
+	mov	rax, r8
+	mul	rbx
+	mov	r10, rdx
+	mov	r8, rax
+	mov	rax, r9
+	imul	rbx
+	mov	r9, rax
+	add	r9, r10
+	adc	rdx, 0
+	mov	rax, r9
+	sar	rax, 63
+	mov	r10, rdx
+	xor	r10, rax
+	sar	rdx, 63
+	xor	rdx, rax
+	or	rdx, r10
+	jne	.LBB7_1
+	movzx	eax, bpl
+	test	r11b, r11b
+	je	.LBB7_21
+	sub	r8, rax
+	sbb	r9, 0
+	jo	.LBB7_1
+	jmp	.LBB7_23
+.LBB7_21:
+	add	r8, rax
+	adc	r9, 0
+	jo	.LBB7_1
+.LBB7_23:
+	mov	dl, 1
+	jmp	.LBB7_15
+.LBB7_30:
+	mov	r10b, 8
+	jmp	.LBB7_32
+.LBB7_31:
+	mov	r10b, 2
+.LBB7_32:
+	xor	ebx, ebx
+.LBB7_33:
+	cmp	rcx, 2
+	je	.LBB7_1
+	lea	r14, [rsi + 2]
+	mov	al, byte ptr [r14]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB7_35
+.LBB7_38:
+	cmp	dl, r10b
+	jae	.LBB7_1
+	add	rsi, rcx
+	mov	rax, rsi
+	dec	rax
+	je	.LBB7_41
+	cmp	byte ptr [rax], 95
+	je	.LBB7_1
+.LBB7_41:
+	movzx	r15d, r10b
+	xor	ecx, ecx
+	xor	r8d, r8d
+	xor	r9d, r9d
+.LBB7_42:
+	cmp	r14, rsi
+	je	.LBB7_55
+	mov	al, byte ptr [r14]
+	inc	r14
+	cmp	al, 95
+	jne	.LBB7_45
+	xor	eax, eax
+	test	cl, 1
+	mov	ecx, 0
+	jne	.LBB7_42
+	jmp	.LBB7_56
+.LBB7_45:
+	lea	ebp, [rax - 48]
+	cmp	bpl, 10
+	jb	.LBB7_49
+	test	bl, bl
+	je	.LBB7_1
+	or	al, 32
+	lea	ecx, [rax - 97]
+	cmp	cl, 5
+	ja	.LBB7_1
+	add	al, -87
+	mov	ebp, eax
+.LBB7_49:
+	cmp	bpl, r10b
+	jae	.LBB7_1
+	mov	rax, r8
+	mul	r15
+	mov	rcx, rdx
+	mov	r8, rax
+	mov	rax, r9
+	imul	r15
+	mov	r9, rax
+	add	r9, rcx
+	adc	rdx, 0
+	mov	rax, r9
+	sar	rax, 63
+	mov	rcx, rdx
+	xor	rcx, rax
+	sar	rdx, 63
+	xor	rdx, rax
+	or	rdx, rcx
+	jne	.LBB7_1
+	movzx	eax, bpl
+	test	r11b, r11b
+	je	.LBB7_52
+	sub	r8, rax
+	sbb	r9, 0
+	jo	.LBB7_1
+	jmp	.LBB7_54
+.LBB7_52:
+	add	r8, rax
+	adc	r9, 0
+	jo	.LBB7_1
+.LBB7_54:
+	mov	cl, 1
+	jmp	.LBB7_42
+.LBB7_55:
+	mov	qword ptr [rdi + 16], r8
+	mov	qword ptr [rdi + 24], r9
+	mov	eax, 1
+	jmp	.LBB7_56
+.LBB7_35:
+	test	bl, bl
+	je	.LBB7_1
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB7_1
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB7_38
+.LBB7_1:
+	xor	eax, eax
+.LBB7_56:
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], 0
+	pop	rbx
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end7:
+
+optional_trait_methods::num::parse_num::<u16>:
+.Lfunc_begin8:
+	test	rsi, rsi
+	je	.LBB8_54
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB8_4
+	cmp	ecx, 45
+	jne	.LBB8_6
+	mov	cl, 1
+	jmp	.LBB8_5
+.LBB8_4:
+	xor	ecx, ecx
+.LBB8_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB8_7
+.LBB8_6:
+	xor	ecx, ecx
+.LBB8_7:
+	cmp	rsi, 1
+	jbe	.LBB8_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB8_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB8_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB8_29
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB8_28
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB8_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB8_31
+.LBB8_14:
+	test	rsi, rsi
+	je	.LBB8_54
+	mov	al, byte ptr [rdi]
+.LBB8_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB8_54
+.LBB8_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB8_19
+	cmp	byte ptr [rax], 95
+	je	.LBB8_54
+.LBB8_19:
+	xor	r9d, r9d
+	mov	r8w, 10
+	xor	edx, edx
+.LBB8_20:
+	cmp	rdi, rsi
+	je	.LBB8_49
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB8_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB8_20
+	jmp	.LBB8_55
+.LBB8_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB8_54
+	mov	eax, edx
+	mul	r8w
+	jo	.LBB8_54
+	mov	edx, eax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB8_27
+	sub	dx, ax
+	jae	.LBB8_20
+	jmp	.LBB8_54
+.LBB8_27:
+	add	dx, ax
+	jae	.LBB8_20
+	jmp	.LBB8_54
+.LBB8_28:
+	mov	r8b, 8
+	jmp	.LBB8_30
+.LBB8_29:
+	mov	r8b, 2
+.LBB8_30:
+	xor	r9d, r9d
+.LBB8_31:
+	cmp	rsi, 2
+	je	.LBB8_54
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB8_51
+.LBB8_33:
+	cmp	dl, r8b
+	jae	.LBB8_54
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB8_36
+	cmp	byte ptr [rax], 95
+	je	.LBB8_54
+.LBB8_36:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB8_37:
+	cmp	r10, rdi
+	je	.LBB8_49
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB8_40
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	jne	.LBB8_37
+	jmp	.LBB8_55
+.LBB8_40:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB8_44
+	test	r9b, r9b
+	je	.LBB8_54
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB8_54
+	add	al, -87
+	mov	r11d, eax
+.LBB8_44:
+	cmp	r11b, r8b
+	jae	.LBB8_54
+	mov	eax, edx
+	mul	si
+	jo	.LBB8_54
+	mov	edx, eax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB8_48
+	sub	dx, ax
+	jae	.LBB8_37
+	jmp	.LBB8_54
+.LBB8_48:
+	add	dx, ax
+	jae	.LBB8_37
+	jmp	.LBB8_54
+.LBB8_49:
+	mov	ax, 1
+	ret
+.LBB8_51:
+	test	r9b, r9b
+	je	.LBB8_54
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB8_54
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB8_33
+.LBB8_54:
+	xor	eax, eax
+.LBB8_55:
+	ret
+.Lfunc_end8:
+
+optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin9:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB9_2
+	lea	rcx, [rip + .LJTI9_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB9_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.21]
+	mov	edx, 2
+	jmp	.LBB9_5
+.LBB9_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB9_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB9_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB9_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB9_18
+.LBB9_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB9_15
+	jmp	.LBB9_28
+.LBB9_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB9_14
+	jmp	.LBB9_28
+.LBB9_19:
+	lea	r14, [rbx + 16]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 144]
+	mov	rax, rcx
+	shl	rax, 4
+	cmp	rcx, 9
+	mov	ecx, 16
+	cmovb	rcx, r14
+	cmovae	rax, r12
+	test	rax, rax
+	je	.LBB9_26
+	add	rax, rcx
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+.LBB9_21:
+	movaps	xmm0, xmmword ptr [rcx]
+	add	rcx, 16
+	movaps	xmmword ptr [rsp + 16], xmm0
+	xor	esi, esi
+.LBB9_22:
+	cmp	r12, 256
+	jae	.LBB9_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB9_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 16
+	jne	.LBB9_22
+	cmp	rcx, rax
+	jne	.LBB9_21
+.LBB9_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB9_27
+.LBB9_11:
+	mov	rdi, qword ptr [rbx + 16]
+	mov	rsi, qword ptr [rbx + 24]
+	xorps	xmm0, xmm0
+	lea	rdx, [rsp + 32]
+	movaps	xmmword ptr [rdx + 16], xmm0
+	movaps	xmmword ptr [rdx], xmm0
+	mov	qword ptr [rdx + 32], 0
+	call	optional_trait_methods::num::format_decimal::<i128>
+.LBB9_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB9_27
+.LBB9_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI9_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB9_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB9_4
+.LBB9_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB9_4
+.LBB9_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB9_27
+.LBB9_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB9_12
+.LBB9_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB9_4:
+	mov	edx, 4
+.LBB9_5:
+	mov	rdi, r15
+.LBB9_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB9_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 160
+	mov	rdi, rbp
+	mov	rsi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB9_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB9_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end9:
+.LJTI9_0:
+	.long	.LBB9_10-.LJTI9_0
+	.long	.LBB9_11-.LJTI9_0
+	.long	.LBB9_13-.LJTI9_0
+	.long	.LBB9_19-.LJTI9_0
+.LJTI9_1:
+	.long	.LBB9_3-.LJTI9_1
+	.long	.LBB9_6-.LJTI9_1
+	.long	.LBB9_7-.LJTI9_1
+	.long	.LBB9_8-.LJTI9_1
+	.long	.LBB9_9-.LJTI9_1
+
+optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin10:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB10_2
+	lea	rcx, [rip + .LJTI10_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB10_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.21]
+	mov	edx, 2
+	jmp	.LBB10_5
+.LBB10_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB10_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB10_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB10_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB10_18
+.LBB10_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB10_15
+	jmp	.LBB10_28
+.LBB10_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB10_14
+	jmp	.LBB10_28
+.LBB10_19:
+	lea	r14, [rbx + 16]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 144]
+	mov	rax, rcx
+	shl	rax, 4
+	cmp	rcx, 9
+	mov	ecx, 16
+	cmovb	rcx, r14
+	cmovae	rax, r12
+	test	rax, rax
+	je	.LBB10_26
+	add	rax, rcx
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+.LBB10_21:
+	movaps	xmm0, xmmword ptr [rcx]
+	add	rcx, 16
+	movaps	xmmword ptr [rsp + 16], xmm0
+	xor	esi, esi
+.LBB10_22:
+	cmp	r12, 256
+	jae	.LBB10_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB10_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 16
+	jne	.LBB10_22
+	cmp	rcx, rax
+	jne	.LBB10_21
+.LBB10_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB10_27
+.LBB10_11:
+	mov	rdi, qword ptr [rbx + 16]
+	mov	rsi, qword ptr [rbx + 24]
+	xorps	xmm0, xmm0
+	lea	rdx, [rsp + 32]
+	movaps	xmmword ptr [rdx + 16], xmm0
+	movaps	xmmword ptr [rdx], xmm0
+	mov	qword ptr [rdx + 32], 0
+	call	optional_trait_methods::num::format_decimal::<i128>
+.LBB10_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB10_27
+.LBB10_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI10_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB10_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB10_4
+.LBB10_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB10_4
+.LBB10_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB10_27
+.LBB10_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB10_12
+.LBB10_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB10_4:
+	mov	edx, 4
+.LBB10_5:
+	mov	rdi, r15
+.LBB10_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB10_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 160
+	mov	rdi, rbp
+	mov	rsi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB10_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB10_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end10:
+.LJTI10_0:
+	.long	.LBB10_10-.LJTI10_0
+	.long	.LBB10_11-.LJTI10_0
+	.long	.LBB10_13-.LJTI10_0
+	.long	.LBB10_19-.LJTI10_0
+.LJTI10_1:
+	.long	.LBB10_3-.LJTI10_1
+	.long	.LBB10_6-.LJTI10_1
+	.long	.LBB10_7-.LJTI10_1
+	.long	.LBB10_8-.LJTI10_1
+	.long	.LBB10_9-.LJTI10_1
+
+__rustc::rust_begin_unwind:
+.Lfunc_begin11:
+	push	rax
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.30]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	call	qword ptr [rip + abort@GOTPCREL]
+.Lfunc_end11:
+
+optional_trait_methods::report_port:
+.Lfunc_begin12:
+	push	rbx
+	sub	rsp, 64
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 16]
+	movaps	xmmword ptr [rsi], xmm0
+	lea	rdx, [rsp + 12]
+	mov	dword ptr [rdx], 16
+	call	qword ptr [rip + getsockname@GOTPCREL]
+	test	eax, eax
+	js	.LBB12_1
+	xor	eax, eax
+	test	al, al
+	jne	.LBB12_7
+	movzx	ebx, word ptr [rsp + 18]
+	rol	bx, 8
+	shl	ebx, 16
+	shr	ebx, 16
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 48], 0
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.31]
+	mov	edx, 23
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	ecx, 39
+.LBB12_4:
+	cmp	rcx, 39
+	ja	.LBB12_8
+	mov	rax, rcx
+	movzx	edx, bx
+	imul	esi, edx, 52429
+	shr	esi, 19
+	lea	ecx, [rsi + rsi]
+	lea	ecx, [rcx + 4*rcx]
+	sub	ebx, ecx
+	or	bl, 48
+	mov	byte ptr [rsp + rax + 16], bl
+	lea	rcx, [rax - 1]
+	mov	ebx, esi
+	cmp	dx, 10
+	jae	.LBB12_4
+	lea	rsi, [rsp + rax]
+	add	rsi, 16
+	inc	rax
+	mov	edx, 41
+	sub	rdx, rax
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB12_7
+.LBB12_1:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB12_7:
+	add	rsp, 64
+	pop	rbx
+	ret
+.LBB12_8:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end12:
+
+optional_trait_methods::usage_error:
+.Lfunc_begin13:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.32]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	rax, qword ptr [rbx]
+	lea	rcx, [rip + .LJTI13_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB13_1:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.11]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 16
+	jmp	.LBB13_2
+.LBB13_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.16]
+	mov	edx, 39
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	jmp	.LBB13_7
+.LBB13_4:
+	mov	r14, qword ptr [rbx + 8]
+	mov	r15, qword ptr [rbx + 16]
+	mov	r12, qword ptr [rbx + 24]
+	mov	rbx, qword ptr [rbx + 32]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.13]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, r15
+	call	r13
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+	mov	edx, 2
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r12
+	mov	rdx, rbx
+	call	r13
+	jmp	.LBB13_7
+.LBB13_5:
+	mov	rsi, qword ptr [rbx + 8]
+	mov	rdx, qword ptr [rbx + 16]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.15]
+	mov	edx, 21
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB13_7
+.LBB13_3:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.12]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+.LBB13_2:
+	mov	edi, 2
+	call	r15
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, rbx
+	call	r15
+.LBB13_7:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 2
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	mov	edx, 823
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	mov	rax, rbx
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	jmp	rax
+.Lfunc_end13:
+.LJTI13_0:
+	.long	.LBB13_1-.LJTI13_0
+	.long	.LBB13_3-.LJTI13_0
+	.long	.LBB13_4-.LJTI13_0
+	.long	.LBB13_5-.LJTI13_0
+	.long	.LBB13_6-.LJTI13_0
+
+<optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::parse_command:
+.Lfunc_begin14:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	sub	rsp, 336
+	mov	rbx, rdi
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.35]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 22
+	#APP
+	#NO_APP
+	lea	rax, [rsi + 1]
+	movzx	ecx, byte ptr [rsi]
+	cmp	ecx, 45
+	je	.LBB14_4
+	cmp	ecx, 43
+	jne	.LBB14_9
+	cmp	rdx, 1
+	jne	.LBB14_6
+	mov	byte ptr [rbx + 1], 0
+	jmp	.LBB14_31
+.LBB14_4:
+	cmp	rdx, 1
+	jne	.LBB14_6
+	mov	byte ptr [rbx + 1], 1
+	jmp	.LBB14_31
+.LBB14_6:
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_10
+.LBB14_7:
+	mov	qword ptr [rbx + 8], 1
+.LBB14_8:
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+	jmp	.LBB14_32
+.LBB14_9:
+	cmp	rdx, 2
+	jb	.LBB14_11
+.LBB14_10:
+	cmp	word ptr [rsi], 11563
+	je	.LBB14_29
+.LBB14_11:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.39]
+	mov	r8, rsp
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	ecx, 42
+	jne	.LBB14_17
+	mov	rdi, rdx
+	dec	rdi
+	je	.LBB14_14
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_17
+.LBB14_14:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rdi
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB14_113
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB14_104
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 8
+	jmp	.LBB14_28
+.LBB14_17:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.41]
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 2
+	jb	.LBB14_19
+	cmp	word ptr [rsi], 32298
+	je	.LBB14_45
+.LBB14_19:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.43]
+	mov	r8, rsp
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 3
+	jb	.LBB14_21
+	movzx	edi, word ptr [rsi]
+	xor	edi, 32298
+	movzx	r8d, byte ptr [rsi + 2]
+	xor	r8d, 126
+	or	r8w, di
+	je	.LBB14_52
+.LBB14_21:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.45]
+	mov	r8, rsp
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	ecx, 37
+	je	.LBB14_33
+	cmp	ecx, 47
+	jne	.LBB14_38
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_25
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_58
+.LBB14_25:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB14_113
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB14_104
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 9
+.LBB14_28:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rbx + 16], xmm0
+	jmp	.LBB14_51
+.LBB14_29:
+	cmp	rdx, 2
+	jne	.LBB14_43
+	mov	byte ptr [rbx + 1], 2
+.LBB14_31:
+	mov	al, 7
+.LBB14_32:
+	mov	byte ptr [rbx], al
+	jmp	.LBB14_116
+.LBB14_33:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_35
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_58
+.LBB14_35:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB14_113
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB14_104
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 9
+	jmp	.LBB14_50
+.LBB14_38:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 20
+	#APP
+	#NO_APP
+	cmp	ecx, 111
+	jle	.LBB14_61
+	lea	edi, [rcx - 112]
+	cmp	edi, 7
+	ja	.LBB14_59
+	lea	r8, [rip + .LJTI14_0]
+	movsxd	rdi, dword ptr [r8 + 4*rdi]
+	add	rdi, r8
+	jmp	rdi
+.LBB14_41:
+	cmp	rdx, 1
+	jne	.LBB14_96
+	xor	eax, eax
+	jmp	.LBB14_98
+.LBB14_43:
+	cmp	byte ptr [rsi + 2], 32
+	jne	.LBB14_11
+	mov	qword ptr [rbx + 8], 2
+	jmp	.LBB14_8
+.LBB14_45:
+	lea	rdi, [rsi + 2]
+	mov	r8, rdx
+	sub	r8, 2
+	je	.LBB14_47
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_19
+.LBB14_47:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 2
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB14_113
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB14_104
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 8
+.LBB14_50:
+	mov	qword ptr [rbx + 24], 0
+	mov	qword ptr [rbx + 16], 1
+.LBB14_51:
+	mov	qword ptr [rbx + 32], rax
+	mov	qword ptr [rbx + 40], rcx
+	jmp	.LBB14_116
+.LBB14_52:
+	lea	rdi, [rsi + 3]
+	mov	r8, rdx
+	sub	r8, 3
+	je	.LBB14_54
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_21
+.LBB14_54:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 3
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB14_113
+	mov	r14, rsp
+	mov	r15, qword ptr [r14 + 16]
+	mov	r12, qword ptr [r14 + 24]
+	lea	rsi, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB14_113
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB14_104
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 8
+	mov	qword ptr [rbx + 24], 0
+	mov	qword ptr [rbx + 16], 2
+	mov	qword ptr [rbx + 32], r15
+	mov	qword ptr [rbx + 40], r12
+	mov	qword ptr [rbx + 48], rax
+	mov	qword ptr [rbx + 56], rcx
+	jmp	.LBB14_116
+.LBB14_58:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 20
+	#APP
+	#NO_APP
+.LBB14_59:
+	mov	rdi, rsi
+	mov	rsi, rdx
+	call	optional_trait_methods::commands::unrecognized
+	mov	qword ptr [rbx + 8], 0
+.LBB14_60:
+	mov	byte ptr [rbx + 16], al
+	jmp	.LBB14_115
+.LBB14_61:
+	cmp	ecx, 63
+	je	.LBB14_83
+	cmp	ecx, 71
+	je	.LBB14_86
+	cmp	ecx, 103
+	jne	.LBB14_59
+	cmp	rdx, 1
+	jne	.LBB14_99
+	mov	al, 5
+	jmp	.LBB14_32
+.LBB14_66:
+	mov	rcx, rdx
+	dec	rcx
+	jne	.LBB14_87
+	mov	edx, 1
+.LBB14_68:
+	mov	byte ptr [rbx], 10
+	mov	qword ptr [rbx + 8], rdx
+	jmp	.LBB14_116
+.LBB14_69:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_71
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_59
+.LBB14_71:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB14_113
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB14_104
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 1
+	jmp	.LBB14_128
+.LBB14_74:
+	mov	rdi, rdx
+	dec	rdi
+	je	.LBB14_76
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_107
+.LBB14_76:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rdi
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_114
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_125
+	mov	byte ptr [rbx], 3
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB14_116
+.LBB14_79:
+	mov	rdi, rdx
+	dec	rdi
+	je	.LBB14_81
+.LBB14_80:
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_100
+.LBB14_81:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rdi
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	je	.LBB14_102
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB14_60
+.LBB14_83:
+	mov	rcx, rdx
+	dec	rcx
+	jne	.LBB14_120
+	mov	qword ptr [rsp], rax
+	mov	qword ptr [rsp + 8], rcx
+	mov	qword ptr [rsp + 16], 1
+	xor	ebp, ebp
+.LBB14_85:
+	mov	byte ptr [rbx], 2
+	mov	byte ptr [rbx + 1], bpl
+	mov	byte ptr [rbx + 2], al
+	jmp	.LBB14_116
+.LBB14_86:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_112
+	jmp	.LBB14_111
+.LBB14_87:
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_59
+	lea	r15, [rdx - 2]
+	add	rsi, 2
+	mov	r14, r15
+	cmp	rcx, 1
+	je	.LBB14_93
+	add	rdx, -2
+	xor	r14d, r14d
+.LBB14_90:
+	cmp	byte ptr [rsi + r14], 32
+	je	.LBB14_93
+	inc	r14
+	cmp	rdx, r14
+	jne	.LBB14_90
+	mov	r14, r15
+.LBB14_93:
+	mov	rdi, rsi
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<usize>
+	test	al, 1
+	je	.LBB14_126
+	sub	r15, r14
+	je	.LBB14_68
+	add	r14, 2
+	mov	qword ptr [rbx + 8], r14
+	jmp	.LBB14_106
+.LBB14_96:
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_59
+	mov	qword ptr [rbx + 8], 1
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+.LBB14_98:
+	mov	byte ptr [rbx], al
+	jmp	.LBB14_116
+.LBB14_99:
+	cmp	byte ptr [rax], 32
+	je	.LBB14_7
+.LBB14_100:
+	cmp	ecx, 63
+	je	.LBB14_119
+	cmp	ecx, 71
+	jne	.LBB14_59
+	jmp	.LBB14_110
+.LBB14_102:
+	mov	r14, rsp
+	lea	rsi, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB14_113
+	cmp	qword ptr [rsp + 176], 0
+	je	.LBB14_127
+.LBB14_104:
+	mov	rax, qword ptr [rsp + 184]
+.LBB14_105:
+	mov	qword ptr [rbx + 8], rax
+.LBB14_106:
+	mov	byte ptr [rbx + 16], 3
+	jmp	.LBB14_115
+.LBB14_107:
+	mov	r8d, ecx
+	add	r8b, -63
+	je	.LBB14_119
+	rol	r8b, 5
+	movzx	r8d, r8b
+	cmp	r8d, 1
+	je	.LBB14_110
+	cmp	r8d, 7
+	jne	.LBB14_59
+	jmp	.LBB14_80
+.LBB14_110:
+	lea	rcx, [rdx - 1]
+.LBB14_111:
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_59
+.LBB14_112:
+	lea	rsi, [rsp + 312]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::regs::<i128>
+	cmp	byte ptr [r14], 0
+	je	.LBB14_117
+.LBB14_113:
+	mov	rax, qword ptr [rsp + 8]
+	mov	cl, byte ptr [rsp + 16]
+.LBB14_114:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], cl
+.LBB14_115:
+	mov	byte ptr [rbx], -1
+.LBB14_116:
+	add	rsp, 336
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB14_117:
+	lea	rsi, [rsp + 17]
+	mov	bpl, byte ptr [rsi - 1]
+	lea	rdi, [rsp + 168]
+	mov	edx, 143
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	cmp	qword ptr [rsp + 320], 0
+	je	.LBB14_123
+	mov	rax, qword ptr [rsp + 328]
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB14_8
+.LBB14_119:
+	lea	rcx, [rdx - 1]
+.LBB14_120:
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_59
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	test	rcx, rcx
+	setne	bpl
+	lea	r14, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::names
+	mov	al, byte ptr [r14 + 8]
+	cmp	al, -1
+	je	.LBB14_124
+	mov	rcx, qword ptr [rsp + 168]
+	mov	qword ptr [rbx + 8], rcx
+	jmp	.LBB14_60
+.LBB14_123:
+	mov	byte ptr [rbx + 16], bpl
+	lea	rdi, [rbx + 17]
+	lea	rsi, [rsp + 168]
+	mov	edx, 143
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	al, 6
+	jmp	.LBB14_32
+.LBB14_124:
+	cmp	qword ptr [rsp + 8], 0
+	je	.LBB14_129
+.LBB14_125:
+	mov	rax, qword ptr [rsp + 16]
+	jmp	.LBB14_105
+.LBB14_126:
+	mov	qword ptr [rbx + 8], 2
+	mov	byte ptr [rbx + 16], 2
+	jmp	.LBB14_115
+.LBB14_127:
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 4
+	mov	qword ptr [rbx + 8], r15
+.LBB14_128:
+	mov	qword ptr [rbx + 16], rax
+	mov	qword ptr [rbx + 24], rcx
+	jmp	.LBB14_116
+.LBB14_129:
+	mov	al, byte ptr [rsp + 168]
+	jmp	.LBB14_85
+.Lfunc_end14:
+.LJTI14_0:
+	.long	.LBB14_41-.LJTI14_0
+	.long	.LBB14_59-.LJTI14_0
+	.long	.LBB14_74-.LJTI14_0
+	.long	.LBB14_69-.LJTI14_0
+	.long	.LBB14_59-.LJTI14_0
+	.long	.LBB14_66-.LJTI14_0
+	.long	.LBB14_59-.LJTI14_0
+	.long	.LBB14_79-.LJTI14_0
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin15:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB15_1
+	xor	eax, eax
+	mov	qword ptr [r14 + 1024], rax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	cmovae	rdx, rax
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB15_3
+	mov	edi, 1
+	mov	rsi, r15
+	mov	rdx, rbx
+	xor	ecx, ecx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	jmp	optional_trait_methods::connection::write_fd
+.LBB15_1:
+	cmp	rax, rdx
+	jb	.LBB15_4
+.LBB15_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB15_4:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end15:
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin16:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB16_1
+	xor	eax, eax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	mov	qword ptr [r14 + 1024], rax
+	cmovae	rdx, rax
+	mov	ebp, dword ptr [r14 + 1032]
+	mov	edi, ebp
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB16_3
+	mov	edi, ebp
+	mov	rsi, r15
+	mov	rdx, rbx
+	mov	ecx, 1
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	optional_trait_methods::connection::write_fd
+.LBB16_1:
+	cmp	rax, rdx
+	jb	.LBB16_4
+.LBB16_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB16_4:
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end16:
+
+main:
+.Lfunc_begin17:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 3960
+	cmp	rdi, 2
+	jl	.LBB17_55
+	mov	r12, rsi
+	mov	ebp, 1
+	lea	r13, [rsp + 624]
+	mov	qword ptr [rsp + 24], 0
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	mov	dword ptr [rsp + 20], eax
+	mov	qword ptr [rsp + 72], 0
+	mov	qword ptr [rsp + 56], 0
+	mov	qword ptr [rsp + 64], 0
+	mov	qword ptr [rsp + 88], 0
+	mov	qword ptr [rsp + 80], 0
+	mov	qword ptr [rsp + 32], rsi
+	mov	qword ptr [rsp + 96], rdi
+.LBB17_2:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [r12 + 8*rbp]
+	mov	rdi, rbp
+	call	rcx
+	mov	rbx, rax
+	test	rax, rax
+	je	.LBB17_7
+	xor	r14d, r14d
+.LBB17_4:
+	cmp	byte ptr [rbp + r14], 61
+	je	.LBB17_9
+	inc	r14
+	cmp	rbx, r14
+	jne	.LBB17_4
+	mov	qword ptr [rsp], 0
+	mov	r14, rbx
+	jmp	.LBB17_8
+.LBB17_7:
+	mov	qword ptr [rsp], 0
+	xor	r14d, r14d
+.LBB17_8:
+	jmp	.LBB17_10
+.LBB17_9:
+	lea	rax, [r14 + 1]
+	mov	rcx, rbx
+	sub	rcx, rax
+	mov	qword ptr [rsp + 48], rcx
+	lea	rax, [r14 + rbp]
+	inc	rax
+	mov	qword ptr [rsp], rax
+.LBB17_10:
+	mov	rax, qword ptr [rsp + 8]
+	inc	rax
+	mov	qword ptr [rsp + 40], rax
+	mov	qword ptr [rsp + 608], 0
+	mov	qword ptr [rsp + 616], 7
+	mov	edx, 168
+	mov	rdi, r13
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	call	r15
+	mov	r12d, 32
+.LBB17_11:
+	cmp	qword ptr [rsp + r12 + 600], r14
+	jne	.LBB17_14
+	movzx	r13d, byte ptr [rsp + r12 + 608]
+	mov	r15, qword ptr [rsp + r12 + 592]
+	mov	rdi, r15
+	mov	rsi, rbp
+	mov	rdx, r14
+	call	qword ptr [rip + bcmp@GOTPCREL]
+	test	eax, eax
+	jne	.LBB17_14
+	cmp	r13d, 255
+	jne	.LBB17_15
+.LBB17_14:
+	add	r12, 24
+	cmp	r12, 200
+	jne	.LBB17_11
+	jmp	.LBB17_92
+.LBB17_15:
+	cmp	r13, 255
+	je	.LBB17_92
+	cmp	r13b, 4
+	jae	.LBB17_19
+	mov	rdx, qword ptr [rsp]
+	test	rdx, rdx
+	mov	r12, qword ptr [rsp + 32]
+	mov	rbx, qword ptr [rsp + 96]
+	je	.LBB17_21
+	mov	rbp, qword ptr [rsp + 40]
+	mov	rax, qword ptr [rsp + 48]
+	jmp	.LBB17_24
+.LBB17_19:
+	cmp	qword ptr [rsp], 0
+	mov	r12, qword ptr [rsp + 32]
+	mov	rbx, qword ptr [rsp + 96]
+	jne	.LBB17_101
+	mov	edx, 1
+	mov	rbp, qword ptr [rsp + 40]
+	xor	eax, eax
+	jmp	.LBB17_24
+.LBB17_21:
+	cmp	qword ptr [rsp + 40], rbx
+	mov	rbp, qword ptr [rsp + 8]
+	jge	.LBB17_102
+	mov	rdi, qword ptr [r12 + 8*rbp + 8]
+	test	rdi, rdi
+	je	.LBB17_102
+	mov	qword ptr [rsp], rdi
+	call	qword ptr [rip + strlen@GOTPCREL]
+	mov	rdx, qword ptr [rsp]
+	add	rbp, 2
+.LBB17_24:
+	cmp	r13b, 5
+	jne	.LBB17_26
+	mov	al, 1
+	mov	qword ptr [rsp + 88], rax
+	cmp	rbp, rbx
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r13, [rsp + 624]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB17_2
+	jmp	.LBB17_53
+.LBB17_26:
+	movzx	ecx, r13b
+	mov	esi, ecx
+	lea	rcx, [rip + .LJTI17_0]
+	movsxd	rsi, dword ptr [rcx + 4*rsi]
+	add	rsi, rcx
+	jmp	rsi
+.LBB17_27:
+	test	rax, rax
+	lea	r13, [rsp + 624]
+	je	.LBB17_116
+	mov	qword ptr [rsp + 104], rdx
+	mov	qword ptr [rsp + 112], rax
+	mov	dword ptr [rsp + 20], eax
+	cmp	word ptr [rsp + 24], 0
+	mov	ax, 1
+	mov	qword ptr [rsp + 24], rax
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	je	.LBB17_51
+	jmp	.LBB17_109
+.LBB17_29:
+	mov	al, 1
+	mov	qword ptr [rsp + 64], rax
+	jmp	.LBB17_41
+.LBB17_30:
+	mov	qword ptr [rsp + 120], rax
+	mov	qword ptr [rsp + 72], rdx
+	jmp	.LBB17_41
+.LBB17_31:
+	cmp	rax, 8
+	lea	r13, [rsp + 624]
+	je	.LBB17_42
+	cmp	rax, 4
+	jne	.LBB17_121
+	mov	esi, 4
+	cmp	byte ptr [rdx], 101
+	jne	.LBB17_122
+	cmp	byte ptr [rdx + 1], 120
+	jne	.LBB17_122
+	cmp	byte ptr [rdx + 2], 105
+	jne	.LBB17_122
+	cmp	byte ptr [rdx + 3], 116
+	jne	.LBB17_122
+	jmp	.LBB17_50
+.LBB17_37:
+	mov	rdi, rdx
+	mov	rsi, rax
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp], rdx
+	call	optional_trait_methods::num::parse_num::<u16>
+	mov	dword ptr [rsp + 20], edx
+	test	al, 1
+	lea	r13, [rsp + 624]
+	je	.LBB17_115
+	cmp	word ptr [rsp + 24], 0
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	jne	.LBB17_109
+	mov	ax, 2
+	mov	qword ptr [rsp + 24], rax
+	jmp	.LBB17_51
+.LBB17_40:
+	mov	al, 1
+	mov	qword ptr [rsp + 80], rax
+.LBB17_41:
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r13, [rsp + 624]
+	jmp	.LBB17_51
+.LBB17_42:
+	mov	esi, 8
+	cmp	byte ptr [rdx], 99
+	jne	.LBB17_122
+	cmp	byte ptr [rdx + 1], 111
+	jne	.LBB17_122
+	cmp	byte ptr [rdx + 2], 110
+	jne	.LBB17_122
+	cmp	byte ptr [rdx + 3], 116
+	jne	.LBB17_122
+	cmp	byte ptr [rdx + 4], 105
+	jne	.LBB17_122
+	cmp	byte ptr [rdx + 5], 110
+	jne	.LBB17_122
+	cmp	byte ptr [rdx + 6], 117
+	jne	.LBB17_122
+	cmp	byte ptr [rdx + 7], 101
+	jne	.LBB17_122
+.LBB17_50:
+	cmp	rax, 8
+	setne	al
+	mov	qword ptr [rsp + 56], rax
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+.LBB17_51:
+	cmp	rbp, rbx
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB17_2
+	test	byte ptr [rsp + 88], 1
+	je	.LBB17_110
+.LBB17_53:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.73]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 31
+.LBB17_54:
+	mov	edi, 2
+	call	r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	ebx, 1
+	mov	edx, 1
+	mov	edi, 2
+	call	r14
+	jmp	.LBB17_99
+.LBB17_55:
+	xor	r15d, r15d
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB17_56:
+	xorps	xmm0, xmm0
+	lea	r14, [rsp + 3216]
+	movaps	xmmword ptr [r14 + 672], xmm0
+	movaps	xmmword ptr [r14 + 656], xmm0
+	movaps	xmmword ptr [r14 + 640], xmm0
+	movaps	xmmword ptr [r14 + 624], xmm0
+	movaps	xmmword ptr [r14 + 608], xmm0
+	movaps	xmmword ptr [r14 + 592], xmm0
+	movaps	xmmword ptr [r14 + 576], xmm0
+	lea	rdi, [rsp + 640]
+	xor	ebx, ebx
+	mov	r13, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 528
+	xor	esi, esi
+	call	r13
+	mov	qword ptr [r14 + 568], r12
+	mov	qword ptr [r14 + 560], r15
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [r14 + 696], xmm0
+	mov	qword ptr [r14 + 688], 1
+	movabs	rax, -9223372036854775808
+	mov	qword ptr [r14 + 712], rax
+	movabs	rax, 9223372036854775807
+	mov	qword ptr [r14 + 728], rax
+	mov	qword ptr [r14 + 720], -1
+	movaps	xmmword ptr [r14 - 16], xmm0
+	lea	rsi, [rsp + 608]
+	mov	edx, 560
+	mov	rdi, r14
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 1648]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 608]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 2688]
+	mov	edx, 512
+	xor	esi, esi
+	call	r13
+	mov	dword ptr [rsp + 40], ebp
+	shr	ebp, 8
+	mov	dword ptr [rsp + 8], ebp
+	mov	r12d, 1
+	mov	rbp, qword ptr [rip + read@GOTPCREL]
+	xor	ecx, ecx
+	mov	dword ptr [rsp], eax
+.LBB17_57:
+	mov	rdi, qword ptr [rsp + 1632]
+	mov	rax, qword ptr [rsp + 1640]
+	xor	r15d, r15d
+	mov	rdx, rcx
+	xor	r13d, r13d
+.LBB17_58:
+	mov	rsi, rax
+	mov	rax, rdi
+.LBB17_59:
+	cmp	rsi, rax
+	jb	.LBB17_63
+	mov	qword ptr [rsp + 2672], rbx
+	cmp	rdx, 1025
+	mov	rsi, r14
+	cmovae	rsi, r12
+	cmovae	rdx, rbx
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 1024
+	xor	edi, edi
+	lea	rsi, [rsp + 608]
+	call	rbp
+	test	rax, rax
+	js	.LBB17_73
+	je	.LBB17_74
+	mov	qword ptr [rsp + 1632], rax
+	mov	qword ptr [rsp + 1640], 0
+	xor	ecx, ecx
+	xor	edx, edx
+	xor	esi, esi
+.LBB17_63:
+	cmp	rax, rsi
+	jb	.LBB17_96
+	cmp	rax, 1024
+	ja	.LBB17_96
+	cmp	rsi, rax
+	je	.LBB17_59
+	inc	rsi
+	mov	rdi, rax
+	neg	rdi
+.LBB17_67:
+	mov	r8b, byte ptr [rsp + rsi + 607]
+	mov	qword ptr [rsp + 1640], rsi
+	cmp	r8b, 10
+	je	.LBB17_77
+	cmp	r13, 511
+	jbe	.LBB17_70
+	mov	r15b, 1
+	jmp	.LBB17_71
+.LBB17_70:
+	mov	byte ptr [rsp + r13 + 2688], r8b
+	inc	r13
+.LBB17_71:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB17_67
+	mov	rdi, rax
+	jmp	.LBB17_58
+.LBB17_73:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB17_74:
+	test	r13, r13
+	setne	al
+	or	al, r15b
+	test	al, 1
+	je	.LBB17_100
+	test	r15b, 1
+	jne	.LBB17_81
+	mov	ecx, 0
+	mov	edx, 0
+	cmp	r13, 512
+	ja	.LBB17_97
+	jmp	.LBB17_79
+.LBB17_77:
+	test	r15b, 1
+	jne	.LBB17_81
+	cmp	r13, 512
+	ja	.LBB17_96
+.LBB17_79:
+	test	r13, r13
+	setne	al
+	test	byte ptr [rsp + 8], al
+	jne	.LBB17_82
+	test	r13, r13
+	je	.LBB17_57
+	jmp	.LBB17_83
+.LBB17_81:
+	mov	qword ptr [rsp + 136], 3
+	mov	qword ptr [rsp + 144], 512
+	mov	byte ptr [rsp + 152], 4
+	mov	byte ptr [rsp + 128], -1
+	lea	rdi, [rsp + 288]
+	mov	rsi, r14
+	lea	rdx, [rsp + 128]
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	jmp	.LBB17_85
+.LBB17_82:
+	mov	rdi, r14
+	lea	rsi, [rsp + 2688]
+	mov	rdx, r13
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB17_83:
+	lea	r15, [rsp + 448]
+	mov	rdi, r15
+	lea	rsi, [rsp + 2688]
+	mov	rdx, r13
+	call	<optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::parse_command
+	cmp	byte ptr [rsp + 448], -1
+	je	.LBB17_86
+	mov	edx, 160
+	mov	rsi, r15
+	lea	r15, [rsp + 128]
+	mov	rdi, r15
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	rdi, [rsp + 288]
+	lea	rsi, [rsp + 3200]
+	mov	rdx, r14
+	mov	rcx, r15
+	call	<optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB17_85:
+	mov	r15d, dword ptr [rsp]
+	mov	r13, qword ptr [rsp + 32]
+	jmp	.LBB17_87
+.LBB17_86:
+	mov	eax, dword ptr [rsp + 464]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	r13, qword ptr [rsp + 32]
+	cmovne	r13, qword ptr [rsp + 456]
+	sete	cl
+	mov	r15d, dword ptr [rsp]
+	movzx	r15d, r15b
+	cmovne	r15d, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 136], rcx
+	mov	qword ptr [rsp + 144], r13
+	mov	byte ptr [rsp + 152], r15b
+	mov	byte ptr [rsp + 128], -1
+	lea	rdi, [rsp + 288]
+	mov	rsi, r14
+	lea	rdx, [rsp + 128]
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB17_87:
+	cmp	byte ptr [rsp + 288], -1
+	jne	.LBB17_91
+	mov	rax, qword ptr [rsp + 296]
+	cmp	byte ptr [rsp + 40], 0
+	jne	.LBB17_103
+	cmp	eax, 1
+	je	.LBB17_106
+	cmp	eax, 4
+	je	.LBB17_105
+.LBB17_91:
+	mov	qword ptr [rsp + 32], r13
+	mov	dword ptr [rsp], r15d
+	mov	rcx, qword ptr [rsp + 2672]
+	jmp	.LBB17_57
+.LBB17_92:
+	xor	ecx, ecx
+	mov	r15, rbp
+	mov	r14, rbx
+.LBB17_93:
+.LBB17_94:
+	lea	rdi, [rsp + 608]
+	mov	qword ptr [rdi], rcx
+	mov	qword ptr [rdi + 8], r15
+	mov	qword ptr [rdi + 16], r14
+	mov	qword ptr [rdi + 24], rdx
+	mov	qword ptr [rdi + 32], rax
+.LBB17_95:
+	call	optional_trait_methods::usage_error
+	mov	ebx, 1
+	jmp	.LBB17_99
+.LBB17_96:
+	mov	rdx, rcx
+.LBB17_97:
+	xor	eax, eax
+	mov	qword ptr [rsp + 2672], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, 1
+	mov	rsi, r14
+.LBB17_98:
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+.LBB17_99:
+	mov	rax, rbx
+	add	rsp, 3960
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB17_100:
+	xor	edx, edx
+	jmp	.LBB17_97
+.LBB17_101:
+	mov	ecx, 3
+	jmp	.LBB17_93
+.LBB17_102:
+	mov	ecx, 1
+	jmp	.LBB17_93
+.LBB17_103:
+	cmp	eax, 1
+	je	.LBB17_106
+	cmp	eax, 4
+	jne	.LBB17_108
+.LBB17_105:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.20]
+	lea	rdi, [rsp + 1648]
+	mov	edx, 23
+	jmp	.LBB17_107
+.LBB17_106:
+	mov	rsi, qword ptr [rsp + 304]
+	mov	rdx, qword ptr [rsp + 312]
+	lea	rdi, [rsp + 1648]
+.LBB17_107:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 1648]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB17_108:
+	mov	ebx, 1
+	mov	rdx, qword ptr [rsp + 2672]
+	jmp	.LBB17_97
+.LBB17_109:
+	mov	ecx, 4
+	jmp	.LBB17_93
+.LBB17_110:
+	test	byte ptr [rsp + 80], 1
+	je	.LBB17_112
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	xor	ebx, ebx
+	mov	edx, 823
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 1
+	jmp	.LBB17_98
+.LBB17_112:
+	mov	r15, qword ptr [rsp + 72]
+	test	r15, r15
+	je	.LBB17_117
+	lea	rbx, [rsp + 1648]
+	mov	rdi, rbx
+	mov	rsi, r15
+	mov	r14, qword ptr [rsp + 120]
+	mov	rdx, r14
+	call	optional_trait_methods::num::parse_num::<i128>
+	test	byte ptr [rbx], 1
+	je	.LBB17_123
+	mov	r15, qword ptr [rsp + 1664]
+	mov	r12, qword ptr [rsp + 1672]
+	jmp	.LBB17_118
+.LBB17_115:
+	mov	ecx, 2
+	mov	rdx, qword ptr [rsp]
+	mov	rax, qword ptr [rsp + 48]
+	jmp	.LBB17_94
+.LBB17_116:
+	mov	ecx, 2
+	xor	eax, eax
+	jmp	.LBB17_94
+.LBB17_117:
+	xor	r15d, r15d
+	xor	r12d, r12d
+.LBB17_118:
+	mov	rax, qword ptr [rsp + 24]
+	test	ax, ax
+	je	.LBB17_124
+	movzx	eax, ax
+	cmp	eax, 1
+	jne	.LBB17_125
+	mov	rdi, qword ptr [rsp + 104]
+	mov	rsi, qword ptr [rsp + 112]
+	call	<optional_trait_methods::connection::Listener>::unix
+	mov	ebx, edx
+	jmp	.LBB17_126
+.LBB17_121:
+	mov	rsi, rax
+.LBB17_122:
+	mov	ecx, 2
+	mov	rax, rsi
+	jmp	.LBB17_94
+.LBB17_123:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.24]
+	lea	rdi, [rsp + 608]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 15
+	mov	qword ptr [rdi + 24], r15
+	mov	qword ptr [rdi + 32], r14
+	mov	qword ptr [rdi], 2
+	jmp	.LBB17_95
+.LBB17_124:
+	movzx	eax, byte ptr [rsp + 64]
+	shl	eax, 8
+	movzx	ebp, byte ptr [rsp + 56]
+	or	ebp, eax
+	jmp	.LBB17_56
+.LBB17_125:
+	mov	ebp, dword ptr [rsp + 20]
+	mov	edi, ebp
+	call	<optional_trait_methods::connection::Listener>::tcp_loopback
+	mov	ebx, edx
+	test	bp, bp
+	setne	cl
+	or	cl, al
+	test	cl, 1
+	je	.LBB17_128
+.LBB17_126:
+	cmp	eax, 1
+	jne	.LBB17_129
+	mov	r14d, 1
+	mov	ebp, ebx
+	jmp	.LBB17_130
+.LBB17_128:
+	mov	edi, ebx
+	call	optional_trait_methods::report_port
+.LBB17_129:
+	mov	edi, ebx
+	call	<optional_trait_methods::connection::Listener>::accept
+	mov	r14d, eax
+	mov	ebp, edx
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+.LBB17_130:
+	test	r14b, 1
+	je	.LBB17_132
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.72]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 29
+	jmp	.LBB17_54
+.LBB17_132:
+	xorps	xmm0, xmm0
+	lea	r14, [rsp + 3216]
+	movaps	xmmword ptr [r14 + 672], xmm0
+	movaps	xmmword ptr [r14 + 656], xmm0
+	movaps	xmmword ptr [r14 + 640], xmm0
+	movaps	xmmword ptr [r14 + 624], xmm0
+	movaps	xmmword ptr [r14 + 608], xmm0
+	movaps	xmmword ptr [r14 + 592], xmm0
+	movaps	xmmword ptr [r14 + 576], xmm0
+	lea	rdi, [rsp + 640]
+	xor	ebx, ebx
+	mov	r13, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 528
+	xor	esi, esi
+	call	r13
+	mov	qword ptr [r14 + 568], r12
+	mov	qword ptr [r14 + 560], r15
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [r14 + 696], xmm0
+	mov	qword ptr [r14 + 688], 1
+	movabs	rax, -9223372036854775808
+	mov	qword ptr [r14 + 712], rax
+	movabs	rax, 9223372036854775807
+	mov	qword ptr [r14 + 728], rax
+	mov	qword ptr [r14 + 720], -1
+	movaps	xmmword ptr [r14 - 16], xmm0
+	lea	rsi, [rsp + 608]
+	mov	edx, 560
+	mov	rdi, r14
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 1648]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r13
+	mov	dword ptr [r14 + 1032], ebp
+	lea	rdi, [rsp + 608]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 2688]
+	mov	edx, 512
+	xor	esi, esi
+	call	r13
+	lea	r13, [rsp + 128]
+	xor	edx, edx
+	mov	dword ptr [rsp + 8], eax
+.LBB17_133:
+	mov	rdi, qword ptr [rsp + 1632]
+	mov	rcx, qword ptr [rsp + 1640]
+	xor	r15d, r15d
+	mov	rax, rdx
+	xor	r12d, r12d
+.LBB17_134:
+	mov	rsi, rcx
+	mov	rcx, rdi
+.LBB17_135:
+	cmp	rsi, rcx
+	jb	.LBB17_139
+	mov	qword ptr [rsp + 2672], rbx
+	cmp	rax, 1025
+	mov	rsi, r14
+	mov	ecx, 1
+	cmovae	rsi, rcx
+	cmovae	rax, rbx
+	mov	edi, ebp
+	mov	rdx, rax
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	rdi, r13
+	mov	esi, ebp
+	lea	rdx, [rsp + 608]
+	call	optional_trait_methods::connection::read_fd
+	cmp	byte ptr [rsp + 128], 0
+	jne	.LBB17_149
+	mov	rcx, qword ptr [rsp + 136]
+	test	rcx, rcx
+	je	.LBB17_149
+	mov	qword ptr [rsp + 1632], rcx
+	mov	qword ptr [rsp + 1640], 0
+	xor	edx, edx
+	xor	eax, eax
+	xor	esi, esi
+.LBB17_139:
+	cmp	rcx, rsi
+	jb	.LBB17_168
+	cmp	rcx, 1024
+	ja	.LBB17_168
+	cmp	rsi, rcx
+	je	.LBB17_135
+	inc	rsi
+	mov	rdi, rcx
+	neg	rdi
+.LBB17_143:
+	mov	r8b, byte ptr [rsp + rsi + 607]
+	mov	qword ptr [rsp + 1640], rsi
+	cmp	r8b, 10
+	je	.LBB17_152
+	cmp	r12, 511
+	jbe	.LBB17_146
+	mov	r15b, 1
+	jmp	.LBB17_147
+.LBB17_146:
+	mov	byte ptr [rsp + r12 + 2688], r8b
+	inc	r12
+.LBB17_147:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB17_143
+	mov	rdi, rcx
+	jmp	.LBB17_134
+.LBB17_149:
+	test	r12, r12
+	setne	al
+	or	al, r15b
+	test	al, 1
+	je	.LBB17_167
+	test	r15b, 1
+	jne	.LBB17_156
+	mov	edx, 0
+	cmp	r12, 512
+	jbe	.LBB17_154
+	jmp	.LBB17_167
+.LBB17_152:
+	test	r15b, 1
+	jne	.LBB17_156
+	cmp	r12, 512
+	ja	.LBB17_168
+.LBB17_154:
+	test	r12, r12
+	setne	al
+	test	byte ptr [rsp + 64], al
+	jne	.LBB17_157
+	test	r12, r12
+	je	.LBB17_133
+	jmp	.LBB17_158
+.LBB17_156:
+	mov	qword ptr [rsp + 136], 3
+	mov	qword ptr [rsp + 144], 512
+	mov	byte ptr [rsp + 152], 4
+	jmp	.LBB17_161
+.LBB17_157:
+	mov	rdi, r14
+	lea	rsi, [rsp + 2688]
+	mov	rdx, r12
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB17_158:
+	lea	rdi, [rsp + 448]
+	lea	rsi, [rsp + 2688]
+	mov	rdx, r12
+	call	<optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::parse_command
+	cmp	byte ptr [rsp + 448], -1
+	je	.LBB17_160
+	mov	edx, 160
+	mov	rdi, r13
+	lea	rsi, [rsp + 448]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	rdi, [rsp + 288]
+	lea	rsi, [rsp + 3200]
+	mov	rdx, r14
+	mov	rcx, r13
+	call	<optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::handle::<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	jmp	.LBB17_162
+.LBB17_160:
+	mov	eax, dword ptr [rsp + 464]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rdx, qword ptr [rsp]
+	cmovne	rdx, qword ptr [rsp + 456]
+	sete	cl
+	mov	esi, dword ptr [rsp + 8]
+	movzx	esi, sil
+	cmovne	esi, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 136], rcx
+	mov	qword ptr [rsp], rdx
+	mov	qword ptr [rsp + 144], rdx
+	mov	dword ptr [rsp + 8], esi
+	mov	byte ptr [rsp + 152], sil
+.LBB17_161:
+	mov	byte ptr [rsp + 128], -1
+	lea	rdi, [rsp + 288]
+	mov	rsi, r14
+	mov	rdx, r13
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+.LBB17_162:
+	cmp	byte ptr [rsp + 288], -1
+	jne	.LBB17_166
+	mov	rax, qword ptr [rsp + 296]
+	cmp	byte ptr [rsp + 56], 0
+	jne	.LBB17_169
+	cmp	eax, 1
+	je	.LBB17_172
+	cmp	eax, 4
+	je	.LBB17_171
+.LBB17_166:
+	mov	ebp, dword ptr [rsp + 2680]
+	mov	rdx, qword ptr [rsp + 2672]
+	jmp	.LBB17_133
+.LBB17_167:
+	xor	edx, edx
+.LBB17_168:
+	xor	eax, eax
+	mov	qword ptr [rsp + 2672], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, ebp
+	mov	rsi, r14
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	jmp	.LBB17_99
+.LBB17_169:
+	cmp	eax, 1
+	je	.LBB17_172
+	cmp	eax, 4
+	jne	.LBB17_174
+.LBB17_171:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.20]
+	lea	rdi, [rsp + 1648]
+	mov	edx, 23
+	jmp	.LBB17_173
+.LBB17_172:
+	mov	rsi, qword ptr [rsp + 304]
+	mov	rdx, qword ptr [rsp + 312]
+	lea	rdi, [rsp + 1648]
+.LBB17_173:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 1648]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB17_174:
+	mov	rdx, qword ptr [rsp + 2672]
+	mov	ebx, 1
+	mov	ebp, dword ptr [rsp + 2680]
+	jmp	.LBB17_168
+.LBB17_175:
+	ud2
+.Lfunc_end17:
+.LJTI17_0:
+	.long	.LBB17_27-.LJTI17_0
+	.long	.LBB17_37-.LJTI17_0
+	.long	.LBB17_30-.LJTI17_0
+	.long	.LBB17_31-.LJTI17_0
+	.long	.LBB17_29-.LJTI17_0
+	.long	.LBB17_175-.LJTI17_0
+	.long	.LBB17_40-.LJTI17_0
+
+rust_eh_personality:
+.Lfunc_begin18:
+	ret
+.Lfunc_end18:
+
+core::panicking::panic_fmt:
+.Lfunc_begin19:
+	.loc	1 60 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp0:
+	.loc	1 80 14 prologue_end
+	call	__rustc::rust_begin_unwind
+.Ltmp1:
+.Lfunc_end19:
+
+core::panicking::panic_bounds_check:
+.Lfunc_begin20:
+	.loc	1 266 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp2:
+	.loc	1 271 5 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp3:
+.Lfunc_end20:
+
+core::slice::index::slice_index_fail:
+.Lfunc_begin21:
+	.loc	2 38 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp4:
+	.loc	3 177 9 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp5:
+.Lfunc_end21:
+
+optional_trait_methods::num::parse_num::<usize>:
+.Lfunc_begin22:
+	test	rsi, rsi
+	je	.LBB22_55
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB22_4
+	cmp	ecx, 45
+	jne	.LBB22_6
+	mov	cl, 1
+	jmp	.LBB22_5
+.LBB22_4:
+	xor	ecx, ecx
+.LBB22_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB22_7
+.LBB22_6:
+	xor	ecx, ecx
+.LBB22_7:
+	cmp	rsi, 1
+	jbe	.LBB22_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB22_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB22_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB22_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB22_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB22_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB22_32
+.LBB22_14:
+	test	rsi, rsi
+	je	.LBB22_55
+	mov	al, byte ptr [rdi]
+.LBB22_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB22_55
+.LBB22_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB22_19
+	cmp	byte ptr [rax], 95
+	je	.LBB22_55
+.LBB22_19:
+	mov	r8d, 10
+	xor	r9d, r9d
+	xor	edx, edx
+.LBB22_20:
+	cmp	rdi, rsi
+	je	.LBB22_50
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB22_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB22_20
+	jmp	.LBB22_51
+.LBB22_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB22_28
+	mov	rax, rdx
+	mul	r8
+	jo	.LBB22_28
+	mov	rdx, rax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB22_27
+	sub	rdx, rax
+	jae	.LBB22_20
+	jmp	.LBB22_28
+.LBB22_27:
+	add	rdx, rax
+	jae	.LBB22_20
+.LBB22_28:
+	xor	eax, eax
+	ret
+.LBB22_29:
+	mov	r8b, 8
+	jmp	.LBB22_31
+.LBB22_30:
+	mov	r8b, 2
+.LBB22_31:
+	xor	r9d, r9d
+.LBB22_32:
+	cmp	rsi, 2
+	je	.LBB22_55
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB22_52
+.LBB22_34:
+	cmp	dl, r8b
+	jae	.LBB22_55
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB22_37
+	cmp	byte ptr [rax], 95
+	je	.LBB22_55
+.LBB22_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB22_38:
+	cmp	r10, rdi
+	je	.LBB22_50
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB22_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB22_51
+	jmp	.LBB22_38
+.LBB22_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB22_45
+	test	r9b, r9b
+	je	.LBB22_28
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB22_28
+	add	al, -87
+	mov	r11d, eax
+.LBB22_45:
+	cmp	r11b, r8b
+	jae	.LBB22_28
+	mov	rax, rdx
+	mul	rsi
+	jo	.LBB22_28
+	mov	rdx, rax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB22_49
+	sub	rdx, rax
+	jae	.LBB22_38
+	jmp	.LBB22_28
+.LBB22_49:
+	add	rdx, rax
+	jae	.LBB22_38
+	jmp	.LBB22_28
+.LBB22_50:
+	mov	eax, 1
+	ret
+.LBB22_52:
+	test	r9b, r9b
+	je	.LBB22_55
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB22_55
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB22_34
+.LBB22_55:
+	xor	eax, eax
+.LBB22_51:
+	ret
+.Lfunc_end22:
+
+<optional_trait_methods::commands::Args>::reg:
+.Lfunc_begin23:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	cmp	rdx, 8
+	setb	cl
+	and	cl, al
+	cmovne	r15, rdx
+	neg	cl
+	or	cl, 2
+	mov	qword ptr [rbx], r15
+	mov	byte ptr [rbx + 8], cl
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end23:
+
+<optional_trait_methods::commands::Args>::next:
+.Lfunc_begin24:
+	mov	r10, qword ptr [rsi + 8]
+	mov	rax, qword ptr [rsi + 16]
+	xor	edx, edx
+	test	r10, r10
+	setne	dl
+	cmp	r10, 1
+	mov	rcx, r10
+	adc	rcx, -1
+	mov	r8, qword ptr [rsi]
+	add	r8, rdx
+	mov	r9, rcx
+	cmp	r10, 2
+	jb	.LBB24_5
+	xor	r9d, r9d
+.LBB24_2:
+	cmp	byte ptr [r8 + r9], 32
+	je	.LBB24_5
+	inc	r9
+	cmp	rcx, r9
+	jne	.LBB24_2
+	mov	r9, rcx
+.LBB24_5:
+	add	rax, rdx
+	sub	rcx, r9
+	lea	rdx, [r8 + r9]
+	mov	qword ptr [rsi], rdx
+	mov	qword ptr [rsi + 8], rcx
+	lea	rcx, [r9 + rax]
+	mov	qword ptr [rsi + 16], rcx
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], r8
+	mov	qword ptr [rdi + 16], r9
+	ret
+.Lfunc_end24:
+
+<optional_trait_methods::commands::Args>::names:
+.Lfunc_begin25:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 200
+	mov	qword ptr [rsp + 32], rdi
+	lea	r14, [rsp + 40]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r12, qword ptr [r14 + 8]
+	mov	rbx, qword ptr [r14 + 16]
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, qword ptr [rip + bcmp@GOTPCREL]
+.LBB25_1:
+	test	rbx, rbx
+	je	.LBB25_2
+	xor	r13d, r13d
+.LBB25_4:
+	cmp	byte ptr [r12 + r13], 59
+	je	.LBB25_7
+	inc	r13
+	cmp	rbx, r13
+	jne	.LBB25_4
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 24], r12
+	mov	r13, rbx
+	jmp	.LBB25_8
+.LBB25_2:
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	xor	ebx, ebx
+	mov	qword ptr [rsp + 24], r12
+	xor	r13d, r13d
+	jmp	.LBB25_8
+.LBB25_7:
+	lea	rax, [r13 + 1]
+	sub	rbx, rax
+	lea	rax, [r12 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 24], rax
+	mov	dword ptr [rsp + 12], 0
+.LBB25_8:
+	mov	edx, 144
+	lea	rbp, [rsp + 56]
+	mov	rdi, rbp
+	lea	rsi, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.6]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r14d, 6
+.LBB25_9:
+	cmp	qword ptr [rbp + 16], r13
+	jne	.LBB25_11
+	mov	rdi, qword ptr [rbp + 8]
+	mov	rsi, r12
+	mov	rdx, r13
+	call	r15
+	test	eax, eax
+	je	.LBB25_12
+.LBB25_11:
+	add	rbp, 24
+	dec	r14
+	jne	.LBB25_9
+	jmp	.LBB25_13
+.LBB25_12:
+	mov	rax, qword ptr [rsp + 16]
+	or	al, byte ptr [rbp]
+	mov	qword ptr [rsp + 16], rax
+.LBB25_13:
+	mov	r12, qword ptr [rsp + 24]
+	cmp	byte ptr [rsp + 12], 0
+	je	.LBB25_1
+	mov	rax, qword ptr [rsp + 32]
+	mov	rcx, qword ptr [rsp + 16]
+	mov	byte ptr [rax], cl
+	mov	byte ptr [rax + 8], -1
+	add	rsp, 200
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end25:
+
+<optional_trait_methods::connection::Listener>::tcp_loopback:
+.Lfunc_begin26:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	qword ptr [rsp + 20], 0
+	mov	word ptr [rsp + 12], 2
+	rol	di, 8
+	mov	word ptr [rsp + 14], di
+	mov	dword ptr [rsp + 16], 16777343
+	mov	edi, 2
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebx, edx
+	mov	ebp, 1
+	test	al, 1
+	jne	.LBB26_5
+	lea	rcx, [rsp + 28]
+	mov	dword ptr [rcx], 1
+	mov	edi, ebx
+	mov	esi, 1
+	mov	edx, 2
+	mov	r8d, 4
+	call	qword ptr [rip + setsockopt@GOTPCREL]
+	lea	rsi, [rsp + 12]
+	mov	edi, ebx
+	mov	edx, 16
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB26_3
+	mov	edi, ebx
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB26_3
+	xor	ebp, ebp
+	jmp	.LBB26_5
+.LBB26_3:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebx, r14d
+.LBB26_5:
+	mov	eax, ebp
+	mov	edx, ebx
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end26:
+
+<optional_trait_methods::connection::Listener>::socket:
+.Lfunc_begin27:
+	push	rbx
+	xor	ebx, ebx
+	mov	esi, 1
+	xor	edx, edx
+	call	qword ptr [rip + socket@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB27_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB27_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end27:
+
+<optional_trait_methods::connection::Listener>::unix:
+.Lfunc_begin28:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 112
+	mov	rdx, rsi
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [rsp + 96], xmm0
+	movups	xmmword ptr [rsp + 84], xmm0
+	movups	xmmword ptr [rsp + 68], xmm0
+	movups	xmmword ptr [rsp + 52], xmm0
+	movups	xmmword ptr [rsp + 36], xmm0
+	movups	xmmword ptr [rsp + 20], xmm0
+	movups	xmmword ptr [rsp + 4], xmm0
+	mov	word ptr [rsp + 2], 1
+	lea	rax, [rsi - 108]
+	cmp	rax, -107
+	jae	.LBB28_2
+	mov	ebx, 1
+	mov	ebp, 36
+	jmp	.LBB28_7
+.LBB28_2:
+	mov	rsi, rdi
+	lea	rbx, [rsp + 4]
+	mov	rdi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	rdi, rbx
+	call	qword ptr [rip + unlink@GOTPCREL]
+	mov	ebx, 1
+	mov	edi, 1
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebp, edx
+	test	al, 1
+	jne	.LBB28_7
+	lea	rsi, [rsp + 2]
+	mov	edi, ebp
+	mov	edx, 110
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB28_6
+	mov	edi, ebp
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB28_6
+	xor	ebx, ebx
+	jmp	.LBB28_7
+.LBB28_6:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebp, r14d
+.LBB28_7:
+	mov	eax, ebx
+	mov	edx, ebp
+	add	rsp, 112
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end28:
+
+<optional_trait_methods::connection::Listener>::accept:
+.Lfunc_begin29:
+	push	rbx
+	xor	ebx, ebx
+	xor	esi, esi
+	xor	edx, edx
+	call	qword ptr [rip + accept@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB29_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB29_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end29:
+
+optional_trait_methods::commands::unrecognized:
+.Lfunc_begin30:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	xor	r14d, r14d
+.LBB30_1:
+	cmp	byte ptr [rbx + r14], 32
+	je	.LBB30_4
+	inc	r14
+	cmp	rsi, r14
+	jne	.LBB30_1
+	mov	r14, rsi
+.LBB30_4:
+	mov	r15d, 8
+	lea	r12, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.33]
+	mov	r13, qword ptr [rip + bcmp@GOTPCREL]
+.LBB30_5:
+	cmp	qword ptr [r15 + r12], r14
+	jne	.LBB30_8
+	mov	rdi, qword ptr [r15 + r12 - 8]
+	mov	rsi, rbx
+	mov	rdx, r14
+	call	r13
+	test	eax, eax
+	je	.LBB30_7
+.LBB30_8:
+	add	r15, 16
+	cmp	r15, 152
+	jne	.LBB30_5
+	xor	eax, eax
+	jmp	.LBB30_10
+.LBB30_7:
+	mov	al, 1
+.LBB30_10:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end30:
+
+optional_trait_methods::using_fn::targets::advanced::checkpoint:
+.Lfunc_begin31:
+	push	r14
+	push	rbx
+	push	rax
+	mov	rdx, qword ptr [rdi + 584]
+	mov	rcx, qword ptr [rdi + 576]
+	mov	r8, qword ptr [rdi + 712]
+	mov	rsi, qword ptr [rdi + 704]
+	lea	r9, [rdi + 48]
+	cmp	qword ptr [rdi + 568], 16
+	jne	.LBB31_3
+	mov	rax, qword ptr [rdi + 560]
+	cmp	rax, 16
+	jae	.LBB31_5
+	mov	r10, rax
+	shl	r10, 5
+	mov	r11, qword ptr [r9 + r10]
+	mov	rbx, qword ptr [r9 + r10 + 8]
+	mov	r14, qword ptr [r9 + r10 + 24]
+	mov	r10, qword ptr [r9 + r10 + 16]
+	mov	qword ptr [rdi], 1
+	mov	qword ptr [rdi + 8], 0
+	mov	qword ptr [rdi + 16], r11
+	mov	qword ptr [rdi + 24], rbx
+	mov	qword ptr [rdi + 32], r10
+	mov	qword ptr [rdi + 40], r14
+	jmp	.LBB31_4
+.LBB31_3:
+	mov	rax, qword ptr [rdi + 560]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rdi], xmm0
+	cmp	rax, 16
+	jae	.LBB31_5
+.LBB31_4:
+	shl	rax, 5
+	mov	qword ptr [r9 + rax + 8], rdx
+	mov	qword ptr [r9 + rax], rcx
+	mov	qword ptr [r9 + rax + 24], r8
+	mov	qword ptr [r9 + rax + 16], rsi
+	mov	eax, dword ptr [rdi + 560]
+	inc	eax
+	and	eax, 15
+	mov	qword ptr [rdi + 560], rax
+	mov	rax, qword ptr [rdi + 568]
+	inc	rax
+	cmp	rax, 16
+	mov	ecx, 16
+	cmovb	rcx, rax
+	mov	qword ptr [rdi + 568], rcx
+	add	rsp, 8
+	pop	rbx
+	pop	r14
+	ret
+.LBB31_5:
+	mov	esi, 16
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end31:
+
+optional_trait_methods::using_fn::targets::advanced::scale_bounds:
+.Lfunc_begin32:
+	cmp	rcx, rsi
+	mov	rax, r8
+	sbb	rax, rdx
+	jge	.LBB32_2
+	lea	rax, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.42]
+	jmp	.LBB32_3
+.LBB32_2:
+	mov	qword ptr [rdi + 720], rsi
+	mov	qword ptr [rdi + 728], rdx
+	mov	qword ptr [rdi + 736], rcx
+	mov	qword ptr [rdi + 744], r8
+	mov	rax, qword ptr [rdi + 704]
+	mov	r9, qword ptr [rdi + 712]
+	cmp	rax, rsi
+	mov	r10, r9
+	sbb	r10, rdx
+	cmovl	r9, rdx
+	cmovl	rax, rsi
+	cmp	rcx, rax
+	mov	rdx, r8
+	sbb	rdx, r9
+	cmovl	r9, r8
+	cmovl	rax, rcx
+	mov	qword ptr [rdi + 704], rax
+	mov	qword ptr [rdi + 712], r9
+	xor	eax, eax
+.LBB32_3:
+	mov	edx, 26
+	ret
+.Lfunc_end32:
+
+optional_trait_methods::using_fn::targets::advanced::scale_factor:
+.Lfunc_begin33:
+	mov	rax, qword ptr [rdi + 720]
+	mov	rcx, qword ptr [rdi + 728]
+	mov	r8, qword ptr [rdi + 744]
+	mov	r9, qword ptr [rdi + 736]
+	cmp	rsi, rax
+	mov	r10, rdx
+	sbb	r10, rcx
+	cmovl	rdx, rcx
+	cmovl	rsi, rax
+	cmp	r9, rsi
+	mov	rax, r8
+	sbb	rax, rdx
+	cmovl	rsi, r9
+	cmovl	rdx, r8
+	mov	qword ptr [rdi + 712], rdx
+	mov	qword ptr [rdi + 704], rsi
+	ret
+.Lfunc_end33:
+
+optional_trait_methods::using_fn::targets::advanced::dec:
+.Lfunc_begin34:
+	add	qword ptr [rdi + 576], -1
+	adc	qword ptr [rdi + 584], -1
+	ret
+.Lfunc_end34:
+
+.LCPI35_0:
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	128
+optional_trait_methods::using_fn::targets::advanced::div:
+.Lfunc_begin35:
+	mov	rcx, rdx
+	mov	rax, rsi
+	or	rax, rdx
+	je	.LBB35_1
+	push	rbx
+	mov	rdx, rsi
+	mov	rbx, rdi
+	mov	rdi, qword ptr [rdi + 576]
+	mov	rsi, qword ptr [rbx + 584]
+	movq	xmm0, rdi
+	movq	xmm1, rsi
+	punpcklqdq	xmm0, xmm1
+	movq	xmm1, rdx
+	movq	xmm2, rcx
+	punpcklqdq	xmm1, xmm2
+	pcmpeqd	xmm2, xmm2
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI35_0]
+	pcmpeqb	xmm2, xmm1
+	pand	xmm0, xmm2
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	je	.LBB35_4
+	call	qword ptr [rip + __divti3@GOTPCREL]
+	mov	qword ptr [rbx + 584], rdx
+	mov	qword ptr [rbx + 576], rax
+	xor	eax, eax
+.LBB35_6:
+	pop	rbx
+	ret
+.LBB35_1:
+	lea	rax, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.43]
+	mov	edx, 17
+	ret
+.LBB35_4:
+	lea	rax, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.44]
+	mov	edx, 29
+	jmp	.LBB35_6
+.Lfunc_end35:
+
+optional_trait_methods::using_fn::targets::advanced::inc:
+.Lfunc_begin36:
+	add	qword ptr [rdi + 576], 1
+	adc	qword ptr [rdi + 584], 0
+	ret
+.Lfunc_end36:
+
+optional_trait_methods::using_fn::targets::advanced::mul:
+.Lfunc_begin37:
+	mov	rax, rsi
+	xor	rax, 7
+	or	rax, rdx
+	jne	.LBB37_2
+	lea	rax, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.45]
+	jmp	.LBB37_3
+.LBB37_2:
+	mov	rcx, rdx
+	mov	rax, qword ptr [rdi + 704]
+	imul	rcx, rax
+	mul	rsi
+	mov	r8, qword ptr [rdi + 576]
+	add	rdx, rcx
+	mov	rcx, qword ptr [rdi + 712]
+	imul	rcx, rsi
+	add	rcx, rdx
+	mov	rsi, qword ptr [rdi + 584]
+	imul	rsi, rax
+	mul	r8
+	imul	rcx, r8
+	add	rcx, rdx
+	add	rcx, rsi
+	mov	qword ptr [rdi + 576], rax
+	mov	qword ptr [rdi + 584], rcx
+	xor	eax, eax
+.LBB37_3:
+	mov	edx, 28
+	ret
+.Lfunc_end37:
+
+.LCPI38_0:
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	128
+optional_trait_methods::using_fn::targets::advanced::rem:
+.Lfunc_begin38:
+	mov	rcx, rdx
+	mov	rax, rsi
+	or	rax, rdx
+	je	.LBB38_1
+	push	rbx
+	mov	rdx, rsi
+	mov	rbx, rdi
+	mov	rdi, qword ptr [rdi + 576]
+	mov	rsi, qword ptr [rbx + 584]
+	movq	xmm0, rdi
+	movq	xmm1, rsi
+	punpcklqdq	xmm0, xmm1
+	movq	xmm1, rdx
+	movq	xmm2, rcx
+	punpcklqdq	xmm1, xmm2
+	pcmpeqd	xmm2, xmm2
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI38_0]
+	pcmpeqb	xmm2, xmm1
+	pand	xmm0, xmm2
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	je	.LBB38_4
+	call	qword ptr [rip + __modti3@GOTPCREL]
+	mov	qword ptr [rbx + 584], rdx
+	mov	qword ptr [rbx + 576], rax
+	xor	eax, eax
+.LBB38_6:
+	pop	rbx
+	ret
+.LBB38_1:
+	lea	rax, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.43]
+	mov	edx, 17
+	ret
+.LBB38_4:
+	lea	rax, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.44]
+	mov	edx, 29
+	jmp	.LBB38_6
+.Lfunc_end38:
+
+optional_trait_methods::using_fn::targets::advanced::undo:
+.Lfunc_begin39:
+	mov	rax, qword ptr [rdi + 568]
+	lea	rcx, [rsi - 1]
+	cmp	rcx, rax
+	jae	.LBB39_2
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rdi], xmm0
+	mov	ecx, dword ptr [rdi + 560]
+	sub	ecx, esi
+	and	ecx, 15
+	mov	qword ptr [rdi + 560], rcx
+	sub	rax, rsi
+	mov	qword ptr [rdi + 568], rax
+	shl	ecx, 5
+	mov	rax, qword ptr [rdi + rcx + 56]
+	mov	rdx, qword ptr [rdi + rcx + 72]
+	mov	rsi, qword ptr [rdi + rcx + 48]
+	mov	rcx, qword ptr [rdi + rcx + 64]
+	mov	r8, qword ptr [rdi + 728]
+	mov	r9, qword ptr [rdi + 720]
+	mov	r10, qword ptr [rdi + 744]
+	mov	r11, qword ptr [rdi + 736]
+	mov	qword ptr [rdi + 576], rsi
+	cmp	rcx, r9
+	mov	rsi, rdx
+	sbb	rsi, r8
+	cmovl	rdx, r8
+	cmovl	rcx, r9
+	cmp	r11, rcx
+	mov	rsi, r10
+	sbb	rsi, rdx
+	cmovl	rcx, r11
+	cmovl	rdx, r10
+	mov	qword ptr [rdi + 584], rax
+	mov	qword ptr [rdi + 712], rdx
+	mov	qword ptr [rdi + 704], rcx
+	jmp	.LBB39_3
+.LBB39_2:
+	test	rsi, rsi
+	je	.LBB39_3
+	xor	ecx, ecx
+	test	rax, rax
+	setne	cl
+	lea	rdx, [rcx + 2*rcx + 16]
+	lea	rcx, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.47]
+	lea	rax, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.46]
+	cmove	rax, rcx
+	ret
+.LBB39_3:
+	xor	eax, eax
+	ret
+.Lfunc_end39:
+
+optional_trait_methods::using_fn::targets::advanced::read_reg:
+.Lfunc_begin40:
+	test	rsi, rsi
+	je	.LBB40_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB40_5
+	shl	rax, 4
+	add	rdi, rax
+	add	rdi, 592
+	jmp	.LBB40_2
+.LBB40_1:
+	add	rdi, 576
+.LBB40_2:
+	mov	rax, qword ptr [rdi]
+	mov	rdx, qword ptr [rdi + 8]
+	ret
+.LBB40_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end40:
+
+optional_trait_methods::using_fn::targets::advanced::rollback:
+.Lfunc_begin41:
+	mov	r9, qword ptr [rdi]
+	mov	rax, qword ptr [rdi + 24]
+	mov	rdx, qword ptr [rdi + 16]
+	mov	rcx, qword ptr [rdi + 40]
+	mov	rsi, qword ptr [rdi + 32]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rdi], xmm0
+	mov	r8, qword ptr [rdi + 568]
+	test	r8, r8
+	je	.LBB41_1
+	push	r15
+	push	r14
+	push	rbx
+	mov	r10d, dword ptr [rdi + 560]
+	dec	r10d
+	and	r10d, 15
+	mov	qword ptr [rdi + 560], r10
+	lea	r11, [r8 - 1]
+	mov	qword ptr [rdi + 568], r11
+	shl	r10d, 5
+	mov	rbx, qword ptr [rdi + r10 + 56]
+	mov	r11, qword ptr [rdi + r10 + 72]
+	mov	r15, qword ptr [rdi + r10 + 48]
+	mov	r14, qword ptr [rdi + r10 + 64]
+	test	r9b, 1
+	je	.LBB41_4
+	lea	r9, [rdi + r10]
+	add	r9, 48
+	mov	qword ptr [r9], rdx
+	mov	qword ptr [r9 + 8], rax
+	mov	qword ptr [r9 + 16], rsi
+	mov	qword ptr [r9 + 24], rcx
+	mov	qword ptr [rdi + 568], r8
+.LBB41_4:
+	mov	qword ptr [rdi + 576], r15
+	mov	qword ptr [rdi + 584], rbx
+	mov	qword ptr [rdi + 704], r14
+	mov	qword ptr [rdi + 712], r11
+	xor	eax, eax
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.LBB41_1:
+	lea	rax, [rip + .Lanon.69bf9670f07f92ae81ce27265fe0c4c8.50]
+	ret
+.Lfunc_end41:
+
+optional_trait_methods::using_fn::targets::advanced::set_state:
+.Lfunc_begin42:
+	mov	qword ptr [rdi + 584], rdx
+	mov	qword ptr [rdi + 576], rsi
+	ret
+.Lfunc_end42:
+
+optional_trait_methods::using_fn::targets::advanced::write_reg:
+.Lfunc_begin43:
+	test	rsi, rsi
+	je	.LBB43_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB43_5
+	shl	rax, 4
+	mov	qword ptr [rdi + rax + 600], rcx
+	mov	qword ptr [rdi + rax + 592], rdx
+	ret
+.LBB43_1:
+	mov	qword ptr [rdi + 576], rdx
+	mov	qword ptr [rdi + 584], rcx
+	ret
+.LBB43_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end43:
+
+optional_trait_methods::connection::read_fd:
+.Lfunc_begin44:
+	push	rbx
+	mov	rax, rdx
+	mov	rbx, rdi
+	mov	edx, 1024
+	mov	edi, esi
+	mov	rsi, rax
+	call	qword ptr [rip + read@GOTPCREL]
+	test	rax, rax
+	js	.LBB44_2
+	mov	qword ptr [rbx + 8], rax
+	xor	eax, eax
+	jmp	.LBB44_3
+.LBB44_2:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	eax, dword ptr [rax]
+	mov	dword ptr [rbx + 4], eax
+	mov	eax, 1
+.LBB44_3:
+	mov	dword ptr [rbx], eax
+	pop	rbx
+	ret
+.Lfunc_end44:
+
+optional_trait_methods::connection::write_fd:
+.Lfunc_begin45:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	test	rdx, rdx
+	je	.LBB45_8
+	mov	ebp, ecx
+	mov	rbx, rdx
+	mov	r14, rsi
+	mov	r15d, edi
+	mov	r12, qword ptr [rip + send@GOTPCREL]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+.LBB45_2:
+	mov	edi, r15d
+	mov	rsi, r14
+	mov	rdx, rbx
+	test	bpl, bpl
+	je	.LBB45_3
+	mov	ecx, 16384
+	call	r12
+	jmp	.LBB45_5
+.LBB45_3:
+	call	r13
+.LBB45_5:
+	test	rax, rax
+	js	.LBB45_10
+	cmp	rbx, rax
+	jb	.LBB45_9
+	add	r14, rax
+	sub	rbx, rax
+	jne	.LBB45_2
+.LBB45_8:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB45_10:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB45_9:
+	mov	rdi, rax
+	mov	rsi, rbx
+	mov	rdx, rbx
+	call	core::slice::index::slice_index_fail
+.Lfunc_end45:
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.0:
+	.byte	10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.1:
+	.byte	59
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.2:
+	.ascii	"E 01"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.3:
+	.ascii	"E 02"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.4:
+	.ascii	"E."
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.5:
+	.ascii	" at "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.6:
+	.ascii	"E 05"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.8:
+	.ascii	"0123456789abcdef"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.11:
+	.ascii	"unknown option: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.12:
+	.ascii	"missing value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.13:
+	.ascii	"invalid value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.14:
+	.ascii	": "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.15:
+	.ascii	" doesn't take a value"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.16:
+	.ascii	"only one of --unix / --tcp may be given"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.20:
+	.ascii	"Invalid implementation!"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.21:
+	.ascii	"OK"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.22:
+	.ascii	"--unix"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.23:
+	.ascii	"--tcp"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.24:
+	.ascii	"--initial-state"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.25:
+	.ascii	"--on-error"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.26:
+	.ascii	"--echo"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.27:
+	.ascii	"--repl"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.28:
+	.ascii	"--help"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.29:
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.22
+	.asciz	"\006\000\000\000\000\000\000\000"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.23
+	.ascii	"\005\000\000\000\000\000\000\000\001"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.24
+	.ascii	"\017\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.25
+	.ascii	"\n\000\000\000\000\000\000\000\003"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.26
+	.ascii	"\006\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.27
+	.ascii	"\006\000\000\000\000\000\000\000\005"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.28
+	.ascii	"\006\000\000\000\000\000\000\000\006"
+	.zero	7
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.30:
+	.ascii	"panic!\n"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.31:
+	.ascii	"listening on 127.0.0.1:"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.32:
+	.ascii	"error: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.33:
+	.zero	2,10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.34:
+	.ascii	"usage: optional-trait-methods [options]\n\n  --unix <path>              serve the first client to connect to a Unix domain socket\n  --tcp <port>               serve the first client to connect to 127.0.0.1:<port>\n                             (port 0 picks any free port, printed to stderr)\n                             (by default, serve stdin / stdout)\n  --initial-state <n>        start the target off in state <n> (by default, 0)\n  --on-error=continue|exit   whether to keep serving after a command fails\n  --echo                     repeat every command before its reply\n  --repl                     expect a human instead of a client (prompt, aliases, help)\n                             (needs the `repl` feature)\n  --help                     print this message\n\n  (`--name <value>` may also be written `--name=<value>`)"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.35:
+	.ascii	"Parse IncDec extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.39:
+	.ascii	"Parse Mul extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.41:
+	.ascii	"Parse ScaleFactor extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.43:
+	.ascii	"Parse ScaleBounds extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.45:
+	.ascii	"Parse Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.48:
+	.ascii	"Parse Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.57:
+	.ascii	"IncDec extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.58:
+	.ascii	"Mul extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.59:
+	.ascii	"ScaleFactor nested extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.60:
+	.ascii	"ScaleBounds nested extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.61:
+	.ascii	"Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.62:
+	.ascii	"Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.63:
+	.ascii	"Undo checkpoint"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.64:
+	.ascii	"unknown command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.65:
+	.ascii	"unsupported command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.66:
+	.ascii	"bad number"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.67:
+	.ascii	"trailing garbage"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.68:
+	.ascii	"line too long"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.72:
+	.ascii	"failed to accept a connection"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.73:
+	.ascii	"--repl needs the `repl` feature"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.0:
+	.ascii	"incdec"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.1:
+	.ascii	"mul"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.2:
+	.ascii	"mul.scale_factor"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.3:
+	.ascii	"mul.scale_factor.scale_bounds"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.4:
+	.ascii	"div"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.5:
+	.ascii	"undo"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.6:
+	.byte	1
+	.zero	7
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.0
+	.ascii	"\006\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.1
+	.ascii	"\003\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.2
+	.ascii	"\020\000\000\000\000\000\000\000\b"
+	.zero	7
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.3
+	.ascii	"\035\000\000\000\000\000\000\000\020"
+	.zero	7
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.4
+	.ascii	"\003\000\000\000\000\000\000\000 "
+	.zero	7
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.5
+	.asciz	"\004\000\000\000\000\000\000"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.15:
+	.byte	43
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.17:
+	.byte	45
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.18:
+	.byte	42
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.20:
+	.ascii	"*~"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.28:
+	.ascii	"+-"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.29:
+	.ascii	"*~~"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.30:
+	.byte	47
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.31:
+	.byte	37
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.32:
+	.byte	117
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.33:
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.15
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.17
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.28
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.18
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.20
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.29
+	.asciz	"\003\000\000\000\000\000\000"
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.30
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.31
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.32
+	.asciz	"\001\000\000\000\000\000\000"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.42:
+	.ascii	"scale bounds are inverted!"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.43:
+	.ascii	"dividing by zero!"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.44:
+	.ascii	"dividing MIN by -1 overflows!"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.45:
+	.ascii	"multiplying by 7 is unlucky!"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.46:
+	.ascii	"not enough history!"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.47:
+	.ascii	"nothing to undo!"
+
+.Lanon.69bf9670f07f92ae81ce27265fe0c4c8.50:
+	.ascii	"nothing to roll back!"
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+
+	.byte	1
+	.byte	17
+	.byte	1
+	.byte	37
+	.byte	14
+	.byte	19
+	.byte	5
+	.byte	3
+	.byte	14
+	.byte	16
+	.byte	23
+	.byte	27
+	.byte	14
+	.byte	17
+	.byte	1
+	.byte	85
+	.byte	23
+	.byte	0
+	.byte	0
+	.byte	2
+	.byte	57
+	.byte	1
+	.byte	3
+	.byte	14
+	.byte	0
+	.byte	0
+	.byte	3
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	11
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	4
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	5
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	0
+.Lcu_begin0:
+	.long	.Ldebug_info_end0-.Ldebug_info_start0
+.Ldebug_info_start0:
+	.short	4
+	.long	.debug_abbrev
+	.byte	8
+	.byte	1
+	.long	.Linfo_string0
+	.short	28
+	.long	.Linfo_string1
+	.long	.Lline_table_start0
+	.long	.Linfo_string2
+	.quad	0
+	.long	.Ldebug_ranges0
+	.byte	2
+	.long	.Linfo_string3
+	.byte	2
+	.long	.Linfo_string4
+	.byte	3
+	.quad	.Lfunc_begin19
+	.long	.Lfunc_end19-.Lfunc_begin19
+	.byte	1
+	.byte	86
+	.long	.Linfo_string7
+	.long	.Linfo_string8
+	.byte	1
+	.byte	60
+	.byte	3
+
+
+	.byte	4
+	.quad	.Lfunc_begin20
+	.long	.Lfunc_end20-.Lfunc_begin20
+	.byte	1
+	.byte	86
+	.long	.Linfo_string9
+	.long	.Linfo_string10
+	.byte	1
+	.short	266
+	.byte	3
+
+
+	.byte	0
+	.byte	2
+	.long	.Linfo_string5
+	.byte	2
+	.long	.Linfo_string6
+	.byte	3
+	.quad	.Lfunc_begin21
+	.long	.Lfunc_end21-.Lfunc_begin21
+	.byte	1
+	.byte	86
+	.long	.Linfo_string11
+	.long	.Linfo_string12
+	.byte	2
+	.byte	38
+	.byte	3
+
+
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+.Ldebug_info_end0:
+.Lsec_end0:
+.Lsec_end1:
+.Lsec_end2:
+	.long	76
+	.short	2
+	.long	.Lcu_begin0
+	.byte	8
+	.byte	0
+	.zero	4,255
+	.quad	.Lfunc_begin19
+	.quad	.Lsec_end0-.Lfunc_begin19
+	.quad	.Lfunc_begin20
+	.quad	.Lsec_end1-.Lfunc_begin20
+	.quad	.Lfunc_begin21
+	.quad	.Lsec_end2-.Lfunc_begin21
+	.quad	0
+	.quad	0
+.Ldebug_ranges0:
+	.quad	.Lfunc_begin19
+	.quad	.Lfunc_end19
+	.quad	.Lfunc_begin20
+	.quad	.Lfunc_end20
+	.quad	.Lfunc_begin21
+	.quad	.Lfunc_end21
+	.quad	0
+	.quad	0
+.Linfo_string0:
+	.asciz	"clang LLVM (rustc version 1.97.0-nightly (e50aa6fba 2026-05-19))"
+.Linfo_string1:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a/library/core/src/lib.rs/@/core.667c7a611d73a360-cgu.0"
+.Linfo_string2:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a"
+.Linfo_string3:
+	.asciz	"core"
+.Linfo_string4:
+	.asciz	"panicking"
+.Linfo_string5:
+	.asciz	"slice"
+.Linfo_string6:
+	.asciz	"index"
+.Linfo_string7:
+	.asciz	"core::panicking::panic_fmt"
+.Linfo_string8:
+	.asciz	"panic_fmt"
+.Linfo_string9:
+	.asciz	"core::panicking::panic_bounds_check"
+.Linfo_string10:
+	.asciz	"panic_bounds_check"
+.Linfo_string11:
+	.asciz	"core::slice::index::slice_index_fail"
+.Linfo_string12:
+	.asciz	"slice_index_fail"
+	.ident	"rustc version 1.97.0-nightly (e50aa6fba 2026-05-19)"
+.Lline_table_start0:
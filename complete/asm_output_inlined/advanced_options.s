@@ -1,444 +1,463 @@
-__rustc::rust_begin_unwind:
-	push	rax
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.0]
-	mov	edx, 7
-	mov	edi, 2
-	call	qword ptr [rip + write@GOTPCREL]
-	call	qword ptr [rip + abort@GOTPCREL]
+<optional_trait_methods::commands::Args>::num::<isize>:
+.Lfunc_begin0:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<isize>
+	and	al, 1
+	cmove	rdx, r15
+	neg	al
+	or	al, 2
+	mov	qword ptr [rbx], rdx
+	mov	byte ptr [rbx + 8], al
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
 .Lfunc_end0:
 
-<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_factor:
-	mov	rax, rdi
-	mov	qword ptr [rsi + 8], rdx
-	mov	qword ptr [rdi], 0
+<optional_trait_methods::commands::Args>::regs::<isize>:
+.Lfunc_begin1:
+	push	r14
+	push	rbx
+	sub	rsp, 88
+	mov	rbx, rdi
+	lea	r14, [rsp + 16]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	rax, qword ptr [r14]
+	cmp	qword ptr [r14 + 16], 128
+	jne	.LBB1_20
+	mov	rcx, qword ptr [rsp + 24]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 80], 0
+	mov	edx, 128
+	xor	esi, esi
+.LBB1_2:
+	mov	rdi, rcx
+	add	rcx, 16
+	add	rdx, -16
+	movaps	xmmword ptr [rsp], xmm0
+	xor	r8d, r8d
+.LBB1_3:
+	mov	r10b, byte ptr [rdi + 2*r8]
+	lea	r9d, [r10 - 48]
+	cmp	r9b, 10
+	jb	.LBB1_13
+	lea	r9d, [r10 - 97]
+	cmp	r9b, 5
+	ja	.LBB1_10
+	add	r10b, -87
+	jmp	.LBB1_12
+.LBB1_10:
+	lea	r9d, [r10 - 71]
+	cmp	r9b, -6
+	jb	.LBB1_20
+	add	r10b, -55
+.LBB1_12:
+	mov	r9d, r10d
+.LBB1_13:
+	mov	r10b, byte ptr [rdi + 2*r8 + 1]
+	lea	r11d, [r10 - 48]
+	cmp	r11b, 10
+	jb	.LBB1_19
+	lea	r11d, [r10 - 97]
+	cmp	r11b, 5
+	ja	.LBB1_16
+	add	r10b, -87
+	jmp	.LBB1_18
+.LBB1_16:
+	lea	r11d, [r10 - 71]
+	cmp	r11b, -6
+	jb	.LBB1_20
+	add	r10b, -55
+.LBB1_18:
+	mov	r11d, r10d
+.LBB1_19:
+	shl	r9b, 4
+	or	r9b, r11b
+	mov	byte ptr [rsp + r8], r9b
+	inc	r8
+	cmp	r8, 8
+	jne	.LBB1_3
+	cmp	rsi, 7
+	ja	.LBB1_8
+	mov	rdi, qword ptr [rsp]
+	mov	qword ptr [rsp + 8*rsi + 16], rdi
+	mov	rsi, qword ptr [rsp + 80]
+	inc	rsi
+	mov	qword ptr [rsp + 80], rsi
+.LBB1_8:
+	test	rdx, rdx
+	jne	.LBB1_2
+	lea	rdi, [rbx + 8]
+	lea	rsi, [rsp + 16]
+	mov	ecx, 9
+	rep movsq es:[rdi], [rsi]
+	xor	eax, eax
+	jmp	.LBB1_21
+.LBB1_20:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB1_21:
+	mov	qword ptr [rbx], rax
+	add	rsp, 88
+	pop	rbx
+	pop	r14
 	ret
 .Lfunc_end1:
 
-<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec:
-	dec	qword ptr [rsi]
-	mov	rax, rdi
-	mov	qword ptr [rdi], 0
+<optional_trait_methods::commands::Args>::num_or::<usize>:
+.Lfunc_begin2:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	cmp	qword ptr [rsi + 8], 0
+	je	.LBB2_1
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	and	al, 1
+	cmove	rdx, r15
+	neg	al
+	or	al, 2
+	jmp	.LBB2_3
+.LBB2_1:
+	mov	al, -1
+	mov	edx, 1
+.LBB2_3:
+	mov	qword ptr [rbx], rdx
+	mov	byte ptr [rbx + 8], al
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
 	ret
 .Lfunc_end2:
 
-<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc:
-	inc	qword ptr [rsi]
-	mov	rax, rdi
-	mov	qword ptr [rdi], 0
+<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::apply::{closure#0}>:
+.Lfunc_begin3:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rax, qword ptr [rdi + 64]
+	xor	r15d, r15d
+	cmp	rax, 9
+	mov	r12d, 8
+	cmovb	r12, rdi
+	lea	rax, [8*rax]
+	cmovb	r15, rax
+	test	r15, r15
+	je	.LBB3_3
+	mov	rbx, rsi
+	xor	r14d, r14d
+.LBB3_2:
+	mov	rdx, qword ptr [r12 + 8*r14]
+	mov	rdi, rbx
+	mov	rsi, r14
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::write_reg
+	inc	r14
+	add	r15, -8
+	jne	.LBB3_2
+.LBB3_3:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
 	ret
 .Lfunc_end3:
 
-<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::mul:
-	mov	rax, rdi
-	cmp	rdx, 7
-	jne	.LBB4_2
-	lea	rcx, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.11]
-	mov	qword ptr [rax + 8], rcx
-	mov	qword ptr [rax + 16], 28
-	mov	ecx, 1
-	jmp	.LBB4_3
-.LBB4_2:
-	imul	rdx, qword ptr [rsi + 8]
-	imul	rdx, qword ptr [rsi]
-	mov	qword ptr [rsi], rdx
-	xor	ecx, ecx
-.LBB4_3:
-	mov	qword ptr [rax], rcx
+optional_trait_methods::num::format_decimal::<isize>:
+.Lfunc_begin4:
+	push	rax
+	mov	eax, 39
+	movabs	r9, 7378697629483820647
+	mov	rcx, rdi
+.LBB4_1:
+	cmp	rax, 39
+	ja	.LBB4_7
+	mov	r8, rax
+	mov	rax, rcx
+	imul	r9
+	mov	rax, rdx
+	shr	rax, 63
+	sar	rdx, 2
+	add	rdx, rax
+	lea	rax, [rdx + rdx]
+	lea	rax, [rax + 4*rax]
+	mov	r10, rcx
+	sub	r10, rax
+	mov	rax, r10
+	neg	rax
+	cmovs	rax, r10
+	add	al, 48
+	mov	byte ptr [rsi + r8], al
+	add	rcx, 9
+	lea	rax, [r8 - 1]
+	cmp	rcx, 19
+	mov	rcx, rdx
+	jae	.LBB4_1
+	test	rdi, rdi
+	jns	.LBB4_6
+	inc	r8
+	add	r8, -2
+	cmp	r8, 39
+	ja	.LBB4_7
+	mov	byte ptr [rsi + r8], 45
+.LBB4_6:
+	mov	edx, 40
+	sub	rdx, r8
+	add	rsi, r8
+	mov	rax, rsi
+	pop	rcx
 	ret
+.LBB4_7:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
 .Lfunc_end4:
 
-<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::get_state:
-	mov	rax, qword ptr [rdi]
+optional_trait_methods::num::format_decimal::<usize>:
+.Lfunc_begin5:
+	mov	eax, 39
+	movabs	r8, -3689348814741910323
+.LBB5_1:
+	cmp	rax, 39
+	ja	.LBB5_4
+	mov	rcx, rax
+	mov	rax, rdi
+	mul	r8
+	shr	rdx, 3
+	lea	eax, [rdx + rdx]
+	lea	eax, [rax + 4*rax]
+	mov	r9d, edi
+	sub	r9d, eax
+	or	r9b, 48
+	mov	byte ptr [rsi + rcx], r9b
+	lea	rax, [rcx - 1]
+	cmp	rdi, 10
+	mov	rdi, rdx
+	jae	.LBB5_1
+	add	rsi, rcx
+	inc	rcx
+	mov	edx, 41
+	sub	rdx, rcx
+	mov	rax, rsi
 	ret
+.LBB5_4:
+	push	rax
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
 .Lfunc_end5:
 
-<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::set_state:
-	mov	qword ptr [rdi], rsi
-	ret
-.Lfunc_end6:
-
-main:
+optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin6:
 	push	rbp
 	push	r15
 	push	r14
 	push	r13
 	push	r12
 	push	rbx
-	sub	rsp, 1224
-	lea	rbx, [rsp + 168]
-	mov	qword ptr [rbx], 0
-	mov	qword ptr [rbx + 8], 1
-	lea	r14, [rsp + 184]
-	mov	edx, 1040
-	mov	rdi, r14
-	xor	esi, esi
-	call	qword ptr [rip + memset@GOTPCREL]
-	xorps	xmm0, xmm0
-	lea	rbp, [rsp + 34]
-	movaps	xmmword ptr [rbp + 110], xmm0
-	movaps	xmmword ptr [rbp + 94], xmm0
-	movaps	xmmword ptr [rbp + 78], xmm0
-	movaps	xmmword ptr [rbp + 62], xmm0
-	movaps	xmmword ptr [rbp + 46], xmm0
-	movaps	xmmword ptr [rbp + 30], xmm0
-	movaps	xmmword ptr [rbp + 14], xmm0
-	movaps	xmmword ptr [rbp - 2], xmm0
-	lea	r15, [rsp + 8]
-	mov	r12, qword ptr [rip + read@GOTPCREL]
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB6_2
+	lea	rcx, [rip + .LJTI6_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB6_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB6_5
+.LBB6_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
 	xor	edx, edx
-	xor	eax, eax
-.LBB7_1:
-	xor	r13d, r13d
-.LBB7_2:
-	mov	rcx, rax
-	mov	rax, rdx
-.LBB7_3:
-	cmp	rcx, rax
-	jb	.LBB7_6
-	mov	edx, 1024
-	xor	edi, edi
-	mov	rsi, r14
-	call	r12
-	test	rax, rax
-	jle	.LBB7_81
-	mov	qword ptr [rsp + 1208], rax
-	mov	qword ptr [rsp + 1216], 0
-	xor	ecx, ecx
-.LBB7_6:
-	cmp	rax, rcx
-	jb	.LBB7_75
-	cmp	rax, 1024
-	ja	.LBB7_75
-	cmp	rcx, rax
-	je	.LBB7_3
-	inc	rcx
-	mov	rdx, rax
-	neg	rdx
-.LBB7_10:
-	mov	sil, byte ptr [rsp + rcx + 183]
-	mov	qword ptr [rsp + 1216], rcx
-	cmp	sil, 10
-	je	.LBB7_11
-	cmp	r13, 127
-	ja	.LBB7_15
-	mov	byte ptr [rsp + r13 + 32], sil
-	inc	r13
-.LBB7_15:
-	lea	rsi, [rdx + rcx]
-	inc	rsi
-	inc	rcx
-	cmp	rsi, 1
-	jne	.LBB7_10
-	mov	rdx, rax
-	jmp	.LBB7_2
-.LBB7_81:
-	lea	rax, [r13 - 129]
-	cmp	rax, -128
-	jb	.LBB7_75
-	jmp	.LBB7_82
-.LBB7_11:
-	cmp	r13, 128
-	ja	.LBB7_75
+	not	r12b
+	xor	r14d, r14d
+.LBB6_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB6_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB6_17
+	mov	r13, qword ptr [rax + 8]
 	test	r13, r13
-	je	.LBB7_80
-.LBB7_82:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.1]
-	mov	qword ptr [rsp + 8], rax
-	mov	qword ptr [rsp + 16], 22
-	#APP
-	#NO_APP
-	cmp	r13, 1
-	jne	.LBB7_17
-	movzx	eax, byte ptr [rsp + 32]
-	cmp	eax, 43
-	je	.LBB7_64
-	cmp	eax, 45
-	jne	.LBB7_18
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	mov	qword ptr [rsp + 8], rax
-	mov	qword ptr [rsp + 16], 16
-	#APP
-	#NO_APP
-	mov	rdi, r15
-	mov	rsi, rbx
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
-	jmp	.LBB7_65
-.LBB7_17:
-	cmp	r13, 2
-	sete	cl
-	movzx	eax, word ptr [rsp + 32]
-	movzx	edx, ax
-	cmp	edx, 11563
-	sete	dl
-	test	cl, dl
-	je	.LBB7_18
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	mov	qword ptr [rsp + 8], rax
-	mov	qword ptr [rsp + 16], 16
-	#APP
-	#NO_APP
+	jne	.LBB6_18
+.LBB6_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB6_15
+	jmp	.LBB6_28
+.LBB6_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
 	mov	rdi, r15
-	mov	rsi, rbx
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc
-	cmp	dword ptr [rsp + 8], 1
-	jne	.LBB7_71
-	mov	rsi, qword ptr [rsp + 16]
-	test	rsi, rsi
-	jne	.LBB7_67
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
 	mov	rdi, r15
-	mov	rsi, rbx
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
-	cmp	byte ptr [rsp + 8], 0
-	je	.LBB7_73
-	jmp	.LBB7_66
-.LBB7_18:
-	lea	rcx, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.2]
-	mov	qword ptr [rsp + 8], rcx
-	mov	qword ptr [rsp + 16], 19
-	#APP
-	#NO_APP
-	cmp	r13, 2
-	jb	.LBB7_32
-	cmp	word ptr [rsp + 32], 8234
-	jne	.LBB7_32
-	mov	rcx, r13
-	add	rcx, -2
-	je	.LBB7_32
-	movzx	edx, byte ptr [rsp + 34]
-	cmp	edx, 45
-	je	.LBB7_22
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB6_14
+	jmp	.LBB6_28
+.LBB6_19:
+	lea	r14, [rbx + 8]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
 	xor	esi, esi
-	cmp	edx, 43
-	je	.LBB7_23
-	mov	rdi, rbp
-	jmp	.LBB7_26
-.LBB7_64:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	mov	qword ptr [rsp + 8], rax
-	mov	qword ptr [rsp + 16], 16
-	#APP
-	#NO_APP
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 72]
+	cmp	rcx, 9
+	mov	eax, 8
+	cmovb	rax, r14
+	lea	rcx, [8*rcx]
+	cmovae	rcx, r12
+	test	rcx, rcx
+	je	.LBB6_26
+	add	rcx, rax
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB6_21:
+	mov	qword ptr [rsp + 24], 0
+	mov	rsi, qword ptr [rax]
+	add	rax, 8
+	mov	qword ptr [rsp + 16], rsi
+	xor	esi, esi
+.LBB6_22:
+	cmp	r12, 256
+	jae	.LBB6_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB6_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 8
+	jne	.LBB6_22
+	cmp	rax, rcx
+	jne	.LBB6_21
+.LBB6_26:
+	lea	rsi, [rsp + 32]
 	mov	rdi, r15
-	mov	rsi, rbx
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc
-.LBB7_65:
-	cmp	byte ptr [rsp + 8], 0
-	je	.LBB7_80
-.LBB7_66:
-	mov	rsi, qword ptr [rsp + 16]
-	test	rsi, rsi
-	jne	.LBB7_67
-.LBB7_79:
-	mov	edx, 16
-	mov	edi, 1
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.5]
-	mov	r13, qword ptr [rip + write@GOTPCREL]
-	call	r13
-	mov	edx, 1
-	mov	edi, 1
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.6]
-	call	r13
-.LBB7_80:
-	mov	rdx, qword ptr [rsp + 1208]
-	mov	rax, qword ptr [rsp + 1216]
-	jmp	.LBB7_1
-.LBB7_71:
+	mov	rdx, r12
+	jmp	.LBB6_27
+.LBB6_11:
+	mov	rdi, qword ptr [rbx + 8]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<isize>
+.LBB6_12:
 	mov	rdi, r15
-	mov	rsi, rbx
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
-	cmp	byte ptr [rsp + 8], 0
-	jne	.LBB7_72
-	jmp	.LBB7_80
-.LBB7_22:
-	mov	sil, 1
-.LBB7_23:
-	mov	rcx, r13
-	lea	rdi, [rsp + 35]
-	add	rcx, -3
-	je	.LBB7_32
-.LBB7_26:
-	xor	r8d, r8d
-	xor	edx, edx
-.LBB7_27:
-	cmp	rcx, r8
-	je	.LBB7_31
-	mov	r9b, byte ptr [rdi + r8]
-	add	r9b, -48
-	cmp	r9b, 9
-	ja	.LBB7_32
-	imul	rdx, rdx, 10
-	jo	.LBB7_32
-	movzx	r9d, r9b
-	inc	r8
-	add	rdx, r9
-	jno	.LBB7_27
-	jmp	.LBB7_32
-.LBB7_31:
-	movzx	ecx, sil
-	neg	rcx
-	or	rcx, 1
-	imul	rdx, rcx
-	jno	.LBB7_77
-.LBB7_32:
-	lea	rcx, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.3]
-	mov	qword ptr [rsp + 8], rcx
-	mov	qword ptr [rsp + 16], 27
-	#APP
-	#NO_APP
-	cmp	r13, 3
-	jb	.LBB7_46
-	mov	ecx, dword ptr [rsp + 32]
-	mov	edx, 32298
-	xor	ecx, edx
-	movzx	edx, byte ptr [rsp + 34]
-	xor	edx, 32
-	or	dx, cx
-	jne	.LBB7_50
-	mov	rcx, r13
-	add	rcx, -3
-	je	.LBB7_50
-	movzx	edx, byte ptr [rsp + 35]
-	cmp	edx, 45
-	je	.LBB7_36
-	xor	esi, esi
-	cmp	edx, 43
-	je	.LBB7_37
-	lea	rdi, [rsp + 35]
-	jmp	.LBB7_40
-.LBB7_36:
-	mov	sil, 1
-.LBB7_37:
-	mov	rcx, r13
-	lea	rdi, [rsp + 36]
-	add	rcx, -4
-	je	.LBB7_50
-.LBB7_40:
-	xor	r8d, r8d
-	xor	edx, edx
-.LBB7_41:
-	cmp	rcx, r8
-	je	.LBB7_45
-	mov	r9b, byte ptr [rdi + r8]
-	add	r9b, -48
-	cmp	r9b, 9
-	ja	.LBB7_46
-	imul	rdx, rdx, 10
-	jo	.LBB7_46
-	movzx	r9d, r9b
-	inc	r8
-	add	rdx, r9
-	jno	.LBB7_41
-	jmp	.LBB7_46
-.LBB7_45:
-	movzx	ecx, sil
-	neg	rcx
-	or	rcx, 1
-	imul	rdx, rcx
-	jno	.LBB7_76
-.LBB7_46:
-	cmp	r13, 1
-	jne	.LBB7_49
-	cmp	al, 112
-	jne	.LBB7_79
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::get_state
-	lea	rdi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.7]
 	mov	rsi, rax
-	xor	eax, eax
-	call	qword ptr [rip + printf@GOTPCREL]
-	jmp	.LBB7_80
-.LBB7_49:
-	jbe	.LBB7_79
-.LBB7_50:
-	cmp	word ptr [rsp + 32], 8307
-	jne	.LBB7_79
-	mov	rcx, r13
-	add	rcx, -2
-	je	.LBB7_79
-	movzx	eax, byte ptr [rsp + 34]
-	cmp	eax, 45
-	je	.LBB7_53
-	cmp	eax, 43
-	jne	.LBB7_55
-	xor	eax, eax
-	jmp	.LBB7_57
-.LBB7_53:
-	mov	al, 1
-.LBB7_57:
-	lea	rdx, [rsp + 35]
-	add	r13, -3
-	mov	rcx, r13
-	je	.LBB7_79
-	jmp	.LBB7_58
-.LBB7_55:
-	xor	eax, eax
-	mov	rdx, rbp
-.LBB7_58:
-	xor	edi, edi
-	xor	esi, esi
-.LBB7_59:
-	cmp	rcx, rdi
-	je	.LBB7_78
-	mov	r8b, byte ptr [rdx + rdi]
-	add	r8b, -48
-	cmp	r8b, 9
-	ja	.LBB7_79
-	imul	rsi, rsi, 10
-	jo	.LBB7_79
-	movzx	r8d, r8b
-	inc	rdi
-	add	rsi, r8
-	jno	.LBB7_59
-	jmp	.LBB7_79
-.LBB7_78:
-	movzx	eax, al
-	neg	rax
-	or	rax, 1
-	imul	rsi, rax
-	jo	.LBB7_79
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::set_state
-	jmp	.LBB7_80
-.LBB7_77:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.9]
-	mov	qword ptr [rsp + 8], rax
-	mov	qword ptr [rsp + 16], 13
-	#APP
-	#NO_APP
+	jmp	.LBB6_27
+.LBB6_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI6_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB6_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB6_4
+.LBB6_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB6_4
+.LBB6_7:
+	mov	esi, 1
 	mov	rdi, r15
-	mov	rsi, rbx
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::mul
-	jmp	.LBB7_65
-.LBB7_76:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.10]
-	mov	qword ptr [rsp + 8], rax
-	mov	qword ptr [rsp + 16], 21
-	#APP
-	#NO_APP
+	xor	edx, edx
+	jmp	.LBB6_27
+.LBB6_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
 	mov	rdi, r15
-	mov	rsi, rbx
-	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_factor
-	jmp	.LBB7_65
-.LBB7_72:
-	mov	rsi, qword ptr [rsp + 16]
-	test	rsi, rsi
-	je	.LBB7_73
-.LBB7_67:
-	mov	rdx, qword ptr [rsp + 24]
-	jmp	.LBB7_74
-.LBB7_73:
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.12]
-	mov	edx, 23
-.LBB7_74:
-	mov	edi, 1
-	call	qword ptr [rip + write@GOTPCREL]
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.6]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB6_12
+.LBB6_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB6_4:
+	mov	edx, 4
+.LBB6_5:
+	mov	rdi, r15
+.LBB6_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB6_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
 	mov	edx, 1
-	mov	edi, 1
-	call	qword ptr [rip + write@GOTPCREL]
-.LBB7_75:
-	xor	eax, eax
-	add	rsp, 1224
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	ecx, 10
+	mov	rdi, rbp
+	mov	rsi, rbx
+	rep movsq es:[rdi], [rsi]
+	add	rsp, 296
 	pop	rbx
 	pop	r12
 	pop	r13
@@ -446,49 +465,4748 @@ main:
 	pop	r15
 	pop	rbp
 	ret
-.Lfunc_end7:
-
-rust_eh_personality:
-	ret
-.Lfunc_end8:
-
-.Lanon.cabb224323f7f456a7965482e4c09287.0:
-	.ascii	"panic!\n"
-
-.Lanon.cabb224323f7f456a7965482e4c09287.1:
-	.ascii	"Parse IncDec extension"
-
-.Lanon.cabb224323f7f456a7965482e4c09287.2:
-	.ascii	"Parse Mul extension"
-
-.Lanon.cabb224323f7f456a7965482e4c09287.3:
-	.ascii	"Parse ScaleFactor extension"
-
-.Lanon.cabb224323f7f456a7965482e4c09287.4:
-	.ascii	"*~ "
-
-.Lanon.cabb224323f7f456a7965482e4c09287.5:
-	.ascii	"Unsupported cmd!"
-
-.Lanon.cabb224323f7f456a7965482e4c09287.6:
-	.byte	10
-
-.Lanon.cabb224323f7f456a7965482e4c09287.7:
-	.asciz	"%d\n"
-
-.Lanon.cabb224323f7f456a7965482e4c09287.8:
-	.ascii	"IncDec extension"
-
-.Lanon.cabb224323f7f456a7965482e4c09287.9:
-	.ascii	"Mul extension"
-
-.Lanon.cabb224323f7f456a7965482e4c09287.10:
-	.ascii	"ScaleFactor extension"
-
-.Lanon.cabb224323f7f456a7965482e4c09287.11:
-	.ascii	"multiplying by 7 is unlucky!"
+.LBB6_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB6_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end6:
+.LJTI6_0:
+	.long	.LBB6_10-.LJTI6_0
+	.long	.LBB6_11-.LJTI6_0
+	.long	.LBB6_13-.LJTI6_0
+	.long	.LBB6_19-.LJTI6_0
+.LJTI6_1:
+	.long	.LBB6_3-.LJTI6_1
+	.long	.LBB6_6-.LJTI6_1
+	.long	.LBB6_7-.LJTI6_1
+	.long	.LBB6_8-.LJTI6_1
+	.long	.LBB6_9-.LJTI6_1
+
+optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin7:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB7_2
+	lea	rcx, [rip + .LJTI7_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB7_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB7_5
+.LBB7_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB7_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB7_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB7_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB7_18
+.LBB7_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB7_15
+	jmp	.LBB7_28
+.LBB7_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB7_14
+	jmp	.LBB7_28
+.LBB7_19:
+	lea	r14, [rbx + 8]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 72]
+	cmp	rcx, 9
+	mov	eax, 8
+	cmovb	rax, r14
+	lea	rcx, [8*rcx]
+	cmovae	rcx, r12
+	test	rcx, rcx
+	je	.LBB7_26
+	add	rcx, rax
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB7_21:
+	mov	qword ptr [rsp + 24], 0
+	mov	rsi, qword ptr [rax]
+	add	rax, 8
+	mov	qword ptr [rsp + 16], rsi
+	xor	esi, esi
+.LBB7_22:
+	cmp	r12, 256
+	jae	.LBB7_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB7_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 8
+	jne	.LBB7_22
+	cmp	rax, rcx
+	jne	.LBB7_21
+.LBB7_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB7_27
+.LBB7_11:
+	mov	rdi, qword ptr [rbx + 8]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<isize>
+.LBB7_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB7_27
+.LBB7_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI7_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB7_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB7_4
+.LBB7_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB7_4
+.LBB7_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB7_27
+.LBB7_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB7_12
+.LBB7_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB7_4:
+	mov	edx, 4
+.LBB7_5:
+	mov	rdi, r15
+.LBB7_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB7_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	ecx, 10
+	mov	rdi, rbp
+	mov	rsi, rbx
+	rep movsq es:[rdi], [rsi]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB7_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB7_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end7:
+.LJTI7_0:
+	.long	.LBB7_10-.LJTI7_0
+	.long	.LBB7_11-.LJTI7_0
+	.long	.LBB7_13-.LJTI7_0
+	.long	.LBB7_19-.LJTI7_0
+.LJTI7_1:
+	.long	.LBB7_3-.LJTI7_1
+	.long	.LBB7_6-.LJTI7_1
+	.long	.LBB7_7-.LJTI7_1
+	.long	.LBB7_8-.LJTI7_1
+	.long	.LBB7_9-.LJTI7_1
+
+optional_trait_methods::report_port:
+.Lfunc_begin8:
+	push	rbx
+	sub	rsp, 64
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 16]
+	movaps	xmmword ptr [rsi], xmm0
+	lea	rdx, [rsp + 12]
+	mov	dword ptr [rdx], 16
+	call	qword ptr [rip + getsockname@GOTPCREL]
+	test	eax, eax
+	js	.LBB8_1
+	xor	eax, eax
+	test	al, al
+	jne	.LBB8_7
+	movzx	ebx, word ptr [rsp + 18]
+	rol	bx, 8
+	shl	ebx, 16
+	shr	ebx, 16
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 48], 0
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.30]
+	mov	edx, 23
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	ecx, 39
+.LBB8_4:
+	cmp	rcx, 39
+	ja	.LBB8_8
+	mov	rax, rcx
+	movzx	edx, bx
+	imul	esi, edx, 52429
+	shr	esi, 19
+	lea	ecx, [rsi + rsi]
+	lea	ecx, [rcx + 4*rcx]
+	sub	ebx, ecx
+	or	bl, 48
+	mov	byte ptr [rsp + rax + 16], bl
+	lea	rcx, [rax - 1]
+	mov	ebx, esi
+	cmp	dx, 10
+	jae	.LBB8_4
+	lea	rsi, [rsp + rax]
+	add	rsi, 16
+	inc	rax
+	mov	edx, 41
+	sub	rdx, rax
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB8_7
+.LBB8_1:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB8_7:
+	add	rsp, 64
+	pop	rbx
+	ret
+.LBB8_8:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end8:
+
+optional_trait_methods::usage_error:
+.Lfunc_begin9:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.31]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	rax, qword ptr [rbx]
+	lea	rcx, [rip + .LJTI9_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB9_1:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.7]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 16
+	jmp	.LBB9_2
+.LBB9_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.12]
+	mov	edx, 39
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	jmp	.LBB9_7
+.LBB9_4:
+	mov	r14, qword ptr [rbx + 8]
+	mov	r15, qword ptr [rbx + 16]
+	mov	r12, qword ptr [rbx + 24]
+	mov	rbx, qword ptr [rbx + 32]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.9]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, r15
+	call	r13
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.10]
+	mov	edx, 2
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r12
+	mov	rdx, rbx
+	call	r13
+	jmp	.LBB9_7
+.LBB9_5:
+	mov	rsi, qword ptr [rbx + 8]
+	mov	rdx, qword ptr [rbx + 16]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.11]
+	mov	edx, 21
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB9_7
+.LBB9_3:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+.LBB9_2:
+	mov	edi, 2
+	call	r15
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, rbx
+	call	r15
+.LBB9_7:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.32]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 2
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	mov	edx, 823
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	mov	rax, rbx
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	jmp	rax
+.Lfunc_end9:
+.LJTI9_0:
+	.long	.LBB9_1-.LJTI9_0
+	.long	.LBB9_3-.LJTI9_0
+	.long	.LBB9_4-.LJTI9_0
+	.long	.LBB9_5-.LJTI9_0
+	.long	.LBB9_6-.LJTI9_0
+
+__rustc::rust_begin_unwind:
+.Lfunc_begin10:
+	push	rax
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	call	qword ptr [rip + abort@GOTPCREL]
+.Lfunc_end10:
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin11:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB11_1
+	xor	eax, eax
+	mov	qword ptr [r14 + 1024], rax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	cmovae	rdx, rax
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB11_3
+	mov	edi, 1
+	mov	rsi, r15
+	mov	rdx, rbx
+	xor	ecx, ecx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	jmp	optional_trait_methods::connection::write_fd
+.LBB11_1:
+	cmp	rax, rdx
+	jb	.LBB11_4
+.LBB11_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB11_4:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end11:
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin12:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB12_1
+	xor	eax, eax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	mov	qword ptr [r14 + 1024], rax
+	cmovae	rdx, rax
+	mov	ebp, dword ptr [r14 + 1032]
+	mov	edi, ebp
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB12_3
+	mov	edi, ebp
+	mov	rsi, r15
+	mov	rdx, rbx
+	mov	ecx, 1
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	optional_trait_methods::connection::write_fd
+.LBB12_1:
+	cmp	rax, rdx
+	jb	.LBB12_4
+.LBB12_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB12_4:
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end12:
+
+<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget> as optional_trait_methods::state_machine::Controller>::parse_command:
+.Lfunc_begin13:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	sub	rsp, 176
+	mov	rbx, rdi
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.35]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 22
+	#APP
+	#NO_APP
+	lea	rdi, [rsi + 1]
+	movzx	eax, byte ptr [rsi]
+	cmp	eax, 45
+	je	.LBB13_4
+	cmp	eax, 43
+	jne	.LBB13_9
+	cmp	rdx, 1
+	jne	.LBB13_6
+	mov	byte ptr [rbx + 1], 0
+	jmp	.LBB13_20
+.LBB13_4:
+	cmp	rdx, 1
+	jne	.LBB13_6
+	mov	byte ptr [rbx + 1], 1
+	jmp	.LBB13_20
+.LBB13_6:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_10
+.LBB13_7:
+	mov	qword ptr [rbx + 8], 1
+.LBB13_8:
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+	jmp	.LBB13_21
+.LBB13_9:
+	cmp	rdx, 2
+	jb	.LBB13_11
+.LBB13_10:
+	cmp	word ptr [rsi], 11563
+	je	.LBB13_18
+.LBB13_11:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.39]
+	mov	r8, rsp
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	eax, 42
+	jne	.LBB13_28
+	mov	r15, rdx
+	dec	r15
+	jne	.LBB13_23
+	setne	al
+	movzx	ecx, al
+	sub	r15, rcx
+	add	rdi, rcx
+.LBB13_14:
+	mov	r14, r15
+.LBB13_15:
+	movzx	r12d, al
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB13_39
+	cmp	r15, r14
+	jne	.LBB13_63
+	mov	byte ptr [rbx], 8
+	mov	qword ptr [rbx + 8], 0
+	mov	qword ptr [rbx + 16], rdx
+	jmp	.LBB13_22
+.LBB13_18:
+	cmp	rdx, 2
+	jne	.LBB13_50
+	mov	byte ptr [rbx + 1], 2
+.LBB13_20:
+	mov	al, 7
+.LBB13_21:
+	mov	byte ptr [rbx], al
+	jmp	.LBB13_22
+.LBB13_23:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_28
+	xor	eax, eax
+	test	r15, r15
+	setne	al
+	sub	r15, rax
+	add	rdi, rax
+	cmp	rdx, 3
+	jb	.LBB13_14
+	xor	r14d, r14d
+.LBB13_26:
+	cmp	byte ptr [rdi + r14], 32
+	je	.LBB13_15
+	inc	r14
+	cmp	r15, r14
+	jne	.LBB13_26
+	jmp	.LBB13_14
+.LBB13_28:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.41]
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 2
+	jb	.LBB13_30
+	cmp	word ptr [rsi], 32298
+	je	.LBB13_52
+.LBB13_30:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.43]
+	mov	r8, rsp
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 3
+	jb	.LBB13_32
+	movzx	ecx, word ptr [rsi]
+	xor	ecx, 32298
+	movzx	r8d, byte ptr [rsi + 2]
+	xor	r8d, 126
+	or	r8w, cx
+	je	.LBB13_59
+.LBB13_32:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.45]
+	mov	r8, rsp
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	eax, 37
+	je	.LBB13_40
+	cmp	eax, 47
+	jne	.LBB13_45
+	mov	rax, rdx
+	dec	rax
+	je	.LBB13_36
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_64
+.LBB13_36:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB13_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB13_124
+	mov	byte ptr [rbx], 9
+	mov	qword ptr [rbx + 8], 0
+	jmp	.LBB13_58
+.LBB13_39:
+	inc	r12
+	mov	qword ptr [rbx + 8], r12
+	mov	byte ptr [rbx + 16], 2
+	jmp	.LBB13_67
+.LBB13_40:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB13_42
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_64
+.LBB13_42:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB13_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB13_124
+	mov	byte ptr [rbx], 9
+	jmp	.LBB13_57
+.LBB13_45:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	qword ptr [r8], rcx
+	mov	qword ptr [r8 + 8], 20
+	#APP
+	#NO_APP
+	cmp	eax, 111
+	jle	.LBB13_68
+	lea	ecx, [rax - 112]
+	cmp	ecx, 7
+	ja	.LBB13_65
+	lea	r8, [rip + .LJTI13_0]
+	movsxd	rcx, dword ptr [r8 + 4*rcx]
+	add	rcx, r8
+	jmp	rcx
+.LBB13_48:
+	cmp	rdx, 1
+	jne	.LBB13_101
+	xor	eax, eax
+	jmp	.LBB13_103
+.LBB13_50:
+	cmp	byte ptr [rsi + 2], 32
+	jne	.LBB13_11
+	mov	qword ptr [rbx + 8], 2
+	jmp	.LBB13_8
+.LBB13_52:
+	lea	rcx, [rsi + 2]
+	mov	r8, rdx
+	sub	r8, 2
+	je	.LBB13_54
+	cmp	byte ptr [rcx], 32
+	jne	.LBB13_30
+.LBB13_54:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rcx
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 2
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB13_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB13_124
+	mov	byte ptr [rbx], 8
+.LBB13_57:
+	mov	qword ptr [rbx + 8], 1
+.LBB13_58:
+	mov	qword ptr [rbx + 16], rax
+	jmp	.LBB13_22
+.LBB13_59:
+	lea	rcx, [rsi + 3]
+	mov	r8, rdx
+	sub	r8, 3
+	je	.LBB13_61
+	cmp	byte ptr [rcx], 32
+	jne	.LBB13_32
+.LBB13_61:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rcx
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 3
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	je	.LBB13_73
+.LBB13_62:
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB13_66
+.LBB13_63:
+	lea	rax, [r14 + r12]
+	inc	rax
+	jmp	.LBB13_125
+.LBB13_64:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 20
+	#APP
+	#NO_APP
+.LBB13_65:
+	mov	rdi, rsi
+	mov	rsi, rdx
+	call	optional_trait_methods::commands::unrecognized
+	mov	qword ptr [rbx + 8], 0
+.LBB13_66:
+	mov	byte ptr [rbx + 16], al
+	jmp	.LBB13_67
+.LBB13_68:
+	cmp	eax, 63
+	je	.LBB13_97
+	cmp	eax, 71
+	je	.LBB13_100
+	cmp	eax, 103
+	jne	.LBB13_65
+	cmp	rdx, 1
+	jne	.LBB13_104
+	mov	al, 5
+	jmp	.LBB13_21
+.LBB13_73:
+	lea	r14, [rsp + 80]
+	mov	rsi, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB13_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB13_124
+	mov	byte ptr [rbx], 8
+	mov	qword ptr [rbx + 8], 2
+	mov	qword ptr [rbx + 16], r15
+	mov	qword ptr [rbx + 24], rax
+	jmp	.LBB13_22
+.LBB13_76:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB13_78
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_65
+.LBB13_78:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num_or::<usize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB13_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB13_124
+	mov	byte ptr [rbx], 10
+	jmp	.LBB13_81
+.LBB13_82:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB13_84
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_65
+.LBB13_84:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB13_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB13_124
+	mov	byte ptr [rbx], 1
+	jmp	.LBB13_81
+.LBB13_87:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB13_89
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_107
+.LBB13_89:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB13_96
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB13_124
+	mov	byte ptr [rbx], 3
+.LBB13_81:
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB13_22
+.LBB13_92:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB13_94
+.LBB13_93:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_105
+.LBB13_94:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	jne	.LBB13_62
+	lea	r14, [rsp + 80]
+	mov	rsi, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	je	.LBB13_123
+.LBB13_96:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], cl
+	jmp	.LBB13_67
+.LBB13_97:
+	mov	rax, rdx
+	dec	rax
+	jne	.LBB13_116
+	mov	qword ptr [rsp], rdi
+	mov	qword ptr [rsp + 8], rax
+	mov	qword ptr [rsp + 16], 1
+	xor	ebp, ebp
+.LBB13_99:
+	mov	byte ptr [rbx], 2
+	mov	byte ptr [rbx + 1], bpl
+	mov	byte ptr [rbx + 2], al
+	jmp	.LBB13_22
+.LBB13_100:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB13_112
+	jmp	.LBB13_111
+.LBB13_101:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_65
+	mov	qword ptr [rbx + 8], 1
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+.LBB13_103:
+	mov	byte ptr [rbx], al
+	jmp	.LBB13_22
+.LBB13_104:
+	cmp	byte ptr [rdi], 32
+	je	.LBB13_7
+.LBB13_105:
+	cmp	eax, 63
+	je	.LBB13_115
+	cmp	eax, 71
+	jne	.LBB13_65
+	jmp	.LBB13_110
+.LBB13_107:
+	mov	r8d, eax
+	add	r8b, -63
+	je	.LBB13_115
+	rol	r8b, 5
+	movzx	r8d, r8b
+	cmp	r8d, 1
+	je	.LBB13_110
+	cmp	r8d, 7
+	jne	.LBB13_65
+	jmp	.LBB13_93
+.LBB13_110:
+	lea	rax, [rdx - 1]
+.LBB13_111:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_65
+.LBB13_112:
+	lea	rsi, [rsp + 152]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::regs::<isize>
+	mov	rcx, qword ptr [r14 + 8]
+	mov	al, byte ptr [r14 + 16]
+	cmp	byte ptr [r14], 0
+	jne	.LBB13_119
+	movups	xmm0, xmmword ptr [rsp + 17]
+	movups	xmm1, xmmword ptr [rsp + 33]
+	movups	xmm2, xmmword ptr [rsp + 49]
+	movups	xmm3, xmmword ptr [rsp + 64]
+	movups	xmmword ptr [rsp + 127], xmm3
+	movaps	xmmword ptr [rsp + 112], xmm2
+	movaps	xmmword ptr [rsp + 96], xmm1
+	movaps	xmmword ptr [rsp + 80], xmm0
+	cmp	qword ptr [rsp + 160], 0
+	je	.LBB13_120
+	mov	rax, qword ptr [rsp + 168]
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB13_8
+.LBB13_115:
+	lea	rax, [rdx - 1]
+.LBB13_116:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_65
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	test	rax, rax
+	setne	bpl
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::names
+	mov	al, byte ptr [r14 + 8]
+	cmp	al, -1
+	je	.LBB13_121
+	mov	rcx, qword ptr [rsp + 80]
+.LBB13_119:
+	mov	qword ptr [rbx + 8], rcx
+	jmp	.LBB13_66
+.LBB13_120:
+	mov	qword ptr [rbx + 8], rcx
+	mov	byte ptr [rbx + 16], al
+	movaps	xmm0, xmmword ptr [rsp + 80]
+	movaps	xmm1, xmmword ptr [rsp + 96]
+	movaps	xmm2, xmmword ptr [rsp + 112]
+	movups	xmmword ptr [rbx + 17], xmm0
+	movups	xmmword ptr [rbx + 33], xmm1
+	movups	xmmword ptr [rbx + 49], xmm2
+	movups	xmm0, xmmword ptr [rsp + 127]
+	movups	xmmword ptr [rbx + 64], xmm0
+	mov	al, 6
+	jmp	.LBB13_21
+.LBB13_121:
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB13_124
+	mov	al, byte ptr [rsp + 80]
+	jmp	.LBB13_99
+.LBB13_123:
+	cmp	qword ptr [rsp + 8], 0
+	je	.LBB13_126
+.LBB13_124:
+	mov	rax, qword ptr [rsp + 16]
+.LBB13_125:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], 3
+.LBB13_67:
+	mov	byte ptr [rbx], -1
+.LBB13_22:
+	add	rsp, 176
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB13_126:
+	mov	byte ptr [rbx], 4
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB13_58
+.Lfunc_end13:
+.LJTI13_0:
+	.long	.LBB13_48-.LJTI13_0
+	.long	.LBB13_65-.LJTI13_0
+	.long	.LBB13_87-.LJTI13_0
+	.long	.LBB13_82-.LJTI13_0
+	.long	.LBB13_65-.LJTI13_0
+	.long	.LBB13_76-.LJTI13_0
+	.long	.LBB13_65-.LJTI13_0
+	.long	.LBB13_92-.LJTI13_0
+
+main:
+.Lfunc_begin14:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 3608
+	cmp	rdi, 2
+	jl	.LBB14_104
+	mov	rbx, rsi
+	mov	ebp, 1
+	lea	r12, [rsp + 1032]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	mov	dword ptr [rsp + 28], eax
+	mov	qword ptr [rsp + 200], 0
+	mov	qword ptr [rsp + 424], 0
+	mov	qword ptr [rsp + 304], 0
+	mov	qword ptr [rsp + 312], 0
+	mov	qword ptr [rsp + 288], 0
+	mov	qword ptr [rsp + 432], 0
+	mov	qword ptr [rsp + 296], rsi
+	mov	qword ptr [rsp + 16], rdi
+.LBB14_2:
+	mov	r13, qword ptr [rbx + 8*rbp]
+	mov	rdi, r13
+	call	rcx
+	mov	rbx, rax
+	test	rax, rax
+	je	.LBB14_7
+	xor	r15d, r15d
+.LBB14_4:
+	cmp	byte ptr [r13 + r15], 61
+	je	.LBB14_9
+	inc	r15
+	cmp	rbx, r15
+	jne	.LBB14_4
+	mov	qword ptr [rsp + 112], 0
+	mov	r15, rbx
+	jmp	.LBB14_8
+.LBB14_7:
+	mov	qword ptr [rsp + 112], 0
+	xor	r15d, r15d
+.LBB14_8:
+	jmp	.LBB14_10
+.LBB14_9:
+	lea	rax, [r15 + 1]
+	mov	rcx, rbx
+	sub	rcx, rax
+	mov	qword ptr [rsp + 464], rcx
+	lea	rax, [r15 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 112], rax
+.LBB14_10:
+	mov	qword ptr [rsp], rbp
+	lea	rax, [rbp + 1]
+	mov	qword ptr [rsp + 8], rax
+	mov	qword ptr [rsp + 1016], 0
+	mov	qword ptr [rsp + 1024], 7
+	mov	edx, 168
+	mov	rdi, r12
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.24]
+	call	r14
+	mov	r14d, 32
+.LBB14_11:
+	cmp	qword ptr [rsp + r14 + 1008], r15
+	jne	.LBB14_14
+	movzx	r12d, byte ptr [rsp + r14 + 1016]
+	mov	rbp, qword ptr [rsp + r14 + 1000]
+	mov	rdi, rbp
+	mov	rsi, r13
+	mov	rdx, r15
+	call	qword ptr [rip + bcmp@GOTPCREL]
+	test	eax, eax
+	jne	.LBB14_14
+	cmp	r12d, 255
+	jne	.LBB14_15
+.LBB14_14:
+	add	r14, 24
+	cmp	r14, 200
+	jne	.LBB14_11
+	jmp	.LBB14_228
+.LBB14_15:
+	cmp	r12, 255
+	je	.LBB14_228
+	cmp	r12b, 4
+	jae	.LBB14_19
+	mov	r11, qword ptr [rsp + 112]
+	test	r11, r11
+	mov	rbx, qword ptr [rsp + 296]
+	mov	r13, qword ptr [rsp + 16]
+	je	.LBB14_21
+	mov	r9, qword ptr [rsp + 8]
+	mov	r10, qword ptr [rsp + 464]
+	jmp	.LBB14_24
+.LBB14_19:
+	cmp	qword ptr [rsp + 112], 0
+	mov	rbx, qword ptr [rsp + 296]
+	mov	r13, qword ptr [rsp + 16]
+	jne	.LBB14_237
+	mov	r11d, 1
+	mov	r9, qword ptr [rsp + 8]
+	xor	r10d, r10d
+	jmp	.LBB14_24
+.LBB14_21:
+	cmp	qword ptr [rsp + 8], r13
+	mov	r14, qword ptr [rsp]
+	jge	.LBB14_238
+	mov	rdi, qword ptr [rbx + 8*r14 + 8]
+	test	rdi, rdi
+	je	.LBB14_238
+	mov	qword ptr [rsp + 112], rdi
+	call	qword ptr [rip + strlen@GOTPCREL]
+	mov	r11, qword ptr [rsp + 112]
+	mov	r10, rax
+	add	r14, 2
+	mov	r9, r14
+.LBB14_24:
+	cmp	r12b, 5
+	jne	.LBB14_26
+	mov	al, 1
+	mov	qword ptr [rsp + 288], rax
+	mov	rbp, r9
+	cmp	r9, r13
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 1032]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB14_2
+	jmp	.LBB14_240
+.LBB14_26:
+	movzx	eax, r12b
+	mov	eax, eax
+	lea	rcx, [rip + .LJTI14_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB14_27:
+	test	r10, r10
+	je	.LBB14_255
+	mov	qword ptr [rsp + 440], r11
+	mov	qword ptr [rsp + 448], r10
+	mov	dword ptr [rsp + 28], eax
+	cmp	word ptr [rsp + 200], 0
+	mov	ax, 1
+	jmp	.LBB14_77
+.LBB14_29:
+	mov	al, 1
+	mov	qword ptr [rsp + 312], rax
+	jmp	.LBB14_51
+.LBB14_30:
+	mov	qword ptr [rsp + 456], r10
+	mov	qword ptr [rsp + 424], r11
+	jmp	.LBB14_51
+.LBB14_31:
+	cmp	r10, 8
+	je	.LBB14_42
+	cmp	r10, 4
+	jne	.LBB14_256
+	mov	eax, 4
+	cmp	byte ptr [r11], 101
+	jne	.LBB14_257
+	cmp	byte ptr [r11 + 1], 120
+	jne	.LBB14_257
+	cmp	byte ptr [r11 + 2], 105
+	jne	.LBB14_257
+	cmp	byte ptr [r11 + 3], 116
+	jne	.LBB14_257
+	jmp	.LBB14_50
+.LBB14_37:
+	test	r10, r10
+	je	.LBB14_254
+	lea	rcx, [r11 + 1]
+	lea	rdi, [r10 - 1]
+	movzx	eax, byte ptr [r11]
+	cmp	eax, 43
+	je	.LBB14_52
+	cmp	eax, 45
+	jne	.LBB14_53
+	mov	sil, 1
+	jmp	.LBB14_54
+.LBB14_41:
+	mov	al, 1
+	mov	qword ptr [rsp + 432], rax
+	jmp	.LBB14_51
+.LBB14_42:
+	mov	eax, 8
+	cmp	byte ptr [r11], 99
+	jne	.LBB14_257
+	cmp	byte ptr [r11 + 1], 111
+	jne	.LBB14_257
+	cmp	byte ptr [r11 + 2], 110
+	jne	.LBB14_257
+	cmp	byte ptr [r11 + 3], 116
+	jne	.LBB14_257
+	cmp	byte ptr [r11 + 4], 105
+	jne	.LBB14_257
+	cmp	byte ptr [r11 + 5], 110
+	jne	.LBB14_257
+	cmp	byte ptr [r11 + 6], 117
+	jne	.LBB14_257
+	cmp	byte ptr [r11 + 7], 101
+	jne	.LBB14_257
+.LBB14_50:
+	cmp	r10, 8
+	setne	al
+	mov	qword ptr [rsp + 304], rax
+.LBB14_51:
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 1032]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	rbp, r9
+	jmp	.LBB14_78
+.LBB14_52:
+	xor	esi, esi
+	jmp	.LBB14_54
+.LBB14_53:
+	xor	esi, esi
+	mov	rdi, r10
+	mov	rcx, r11
+.LBB14_54:
+	cmp	rdi, 1
+	jbe	.LBB14_61
+	mov	al, byte ptr [rcx]
+	cmp	al, 48
+	jne	.LBB14_63
+	movzx	eax, byte ptr [rcx + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB14_64
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB14_80
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB14_79
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB14_64
+	mov	r8b, 16
+	mov	r12b, 1
+	jmp	.LBB14_82
+.LBB14_61:
+	test	rdi, rdi
+	je	.LBB14_269
+	mov	al, byte ptr [rcx]
+.LBB14_63:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB14_269
+.LBB14_64:
+	add	rdi, rcx
+	mov	rax, rdi
+	dec	rax
+	je	.LBB14_66
+	cmp	byte ptr [rax], 95
+	je	.LBB14_269
+.LBB14_66:
+	xor	r14d, r14d
+	xor	eax, eax
+.LBB14_67:
+	cmp	rcx, rdi
+	je	.LBB14_75
+	mov	r8b, byte ptr [rcx]
+	inc	rcx
+	cmp	r8b, 95
+	jne	.LBB14_70
+	test	r14b, 1
+	mov	r14d, 0
+	je	.LBB14_269
+	jmp	.LBB14_67
+.LBB14_70:
+	add	r8b, -48
+	cmp	r8b, 9
+	ja	.LBB14_269
+	mov	dx, 10
+	mul	dx
+	jo	.LBB14_269
+	movzx	edx, r8b
+	mov	r14b, 1
+	test	sil, sil
+	je	.LBB14_74
+	sub	ax, dx
+	jb	.LBB14_269
+	jmp	.LBB14_67
+.LBB14_74:
+	add	ax, dx
+	jb	.LBB14_269
+	jmp	.LBB14_67
+.LBB14_75:
+	mov	dword ptr [rsp + 28], eax
+.LBB14_76:
+	cmp	word ptr [rsp + 200], 0
+	mov	ax, 2
+.LBB14_77:
+	mov	qword ptr [rsp + 200], rax
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 1032]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	rbp, r9
+	jne	.LBB14_250
+.LBB14_78:
+	cmp	rbp, r13
+	jl	.LBB14_2
+	jmp	.LBB14_239
+.LBB14_79:
+	mov	r8b, 8
+	jmp	.LBB14_81
+.LBB14_80:
+	mov	r8b, 2
+.LBB14_81:
+	xor	r12d, r12d
+.LBB14_82:
+	mov	r14, r10
+	cmp	rdi, 2
+	je	.LBB14_268
+	lea	r10, [rcx + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jb	.LBB14_87
+	test	r12b, r12b
+	je	.LBB14_268
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB14_268
+	add	al, -87
+	mov	edx, eax
+.LBB14_87:
+	cmp	dl, r8b
+	jae	.LBB14_268
+	add	rcx, rdi
+	mov	rax, rcx
+	dec	rax
+	je	.LBB14_90
+	cmp	byte ptr [rax], 95
+	je	.LBB14_268
+.LBB14_90:
+	movzx	edi, r8b
+	xor	eax, eax
+	mov	dword ptr [rsp + 28], 0
+.LBB14_91:
+	cmp	r10, rcx
+	je	.LBB14_76
+	mov	dl, byte ptr [r10]
+	inc	r10
+	cmp	dl, 95
+	jne	.LBB14_94
+	test	al, 1
+	mov	eax, 0
+	je	.LBB14_268
+	jmp	.LBB14_91
+.LBB14_94:
+	mov	qword ptr [rsp + 112], r11
+	lea	r11d, [rdx - 48]
+	cmp	r11b, 10
+	jb	.LBB14_98
+	test	r12b, r12b
+	je	.LBB14_267
+	or	dl, 32
+	lea	eax, [rdx - 97]
+	cmp	al, 5
+	ja	.LBB14_267
+	add	dl, -87
+	mov	r11d, edx
+.LBB14_98:
+	cmp	r11b, r8b
+	jae	.LBB14_267
+	mov	eax, dword ptr [rsp + 28]
+	mul	di
+	mov	dword ptr [rsp + 28], eax
+	jo	.LBB14_267
+	movzx	edx, r11b
+	mov	al, 1
+	test	sil, sil
+	je	.LBB14_102
+	mov	r11d, dword ptr [rsp + 28]
+	sub	r11w, dx
+	jmp	.LBB14_103
+.LBB14_102:
+	mov	r11d, dword ptr [rsp + 28]
+	add	r11w, dx
+.LBB14_103:
+	mov	dword ptr [rsp + 28], r11d
+	mov	r11, qword ptr [rsp + 112]
+	jb	.LBB14_268
+	jmp	.LBB14_91
+.LBB14_104:
+	xor	r14d, r14d
+	xor	ebp, ebp
+.LBB14_105:
+	xorps	xmm0, xmm0
+	lea	r12, [rsp + 560]
+	movups	xmmword ptr [r12 + 328], xmm0
+	movups	xmmword ptr [r12 + 312], xmm0
+	movups	xmmword ptr [r12 + 296], xmm0
+	xor	ebx, ebx
+	mov	qword ptr [r12 + 344], rbx
+	lea	rdi, [rsp + 1032]
+	mov	r13, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 272
+	xor	esi, esi
+	call	r13
+	mov	qword ptr [r12 + 288], r14
+	mov	qword ptr [r12 + 352], 1
+	movabs	rax, 9223372036854775807
+	lea	rcx, [rax + 1]
+	mov	qword ptr [r12 + 360], rcx
+	mov	qword ptr [r12 + 368], rax
+	mov	qword ptr [r12 - 8], rbx
+	lea	r15, [rsp + 1016]
+	mov	edx, 288
+	mov	rdi, r12
+	mov	rsi, r15
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 2056]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 1016]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 3096]
+	mov	edx, 512
+	xor	esi, esi
+	call	r13
+	mov	eax, ebp
+	shr	eax, 8
+	mov	dword ptr [rsp + 112], eax
+	mov	r13, qword ptr [rip + read@GOTPCREL]
+	xor	ecx, ecx
+	mov	dword ptr [rsp + 8], eax
+	mov	dword ptr [rsp], ebp
+.LBB14_106:
+	mov	rdi, qword ptr [rsp + 2040]
+	mov	rax, qword ptr [rsp + 2048]
+	xor	r12d, r12d
+	mov	rdx, rcx
+	xor	ebp, ebp
+.LBB14_107:
+	mov	rsi, rax
+	mov	rax, rdi
+.LBB14_108:
+	cmp	rsi, rax
+	jb	.LBB14_112
+	mov	qword ptr [rsp + 3080], rbx
+	cmp	rdx, 1025
+	mov	rsi, r14
+	mov	eax, 1
+	cmovae	rsi, rax
+	cmovae	rdx, rbx
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 1024
+	xor	edi, edi
+	mov	rsi, r15
+	call	r13
+	test	rax, rax
+	js	.LBB14_122
+	je	.LBB14_123
+	mov	qword ptr [rsp + 2040], rax
+	mov	qword ptr [rsp + 2048], 0
+	xor	ecx, ecx
+	xor	edx, edx
+	xor	esi, esi
+.LBB14_112:
+	cmp	rax, rsi
+	jb	.LBB14_232
+	cmp	rax, 1024
+	ja	.LBB14_232
+	cmp	rsi, rax
+	je	.LBB14_108
+	inc	rsi
+	mov	rdi, rax
+	neg	rdi
+.LBB14_116:
+	mov	r8b, byte ptr [rsp + rsi + 1015]
+	mov	qword ptr [rsp + 2048], rsi
+	cmp	r8b, 10
+	je	.LBB14_126
+	cmp	rbp, 511
+	jbe	.LBB14_119
+	mov	r12b, 1
+	jmp	.LBB14_120
+.LBB14_119:
+	mov	byte ptr [rsp + rbp + 3096], r8b
+	inc	rbp
+.LBB14_120:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB14_116
+	mov	rdi, rax
+	jmp	.LBB14_107
+.LBB14_122:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB14_123:
+	test	rbp, rbp
+	setne	al
+	or	al, r12b
+	test	al, 1
+	je	.LBB14_236
+	test	r12b, 1
+	jne	.LBB14_130
+	mov	ecx, 0
+	mov	edx, 0
+	cmp	rbp, 512
+	ja	.LBB14_233
+	jmp	.LBB14_128
+.LBB14_126:
+	test	r12b, 1
+	jne	.LBB14_130
+	cmp	rbp, 512
+	ja	.LBB14_232
+.LBB14_128:
+	test	rbp, rbp
+	setne	al
+	test	byte ptr [rsp + 112], al
+	jne	.LBB14_131
+	test	rbp, rbp
+	je	.LBB14_106
+	jmp	.LBB14_132
+.LBB14_130:
+	mov	qword ptr [rsp + 128], 3
+	mov	qword ptr [rsp + 136], 512
+	mov	byte ptr [rsp + 144], 4
+	mov	byte ptr [rsp + 120], -1
+	lea	rdi, [rsp + 472]
+	mov	rsi, r14
+	lea	rdx, [rsp + 120]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	mov	ebp, dword ptr [rsp]
+	mov	r12d, dword ptr [rsp + 8]
+	jmp	.LBB14_220
+.LBB14_131:
+	mov	rdi, r14
+	lea	rsi, [rsp + 3096]
+	mov	rdx, rbp
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB14_132:
+	lea	r12, [rsp + 936]
+	mov	rdi, r12
+	lea	rsi, [rsp + 3096]
+	mov	rdx, rbp
+	call	<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget> as optional_trait_methods::state_machine::Controller>::parse_command
+	cmp	byte ptr [rsp + 936], -1
+	je	.LBB14_137
+	mov	ecx, 10
+	lea	rdi, [rsp + 208]
+	mov	rsi, r12
+	rep movsq es:[rdi], [rsi]
+	movzx	r12d, byte ptr [rsp + 208]
+	lea	rbp, [r12 - 6]
+	cmp	r12, 7
+	cmovb	rbp, rbx
+	lea	rcx, [rip + .LJTI14_5]
+	movsxd	rax, dword ptr [rcx + 4*rbp]
+	add	rax, rcx
+	jmp	rax
+.LBB14_134:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 15
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::checkpoint
+	mov	rdx, qword ptr [rsp + 32]
+	cmp	rdx, 1
+	jne	.LBB14_138
+	mov	rax, qword ptr [rsp + 40]
+	test	rax, rax
+	je	.LBB14_138
+	mov	rcx, qword ptr [rsp + 48]
+	mov	qword ptr [rsp + 128], 0
+	mov	qword ptr [rsp + 136], rax
+	mov	qword ptr [rsp + 144], rcx
+	mov	byte ptr [rsp + 120], -1
+	jmp	.LBB14_190
+.LBB14_137:
+	mov	eax, dword ptr [rsp + 952]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rdx, qword ptr [rsp + 296]
+	cmovne	rdx, qword ptr [rsp + 944]
+	sete	cl
+	mov	r12d, dword ptr [rsp + 8]
+	movzx	r12d, r12b
+	cmovne	r12d, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 128], rcx
+	mov	qword ptr [rsp + 296], rdx
+	mov	qword ptr [rsp + 136], rdx
+	mov	byte ptr [rsp + 144], r12b
+	mov	byte ptr [rsp + 120], -1
+	lea	rdi, [rsp + 472]
+	mov	rsi, r14
+	lea	rdx, [rsp + 120]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	mov	ebp, dword ptr [rsp]
+	jmp	.LBB14_220
+.LBB14_138:
+	lea	rcx, [rip + .LJTI14_7]
+	movsxd	rax, dword ptr [rcx + 4*rbp]
+	add	rax, rcx
+	jmp	rax
+.LBB14_139:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI14_8]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB14_140:
+	mov	al, 1
+	mov	rdi, qword ptr [rsp + 848]
+	jmp	.LBB14_202
+.LBB14_141:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 14
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [rsp + 216]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::undo
+	jmp	.LBB14_146
+.LBB14_142:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI14_6]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB14_143:
+	mov	rax, qword ptr [rsp + 848]
+	jmp	.LBB14_184
+.LBB14_144:
+	cmp	dword ptr [rsp + 216], 2
+	jne	.LBB14_134
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 21
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [rsp + 224]
+	mov	rcx, qword ptr [rsp + 232]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_bounds
+.LBB14_146:
+	cmp	dword ptr [rsp + 32], 1
+	jne	.LBB14_196
+	mov	rax, qword ptr [rsp + 40]
+	test	rax, rax
+	mov	ebp, dword ptr [rsp]
+	mov	r12d, dword ptr [rsp + 8]
+	je	.LBB14_157
+.LBB14_148:
+	mov	rcx, qword ptr [rsp + 48]
+	mov	qword ptr [rsp + 128], 0
+	mov	qword ptr [rsp + 136], rax
+	mov	qword ptr [rsp + 144], rcx
+	jmp	.LBB14_158
+.LBB14_149:
+	mov	rax, qword ptr [rsp + 216]
+	test	rax, rax
+	mov	qword ptr [rsp + 16], rdx
+	je	.LBB14_163
+	cmp	eax, 1
+	mov	ebp, dword ptr [rsp]
+	mov	r12d, dword ptr [rsp + 8]
+	jne	.LBB14_167
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 21
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [rsp + 224]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_factor
+	jmp	.LBB14_168
+.LBB14_152:
+	mov	qword ptr [rsp + 16], rdx
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 13
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [rsp + 224]
+	cmp	byte ptr [rsp + 216], 0
+	lea	rsi, [rsp + 552]
+	lea	rdi, [rsp + 400]
+	je	.LBB14_159
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::rem
+	jmp	.LBB14_160
+.LBB14_154:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 16
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [rsp + 209]
+	test	eax, eax
+	mov	qword ptr [rsp + 16], rdx
+	je	.LBB14_164
+	cmp	eax, 1
+	mov	ebp, dword ptr [rsp]
+	jne	.LBB14_178
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
+	cmp	dword ptr [rsp + 32], 1
+	jmp	.LBB14_166
+.LBB14_157:
+	mov	qword ptr [rsp + 128], 2
+.LBB14_158:
+	mov	byte ptr [rsp + 120], -1
+	jmp	.LBB14_219
+.LBB14_159:
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::div
+.LBB14_160:
+	mov	ebp, dword ptr [rsp]
+	mov	r12d, dword ptr [rsp + 8]
+	cmp	dword ptr [rsp + 400], 1
+	jne	.LBB14_172
+	mov	rdx, qword ptr [rsp + 408]
+	test	rdx, rdx
+	je	.LBB14_173
+	mov	rsi, qword ptr [rsp + 416]
+	jmp	.LBB14_171
+.LBB14_163:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 13
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [rsp + 224]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::mul
+	jmp	.LBB14_165
+.LBB14_164:
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc
+.LBB14_165:
+	cmp	dword ptr [rsp + 32], 1
+	mov	ebp, dword ptr [rsp]
+.LBB14_166:
+	mov	r12d, dword ptr [rsp + 8]
+	je	.LBB14_169
+	jmp	.LBB14_172
+.LBB14_167:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 21
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [rsp + 224]
+	mov	rcx, qword ptr [rsp + 232]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_bounds
+.LBB14_168:
+	cmp	dword ptr [rsp + 32], 1
+	jne	.LBB14_172
+.LBB14_169:
+	mov	rdx, qword ptr [rsp + 40]
+	test	rdx, rdx
+	je	.LBB14_173
+.LBB14_170:
+	mov	rsi, qword ptr [rsp + 48]
+.LBB14_171:
+	mov	al, -1
+	mov	edi, 0
+	jmp	.LBB14_174
+.LBB14_172:
+	xor	eax, eax
+	jmp	.LBB14_218
+.LBB14_173:
+	mov	edi, 2
+	mov	al, -1
+	mov	esi, 0
+.LBB14_174:
+	cmp	byte ptr [rsp + 16], 0
+	jne	.LBB14_218
+.LBB14_175:
+	mov	qword ptr [rsp + 288], rdi
+	mov	qword ptr [rsp + 16], rsi
+	mov	qword ptr [rsp + 8], rdx
+	lea	rdi, [rsp + 32]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::rollback
+	cmp	dword ptr [rsp + 32], 1
+	jne	.LBB14_177
+	mov	rax, qword ptr [rsp + 40]
+	test	rax, rax
+	jne	.LBB14_148
+.LBB14_177:
+	mov	al, -1
+	mov	rdx, qword ptr [rsp + 8]
+	mov	rsi, qword ptr [rsp + 16]
+	mov	rdi, qword ptr [rsp + 288]
+	jmp	.LBB14_218
+.LBB14_178:
+	lea	r12, [rsp + 552]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc
+	cmp	dword ptr [rsp + 32], 1
+	jne	.LBB14_197
+	mov	rdx, qword ptr [rsp + 40]
+	test	rdx, rdx
+	je	.LBB14_226
+	mov	rsi, qword ptr [rsp + 48]
+	mov	al, -1
+	mov	edi, 0
+	cmp	byte ptr [rsp + 16], 0
+	mov	r12d, dword ptr [rsp + 8]
+	je	.LBB14_175
+	jmp	.LBB14_218
+.LBB14_181:
+	mov	rsi, qword ptr [rsp + 216]
+	mov	rdx, qword ptr [rsp + 224]
+	lea	rdi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::write_reg
+	jmp	.LBB14_196
+.LBB14_182:
+	mov	al, byte ptr [rsp + 210]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [rsp + 209], 0
+	mov	ecx, 63
+	cmove	eax, ecx
+	mov	byte ptr [rsp + 120], 2
+	mov	byte ptr [rsp + 121], al
+	jmp	.LBB14_190
+.LBB14_183:
+	mov	rsi, qword ptr [rsp + 216]
+	lea	rdi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+.LBB14_184:
+	mov	byte ptr [rsp + 120], 1
+	mov	qword ptr [rsp + 128], rax
+	jmp	.LBB14_190
+.LBB14_185:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 320], xmm0
+	movaps	xmmword ptr [rsp + 336], xmm0
+	movaps	xmmword ptr [rsp + 352], xmm0
+	movaps	xmmword ptr [rsp + 368], xmm0
+	mov	qword ptr [rsp + 384], 0
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB14_186:
+	lea	rdi, [rsp + 552]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	r12, 7
+	ja	.LBB14_188
+	mov	qword ptr [rsp + 8*r12 + 320], rax
+	mov	r12, qword ptr [rsp + 384]
+	inc	r12
+	mov	qword ptr [rsp + 384], r12
+.LBB14_188:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB14_186
+	mov	edx, 72
+	lea	rdi, [rsp + 39]
+	lea	rsi, [rsp + 320]
+	mov	r12, qword ptr [rip + memcpy@GOTPCREL]
+	call	r12
+	mov	byte ptr [rsp + 120], 3
+	mov	edx, 79
+	lea	rdi, [rsp + 121]
+	lea	rsi, [rsp + 32]
+	call	r12
+.LBB14_190:
+	mov	ebp, dword ptr [rsp]
+	mov	r12d, dword ptr [rsp + 8]
+	jmp	.LBB14_219
+.LBB14_191:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	mov	qword ptr [rsp + 96], 0
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB14_192:
+	lea	rdi, [rsp + 552]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	r12, 7
+	ja	.LBB14_194
+	mov	qword ptr [rsp + 8*r12 + 32], rax
+	mov	r12, qword ptr [rsp + 96]
+	inc	r12
+	mov	qword ptr [rsp + 96], r12
+.LBB14_194:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB14_192
+	lea	rdi, [rsp + 216]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB14_196:
+	mov	ebp, dword ptr [rsp]
+	mov	r12d, dword ptr [rsp + 8]
+	mov	byte ptr [rsp + 120], 0
+	jmp	.LBB14_219
+.LBB14_197:
+	lea	rdi, [rsp + 32]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
+	cmp	byte ptr [rsp + 32], 0
+	je	.LBB14_225
+	mov	rdx, qword ptr [rsp + 40]
+	test	rdx, rdx
+	mov	r12d, dword ptr [rsp + 8]
+	jne	.LBB14_170
+	jmp	.LBB14_227
+.LBB14_199:
+	mov	rsi, qword ptr [rsp + 216]
+	mov	rdx, qword ptr [rsp + 224]
+	lea	rdi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::write_reg
+	jmp	.LBB14_214
+.LBB14_200:
+	mov	al, byte ptr [rsp + 210]
+	and	al, 63
+	cmp	byte ptr [rsp + 209], 0
+	movzx	ecx, al
+	mov	eax, 63
+	cmove	ecx, eax
+	mov	al, 2
+	jmp	.LBB14_216
+.LBB14_201:
+	mov	rsi, qword ptr [rsp + 216]
+	lea	rdi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	mov	rdi, rax
+	mov	al, 1
+.LBB14_202:
+	jmp	.LBB14_215
+.LBB14_203:
+	mov	rsi, qword ptr [rsp + 216]
+	lea	rdi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::set_state
+	jmp	.LBB14_214
+.LBB14_204:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	mov	qword ptr [rsp + 96], 0
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB14_205:
+	lea	rdi, [rsp + 552]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	r12, 7
+	ja	.LBB14_207
+	mov	qword ptr [rsp + 8*r12 + 32], rax
+	mov	r12, qword ptr [rsp + 96]
+	inc	r12
+	mov	qword ptr [rsp + 96], r12
+.LBB14_207:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB14_205
+	mov	rdi, qword ptr [rsp + 32]
+	mov	rdx, qword ptr [rsp + 40]
+	mov	rsi, qword ptr [rsp + 48]
+	lea	rax, [rsp + 39]
+	movups	xmm0, xmmword ptr [rax + 17]
+	movups	xmm1, xmmword ptr [rax + 33]
+	movups	xmm2, xmmword ptr [rax + 49]
+	movaps	xmmword ptr [rsp + 320], xmm0
+	movaps	xmmword ptr [rsp + 336], xmm1
+	movaps	xmmword ptr [rsp + 352], xmm2
+	mov	al, 3
+	jmp	.LBB14_215
+.LBB14_209:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	mov	qword ptr [rsp + 96], 0
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB14_210:
+	lea	rdi, [rsp + 552]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	r12, 7
+	ja	.LBB14_212
+	mov	qword ptr [rsp + 8*r12 + 32], rax
+	mov	r12, qword ptr [rsp + 96]
+	inc	r12
+	mov	qword ptr [rsp + 96], r12
+.LBB14_212:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB14_210
+	lea	rdi, [rsp + 216]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB14_214:
+	xor	eax, eax
+.LBB14_215:
+.LBB14_216:
+	mov	ebp, dword ptr [rsp]
+.LBB14_217:
+	mov	r12d, dword ptr [rsp + 8]
+.LBB14_218:
+	mov	byte ptr [rsp + 120], al
+	mov	byte ptr [rsp + 121], cl
+	mov	qword ptr [rsp + 128], rdi
+	mov	qword ptr [rsp + 136], rdx
+	mov	qword ptr [rsp + 144], rsi
+	movaps	xmm0, xmmword ptr [rsp + 320]
+	movaps	xmm1, xmmword ptr [rsp + 336]
+	movaps	xmm2, xmmword ptr [rsp + 352]
+	lea	rax, [rsp + 121]
+	movups	xmmword ptr [rax + 63], xmm2
+	movups	xmmword ptr [rax + 47], xmm1
+	movups	xmmword ptr [rax + 31], xmm0
+.LBB14_219:
+	lea	rdi, [rsp + 472]
+	mov	rsi, r14
+	lea	rdx, [rsp + 120]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB14_220:
+	cmp	byte ptr [rsp + 472], -1
+	jne	.LBB14_224
+	mov	rax, qword ptr [rsp + 480]
+	test	bpl, bpl
+	jne	.LBB14_242
+	cmp	eax, 1
+	je	.LBB14_245
+	cmp	eax, 4
+	je	.LBB14_244
+.LBB14_224:
+	mov	dword ptr [rsp + 8], r12d
+	mov	rcx, qword ptr [rsp + 3080]
+	jmp	.LBB14_106
+.LBB14_225:
+	xor	eax, eax
+	jmp	.LBB14_217
+.LBB14_226:
+	lea	rdi, [rsp + 32]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
+	cmp	byte ptr [rsp + 32], 0
+	mov	r12d, dword ptr [rsp + 8]
+	jne	.LBB14_169
+.LBB14_227:
+	mov	edi, 4
+	mov	al, -1
+	jmp	.LBB14_174
+.LBB14_228:
+	xor	ecx, ecx
+	mov	rbp, r13
+	mov	r15, rbx
+.LBB14_229:
+.LBB14_230:
+	lea	rdi, [rsp + 1016]
+	mov	qword ptr [rdi], rcx
+	mov	qword ptr [rdi + 8], rbp
+	mov	qword ptr [rdi + 16], r15
+	mov	qword ptr [rdi + 24], r11
+	mov	qword ptr [rdi + 32], r10
+.LBB14_231:
+	call	optional_trait_methods::usage_error
+	mov	ebx, 1
+	jmp	.LBB14_235
+.LBB14_232:
+	mov	rdx, rcx
+.LBB14_233:
+	xor	eax, eax
+	mov	qword ptr [rsp + 3080], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, 1
+	mov	rsi, r14
+.LBB14_234:
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+.LBB14_235:
+	mov	rax, rbx
+	add	rsp, 3608
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB14_236:
+	xor	edx, edx
+	jmp	.LBB14_233
+.LBB14_237:
+	mov	ecx, 3
+	jmp	.LBB14_229
+.LBB14_238:
+	mov	ecx, 1
+	jmp	.LBB14_229
+.LBB14_239:
+	test	byte ptr [rsp + 288], 1
+	je	.LBB14_248
+.LBB14_240:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.73]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 31
+.LBB14_241:
+	mov	edi, 2
+	call	r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	ebx, 1
+	mov	edx, 1
+	mov	edi, 2
+	call	r14
+	jmp	.LBB14_235
+.LBB14_242:
+	cmp	eax, 1
+	je	.LBB14_245
+	cmp	eax, 4
+	jne	.LBB14_247
+.LBB14_244:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 2056]
+	mov	edx, 23
+	jmp	.LBB14_246
+.LBB14_245:
+	mov	rsi, qword ptr [rsp + 488]
+	mov	rdx, qword ptr [rsp + 496]
+	lea	rdi, [rsp + 2056]
+.LBB14_246:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 2056]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB14_247:
+	mov	ebx, 1
+	mov	rdx, qword ptr [rsp + 3080]
+	jmp	.LBB14_233
+.LBB14_248:
+	test	byte ptr [rsp + 432], 1
+	je	.LBB14_251
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	xor	ebx, ebx
+	mov	edx, 823
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 1
+	jmp	.LBB14_234
+.LBB14_250:
+	mov	ecx, 4
+	jmp	.LBB14_229
+.LBB14_251:
+	mov	r15, qword ptr [rsp + 424]
+	test	r15, r15
+	je	.LBB14_258
+	mov	rdi, r15
+	mov	rbx, qword ptr [rsp + 456]
+	mov	rsi, rbx
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB14_262
+	mov	r14, rdx
+	jmp	.LBB14_259
+.LBB14_254:
+	xor	r10d, r10d
+	jmp	.LBB14_269
+.LBB14_255:
+	mov	ecx, 2
+	xor	r10d, r10d
+	jmp	.LBB14_230
+.LBB14_256:
+	mov	rax, r10
+.LBB14_257:
+	mov	ecx, 2
+	mov	r10, rax
+	jmp	.LBB14_230
+.LBB14_258:
+	xor	r14d, r14d
+.LBB14_259:
+	mov	rax, qword ptr [rsp + 200]
+	test	ax, ax
+	je	.LBB14_263
+	movzx	eax, ax
+	cmp	eax, 1
+	jne	.LBB14_264
+	mov	rdi, qword ptr [rsp + 440]
+	mov	rsi, qword ptr [rsp + 448]
+	call	<optional_trait_methods::connection::Listener>::unix
+	mov	ebx, edx
+	jmp	.LBB14_265
+.LBB14_262:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.19]
+	lea	rdi, [rsp + 1016]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 15
+	mov	qword ptr [rdi + 24], r15
+	mov	qword ptr [rdi + 32], rbx
+	mov	qword ptr [rdi], 2
+	jmp	.LBB14_231
+.LBB14_263:
+	movzx	eax, byte ptr [rsp + 312]
+	shl	eax, 8
+	movzx	ebp, byte ptr [rsp + 304]
+	or	ebp, eax
+	jmp	.LBB14_105
+.LBB14_264:
+	mov	ebp, dword ptr [rsp + 28]
+	mov	edi, ebp
+	call	<optional_trait_methods::connection::Listener>::tcp_loopback
+	mov	ebx, edx
+	test	bp, bp
+	setne	cl
+	or	cl, al
+	test	cl, 1
+	je	.LBB14_270
+.LBB14_265:
+	cmp	eax, 1
+	jne	.LBB14_271
+	mov	r15d, 1
+	mov	ebp, ebx
+	jmp	.LBB14_272
+.LBB14_267:
+	mov	r11, qword ptr [rsp + 112]
+.LBB14_268:
+	mov	r10, r14
+.LBB14_269:
+	mov	ecx, 2
+	jmp	.LBB14_230
+.LBB14_270:
+	mov	edi, ebx
+	call	optional_trait_methods::report_port
+.LBB14_271:
+	mov	edi, ebx
+	call	<optional_trait_methods::connection::Listener>::accept
+	mov	r15d, eax
+	mov	ebp, edx
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+.LBB14_272:
+	test	r15b, 1
+	je	.LBB14_274
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.72]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 29
+	jmp	.LBB14_241
+.LBB14_274:
+	xorps	xmm0, xmm0
+	lea	r12, [rsp + 560]
+	movups	xmmword ptr [r12 + 328], xmm0
+	movups	xmmword ptr [r12 + 312], xmm0
+	movups	xmmword ptr [r12 + 296], xmm0
+	xor	ebx, ebx
+	mov	qword ptr [r12 + 344], rbx
+	lea	rdi, [rsp + 1032]
+	mov	r13, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 272
+	xor	esi, esi
+	call	r13
+	mov	qword ptr [r12 + 288], r14
+	mov	qword ptr [r12 + 352], 1
+	movabs	rax, 9223372036854775807
+	lea	rcx, [rax + 1]
+	mov	qword ptr [r12 + 360], rcx
+	mov	qword ptr [r12 + 368], rax
+	mov	qword ptr [r12 - 8], rbx
+	lea	r15, [rsp + 1016]
+	mov	edx, 288
+	mov	rdi, r12
+	mov	rsi, r15
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 2056]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r13
+	mov	dword ptr [r14 + 1032], ebp
+	lea	rdi, [rsp + 1016]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 3096]
+	mov	edx, 512
+	xor	esi, esi
+	call	r13
+	xor	edx, edx
+	mov	dword ptr [rsp + 112], eax
+.LBB14_275:
+	mov	rdi, qword ptr [rsp + 2040]
+	mov	rcx, qword ptr [rsp + 2048]
+	xor	r13d, r13d
+	mov	rax, rdx
+	xor	r12d, r12d
+.LBB14_276:
+	mov	rsi, rcx
+	mov	rcx, rdi
+.LBB14_277:
+	cmp	rsi, rcx
+	jb	.LBB14_281
+	mov	qword ptr [rsp + 3080], rbx
+	cmp	rax, 1025
+	mov	rsi, r14
+	mov	ecx, 1
+	cmovae	rsi, rcx
+	cmovae	rax, rbx
+	mov	edi, ebp
+	mov	rdx, rax
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	lea	rdi, [rsp + 120]
+	mov	esi, ebp
+	mov	rdx, r15
+	call	optional_trait_methods::connection::read_fd
+	cmp	byte ptr [rsp + 120], 0
+	jne	.LBB14_291
+	mov	rcx, qword ptr [rsp + 128]
+	test	rcx, rcx
+	je	.LBB14_291
+	mov	qword ptr [rsp + 2040], rcx
+	mov	qword ptr [rsp + 2048], 0
+	xor	edx, edx
+	xor	eax, eax
+	xor	esi, esi
+.LBB14_281:
+	cmp	rcx, rsi
+	jb	.LBB14_387
+	cmp	rcx, 1024
+	ja	.LBB14_387
+	cmp	rsi, rcx
+	je	.LBB14_277
+	inc	rsi
+	mov	rdi, rcx
+	neg	rdi
+.LBB14_285:
+	mov	r8b, byte ptr [rsp + rsi + 1015]
+	mov	qword ptr [rsp + 2048], rsi
+	cmp	r8b, 10
+	je	.LBB14_294
+	cmp	r12, 511
+	jbe	.LBB14_288
+	mov	r13b, 1
+	jmp	.LBB14_289
+.LBB14_288:
+	mov	byte ptr [rsp + r12 + 3096], r8b
+	inc	r12
+.LBB14_289:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB14_285
+	mov	rdi, rcx
+	jmp	.LBB14_276
+.LBB14_291:
+	test	r12, r12
+	setne	al
+	or	al, r13b
+	test	al, 1
+	je	.LBB14_386
+	test	r13b, 1
+	jne	.LBB14_298
+	mov	edx, 0
+	cmp	r12, 512
+	jbe	.LBB14_296
+	jmp	.LBB14_386
+.LBB14_294:
+	test	r13b, 1
+	jne	.LBB14_298
+	cmp	r12, 512
+	ja	.LBB14_387
+.LBB14_296:
+	test	r12, r12
+	setne	al
+	test	byte ptr [rsp + 312], al
+	jne	.LBB14_299
+	test	r12, r12
+	je	.LBB14_275
+	jmp	.LBB14_300
+.LBB14_298:
+	mov	qword ptr [rsp + 128], 3
+	mov	qword ptr [rsp + 136], 512
+	mov	byte ptr [rsp + 144], 4
+	jmp	.LBB14_326
+.LBB14_299:
+	mov	rdi, r14
+	lea	rsi, [rsp + 3096]
+	mov	rdx, r12
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB14_300:
+	lea	rdi, [rsp + 936]
+	lea	rsi, [rsp + 3096]
+	mov	rdx, r12
+	call	<optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget> as optional_trait_methods::state_machine::Controller>::parse_command
+	cmp	byte ptr [rsp + 936], -1
+	je	.LBB14_308
+	mov	ecx, 10
+	lea	rdi, [rsp + 208]
+	lea	rsi, [rsp + 936]
+	rep movsq es:[rdi], [rsi]
+	movzx	r12d, byte ptr [rsp + 208]
+	lea	r13, [r12 - 6]
+	cmp	r12, 7
+	cmovb	r13, rbx
+	lea	rcx, [rip + .LJTI14_1]
+	movsxd	rax, dword ptr [rcx + 4*r13]
+	add	rax, rcx
+	jmp	rax
+.LBB14_302:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 15
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::checkpoint
+	mov	rbp, qword ptr [rsp + 32]
+	cmp	rbp, 1
+	jne	.LBB14_304
+	mov	rax, qword ptr [rsp + 40]
+	test	rax, rax
+	jne	.LBB14_316
+.LBB14_304:
+	lea	rcx, [rip + .LJTI14_3]
+	movsxd	rax, dword ptr [rcx + 4*r13]
+	add	rax, rcx
+	jmp	rax
+.LBB14_305:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI14_4]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB14_306:
+	mov	al, 1
+	mov	rcx, qword ptr [rsp + 848]
+	mov	qword ptr [rsp], rcx
+	jmp	.LBB14_307
+.LBB14_308:
+	mov	eax, dword ptr [rsp + 952]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rdx, qword ptr [rsp + 8]
+	cmovne	rdx, qword ptr [rsp + 944]
+	sete	cl
+	mov	esi, dword ptr [rsp + 112]
+	movzx	esi, sil
+	cmovne	esi, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 128], rcx
+	mov	qword ptr [rsp + 8], rdx
+	mov	qword ptr [rsp + 136], rdx
+	mov	dword ptr [rsp + 112], esi
+	mov	byte ptr [rsp + 144], sil
+	jmp	.LBB14_326
+.LBB14_309:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI14_2]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB14_310:
+	mov	rax, qword ptr [rsp + 848]
+	jmp	.LBB14_348
+.LBB14_311:
+	cmp	dword ptr [rsp + 216], 2
+	jne	.LBB14_302
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 21
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [rsp + 224]
+	mov	rcx, qword ptr [rsp + 232]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_bounds
+	jmp	.LBB14_314
+.LBB14_313:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 14
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [rsp + 216]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::undo
+.LBB14_314:
+	cmp	dword ptr [rsp + 32], 1
+	jne	.LBB14_360
+	mov	rax, qword ptr [rsp + 40]
+	test	rax, rax
+	je	.LBB14_325
+.LBB14_316:
+	mov	rcx, qword ptr [rsp + 48]
+	mov	qword ptr [rsp + 128], 0
+	mov	qword ptr [rsp + 136], rax
+	mov	qword ptr [rsp + 144], rcx
+.LBB14_326:
+	mov	byte ptr [rsp + 120], -1
+.LBB14_381:
+	lea	rdi, [rsp + 472]
+	mov	rsi, r14
+	lea	rdx, [rsp + 120]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	cmp	byte ptr [rsp + 472], -1
+	jne	.LBB14_385
+	mov	rax, qword ptr [rsp + 480]
+	cmp	byte ptr [rsp + 304], 0
+	jne	.LBB14_388
+	cmp	eax, 1
+	je	.LBB14_391
+	cmp	eax, 4
+	je	.LBB14_390
+.LBB14_385:
+	mov	ebp, dword ptr [rsp + 3088]
+	mov	rdx, qword ptr [rsp + 3080]
+	jmp	.LBB14_275
+.LBB14_317:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 16
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [rsp + 209]
+	lea	rdi, [rsp + 32]
+	lea	rsi, [rsp + 552]
+	test	eax, eax
+	je	.LBB14_331
+	cmp	eax, 1
+	jne	.LBB14_332
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
+	jmp	.LBB14_337
+.LBB14_320:
+	mov	rax, qword ptr [rsp + 216]
+	test	rax, rax
+	je	.LBB14_335
+	cmp	eax, 1
+	jne	.LBB14_336
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 21
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [rsp + 224]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_factor
+	jmp	.LBB14_337
+.LBB14_323:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 13
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [rsp + 224]
+	cmp	byte ptr [rsp + 216], 0
+	je	.LBB14_327
+	lea	rdi, [rsp + 400]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::rem
+	jmp	.LBB14_328
+.LBB14_325:
+	mov	qword ptr [rsp + 128], 2
+	jmp	.LBB14_326
+.LBB14_327:
+	lea	rdi, [rsp + 400]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::div
+.LBB14_328:
+	cmp	dword ptr [rsp + 400], 1
+	jne	.LBB14_378
+	mov	r12, qword ptr [rsp + 408]
+	test	r12, r12
+	je	.LBB14_341
+	mov	r13, qword ptr [rsp + 416]
+	jmp	.LBB14_340
+.LBB14_331:
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc
+	jmp	.LBB14_337
+.LBB14_332:
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc
+	cmp	dword ptr [rsp + 32], 1
+	jne	.LBB14_361
+	mov	r12, qword ptr [rsp + 40]
+	test	r12, r12
+	jne	.LBB14_339
+	lea	rdi, [rsp + 32]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
+	cmp	byte ptr [rsp + 32], 0
+	jne	.LBB14_338
+	jmp	.LBB14_363
+.LBB14_335:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 13
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [rsp + 224]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::mul
+	jmp	.LBB14_337
+.LBB14_336:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 21
+	lea	rdi, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rdx, qword ptr [rsp + 224]
+	mov	rcx, qword ptr [rsp + 232]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_bounds
+.LBB14_337:
+	cmp	dword ptr [rsp + 32], 1
+	jne	.LBB14_378
+.LBB14_338:
+	mov	r12, qword ptr [rsp + 40]
+	test	r12, r12
+	je	.LBB14_341
+.LBB14_339:
+	mov	r13, qword ptr [rsp + 48]
+.LBB14_340:
+	mov	al, -1
+	mov	edx, 0
+	mov	qword ptr [rsp], rdx
+	jmp	.LBB14_342
+.LBB14_341:
+	mov	eax, 2
+	mov	qword ptr [rsp], rax
+	mov	al, -1
+	mov	r13d, 0
+.LBB14_342:
+	test	bpl, bpl
+	jne	.LBB14_380
+	lea	rdi, [rsp + 32]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::rollback
+	cmp	dword ptr [rsp + 32], 1
+	jne	.LBB14_345
+	mov	rax, qword ptr [rsp + 40]
+	test	rax, rax
+	jne	.LBB14_316
+.LBB14_345:
+	mov	al, -1
+	jmp	.LBB14_379
+.LBB14_346:
+	mov	al, byte ptr [rsp + 210]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [rsp + 209], 0
+	mov	ecx, 63
+	cmove	eax, ecx
+	mov	byte ptr [rsp + 120], 2
+	mov	byte ptr [rsp + 121], al
+	jmp	.LBB14_381
+.LBB14_347:
+	mov	rsi, qword ptr [rsp + 216]
+	lea	rdi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+.LBB14_348:
+	mov	byte ptr [rsp + 120], 1
+	mov	qword ptr [rsp + 128], rax
+	jmp	.LBB14_381
+.LBB14_349:
+	mov	rsi, qword ptr [rsp + 216]
+	mov	rdx, qword ptr [rsp + 224]
+	lea	rdi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::write_reg
+	jmp	.LBB14_360
+.LBB14_350:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 320], xmm0
+	movaps	xmmword ptr [rsp + 336], xmm0
+	movaps	xmmword ptr [rsp + 352], xmm0
+	movaps	xmmword ptr [rsp + 368], xmm0
+	mov	qword ptr [rsp + 384], 0
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB14_351:
+	lea	rdi, [rsp + 552]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB14_353
+	mov	qword ptr [rsp + 8*r13 + 320], rax
+	mov	r13, qword ptr [rsp + 384]
+	inc	r13
+	mov	qword ptr [rsp + 384], r13
+.LBB14_353:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB14_351
+	mov	edx, 72
+	lea	rdi, [rsp + 39]
+	lea	rsi, [rsp + 320]
+	mov	r12, qword ptr [rip + memcpy@GOTPCREL]
+	call	r12
+	mov	byte ptr [rsp + 120], 3
+	mov	edx, 79
+	lea	rdi, [rsp + 121]
+	lea	rsi, [rsp + 32]
+	call	r12
+	jmp	.LBB14_381
+.LBB14_355:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	mov	qword ptr [rsp + 96], 0
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB14_356:
+	lea	rdi, [rsp + 552]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB14_358
+	mov	qword ptr [rsp + 8*r13 + 32], rax
+	mov	r13, qword ptr [rsp + 96]
+	inc	r13
+	mov	qword ptr [rsp + 96], r13
+.LBB14_358:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB14_356
+	lea	rdi, [rsp + 216]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB14_360:
+	mov	byte ptr [rsp + 120], 0
+	jmp	.LBB14_381
+.LBB14_361:
+	lea	rdi, [rsp + 32]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec
+	cmp	byte ptr [rsp + 32], 0
+	je	.LBB14_378
+	mov	r12, qword ptr [rsp + 40]
+	test	r12, r12
+	jne	.LBB14_339
+.LBB14_363:
+	mov	eax, 4
+	mov	qword ptr [rsp], rax
+	mov	al, -1
+	jmp	.LBB14_342
+.LBB14_364:
+	mov	rsi, qword ptr [rsp + 216]
+	lea	rdi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::set_state
+	jmp	.LBB14_378
+.LBB14_365:
+	mov	al, byte ptr [rsp + 210]
+	and	al, 63
+	cmp	byte ptr [rsp + 209], 0
+	movzx	ecx, al
+	mov	eax, 63
+	cmove	ecx, eax
+	mov	al, 2
+	jmp	.LBB14_380
+.LBB14_366:
+	mov	rsi, qword ptr [rsp + 216]
+	lea	rdi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	mov	qword ptr [rsp], rax
+	mov	al, 1
+.LBB14_307:
+	jmp	.LBB14_379
+.LBB14_367:
+	mov	rsi, qword ptr [rsp + 216]
+	mov	rdx, qword ptr [rsp + 224]
+	lea	rdi, [rsp + 552]
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::write_reg
+	jmp	.LBB14_378
+.LBB14_368:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	mov	qword ptr [rsp + 96], 0
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB14_369:
+	lea	rdi, [rsp + 552]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB14_371
+	mov	qword ptr [rsp + 8*r13 + 32], rax
+	mov	r13, qword ptr [rsp + 96]
+	inc	r13
+	mov	qword ptr [rsp + 96], r13
+.LBB14_371:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB14_369
+	mov	rax, qword ptr [rsp + 32]
+	mov	qword ptr [rsp], rax
+	mov	r12, qword ptr [rsp + 40]
+	mov	r13, qword ptr [rsp + 48]
+	lea	rax, [rsp + 39]
+	movups	xmm0, xmmword ptr [rax + 17]
+	movups	xmm1, xmmword ptr [rax + 33]
+	movups	xmm2, xmmword ptr [rax + 49]
+	movaps	xmmword ptr [rsp + 320], xmm0
+	movaps	xmmword ptr [rsp + 336], xmm1
+	movaps	xmmword ptr [rsp + 352], xmm2
+	mov	al, 3
+	jmp	.LBB14_379
+.LBB14_373:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	mov	qword ptr [rsp + 96], 0
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB14_374:
+	lea	rdi, [rsp + 552]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB14_376
+	mov	qword ptr [rsp + 8*r13 + 32], rax
+	mov	r13, qword ptr [rsp + 96]
+	inc	r13
+	mov	qword ptr [rsp + 96], r13
+.LBB14_376:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB14_374
+	lea	rdi, [rsp + 216]
+	lea	rsi, [rsp + 552]
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_options::controller::TargetController<optional_trait_methods::using_options::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB14_378:
+	xor	eax, eax
+.LBB14_379:
+.LBB14_380:
+	mov	byte ptr [rsp + 120], al
+	mov	byte ptr [rsp + 121], cl
+	mov	rax, qword ptr [rsp]
+	mov	qword ptr [rsp + 128], rax
+	mov	qword ptr [rsp + 136], r12
+	mov	qword ptr [rsp + 144], r13
+	movaps	xmm0, xmmword ptr [rsp + 320]
+	movaps	xmm1, xmmword ptr [rsp + 336]
+	movaps	xmm2, xmmword ptr [rsp + 352]
+	lea	rax, [rsp + 121]
+	movups	xmmword ptr [rax + 63], xmm2
+	movups	xmmword ptr [rax + 47], xmm1
+	movups	xmmword ptr [rax + 31], xmm0
+	jmp	.LBB14_381
+.LBB14_386:
+	xor	edx, edx
+.LBB14_387:
+	xor	eax, eax
+	mov	qword ptr [rsp + 3080], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, ebp
+	mov	rsi, r14
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	jmp	.LBB14_235
+.LBB14_388:
+	cmp	eax, 1
+	je	.LBB14_391
+	cmp	eax, 4
+	jne	.LBB14_393
+.LBB14_390:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 2056]
+	mov	edx, 23
+	jmp	.LBB14_392
+.LBB14_391:
+	mov	rsi, qword ptr [rsp + 488]
+	mov	rdx, qword ptr [rsp + 496]
+	lea	rdi, [rsp + 2056]
+.LBB14_392:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 2056]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB14_393:
+	mov	rdx, qword ptr [rsp + 3080]
+	mov	ebx, 1
+	mov	ebp, dword ptr [rsp + 3088]
+	jmp	.LBB14_387
+.LBB14_394:
+	ud2
+.Lfunc_end14:
+.LJTI14_0:
+	.long	.LBB14_27-.LJTI14_0
+	.long	.LBB14_37-.LJTI14_0
+	.long	.LBB14_30-.LJTI14_0
+	.long	.LBB14_31-.LJTI14_0
+	.long	.LBB14_29-.LJTI14_0
+	.long	.LBB14_394-.LJTI14_0
+	.long	.LBB14_41-.LJTI14_0
+.LJTI14_1:
+	.long	.LBB14_309-.LJTI14_1
+	.long	.LBB14_302-.LJTI14_1
+	.long	.LBB14_311-.LJTI14_1
+	.long	.LBB14_302-.LJTI14_1
+	.long	.LBB14_313-.LJTI14_1
+.LJTI14_2:
+	.long	.LBB14_310-.LJTI14_2
+	.long	.LBB14_302-.LJTI14_2
+	.long	.LBB14_346-.LJTI14_2
+	.long	.LBB14_347-.LJTI14_2
+	.long	.LBB14_349-.LJTI14_2
+	.long	.LBB14_350-.LJTI14_2
+	.long	.LBB14_355-.LJTI14_2
+.LJTI14_3:
+	.long	.LBB14_305-.LJTI14_3
+	.long	.LBB14_317-.LJTI14_3
+	.long	.LBB14_320-.LJTI14_3
+	.long	.LBB14_323-.LJTI14_3
+.LJTI14_4:
+	.long	.LBB14_306-.LJTI14_4
+	.long	.LBB14_364-.LJTI14_4
+	.long	.LBB14_365-.LJTI14_4
+	.long	.LBB14_366-.LJTI14_4
+	.long	.LBB14_367-.LJTI14_4
+	.long	.LBB14_368-.LJTI14_4
+	.long	.LBB14_373-.LJTI14_4
+.LJTI14_5:
+	.long	.LBB14_142-.LJTI14_5
+	.long	.LBB14_134-.LJTI14_5
+	.long	.LBB14_144-.LJTI14_5
+	.long	.LBB14_134-.LJTI14_5
+	.long	.LBB14_141-.LJTI14_5
+.LJTI14_6:
+	.long	.LBB14_143-.LJTI14_6
+	.long	.LBB14_134-.LJTI14_6
+	.long	.LBB14_182-.LJTI14_6
+	.long	.LBB14_183-.LJTI14_6
+	.long	.LBB14_181-.LJTI14_6
+	.long	.LBB14_185-.LJTI14_6
+	.long	.LBB14_191-.LJTI14_6
+.LJTI14_7:
+	.long	.LBB14_139-.LJTI14_7
+	.long	.LBB14_154-.LJTI14_7
+	.long	.LBB14_149-.LJTI14_7
+	.long	.LBB14_152-.LJTI14_7
+.LJTI14_8:
+	.long	.LBB14_140-.LJTI14_8
+	.long	.LBB14_203-.LJTI14_8
+	.long	.LBB14_200-.LJTI14_8
+	.long	.LBB14_201-.LJTI14_8
+	.long	.LBB14_199-.LJTI14_8
+	.long	.LBB14_204-.LJTI14_8
+	.long	.LBB14_209-.LJTI14_8
+
+rust_eh_personality:
+.Lfunc_begin15:
+	ret
+.Lfunc_end15:
+
+core::panicking::panic_fmt:
+.Lfunc_begin16:
+	.loc	1 60 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp0:
+	.loc	1 80 14 prologue_end
+	call	__rustc::rust_begin_unwind
+.Ltmp1:
+.Lfunc_end16:
+
+core::panicking::panic_bounds_check:
+.Lfunc_begin17:
+	.loc	1 266 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp2:
+	.loc	1 271 5 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp3:
+.Lfunc_end17:
+
+core::slice::index::slice_index_fail:
+.Lfunc_begin18:
+	.loc	2 38 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp4:
+	.loc	3 177 9 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp5:
+.Lfunc_end18:
+
+optional_trait_methods::num::parse_num::<isize>:
+.Lfunc_begin19:
+	test	rsi, rsi
+	je	.LBB19_56
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB19_4
+	cmp	ecx, 45
+	jne	.LBB19_6
+	mov	cl, 1
+	jmp	.LBB19_5
+.LBB19_4:
+	xor	ecx, ecx
+.LBB19_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB19_7
+.LBB19_6:
+	xor	ecx, ecx
+.LBB19_7:
+	cmp	rsi, 1
+	jbe	.LBB19_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB19_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB19_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB19_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB19_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB19_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB19_32
+.LBB19_14:
+	test	rsi, rsi
+	je	.LBB19_56
+	mov	al, byte ptr [rdi]
+.LBB19_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB19_56
+.LBB19_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB19_19
+	cmp	byte ptr [rax], 95
+	je	.LBB19_56
+.LBB19_19:
+	xor	r8d, r8d
+	xor	edx, edx
+.LBB19_20:
+	cmp	rdi, rsi
+	je	.LBB19_51
+	mov	al, byte ptr [rdi]
+	inc	rdi
+	cmp	al, 95
+	jne	.LBB19_23
+	xor	eax, eax
+	test	r8b, 1
+	mov	r8d, 0
+	jne	.LBB19_20
+	jmp	.LBB19_52
+.LBB19_23:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB19_57
+	imul	rdx, rdx, 10
+	jo	.LBB19_57
+	movzx	eax, al
+	test	cl, cl
+	je	.LBB19_27
+	sub	rdx, rax
+	jno	.LBB19_28
+	jmp	.LBB19_57
+.LBB19_27:
+	add	rdx, rax
+	jo	.LBB19_57
+.LBB19_28:
+	mov	r8b, 1
+	jmp	.LBB19_20
+.LBB19_29:
+	mov	r8b, 8
+	jmp	.LBB19_31
+.LBB19_30:
+	mov	r8b, 2
+.LBB19_31:
+	xor	r9d, r9d
+.LBB19_32:
+	cmp	rsi, 2
+	je	.LBB19_56
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB19_53
+.LBB19_34:
+	cmp	dl, r8b
+	jae	.LBB19_56
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB19_37
+	cmp	byte ptr [rax], 95
+	je	.LBB19_56
+.LBB19_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB19_38:
+	cmp	r10, rdi
+	je	.LBB19_51
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB19_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB19_52
+	jmp	.LBB19_38
+.LBB19_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB19_45
+	test	r9b, r9b
+	je	.LBB19_57
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB19_57
+	add	al, -87
+	mov	r11d, eax
+.LBB19_45:
+	cmp	r11b, r8b
+	jae	.LBB19_57
+	imul	rdx, rsi
+	jo	.LBB19_57
+	movzx	eax, r11b
+	test	cl, cl
+	je	.LBB19_49
+	sub	rdx, rax
+	jo	.LBB19_57
+	jmp	.LBB19_50
+.LBB19_49:
+	add	rdx, rax
+	jo	.LBB19_57
+.LBB19_50:
+	mov	r11b, 1
+	jmp	.LBB19_38
+.LBB19_51:
+	mov	eax, 1
+	ret
+.LBB19_53:
+	test	r9b, r9b
+	je	.LBB19_56
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB19_56
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB19_34
+.LBB19_56:
+	xor	eax, eax
+.LBB19_52:
+	ret
+.LBB19_57:
+	xor	eax, eax
+	ret
+.Lfunc_end19:
+
+optional_trait_methods::num::parse_num::<usize>:
+.Lfunc_begin20:
+	test	rsi, rsi
+	je	.LBB20_55
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB20_4
+	cmp	ecx, 45
+	jne	.LBB20_6
+	mov	cl, 1
+	jmp	.LBB20_5
+.LBB20_4:
+	xor	ecx, ecx
+.LBB20_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB20_7
+.LBB20_6:
+	xor	ecx, ecx
+.LBB20_7:
+	cmp	rsi, 1
+	jbe	.LBB20_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB20_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB20_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB20_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB20_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB20_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB20_32
+.LBB20_14:
+	test	rsi, rsi
+	je	.LBB20_55
+	mov	al, byte ptr [rdi]
+.LBB20_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB20_55
+.LBB20_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB20_19
+	cmp	byte ptr [rax], 95
+	je	.LBB20_55
+.LBB20_19:
+	mov	r8d, 10
+	xor	r9d, r9d
+	xor	edx, edx
+.LBB20_20:
+	cmp	rdi, rsi
+	je	.LBB20_50
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB20_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB20_20
+	jmp	.LBB20_51
+.LBB20_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB20_28
+	mov	rax, rdx
+	mul	r8
+	jo	.LBB20_28
+	mov	rdx, rax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB20_27
+	sub	rdx, rax
+	jae	.LBB20_20
+	jmp	.LBB20_28
+.LBB20_27:
+	add	rdx, rax
+	jae	.LBB20_20
+.LBB20_28:
+	xor	eax, eax
+	ret
+.LBB20_29:
+	mov	r8b, 8
+	jmp	.LBB20_31
+.LBB20_30:
+	mov	r8b, 2
+.LBB20_31:
+	xor	r9d, r9d
+.LBB20_32:
+	cmp	rsi, 2
+	je	.LBB20_55
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB20_52
+.LBB20_34:
+	cmp	dl, r8b
+	jae	.LBB20_55
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB20_37
+	cmp	byte ptr [rax], 95
+	je	.LBB20_55
+.LBB20_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB20_38:
+	cmp	r10, rdi
+	je	.LBB20_50
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB20_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB20_51
+	jmp	.LBB20_38
+.LBB20_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB20_45
+	test	r9b, r9b
+	je	.LBB20_28
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB20_28
+	add	al, -87
+	mov	r11d, eax
+.LBB20_45:
+	cmp	r11b, r8b
+	jae	.LBB20_28
+	mov	rax, rdx
+	mul	rsi
+	jo	.LBB20_28
+	mov	rdx, rax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB20_49
+	sub	rdx, rax
+	jae	.LBB20_38
+	jmp	.LBB20_28
+.LBB20_49:
+	add	rdx, rax
+	jae	.LBB20_38
+	jmp	.LBB20_28
+.LBB20_50:
+	mov	eax, 1
+	ret
+.LBB20_52:
+	test	r9b, r9b
+	je	.LBB20_55
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB20_55
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB20_34
+.LBB20_55:
+	xor	eax, eax
+.LBB20_51:
+	ret
+.Lfunc_end20:
+
+<optional_trait_methods::commands::Args>::reg:
+.Lfunc_begin21:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	cmp	rdx, 8
+	setb	cl
+	and	cl, al
+	cmovne	r15, rdx
+	neg	cl
+	or	cl, 2
+	mov	qword ptr [rbx], r15
+	mov	byte ptr [rbx + 8], cl
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end21:
+
+<optional_trait_methods::commands::Args>::next:
+.Lfunc_begin22:
+	mov	r10, qword ptr [rsi + 8]
+	mov	rax, qword ptr [rsi + 16]
+	xor	edx, edx
+	test	r10, r10
+	setne	dl
+	cmp	r10, 1
+	mov	rcx, r10
+	adc	rcx, -1
+	mov	r8, qword ptr [rsi]
+	add	r8, rdx
+	mov	r9, rcx
+	cmp	r10, 2
+	jb	.LBB22_5
+	xor	r9d, r9d
+.LBB22_2:
+	cmp	byte ptr [r8 + r9], 32
+	je	.LBB22_5
+	inc	r9
+	cmp	rcx, r9
+	jne	.LBB22_2
+	mov	r9, rcx
+.LBB22_5:
+	add	rax, rdx
+	sub	rcx, r9
+	lea	rdx, [r8 + r9]
+	mov	qword ptr [rsi], rdx
+	mov	qword ptr [rsi + 8], rcx
+	lea	rcx, [r9 + rax]
+	mov	qword ptr [rsi + 16], rcx
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], r8
+	mov	qword ptr [rdi + 16], r9
+	ret
+.Lfunc_end22:
+
+<optional_trait_methods::commands::Args>::names:
+.Lfunc_begin23:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 200
+	mov	qword ptr [rsp + 32], rdi
+	lea	r14, [rsp + 40]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r12, qword ptr [r14 + 8]
+	mov	rbx, qword ptr [r14 + 16]
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, qword ptr [rip + bcmp@GOTPCREL]
+.LBB23_1:
+	test	rbx, rbx
+	je	.LBB23_2
+	xor	r13d, r13d
+.LBB23_4:
+	cmp	byte ptr [r12 + r13], 59
+	je	.LBB23_7
+	inc	r13
+	cmp	rbx, r13
+	jne	.LBB23_4
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 24], r12
+	mov	r13, rbx
+	jmp	.LBB23_8
+.LBB23_2:
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	xor	ebx, ebx
+	mov	qword ptr [rsp + 24], r12
+	xor	r13d, r13d
+	jmp	.LBB23_8
+.LBB23_7:
+	lea	rax, [r13 + 1]
+	sub	rbx, rax
+	lea	rax, [r12 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 24], rax
+	mov	dword ptr [rsp + 12], 0
+.LBB23_8:
+	mov	edx, 144
+	lea	rbp, [rsp + 56]
+	mov	rdi, rbp
+	lea	rsi, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.6]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r14d, 6
+.LBB23_9:
+	cmp	qword ptr [rbp + 16], r13
+	jne	.LBB23_11
+	mov	rdi, qword ptr [rbp + 8]
+	mov	rsi, r12
+	mov	rdx, r13
+	call	r15
+	test	eax, eax
+	je	.LBB23_12
+.LBB23_11:
+	add	rbp, 24
+	dec	r14
+	jne	.LBB23_9
+	jmp	.LBB23_13
+.LBB23_12:
+	mov	rax, qword ptr [rsp + 16]
+	or	al, byte ptr [rbp]
+	mov	qword ptr [rsp + 16], rax
+.LBB23_13:
+	mov	r12, qword ptr [rsp + 24]
+	cmp	byte ptr [rsp + 12], 0
+	je	.LBB23_1
+	mov	rax, qword ptr [rsp + 32]
+	mov	rcx, qword ptr [rsp + 16]
+	mov	byte ptr [rax], cl
+	mov	byte ptr [rax + 8], -1
+	add	rsp, 200
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end23:
+
+<optional_trait_methods::connection::Listener>::tcp_loopback:
+.Lfunc_begin24:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	qword ptr [rsp + 20], 0
+	mov	word ptr [rsp + 12], 2
+	rol	di, 8
+	mov	word ptr [rsp + 14], di
+	mov	dword ptr [rsp + 16], 16777343
+	mov	edi, 2
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebx, edx
+	mov	ebp, 1
+	test	al, 1
+	jne	.LBB24_5
+	lea	rcx, [rsp + 28]
+	mov	dword ptr [rcx], 1
+	mov	edi, ebx
+	mov	esi, 1
+	mov	edx, 2
+	mov	r8d, 4
+	call	qword ptr [rip + setsockopt@GOTPCREL]
+	lea	rsi, [rsp + 12]
+	mov	edi, ebx
+	mov	edx, 16
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB24_3
+	mov	edi, ebx
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB24_3
+	xor	ebp, ebp
+	jmp	.LBB24_5
+.LBB24_3:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebx, r14d
+.LBB24_5:
+	mov	eax, ebp
+	mov	edx, ebx
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end24:
+
+<optional_trait_methods::connection::Listener>::socket:
+.Lfunc_begin25:
+	push	rbx
+	xor	ebx, ebx
+	mov	esi, 1
+	xor	edx, edx
+	call	qword ptr [rip + socket@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB25_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB25_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end25:
+
+<optional_trait_methods::connection::Listener>::unix:
+.Lfunc_begin26:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 112
+	mov	rdx, rsi
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [rsp + 96], xmm0
+	movups	xmmword ptr [rsp + 84], xmm0
+	movups	xmmword ptr [rsp + 68], xmm0
+	movups	xmmword ptr [rsp + 52], xmm0
+	movups	xmmword ptr [rsp + 36], xmm0
+	movups	xmmword ptr [rsp + 20], xmm0
+	movups	xmmword ptr [rsp + 4], xmm0
+	mov	word ptr [rsp + 2], 1
+	lea	rax, [rsi - 108]
+	cmp	rax, -107
+	jae	.LBB26_2
+	mov	ebx, 1
+	mov	ebp, 36
+	jmp	.LBB26_7
+.LBB26_2:
+	mov	rsi, rdi
+	lea	rbx, [rsp + 4]
+	mov	rdi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	rdi, rbx
+	call	qword ptr [rip + unlink@GOTPCREL]
+	mov	ebx, 1
+	mov	edi, 1
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebp, edx
+	test	al, 1
+	jne	.LBB26_7
+	lea	rsi, [rsp + 2]
+	mov	edi, ebp
+	mov	edx, 110
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB26_6
+	mov	edi, ebp
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB26_6
+	xor	ebx, ebx
+	jmp	.LBB26_7
+.LBB26_6:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebp, r14d
+.LBB26_7:
+	mov	eax, ebx
+	mov	edx, ebp
+	add	rsp, 112
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end26:
+
+<optional_trait_methods::connection::Listener>::accept:
+.Lfunc_begin27:
+	push	rbx
+	xor	ebx, ebx
+	xor	esi, esi
+	xor	edx, edx
+	call	qword ptr [rip + accept@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB27_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB27_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end27:
+
+optional_trait_methods::commands::unrecognized:
+.Lfunc_begin28:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	xor	r14d, r14d
+.LBB28_1:
+	cmp	byte ptr [rbx + r14], 32
+	je	.LBB28_4
+	inc	r14
+	cmp	rsi, r14
+	jne	.LBB28_1
+	mov	r14, rsi
+.LBB28_4:
+	mov	r15d, 8
+	lea	r12, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.33]
+	mov	r13, qword ptr [rip + bcmp@GOTPCREL]
+.LBB28_5:
+	cmp	qword ptr [r15 + r12], r14
+	jne	.LBB28_8
+	mov	rdi, qword ptr [r15 + r12 - 8]
+	mov	rsi, rbx
+	mov	rdx, r14
+	call	r13
+	test	eax, eax
+	je	.LBB28_7
+.LBB28_8:
+	add	r15, 16
+	cmp	r15, 152
+	jne	.LBB28_5
+	xor	eax, eax
+	jmp	.LBB28_10
+.LBB28_7:
+	mov	al, 1
+.LBB28_10:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end28:
+
+optional_trait_methods::connection::read_fd:
+.Lfunc_begin29:
+	push	rbx
+	mov	rax, rdx
+	mov	rbx, rdi
+	mov	edx, 1024
+	mov	edi, esi
+	mov	rsi, rax
+	call	qword ptr [rip + read@GOTPCREL]
+	test	rax, rax
+	js	.LBB29_2
+	mov	qword ptr [rbx + 8], rax
+	xor	eax, eax
+	jmp	.LBB29_3
+.LBB29_2:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	eax, dword ptr [rax]
+	mov	dword ptr [rbx + 4], eax
+	mov	eax, 1
+.LBB29_3:
+	mov	dword ptr [rbx], eax
+	pop	rbx
+	ret
+.Lfunc_end29:
+
+optional_trait_methods::connection::write_fd:
+.Lfunc_begin30:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	test	rdx, rdx
+	je	.LBB30_8
+	mov	ebp, ecx
+	mov	rbx, rdx
+	mov	r14, rsi
+	mov	r15d, edi
+	mov	r12, qword ptr [rip + send@GOTPCREL]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+.LBB30_2:
+	mov	edi, r15d
+	mov	rsi, r14
+	mov	rdx, rbx
+	test	bpl, bpl
+	je	.LBB30_3
+	mov	ecx, 16384
+	call	r12
+	jmp	.LBB30_5
+.LBB30_3:
+	call	r13
+.LBB30_5:
+	test	rax, rax
+	js	.LBB30_10
+	cmp	rbx, rax
+	jb	.LBB30_9
+	add	r14, rax
+	sub	rbx, rax
+	jne	.LBB30_2
+.LBB30_8:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB30_10:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB30_9:
+	mov	rdi, rax
+	mov	rsi, rbx
+	mov	rdx, rbx
+	call	core::slice::index::slice_index_fail
+.Lfunc_end30:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::checkpoint:
+.Lfunc_begin31:
+	push	rax
+	mov	rcx, qword ptr [rsi + 296]
+	mov	rdx, qword ptr [rsi + 360]
+	mov	rax, qword ptr [rsi + 280]
+	lea	r8, [rsi + 24]
+	cmp	qword ptr [rsi + 288], 16
+	jne	.LBB31_3
+	cmp	rax, 16
+	jae	.LBB31_5
+	mov	r9, rax
+	shl	r9, 4
+	movups	xmm0, xmmword ptr [r8 + r9]
+	mov	qword ptr [rsi], 1
+	movups	xmmword ptr [rsi + 8], xmm0
+	jmp	.LBB31_4
+.LBB31_3:
+	mov	qword ptr [rsi], 0
+	cmp	rax, 16
+	jae	.LBB31_5
+.LBB31_4:
+	shl	rax, 4
+	mov	qword ptr [r8 + rax], rcx
+	mov	qword ptr [r8 + rax + 8], rdx
+	mov	eax, dword ptr [rsi + 280]
+	inc	eax
+	and	eax, 15
+	mov	qword ptr [rsi + 280], rax
+	mov	rax, qword ptr [rsi + 288]
+	inc	rax
+	cmp	rax, 16
+	mov	ecx, 16
+	cmovb	rcx, rax
+	mov	qword ptr [rsi + 288], rcx
+	mov	qword ptr [rdi], 0
+	pop	rax
+	ret
+.LBB31_5:
+	mov	esi, 16
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end31:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_bounds:
+.Lfunc_begin32:
+	cmp	rdx, rcx
+	jle	.LBB32_1
+	lea	rax, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.42]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 26
+	mov	eax, 1
+	jmp	.LBB32_3
+.LBB32_1:
+	mov	qword ptr [rsi + 368], rdx
+	mov	qword ptr [rsi + 376], rcx
+	mov	rax, qword ptr [rsi + 360]
+	cmp	rdx, rax
+	cmovg	rax, rdx
+	cmp	rcx, rax
+	cmovl	rax, rcx
+	mov	qword ptr [rsi + 360], rax
+	xor	eax, eax
+.LBB32_3:
+	mov	qword ptr [rdi], rax
+	ret
+.Lfunc_end32:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::scale_factor:
+.Lfunc_begin33:
+	mov	rax, qword ptr [rsi + 368]
+	mov	rcx, qword ptr [rsi + 376]
+	cmp	rax, rdx
+	cmovg	rdx, rax
+	cmp	rcx, rdx
+	cmovl	rdx, rcx
+	mov	qword ptr [rsi + 360], rdx
+	mov	qword ptr [rdi], 0
+	ret
+.Lfunc_end33:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::dec:
+.Lfunc_begin34:
+	dec	qword ptr [rsi + 296]
+	mov	qword ptr [rdi], 0
+	ret
+.Lfunc_end34:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::div:
+.Lfunc_begin35:
+	test	rdx, rdx
+	je	.LBB35_3
+	mov	rcx, rdx
+	mov	rax, qword ptr [rsi + 296]
+	mov	rdx, rax
+	btc	rdx, 63
+	mov	r8, rcx
+	not	r8
+	or	r8, rdx
+	je	.LBB35_4
+	cqo
+	idiv	rcx
+	mov	qword ptr [rsi + 296], rax
+	xor	eax, eax
+.LBB35_6:
+	mov	qword ptr [rdi], rax
+	ret
+.LBB35_3:
+	lea	rax, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.43]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 17
+	jmp	.LBB35_5
+.LBB35_4:
+	lea	rax, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.44]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 29
+.LBB35_5:
+	mov	eax, 1
+	jmp	.LBB35_6
+.Lfunc_end35:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::inc:
+.Lfunc_begin36:
+	inc	qword ptr [rsi + 296]
+	mov	qword ptr [rdi], 0
+	ret
+.Lfunc_end36:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::mul:
+.Lfunc_begin37:
+	cmp	rdx, 7
+	jne	.LBB37_2
+	lea	rax, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.45]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 28
+	mov	eax, 1
+	jmp	.LBB37_3
+.LBB37_2:
+	imul	rdx, qword ptr [rsi + 360]
+	imul	rdx, qword ptr [rsi + 296]
+	mov	qword ptr [rsi + 296], rdx
+	xor	eax, eax
+.LBB37_3:
+	mov	qword ptr [rdi], rax
+	ret
+.Lfunc_end37:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::rem:
+.Lfunc_begin38:
+	test	rdx, rdx
+	je	.LBB38_3
+	mov	rcx, rdx
+	mov	rax, qword ptr [rsi + 296]
+	mov	rdx, rax
+	btc	rdx, 63
+	mov	r8, rcx
+	not	r8
+	or	r8, rdx
+	je	.LBB38_4
+	cqo
+	idiv	rcx
+	mov	qword ptr [rsi + 296], rdx
+	xor	eax, eax
+.LBB38_6:
+	mov	qword ptr [rdi], rax
+	ret
+.LBB38_3:
+	lea	rax, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.43]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 17
+	jmp	.LBB38_5
+.LBB38_4:
+	lea	rax, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.44]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 29
+.LBB38_5:
+	mov	eax, 1
+	jmp	.LBB38_6
+.Lfunc_end38:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::undo:
+.Lfunc_begin39:
+	mov	rax, qword ptr [rsi + 288]
+	lea	rcx, [rdx - 1]
+	cmp	rcx, rax
+	jae	.LBB39_2
+	mov	qword ptr [rsi], 0
+	mov	ecx, dword ptr [rsi + 280]
+	sub	ecx, edx
+	and	ecx, 15
+	mov	qword ptr [rsi + 280], rcx
+	sub	rax, rdx
+	mov	qword ptr [rsi + 288], rax
+	shl	ecx, 4
+	mov	rax, qword ptr [rsi + rcx + 24]
+	mov	rcx, qword ptr [rsi + rcx + 32]
+	mov	rdx, qword ptr [rsi + 368]
+	mov	r8, qword ptr [rsi + 376]
+	mov	qword ptr [rsi + 296], rax
+	cmp	rdx, rcx
+	cmovg	rcx, rdx
+	cmp	r8, rcx
+	cmovl	rcx, r8
+	mov	qword ptr [rsi + 360], rcx
+	jmp	.LBB39_6
+.LBB39_2:
+	test	rdx, rdx
+	je	.LBB39_6
+	test	rax, rax
+	je	.LBB39_8
+	lea	rax, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.46]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 19
+	jmp	.LBB39_5
+.LBB39_6:
+	xor	eax, eax
+	jmp	.LBB39_7
+.LBB39_8:
+	lea	rax, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.47]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 16
+.LBB39_5:
+	mov	eax, 1
+.LBB39_7:
+	mov	qword ptr [rdi], rax
+	ret
+.Lfunc_end39:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::read_reg:
+.Lfunc_begin40:
+	test	rsi, rsi
+	je	.LBB40_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB40_5
+	lea	rdi, [rdi + 8*rax]
+	add	rdi, 304
+	jmp	.LBB40_2
+.LBB40_1:
+	add	rdi, 296
+.LBB40_2:
+	mov	rax, qword ptr [rdi]
+	ret
+.LBB40_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end40:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::rollback:
+.Lfunc_begin41:
+	mov	r8, qword ptr [rsi]
+	mov	rax, qword ptr [rsi + 8]
+	mov	rcx, qword ptr [rsi + 16]
+	mov	qword ptr [rsi], 0
+	mov	rdx, qword ptr [rsi + 288]
+	test	rdx, rdx
+	je	.LBB41_4
+	mov	r9d, dword ptr [rsi + 280]
+	dec	r9d
+	and	r9d, 15
+	mov	qword ptr [rsi + 280], r9
+	lea	r10, [rdx - 1]
+	mov	qword ptr [rsi + 288], r10
+	shl	r9d, 4
+	mov	r10, qword ptr [rsi + r9 + 24]
+	mov	r11, qword ptr [rsi + r9 + 32]
+	cmp	r8, 1
+	jne	.LBB41_3
+	lea	r8, [rsi + r9]
+	add	r8, 24
+	mov	qword ptr [r8], rax
+	mov	qword ptr [r8 + 8], rcx
+	mov	qword ptr [rsi + 288], rdx
+.LBB41_3:
+	mov	qword ptr [rsi + 296], r10
+	mov	qword ptr [rsi + 360], r11
+	xor	eax, eax
+	jmp	.LBB41_5
+.LBB41_4:
+	lea	rax, [rip + .Lanon.384c8b5de1d2241d4567a9c4d42f0f59.50]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 21
+	mov	eax, 1
+.LBB41_5:
+	mov	qword ptr [rdi], rax
+	ret
+.Lfunc_end41:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::set_state:
+.Lfunc_begin42:
+	mov	qword ptr [rdi + 296], rsi
+	ret
+.Lfunc_end42:
+
+<optional_trait_methods::using_options::targets::advanced::AdvancedTarget as optional_trait_methods::using_options::target::Target>::write_reg:
+.Lfunc_begin43:
+	test	rsi, rsi
+	je	.LBB43_1
+	mov	rax, rsi
+	cmp	rsi, 8
+	jae	.LBB43_5
+	mov	qword ptr [rdi + 8*rax + 296], rdx
+	ret
+.LBB43_1:
+	mov	qword ptr [rdi + 296], rdx
+	ret
+.LBB43_5:
+	push	rax
+	dec	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end43:
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.0:
+	.byte	10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.1:
+	.byte	59
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.2:
+	.ascii	"E 01"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.3:
+	.ascii	"E 02"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.4:
+	.ascii	"E."
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.5:
+	.ascii	" at "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.6:
+	.ascii	"E 05"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.7:
+	.ascii	"unknown option: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.8:
+	.ascii	"missing value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.9:
+	.ascii	"invalid value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.10:
+	.ascii	": "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.11:
+	.ascii	" doesn't take a value"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.12:
+	.ascii	"only one of --unix / --tcp may be given"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.14:
+	.ascii	"0123456789abcdef"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.17:
+	.ascii	"--unix"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.18:
+	.ascii	"--tcp"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.19:
+	.ascii	"--initial-state"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.20:
+	.ascii	"--on-error"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.21:
+	.ascii	"--echo"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.22:
+	.ascii	"--repl"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.23:
+	.ascii	"--help"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.24:
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.17
+	.asciz	"\006\000\000\000\000\000\000\000"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.18
+	.ascii	"\005\000\000\000\000\000\000\000\001"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.19
+	.ascii	"\017\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.20
+	.ascii	"\n\000\000\000\000\000\000\000\003"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.21
+	.ascii	"\006\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.22
+	.ascii	"\006\000\000\000\000\000\000\000\005"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.23
+	.ascii	"\006\000\000\000\000\000\000\000\006"
+	.zero	7
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.28:
+	.ascii	"Invalid implementation!"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.29:
+	.ascii	"OK"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.30:
+	.ascii	"listening on 127.0.0.1:"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.31:
+	.ascii	"error: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.32:
+	.zero	2,10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.33:
+	.ascii	"usage: optional-trait-methods [options]\n\n  --unix <path>              serve the first client to connect to a Unix domain socket\n  --tcp <port>               serve the first client to connect to 127.0.0.1:<port>\n                             (port 0 picks any free port, printed to stderr)\n                             (by default, serve stdin / stdout)\n  --initial-state <n>        start the target off in state <n> (by default, 0)\n  --on-error=continue|exit   whether to keep serving after a command fails\n  --echo                     repeat every command before its reply\n  --repl                     expect a human instead of a client (prompt, aliases, help)\n                             (needs the `repl` feature)\n  --help                     print this message\n\n  (`--name <value>` may also be written `--name=<value>`)"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.34:
+	.ascii	"panic!\n"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.35:
+	.ascii	"Parse IncDec extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.39:
+	.ascii	"Parse Mul extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.41:
+	.ascii	"Parse ScaleFactor extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.43:
+	.ascii	"Parse ScaleBounds extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.45:
+	.ascii	"Parse Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.48:
+	.ascii	"Parse Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.57:
+	.ascii	"IncDec extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.58:
+	.ascii	"Mul extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.59:
+	.ascii	"ScaleFactor extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.60:
+	.ascii	"ScaleBounds extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.61:
+	.ascii	"Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.62:
+	.ascii	"Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.63:
+	.ascii	"Undo checkpoint"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.64:
+	.ascii	"unknown command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.65:
+	.ascii	"unsupported command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.66:
+	.ascii	"bad number"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.67:
+	.ascii	"trailing garbage"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.68:
+	.ascii	"line too long"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.72:
+	.ascii	"failed to accept a connection"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.73:
+	.ascii	"--repl needs the `repl` feature"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.0:
+	.ascii	"incdec"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.1:
+	.ascii	"mul"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.2:
+	.ascii	"mul.scale_factor"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.3:
+	.ascii	"mul.scale_factor.scale_bounds"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.4:
+	.ascii	"div"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.5:
+	.ascii	"undo"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.6:
+	.byte	1
+	.zero	7
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.0
+	.ascii	"\006\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.1
+	.ascii	"\003\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.2
+	.ascii	"\020\000\000\000\000\000\000\000\b"
+	.zero	7
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.3
+	.ascii	"\035\000\000\000\000\000\000\000\020"
+	.zero	7
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.4
+	.ascii	"\003\000\000\000\000\000\000\000 "
+	.zero	7
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.5
+	.asciz	"\004\000\000\000\000\000\000"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.15:
+	.byte	43
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.17:
+	.byte	45
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.18:
+	.byte	42
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.20:
+	.ascii	"*~"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.28:
+	.ascii	"+-"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.29:
+	.ascii	"*~~"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.30:
+	.byte	47
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.31:
+	.byte	37
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.32:
+	.byte	117
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.33:
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.15
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.17
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.28
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.18
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.20
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.29
+	.asciz	"\003\000\000\000\000\000\000"
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.30
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.31
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.32
+	.asciz	"\001\000\000\000\000\000\000"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.42:
+	.ascii	"scale bounds are inverted!"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.43:
+	.ascii	"dividing by zero!"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.44:
+	.ascii	"dividing MIN by -1 overflows!"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.45:
+	.ascii	"multiplying by 7 is unlucky!"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.46:
+	.ascii	"not enough history!"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.47:
+	.ascii	"nothing to undo!"
+
+.Lanon.384c8b5de1d2241d4567a9c4d42f0f59.50:
+	.ascii	"nothing to roll back!"
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+
+	.byte	1
+	.byte	17
+	.byte	1
+	.byte	37
+	.byte	14
+	.byte	19
+	.byte	5
+	.byte	3
+	.byte	14
+	.byte	16
+	.byte	23
+	.byte	27
+	.byte	14
+	.byte	17
+	.byte	1
+	.byte	85
+	.byte	23
+	.byte	0
+	.byte	0
+	.byte	2
+	.byte	57
+	.byte	1
+	.byte	3
+	.byte	14
+	.byte	0
+	.byte	0
+	.byte	3
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	11
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	4
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	5
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	0
+.Lcu_begin0:
+	.long	.Ldebug_info_end0-.Ldebug_info_start0
+.Ldebug_info_start0:
+	.short	4
+	.long	.debug_abbrev
+	.byte	8
+	.byte	1
+	.long	.Linfo_string0
+	.short	28
+	.long	.Linfo_string1
+	.long	.Lline_table_start0
+	.long	.Linfo_string2
+	.quad	0
+	.long	.Ldebug_ranges0
+	.byte	2
+	.long	.Linfo_string3
+	.byte	2
+	.long	.Linfo_string4
+	.byte	3
+	.quad	.Lfunc_begin16
+	.long	.Lfunc_end16-.Lfunc_begin16
+	.byte	1
+	.byte	86
+	.long	.Linfo_string7
+	.long	.Linfo_string8
+	.byte	1
+	.byte	60
+	.byte	3
+
+
+	.byte	4
+	.quad	.Lfunc_begin17
+	.long	.Lfunc_end17-.Lfunc_begin17
+	.byte	1
+	.byte	86
+	.long	.Linfo_string9
+	.long	.Linfo_string10
+	.byte	1
+	.short	266
+	.byte	3
+
+
+	.byte	0
+	.byte	2
+	.long	.Linfo_string5
+	.byte	2
+	.long	.Linfo_string6
+	.byte	3
+	.quad	.Lfunc_begin18
+	.long	.Lfunc_end18-.Lfunc_begin18
+	.byte	1
+	.byte	86
+	.long	.Linfo_string11
+	.long	.Linfo_string12
+	.byte	2
+	.byte	38
+	.byte	3
 
-.Lanon.cabb224323f7f456a7965482e4c09287.12:
-	.ascii	"Invalid implementation!"
 
-	.ident	"rustc version 1.99.0-nightly (6f72b5dd5 2026-07-22)"
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+.Ldebug_info_end0:
+.Lsec_end0:
+.Lsec_end1:
+.Lsec_end2:
+	.long	76
+	.short	2
+	.long	.Lcu_begin0
+	.byte	8
+	.byte	0
+	.zero	4,255
+	.quad	.Lfunc_begin16
+	.quad	.Lsec_end0-.Lfunc_begin16
+	.quad	.Lfunc_begin17
+	.quad	.Lsec_end1-.Lfunc_begin17
+	.quad	.Lfunc_begin18
+	.quad	.Lsec_end2-.Lfunc_begin18
+	.quad	0
+	.quad	0
+.Ldebug_ranges0:
+	.quad	.Lfunc_begin16
+	.quad	.Lfunc_end16
+	.quad	.Lfunc_begin17
+	.quad	.Lfunc_end17
+	.quad	.Lfunc_begin18
+	.quad	.Lfunc_end18
+	.quad	0
+	.quad	0
+.Linfo_string0:
+	.asciz	"clang LLVM (rustc version 1.97.0-nightly (e50aa6fba 2026-05-19))"
+.Linfo_string1:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a/library/core/src/lib.rs/@/core.667c7a611d73a360-cgu.0"
+.Linfo_string2:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a"
+.Linfo_string3:
+	.asciz	"core"
+.Linfo_string4:
+	.asciz	"panicking"
+.Linfo_string5:
+	.asciz	"slice"
+.Linfo_string6:
+	.asciz	"index"
+.Linfo_string7:
+	.asciz	"core::panicking::panic_fmt"
+.Linfo_string8:
+	.asciz	"panic_fmt"
+.Linfo_string9:
+	.asciz	"core::panicking::panic_bounds_check"
+.Linfo_string10:
+	.asciz	"panic_bounds_check"
+.Linfo_string11:
+	.asciz	"core::slice::index::slice_index_fail"
+.Linfo_string12:
+	.asciz	"slice_index_fail"
+	.ident	"rustc version 1.97.0-nightly (e50aa6fba 2026-05-19)"
+.Lline_table_start0:
@@ -1,455 +1,5164 @@
+<optional_trait_methods::commands::Args>::num::<isize>:
+.Lfunc_begin0:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<isize>
+	and	al, 1
+	cmove	rdx, r15
+	neg	al
+	or	al, 2
+	mov	qword ptr [rbx], rdx
+	mov	byte ptr [rbx + 8], al
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end0:
+
+<optional_trait_methods::commands::Args>::regs::<isize>:
+.Lfunc_begin1:
+	push	r14
+	push	rbx
+	sub	rsp, 88
+	mov	rbx, rdi
+	lea	r14, [rsp + 16]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	rax, qword ptr [r14]
+	cmp	qword ptr [r14 + 16], 128
+	jne	.LBB1_20
+	mov	rcx, qword ptr [rsp + 24]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 80], 0
+	mov	edx, 128
+	xor	esi, esi
+.LBB1_2:
+	mov	rdi, rcx
+	add	rcx, 16
+	add	rdx, -16
+	movaps	xmmword ptr [rsp], xmm0
+	xor	r8d, r8d
+.LBB1_3:
+	mov	r10b, byte ptr [rdi + 2*r8]
+	lea	r9d, [r10 - 48]
+	cmp	r9b, 10
+	jb	.LBB1_13
+	lea	r9d, [r10 - 97]
+	cmp	r9b, 5
+	ja	.LBB1_10
+	add	r10b, -87
+	jmp	.LBB1_12
+.LBB1_10:
+	lea	r9d, [r10 - 71]
+	cmp	r9b, -6
+	jb	.LBB1_20
+	add	r10b, -55
+.LBB1_12:
+	mov	r9d, r10d
+.LBB1_13:
+	mov	r10b, byte ptr [rdi + 2*r8 + 1]
+	lea	r11d, [r10 - 48]
+	cmp	r11b, 10
+	jb	.LBB1_19
+	lea	r11d, [r10 - 97]
+	cmp	r11b, 5
+	ja	.LBB1_16
+	add	r10b, -87
+	jmp	.LBB1_18
+.LBB1_16:
+	lea	r11d, [r10 - 71]
+	cmp	r11b, -6
+	jb	.LBB1_20
+	add	r10b, -55
+.LBB1_18:
+	mov	r11d, r10d
+.LBB1_19:
+	shl	r9b, 4
+	or	r9b, r11b
+	mov	byte ptr [rsp + r8], r9b
+	inc	r8
+	cmp	r8, 8
+	jne	.LBB1_3
+	cmp	rsi, 7
+	ja	.LBB1_8
+	mov	rdi, qword ptr [rsp]
+	mov	qword ptr [rsp + 8*rsi + 16], rdi
+	mov	rsi, qword ptr [rsp + 80]
+	inc	rsi
+	mov	qword ptr [rsp + 80], rsi
+.LBB1_8:
+	test	rdx, rdx
+	jne	.LBB1_2
+	lea	rdi, [rbx + 8]
+	lea	rsi, [rsp + 16]
+	mov	ecx, 9
+	rep movsq es:[rdi], [rsi]
+	xor	eax, eax
+	jmp	.LBB1_21
+.LBB1_20:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB1_21:
+	mov	qword ptr [rbx], rax
+	add	rsp, 88
+	pop	rbx
+	pop	r14
+	ret
+.Lfunc_end1:
+
+<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>:
+.Lfunc_begin2:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rax, qword ptr [rdi + 64]
+	xor	r15d, r15d
+	cmp	rax, 9
+	mov	r12d, 8
+	cmovb	r12, rdi
+	lea	rax, [8*rax]
+	cmovb	r15, rax
+	test	r15, r15
+	je	.LBB2_3
+	mov	rbx, rsi
+	xor	r14d, r14d
+.LBB2_2:
+	mov	rdx, qword ptr [r12 + 8*r14]
+	mov	rdi, rbx
+	mov	rsi, r14
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::write_reg
+	inc	r14
+	add	r15, -8
+	jne	.LBB2_2
+.LBB2_3:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end2:
+
+optional_trait_methods::num::format_decimal::<isize>:
+.Lfunc_begin3:
+	push	rax
+	mov	eax, 39
+	movabs	r9, 7378697629483820647
+	mov	rcx, rdi
+.LBB3_1:
+	cmp	rax, 39
+	ja	.LBB3_7
+	mov	r8, rax
+	mov	rax, rcx
+	imul	r9
+	mov	rax, rdx
+	shr	rax, 63
+	sar	rdx, 2
+	add	rdx, rax
+	lea	rax, [rdx + rdx]
+	lea	rax, [rax + 4*rax]
+	mov	r10, rcx
+	sub	r10, rax
+	mov	rax, r10
+	neg	rax
+	cmovs	rax, r10
+	add	al, 48
+	mov	byte ptr [rsi + r8], al
+	add	rcx, 9
+	lea	rax, [r8 - 1]
+	cmp	rcx, 19
+	mov	rcx, rdx
+	jae	.LBB3_1
+	test	rdi, rdi
+	jns	.LBB3_6
+	inc	r8
+	add	r8, -2
+	cmp	r8, 39
+	ja	.LBB3_7
+	mov	byte ptr [rsi + r8], 45
+.LBB3_6:
+	mov	edx, 40
+	sub	rdx, r8
+	add	rsi, r8
+	mov	rax, rsi
+	pop	rcx
+	ret
+.LBB3_7:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end3:
+
+optional_trait_methods::num::format_decimal::<usize>:
+.Lfunc_begin4:
+	mov	eax, 39
+	movabs	r8, -3689348814741910323
+.LBB4_1:
+	cmp	rax, 39
+	ja	.LBB4_4
+	mov	rcx, rax
+	mov	rax, rdi
+	mul	r8
+	shr	rdx, 3
+	lea	eax, [rdx + rdx]
+	lea	eax, [rax + 4*rax]
+	mov	r9d, edi
+	sub	r9d, eax
+	or	r9b, 48
+	mov	byte ptr [rsi + rcx], r9b
+	lea	rax, [rcx - 1]
+	cmp	rdi, 10
+	mov	rdi, rdx
+	jae	.LBB4_1
+	add	rsi, rcx
+	inc	rcx
+	mov	edx, 41
+	sub	rdx, rcx
+	mov	rax, rsi
+	ret
+.LBB4_4:
+	push	rax
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end4:
+
+optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin5:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB5_2
+	lea	rcx, [rip + .LJTI5_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB5_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB5_5
+.LBB5_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB5_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB5_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB5_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB5_18
+.LBB5_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB5_15
+	jmp	.LBB5_28
+.LBB5_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB5_14
+	jmp	.LBB5_28
+.LBB5_19:
+	lea	r14, [rbx + 8]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 72]
+	cmp	rcx, 9
+	mov	eax, 8
+	cmovb	rax, r14
+	lea	rcx, [8*rcx]
+	cmovae	rcx, r12
+	test	rcx, rcx
+	je	.LBB5_26
+	add	rcx, rax
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB5_21:
+	mov	qword ptr [rsp + 24], 0
+	mov	rsi, qword ptr [rax]
+	add	rax, 8
+	mov	qword ptr [rsp + 16], rsi
+	xor	esi, esi
+.LBB5_22:
+	cmp	r12, 256
+	jae	.LBB5_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB5_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 8
+	jne	.LBB5_22
+	cmp	rax, rcx
+	jne	.LBB5_21
+.LBB5_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB5_27
+.LBB5_11:
+	mov	rdi, qword ptr [rbx + 8]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<isize>
+.LBB5_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB5_27
+.LBB5_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI5_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB5_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB5_4
+.LBB5_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB5_4
+.LBB5_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB5_27
+.LBB5_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB5_12
+.LBB5_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB5_4:
+	mov	edx, 4
+.LBB5_5:
+	mov	rdi, r15
+.LBB5_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB5_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	ecx, 10
+	mov	rdi, rbp
+	mov	rsi, rbx
+	rep movsq es:[rdi], [rsi]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB5_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB5_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end5:
+.LJTI5_0:
+	.long	.LBB5_10-.LJTI5_0
+	.long	.LBB5_11-.LJTI5_0
+	.long	.LBB5_13-.LJTI5_0
+	.long	.LBB5_19-.LJTI5_0
+.LJTI5_1:
+	.long	.LBB5_3-.LJTI5_1
+	.long	.LBB5_6-.LJTI5_1
+	.long	.LBB5_7-.LJTI5_1
+	.long	.LBB5_8-.LJTI5_1
+	.long	.LBB5_9-.LJTI5_1
+
+optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin6:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB6_2
+	lea	rcx, [rip + .LJTI6_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB6_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB6_5
+.LBB6_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB6_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB6_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB6_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB6_18
+.LBB6_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB6_15
+	jmp	.LBB6_28
+.LBB6_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB6_14
+	jmp	.LBB6_28
+.LBB6_19:
+	lea	r14, [rbx + 8]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 72]
+	cmp	rcx, 9
+	mov	eax, 8
+	cmovb	rax, r14
+	lea	rcx, [8*rcx]
+	cmovae	rcx, r12
+	test	rcx, rcx
+	je	.LBB6_26
+	add	rcx, rax
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB6_21:
+	mov	qword ptr [rsp + 24], 0
+	mov	rsi, qword ptr [rax]
+	add	rax, 8
+	mov	qword ptr [rsp + 16], rsi
+	xor	esi, esi
+.LBB6_22:
+	cmp	r12, 256
+	jae	.LBB6_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB6_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 8
+	jne	.LBB6_22
+	cmp	rax, rcx
+	jne	.LBB6_21
+.LBB6_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB6_27
+.LBB6_11:
+	mov	rdi, qword ptr [rbx + 8]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<isize>
+.LBB6_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB6_27
+.LBB6_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI6_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB6_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB6_4
+.LBB6_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB6_4
+.LBB6_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB6_27
+.LBB6_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB6_12
+.LBB6_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB6_4:
+	mov	edx, 4
+.LBB6_5:
+	mov	rdi, r15
+.LBB6_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB6_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	ecx, 10
+	mov	rdi, rbp
+	mov	rsi, rbx
+	rep movsq es:[rdi], [rsi]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB6_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB6_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end6:
+.LJTI6_0:
+	.long	.LBB6_10-.LJTI6_0
+	.long	.LBB6_11-.LJTI6_0
+	.long	.LBB6_13-.LJTI6_0
+	.long	.LBB6_19-.LJTI6_0
+.LJTI6_1:
+	.long	.LBB6_3-.LJTI6_1
+	.long	.LBB6_6-.LJTI6_1
+	.long	.LBB6_7-.LJTI6_1
+	.long	.LBB6_8-.LJTI6_1
+	.long	.LBB6_9-.LJTI6_1
+
 __rustc::rust_begin_unwind:
+.Lfunc_begin7:
 	push	rax
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.0]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.30]
 	mov	edx, 7
 	mov	edi, 2
 	call	qword ptr [rip + write@GOTPCREL]
 	call	qword ptr [rip + abort@GOTPCREL]
-.Lfunc_end0:
+.Lfunc_end7:
+
+optional_trait_methods::report_port:
+.Lfunc_begin8:
+	push	rbx
+	sub	rsp, 64
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 16]
+	movaps	xmmword ptr [rsi], xmm0
+	lea	rdx, [rsp + 12]
+	mov	dword ptr [rdx], 16
+	call	qword ptr [rip + getsockname@GOTPCREL]
+	test	eax, eax
+	js	.LBB8_1
+	xor	eax, eax
+	test	al, al
+	jne	.LBB8_7
+	movzx	ebx, word ptr [rsp + 18]
+	rol	bx, 8
+	shl	ebx, 16
+	shr	ebx, 16
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 48], 0
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.31]
+	mov	edx, 23
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	ecx, 39
+.LBB8_4:
+	cmp	rcx, 39
+	ja	.LBB8_8
+	mov	rax, rcx
+	movzx	edx, bx
+	imul	esi, edx, 52429
+	shr	esi, 19
+	lea	ecx, [rsi + rsi]
+	lea	ecx, [rcx + 4*rcx]
+	sub	ebx, ecx
+	or	bl, 48
+	mov	byte ptr [rsp + rax + 16], bl
+	lea	rcx, [rax - 1]
+	mov	ebx, esi
+	cmp	dx, 10
+	jae	.LBB8_4
+	lea	rsi, [rsp + rax]
+	add	rsi, 16
+	inc	rax
+	mov	edx, 41
+	sub	rdx, rax
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB8_7
+.LBB8_1:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB8_7:
+	add	rsp, 64
+	pop	rbx
+	ret
+.LBB8_8:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end8:
+
+optional_trait_methods::usage_error:
+.Lfunc_begin9:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.32]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	rax, qword ptr [rbx]
+	lea	rcx, [rip + .LJTI9_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB9_1:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.7]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 16
+	jmp	.LBB9_2
+.LBB9_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.12]
+	mov	edx, 39
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	jmp	.LBB9_7
+.LBB9_4:
+	mov	r14, qword ptr [rbx + 8]
+	mov	r15, qword ptr [rbx + 16]
+	mov	r12, qword ptr [rbx + 24]
+	mov	rbx, qword ptr [rbx + 32]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.9]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, r15
+	call	r13
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.10]
+	mov	edx, 2
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r12
+	mov	rdx, rbx
+	call	r13
+	jmp	.LBB9_7
+.LBB9_5:
+	mov	rsi, qword ptr [rbx + 8]
+	mov	rdx, qword ptr [rbx + 16]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.11]
+	mov	edx, 21
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB9_7
+.LBB9_3:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+.LBB9_2:
+	mov	edi, 2
+	call	r15
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, rbx
+	call	r15
+.LBB9_7:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 2
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	mov	edx, 823
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	mov	rax, rbx
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	jmp	rax
+.Lfunc_end9:
+.LJTI9_0:
+	.long	.LBB9_1-.LJTI9_0
+	.long	.LBB9_3-.LJTI9_0
+	.long	.LBB9_4-.LJTI9_0
+	.long	.LBB9_5-.LJTI9_0
+	.long	.LBB9_6-.LJTI9_0
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin10:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB10_1
+	xor	eax, eax
+	mov	qword ptr [r14 + 1024], rax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	cmovae	rdx, rax
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB10_3
+	mov	edi, 1
+	mov	rsi, r15
+	mov	rdx, rbx
+	xor	ecx, ecx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	jmp	optional_trait_methods::connection::write_fd
+.LBB10_1:
+	cmp	rax, rdx
+	jb	.LBB10_4
+.LBB10_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB10_4:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end10:
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin11:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB11_1
+	xor	eax, eax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	mov	qword ptr [r14 + 1024], rax
+	cmovae	rdx, rax
+	mov	ebp, dword ptr [r14 + 1032]
+	mov	edi, ebp
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB11_3
+	mov	edi, ebp
+	mov	rsi, r15
+	mov	rdx, rbx
+	mov	ecx, 1
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	optional_trait_methods::connection::write_fd
+.LBB11_1:
+	cmp	rax, rdx
+	jb	.LBB11_4
+.LBB11_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB11_4:
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end11:
+
+<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget> as optional_trait_methods::state_machine::Controller>::parse_command:
+.Lfunc_begin12:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 168
+	mov	rbx, rdi
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.35]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 22
+	#APP
+	#NO_APP
+	lea	rdi, [rsi + 1]
+	movzx	ecx, byte ptr [rsi]
+	cmp	ecx, 45
+	je	.LBB12_4
+	cmp	ecx, 43
+	jne	.LBB12_9
+	cmp	rdx, 1
+	jne	.LBB12_6
+	mov	byte ptr [rbx + 1], 0
+	jmp	.LBB12_20
+.LBB12_4:
+	cmp	rdx, 1
+	jne	.LBB12_6
+	mov	byte ptr [rbx + 1], 1
+	jmp	.LBB12_20
+.LBB12_6:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_10
+.LBB12_7:
+	mov	qword ptr [rbx + 8], 1
+.LBB12_8:
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+	jmp	.LBB12_21
+.LBB12_9:
+	cmp	rdx, 2
+	jb	.LBB12_11
+.LBB12_10:
+	cmp	word ptr [rsi], 11563
+	je	.LBB12_18
+.LBB12_11:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.39]
+	mov	r8, rsp
+	mov	qword ptr [r8], rax
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	ecx, 42
+	jne	.LBB12_27
+	mov	r15, rdx
+	dec	r15
+	jne	.LBB12_22
+	setne	al
+	movzx	ecx, al
+	sub	r15, rcx
+	add	rdi, rcx
+.LBB12_14:
+	mov	r14, r15
+.LBB12_15:
+	movzx	r12d, al
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB12_47
+	cmp	r15, r14
+	jne	.LBB12_57
+	mov	byte ptr [rbx], 8
+	jmp	.LBB12_39
+.LBB12_18:
+	cmp	rdx, 2
+	jne	.LBB12_55
+	mov	byte ptr [rbx + 1], 2
+.LBB12_20:
+	mov	al, 7
+.LBB12_21:
+	mov	byte ptr [rbx], al
+	jmp	.LBB12_152
+.LBB12_22:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_27
+	xor	eax, eax
+	test	r15, r15
+	setne	al
+	sub	r15, rax
+	add	rdi, rax
+	cmp	rdx, 3
+	jb	.LBB12_14
+	xor	r14d, r14d
+.LBB12_25:
+	cmp	byte ptr [rdi + r14], 32
+	je	.LBB12_15
+	inc	r14
+	cmp	r15, r14
+	jne	.LBB12_25
+	jmp	.LBB12_14
+.LBB12_27:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.41]
+	mov	qword ptr [r8], rax
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 2
+	jb	.LBB12_29
+	cmp	word ptr [rsi], 32298
+	je	.LBB12_60
+.LBB12_29:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.43]
+	mov	qword ptr [r8], rax
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 3
+	jb	.LBB12_31
+	movzx	eax, word ptr [rsi]
+	xor	eax, 32298
+	movzx	r8d, byte ptr [rsi + 2]
+	xor	r8d, 126
+	or	r8w, ax
+	je	.LBB12_62
+.LBB12_31:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.45]
+	mov	r8, rsp
+	mov	qword ptr [r8], rax
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	ecx, 37
+	je	.LBB12_41
+	cmp	ecx, 47
+	jne	.LBB12_50
+	mov	r15, rdx
+	dec	r15
+	jne	.LBB12_64
+	setne	al
+	movzx	ecx, al
+	sub	r15, rcx
+	add	rdi, rcx
+.LBB12_35:
+	mov	r14, r15
+.LBB12_36:
+	movzx	r12d, al
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB12_47
+	cmp	r15, r14
+	jne	.LBB12_57
+	mov	byte ptr [rbx], 9
+.LBB12_39:
+	mov	qword ptr [rbx + 8], 0
+	jmp	.LBB12_40
+.LBB12_41:
+	mov	r15, rdx
+	dec	r15
+	jne	.LBB12_69
+	setne	al
+	movzx	ecx, al
+	sub	r15, rcx
+	add	rdi, rcx
+.LBB12_43:
+	mov	r14, r15
+.LBB12_44:
+	movzx	r12d, al
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB12_47
+	cmp	r15, r14
+	jne	.LBB12_57
+	mov	byte ptr [rbx], 9
+	jmp	.LBB12_91
+.LBB12_47:
+	inc	r12
+.LBB12_48:
+	mov	qword ptr [rbx + 8], r12
+.LBB12_49:
+	mov	byte ptr [rbx + 16], 2
+	jmp	.LBB12_151
+.LBB12_50:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	qword ptr [r8], rax
+	mov	qword ptr [r8 + 8], 20
+	#APP
+	#NO_APP
+	cmp	ecx, 111
+	jle	.LBB12_77
+	lea	eax, [rcx - 112]
+	cmp	eax, 7
+	ja	.LBB12_75
+	lea	r8, [rip + .LJTI12_0]
+	movsxd	rax, dword ptr [r8 + 4*rax]
+	add	rax, r8
+	jmp	rax
+.LBB12_53:
+	cmp	rdx, 1
+	jne	.LBB12_143
+	xor	eax, eax
+	jmp	.LBB12_145
+.LBB12_55:
+	cmp	byte ptr [rsi + 2], 32
+	jne	.LBB12_11
+	mov	qword ptr [rbx + 8], 2
+	jmp	.LBB12_8
+.LBB12_57:
+	lea	rax, [r14 + r12]
+	inc	rax
+.LBB12_58:
+	mov	qword ptr [rbx + 8], rax
+.LBB12_59:
+	mov	byte ptr [rbx + 16], 3
+	jmp	.LBB12_151
+.LBB12_60:
+	lea	rax, [rsi + 2]
+	mov	r14, rdx
+	sub	r14, 2
+	jne	.LBB12_82
+	setne	cl
+	movzx	edx, cl
+	sub	r14, rdx
+	add	rax, rdx
+	mov	r15, r14
+	jmp	.LBB12_88
+.LBB12_62:
+	lea	r14, [rsi + 3]
+	mov	r15, rdx
+	sub	r15, 3
+	jne	.LBB12_92
+	setne	al
+	movzx	ecx, al
+	sub	r15, rcx
+	add	r14, rcx
+	mov	rbp, r15
+	jmp	.LBB12_98
+.LBB12_64:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_74
+	xor	eax, eax
+	test	r15, r15
+	setne	al
+	sub	r15, rax
+	add	rdi, rax
+	cmp	rdx, 3
+	jb	.LBB12_35
+	xor	r14d, r14d
+.LBB12_67:
+	cmp	byte ptr [rdi + r14], 32
+	je	.LBB12_36
+	inc	r14
+	cmp	r15, r14
+	jne	.LBB12_67
+	jmp	.LBB12_35
+.LBB12_69:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_74
+	xor	eax, eax
+	test	r15, r15
+	setne	al
+	sub	r15, rax
+	add	rdi, rax
+	cmp	rdx, 3
+	jb	.LBB12_43
+	xor	r14d, r14d
+.LBB12_72:
+	cmp	byte ptr [rdi + r14], 32
+	je	.LBB12_44
+	inc	r14
+	cmp	r15, r14
+	jne	.LBB12_72
+	jmp	.LBB12_43
+.LBB12_74:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 20
+	#APP
+	#NO_APP
+.LBB12_75:
+	mov	rdi, rsi
+	mov	rsi, rdx
+	call	optional_trait_methods::commands::unrecognized
+	mov	qword ptr [rbx + 8], 0
+.LBB12_76:
+	mov	byte ptr [rbx + 16], al
+.LBB12_151:
+	mov	byte ptr [rbx], -1
+.LBB12_152:
+	add	rsp, 168
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB12_77:
+	cmp	ecx, 63
+	je	.LBB12_129
+	cmp	ecx, 71
+	je	.LBB12_132
+	cmp	ecx, 103
+	jne	.LBB12_75
+	cmp	rdx, 1
+	jne	.LBB12_146
+	mov	al, 5
+	jmp	.LBB12_21
+.LBB12_82:
+	cmp	byte ptr [rax], 32
+	jne	.LBB12_29
+	xor	ecx, ecx
+	test	r14, r14
+	setne	cl
+	mov	r15, r14
+	sub	r15, rcx
+	add	rax, rcx
+	cmp	r14, 2
+	jb	.LBB12_87
+	xor	r14d, r14d
+.LBB12_85:
+	cmp	byte ptr [rax + r14], 32
+	je	.LBB12_88
+	inc	r14
+	cmp	r15, r14
+	jne	.LBB12_85
+.LBB12_87:
+	mov	r14, r15
+.LBB12_88:
+	movzx	r12d, cl
+	mov	rdi, rax
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB12_107
+	cmp	r15, r14
+	jne	.LBB12_109
+	mov	byte ptr [rbx], 8
+.LBB12_91:
+	mov	qword ptr [rbx + 8], 1
+.LBB12_40:
+	mov	qword ptr [rbx + 16], rdx
+	jmp	.LBB12_152
+.LBB12_92:
+	cmp	byte ptr [r14], 32
+	jne	.LBB12_31
+	xor	eax, eax
+	test	r15, r15
+	setne	al
+	mov	rbp, r15
+	sub	rbp, rax
+	add	r14, rax
+	cmp	r15, 2
+	jb	.LBB12_97
+	xor	r15d, r15d
+.LBB12_95:
+	cmp	byte ptr [r14 + r15], 32
+	je	.LBB12_98
+	inc	r15
+	cmp	rbp, r15
+	jne	.LBB12_95
+.LBB12_97:
+	mov	r15, rbp
+.LBB12_98:
+	movzx	r13d, al
+	mov	rdi, r14
+	mov	rsi, r15
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB12_108
+	add	r14, r15
+	xor	eax, eax
+	mov	rcx, rbp
+	sub	rcx, r15
+	setne	al
+	cmp	rcx, 1
+	mov	r12, rcx
+	adc	r12, -1
+	lea	rsi, [r15 + r13]
+	add	rsi, 3
+	add	r14, rax
+	mov	r13, r12
+	cmp	rcx, 2
+	jb	.LBB12_104
+	not	r15
+	add	r15, rbp
+	xor	r13d, r13d
+.LBB12_101:
+	cmp	byte ptr [r14 + r13], 32
+	je	.LBB12_104
+	inc	r13
+	cmp	r15, r13
+	jne	.LBB12_101
+	mov	r13, r12
+.LBB12_104:
+	mov	r15, rdx
+	add	rsi, rax
+	mov	rdi, r14
+	mov	r14, rsi
+	mov	rsi, r13
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB12_110
+	cmp	r12, r13
+	jne	.LBB12_133
+	mov	byte ptr [rbx], 8
+	mov	qword ptr [rbx + 8], 2
+	mov	qword ptr [rbx + 16], r15
+	mov	qword ptr [rbx + 24], rdx
+	jmp	.LBB12_152
+.LBB12_107:
+	or	r12, 2
+	jmp	.LBB12_48
+.LBB12_108:
+	add	r13, 3
+	mov	qword ptr [rbx + 8], r13
+	jmp	.LBB12_49
+.LBB12_109:
+	lea	rax, [r14 + r12]
+	add	rax, 2
+	jmp	.LBB12_58
+.LBB12_110:
+	mov	qword ptr [rbx + 8], r14
+	jmp	.LBB12_49
+.LBB12_111:
+	mov	rax, rdx
+	dec	rax
+	jne	.LBB12_134
+	mov	edx, 1
+.LBB12_113:
+	mov	byte ptr [rbx], 10
+	mov	qword ptr [rbx + 8], rdx
+	jmp	.LBB12_152
+.LBB12_114:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB12_116
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_75
+.LBB12_116:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB12_150
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB12_171
+	mov	byte ptr [rbx], 1
+	jmp	.LBB12_124
+.LBB12_119:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB12_121
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_153
+.LBB12_121:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB12_150
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB12_171
+	mov	byte ptr [rbx], 3
+.LBB12_124:
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB12_152
+.LBB12_125:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB12_127
+.LBB12_126:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_147
+.LBB12_127:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	je	.LBB12_149
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB12_76
+.LBB12_129:
+	mov	rax, rdx
+	dec	rax
+	jne	.LBB12_162
+	mov	qword ptr [rsp], rdi
+	mov	qword ptr [rsp + 8], rax
+	mov	qword ptr [rsp + 16], 1
+	xor	ebp, ebp
+.LBB12_131:
+	mov	byte ptr [rbx], 2
+	mov	byte ptr [rbx + 1], bpl
+	mov	byte ptr [rbx + 2], al
+	jmp	.LBB12_152
+.LBB12_132:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB12_158
+	jmp	.LBB12_157
+.LBB12_133:
+	add	r13, r14
+	mov	qword ptr [rbx + 8], r13
+	jmp	.LBB12_59
+.LBB12_134:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_75
+	lea	r15, [rdx - 2]
+	add	rsi, 2
+	mov	r14, r15
+	cmp	rax, 1
+	je	.LBB12_140
+	add	rdx, -2
+	xor	r14d, r14d
+.LBB12_137:
+	cmp	byte ptr [rsi + r14], 32
+	je	.LBB12_140
+	inc	r14
+	cmp	rdx, r14
+	jne	.LBB12_137
+	mov	r14, r15
+.LBB12_140:
+	mov	rdi, rsi
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<usize>
+	test	al, 1
+	je	.LBB12_169
+	sub	r15, r14
+	je	.LBB12_113
+	add	r14, 2
+	mov	qword ptr [rbx + 8], r14
+	jmp	.LBB12_59
+.LBB12_143:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_75
+	mov	qword ptr [rbx + 8], 1
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+.LBB12_145:
+	mov	byte ptr [rbx], al
+	jmp	.LBB12_152
+.LBB12_146:
+	cmp	byte ptr [rdi], 32
+	je	.LBB12_7
+.LBB12_147:
+	cmp	ecx, 63
+	je	.LBB12_161
+	cmp	ecx, 71
+	jne	.LBB12_75
+	jmp	.LBB12_156
+.LBB12_149:
+	lea	r14, [rsp + 80]
+	mov	rsi, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	je	.LBB12_170
+.LBB12_150:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], cl
+	jmp	.LBB12_151
+.LBB12_153:
+	mov	r8d, ecx
+	add	r8b, -63
+	je	.LBB12_161
+	rol	r8b, 5
+	movzx	r8d, r8b
+	cmp	r8d, 1
+	je	.LBB12_156
+	cmp	r8d, 7
+	jne	.LBB12_75
+	jmp	.LBB12_126
+.LBB12_156:
+	lea	rax, [rdx - 1]
+.LBB12_157:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_75
+.LBB12_158:
+	lea	rsi, [rsp + 144]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::regs::<isize>
+	mov	rcx, qword ptr [r14 + 8]
+	mov	al, byte ptr [r14 + 16]
+	cmp	byte ptr [r14], 0
+	jne	.LBB12_165
+	movups	xmm0, xmmword ptr [rsp + 17]
+	movups	xmm1, xmmword ptr [rsp + 33]
+	movups	xmm2, xmmword ptr [rsp + 49]
+	movups	xmm3, xmmword ptr [rsp + 64]
+	movups	xmmword ptr [rsp + 127], xmm3
+	movaps	xmmword ptr [rsp + 112], xmm2
+	movaps	xmmword ptr [rsp + 96], xmm1
+	movaps	xmmword ptr [rsp + 80], xmm0
+	cmp	qword ptr [rsp + 152], 0
+	je	.LBB12_166
+	mov	rax, qword ptr [rsp + 160]
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB12_8
+.LBB12_161:
+	lea	rax, [rdx - 1]
+.LBB12_162:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_75
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	test	rax, rax
+	setne	bpl
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::names
+	mov	al, byte ptr [r14 + 8]
+	cmp	al, -1
+	je	.LBB12_167
+	mov	rcx, qword ptr [rsp + 80]
+.LBB12_165:
+	mov	qword ptr [rbx + 8], rcx
+	jmp	.LBB12_76
+.LBB12_166:
+	mov	qword ptr [rbx + 8], rcx
+	mov	byte ptr [rbx + 16], al
+	movaps	xmm0, xmmword ptr [rsp + 80]
+	movaps	xmm1, xmmword ptr [rsp + 96]
+	movaps	xmm2, xmmword ptr [rsp + 112]
+	movups	xmmword ptr [rbx + 17], xmm0
+	movups	xmmword ptr [rbx + 33], xmm1
+	movups	xmmword ptr [rbx + 49], xmm2
+	movups	xmm0, xmmword ptr [rsp + 127]
+	movups	xmmword ptr [rbx + 64], xmm0
+	mov	al, 6
+	jmp	.LBB12_21
+.LBB12_167:
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB12_171
+	mov	al, byte ptr [rsp + 80]
+	jmp	.LBB12_131
+.LBB12_169:
+	mov	qword ptr [rbx + 8], 2
+	jmp	.LBB12_49
+.LBB12_170:
+	cmp	qword ptr [rsp + 8], 0
+	je	.LBB12_172
+.LBB12_171:
+	mov	rax, qword ptr [rsp + 16]
+	jmp	.LBB12_58
+.LBB12_172:
+	mov	byte ptr [rbx], 4
+	mov	qword ptr [rbx + 8], r15
+	mov	qword ptr [rbx + 16], rax
+	jmp	.LBB12_152
+.Lfunc_end12:
+.LJTI12_0:
+	.long	.LBB12_53-.LJTI12_0
+	.long	.LBB12_75-.LJTI12_0
+	.long	.LBB12_119-.LJTI12_0
+	.long	.LBB12_114-.LJTI12_0
+	.long	.LBB12_75-.LJTI12_0
+	.long	.LBB12_111-.LJTI12_0
+	.long	.LBB12_75-.LJTI12_0
+	.long	.LBB12_125-.LJTI12_0
+
+main:
+.Lfunc_begin13:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 3592
+	cmp	rdi, 2
+	jl	.LBB13_104
+	mov	rbx, rsi
+	mov	ebp, 1
+	lea	r12, [rsp + 1016]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 208], 0
+	mov	qword ptr [rsp + 400], 0
+	mov	qword ptr [rsp + 304], 0
+	mov	qword ptr [rsp + 312], 0
+	mov	qword ptr [rsp + 416], 0
+	mov	qword ptr [rsp + 408], 0
+	mov	qword ptr [rsp + 296], rsi
+	mov	qword ptr [rsp + 120], rdi
+.LBB13_2:
+	mov	r13, qword ptr [rbx + 8*rbp]
+	mov	rdi, r13
+	call	rcx
+	mov	rbx, rax
+	test	rax, rax
+	je	.LBB13_7
+	xor	r15d, r15d
+.LBB13_4:
+	cmp	byte ptr [r13 + r15], 61
+	je	.LBB13_9
+	inc	r15
+	cmp	rbx, r15
+	jne	.LBB13_4
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, rbx
+	jmp	.LBB13_8
+.LBB13_7:
+	mov	qword ptr [rsp + 16], 0
+	xor	r15d, r15d
+.LBB13_8:
+	jmp	.LBB13_10
+.LBB13_9:
+	lea	rax, [r15 + 1]
+	mov	rcx, rbx
+	sub	rcx, rax
+	mov	qword ptr [rsp + 448], rcx
+	lea	rax, [r15 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 16], rax
+.LBB13_10:
+	mov	qword ptr [rsp + 24], rbp
+	lea	rax, [rbp + 1]
+	mov	qword ptr [rsp], rax
+	mov	qword ptr [rsp + 1000], 0
+	mov	qword ptr [rsp + 1008], 7
+	mov	edx, 168
+	mov	rdi, r12
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.24]
+	call	r14
+	mov	r14d, 32
+.LBB13_11:
+	cmp	qword ptr [rsp + r14 + 992], r15
+	jne	.LBB13_14
+	movzx	r12d, byte ptr [rsp + r14 + 1000]
+	mov	rbp, qword ptr [rsp + r14 + 984]
+	mov	rdi, rbp
+	mov	rsi, r13
+	mov	rdx, r15
+	call	qword ptr [rip + bcmp@GOTPCREL]
+	test	eax, eax
+	jne	.LBB13_14
+	cmp	r12d, 255
+	jne	.LBB13_15
+.LBB13_14:
+	add	r14, 24
+	cmp	r14, 200
+	jne	.LBB13_11
+	jmp	.LBB13_213
+.LBB13_15:
+	cmp	r12, 255
+	je	.LBB13_213
+	cmp	r12b, 4
+	jae	.LBB13_19
+	mov	r11, qword ptr [rsp + 16]
+	test	r11, r11
+	mov	rbx, qword ptr [rsp + 296]
+	mov	r13, qword ptr [rsp + 120]
+	je	.LBB13_21
+	mov	r9, qword ptr [rsp]
+	mov	r10, qword ptr [rsp + 448]
+	jmp	.LBB13_24
+.LBB13_19:
+	cmp	qword ptr [rsp + 16], 0
+	mov	rbx, qword ptr [rsp + 296]
+	mov	r13, qword ptr [rsp + 120]
+	jne	.LBB13_222
+	mov	r11d, 1
+	mov	r9, qword ptr [rsp]
+	xor	r10d, r10d
+	jmp	.LBB13_24
+.LBB13_21:
+	cmp	qword ptr [rsp], r13
+	mov	r14, qword ptr [rsp + 24]
+	jge	.LBB13_223
+	mov	rdi, qword ptr [rbx + 8*r14 + 8]
+	test	rdi, rdi
+	je	.LBB13_223
+	mov	qword ptr [rsp + 16], rdi
+	call	qword ptr [rip + strlen@GOTPCREL]
+	mov	r11, qword ptr [rsp + 16]
+	mov	r10, rax
+	add	r14, 2
+	mov	r9, r14
+.LBB13_24:
+	cmp	r12b, 5
+	jne	.LBB13_26
+	mov	al, 1
+	mov	qword ptr [rsp + 416], rax
+	mov	rbp, r9
+	cmp	r9, r13
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 1016]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB13_2
+	jmp	.LBB13_225
+.LBB13_26:
+	movzx	eax, r12b
+	mov	eax, eax
+	lea	rcx, [rip + .LJTI13_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB13_27:
+	test	r10, r10
+	je	.LBB13_240
+	mov	qword ptr [rsp + 424], r11
+	mov	qword ptr [rsp + 432], r10
+	mov	dword ptr [rsp + 12], eax
+	cmp	word ptr [rsp + 208], 0
+	mov	ax, 1
+	jmp	.LBB13_77
+.LBB13_29:
+	mov	al, 1
+	mov	qword ptr [rsp + 312], rax
+	jmp	.LBB13_51
+.LBB13_30:
+	mov	qword ptr [rsp + 440], r10
+	mov	qword ptr [rsp + 400], r11
+	jmp	.LBB13_51
+.LBB13_31:
+	cmp	r10, 8
+	je	.LBB13_42
+	cmp	r10, 4
+	jne	.LBB13_241
+	mov	eax, 4
+	cmp	byte ptr [r11], 101
+	jne	.LBB13_242
+	cmp	byte ptr [r11 + 1], 120
+	jne	.LBB13_242
+	cmp	byte ptr [r11 + 2], 105
+	jne	.LBB13_242
+	cmp	byte ptr [r11 + 3], 116
+	jne	.LBB13_242
+	jmp	.LBB13_50
+.LBB13_37:
+	test	r10, r10
+	je	.LBB13_239
+	lea	rcx, [r11 + 1]
+	lea	rdi, [r10 - 1]
+	movzx	eax, byte ptr [r11]
+	cmp	eax, 43
+	je	.LBB13_52
+	cmp	eax, 45
+	jne	.LBB13_53
+	mov	sil, 1
+	jmp	.LBB13_54
+.LBB13_41:
+	mov	al, 1
+	mov	qword ptr [rsp + 408], rax
+	jmp	.LBB13_51
+.LBB13_42:
+	mov	eax, 8
+	cmp	byte ptr [r11], 99
+	jne	.LBB13_242
+	cmp	byte ptr [r11 + 1], 111
+	jne	.LBB13_242
+	cmp	byte ptr [r11 + 2], 110
+	jne	.LBB13_242
+	cmp	byte ptr [r11 + 3], 116
+	jne	.LBB13_242
+	cmp	byte ptr [r11 + 4], 105
+	jne	.LBB13_242
+	cmp	byte ptr [r11 + 5], 110
+	jne	.LBB13_242
+	cmp	byte ptr [r11 + 6], 117
+	jne	.LBB13_242
+	cmp	byte ptr [r11 + 7], 101
+	jne	.LBB13_242
+.LBB13_50:
+	cmp	r10, 8
+	setne	al
+	mov	qword ptr [rsp + 304], rax
+.LBB13_51:
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 1016]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	rbp, r9
+	jmp	.LBB13_78
+.LBB13_52:
+	xor	esi, esi
+	jmp	.LBB13_54
+.LBB13_53:
+	xor	esi, esi
+	mov	rdi, r10
+	mov	rcx, r11
+.LBB13_54:
+	cmp	rdi, 1
+	jbe	.LBB13_61
+	mov	al, byte ptr [rcx]
+	cmp	al, 48
+	jne	.LBB13_63
+	movzx	eax, byte ptr [rcx + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB13_64
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB13_80
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB13_79
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB13_64
+	mov	r8b, 16
+	mov	r12b, 1
+	jmp	.LBB13_82
+.LBB13_61:
+	test	rdi, rdi
+	je	.LBB13_254
+	mov	al, byte ptr [rcx]
+.LBB13_63:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB13_254
+.LBB13_64:
+	add	rdi, rcx
+	mov	rax, rdi
+	dec	rax
+	je	.LBB13_66
+	cmp	byte ptr [rax], 95
+	je	.LBB13_254
+.LBB13_66:
+	xor	r14d, r14d
+	xor	eax, eax
+.LBB13_67:
+	cmp	rcx, rdi
+	je	.LBB13_75
+	mov	r8b, byte ptr [rcx]
+	inc	rcx
+	cmp	r8b, 95
+	jne	.LBB13_70
+	test	r14b, 1
+	mov	r14d, 0
+	je	.LBB13_254
+	jmp	.LBB13_67
+.LBB13_70:
+	add	r8b, -48
+	cmp	r8b, 9
+	ja	.LBB13_254
+	mov	dx, 10
+	mul	dx
+	jo	.LBB13_254
+	movzx	edx, r8b
+	mov	r14b, 1
+	test	sil, sil
+	je	.LBB13_74
+	sub	ax, dx
+	jb	.LBB13_254
+	jmp	.LBB13_67
+.LBB13_74:
+	add	ax, dx
+	jb	.LBB13_254
+	jmp	.LBB13_67
+.LBB13_75:
+	mov	dword ptr [rsp + 12], eax
+.LBB13_76:
+	cmp	word ptr [rsp + 208], 0
+	mov	ax, 2
+.LBB13_77:
+	mov	qword ptr [rsp + 208], rax
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 1016]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	rbp, r9
+	jne	.LBB13_235
+.LBB13_78:
+	cmp	rbp, r13
+	jl	.LBB13_2
+	jmp	.LBB13_224
+.LBB13_79:
+	mov	r8b, 8
+	jmp	.LBB13_81
+.LBB13_80:
+	mov	r8b, 2
+.LBB13_81:
+	xor	r12d, r12d
+.LBB13_82:
+	mov	r14, r10
+	cmp	rdi, 2
+	je	.LBB13_253
+	lea	r10, [rcx + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jb	.LBB13_87
+	test	r12b, r12b
+	je	.LBB13_253
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB13_253
+	add	al, -87
+	mov	edx, eax
+.LBB13_87:
+	cmp	dl, r8b
+	jae	.LBB13_253
+	add	rcx, rdi
+	mov	rax, rcx
+	dec	rax
+	je	.LBB13_90
+	cmp	byte ptr [rax], 95
+	je	.LBB13_253
+.LBB13_90:
+	movzx	edi, r8b
+	xor	eax, eax
+	mov	dword ptr [rsp + 12], 0
+.LBB13_91:
+	cmp	r10, rcx
+	je	.LBB13_76
+	mov	dl, byte ptr [r10]
+	inc	r10
+	cmp	dl, 95
+	jne	.LBB13_94
+	test	al, 1
+	mov	eax, 0
+	je	.LBB13_253
+	jmp	.LBB13_91
+.LBB13_94:
+	mov	qword ptr [rsp + 16], r11
+	lea	r11d, [rdx - 48]
+	cmp	r11b, 10
+	jb	.LBB13_98
+	test	r12b, r12b
+	je	.LBB13_252
+	or	dl, 32
+	lea	eax, [rdx - 97]
+	cmp	al, 5
+	ja	.LBB13_252
+	add	dl, -87
+	mov	r11d, edx
+.LBB13_98:
+	cmp	r11b, r8b
+	jae	.LBB13_252
+	mov	eax, dword ptr [rsp + 12]
+	mul	di
+	mov	dword ptr [rsp + 12], eax
+	jo	.LBB13_252
+	movzx	edx, r11b
+	mov	al, 1
+	test	sil, sil
+	je	.LBB13_102
+	mov	r11d, dword ptr [rsp + 12]
+	sub	r11w, dx
+	jmp	.LBB13_103
+.LBB13_102:
+	mov	r11d, dword ptr [rsp + 12]
+	add	r11w, dx
+.LBB13_103:
+	mov	dword ptr [rsp + 12], r11d
+	mov	r11, qword ptr [rsp + 16]
+	jb	.LBB13_253
+	jmp	.LBB13_91
+.LBB13_104:
+	xor	r14d, r14d
+	xor	ebp, ebp
+.LBB13_105:
+	xorps	xmm0, xmm0
+	lea	r12, [rsp + 544]
+	movups	xmmword ptr [r12 + 328], xmm0
+	movups	xmmword ptr [r12 + 312], xmm0
+	movups	xmmword ptr [r12 + 296], xmm0
+	xor	ebx, ebx
+	mov	qword ptr [r12 + 344], rbx
+	lea	rdi, [rsp + 1016]
+	mov	r13, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 272
+	xor	esi, esi
+	call	r13
+	mov	qword ptr [r12 + 288], r14
+	mov	qword ptr [r12 + 352], 1
+	movabs	rax, 9223372036854775807
+	lea	rcx, [rax + 1]
+	mov	qword ptr [r12 + 360], rcx
+	mov	qword ptr [r12 + 368], rax
+	mov	qword ptr [r12 - 8], rbx
+	lea	r15, [rsp + 1000]
+	mov	edx, 288
+	mov	rdi, r12
+	mov	rsi, r15
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 2040]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 1000]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 3080]
+	mov	edx, 512
+	xor	esi, esi
+	call	r13
+	mov	eax, ebp
+	shr	eax, 8
+	mov	dword ptr [rsp + 16], eax
+	mov	r13, qword ptr [rip + read@GOTPCREL]
+	xor	ecx, ecx
+	mov	dword ptr [rsp], eax
+	mov	dword ptr [rsp + 24], ebp
+.LBB13_106:
+	mov	rdi, qword ptr [rsp + 2024]
+	mov	rax, qword ptr [rsp + 2032]
+	xor	r12d, r12d
+	mov	rdx, rcx
+	xor	ebp, ebp
+.LBB13_107:
+	mov	rsi, rax
+	mov	rax, rdi
+.LBB13_108:
+	cmp	rsi, rax
+	jb	.LBB13_112
+	mov	qword ptr [rsp + 3064], rbx
+	cmp	rdx, 1025
+	mov	rsi, r14
+	mov	eax, 1
+	cmovae	rsi, rax
+	cmovae	rdx, rbx
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 1024
+	xor	edi, edi
+	mov	rsi, r15
+	call	r13
+	test	rax, rax
+	js	.LBB13_122
+	je	.LBB13_123
+	mov	qword ptr [rsp + 2024], rax
+	mov	qword ptr [rsp + 2032], 0
+	xor	ecx, ecx
+	xor	edx, edx
+	xor	esi, esi
+.LBB13_112:
+	cmp	rax, rsi
+	jb	.LBB13_217
+	cmp	rax, 1024
+	ja	.LBB13_217
+	cmp	rsi, rax
+	je	.LBB13_108
+	inc	rsi
+	mov	rdi, rax
+	neg	rdi
+.LBB13_116:
+	mov	r8b, byte ptr [rsp + rsi + 999]
+	mov	qword ptr [rsp + 2032], rsi
+	cmp	r8b, 10
+	je	.LBB13_126
+	cmp	rbp, 511
+	jbe	.LBB13_119
+	mov	r12b, 1
+	jmp	.LBB13_120
+.LBB13_119:
+	mov	byte ptr [rsp + rbp + 3080], r8b
+	inc	rbp
+.LBB13_120:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB13_116
+	mov	rdi, rax
+	jmp	.LBB13_107
+.LBB13_122:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB13_123:
+	test	rbp, rbp
+	setne	al
+	or	al, r12b
+	test	al, 1
+	je	.LBB13_221
+	test	r12b, 1
+	jne	.LBB13_130
+	mov	ecx, 0
+	mov	edx, 0
+	cmp	rbp, 512
+	ja	.LBB13_218
+	jmp	.LBB13_128
+.LBB13_126:
+	test	r12b, 1
+	jne	.LBB13_130
+	cmp	rbp, 512
+	ja	.LBB13_217
+.LBB13_128:
+	test	rbp, rbp
+	setne	al
+	test	byte ptr [rsp + 16], al
+	jne	.LBB13_131
+	test	rbp, rbp
+	je	.LBB13_106
+	jmp	.LBB13_132
+.LBB13_130:
+	mov	qword ptr [rsp + 136], 3
+	mov	qword ptr [rsp + 144], 512
+	mov	byte ptr [rsp + 152], 4
+	mov	byte ptr [rsp + 128], -1
+	lea	rdi, [rsp + 456]
+	mov	rsi, r14
+	lea	rdx, [rsp + 128]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	mov	ebp, dword ptr [rsp + 24]
+	mov	r12d, dword ptr [rsp]
+	jmp	.LBB13_208
+.LBB13_131:
+	mov	rdi, r14
+	lea	rsi, [rsp + 3080]
+	mov	rdx, rbp
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB13_132:
+	lea	r12, [rsp + 920]
+	mov	rdi, r12
+	lea	rsi, [rsp + 3080]
+	mov	rdx, rbp
+	call	<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget> as optional_trait_methods::state_machine::Controller>::parse_command
+	cmp	byte ptr [rsp + 920], -1
+	je	.LBB13_138
+	mov	ecx, 10
+	lea	rdi, [rsp + 216]
+	mov	rsi, r12
+	rep movsq es:[rdi], [rsi]
+	movzx	r12d, byte ptr [rsp + 216]
+	lea	rbp, [r12 - 6]
+	cmp	r12, 7
+	cmovb	rbp, rbx
+	lea	rcx, [rip + .LJTI13_5]
+	movsxd	rax, dword ptr [rcx + 4*rbp]
+	add	rax, rcx
+	jmp	rax
+.LBB13_134:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 15
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint
+	lea	rcx, [rip + .LJTI13_6]
+	movsxd	rax, dword ptr [rcx + 4*rbp]
+	add	rax, rcx
+	jmp	rax
+.LBB13_135:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI13_7]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB13_136:
+	mov	cl, 1
+	mov	rax, qword ptr [rsp + 832]
+	jmp	.LBB13_137
+.LBB13_138:
+	mov	eax, dword ptr [rsp + 936]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rdx, qword ptr [rsp + 296]
+	cmovne	rdx, qword ptr [rsp + 928]
+	sete	cl
+	mov	r12d, dword ptr [rsp]
+	movzx	r12d, r12b
+	cmovne	r12d, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 136], rcx
+	mov	qword ptr [rsp + 296], rdx
+	mov	qword ptr [rsp + 144], rdx
+	mov	byte ptr [rsp + 152], r12b
+	mov	byte ptr [rsp + 128], -1
+	lea	rdi, [rsp + 456]
+	mov	rsi, r14
+	lea	rdx, [rsp + 128]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	mov	ebp, dword ptr [rsp + 24]
+	jmp	.LBB13_208
+.LBB13_139:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 14
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 224]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::undo
+	test	rax, rax
+	mov	ebp, dword ptr [rsp + 24]
+	je	.LBB13_186
+	mov	qword ptr [rsp + 136], 0
+	mov	qword ptr [rsp + 144], rax
+	mov	qword ptr [rsp + 152], rdx
+	jmp	.LBB13_146
+.LBB13_141:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI13_8]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB13_142:
+	mov	rax, qword ptr [rsp + 832]
+	jmp	.LBB13_171
+.LBB13_143:
+	mov	r12, qword ptr [rsp + 224]
+	cmp	r12, 2
+	jne	.LBB13_148
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 28
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 232]
+	mov	rdx, qword ptr [rsp + 240]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleBounds>::scale_bounds
+	test	rax, rax
+	mov	ebp, dword ptr [rsp + 24]
+	je	.LBB13_186
+	mov	qword ptr [rsp + 136], 0
+	mov	qword ptr [rsp + 144], rax
+	mov	qword ptr [rsp + 152], 26
+.LBB13_146:
+	mov	byte ptr [rsp + 128], -1
+	jmp	.LBB13_187
+.LBB13_147:
+	mov	r12, qword ptr [rsp + 224]
+	lea	rbp, [rsp + 32]
+	jmp	.LBB13_149
+.LBB13_148:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 15
+	lea	rbp, [rsp + 32]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint
+.LBB13_149:
+	test	r12, r12
+	je	.LBB13_157
+	cmp	r12d, 1
+	jne	.LBB13_158
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 232]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleFactor>::scale_factor
+	jmp	.LBB13_201
+.LBB13_152:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 13
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 232]
+	cmp	dword ptr [rsp + 224], 1
+	jne	.LBB13_159
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtDiv>::rem
+	jmp	.LBB13_160
+.LBB13_154:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 16
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [rsp + 217]
+	cmp	eax, 2
+	je	.LBB13_164
+	cmp	eax, 1
+	jne	.LBB13_166
+	lea	rdi, [rsp + 536]
+	jmp	.LBB13_165
+.LBB13_157:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 13
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 232]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtMul>::mul
+	jmp	.LBB13_160
+.LBB13_158:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 232]
+	mov	rdx, qword ptr [rsp + 240]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleBounds>::scale_bounds
+	jmp	.LBB13_160
+.LBB13_159:
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtDiv>::div
+.LBB13_160:
+	test	rax, rax
+	mov	r12d, dword ptr [rsp]
+	je	.LBB13_167
+	mov	rbp, rax
+	mov	qword ptr [rsp + 120], rdx
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::rollback
+	test	rax, rax
+	je	.LBB13_163
+	mov	qword ptr [rsp + 136], 0
+	mov	qword ptr [rsp + 144], rax
+	mov	qword ptr [rsp + 152], 21
+	mov	byte ptr [rsp + 128], -1
+	jmp	.LBB13_206
+.LBB13_163:
+	mov	cl, -1
+	xor	eax, eax
+	mov	rsi, qword ptr [rsp + 120]
+	jmp	.LBB13_205
+.LBB13_164:
+	lea	r12, [rsp + 536]
+	mov	rdi, r12
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc
+	mov	rdi, r12
+.LBB13_165:
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::dec
+	jmp	.LBB13_201
+.LBB13_166:
+	mov	r12d, dword ptr [rsp]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc
+.LBB13_167:
+	xor	ecx, ecx
+	jmp	.LBB13_205
+.LBB13_168:
+	mov	rsi, qword ptr [rsp + 224]
+	mov	rdx, qword ptr [rsp + 232]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::write_reg
+	jmp	.LBB13_185
+.LBB13_169:
+	mov	al, byte ptr [rsp + 218]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [rsp + 217], 0
+	mov	ecx, 63
+	cmove	eax, ecx
+	mov	byte ptr [rsp + 128], 2
+	mov	byte ptr [rsp + 129], al
+	jmp	.LBB13_179
+.LBB13_170:
+	mov	rsi, qword ptr [rsp + 224]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+.LBB13_171:
+	mov	byte ptr [rsp + 128], 1
+	mov	qword ptr [rsp + 136], rax
+	jmp	.LBB13_179
+.LBB13_172:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 15
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint
+.LBB13_173:
+	mov	rsi, qword ptr [rsp + 224]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::set_state
+	jmp	.LBB13_201
+.LBB13_174:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 320], xmm0
+	movaps	xmmword ptr [rsp + 336], xmm0
+	movaps	xmmword ptr [rsp + 352], xmm0
+	movaps	xmmword ptr [rsp + 368], xmm0
+	mov	qword ptr [rsp + 384], 0
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB13_175:
+	lea	rdi, [rsp + 536]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	r12, 7
+	ja	.LBB13_177
+	mov	qword ptr [rsp + 8*r12 + 320], rax
+	mov	r12, qword ptr [rsp + 384]
+	inc	r12
+	mov	qword ptr [rsp + 384], r12
+.LBB13_177:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB13_175
+	mov	edx, 72
+	lea	rdi, [rsp + 39]
+	lea	rsi, [rsp + 320]
+	mov	r12, qword ptr [rip + memcpy@GOTPCREL]
+	call	r12
+	mov	byte ptr [rsp + 128], 3
+	mov	edx, 79
+	lea	rdi, [rsp + 129]
+	lea	rsi, [rsp + 32]
+	call	r12
+.LBB13_179:
+	mov	ebp, dword ptr [rsp + 24]
+	jmp	.LBB13_187
+.LBB13_180:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	mov	qword ptr [rsp + 96], 0
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB13_181:
+	lea	rdi, [rsp + 536]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	r12, 7
+	ja	.LBB13_183
+	mov	qword ptr [rsp + 8*r12 + 32], rax
+	mov	r12, qword ptr [rsp + 96]
+	inc	r12
+	mov	qword ptr [rsp + 96], r12
+.LBB13_183:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB13_181
+	lea	rdi, [rsp + 224]
+	lea	rsi, [rsp + 536]
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB13_185:
+	mov	ebp, dword ptr [rsp + 24]
+.LBB13_186:
+	mov	byte ptr [rsp + 128], 0
+.LBB13_187:
+	mov	r12d, dword ptr [rsp]
+	jmp	.LBB13_207
+.LBB13_188:
+	mov	rsi, qword ptr [rsp + 224]
+	mov	rdx, qword ptr [rsp + 232]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::write_reg
+	jmp	.LBB13_201
+.LBB13_189:
+	mov	al, byte ptr [rsp + 218]
+	and	al, 63
+	cmp	byte ptr [rsp + 217], 0
+	movzx	edx, al
+	mov	eax, 63
+	cmove	edx, eax
+	mov	cl, 2
+	jmp	.LBB13_202
+.LBB13_190:
+	mov	rsi, qword ptr [rsp + 224]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	mov	cl, 1
+.LBB13_137:
+	jmp	.LBB13_203
+.LBB13_191:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	mov	qword ptr [rsp + 96], 0
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB13_192:
+	lea	rdi, [rsp + 536]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	r12, 7
+	ja	.LBB13_194
+	mov	qword ptr [rsp + 8*r12 + 32], rax
+	mov	r12, qword ptr [rsp + 96]
+	inc	r12
+	mov	qword ptr [rsp + 96], r12
+.LBB13_194:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB13_192
+	mov	rax, qword ptr [rsp + 32]
+	mov	rbp, qword ptr [rsp + 40]
+	mov	rsi, qword ptr [rsp + 48]
+	lea	rcx, [rsp + 39]
+	movups	xmm0, xmmword ptr [rcx + 17]
+	movups	xmm1, xmmword ptr [rcx + 33]
+	movups	xmm2, xmmword ptr [rcx + 49]
+	movaps	xmmword ptr [rsp + 320], xmm0
+	movaps	xmmword ptr [rsp + 336], xmm1
+	movaps	xmmword ptr [rsp + 352], xmm2
+	mov	cl, 3
+	jmp	.LBB13_204
+.LBB13_196:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	mov	qword ptr [rsp + 96], 0
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB13_197:
+	lea	rdi, [rsp + 536]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	r12, 7
+	ja	.LBB13_199
+	mov	qword ptr [rsp + 8*r12 + 32], rax
+	mov	r12, qword ptr [rsp + 96]
+	inc	r12
+	mov	qword ptr [rsp + 96], r12
+.LBB13_199:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB13_197
+	lea	rdi, [rsp + 224]
+	lea	rsi, [rsp + 536]
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB13_201:
+	xor	ecx, ecx
+.LBB13_202:
+.LBB13_203:
+.LBB13_204:
+	mov	r12d, dword ptr [rsp]
+.LBB13_205:
+	mov	byte ptr [rsp + 128], cl
+	mov	byte ptr [rsp + 129], dl
+	mov	qword ptr [rsp + 136], rax
+	mov	qword ptr [rsp + 144], rbp
+	mov	qword ptr [rsp + 152], rsi
+	movaps	xmm0, xmmword ptr [rsp + 320]
+	movaps	xmm1, xmmword ptr [rsp + 336]
+	movaps	xmm2, xmmword ptr [rsp + 352]
+	lea	rax, [rsp + 129]
+	movups	xmmword ptr [rax + 63], xmm2
+	movups	xmmword ptr [rax + 47], xmm1
+	movups	xmmword ptr [rax + 31], xmm0
+.LBB13_206:
+	mov	ebp, dword ptr [rsp + 24]
+.LBB13_207:
+	lea	rdi, [rsp + 456]
+	mov	rsi, r14
+	lea	rdx, [rsp + 128]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB13_208:
+	cmp	byte ptr [rsp + 456], -1
+	jne	.LBB13_212
+	mov	rax, qword ptr [rsp + 464]
+	test	bpl, bpl
+	jne	.LBB13_227
+	cmp	eax, 1
+	je	.LBB13_230
+	cmp	eax, 4
+	je	.LBB13_229
+.LBB13_212:
+	mov	dword ptr [rsp], r12d
+	mov	rcx, qword ptr [rsp + 3064]
+	jmp	.LBB13_106
+.LBB13_213:
+	xor	ecx, ecx
+	mov	rbp, r13
+	mov	r15, rbx
+.LBB13_214:
+.LBB13_215:
+	lea	rdi, [rsp + 1000]
+	mov	qword ptr [rdi], rcx
+	mov	qword ptr [rdi + 8], rbp
+	mov	qword ptr [rdi + 16], r15
+	mov	qword ptr [rdi + 24], r11
+	mov	qword ptr [rdi + 32], r10
+.LBB13_216:
+	call	optional_trait_methods::usage_error
+	mov	ebx, 1
+	jmp	.LBB13_220
+.LBB13_217:
+	mov	rdx, rcx
+.LBB13_218:
+	xor	eax, eax
+	mov	qword ptr [rsp + 3064], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, 1
+	mov	rsi, r14
+.LBB13_219:
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+.LBB13_220:
+	mov	rax, rbx
+	add	rsp, 3592
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB13_221:
+	xor	edx, edx
+	jmp	.LBB13_218
+.LBB13_222:
+	mov	ecx, 3
+	jmp	.LBB13_214
+.LBB13_223:
+	mov	ecx, 1
+	jmp	.LBB13_214
+.LBB13_224:
+	test	byte ptr [rsp + 416], 1
+	je	.LBB13_233
+.LBB13_225:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.73]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 31
+.LBB13_226:
+	mov	edi, 2
+	call	r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	ebx, 1
+	mov	edx, 1
+	mov	edi, 2
+	call	r14
+	jmp	.LBB13_220
+.LBB13_227:
+	cmp	eax, 1
+	je	.LBB13_230
+	cmp	eax, 4
+	jne	.LBB13_232
+.LBB13_229:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 2040]
+	mov	edx, 23
+	jmp	.LBB13_231
+.LBB13_230:
+	mov	rsi, qword ptr [rsp + 472]
+	mov	rdx, qword ptr [rsp + 480]
+	lea	rdi, [rsp + 2040]
+.LBB13_231:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 2040]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB13_232:
+	mov	ebx, 1
+	mov	rdx, qword ptr [rsp + 3064]
+	jmp	.LBB13_218
+.LBB13_233:
+	test	byte ptr [rsp + 408], 1
+	je	.LBB13_236
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	xor	ebx, ebx
+	mov	edx, 823
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 1
+	jmp	.LBB13_219
+.LBB13_235:
+	mov	ecx, 4
+	jmp	.LBB13_214
+.LBB13_236:
+	mov	r15, qword ptr [rsp + 400]
+	test	r15, r15
+	je	.LBB13_243
+	mov	rdi, r15
+	mov	rbx, qword ptr [rsp + 440]
+	mov	rsi, rbx
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB13_247
+	mov	r14, rdx
+	jmp	.LBB13_244
+.LBB13_239:
+	xor	r10d, r10d
+	jmp	.LBB13_254
+.LBB13_240:
+	mov	ecx, 2
+	xor	r10d, r10d
+	jmp	.LBB13_215
+.LBB13_241:
+	mov	rax, r10
+.LBB13_242:
+	mov	ecx, 2
+	mov	r10, rax
+	jmp	.LBB13_215
+.LBB13_243:
+	xor	r14d, r14d
+.LBB13_244:
+	mov	rax, qword ptr [rsp + 208]
+	test	ax, ax
+	je	.LBB13_248
+	movzx	eax, ax
+	cmp	eax, 1
+	jne	.LBB13_249
+	mov	rdi, qword ptr [rsp + 424]
+	mov	rsi, qword ptr [rsp + 432]
+	call	<optional_trait_methods::connection::Listener>::unix
+	mov	ebx, edx
+	jmp	.LBB13_250
+.LBB13_247:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.19]
+	lea	rdi, [rsp + 1000]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 15
+	mov	qword ptr [rdi + 24], r15
+	mov	qword ptr [rdi + 32], rbx
+	mov	qword ptr [rdi], 2
+	jmp	.LBB13_216
+.LBB13_248:
+	movzx	eax, byte ptr [rsp + 312]
+	shl	eax, 8
+	movzx	ebp, byte ptr [rsp + 304]
+	or	ebp, eax
+	jmp	.LBB13_105
+.LBB13_249:
+	mov	ebp, dword ptr [rsp + 12]
+	mov	edi, ebp
+	call	<optional_trait_methods::connection::Listener>::tcp_loopback
+	mov	ebx, edx
+	test	bp, bp
+	setne	cl
+	or	cl, al
+	test	cl, 1
+	je	.LBB13_255
+.LBB13_250:
+	cmp	eax, 1
+	jne	.LBB13_256
+	mov	r15d, 1
+	mov	ebp, ebx
+	jmp	.LBB13_257
+.LBB13_252:
+	mov	r11, qword ptr [rsp + 16]
+.LBB13_253:
+	mov	r10, r14
+.LBB13_254:
+	mov	ecx, 2
+	jmp	.LBB13_215
+.LBB13_255:
+	mov	edi, ebx
+	call	optional_trait_methods::report_port
+.LBB13_256:
+	mov	edi, ebx
+	call	<optional_trait_methods::connection::Listener>::accept
+	mov	r15d, eax
+	mov	ebp, edx
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+.LBB13_257:
+	test	r15b, 1
+	je	.LBB13_259
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.72]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 29
+	jmp	.LBB13_226
+.LBB13_259:
+	xorps	xmm0, xmm0
+	lea	r12, [rsp + 544]
+	movups	xmmword ptr [r12 + 328], xmm0
+	movups	xmmword ptr [r12 + 312], xmm0
+	movups	xmmword ptr [r12 + 296], xmm0
+	xor	ebx, ebx
+	mov	qword ptr [r12 + 344], rbx
+	lea	rdi, [rsp + 1016]
+	mov	r13, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 272
+	xor	esi, esi
+	call	r13
+	mov	qword ptr [r12 + 288], r14
+	mov	qword ptr [r12 + 352], 1
+	movabs	rax, 9223372036854775807
+	lea	rcx, [rax + 1]
+	mov	qword ptr [r12 + 360], rcx
+	mov	qword ptr [r12 + 368], rax
+	mov	qword ptr [r12 - 8], rbx
+	lea	r15, [rsp + 1000]
+	mov	edx, 288
+	mov	rdi, r12
+	mov	rsi, r15
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 2040]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r13
+	mov	dword ptr [r14 + 1032], ebp
+	lea	rdi, [rsp + 1000]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 3080]
+	mov	edx, 512
+	xor	esi, esi
+	call	r13
+	xor	edx, edx
+	mov	dword ptr [rsp + 16], eax
+.LBB13_260:
+	mov	rdi, qword ptr [rsp + 2024]
+	mov	rcx, qword ptr [rsp + 2032]
+	xor	r13d, r13d
+	mov	rax, rdx
+	xor	r12d, r12d
+.LBB13_261:
+	mov	rsi, rcx
+	mov	rcx, rdi
+.LBB13_262:
+	cmp	rsi, rcx
+	jb	.LBB13_266
+	mov	qword ptr [rsp + 3064], rbx
+	cmp	rax, 1025
+	mov	rsi, r14
+	mov	ecx, 1
+	cmovae	rsi, rcx
+	cmovae	rax, rbx
+	mov	edi, ebp
+	mov	rdx, rax
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	lea	rdi, [rsp + 128]
+	mov	esi, ebp
+	mov	rdx, r15
+	call	optional_trait_methods::connection::read_fd
+	cmp	byte ptr [rsp + 128], 0
+	jne	.LBB13_276
+	mov	rcx, qword ptr [rsp + 136]
+	test	rcx, rcx
+	je	.LBB13_276
+	mov	qword ptr [rsp + 2024], rcx
+	mov	qword ptr [rsp + 2032], 0
+	xor	edx, edx
+	xor	eax, eax
+	xor	esi, esi
+.LBB13_266:
+	cmp	rcx, rsi
+	jb	.LBB13_359
+	cmp	rcx, 1024
+	ja	.LBB13_359
+	cmp	rsi, rcx
+	je	.LBB13_262
+	inc	rsi
+	mov	rdi, rcx
+	neg	rdi
+.LBB13_270:
+	mov	r8b, byte ptr [rsp + rsi + 999]
+	mov	qword ptr [rsp + 2032], rsi
+	cmp	r8b, 10
+	je	.LBB13_279
+	cmp	r12, 511
+	jbe	.LBB13_273
+	mov	r13b, 1
+	jmp	.LBB13_274
+.LBB13_273:
+	mov	byte ptr [rsp + r12 + 3080], r8b
+	inc	r12
+.LBB13_274:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB13_270
+	mov	rdi, rcx
+	jmp	.LBB13_261
+.LBB13_276:
+	test	r12, r12
+	setne	al
+	or	al, r13b
+	test	al, 1
+	je	.LBB13_358
+	test	r13b, 1
+	jne	.LBB13_283
+	mov	edx, 0
+	cmp	r12, 512
+	jbe	.LBB13_281
+	jmp	.LBB13_358
+.LBB13_279:
+	test	r13b, 1
+	jne	.LBB13_283
+	cmp	r12, 512
+	ja	.LBB13_359
+.LBB13_281:
+	test	r12, r12
+	setne	al
+	test	byte ptr [rsp + 312], al
+	jne	.LBB13_285
+	test	r12, r12
+	je	.LBB13_260
+	jmp	.LBB13_286
+.LBB13_283:
+	mov	qword ptr [rsp + 136], 3
+	mov	qword ptr [rsp + 144], 512
+	mov	byte ptr [rsp + 152], 4
+	jmp	.LBB13_284
+.LBB13_285:
+	mov	rdi, r14
+	lea	rsi, [rsp + 3080]
+	mov	rdx, r12
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB13_286:
+	lea	rdi, [rsp + 920]
+	lea	rsi, [rsp + 3080]
+	mov	rdx, r12
+	call	<optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget> as optional_trait_methods::state_machine::Controller>::parse_command
+	cmp	byte ptr [rsp + 920], -1
+	je	.LBB13_292
+	mov	ecx, 10
+	lea	rdi, [rsp + 216]
+	lea	rsi, [rsp + 920]
+	rep movsq es:[rdi], [rsi]
+	movzx	r12d, byte ptr [rsp + 216]
+	lea	r13, [r12 - 6]
+	cmp	r12, 7
+	cmovb	r13, rbx
+	lea	rcx, [rip + .LJTI13_1]
+	movsxd	rax, dword ptr [rcx + 4*r13]
+	add	rax, rcx
+	jmp	rax
+.LBB13_288:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 15
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint
+	lea	rcx, [rip + .LJTI13_2]
+	movsxd	rax, dword ptr [rcx + 4*r13]
+	add	rax, rcx
+	jmp	rax
+.LBB13_289:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI13_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB13_290:
+	mov	cl, 1
+	mov	rax, qword ptr [rsp + 832]
+	jmp	.LBB13_291
+.LBB13_292:
+	mov	eax, dword ptr [rsp + 936]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rdx, qword ptr [rsp]
+	cmovne	rdx, qword ptr [rsp + 928]
+	sete	cl
+	mov	esi, dword ptr [rsp + 16]
+	movzx	esi, sil
+	cmovne	esi, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 136], rcx
+	mov	qword ptr [rsp], rdx
+	mov	qword ptr [rsp + 144], rdx
+	mov	dword ptr [rsp + 16], esi
+	mov	byte ptr [rsp + 152], sil
+.LBB13_284:
+	mov	byte ptr [rsp + 128], -1
+	jmp	.LBB13_353
+.LBB13_293:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI13_4]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB13_294:
+	mov	rax, qword ptr [rsp + 832]
+	jmp	.LBB13_323
+.LBB13_295:
+	mov	r12, qword ptr [rsp + 224]
+	cmp	r12, 2
+	jne	.LBB13_306
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 28
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 232]
+	mov	rdx, qword ptr [rsp + 240]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleBounds>::scale_bounds
+	test	rax, rax
+	je	.LBB13_335
+	mov	qword ptr [rsp + 136], 0
+	mov	qword ptr [rsp + 144], rax
+	mov	qword ptr [rsp + 152], 26
+	jmp	.LBB13_284
+.LBB13_298:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 14
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 224]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::undo
+	test	rax, rax
+	je	.LBB13_335
+	mov	qword ptr [rsp + 136], 0
+	mov	qword ptr [rsp + 144], rax
+	mov	qword ptr [rsp + 152], rdx
+	jmp	.LBB13_284
+.LBB13_300:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 16
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [rsp + 217]
+	cmp	eax, 2
+	je	.LBB13_317
+	lea	rdi, [rsp + 536]
+	cmp	eax, 1
+	je	.LBB13_318
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc
+	jmp	.LBB13_349
+.LBB13_303:
+	mov	r12, qword ptr [rsp + 224]
+	jmp	.LBB13_307
+.LBB13_304:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 13
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 232]
+	cmp	dword ptr [rsp + 224], 1
+	jne	.LBB13_312
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtDiv>::rem
+	jmp	.LBB13_313
+.LBB13_306:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 15
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint
+.LBB13_307:
+	test	r12, r12
+	je	.LBB13_310
+	cmp	r12d, 1
+	jne	.LBB13_311
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 28
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 232]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleFactor>::scale_factor
+	jmp	.LBB13_349
+.LBB13_310:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 13
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 232]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtMul>::mul
+	jmp	.LBB13_313
+.LBB13_311:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 28
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 232]
+	mov	rdx, qword ptr [rsp + 240]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleBounds>::scale_bounds
+	jmp	.LBB13_313
+.LBB13_312:
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtDiv>::div
+.LBB13_313:
+	test	rax, rax
+	je	.LBB13_349
+	mov	r12, rax
+	mov	rbp, rdx
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::rollback
+	test	rax, rax
+	je	.LBB13_316
+	mov	qword ptr [rsp + 136], 0
+	mov	qword ptr [rsp + 144], rax
+	mov	qword ptr [rsp + 152], 21
+	jmp	.LBB13_284
+.LBB13_316:
+	mov	cl, -1
+	xor	eax, eax
+	jmp	.LBB13_352
+.LBB13_317:
+	lea	r12, [rsp + 536]
+	mov	rdi, r12
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc
+	mov	rdi, r12
+.LBB13_318:
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::dec
+	jmp	.LBB13_349
+.LBB13_319:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 32], rax
+	mov	qword ptr [rsp + 40], 15
+	lea	rax, [rsp + 32]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint
+.LBB13_320:
+	mov	rsi, qword ptr [rsp + 224]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::set_state
+	jmp	.LBB13_349
+.LBB13_321:
+	mov	al, byte ptr [rsp + 218]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [rsp + 217], 0
+	mov	ecx, 63
+	cmove	eax, ecx
+	mov	byte ptr [rsp + 128], 2
+	mov	byte ptr [rsp + 129], al
+	jmp	.LBB13_353
+.LBB13_322:
+	mov	rsi, qword ptr [rsp + 224]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+.LBB13_323:
+	mov	byte ptr [rsp + 128], 1
+	mov	qword ptr [rsp + 136], rax
+	jmp	.LBB13_353
+.LBB13_324:
+	mov	rsi, qword ptr [rsp + 224]
+	mov	rdx, qword ptr [rsp + 232]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::write_reg
+	jmp	.LBB13_335
+.LBB13_325:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 320], xmm0
+	movaps	xmmword ptr [rsp + 336], xmm0
+	movaps	xmmword ptr [rsp + 352], xmm0
+	movaps	xmmword ptr [rsp + 368], xmm0
+	mov	qword ptr [rsp + 384], 0
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB13_326:
+	lea	rdi, [rsp + 536]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	r13, 7
+	ja	.LBB13_328
+	mov	qword ptr [rsp + 8*r13 + 320], rax
+	mov	r13, qword ptr [rsp + 384]
+	inc	r13
+	mov	qword ptr [rsp + 384], r13
+.LBB13_328:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB13_326
+	mov	edx, 72
+	lea	rdi, [rsp + 39]
+	lea	rsi, [rsp + 320]
+	mov	r12, qword ptr [rip + memcpy@GOTPCREL]
+	call	r12
+	mov	byte ptr [rsp + 128], 3
+	mov	edx, 79
+	lea	rdi, [rsp + 129]
+	lea	rsi, [rsp + 32]
+	call	r12
+	jmp	.LBB13_353
+.LBB13_330:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	mov	qword ptr [rsp + 96], 0
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB13_331:
+	lea	rdi, [rsp + 536]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	r13, 7
+	ja	.LBB13_333
+	mov	qword ptr [rsp + 8*r13 + 32], rax
+	mov	r13, qword ptr [rsp + 96]
+	inc	r13
+	mov	qword ptr [rsp + 96], r13
+.LBB13_333:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB13_331
+	lea	rdi, [rsp + 224]
+	lea	rsi, [rsp + 536]
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB13_335:
+	mov	byte ptr [rsp + 128], 0
+	jmp	.LBB13_353
+.LBB13_336:
+	mov	al, byte ptr [rsp + 218]
+	and	al, 63
+	cmp	byte ptr [rsp + 217], 0
+	movzx	edx, al
+	mov	eax, 63
+	cmove	edx, eax
+	mov	cl, 2
+	jmp	.LBB13_350
+.LBB13_337:
+	mov	rsi, qword ptr [rsp + 224]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	mov	cl, 1
+.LBB13_291:
+	jmp	.LBB13_351
+.LBB13_338:
+	mov	rsi, qword ptr [rsp + 224]
+	mov	rdx, qword ptr [rsp + 232]
+	lea	rdi, [rsp + 536]
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::write_reg
+	jmp	.LBB13_349
+.LBB13_339:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	mov	qword ptr [rsp + 96], 0
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB13_340:
+	lea	rdi, [rsp + 536]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	r13, 7
+	ja	.LBB13_342
+	mov	qword ptr [rsp + 8*r13 + 32], rax
+	mov	r13, qword ptr [rsp + 96]
+	inc	r13
+	mov	qword ptr [rsp + 96], r13
+.LBB13_342:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB13_340
+	mov	rax, qword ptr [rsp + 32]
+	mov	r12, qword ptr [rsp + 40]
+	mov	rbp, qword ptr [rsp + 48]
+	lea	rcx, [rsp + 39]
+	movups	xmm0, xmmword ptr [rcx + 17]
+	movups	xmm1, xmmword ptr [rcx + 33]
+	movups	xmm2, xmmword ptr [rcx + 49]
+	movaps	xmmword ptr [rsp + 320], xmm0
+	movaps	xmmword ptr [rsp + 336], xmm1
+	movaps	xmmword ptr [rsp + 352], xmm2
+	mov	cl, 3
+	jmp	.LBB13_352
+.LBB13_344:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 80], xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	mov	qword ptr [rsp + 96], 0
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB13_345:
+	lea	rdi, [rsp + 536]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg
+	cmp	r13, 7
+	ja	.LBB13_347
+	mov	qword ptr [rsp + 8*r13 + 32], rax
+	mov	r13, qword ptr [rsp + 96]
+	inc	r13
+	mov	qword ptr [rsp + 96], r13
+.LBB13_347:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB13_345
+	lea	rdi, [rsp + 224]
+	lea	rsi, [rsp + 536]
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_try_as_dyn::controller::TargetController<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB13_349:
+	xor	ecx, ecx
+.LBB13_350:
+.LBB13_351:
+.LBB13_352:
+	mov	byte ptr [rsp + 128], cl
+	mov	byte ptr [rsp + 129], dl
+	mov	qword ptr [rsp + 136], rax
+	mov	qword ptr [rsp + 144], r12
+	mov	qword ptr [rsp + 152], rbp
+	movaps	xmm0, xmmword ptr [rsp + 320]
+	movaps	xmm1, xmmword ptr [rsp + 336]
+	movaps	xmm2, xmmword ptr [rsp + 352]
+	lea	rax, [rsp + 129]
+	movups	xmmword ptr [rax + 63], xmm2
+	movups	xmmword ptr [rax + 47], xmm1
+	movups	xmmword ptr [rax + 31], xmm0
+.LBB13_353:
+	lea	rdi, [rsp + 456]
+	mov	rsi, r14
+	lea	rdx, [rsp + 128]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	cmp	byte ptr [rsp + 456], -1
+	jne	.LBB13_357
+	mov	rax, qword ptr [rsp + 464]
+	cmp	byte ptr [rsp + 304], 0
+	jne	.LBB13_360
+	cmp	eax, 1
+	je	.LBB13_363
+	cmp	eax, 4
+	je	.LBB13_362
+.LBB13_357:
+	mov	ebp, dword ptr [rsp + 3072]
+	mov	rdx, qword ptr [rsp + 3064]
+	jmp	.LBB13_260
+.LBB13_358:
+	xor	edx, edx
+.LBB13_359:
+	xor	eax, eax
+	mov	qword ptr [rsp + 3064], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, ebp
+	mov	rsi, r14
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	jmp	.LBB13_220
+.LBB13_360:
+	cmp	eax, 1
+	je	.LBB13_363
+	cmp	eax, 4
+	jne	.LBB13_365
+.LBB13_362:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 2040]
+	mov	edx, 23
+	jmp	.LBB13_364
+.LBB13_363:
+	mov	rsi, qword ptr [rsp + 472]
+	mov	rdx, qword ptr [rsp + 480]
+	lea	rdi, [rsp + 2040]
+.LBB13_364:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 2040]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB13_365:
+	mov	rdx, qword ptr [rsp + 3064]
+	mov	ebx, 1
+	mov	ebp, dword ptr [rsp + 3072]
+	jmp	.LBB13_359
+.LBB13_366:
+	ud2
+.Lfunc_end13:
+.LJTI13_0:
+	.long	.LBB13_27-.LJTI13_0
+	.long	.LBB13_37-.LJTI13_0
+	.long	.LBB13_30-.LJTI13_0
+	.long	.LBB13_31-.LJTI13_0
+	.long	.LBB13_29-.LJTI13_0
+	.long	.LBB13_366-.LJTI13_0
+	.long	.LBB13_41-.LJTI13_0
+.LJTI13_1:
+	.long	.LBB13_293-.LJTI13_1
+	.long	.LBB13_288-.LJTI13_1
+	.long	.LBB13_295-.LJTI13_1
+	.long	.LBB13_288-.LJTI13_1
+	.long	.LBB13_298-.LJTI13_1
+.LJTI13_2:
+	.long	.LBB13_289-.LJTI13_2
+	.long	.LBB13_300-.LJTI13_2
+	.long	.LBB13_303-.LJTI13_2
+	.long	.LBB13_304-.LJTI13_2
+.LJTI13_3:
+	.long	.LBB13_290-.LJTI13_3
+	.long	.LBB13_320-.LJTI13_3
+	.long	.LBB13_336-.LJTI13_3
+	.long	.LBB13_337-.LJTI13_3
+	.long	.LBB13_338-.LJTI13_3
+	.long	.LBB13_339-.LJTI13_3
+	.long	.LBB13_344-.LJTI13_3
+.LJTI13_4:
+	.long	.LBB13_294-.LJTI13_4
+	.long	.LBB13_319-.LJTI13_4
+	.long	.LBB13_321-.LJTI13_4
+	.long	.LBB13_322-.LJTI13_4
+	.long	.LBB13_324-.LJTI13_4
+	.long	.LBB13_325-.LJTI13_4
+	.long	.LBB13_330-.LJTI13_4
+.LJTI13_5:
+	.long	.LBB13_141-.LJTI13_5
+	.long	.LBB13_134-.LJTI13_5
+	.long	.LBB13_143-.LJTI13_5
+	.long	.LBB13_134-.LJTI13_5
+	.long	.LBB13_139-.LJTI13_5
+.LJTI13_6:
+	.long	.LBB13_135-.LJTI13_6
+	.long	.LBB13_154-.LJTI13_6
+	.long	.LBB13_147-.LJTI13_6
+	.long	.LBB13_152-.LJTI13_6
+.LJTI13_7:
+	.long	.LBB13_136-.LJTI13_7
+	.long	.LBB13_173-.LJTI13_7
+	.long	.LBB13_189-.LJTI13_7
+	.long	.LBB13_190-.LJTI13_7
+	.long	.LBB13_188-.LJTI13_7
+	.long	.LBB13_191-.LJTI13_7
+	.long	.LBB13_196-.LJTI13_7
+.LJTI13_8:
+	.long	.LBB13_142-.LJTI13_8
+	.long	.LBB13_172-.LJTI13_8
+	.long	.LBB13_169-.LJTI13_8
+	.long	.LBB13_170-.LJTI13_8
+	.long	.LBB13_168-.LJTI13_8
+	.long	.LBB13_174-.LJTI13_8
+	.long	.LBB13_180-.LJTI13_8
+
+rust_eh_personality:
+.Lfunc_begin14:
+	ret
+.Lfunc_end14:
+
+core::panicking::panic_fmt:
+.Lfunc_begin15:
+	.loc	1 60 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp0:
+	.loc	1 80 14 prologue_end
+	call	__rustc::rust_begin_unwind
+.Ltmp1:
+.Lfunc_end15:
+
+core::panicking::panic_bounds_check:
+.Lfunc_begin16:
+	.loc	1 266 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp2:
+	.loc	1 271 5 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp3:
+.Lfunc_end16:
+
+core::slice::index::slice_index_fail:
+.Lfunc_begin17:
+	.loc	2 38 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp4:
+	.loc	3 177 9 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp5:
+.Lfunc_end17:
+
+optional_trait_methods::num::parse_num::<isize>:
+.Lfunc_begin18:
+	test	rsi, rsi
+	je	.LBB18_56
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB18_4
+	cmp	ecx, 45
+	jne	.LBB18_6
+	mov	cl, 1
+	jmp	.LBB18_5
+.LBB18_4:
+	xor	ecx, ecx
+.LBB18_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB18_7
+.LBB18_6:
+	xor	ecx, ecx
+.LBB18_7:
+	cmp	rsi, 1
+	jbe	.LBB18_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB18_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB18_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB18_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB18_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB18_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB18_32
+.LBB18_14:
+	test	rsi, rsi
+	je	.LBB18_56
+	mov	al, byte ptr [rdi]
+.LBB18_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB18_56
+.LBB18_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB18_19
+	cmp	byte ptr [rax], 95
+	je	.LBB18_56
+.LBB18_19:
+	xor	r8d, r8d
+	xor	edx, edx
+.LBB18_20:
+	cmp	rdi, rsi
+	je	.LBB18_51
+	mov	al, byte ptr [rdi]
+	inc	rdi
+	cmp	al, 95
+	jne	.LBB18_23
+	xor	eax, eax
+	test	r8b, 1
+	mov	r8d, 0
+	jne	.LBB18_20
+	jmp	.LBB18_52
+.LBB18_23:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB18_57
+	imul	rdx, rdx, 10
+	jo	.LBB18_57
+	movzx	eax, al
+	test	cl, cl
+	je	.LBB18_27
+	sub	rdx, rax
+	jno	.LBB18_28
+	jmp	.LBB18_57
+.LBB18_27:
+	add	rdx, rax
+	jo	.LBB18_57
+.LBB18_28:
+	mov	r8b, 1
+	jmp	.LBB18_20
+.LBB18_29:
+	mov	r8b, 8
+	jmp	.LBB18_31
+.LBB18_30:
+	mov	r8b, 2
+.LBB18_31:
+	xor	r9d, r9d
+.LBB18_32:
+	cmp	rsi, 2
+	je	.LBB18_56
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB18_53
+.LBB18_34:
+	cmp	dl, r8b
+	jae	.LBB18_56
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB18_37
+	cmp	byte ptr [rax], 95
+	je	.LBB18_56
+.LBB18_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB18_38:
+	cmp	r10, rdi
+	je	.LBB18_51
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB18_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB18_52
+	jmp	.LBB18_38
+.LBB18_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB18_45
+	test	r9b, r9b
+	je	.LBB18_57
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB18_57
+	add	al, -87
+	mov	r11d, eax
+.LBB18_45:
+	cmp	r11b, r8b
+	jae	.LBB18_57
+	imul	rdx, rsi
+	jo	.LBB18_57
+	movzx	eax, r11b
+	test	cl, cl
+	je	.LBB18_49
+	sub	rdx, rax
+	jo	.LBB18_57
+	jmp	.LBB18_50
+.LBB18_49:
+	add	rdx, rax
+	jo	.LBB18_57
+.LBB18_50:
+	mov	r11b, 1
+	jmp	.LBB18_38
+.LBB18_51:
+	mov	eax, 1
+	ret
+.LBB18_53:
+	test	r9b, r9b
+	je	.LBB18_56
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB18_56
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB18_34
+.LBB18_56:
+	xor	eax, eax
+.LBB18_52:
+	ret
+.LBB18_57:
+	xor	eax, eax
+	ret
+.Lfunc_end18:
+
+optional_trait_methods::num::parse_num::<usize>:
+.Lfunc_begin19:
+	test	rsi, rsi
+	je	.LBB19_55
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB19_4
+	cmp	ecx, 45
+	jne	.LBB19_6
+	mov	cl, 1
+	jmp	.LBB19_5
+.LBB19_4:
+	xor	ecx, ecx
+.LBB19_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB19_7
+.LBB19_6:
+	xor	ecx, ecx
+.LBB19_7:
+	cmp	rsi, 1
+	jbe	.LBB19_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB19_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB19_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB19_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB19_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB19_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB19_32
+.LBB19_14:
+	test	rsi, rsi
+	je	.LBB19_55
+	mov	al, byte ptr [rdi]
+.LBB19_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB19_55
+.LBB19_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB19_19
+	cmp	byte ptr [rax], 95
+	je	.LBB19_55
+.LBB19_19:
+	mov	r8d, 10
+	xor	r9d, r9d
+	xor	edx, edx
+.LBB19_20:
+	cmp	rdi, rsi
+	je	.LBB19_50
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB19_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB19_20
+	jmp	.LBB19_51
+.LBB19_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB19_28
+	mov	rax, rdx
+	mul	r8
+	jo	.LBB19_28
+	mov	rdx, rax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB19_27
+	sub	rdx, rax
+	jae	.LBB19_20
+	jmp	.LBB19_28
+.LBB19_27:
+	add	rdx, rax
+	jae	.LBB19_20
+.LBB19_28:
+	xor	eax, eax
+	ret
+.LBB19_29:
+	mov	r8b, 8
+	jmp	.LBB19_31
+.LBB19_30:
+	mov	r8b, 2
+.LBB19_31:
+	xor	r9d, r9d
+.LBB19_32:
+	cmp	rsi, 2
+	je	.LBB19_55
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB19_52
+.LBB19_34:
+	cmp	dl, r8b
+	jae	.LBB19_55
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB19_37
+	cmp	byte ptr [rax], 95
+	je	.LBB19_55
+.LBB19_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB19_38:
+	cmp	r10, rdi
+	je	.LBB19_50
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB19_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB19_51
+	jmp	.LBB19_38
+.LBB19_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB19_45
+	test	r9b, r9b
+	je	.LBB19_28
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB19_28
+	add	al, -87
+	mov	r11d, eax
+.LBB19_45:
+	cmp	r11b, r8b
+	jae	.LBB19_28
+	mov	rax, rdx
+	mul	rsi
+	jo	.LBB19_28
+	mov	rdx, rax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB19_49
+	sub	rdx, rax
+	jae	.LBB19_38
+	jmp	.LBB19_28
+.LBB19_49:
+	add	rdx, rax
+	jae	.LBB19_38
+	jmp	.LBB19_28
+.LBB19_50:
+	mov	eax, 1
+	ret
+.LBB19_52:
+	test	r9b, r9b
+	je	.LBB19_55
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB19_55
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB19_34
+.LBB19_55:
+	xor	eax, eax
+.LBB19_51:
+	ret
+.Lfunc_end19:
+
+<optional_trait_methods::commands::Args>::reg:
+.Lfunc_begin20:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	cmp	rdx, 8
+	setb	cl
+	and	cl, al
+	cmovne	r15, rdx
+	neg	cl
+	or	cl, 2
+	mov	qword ptr [rbx], r15
+	mov	byte ptr [rbx + 8], cl
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end20:
+
+<optional_trait_methods::commands::Args>::next:
+.Lfunc_begin21:
+	mov	r10, qword ptr [rsi + 8]
+	mov	rax, qword ptr [rsi + 16]
+	xor	edx, edx
+	test	r10, r10
+	setne	dl
+	cmp	r10, 1
+	mov	rcx, r10
+	adc	rcx, -1
+	mov	r8, qword ptr [rsi]
+	add	r8, rdx
+	mov	r9, rcx
+	cmp	r10, 2
+	jb	.LBB21_5
+	xor	r9d, r9d
+.LBB21_2:
+	cmp	byte ptr [r8 + r9], 32
+	je	.LBB21_5
+	inc	r9
+	cmp	rcx, r9
+	jne	.LBB21_2
+	mov	r9, rcx
+.LBB21_5:
+	add	rax, rdx
+	sub	rcx, r9
+	lea	rdx, [r8 + r9]
+	mov	qword ptr [rsi], rdx
+	mov	qword ptr [rsi + 8], rcx
+	lea	rcx, [r9 + rax]
+	mov	qword ptr [rsi + 16], rcx
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], r8
+	mov	qword ptr [rdi + 16], r9
+	ret
+.Lfunc_end21:
+
+<optional_trait_methods::commands::Args>::names:
+.Lfunc_begin22:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 200
+	mov	qword ptr [rsp + 32], rdi
+	lea	r14, [rsp + 40]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r12, qword ptr [r14 + 8]
+	mov	rbx, qword ptr [r14 + 16]
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, qword ptr [rip + bcmp@GOTPCREL]
+.LBB22_1:
+	test	rbx, rbx
+	je	.LBB22_2
+	xor	r13d, r13d
+.LBB22_4:
+	cmp	byte ptr [r12 + r13], 59
+	je	.LBB22_7
+	inc	r13
+	cmp	rbx, r13
+	jne	.LBB22_4
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 24], r12
+	mov	r13, rbx
+	jmp	.LBB22_8
+.LBB22_2:
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	xor	ebx, ebx
+	mov	qword ptr [rsp + 24], r12
+	xor	r13d, r13d
+	jmp	.LBB22_8
+.LBB22_7:
+	lea	rax, [r13 + 1]
+	sub	rbx, rax
+	lea	rax, [r12 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 24], rax
+	mov	dword ptr [rsp + 12], 0
+.LBB22_8:
+	mov	edx, 144
+	lea	rbp, [rsp + 56]
+	mov	rdi, rbp
+	lea	rsi, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.6]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r14d, 6
+.LBB22_9:
+	cmp	qword ptr [rbp + 16], r13
+	jne	.LBB22_11
+	mov	rdi, qword ptr [rbp + 8]
+	mov	rsi, r12
+	mov	rdx, r13
+	call	r15
+	test	eax, eax
+	je	.LBB22_12
+.LBB22_11:
+	add	rbp, 24
+	dec	r14
+	jne	.LBB22_9
+	jmp	.LBB22_13
+.LBB22_12:
+	mov	rax, qword ptr [rsp + 16]
+	or	al, byte ptr [rbp]
+	mov	qword ptr [rsp + 16], rax
+.LBB22_13:
+	mov	r12, qword ptr [rsp + 24]
+	cmp	byte ptr [rsp + 12], 0
+	je	.LBB22_1
+	mov	rax, qword ptr [rsp + 32]
+	mov	rcx, qword ptr [rsp + 16]
+	mov	byte ptr [rax], cl
+	mov	byte ptr [rax + 8], -1
+	add	rsp, 200
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end22:
+
+<optional_trait_methods::connection::Listener>::tcp_loopback:
+.Lfunc_begin23:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	qword ptr [rsp + 20], 0
+	mov	word ptr [rsp + 12], 2
+	rol	di, 8
+	mov	word ptr [rsp + 14], di
+	mov	dword ptr [rsp + 16], 16777343
+	mov	edi, 2
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebx, edx
+	mov	ebp, 1
+	test	al, 1
+	jne	.LBB23_5
+	lea	rcx, [rsp + 28]
+	mov	dword ptr [rcx], 1
+	mov	edi, ebx
+	mov	esi, 1
+	mov	edx, 2
+	mov	r8d, 4
+	call	qword ptr [rip + setsockopt@GOTPCREL]
+	lea	rsi, [rsp + 12]
+	mov	edi, ebx
+	mov	edx, 16
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB23_3
+	mov	edi, ebx
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB23_3
+	xor	ebp, ebp
+	jmp	.LBB23_5
+.LBB23_3:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebx, r14d
+.LBB23_5:
+	mov	eax, ebp
+	mov	edx, ebx
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end23:
+
+<optional_trait_methods::connection::Listener>::socket:
+.Lfunc_begin24:
+	push	rbx
+	xor	ebx, ebx
+	mov	esi, 1
+	xor	edx, edx
+	call	qword ptr [rip + socket@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB24_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB24_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end24:
+
+<optional_trait_methods::connection::Listener>::unix:
+.Lfunc_begin25:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 112
+	mov	rdx, rsi
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [rsp + 96], xmm0
+	movups	xmmword ptr [rsp + 84], xmm0
+	movups	xmmword ptr [rsp + 68], xmm0
+	movups	xmmword ptr [rsp + 52], xmm0
+	movups	xmmword ptr [rsp + 36], xmm0
+	movups	xmmword ptr [rsp + 20], xmm0
+	movups	xmmword ptr [rsp + 4], xmm0
+	mov	word ptr [rsp + 2], 1
+	lea	rax, [rsi - 108]
+	cmp	rax, -107
+	jae	.LBB25_2
+	mov	ebx, 1
+	mov	ebp, 36
+	jmp	.LBB25_7
+.LBB25_2:
+	mov	rsi, rdi
+	lea	rbx, [rsp + 4]
+	mov	rdi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	rdi, rbx
+	call	qword ptr [rip + unlink@GOTPCREL]
+	mov	ebx, 1
+	mov	edi, 1
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebp, edx
+	test	al, 1
+	jne	.LBB25_7
+	lea	rsi, [rsp + 2]
+	mov	edi, ebp
+	mov	edx, 110
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB25_6
+	mov	edi, ebp
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB25_6
+	xor	ebx, ebx
+	jmp	.LBB25_7
+.LBB25_6:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebp, r14d
+.LBB25_7:
+	mov	eax, ebx
+	mov	edx, ebp
+	add	rsp, 112
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end25:
+
+<optional_trait_methods::connection::Listener>::accept:
+.Lfunc_begin26:
+	push	rbx
+	xor	ebx, ebx
+	xor	esi, esi
+	xor	edx, edx
+	call	qword ptr [rip + accept@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB26_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB26_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end26:
+
+optional_trait_methods::commands::unrecognized:
+.Lfunc_begin27:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	xor	r14d, r14d
+.LBB27_1:
+	cmp	byte ptr [rbx + r14], 32
+	je	.LBB27_4
+	inc	r14
+	cmp	rsi, r14
+	jne	.LBB27_1
+	mov	r14, rsi
+.LBB27_4:
+	mov	r15d, 8
+	lea	r12, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.33]
+	mov	r13, qword ptr [rip + bcmp@GOTPCREL]
+.LBB27_5:
+	cmp	qword ptr [r15 + r12], r14
+	jne	.LBB27_8
+	mov	rdi, qword ptr [r15 + r12 - 8]
+	mov	rsi, rbx
+	mov	rdx, r14
+	call	r13
+	test	eax, eax
+	je	.LBB27_7
+.LBB27_8:
+	add	r15, 16
+	cmp	r15, 152
+	jne	.LBB27_5
+	xor	eax, eax
+	jmp	.LBB27_10
+.LBB27_7:
+	mov	al, 1
+.LBB27_10:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end27:
+
+optional_trait_methods::connection::read_fd:
+.Lfunc_begin28:
+	push	rbx
+	mov	rax, rdx
+	mov	rbx, rdi
+	mov	edx, 1024
+	mov	edi, esi
+	mov	rsi, rax
+	call	qword ptr [rip + read@GOTPCREL]
+	test	rax, rax
+	js	.LBB28_2
+	mov	qword ptr [rbx + 8], rax
+	xor	eax, eax
+	jmp	.LBB28_3
+.LBB28_2:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	eax, dword ptr [rax]
+	mov	dword ptr [rbx + 4], eax
+	mov	eax, 1
+.LBB28_3:
+	mov	dword ptr [rbx], eax
+	pop	rbx
+	ret
+.Lfunc_end28:
+
+optional_trait_methods::connection::write_fd:
+.Lfunc_begin29:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	test	rdx, rdx
+	je	.LBB29_8
+	mov	ebp, ecx
+	mov	rbx, rdx
+	mov	r14, rsi
+	mov	r15d, edi
+	mov	r12, qword ptr [rip + send@GOTPCREL]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+.LBB29_2:
+	mov	edi, r15d
+	mov	rsi, r14
+	mov	rdx, rbx
+	test	bpl, bpl
+	je	.LBB29_3
+	mov	ecx, 16384
+	call	r12
+	jmp	.LBB29_5
+.LBB29_3:
+	call	r13
+.LBB29_5:
+	test	rax, rax
+	js	.LBB29_10
+	cmp	rbx, rax
+	jb	.LBB29_9
+	add	r14, rax
+	sub	rbx, rax
+	jne	.LBB29_2
+.LBB29_8:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB29_10:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB29_9:
+	mov	rdi, rax
+	mov	rsi, rbx
+	mov	rdx, rbx
+	call	core::slice::index::slice_index_fail
+.Lfunc_end29:
 
-<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::get_state:
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::read_reg:
+.Lfunc_begin30:
+	test	rsi, rsi
+	je	.LBB30_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB30_5
+	lea	rdi, [rdi + 8*rax]
+	add	rdi, 304
+	jmp	.LBB30_2
+.LBB30_1:
+	add	rdi, 296
+.LBB30_2:
 	mov	rax, qword ptr [rdi]
 	ret
-.Lfunc_end1:
+.LBB30_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end30:
 
 <optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::set_state:
-	mov	qword ptr [rdi], rsi
-	xor	eax, eax
+.Lfunc_begin31:
+	mov	qword ptr [rdi + 296], rsi
 	ret
-.Lfunc_end2:
+.Lfunc_end31:
+
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::write_reg:
+.Lfunc_begin32:
+	test	rsi, rsi
+	je	.LBB32_1
+	mov	rax, rsi
+	cmp	rsi, 8
+	jae	.LBB32_5
+	mov	qword ptr [rdi + 8*rax + 296], rdx
+	ret
+.LBB32_1:
+	mov	qword ptr [rdi + 296], rdx
+	ret
+.LBB32_5:
+	push	rax
+	dec	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end32:
 
 <optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::dec:
-	dec	qword ptr [rdi]
-	xor	eax, eax
+.Lfunc_begin33:
+	dec	qword ptr [rdi + 296]
 	ret
-.Lfunc_end3:
+.Lfunc_end33:
 
 <optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc:
-	inc	qword ptr [rdi]
-	xor	eax, eax
+.Lfunc_begin34:
+	inc	qword ptr [rdi + 296]
 	ret
-.Lfunc_end4:
+.Lfunc_end34:
 
 <optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtMul>::mul:
+.Lfunc_begin35:
 	cmp	rsi, 7
-	jne	.LBB5_2
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.11]
-	jmp	.LBB5_3
-.LBB5_2:
-	imul	rsi, qword ptr [rdi + 8]
-	imul	rsi, qword ptr [rdi]
-	mov	qword ptr [rdi], rsi
+	jne	.LBB35_2
+	lea	rax, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.45]
+	jmp	.LBB35_3
+.LBB35_2:
+	imul	rsi, qword ptr [rdi + 360]
+	imul	rsi, qword ptr [rdi + 296]
+	mov	qword ptr [rdi + 296], rsi
 	xor	eax, eax
-.LBB5_3:
+.LBB35_3:
 	mov	edx, 28
 	ret
-.Lfunc_end5:
+.Lfunc_end35:
 
 <optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleFactor>::scale_factor:
-	mov	qword ptr [rdi + 8], rsi
+.Lfunc_begin36:
+	mov	rax, qword ptr [rdi + 368]
+	mov	rcx, qword ptr [rdi + 376]
+	cmp	rax, rsi
+	cmovg	rsi, rax
+	cmp	rcx, rsi
+	cmovl	rsi, rcx
+	mov	qword ptr [rdi + 360], rsi
+	ret
+.Lfunc_end36:
+
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleBounds>::scale_bounds:
+.Lfunc_begin37:
+	cmp	rsi, rdx
+	jle	.LBB37_2
+	lea	rax, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.42]
+	jmp	.LBB37_3
+.LBB37_2:
+	mov	qword ptr [rdi + 368], rsi
+	mov	qword ptr [rdi + 376], rdx
+	mov	rax, qword ptr [rdi + 360]
+	cmp	rsi, rax
+	cmovg	rax, rsi
+	cmp	rdx, rax
+	cmovl	rax, rdx
+	mov	qword ptr [rdi + 360], rax
 	xor	eax, eax
+.LBB37_3:
+	mov	edx, 26
 	ret
-.Lfunc_end6:
+.Lfunc_end37:
 
-main:
-	push	rbp
-	push	r15
-	push	r14
-	push	r13
-	push	r12
-	push	rbx
-	sub	rsp, 1208
-	lea	rbx, [rsp + 152]
-	mov	qword ptr [rbx], 0
-	mov	qword ptr [rbx + 8], 1
-	lea	r14, [rsp + 168]
-	mov	edx, 1040
-	mov	rdi, r14
-	xor	esi, esi
-	call	qword ptr [rip + memset@GOTPCREL]
-	xorps	xmm0, xmm0
-	lea	r13, [rsp + 18]
-	movaps	xmmword ptr [r13 + 110], xmm0
-	movaps	xmmword ptr [r13 + 94], xmm0
-	movaps	xmmword ptr [r13 + 78], xmm0
-	movaps	xmmword ptr [r13 + 62], xmm0
-	movaps	xmmword ptr [r13 + 46], xmm0
-	movaps	xmmword ptr [r13 + 30], xmm0
-	movaps	xmmword ptr [r13 + 14], xmm0
-	movaps	xmmword ptr [r13 - 2], xmm0
-	mov	r12, rsp
-	mov	rbp, qword ptr [rip + read@GOTPCREL]
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtDiv>::div:
+.Lfunc_begin38:
+	test	rsi, rsi
+	je	.LBB38_1
+	mov	rax, qword ptr [rdi + 296]
+	mov	rcx, rax
+	btc	rcx, 63
+	mov	rdx, rsi
+	not	rdx
+	or	rdx, rcx
+	je	.LBB38_3
+	cqo
+	idiv	rsi
+	mov	qword ptr [rdi + 296], rax
 	xor	eax, eax
-	xor	ecx, ecx
-.LBB7_1:
-	xor	r15d, r15d
-.LBB7_2:
-	cmp	rcx, rax
-	jb	.LBB7_5
-	mov	edx, 1024
-	xor	edi, edi
-	mov	rsi, r14
-	call	rbp
-	test	rax, rax
-	jle	.LBB7_76
-	mov	qword ptr [rsp + 1192], rax
-	mov	qword ptr [rsp + 1200], 0
-	xor	ecx, ecx
-.LBB7_5:
-	cmp	rax, rcx
-	jb	.LBB7_71
-	cmp	rax, 1024
-	ja	.LBB7_71
-	cmp	rcx, rax
-	je	.LBB7_2
-	inc	rcx
-	mov	rdx, rax
-	neg	rdx
-.LBB7_9:
-	mov	sil, byte ptr [rsp + rcx + 167]
-	mov	qword ptr [rsp + 1200], rcx
-	cmp	sil, 10
-	je	.LBB7_10
-	cmp	r15, 127
-	ja	.LBB7_14
-	mov	byte ptr [rsp + r15 + 16], sil
-	inc	r15
-.LBB7_14:
-	lea	rsi, [rdx + rcx]
-	inc	rsi
-	inc	rcx
-	cmp	rsi, 1
-	jne	.LBB7_9
+	ret
+.LBB38_1:
+	lea	rax, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.43]
+	mov	edx, 17
+	ret
+.LBB38_3:
+	lea	rax, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.44]
+	mov	edx, 29
+	ret
+.Lfunc_end38:
+
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtDiv>::rem:
+.Lfunc_begin39:
+	test	rsi, rsi
+	je	.LBB39_1
+	mov	rax, qword ptr [rdi + 296]
 	mov	rcx, rax
-	jmp	.LBB7_2
-.LBB7_76:
-	lea	rax, [r15 - 129]
-	cmp	rax, -128
-	jb	.LBB7_71
-	jmp	.LBB7_77
-.LBB7_10:
-	cmp	r15, 128
-	ja	.LBB7_71
-	test	r15, r15
-	je	.LBB7_1
-.LBB7_77:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.1]
-	mov	qword ptr [rsp], rax
-	mov	qword ptr [rsp + 8], 22
-	#APP
-	#NO_APP
-	cmp	r15, 2
-	je	.LBB7_16
-	cmp	r15, 1
-	jne	.LBB7_17
-	movzx	eax, byte ptr [rsp + 16]
-	cmp	eax, 43
-	je	.LBB7_68
-	cmp	eax, 45
-	jne	.LBB7_17
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	mov	qword ptr [rsp], rax
-	mov	qword ptr [rsp + 8], 16
-	#APP
-	#NO_APP
-	jmp	.LBB7_73
-.LBB7_16:
-	cmp	word ptr [rsp + 16], 11563
-	jne	.LBB7_17
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	mov	qword ptr [rsp], rax
-	mov	qword ptr [rsp + 8], 16
-	#APP
-	#NO_APP
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc
-	test	rax, rax
-	jne	.LBB7_70
-.LBB7_73:
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::dec
-	jmp	.LBB7_69
-.LBB7_17:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.2]
-	mov	qword ptr [rsp], rax
-	mov	qword ptr [rsp + 8], 19
-	#APP
-	#NO_APP
-	cmp	r15, 2
-	jb	.LBB7_32
-	cmp	word ptr [rsp + 16], 8234
-	jne	.LBB7_32
-	mov	rax, r15
-	add	rax, -2
-	je	.LBB7_32
-	movzx	ecx, byte ptr [rsp + 18]
-	cmp	ecx, 45
-	je	.LBB7_21
-	cmp	ecx, 43
-	jne	.LBB7_23
+	btc	rcx, 63
+	mov	rdx, rsi
+	not	rdx
+	or	rdx, rcx
+	je	.LBB39_3
+	cqo
+	idiv	rsi
+	mov	qword ptr [rdi + 296], rdx
+	xor	eax, eax
+	ret
+.LBB39_1:
+	lea	rax, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.43]
+	mov	edx, 17
+	ret
+.LBB39_3:
+	lea	rax, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.44]
+	mov	edx, 29
+	ret
+.Lfunc_end39:
+
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::checkpoint:
+.Lfunc_begin40:
+	push	rax
+	mov	rcx, qword ptr [rdi + 296]
+	mov	rdx, qword ptr [rdi + 360]
+	mov	rax, qword ptr [rdi + 280]
+	lea	rsi, [rdi + 24]
+	cmp	qword ptr [rdi + 288], 16
+	jne	.LBB40_3
+	cmp	rax, 16
+	jae	.LBB40_5
+	mov	r8, rax
+	shl	r8, 4
+	movups	xmm0, xmmword ptr [rsi + r8]
+	mov	qword ptr [rdi], 1
+	movups	xmmword ptr [rdi + 8], xmm0
+	jmp	.LBB40_4
+.LBB40_3:
+	mov	qword ptr [rdi], 0
+	cmp	rax, 16
+	jae	.LBB40_5
+.LBB40_4:
+	shl	rax, 4
+	mov	qword ptr [rsi + rax], rcx
+	mov	qword ptr [rsi + rax + 8], rdx
+	mov	eax, dword ptr [rdi + 280]
+	inc	eax
+	and	eax, 15
+	mov	qword ptr [rdi + 280], rax
+	mov	rax, qword ptr [rdi + 288]
+	inc	rax
+	cmp	rax, 16
+	mov	ecx, 16
+	cmovb	rcx, rax
+	mov	qword ptr [rdi + 288], rcx
+	pop	rax
+	ret
+.LBB40_5:
+	mov	esi, 16
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end40:
+
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::undo:
+.Lfunc_begin41:
+	mov	rax, qword ptr [rdi + 288]
+	lea	rcx, [rsi - 1]
+	cmp	rcx, rax
+	jae	.LBB41_2
+	mov	qword ptr [rdi], 0
+	mov	ecx, dword ptr [rdi + 280]
+	sub	ecx, esi
+	and	ecx, 15
+	mov	qword ptr [rdi + 280], rcx
+	sub	rax, rsi
+	mov	qword ptr [rdi + 288], rax
+	shl	ecx, 4
+	mov	rax, qword ptr [rdi + rcx + 24]
+	mov	rcx, qword ptr [rdi + rcx + 32]
+	mov	rdx, qword ptr [rdi + 368]
+	mov	rsi, qword ptr [rdi + 376]
+	mov	qword ptr [rdi + 296], rax
+	cmp	rdx, rcx
+	cmovg	rcx, rdx
+	cmp	rsi, rcx
+	cmovl	rcx, rsi
+	mov	qword ptr [rdi + 360], rcx
+	jmp	.LBB41_3
+.LBB41_2:
+	test	rsi, rsi
+	je	.LBB41_3
 	xor	ecx, ecx
-	jmp	.LBB7_25
-.LBB7_68:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.8]
-	mov	qword ptr [rsp], rax
-	mov	qword ptr [rsp + 8], 16
-	#APP
-	#NO_APP
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtIncDec>::inc
-.LBB7_69:
 	test	rax, rax
-	jne	.LBB7_70
-	jmp	.LBB7_51
-.LBB7_21:
-	mov	cl, 1
-.LBB7_25:
-	mov	rax, r15
-	lea	rdx, [rsp + 19]
-	add	rax, -3
-	jne	.LBB7_26
-	jmp	.LBB7_32
-.LBB7_23:
-	xor	ecx, ecx
-	mov	rdx, r13
-.LBB7_26:
-	xor	edi, edi
-	xor	esi, esi
-.LBB7_27:
-	cmp	rax, rdi
-	je	.LBB7_31
-	mov	r8b, byte ptr [rdx + rdi]
-	add	r8b, -48
-	cmp	r8b, 9
-	ja	.LBB7_32
-	imul	rsi, rsi, 10
-	jo	.LBB7_32
-	movzx	r8d, r8b
-	inc	rdi
-	add	rsi, r8
-	jno	.LBB7_27
-	jmp	.LBB7_32
-.LBB7_31:
-	movzx	eax, cl
-	neg	rax
-	or	rax, 1
-	imul	rsi, rax
-	jno	.LBB7_75
-.LBB7_32:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.3]
-	mov	qword ptr [rsp], rax
-	mov	qword ptr [rsp + 8], 27
-	#APP
-	#NO_APP
-	cmp	r15, 3
-	jb	.LBB7_47
-	mov	eax, dword ptr [rsp + 16]
-	mov	ecx, 32298
-	xor	eax, ecx
-	movzx	ecx, byte ptr [rsp + 18]
-	xor	ecx, 32
-	or	cx, ax
-	jne	.LBB7_52
-	mov	rax, r15
-	add	rax, -3
-	je	.LBB7_52
-	movzx	ecx, byte ptr [rsp + 19]
-	cmp	ecx, 45
-	je	.LBB7_36
-	cmp	ecx, 43
-	jne	.LBB7_38
-	xor	ecx, ecx
-	jmp	.LBB7_40
-.LBB7_36:
-	mov	cl, 1
-.LBB7_40:
-	mov	rax, r15
-	lea	rdx, [rsp + 20]
-	add	rax, -4
-	jne	.LBB7_41
-	jmp	.LBB7_52
-.LBB7_38:
-	xor	ecx, ecx
-	lea	rdx, [rsp + 19]
-.LBB7_41:
-	xor	edi, edi
-	xor	esi, esi
-.LBB7_42:
-	cmp	rax, rdi
-	je	.LBB7_46
-	mov	r8b, byte ptr [rdx + rdi]
-	add	r8b, -48
-	cmp	r8b, 9
-	ja	.LBB7_47
-	imul	rsi, rsi, 10
-	jo	.LBB7_47
-	movzx	r8d, r8b
-	inc	rdi
-	add	rsi, r8
-	jno	.LBB7_42
-	jmp	.LBB7_47
-.LBB7_46:
-	movzx	eax, cl
-	neg	rax
-	or	rax, 1
-	imul	rsi, rax
-	jno	.LBB7_74
-.LBB7_47:
-	test	r15, r15
-	je	.LBB7_65
-	cmp	r15, 1
-	jne	.LBB7_52
-	cmp	byte ptr [rsp + 16], 112
-	jne	.LBB7_65
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::get_state
-	lea	rdi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.7]
-	mov	rsi, rax
-	xor	eax, eax
-	call	qword ptr [rip + printf@GOTPCREL]
-	jmp	.LBB7_51
-.LBB7_52:
-	cmp	word ptr [rsp + 16], 8307
-	jne	.LBB7_65
-	mov	rcx, r15
-	add	rcx, -2
-	je	.LBB7_65
-	movzx	eax, byte ptr [rsp + 18]
-	cmp	eax, 45
-	je	.LBB7_55
-	cmp	eax, 43
-	jne	.LBB7_57
-	xor	eax, eax
-	jmp	.LBB7_59
-.LBB7_55:
-	mov	al, 1
-.LBB7_59:
-	lea	rdx, [rsp + 19]
-	add	r15, -3
-	mov	rcx, r15
-	jne	.LBB7_60
-	jmp	.LBB7_65
-.LBB7_57:
-	xor	eax, eax
-	mov	rdx, r13
-.LBB7_60:
-	xor	edi, edi
-	xor	esi, esi
-.LBB7_61:
-	cmp	rcx, rdi
-	je	.LBB7_66
-	mov	r8b, byte ptr [rdx + rdi]
-	add	r8b, -48
-	cmp	r8b, 9
-	ja	.LBB7_65
-	imul	rsi, rsi, 10
-	jo	.LBB7_65
-	movzx	r8d, r8b
-	inc	rdi
-	add	rsi, r8
-	jno	.LBB7_61
-	jmp	.LBB7_65
-.LBB7_66:
-	movzx	eax, al
-	neg	rax
-	or	rax, 1
-	imul	rsi, rax
-	jno	.LBB7_67
-.LBB7_65:
-	mov	edx, 16
-	mov	edi, 1
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.5]
-	mov	r15, qword ptr [rip + write@GOTPCREL]
-	call	r15
-	mov	edx, 1
-	mov	edi, 1
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.6]
-	call	r15
-.LBB7_51:
-	mov	rax, qword ptr [rsp + 1192]
-	mov	rcx, qword ptr [rsp + 1200]
-	jmp	.LBB7_1
-.LBB7_67:
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetBase>::set_state
-	jmp	.LBB7_69
-.LBB7_75:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.9]
-	mov	qword ptr [rsp], rax
-	mov	qword ptr [rsp + 8], 13
-	#APP
-	#NO_APP
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtMul>::mul
-	jmp	.LBB7_69
-.LBB7_74:
-	lea	rax, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.10]
-	mov	qword ptr [rsp], rax
-	mov	qword ptr [rsp + 8], 28
-	#APP
-	#NO_APP
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtScaleFactor>::scale_factor
-	jmp	.LBB7_69
-.LBB7_70:
-	mov	edi, 1
-	mov	rsi, rax
-	mov	rbx, qword ptr [rip + write@GOTPCREL]
-	call	rbx
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.6]
-	mov	edx, 1
-	mov	edi, 1
-	call	rbx
-.LBB7_71:
+	setne	cl
+	lea	rdx, [rcx + 2*rcx + 16]
+	lea	rcx, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.47]
+	lea	rax, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.46]
+	cmove	rax, rcx
+	ret
+.LBB41_3:
 	xor	eax, eax
-	add	rsp, 1208
-	pop	rbx
-	pop	r12
-	pop	r13
-	pop	r14
-	pop	r15
-	pop	rbp
 	ret
-.Lfunc_end7:
+.Lfunc_end41:
 
-rust_eh_personality:
+<optional_trait_methods::using_try_as_dyn::targets::advanced::AdvancedTarget as optional_trait_methods::using_try_as_dyn::target::TargetExtUndo>::rollback:
+.Lfunc_begin42:
+	mov	rsi, qword ptr [rdi]
+	mov	rax, qword ptr [rdi + 8]
+	mov	rcx, qword ptr [rdi + 16]
+	mov	qword ptr [rdi], 0
+	mov	rdx, qword ptr [rdi + 288]
+	test	rdx, rdx
+	je	.LBB42_1
+	mov	r8d, dword ptr [rdi + 280]
+	dec	r8d
+	and	r8d, 15
+	mov	qword ptr [rdi + 280], r8
+	lea	r9, [rdx - 1]
+	mov	qword ptr [rdi + 288], r9
+	shl	r8d, 4
+	mov	r9, qword ptr [rdi + r8 + 24]
+	mov	r10, qword ptr [rdi + r8 + 32]
+	cmp	rsi, 1
+	jne	.LBB42_4
+	lea	rsi, [rdi + r8]
+	add	rsi, 24
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rdi + 288], rdx
+.LBB42_4:
+	mov	qword ptr [rdi + 296], r9
+	mov	qword ptr [rdi + 360], r10
+	xor	eax, eax
 	ret
-.Lfunc_end8:
+.LBB42_1:
+	lea	rax, [rip + .Lanon.82f370daf7c215be2d2bc157adb991a2.50]
+	ret
+.Lfunc_end42:
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.0:
+	.byte	10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.1:
+	.byte	59
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.2:
+	.ascii	"E 01"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.3:
+	.ascii	"E 02"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.4:
+	.ascii	"E."
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.5:
+	.ascii	" at "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.6:
+	.ascii	"E 05"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.7:
+	.ascii	"unknown option: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.8:
+	.ascii	"missing value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.9:
+	.ascii	"invalid value for "
 
-.Lanon.cabb224323f7f456a7965482e4c09287.0:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.10:
+	.ascii	": "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.11:
+	.ascii	" doesn't take a value"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.12:
+	.ascii	"only one of --unix / --tcp may be given"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.14:
+	.ascii	"0123456789abcdef"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.17:
+	.ascii	"--unix"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.18:
+	.ascii	"--tcp"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.19:
+	.ascii	"--initial-state"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.20:
+	.ascii	"--on-error"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.21:
+	.ascii	"--echo"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.22:
+	.ascii	"--repl"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.23:
+	.ascii	"--help"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.24:
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.17
+	.asciz	"\006\000\000\000\000\000\000\000"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.18
+	.ascii	"\005\000\000\000\000\000\000\000\001"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.19
+	.ascii	"\017\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.20
+	.ascii	"\n\000\000\000\000\000\000\000\003"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.21
+	.ascii	"\006\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.22
+	.ascii	"\006\000\000\000\000\000\000\000\005"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.23
+	.ascii	"\006\000\000\000\000\000\000\000\006"
+	.zero	7
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.28:
+	.ascii	"Invalid implementation!"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.29:
+	.ascii	"OK"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.30:
 	.ascii	"panic!\n"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.1:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.31:
+	.ascii	"listening on 127.0.0.1:"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.32:
+	.ascii	"error: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.33:
+	.zero	2,10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.34:
+	.ascii	"usage: optional-trait-methods [options]\n\n  --unix <path>              serve the first client to connect to a Unix domain socket\n  --tcp <port>               serve the first client to connect to 127.0.0.1:<port>\n                             (port 0 picks any free port, printed to stderr)\n                             (by default, serve stdin / stdout)\n  --initial-state <n>        start the target off in state <n> (by default, 0)\n  --on-error=continue|exit   whether to keep serving after a command fails\n  --echo                     repeat every command before its reply\n  --repl                     expect a human instead of a client (prompt, aliases, help)\n                             (needs the `repl` feature)\n  --help                     print this message\n\n  (`--name <value>` may also be written `--name=<value>`)"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.35:
 	.ascii	"Parse IncDec extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.2:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.39:
 	.ascii	"Parse Mul extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.3:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.41:
 	.ascii	"Parse ScaleFactor extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.4:
-	.ascii	"*~ "
+.Lanon.62fb3b87f511695ce61fb365a19fad11.43:
+	.ascii	"Parse ScaleBounds extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.5:
-	.ascii	"Unsupported cmd!"
-
-.Lanon.cabb224323f7f456a7965482e4c09287.6:
-	.byte	10
+.Lanon.62fb3b87f511695ce61fb365a19fad11.45:
+	.ascii	"Parse Div extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.7:
-	.asciz	"%d\n"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.48:
+	.ascii	"Parse Undo extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.8:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.57:
 	.ascii	"IncDec extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.9:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.58:
 	.ascii	"Mul extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.10:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.59:
 	.ascii	"ScaleFactor nested extension"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.11:
+.Lanon.62fb3b87f511695ce61fb365a19fad11.60:
+	.ascii	"ScaleBounds nested extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.61:
+	.ascii	"Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.62:
+	.ascii	"Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.63:
+	.ascii	"Undo checkpoint"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.64:
+	.ascii	"unknown command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.65:
+	.ascii	"unsupported command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.66:
+	.ascii	"bad number"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.67:
+	.ascii	"trailing garbage"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.68:
+	.ascii	"line too long"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.72:
+	.ascii	"failed to accept a connection"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.73:
+	.ascii	"--repl needs the `repl` feature"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.0:
+	.ascii	"incdec"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.1:
+	.ascii	"mul"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.2:
+	.ascii	"mul.scale_factor"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.3:
+	.ascii	"mul.scale_factor.scale_bounds"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.4:
+	.ascii	"div"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.5:
+	.ascii	"undo"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.6:
+	.byte	1
+	.zero	7
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.0
+	.ascii	"\006\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.1
+	.ascii	"\003\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.2
+	.ascii	"\020\000\000\000\000\000\000\000\b"
+	.zero	7
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.3
+	.ascii	"\035\000\000\000\000\000\000\000\020"
+	.zero	7
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.4
+	.ascii	"\003\000\000\000\000\000\000\000 "
+	.zero	7
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.5
+	.asciz	"\004\000\000\000\000\000\000"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.15:
+	.byte	43
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.17:
+	.byte	45
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.18:
+	.byte	42
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.20:
+	.ascii	"*~"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.28:
+	.ascii	"+-"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.29:
+	.ascii	"*~~"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.30:
+	.byte	47
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.31:
+	.byte	37
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.32:
+	.byte	117
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.33:
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.15
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.17
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.28
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.18
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.20
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.29
+	.asciz	"\003\000\000\000\000\000\000"
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.30
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.31
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.82f370daf7c215be2d2bc157adb991a2.32
+	.asciz	"\001\000\000\000\000\000\000"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.42:
+	.ascii	"scale bounds are inverted!"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.43:
+	.ascii	"dividing by zero!"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.44:
+	.ascii	"dividing MIN by -1 overflows!"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.45:
 	.ascii	"multiplying by 7 is unlucky!"
 
-	.ident	"rustc version 1.99.0-nightly (6f72b5dd5 2026-07-22)"
+.Lanon.82f370daf7c215be2d2bc157adb991a2.46:
+	.ascii	"not enough history!"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.47:
+	.ascii	"nothing to undo!"
+
+.Lanon.82f370daf7c215be2d2bc157adb991a2.50:
+	.ascii	"nothing to roll back!"
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.87.rel
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.88.rel
+
+	.byte	1
+	.byte	17
+	.byte	1
+	.byte	37
+	.byte	14
+	.byte	19
+	.byte	5
+	.byte	3
+	.byte	14
+	.byte	16
+	.byte	23
+	.byte	27
+	.byte	14
+	.byte	17
+	.byte	1
+	.byte	85
+	.byte	23
+	.byte	0
+	.byte	0
+	.byte	2
+	.byte	57
+	.byte	1
+	.byte	3
+	.byte	14
+	.byte	0
+	.byte	0
+	.byte	3
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	11
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	4
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	5
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	0
+.Lcu_begin0:
+	.long	.Ldebug_info_end0-.Ldebug_info_start0
+.Ldebug_info_start0:
+	.short	4
+	.long	.debug_abbrev
+	.byte	8
+	.byte	1
+	.long	.Linfo_string0
+	.short	28
+	.long	.Linfo_string1
+	.long	.Lline_table_start0
+	.long	.Linfo_string2
+	.quad	0
+	.long	.Ldebug_ranges0
+	.byte	2
+	.long	.Linfo_string3
+	.byte	2
+	.long	.Linfo_string4
+	.byte	3
+	.quad	.Lfunc_begin15
+	.long	.Lfunc_end15-.Lfunc_begin15
+	.byte	1
+	.byte	86
+	.long	.Linfo_string7
+	.long	.Linfo_string8
+	.byte	1
+	.byte	60
+	.byte	3
+
+
+	.byte	4
+	.quad	.Lfunc_begin16
+	.long	.Lfunc_end16-.Lfunc_begin16
+	.byte	1
+	.byte	86
+	.long	.Linfo_string9
+	.long	.Linfo_string10
+	.byte	1
+	.short	266
+	.byte	3
+
+
+	.byte	0
+	.byte	2
+	.long	.Linfo_string5
+	.byte	2
+	.long	.Linfo_string6
+	.byte	3
+	.quad	.Lfunc_begin17
+	.long	.Lfunc_end17-.Lfunc_begin17
+	.byte	1
+	.byte	86
+	.long	.Linfo_string11
+	.long	.Linfo_string12
+	.byte	2
+	.byte	38
+	.byte	3
+
+
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+.Ldebug_info_end0:
+.Lsec_end0:
+.Lsec_end1:
+.Lsec_end2:
+	.long	76
+	.short	2
+	.long	.Lcu_begin0
+	.byte	8
+	.byte	0
+	.zero	4,255
+	.quad	.Lfunc_begin15
+	.quad	.Lsec_end0-.Lfunc_begin15
+	.quad	.Lfunc_begin16
+	.quad	.Lsec_end1-.Lfunc_begin16
+	.quad	.Lfunc_begin17
+	.quad	.Lsec_end2-.Lfunc_begin17
+	.quad	0
+	.quad	0
+.Ldebug_ranges0:
+	.quad	.Lfunc_begin15
+	.quad	.Lfunc_end15
+	.quad	.Lfunc_begin16
+	.quad	.Lfunc_end16
+	.quad	.Lfunc_begin17
+	.quad	.Lfunc_end17
+	.quad	0
+	.quad	0
+.Linfo_string0:
+	.asciz	"clang LLVM (rustc version 1.97.0-nightly (e50aa6fba 2026-05-19))"
+.Linfo_string1:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a/library/core/src/lib.rs/@/core.667c7a611d73a360-cgu.0"
+.Linfo_string2:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a"
+.Linfo_string3:
+	.asciz	"core"
+.Linfo_string4:
+	.asciz	"panicking"
+.Linfo_string5:
+	.asciz	"slice"
+.Linfo_string6:
+	.asciz	"index"
+.Linfo_string7:
+	.asciz	"core::panicking::panic_fmt"
+.Linfo_string8:
+	.asciz	"panic_fmt"
+.Linfo_string9:
+	.asciz	"core::panicking::panic_bounds_check"
+.Linfo_string10:
+	.asciz	"panic_bounds_check"
+.Linfo_string11:
+	.asciz	"core::slice::index::slice_index_fail"
+.Linfo_string12:
+	.asciz	"slice_index_fail"
+	.ident	"rustc version 1.97.0-nightly (e50aa6fba 2026-05-19)"
+.Lline_table_start0:
@@ -1,190 +1,3359 @@
+<optional_trait_methods::commands::Args>::num::<isize>:
+.Lfunc_begin0:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<isize>
+	and	al, 1
+	cmove	rdx, r15
+	neg	al
+	or	al, 2
+	mov	qword ptr [rbx], rdx
+	mov	byte ptr [rbx + 8], al
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end0:
+
+<optional_trait_methods::commands::Args>::regs::<isize>:
+.Lfunc_begin1:
+	push	r14
+	push	rbx
+	sub	rsp, 88
+	mov	rbx, rdi
+	lea	r14, [rsp + 16]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	rax, qword ptr [r14]
+	cmp	qword ptr [r14 + 16], 64
+	jne	.LBB1_20
+	mov	rcx, qword ptr [rsp + 24]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 64], xmm0
+	movaps	xmmword ptr [rsp + 48], xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 80], 0
+	mov	edx, 64
+	xor	esi, esi
+.LBB1_2:
+	mov	rdi, rcx
+	add	rcx, 16
+	add	rdx, -16
+	movaps	xmmword ptr [rsp], xmm0
+	xor	r8d, r8d
+.LBB1_3:
+	mov	r10b, byte ptr [rdi + 2*r8]
+	lea	r9d, [r10 - 48]
+	cmp	r9b, 10
+	jb	.LBB1_13
+	lea	r9d, [r10 - 97]
+	cmp	r9b, 5
+	ja	.LBB1_10
+	add	r10b, -87
+	jmp	.LBB1_12
+.LBB1_10:
+	lea	r9d, [r10 - 71]
+	cmp	r9b, -6
+	jb	.LBB1_20
+	add	r10b, -55
+.LBB1_12:
+	mov	r9d, r10d
+.LBB1_13:
+	mov	r10b, byte ptr [rdi + 2*r8 + 1]
+	lea	r11d, [r10 - 48]
+	cmp	r11b, 10
+	jb	.LBB1_19
+	lea	r11d, [r10 - 97]
+	cmp	r11b, 5
+	ja	.LBB1_16
+	add	r10b, -87
+	jmp	.LBB1_18
+.LBB1_16:
+	lea	r11d, [r10 - 71]
+	cmp	r11b, -6
+	jb	.LBB1_20
+	add	r10b, -55
+.LBB1_18:
+	mov	r11d, r10d
+.LBB1_19:
+	shl	r9b, 4
+	or	r9b, r11b
+	mov	byte ptr [rsp + r8], r9b
+	inc	r8
+	cmp	r8, 8
+	jne	.LBB1_3
+	cmp	rsi, 7
+	ja	.LBB1_8
+	mov	rdi, qword ptr [rsp]
+	mov	qword ptr [rsp + 8*rsi + 16], rdi
+	mov	rsi, qword ptr [rsp + 80]
+	inc	rsi
+	mov	qword ptr [rsp + 80], rsi
+.LBB1_8:
+	test	rdx, rdx
+	jne	.LBB1_2
+	lea	rdi, [rbx + 8]
+	lea	rsi, [rsp + 16]
+	mov	ecx, 9
+	rep movsq es:[rdi], [rsi]
+	xor	eax, eax
+	jmp	.LBB1_21
+.LBB1_20:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB1_21:
+	mov	qword ptr [rbx], rax
+	add	rsp, 88
+	pop	rbx
+	pop	r14
+	ret
+.Lfunc_end1:
+
+<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_traits::controller::TargetController<optional_trait_methods::using_traits::targets::basic::BasicTarget>>::apply::{closure#0}>:
+.Lfunc_begin2:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rax, qword ptr [rdi + 64]
+	xor	r15d, r15d
+	cmp	rax, 9
+	mov	r12d, 8
+	cmovb	r12, rdi
+	lea	rax, [8*rax]
+	cmovb	r15, rax
+	test	r15, r15
+	je	.LBB2_3
+	mov	rbx, rsi
+	xor	r14d, r14d
+.LBB2_2:
+	mov	rdx, qword ptr [r12 + 8*r14]
+	mov	rdi, rbx
+	mov	rsi, r14
+	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::write_reg
+	inc	r14
+	add	r15, -8
+	jne	.LBB2_2
+.LBB2_3:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end2:
+
+optional_trait_methods::num::format_decimal::<isize>:
+.Lfunc_begin3:
+	push	rax
+	mov	eax, 39
+	movabs	r9, 7378697629483820647
+	mov	rcx, rdi
+.LBB3_1:
+	cmp	rax, 39
+	ja	.LBB3_7
+	mov	r8, rax
+	mov	rax, rcx
+	imul	r9
+	mov	rax, rdx
+	shr	rax, 63
+	sar	rdx, 2
+	add	rdx, rax
+	lea	rax, [rdx + rdx]
+	lea	rax, [rax + 4*rax]
+	mov	r10, rcx
+	sub	r10, rax
+	mov	rax, r10
+	neg	rax
+	cmovs	rax, r10
+	add	al, 48
+	mov	byte ptr [rsi + r8], al
+	add	rcx, 9
+	lea	rax, [r8 - 1]
+	cmp	rcx, 19
+	mov	rcx, rdx
+	jae	.LBB3_1
+	test	rdi, rdi
+	jns	.LBB3_6
+	inc	r8
+	add	r8, -2
+	cmp	r8, 39
+	ja	.LBB3_7
+	mov	byte ptr [rsi + r8], 45
+.LBB3_6:
+	mov	edx, 40
+	sub	rdx, r8
+	add	rsi, r8
+	mov	rax, rsi
+	pop	rcx
+	ret
+.LBB3_7:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end3:
+
+optional_trait_methods::num::format_decimal::<usize>:
+.Lfunc_begin4:
+	mov	eax, 39
+	movabs	r8, -3689348814741910323
+.LBB4_1:
+	cmp	rax, 39
+	ja	.LBB4_4
+	mov	rcx, rax
+	mov	rax, rdi
+	mul	r8
+	shr	rdx, 3
+	lea	eax, [rdx + rdx]
+	lea	eax, [rax + 4*rax]
+	mov	r9d, edi
+	sub	r9d, eax
+	or	r9b, 48
+	mov	byte ptr [rsi + rcx], r9b
+	lea	rax, [rcx - 1]
+	cmp	rdi, 10
+	mov	rdi, rdx
+	jae	.LBB4_1
+	add	rsi, rcx
+	inc	rcx
+	mov	edx, 41
+	sub	rdx, rcx
+	mov	rax, rsi
+	ret
+.LBB4_4:
+	push	rax
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end4:
+
+optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin5:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB5_2
+	lea	rcx, [rip + .LJTI5_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB5_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB5_5
+.LBB5_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB5_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB5_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB5_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB5_18
+.LBB5_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB5_15
+	jmp	.LBB5_28
+.LBB5_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB5_14
+	jmp	.LBB5_28
+.LBB5_19:
+	lea	r14, [rbx + 8]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 72]
+	cmp	rcx, 9
+	mov	eax, 8
+	cmovb	rax, r14
+	lea	rcx, [8*rcx]
+	cmovae	rcx, r12
+	test	rcx, rcx
+	je	.LBB5_26
+	add	rcx, rax
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB5_21:
+	mov	qword ptr [rsp + 24], 0
+	mov	rsi, qword ptr [rax]
+	add	rax, 8
+	mov	qword ptr [rsp + 16], rsi
+	xor	esi, esi
+.LBB5_22:
+	cmp	r12, 256
+	jae	.LBB5_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB5_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 8
+	jne	.LBB5_22
+	cmp	rax, rcx
+	jne	.LBB5_21
+.LBB5_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB5_27
+.LBB5_11:
+	mov	rdi, qword ptr [rbx + 8]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<isize>
+.LBB5_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB5_27
+.LBB5_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI5_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB5_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB5_4
+.LBB5_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB5_4
+.LBB5_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB5_27
+.LBB5_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.63.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB5_12
+.LBB5_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB5_4:
+	mov	edx, 4
+.LBB5_5:
+	mov	rdi, r15
+.LBB5_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB5_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	ecx, 10
+	mov	rdi, rbp
+	mov	rsi, rbx
+	rep movsq es:[rdi], [rsi]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB5_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB5_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end5:
+.LJTI5_0:
+	.long	.LBB5_10-.LJTI5_0
+	.long	.LBB5_11-.LJTI5_0
+	.long	.LBB5_13-.LJTI5_0
+	.long	.LBB5_19-.LJTI5_0
+.LJTI5_1:
+	.long	.LBB5_3-.LJTI5_1
+	.long	.LBB5_6-.LJTI5_1
+	.long	.LBB5_7-.LJTI5_1
+	.long	.LBB5_8-.LJTI5_1
+	.long	.LBB5_9-.LJTI5_1
+
+optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin6:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB6_2
+	lea	rcx, [rip + .LJTI6_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB6_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB6_5
+.LBB6_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB6_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB6_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB6_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB6_18
+.LBB6_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB6_15
+	jmp	.LBB6_28
+.LBB6_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB6_14
+	jmp	.LBB6_28
+.LBB6_19:
+	lea	r14, [rbx + 8]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 72]
+	cmp	rcx, 9
+	mov	eax, 8
+	cmovb	rax, r14
+	lea	rcx, [8*rcx]
+	cmovae	rcx, r12
+	test	rcx, rcx
+	je	.LBB6_26
+	add	rcx, rax
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB6_21:
+	mov	qword ptr [rsp + 24], 0
+	mov	rsi, qword ptr [rax]
+	add	rax, 8
+	mov	qword ptr [rsp + 16], rsi
+	xor	esi, esi
+.LBB6_22:
+	cmp	r12, 256
+	jae	.LBB6_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB6_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 8
+	jne	.LBB6_22
+	cmp	rax, rcx
+	jne	.LBB6_21
+.LBB6_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB6_27
+.LBB6_11:
+	mov	rdi, qword ptr [rbx + 8]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<isize>
+.LBB6_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB6_27
+.LBB6_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI6_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB6_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB6_4
+.LBB6_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB6_4
+.LBB6_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB6_27
+.LBB6_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.64.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB6_12
+.LBB6_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB6_4:
+	mov	edx, 4
+.LBB6_5:
+	mov	rdi, r15
+.LBB6_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB6_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	ecx, 10
+	mov	rdi, rbp
+	mov	rsi, rbx
+	rep movsq es:[rdi], [rsi]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB6_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB6_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end6:
+.LJTI6_0:
+	.long	.LBB6_10-.LJTI6_0
+	.long	.LBB6_11-.LJTI6_0
+	.long	.LBB6_13-.LJTI6_0
+	.long	.LBB6_19-.LJTI6_0
+.LJTI6_1:
+	.long	.LBB6_3-.LJTI6_1
+	.long	.LBB6_6-.LJTI6_1
+	.long	.LBB6_7-.LJTI6_1
+	.long	.LBB6_8-.LJTI6_1
+	.long	.LBB6_9-.LJTI6_1
+
+optional_trait_methods::report_port:
+.Lfunc_begin7:
+	push	rbx
+	sub	rsp, 64
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 16]
+	movaps	xmmword ptr [rsi], xmm0
+	lea	rdx, [rsp + 12]
+	mov	dword ptr [rdx], 16
+	call	qword ptr [rip + getsockname@GOTPCREL]
+	test	eax, eax
+	js	.LBB7_1
+	xor	eax, eax
+	test	al, al
+	jne	.LBB7_7
+	movzx	ebx, word ptr [rsp + 18]
+	rol	bx, 8
+	shl	ebx, 16
+	shr	ebx, 16
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 48], 0
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.30]
+	mov	edx, 23
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	ecx, 39
+.LBB7_4:
+	cmp	rcx, 39
+	ja	.LBB7_8
+	mov	rax, rcx
+	movzx	edx, bx
+	imul	esi, edx, 52429
+	shr	esi, 19
+	lea	ecx, [rsi + rsi]
+	lea	ecx, [rcx + 4*rcx]
+	sub	ebx, ecx
+	or	bl, 48
+	mov	byte ptr [rsp + rax + 16], bl
+	lea	rcx, [rax - 1]
+	mov	ebx, esi
+	cmp	dx, 10
+	jae	.LBB7_4
+	lea	rsi, [rsp + rax]
+	add	rsi, 16
+	inc	rax
+	mov	edx, 41
+	sub	rdx, rax
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB7_7
+.LBB7_1:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB7_7:
+	add	rsp, 64
+	pop	rbx
+	ret
+.LBB7_8:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end7:
+
+optional_trait_methods::usage_error:
+.Lfunc_begin8:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.31]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	rax, qword ptr [rbx]
+	lea	rcx, [rip + .LJTI8_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB8_1:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.7]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 16
+	jmp	.LBB8_2
+.LBB8_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.12]
+	mov	edx, 39
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	jmp	.LBB8_7
+.LBB8_4:
+	mov	r14, qword ptr [rbx + 8]
+	mov	r15, qword ptr [rbx + 16]
+	mov	r12, qword ptr [rbx + 24]
+	mov	rbx, qword ptr [rbx + 32]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.9]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, r15
+	call	r13
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.10]
+	mov	edx, 2
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r12
+	mov	rdx, rbx
+	call	r13
+	jmp	.LBB8_7
+.LBB8_5:
+	mov	rsi, qword ptr [rbx + 8]
+	mov	rdx, qword ptr [rbx + 16]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.11]
+	mov	edx, 21
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB8_7
+.LBB8_3:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+.LBB8_2:
+	mov	edi, 2
+	call	r15
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, rbx
+	call	r15
+.LBB8_7:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.32]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 2
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	mov	edx, 823
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	mov	rax, rbx
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	jmp	rax
+.Lfunc_end8:
+.LJTI8_0:
+	.long	.LBB8_1-.LJTI8_0
+	.long	.LBB8_3-.LJTI8_0
+	.long	.LBB8_4-.LJTI8_0
+	.long	.LBB8_5-.LJTI8_0
+	.long	.LBB8_6-.LJTI8_0
+
 __rustc::rust_begin_unwind:
+.Lfunc_begin9:
 	push	rax
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.0]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
 	mov	edx, 7
 	mov	edi, 2
 	call	qword ptr [rip + write@GOTPCREL]
 	call	qword ptr [rip + abort@GOTPCREL]
-.Lfunc_end0:
+.Lfunc_end9:
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin10:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB10_1
+	xor	eax, eax
+	mov	qword ptr [r14 + 1024], rax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	cmovae	rdx, rax
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB10_3
+	mov	edi, 1
+	mov	rsi, r15
+	mov	rdx, rbx
+	xor	ecx, ecx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	jmp	optional_trait_methods::connection::write_fd
+.LBB10_1:
+	cmp	rax, rdx
+	jb	.LBB10_4
+.LBB10_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB10_4:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end10:
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin11:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB11_1
+	xor	eax, eax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	mov	qword ptr [r14 + 1024], rax
+	cmovae	rdx, rax
+	mov	ebp, dword ptr [r14 + 1032]
+	mov	edi, ebp
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB11_3
+	mov	edi, ebp
+	mov	rsi, r15
+	mov	rdx, rbx
+	mov	ecx, 1
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	optional_trait_methods::connection::write_fd
+.LBB11_1:
+	cmp	rax, rdx
+	jb	.LBB11_4
+.LBB11_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB11_4:
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end11:
+
+<optional_trait_methods::using_traits::controller::TargetController<optional_trait_methods::using_traits::targets::basic::BasicTarget> as optional_trait_methods::state_machine::Controller>::parse_command:
+.Lfunc_begin12:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	sub	rsp, 176
+	mov	rbx, rdi
+	lea	rdi, [rsi + 1]
+	movzx	eax, byte ptr [rsi]
+	cmp	eax, 111
+	jle	.LBB12_5
+	lea	ecx, [rax - 112]
+	cmp	ecx, 7
+	ja	.LBB12_58
+	lea	r8, [rip + .LJTI12_0]
+	movsxd	rcx, dword ptr [r8 + 4*rcx]
+	add	rcx, r8
+	jmp	rcx
+.LBB12_3:
+	cmp	rdx, 1
+	jne	.LBB12_34
+	xor	eax, eax
+	jmp	.LBB12_36
+.LBB12_5:
+	cmp	eax, 63
+	je	.LBB12_25
+	cmp	eax, 71
+	je	.LBB12_28
+	cmp	eax, 103
+	jne	.LBB12_58
+	cmp	rdx, 1
+	jne	.LBB12_37
+	mov	al, 5
+	jmp	.LBB12_62
+.LBB12_10:
+	mov	r15, rdx
+	dec	r15
+	jne	.LBB12_29
+	setne	al
+	movzx	ecx, al
+	sub	r15, rcx
+	add	rdi, rcx
+.LBB12_12:
+	mov	r14, r15
+.LBB12_13:
+	movzx	r12d, al
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB12_41
+	cmp	r15, r14
+	jne	.LBB12_60
+	mov	byte ptr [rbx], 1
+	mov	qword ptr [rbx + 8], rdx
+	jmp	.LBB12_69
+.LBB12_16:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB12_18
+.LBB12_17:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_39
+.LBB12_18:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	je	.LBB12_42
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB12_59
+.LBB12_20:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB12_22
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_44
+.LBB12_22:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB12_43
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB12_66
+	mov	byte ptr [rbx], 3
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB12_69
+.LBB12_25:
+	mov	rax, rdx
+	dec	rax
+	jne	.LBB12_54
+	mov	qword ptr [rsp], rdi
+	mov	qword ptr [rsp + 8], rax
+	mov	qword ptr [rsp + 16], 1
+	xor	ebp, ebp
+.LBB12_27:
+	mov	byte ptr [rbx], 2
+	mov	byte ptr [rbx + 1], bpl
+	mov	byte ptr [rbx + 2], al
+	jmp	.LBB12_69
+.LBB12_28:
+	mov	rax, rdx
+	dec	rax
+	je	.LBB12_49
+	jmp	.LBB12_48
+.LBB12_29:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_58
+	xor	eax, eax
+	test	r15, r15
+	setne	al
+	sub	r15, rax
+	add	rdi, rax
+	cmp	rdx, 3
+	jb	.LBB12_12
+	xor	r14d, r14d
+.LBB12_32:
+	cmp	byte ptr [rdi + r14], 32
+	je	.LBB12_13
+	inc	r14
+	cmp	r15, r14
+	jne	.LBB12_32
+	jmp	.LBB12_12
+.LBB12_34:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_58
+	mov	qword ptr [rbx + 8], 1
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+.LBB12_36:
+	mov	byte ptr [rbx], al
+	jmp	.LBB12_69
+.LBB12_37:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_39
+	mov	qword ptr [rbx + 8], 1
+	jmp	.LBB12_52
+.LBB12_39:
+	cmp	eax, 63
+	je	.LBB12_53
+	cmp	eax, 71
+	jne	.LBB12_58
+	jmp	.LBB12_47
+.LBB12_41:
+	inc	r12
+	mov	qword ptr [rbx + 8], r12
+	mov	byte ptr [rbx + 16], 2
+	jmp	.LBB12_68
+.LBB12_42:
+	lea	r14, [rsp + 80]
+	mov	rsi, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<isize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	je	.LBB12_65
+.LBB12_43:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], cl
+	jmp	.LBB12_68
+.LBB12_44:
+	mov	r8d, eax
+	add	r8b, -63
+	je	.LBB12_53
+	rol	r8b, 5
+	movzx	r8d, r8b
+	cmp	r8d, 1
+	je	.LBB12_47
+	cmp	r8d, 7
+	je	.LBB12_17
+	jmp	.LBB12_58
+.LBB12_47:
+	lea	rax, [rdx - 1]
+.LBB12_48:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_58
+.LBB12_49:
+	lea	rsi, [rsp + 152]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::regs::<isize>
+	mov	rcx, qword ptr [r14 + 8]
+	mov	al, byte ptr [r14 + 16]
+	cmp	byte ptr [r14], 0
+	jne	.LBB12_57
+	movups	xmm0, xmmword ptr [rsp + 17]
+	movups	xmm1, xmmword ptr [rsp + 33]
+	movups	xmm2, xmmword ptr [rsp + 49]
+	movups	xmm3, xmmword ptr [rsp + 64]
+	movups	xmmword ptr [rsp + 127], xmm3
+	movaps	xmmword ptr [rsp + 112], xmm2
+	movaps	xmmword ptr [rsp + 96], xmm1
+	movaps	xmmword ptr [rsp + 80], xmm0
+	cmp	qword ptr [rsp + 160], 0
+	je	.LBB12_61
+	mov	rax, qword ptr [rsp + 168]
+	mov	qword ptr [rbx + 8], rax
+.LBB12_52:
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+	jmp	.LBB12_62
+.LBB12_53:
+	lea	rax, [rdx - 1]
+.LBB12_54:
+	cmp	byte ptr [rdi], 32
+	jne	.LBB12_58
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], rax
+	mov	qword ptr [rsi + 16], 1
+	test	rax, rax
+	setne	bpl
+	lea	r14, [rsp + 80]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::names
+	mov	al, byte ptr [r14 + 8]
+	cmp	al, -1
+	je	.LBB12_63
+	mov	rcx, qword ptr [rsp + 80]
+.LBB12_57:
+	mov	qword ptr [rbx + 8], rcx
+	jmp	.LBB12_59
+.LBB12_58:
+	mov	rdi, rsi
+	mov	rsi, rdx
+	call	optional_trait_methods::commands::unrecognized
+	mov	qword ptr [rbx + 8], 0
+.LBB12_59:
+	mov	byte ptr [rbx + 16], al
+.LBB12_68:
+	mov	byte ptr [rbx], -1
+.LBB12_69:
+	add	rsp, 176
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB12_60:
+	lea	rax, [r14 + r12]
+	inc	rax
+	jmp	.LBB12_67
+.LBB12_61:
+	mov	qword ptr [rbx + 8], rcx
+	mov	byte ptr [rbx + 16], al
+	movaps	xmm0, xmmword ptr [rsp + 80]
+	movaps	xmm1, xmmword ptr [rsp + 96]
+	movaps	xmm2, xmmword ptr [rsp + 112]
+	movups	xmmword ptr [rbx + 17], xmm0
+	movups	xmmword ptr [rbx + 33], xmm1
+	movups	xmmword ptr [rbx + 49], xmm2
+	movups	xmm0, xmmword ptr [rsp + 127]
+	movups	xmmword ptr [rbx + 64], xmm0
+	mov	al, 6
+.LBB12_62:
+	mov	byte ptr [rbx], al
+	jmp	.LBB12_69
+.LBB12_63:
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB12_66
+	mov	al, byte ptr [rsp + 80]
+	jmp	.LBB12_27
+.LBB12_65:
+	cmp	qword ptr [rsp + 8], 0
+	je	.LBB12_70
+.LBB12_66:
+	mov	rax, qword ptr [rsp + 16]
+.LBB12_67:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], 3
+	jmp	.LBB12_68
+.LBB12_70:
+	mov	byte ptr [rbx], 4
+	mov	qword ptr [rbx + 8], r15
+	mov	qword ptr [rbx + 16], rax
+	jmp	.LBB12_69
+.Lfunc_end12:
+.LJTI12_0:
+	.long	.LBB12_3-.LJTI12_0
+	.long	.LBB12_58-.LJTI12_0
+	.long	.LBB12_20-.LJTI12_0
+	.long	.LBB12_10-.LJTI12_0
+	.long	.LBB12_58-.LJTI12_0
+	.long	.LBB12_58-.LJTI12_0
+	.long	.LBB12_58-.LJTI12_0
+	.long	.LBB12_16-.LJTI12_0
+
+main:
+.Lfunc_begin13:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 3224
+	cmp	rdi, 2
+	jl	.LBB13_104
+	mov	rbx, rsi
+	mov	ebp, 1
+	lea	r12, [rsp + 648]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	mov	dword ptr [rsp + 4], eax
+	mov	qword ptr [rsp + 32], 0
+	mov	qword ptr [rsp + 176], 0
+	mov	qword ptr [rsp + 120], 0
+	mov	qword ptr [rsp + 128], 0
+	mov	qword ptr [rsp + 192], 0
+	mov	qword ptr [rsp + 184], 0
+	mov	qword ptr [rsp + 24], rsi
+	mov	qword ptr [rsp + 200], rdi
+.LBB13_2:
+	mov	r13, qword ptr [rbx + 8*rbp]
+	mov	rdi, r13
+	call	rcx
+	mov	rbx, rax
+	test	rax, rax
+	je	.LBB13_7
+	xor	r15d, r15d
+.LBB13_4:
+	cmp	byte ptr [r13 + r15], 61
+	je	.LBB13_9
+	inc	r15
+	cmp	rbx, r15
+	jne	.LBB13_4
+	mov	qword ptr [rsp + 8], 0
+	mov	r15, rbx
+	jmp	.LBB13_8
+.LBB13_7:
+	mov	qword ptr [rsp + 8], 0
+	xor	r15d, r15d
+.LBB13_8:
+	jmp	.LBB13_10
+.LBB13_9:
+	lea	rax, [r15 + 1]
+	mov	rcx, rbx
+	sub	rcx, rax
+	mov	qword ptr [rsp + 384], rcx
+	lea	rax, [r15 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 8], rax
+.LBB13_10:
+	mov	qword ptr [rsp + 136], rbp
+	lea	rax, [rbp + 1]
+	mov	qword ptr [rsp + 16], rax
+	mov	qword ptr [rsp + 632], 0
+	mov	qword ptr [rsp + 640], 7
+	mov	edx, 168
+	mov	rdi, r12
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.24]
+	call	r14
+	mov	r14d, 32
+.LBB13_11:
+	cmp	qword ptr [rsp + r14 + 624], r15
+	jne	.LBB13_14
+	movzx	r12d, byte ptr [rsp + r14 + 632]
+	mov	rbp, qword ptr [rsp + r14 + 616]
+	mov	rdi, rbp
+	mov	rsi, r13
+	mov	rdx, r15
+	call	qword ptr [rip + bcmp@GOTPCREL]
+	test	eax, eax
+	jne	.LBB13_14
+	cmp	r12d, 255
+	jne	.LBB13_15
+.LBB13_14:
+	add	r14, 24
+	cmp	r14, 200
+	jne	.LBB13_11
+	jmp	.LBB13_162
+.LBB13_15:
+	cmp	r12, 255
+	je	.LBB13_162
+	cmp	r12b, 4
+	jae	.LBB13_19
+	mov	r11, qword ptr [rsp + 8]
+	test	r11, r11
+	mov	rbx, qword ptr [rsp + 24]
+	mov	r13, qword ptr [rsp + 200]
+	je	.LBB13_21
+	mov	r9, qword ptr [rsp + 16]
+	mov	r10, qword ptr [rsp + 384]
+	jmp	.LBB13_24
+.LBB13_19:
+	cmp	qword ptr [rsp + 8], 0
+	mov	rbx, qword ptr [rsp + 24]
+	mov	r13, qword ptr [rsp + 200]
+	jne	.LBB13_171
+	mov	r11d, 1
+	mov	r9, qword ptr [rsp + 16]
+	xor	r10d, r10d
+	jmp	.LBB13_24
+.LBB13_21:
+	cmp	qword ptr [rsp + 16], r13
+	mov	r14, qword ptr [rsp + 136]
+	jge	.LBB13_172
+	mov	rdi, qword ptr [rbx + 8*r14 + 8]
+	test	rdi, rdi
+	je	.LBB13_172
+	mov	qword ptr [rsp + 8], rdi
+	call	qword ptr [rip + strlen@GOTPCREL]
+	mov	r11, qword ptr [rsp + 8]
+	mov	r10, rax
+	add	r14, 2
+	mov	r9, r14
+.LBB13_24:
+	cmp	r12b, 5
+	jne	.LBB13_26
+	mov	al, 1
+	mov	qword ptr [rsp + 192], rax
+	mov	rbp, r9
+	cmp	r9, r13
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 648]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB13_2
+	jmp	.LBB13_174
+.LBB13_26:
+	movzx	eax, r12b
+	mov	eax, eax
+	lea	rcx, [rip + .LJTI13_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB13_27:
+	test	r10, r10
+	je	.LBB13_189
+	mov	qword ptr [rsp + 360], r11
+	mov	qword ptr [rsp + 368], r10
+	mov	dword ptr [rsp + 4], eax
+	cmp	word ptr [rsp + 32], 0
+	mov	ax, 1
+	jmp	.LBB13_77
+.LBB13_29:
+	mov	al, 1
+	mov	qword ptr [rsp + 128], rax
+	jmp	.LBB13_51
+.LBB13_30:
+	mov	qword ptr [rsp + 376], r10
+	mov	qword ptr [rsp + 176], r11
+	jmp	.LBB13_51
+.LBB13_31:
+	cmp	r10, 8
+	je	.LBB13_42
+	cmp	r10, 4
+	jne	.LBB13_190
+	mov	eax, 4
+	cmp	byte ptr [r11], 101
+	jne	.LBB13_191
+	cmp	byte ptr [r11 + 1], 120
+	jne	.LBB13_191
+	cmp	byte ptr [r11 + 2], 105
+	jne	.LBB13_191
+	cmp	byte ptr [r11 + 3], 116
+	jne	.LBB13_191
+	jmp	.LBB13_50
+.LBB13_37:
+	test	r10, r10
+	je	.LBB13_188
+	lea	rcx, [r11 + 1]
+	lea	rdi, [r10 - 1]
+	movzx	eax, byte ptr [r11]
+	cmp	eax, 43
+	je	.LBB13_52
+	cmp	eax, 45
+	jne	.LBB13_53
+	mov	sil, 1
+	jmp	.LBB13_54
+.LBB13_41:
+	mov	al, 1
+	mov	qword ptr [rsp + 184], rax
+	jmp	.LBB13_51
+.LBB13_42:
+	mov	eax, 8
+	cmp	byte ptr [r11], 99
+	jne	.LBB13_191
+	cmp	byte ptr [r11 + 1], 111
+	jne	.LBB13_191
+	cmp	byte ptr [r11 + 2], 110
+	jne	.LBB13_191
+	cmp	byte ptr [r11 + 3], 116
+	jne	.LBB13_191
+	cmp	byte ptr [r11 + 4], 105
+	jne	.LBB13_191
+	cmp	byte ptr [r11 + 5], 110
+	jne	.LBB13_191
+	cmp	byte ptr [r11 + 6], 117
+	jne	.LBB13_191
+	cmp	byte ptr [r11 + 7], 101
+	jne	.LBB13_191
+.LBB13_50:
+	cmp	r10, 8
+	setne	al
+	mov	qword ptr [rsp + 120], rax
+.LBB13_51:
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 648]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	rbp, r9
+	jmp	.LBB13_78
+.LBB13_52:
+	xor	esi, esi
+	jmp	.LBB13_54
+.LBB13_53:
+	xor	esi, esi
+	mov	rdi, r10
+	mov	rcx, r11
+.LBB13_54:
+	cmp	rdi, 1
+	jbe	.LBB13_61
+	mov	al, byte ptr [rcx]
+	cmp	al, 48
+	jne	.LBB13_63
+	movzx	eax, byte ptr [rcx + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB13_64
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB13_80
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB13_79
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB13_64
+	mov	r8b, 16
+	mov	r12b, 1
+	jmp	.LBB13_82
+.LBB13_61:
+	test	rdi, rdi
+	je	.LBB13_203
+	mov	al, byte ptr [rcx]
+.LBB13_63:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB13_203
+.LBB13_64:
+	add	rdi, rcx
+	mov	rax, rdi
+	dec	rax
+	je	.LBB13_66
+	cmp	byte ptr [rax], 95
+	je	.LBB13_203
+.LBB13_66:
+	xor	r14d, r14d
+	xor	eax, eax
+.LBB13_67:
+	cmp	rcx, rdi
+	je	.LBB13_75
+	mov	r8b, byte ptr [rcx]
+	inc	rcx
+	cmp	r8b, 95
+	jne	.LBB13_70
+	test	r14b, 1
+	mov	r14d, 0
+	je	.LBB13_203
+	jmp	.LBB13_67
+.LBB13_70:
+	add	r8b, -48
+	cmp	r8b, 9
+	ja	.LBB13_203
+	mov	dx, 10
+	mul	dx
+	jo	.LBB13_203
+	movzx	edx, r8b
+	mov	r14b, 1
+	test	sil, sil
+	je	.LBB13_74
+	sub	ax, dx
+	jb	.LBB13_203
+	jmp	.LBB13_67
+.LBB13_74:
+	add	ax, dx
+	jb	.LBB13_203
+	jmp	.LBB13_67
+.LBB13_75:
+	mov	dword ptr [rsp + 4], eax
+.LBB13_76:
+	cmp	word ptr [rsp + 32], 0
+	mov	ax, 2
+.LBB13_77:
+	mov	qword ptr [rsp + 32], rax
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 648]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	rbp, r9
+	jne	.LBB13_184
+.LBB13_78:
+	cmp	rbp, r13
+	jl	.LBB13_2
+	jmp	.LBB13_173
+.LBB13_79:
+	mov	r8b, 8
+	jmp	.LBB13_81
+.LBB13_80:
+	mov	r8b, 2
+.LBB13_81:
+	xor	r12d, r12d
+.LBB13_82:
+	mov	r14, r10
+	cmp	rdi, 2
+	je	.LBB13_202
+	lea	r10, [rcx + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jb	.LBB13_87
+	test	r12b, r12b
+	je	.LBB13_202
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB13_202
+	add	al, -87
+	mov	edx, eax
+.LBB13_87:
+	cmp	dl, r8b
+	jae	.LBB13_202
+	add	rcx, rdi
+	mov	rax, rcx
+	dec	rax
+	je	.LBB13_90
+	cmp	byte ptr [rax], 95
+	je	.LBB13_202
+.LBB13_90:
+	movzx	edi, r8b
+	xor	eax, eax
+	mov	dword ptr [rsp + 4], 0
+.LBB13_91:
+	cmp	r10, rcx
+	je	.LBB13_76
+	mov	dl, byte ptr [r10]
+	inc	r10
+	cmp	dl, 95
+	jne	.LBB13_94
+	test	al, 1
+	mov	eax, 0
+	je	.LBB13_202
+	jmp	.LBB13_91
+.LBB13_94:
+	mov	qword ptr [rsp + 8], r11
+	lea	r11d, [rdx - 48]
+	cmp	r11b, 10
+	jb	.LBB13_98
+	test	r12b, r12b
+	je	.LBB13_201
+	or	dl, 32
+	lea	eax, [rdx - 97]
+	cmp	al, 5
+	ja	.LBB13_201
+	add	dl, -87
+	mov	r11d, edx
+.LBB13_98:
+	cmp	r11b, r8b
+	jae	.LBB13_201
+	mov	eax, dword ptr [rsp + 4]
+	mul	di
+	mov	dword ptr [rsp + 4], eax
+	jo	.LBB13_201
+	movzx	edx, r11b
+	mov	al, 1
+	test	sil, sil
+	je	.LBB13_102
+	mov	r11d, dword ptr [rsp + 4]
+	sub	r11w, dx
+	jmp	.LBB13_103
+.LBB13_102:
+	mov	r11d, dword ptr [rsp + 4]
+	add	r11w, dx
+.LBB13_103:
+	mov	dword ptr [rsp + 4], r11d
+	mov	r11, qword ptr [rsp + 8]
+	jb	.LBB13_202
+	jmp	.LBB13_91
+.LBB13_104:
+	xor	ebx, ebx
+	xor	ebp, ebp
+.LBB13_105:
+	xorps	xmm0, xmm0
+	lea	rax, [rsp + 144]
+	movups	xmmword ptr [rax + 8], xmm0
+	mov	qword ptr [rax + 24], 0
+	mov	qword ptr [rax], rbx
+	lea	r14, [rsp + 1672]
+	xor	ebx, ebx
+	mov	r15, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r15
+	lea	r12, [rsp + 632]
+	mov	edx, 1040
+	mov	rdi, r12
+	xor	esi, esi
+	call	r15
+	lea	rdi, [rsp + 2712]
+	mov	edx, 512
+	xor	esi, esi
+	call	r15
+	mov	dword ptr [rsp + 136], ebp
+	shr	ebp, 8
+	mov	dword ptr [rsp + 8], ebp
+	mov	r13, qword ptr [rip + read@GOTPCREL]
+	xor	ecx, ecx
+	mov	dword ptr [rsp + 16], eax
+.LBB13_106:
+	mov	rdi, qword ptr [rsp + 1656]
+	mov	rax, qword ptr [rsp + 1664]
+	xor	r15d, r15d
+	mov	rdx, rcx
+	xor	ebp, ebp
+.LBB13_107:
+	mov	rsi, rax
+	mov	rax, rdi
+.LBB13_108:
+	cmp	rsi, rax
+	jb	.LBB13_112
+	mov	qword ptr [rsp + 2696], rbx
+	cmp	rdx, 1025
+	mov	rsi, r14
+	mov	eax, 1
+	cmovae	rsi, rax
+	cmovae	rdx, rbx
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 1024
+	xor	edi, edi
+	mov	rsi, r12
+	call	r13
+	test	rax, rax
+	js	.LBB13_122
+	je	.LBB13_123
+	mov	qword ptr [rsp + 1656], rax
+	mov	qword ptr [rsp + 1664], 0
+	xor	ecx, ecx
+	xor	edx, edx
+	xor	esi, esi
+.LBB13_112:
+	cmp	rax, rsi
+	jb	.LBB13_166
+	cmp	rax, 1024
+	ja	.LBB13_166
+	cmp	rsi, rax
+	je	.LBB13_108
+	inc	rsi
+	mov	rdi, rax
+	neg	rdi
+.LBB13_116:
+	mov	r8b, byte ptr [rsp + rsi + 631]
+	mov	qword ptr [rsp + 1664], rsi
+	cmp	r8b, 10
+	je	.LBB13_126
+	cmp	rbp, 511
+	jbe	.LBB13_119
+	mov	r15b, 1
+	jmp	.LBB13_120
+.LBB13_119:
+	mov	byte ptr [rsp + rbp + 2712], r8b
+	inc	rbp
+.LBB13_120:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB13_116
+	mov	rdi, rax
+	jmp	.LBB13_107
+.LBB13_122:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB13_123:
+	test	rbp, rbp
+	setne	al
+	or	al, r15b
+	test	al, 1
+	je	.LBB13_170
+	test	r15b, 1
+	jne	.LBB13_130
+	mov	ecx, 0
+	mov	edx, 0
+	cmp	rbp, 512
+	ja	.LBB13_167
+	jmp	.LBB13_128
+.LBB13_126:
+	test	r15b, 1
+	jne	.LBB13_130
+	cmp	rbp, 512
+	ja	.LBB13_166
+.LBB13_128:
+	test	rbp, rbp
+	setne	al
+	test	byte ptr [rsp + 8], al
+	jne	.LBB13_131
+	test	rbp, rbp
+	je	.LBB13_106
+	jmp	.LBB13_132
+.LBB13_130:
+	mov	qword ptr [rsp + 48], 3
+	mov	qword ptr [rsp + 56], 512
+	mov	byte ptr [rsp + 64], 4
+	mov	byte ptr [rsp + 40], -1
+	lea	rdi, [rsp + 392]
+	mov	rsi, r14
+	lea	rdx, [rsp + 40]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	mov	ebp, dword ptr [rsp + 16]
+	mov	r15, qword ptr [rsp + 24]
+	jmp	.LBB13_139
+.LBB13_131:
+	mov	rdi, r14
+	lea	rsi, [rsp + 2712]
+	mov	rdx, rbp
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB13_132:
+	lea	r15, [rsp + 552]
+	mov	rdi, r15
+	lea	rsi, [rsp + 2712]
+	mov	rdx, rbp
+	call	<optional_trait_methods::using_traits::controller::TargetController<optional_trait_methods::using_traits::targets::basic::BasicTarget> as optional_trait_methods::state_machine::Controller>::parse_command
+	cmp	byte ptr [rsp + 552], -1
+	je	.LBB13_137
+	mov	ecx, 10
+	lea	rdi, [rsp + 472]
+	mov	rsi, r15
+	rep movsq es:[rdi], [rsi]
+	movzx	eax, byte ptr [rsp + 472]
+	lea	rcx, [rax - 6]
+	cmp	rax, 7
+	cmovb	rcx, rbx
+	lea	rsi, [rip + .LJTI13_4]
+	movsxd	rdx, dword ptr [rsi + 4*rcx]
+	add	rdx, rsi
+	jmp	rdx
+.LBB13_134:
+	dec	rcx
+	lea	rdx, [rip + .LJTI13_5]
+	movsxd	rax, dword ptr [rdx + 4*rcx]
+	add	rax, rdx
+	jmp	rax
+.LBB13_135:
+	mov	qword ptr [rsp + 48], 2
+	mov	byte ptr [rsp + 40], -1
+.LBB13_136:
+	mov	ebp, dword ptr [rsp + 16]
+	mov	r15, qword ptr [rsp + 24]
+	jmp	.LBB13_138
+.LBB13_137:
+	mov	eax, dword ptr [rsp + 568]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	r15, qword ptr [rsp + 24]
+	cmovne	r15, qword ptr [rsp + 560]
+	sete	cl
+	mov	ebp, dword ptr [rsp + 16]
+	movzx	ebp, bpl
+	cmovne	ebp, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 48], rcx
+	mov	qword ptr [rsp + 56], r15
+	mov	byte ptr [rsp + 64], bpl
+	mov	byte ptr [rsp + 40], -1
+.LBB13_138:
+	lea	rdi, [rsp + 392]
+	mov	rsi, r14
+	lea	rdx, [rsp + 40]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB13_139:
+	cmp	byte ptr [rsp + 392], -1
+	jne	.LBB13_143
+	mov	rax, qword ptr [rsp + 400]
+	cmp	byte ptr [rsp + 136], 0
+	jne	.LBB13_176
+	cmp	eax, 1
+	je	.LBB13_179
+	cmp	eax, 4
+	je	.LBB13_178
+.LBB13_143:
+	mov	qword ptr [rsp + 24], r15
+	mov	dword ptr [rsp + 16], ebp
+	mov	rcx, qword ptr [rsp + 2696]
+	jmp	.LBB13_106
+.LBB13_144:
+	mov	eax, eax
+	lea	rcx, [rip + .LJTI13_6]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	mov	ebp, dword ptr [rsp + 16]
+	mov	r15, qword ptr [rsp + 24]
+	jmp	rax
+.LBB13_145:
+	mov	rax, qword ptr [rsp + 144]
+	jmp	.LBB13_149
+.LBB13_146:
+	mov	rsi, qword ptr [rsp + 480]
+	mov	rdx, qword ptr [rsp + 488]
+	lea	rdi, [rsp + 144]
+	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::write_reg
+	jmp	.LBB13_161
+.LBB13_147:
+	mov	word ptr [rsp + 40], 2
+	jmp	.LBB13_138
+.LBB13_148:
+	mov	rsi, qword ptr [rsp + 480]
+	lea	rdi, [rsp + 144]
+	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::read_reg
+.LBB13_149:
+	mov	byte ptr [rsp + 40], 1
+	mov	qword ptr [rsp + 48], rax
+	jmp	.LBB13_138
+.LBB13_150:
+	mov	rsi, qword ptr [rsp + 480]
+	lea	rdi, [rsp + 144]
+	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::set_state
+	jmp	.LBB13_161
+.LBB13_151:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 288], xmm0
+	movaps	xmmword ptr [rsp + 304], xmm0
+	movaps	xmmword ptr [rsp + 320], xmm0
+	movaps	xmmword ptr [rsp + 336], xmm0
+	mov	qword ptr [rsp + 352], 0
+	xor	r15d, r15d
+	xor	ebp, ebp
+.LBB13_152:
+	lea	rdi, [rsp + 144]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::read_reg
+	cmp	r15, 7
+	ja	.LBB13_154
+	mov	qword ptr [rsp + 8*r15 + 288], rax
+	mov	r15, qword ptr [rsp + 352]
+	inc	r15
+	mov	qword ptr [rsp + 352], r15
+.LBB13_154:
+	inc	rbp
+	cmp	rbp, 4
+	jne	.LBB13_152
+	mov	edx, 72
+	lea	rdi, [rsp + 215]
+	lea	rsi, [rsp + 288]
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	call	r15
+	mov	byte ptr [rsp + 40], 3
+	mov	edx, 79
+	lea	rdi, [rsp + 41]
+	lea	rsi, [rsp + 208]
+	call	r15
+	jmp	.LBB13_136
+.LBB13_156:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 256], xmm0
+	movaps	xmmword ptr [rsp + 240], xmm0
+	movaps	xmmword ptr [rsp + 224], xmm0
+	movaps	xmmword ptr [rsp + 208], xmm0
+	mov	qword ptr [rsp + 272], 0
+	xor	r15d, r15d
+	xor	ebp, ebp
+.LBB13_157:
+	lea	rdi, [rsp + 144]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::read_reg
+	cmp	r15, 7
+	ja	.LBB13_159
+	mov	qword ptr [rsp + 8*r15 + 208], rax
+	mov	r15, qword ptr [rsp + 272]
+	inc	r15
+	mov	qword ptr [rsp + 272], r15
+.LBB13_159:
+	inc	rbp
+	cmp	rbp, 4
+	jne	.LBB13_157
+	lea	rdi, [rsp + 480]
+	lea	rsi, [rsp + 144]
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_traits::controller::TargetController<optional_trait_methods::using_traits::targets::basic::BasicTarget>>::apply::{closure#0}>
+	mov	ebp, dword ptr [rsp + 16]
+	mov	r15, qword ptr [rsp + 24]
+.LBB13_161:
+	mov	byte ptr [rsp + 40], 0
+	jmp	.LBB13_138
+.LBB13_162:
+	xor	ecx, ecx
+	mov	rbp, r13
+	mov	r15, rbx
+.LBB13_163:
+.LBB13_164:
+	lea	rdi, [rsp + 632]
+	mov	qword ptr [rdi], rcx
+	mov	qword ptr [rdi + 8], rbp
+	mov	qword ptr [rdi + 16], r15
+	mov	qword ptr [rdi + 24], r11
+	mov	qword ptr [rdi + 32], r10
+.LBB13_165:
+	call	optional_trait_methods::usage_error
+	mov	ebx, 1
+	jmp	.LBB13_169
+.LBB13_166:
+	mov	rdx, rcx
+.LBB13_167:
+	xor	eax, eax
+	mov	qword ptr [rsp + 2696], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, 1
+	mov	rsi, r14
+.LBB13_168:
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+.LBB13_169:
+	mov	rax, rbx
+	add	rsp, 3224
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB13_170:
+	xor	edx, edx
+	jmp	.LBB13_167
+.LBB13_171:
+	mov	ecx, 3
+	jmp	.LBB13_163
+.LBB13_172:
+	mov	ecx, 1
+	jmp	.LBB13_163
+.LBB13_173:
+	test	byte ptr [rsp + 192], 1
+	je	.LBB13_182
+.LBB13_174:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.51]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 31
+.LBB13_175:
+	mov	edi, 2
+	call	r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	ebx, 1
+	mov	edx, 1
+	mov	edi, 2
+	call	r14
+	jmp	.LBB13_169
+.LBB13_176:
+	cmp	eax, 1
+	je	.LBB13_179
+	cmp	eax, 4
+	jne	.LBB13_181
+.LBB13_178:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 1672]
+	mov	edx, 23
+	jmp	.LBB13_180
+.LBB13_179:
+	mov	rsi, qword ptr [rsp + 408]
+	mov	rdx, qword ptr [rsp + 416]
+	lea	rdi, [rsp + 1672]
+.LBB13_180:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 1672]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB13_181:
+	mov	ebx, 1
+	mov	rdx, qword ptr [rsp + 2696]
+	jmp	.LBB13_167
+.LBB13_182:
+	test	byte ptr [rsp + 184], 1
+	je	.LBB13_185
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	xor	ebx, ebx
+	mov	edx, 823
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 1
+	jmp	.LBB13_168
+.LBB13_184:
+	mov	ecx, 4
+	jmp	.LBB13_163
+.LBB13_185:
+	mov	r15, qword ptr [rsp + 176]
+	test	r15, r15
+	je	.LBB13_192
+	mov	rdi, r15
+	mov	r14, qword ptr [rsp + 376]
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<isize>
+	test	al, 1
+	je	.LBB13_196
+	mov	rbx, rdx
+	jmp	.LBB13_193
+.LBB13_188:
+	xor	r10d, r10d
+	jmp	.LBB13_203
+.LBB13_189:
+	mov	ecx, 2
+	xor	r10d, r10d
+	jmp	.LBB13_164
+.LBB13_190:
+	mov	rax, r10
+.LBB13_191:
+	mov	ecx, 2
+	mov	r10, rax
+	jmp	.LBB13_164
+.LBB13_192:
+	xor	ebx, ebx
+.LBB13_193:
+	mov	rax, qword ptr [rsp + 32]
+	test	ax, ax
+	je	.LBB13_197
+	movzx	eax, ax
+	cmp	eax, 1
+	jne	.LBB13_198
+	mov	rdi, qword ptr [rsp + 360]
+	mov	rsi, qword ptr [rsp + 368]
+	call	<optional_trait_methods::connection::Listener>::unix
+	mov	ebp, edx
+	jmp	.LBB13_199
+.LBB13_196:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.19]
+	lea	rdi, [rsp + 632]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 15
+	mov	qword ptr [rdi + 24], r15
+	mov	qword ptr [rdi + 32], r14
+	mov	qword ptr [rdi], 2
+	jmp	.LBB13_165
+.LBB13_197:
+	movzx	eax, byte ptr [rsp + 128]
+	shl	eax, 8
+	movzx	ebp, byte ptr [rsp + 120]
+	or	ebp, eax
+	jmp	.LBB13_105
+.LBB13_198:
+	mov	r14d, dword ptr [rsp + 4]
+	mov	edi, r14d
+	call	<optional_trait_methods::connection::Listener>::tcp_loopback
+	mov	ebp, edx
+	test	r14w, r14w
+	setne	cl
+	or	cl, al
+	test	cl, 1
+	je	.LBB13_204
+.LBB13_199:
+	cmp	eax, 1
+	jne	.LBB13_205
+	mov	r14d, 1
+	mov	r13d, ebp
+	jmp	.LBB13_206
+.LBB13_201:
+	mov	r11, qword ptr [rsp + 8]
+.LBB13_202:
+	mov	r10, r14
+.LBB13_203:
+	mov	ecx, 2
+	jmp	.LBB13_164
+.LBB13_204:
+	mov	edi, ebp
+	call	optional_trait_methods::report_port
+.LBB13_205:
+	mov	edi, ebp
+	call	<optional_trait_methods::connection::Listener>::accept
+	mov	r14d, eax
+	mov	r13d, edx
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+.LBB13_206:
+	test	r14b, 1
+	je	.LBB13_208
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.50]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 29
+	jmp	.LBB13_175
+.LBB13_208:
+	xorps	xmm0, xmm0
+	lea	rax, [rsp + 144]
+	movups	xmmword ptr [rax + 8], xmm0
+	mov	qword ptr [rax + 24], 0
+	mov	qword ptr [rax], rbx
+	lea	r14, [rsp + 1672]
+	xor	ebx, ebx
+	mov	r15, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r15
+	mov	dword ptr [r14 + 1032], r13d
+	lea	r12, [rsp + 632]
+	mov	edx, 1040
+	mov	rdi, r12
+	xor	esi, esi
+	call	r15
+	lea	rdi, [rsp + 2712]
+	mov	edx, 512
+	xor	esi, esi
+	call	r15
+	xor	edx, edx
+	mov	dword ptr [rsp + 8], eax
+.LBB13_209:
+	mov	rdi, qword ptr [rsp + 1656]
+	mov	rcx, qword ptr [rsp + 1664]
+	xor	ebp, ebp
+	mov	rax, rdx
+	xor	r15d, r15d
+.LBB13_210:
+	mov	rsi, rcx
+	mov	rcx, rdi
+.LBB13_211:
+	cmp	rsi, rcx
+	jb	.LBB13_215
+	mov	qword ptr [rsp + 2696], rbx
+	cmp	rax, 1025
+	mov	rsi, r14
+	mov	ecx, 1
+	cmovae	rsi, rcx
+	cmovae	rax, rbx
+	mov	edi, r13d
+	mov	rdx, rax
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	lea	rdi, [rsp + 40]
+	mov	esi, r13d
+	mov	rdx, r12
+	call	optional_trait_methods::connection::read_fd
+	cmp	byte ptr [rsp + 40], 0
+	jne	.LBB13_225
+	mov	rcx, qword ptr [rsp + 48]
+	test	rcx, rcx
+	je	.LBB13_225
+	mov	qword ptr [rsp + 1656], rcx
+	mov	qword ptr [rsp + 1664], 0
+	xor	edx, edx
+	xor	eax, eax
+	xor	esi, esi
+.LBB13_215:
+	cmp	rcx, rsi
+	jb	.LBB13_264
+	cmp	rcx, 1024
+	ja	.LBB13_264
+	cmp	rsi, rcx
+	je	.LBB13_211
+	inc	rsi
+	mov	rdi, rcx
+	neg	rdi
+.LBB13_219:
+	mov	r8b, byte ptr [rsp + rsi + 631]
+	mov	qword ptr [rsp + 1664], rsi
+	cmp	r8b, 10
+	je	.LBB13_228
+	cmp	r15, 511
+	jbe	.LBB13_222
+	mov	bpl, 1
+	jmp	.LBB13_223
+.LBB13_222:
+	mov	byte ptr [rsp + r15 + 2712], r8b
+	inc	r15
+.LBB13_223:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB13_219
+	mov	rdi, rcx
+	jmp	.LBB13_210
+.LBB13_225:
+	test	r15, r15
+	setne	al
+	or	al, bpl
+	test	al, 1
+	je	.LBB13_263
+	test	bpl, 1
+	jne	.LBB13_232
+	mov	edx, 0
+	cmp	r15, 512
+	jbe	.LBB13_230
+	jmp	.LBB13_263
+.LBB13_228:
+	test	bpl, 1
+	jne	.LBB13_232
+	cmp	r15, 512
+	ja	.LBB13_264
+.LBB13_230:
+	test	r15, r15
+	setne	al
+	test	byte ptr [rsp + 128], al
+	jne	.LBB13_233
+	test	r15, r15
+	je	.LBB13_209
+	jmp	.LBB13_234
+.LBB13_232:
+	mov	qword ptr [rsp + 48], 3
+	mov	qword ptr [rsp + 56], 512
+	mov	byte ptr [rsp + 64], 4
+	jmp	.LBB13_239
+.LBB13_233:
+	mov	rdi, r14
+	lea	rsi, [rsp + 2712]
+	mov	rdx, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB13_234:
+	lea	rdi, [rsp + 552]
+	lea	rsi, [rsp + 2712]
+	mov	rdx, r15
+	call	<optional_trait_methods::using_traits::controller::TargetController<optional_trait_methods::using_traits::targets::basic::BasicTarget> as optional_trait_methods::state_machine::Controller>::parse_command
+	cmp	byte ptr [rsp + 552], -1
+	je	.LBB13_238
+	mov	ecx, 10
+	lea	rdi, [rsp + 472]
+	lea	rsi, [rsp + 552]
+	rep movsq es:[rdi], [rsi]
+	movzx	eax, byte ptr [rsp + 472]
+	lea	rcx, [rax - 6]
+	cmp	rax, 7
+	cmovb	rcx, rbx
+	lea	rsi, [rip + .LJTI13_1]
+	movsxd	rdx, dword ptr [rsi + 4*rcx]
+	add	rdx, rsi
+	jmp	rdx
+.LBB13_236:
+	dec	rcx
+	lea	rdx, [rip + .LJTI13_2]
+	movsxd	rax, dword ptr [rdx + 4*rcx]
+	add	rax, rdx
+	jmp	rax
+.LBB13_237:
+	mov	qword ptr [rsp + 48], 2
+	jmp	.LBB13_239
+.LBB13_238:
+	mov	eax, dword ptr [rsp + 568]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rdx, qword ptr [rsp + 16]
+	cmovne	rdx, qword ptr [rsp + 560]
+	sete	cl
+	mov	esi, dword ptr [rsp + 8]
+	movzx	esi, sil
+	cmovne	esi, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 48], rcx
+	mov	qword ptr [rsp + 16], rdx
+	mov	qword ptr [rsp + 56], rdx
+	mov	dword ptr [rsp + 8], esi
+	mov	byte ptr [rsp + 64], sil
+.LBB13_239:
+	mov	byte ptr [rsp + 40], -1
+.LBB13_240:
+	lea	rdi, [rsp + 392]
+	mov	rsi, r14
+	lea	rdx, [rsp + 40]
+	call	optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	cmp	byte ptr [rsp + 392], -1
+	jne	.LBB13_244
+	mov	rax, qword ptr [rsp + 400]
+	cmp	byte ptr [rsp + 120], 0
+	jne	.LBB13_265
+	cmp	eax, 1
+	je	.LBB13_268
+	cmp	eax, 4
+	je	.LBB13_267
+.LBB13_244:
+	mov	r13d, dword ptr [rsp + 2704]
+	mov	rdx, qword ptr [rsp + 2696]
+	jmp	.LBB13_209
+.LBB13_245:
+	mov	eax, eax
+	lea	rcx, [rip + .LJTI13_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB13_246:
+	mov	rax, qword ptr [rsp + 144]
+	jmp	.LBB13_250
+.LBB13_247:
+	mov	rsi, qword ptr [rsp + 480]
+	lea	rdi, [rsp + 144]
+	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::set_state
+	jmp	.LBB13_262
+.LBB13_248:
+	mov	word ptr [rsp + 40], 2
+	jmp	.LBB13_240
+.LBB13_249:
+	mov	rsi, qword ptr [rsp + 480]
+	lea	rdi, [rsp + 144]
+	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::read_reg
+.LBB13_250:
+	mov	byte ptr [rsp + 40], 1
+	mov	qword ptr [rsp + 48], rax
+	jmp	.LBB13_240
+.LBB13_251:
+	mov	rsi, qword ptr [rsp + 480]
+	mov	rdx, qword ptr [rsp + 488]
+	lea	rdi, [rsp + 144]
+	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::write_reg
+	jmp	.LBB13_262
+.LBB13_252:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 288], xmm0
+	movaps	xmmword ptr [rsp + 304], xmm0
+	movaps	xmmword ptr [rsp + 320], xmm0
+	movaps	xmmword ptr [rsp + 336], xmm0
+	mov	qword ptr [rsp + 352], 0
+	xor	r13d, r13d
+	xor	r15d, r15d
+.LBB13_253:
+	lea	rdi, [rsp + 144]
+	mov	rsi, r15
+	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::read_reg
+	cmp	r13, 7
+	ja	.LBB13_255
+	mov	qword ptr [rsp + 8*r13 + 288], rax
+	mov	r13, qword ptr [rsp + 352]
+	inc	r13
+	mov	qword ptr [rsp + 352], r13
+.LBB13_255:
+	inc	r15
+	cmp	r15, 4
+	jne	.LBB13_253
+	mov	edx, 72
+	lea	rdi, [rsp + 215]
+	lea	rsi, [rsp + 288]
+	mov	r15, qword ptr [rip + memcpy@GOTPCREL]
+	call	r15
+	mov	byte ptr [rsp + 40], 3
+	mov	edx, 79
+	lea	rdi, [rsp + 41]
+	lea	rsi, [rsp + 208]
+	call	r15
+	jmp	.LBB13_240
+.LBB13_257:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 256], xmm0
+	movaps	xmmword ptr [rsp + 240], xmm0
+	movaps	xmmword ptr [rsp + 224], xmm0
+	movaps	xmmword ptr [rsp + 208], xmm0
+	mov	qword ptr [rsp + 272], 0
+	xor	r13d, r13d
+	xor	r15d, r15d
+.LBB13_258:
+	lea	rdi, [rsp + 144]
+	mov	rsi, r15
+	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::read_reg
+	cmp	r13, 7
+	ja	.LBB13_260
+	mov	qword ptr [rsp + 8*r13 + 208], rax
+	mov	r13, qword ptr [rsp + 272]
+	inc	r13
+	mov	qword ptr [rsp + 272], r13
+.LBB13_260:
+	inc	r15
+	cmp	r15, 4
+	jne	.LBB13_258
+	lea	rdi, [rsp + 480]
+	lea	rsi, [rsp + 144]
+	call	<optional_trait_methods::registers::Registers<isize>>::write_all::<&str, <optional_trait_methods::using_traits::controller::TargetController<optional_trait_methods::using_traits::targets::basic::BasicTarget>>::apply::{closure#0}>
+.LBB13_262:
+	mov	byte ptr [rsp + 40], 0
+	jmp	.LBB13_240
+.LBB13_263:
+	xor	edx, edx
+.LBB13_264:
+	xor	eax, eax
+	mov	qword ptr [rsp + 2696], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, r13d
+	mov	rsi, r14
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edi, r13d
+	call	qword ptr [rip + close@GOTPCREL]
+	jmp	.LBB13_169
+.LBB13_265:
+	cmp	eax, 1
+	je	.LBB13_268
+	cmp	eax, 4
+	jne	.LBB13_270
+.LBB13_267:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 1672]
+	mov	edx, 23
+	jmp	.LBB13_269
+.LBB13_268:
+	mov	rsi, qword ptr [rsp + 408]
+	mov	rdx, qword ptr [rsp + 416]
+	lea	rdi, [rsp + 1672]
+.LBB13_269:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 1672]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB13_270:
+	mov	rdx, qword ptr [rsp + 2696]
+	mov	ebx, 1
+	mov	r13d, dword ptr [rsp + 2704]
+	jmp	.LBB13_264
+.LBB13_271:
+	ud2
+.Lfunc_end13:
+.LJTI13_0:
+	.long	.LBB13_27-.LJTI13_0
+	.long	.LBB13_37-.LJTI13_0
+	.long	.LBB13_30-.LJTI13_0
+	.long	.LBB13_31-.LJTI13_0
+	.long	.LBB13_29-.LJTI13_0
+	.long	.LBB13_271-.LJTI13_0
+	.long	.LBB13_41-.LJTI13_0
+.LJTI13_1:
+	.long	.LBB13_245-.LJTI13_1
+	.long	.LBB13_236-.LJTI13_1
+	.long	.LBB13_237-.LJTI13_1
+	.long	.LBB13_236-.LJTI13_1
+	.long	.LBB13_236-.LJTI13_1
+.LJTI13_2:
+	.long	.LBB13_237-.LJTI13_2
+	.long	.LBB13_237-.LJTI13_2
+	.long	.LBB13_237-.LJTI13_2
+	.long	.LBB13_237-.LJTI13_2
+.LJTI13_3:
+	.long	.LBB13_246-.LJTI13_3
+	.long	.LBB13_247-.LJTI13_3
+	.long	.LBB13_248-.LJTI13_3
+	.long	.LBB13_249-.LJTI13_3
+	.long	.LBB13_251-.LJTI13_3
+	.long	.LBB13_252-.LJTI13_3
+	.long	.LBB13_257-.LJTI13_3
+.LJTI13_4:
+	.long	.LBB13_144-.LJTI13_4
+	.long	.LBB13_134-.LJTI13_4
+	.long	.LBB13_135-.LJTI13_4
+	.long	.LBB13_134-.LJTI13_4
+	.long	.LBB13_134-.LJTI13_4
+.LJTI13_5:
+	.long	.LBB13_135-.LJTI13_5
+	.long	.LBB13_135-.LJTI13_5
+	.long	.LBB13_135-.LJTI13_5
+	.long	.LBB13_135-.LJTI13_5
+.LJTI13_6:
+	.long	.LBB13_145-.LJTI13_6
+	.long	.LBB13_150-.LJTI13_6
+	.long	.LBB13_147-.LJTI13_6
+	.long	.LBB13_148-.LJTI13_6
+	.long	.LBB13_146-.LJTI13_6
+	.long	.LBB13_151-.LJTI13_6
+	.long	.LBB13_156-.LJTI13_6
 
-<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::get_state:
-	mov	rax, qword ptr [rdi]
+rust_eh_personality:
+.Lfunc_begin14:
 	ret
-.Lfunc_end1:
+.Lfunc_end14:
 
-<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::set_state:
-	mov	qword ptr [rdi], rsi
+core::panicking::panic_fmt:
+.Lfunc_begin15:
+	.loc	1 60 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp0:
+	.loc	1 80 14 prologue_end
+	call	__rustc::rust_begin_unwind
+.Ltmp1:
+.Lfunc_end15:
+
+core::panicking::panic_bounds_check:
+.Lfunc_begin16:
+	.loc	1 266 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp2:
+	.loc	1 271 5 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp3:
+.Lfunc_end16:
+
+core::slice::index::slice_index_fail:
+.Lfunc_begin17:
+	.loc	2 38 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp4:
+	.loc	3 177 9 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp5:
+.Lfunc_end17:
+
+optional_trait_methods::num::parse_num::<isize>:
+.Lfunc_begin18:
+	test	rsi, rsi
+	je	.LBB18_56
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB18_4
+	cmp	ecx, 45
+	jne	.LBB18_6
+	mov	cl, 1
+	jmp	.LBB18_5
+.LBB18_4:
+	xor	ecx, ecx
+.LBB18_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB18_7
+.LBB18_6:
+	xor	ecx, ecx
+.LBB18_7:
+	cmp	rsi, 1
+	jbe	.LBB18_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB18_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB18_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB18_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB18_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB18_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB18_32
+.LBB18_14:
+	test	rsi, rsi
+	je	.LBB18_56
+	mov	al, byte ptr [rdi]
+.LBB18_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB18_56
+.LBB18_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB18_19
+	cmp	byte ptr [rax], 95
+	je	.LBB18_56
+.LBB18_19:
+	xor	r8d, r8d
+	xor	edx, edx
+.LBB18_20:
+	cmp	rdi, rsi
+	je	.LBB18_51
+	mov	al, byte ptr [rdi]
+	inc	rdi
+	cmp	al, 95
+	jne	.LBB18_23
 	xor	eax, eax
+	test	r8b, 1
+	mov	r8d, 0
+	jne	.LBB18_20
+	jmp	.LBB18_52
+.LBB18_23:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB18_57
+	imul	rdx, rdx, 10
+	jo	.LBB18_57
+	movzx	eax, al
+	test	cl, cl
+	je	.LBB18_27
+	sub	rdx, rax
+	jno	.LBB18_28
+	jmp	.LBB18_57
+.LBB18_27:
+	add	rdx, rax
+	jo	.LBB18_57
+.LBB18_28:
+	mov	r8b, 1
+	jmp	.LBB18_20
+.LBB18_29:
+	mov	r8b, 8
+	jmp	.LBB18_31
+.LBB18_30:
+	mov	r8b, 2
+.LBB18_31:
+	xor	r9d, r9d
+.LBB18_32:
+	cmp	rsi, 2
+	je	.LBB18_56
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB18_53
+.LBB18_34:
+	cmp	dl, r8b
+	jae	.LBB18_56
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB18_37
+	cmp	byte ptr [rax], 95
+	je	.LBB18_56
+.LBB18_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB18_38:
+	cmp	r10, rdi
+	je	.LBB18_51
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB18_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB18_52
+	jmp	.LBB18_38
+.LBB18_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB18_45
+	test	r9b, r9b
+	je	.LBB18_57
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB18_57
+	add	al, -87
+	mov	r11d, eax
+.LBB18_45:
+	cmp	r11b, r8b
+	jae	.LBB18_57
+	imul	rdx, rsi
+	jo	.LBB18_57
+	movzx	eax, r11b
+	test	cl, cl
+	je	.LBB18_49
+	sub	rdx, rax
+	jo	.LBB18_57
+	jmp	.LBB18_50
+.LBB18_49:
+	add	rdx, rax
+	jo	.LBB18_57
+.LBB18_50:
+	mov	r11b, 1
+	jmp	.LBB18_38
+.LBB18_51:
+	mov	eax, 1
 	ret
-.Lfunc_end2:
+.LBB18_53:
+	test	r9b, r9b
+	je	.LBB18_56
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB18_56
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB18_34
+.LBB18_56:
+	xor	eax, eax
+.LBB18_52:
+	ret
+.LBB18_57:
+	xor	eax, eax
+	ret
+.Lfunc_end18:
 
-main:
+<optional_trait_methods::commands::Args>::reg:
+.Lfunc_begin19:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	rcx, qword ptr [r14]
+	mov	r8, qword ptr [r14 + 16]
+	test	r8, r8
+	je	.LBB19_53
+	mov	rsi, qword ptr [rsp + 16]
+	lea	rax, [rsi + 1]
+	lea	rdx, [r8 - 1]
+	movzx	edi, byte ptr [rsi]
+	cmp	edi, 43
+	je	.LBB19_5
+	cmp	edi, 45
+	jne	.LBB19_3
+	mov	dil, 1
+	jmp	.LBB19_6
+.LBB19_5:
+	xor	edi, edi
+.LBB19_6:
+	mov	r8, rdx
+	mov	rsi, rax
+	jmp	.LBB19_7
+.LBB19_3:
+	xor	edi, edi
+.LBB19_7:
+	cmp	r8, 1
+	jbe	.LBB19_8
+	mov	al, byte ptr [rsi]
+	cmp	al, 48
+	jne	.LBB19_10
+	movzx	eax, byte ptr [rsi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB19_11
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB19_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB19_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB19_11
+	mov	r9b, 16
+	mov	r10b, 1
+	jmp	.LBB19_32
+.LBB19_8:
+	test	r8, r8
+	je	.LBB19_53
+	mov	al, byte ptr [rsi]
+.LBB19_10:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB19_53
+.LBB19_11:
+	add	r8, rsi
+	mov	rax, r8
+	dec	rax
+	je	.LBB19_13
+	cmp	byte ptr [rax], 95
+	je	.LBB19_53
+.LBB19_13:
+	xor	r10d, r10d
+	mov	r9d, 10
+	xor	eax, eax
+.LBB19_14:
+	cmp	rsi, r8
+	je	.LBB19_15
+	mov	r11b, byte ptr [rsi]
+	inc	rsi
+	cmp	r11b, 95
+	jne	.LBB19_18
+	xor	edx, edx
+	test	r10b, 1
+	mov	r10d, 0
+	jne	.LBB19_14
+	jmp	.LBB19_54
+.LBB19_18:
+	add	r11b, -48
+	cmp	r11b, 9
+	ja	.LBB19_53
+	mul	r9
+	jo	.LBB19_53
+	movzx	edx, r11b
+	mov	r10b, 1
+	test	dil, dil
+	je	.LBB19_21
+	sub	rax, rdx
+	jae	.LBB19_14
+	jmp	.LBB19_53
+.LBB19_21:
+	add	rax, rdx
+	jae	.LBB19_14
+	jmp	.LBB19_53
+.LBB19_29:
+	mov	r9b, 8
+	jmp	.LBB19_31
+.LBB19_30:
+	mov	r9b, 2
+.LBB19_31:
+	xor	r10d, r10d
+.LBB19_32:
+	cmp	r8, 2
+	je	.LBB19_53
+	lea	r11, [rsi + 2]
+	mov	al, byte ptr [r11]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB19_34
+.LBB19_37:
+	cmp	dl, r9b
+	jae	.LBB19_53
+	add	rsi, r8
+	mov	rax, rsi
+	dec	rax
+	je	.LBB19_40
+	cmp	byte ptr [rax], 95
+	je	.LBB19_53
+.LBB19_40:
+	movzx	r8d, r9b
+	xor	r14d, r14d
+	xor	eax, eax
+.LBB19_41:
+	cmp	r11, rsi
+	je	.LBB19_15
+	mov	dl, byte ptr [r11]
+	inc	r11
+	cmp	dl, 95
+	jne	.LBB19_44
+	xor	edx, edx
+	test	r14b, 1
+	mov	r14d, 0
+	jne	.LBB19_41
+	jmp	.LBB19_54
+.LBB19_44:
+	lea	ebp, [rdx - 48]
+	cmp	bpl, 10
+	jb	.LBB19_48
+	test	r10b, r10b
+	je	.LBB19_53
+	or	dl, 32
+	lea	ebp, [rdx - 97]
+	cmp	bpl, 5
+	ja	.LBB19_53
+	add	dl, -87
+	mov	ebp, edx
+.LBB19_48:
+	cmp	bpl, r9b
+	jae	.LBB19_53
+	mul	r8
+	jo	.LBB19_53
+	movzx	edx, bpl
+	mov	r14b, 1
+	test	dil, dil
+	je	.LBB19_51
+	sub	rax, rdx
+	jae	.LBB19_41
+	jmp	.LBB19_53
+.LBB19_51:
+	add	rax, rdx
+	jae	.LBB19_41
+	jmp	.LBB19_53
+.LBB19_15:
+	mov	dl, 1
+	jmp	.LBB19_54
+.LBB19_34:
+	test	r10b, r10b
+	je	.LBB19_53
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB19_53
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB19_37
+.LBB19_53:
+	xor	edx, edx
+.LBB19_54:
+	cmp	rax, 4
+	setb	sil
+	and	sil, dl
+	cmovne	rcx, rax
+	neg	sil
+	or	sil, 2
+	mov	qword ptr [rbx], rcx
+	mov	byte ptr [rbx + 8], sil
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end19:
+
+<optional_trait_methods::commands::Args>::next:
+.Lfunc_begin20:
+	mov	r10, qword ptr [rsi + 8]
+	mov	rax, qword ptr [rsi + 16]
+	xor	edx, edx
+	test	r10, r10
+	setne	dl
+	cmp	r10, 1
+	mov	rcx, r10
+	adc	rcx, -1
+	mov	r8, qword ptr [rsi]
+	add	r8, rdx
+	mov	r9, rcx
+	cmp	r10, 2
+	jb	.LBB20_5
+	xor	r9d, r9d
+.LBB20_2:
+	cmp	byte ptr [r8 + r9], 32
+	je	.LBB20_5
+	inc	r9
+	cmp	rcx, r9
+	jne	.LBB20_2
+	mov	r9, rcx
+.LBB20_5:
+	add	rax, rdx
+	sub	rcx, r9
+	lea	rdx, [r8 + r9]
+	mov	qword ptr [rsi], rdx
+	mov	qword ptr [rsi + 8], rcx
+	lea	rcx, [r9 + rax]
+	mov	qword ptr [rsi + 16], rcx
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], r8
+	mov	qword ptr [rdi + 16], r9
+	ret
+.Lfunc_end20:
+
+<optional_trait_methods::commands::Args>::names:
+.Lfunc_begin21:
 	push	rbp
 	push	r15
 	push	r14
 	push	r13
 	push	r12
 	push	rbx
-	sub	rsp, 1192
-	lea	rbx, [rsp + 8]
-	mov	qword ptr [rbx], 0
-	lea	r14, [rsp + 152]
-	mov	edx, 1040
+	sub	rsp, 200
+	mov	qword ptr [rsp + 32], rdi
+	lea	r14, [rsp + 40]
 	mov	rdi, r14
-	xor	esi, esi
-	call	qword ptr [rip + memset@GOTPCREL]
-	xorps	xmm0, xmm0
-	lea	r15, [rsp + 18]
-	movaps	xmmword ptr [r15 + 110], xmm0
-	movaps	xmmword ptr [r15 + 94], xmm0
-	movaps	xmmword ptr [r15 + 78], xmm0
-	movaps	xmmword ptr [r15 + 62], xmm0
-	movaps	xmmword ptr [r15 + 46], xmm0
-	movaps	xmmword ptr [r15 + 30], xmm0
-	movaps	xmmword ptr [r15 + 14], xmm0
-	movaps	xmmword ptr [r15 - 2], xmm0
-	mov	rbp, qword ptr [rip + write@GOTPCREL]
-	mov	r12, qword ptr [rip + read@GOTPCREL]
-	xor	eax, eax
-	xor	ecx, ecx
-.LBB3_1:
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r12, qword ptr [r14 + 8]
+	mov	rbx, qword ptr [r14 + 16]
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, qword ptr [rip + bcmp@GOTPCREL]
+.LBB21_1:
+	test	rbx, rbx
+	je	.LBB21_2
 	xor	r13d, r13d
-.LBB3_2:
-	cmp	rcx, rax
-	jb	.LBB3_5
-	mov	edx, 1024
-	xor	edi, edi
-	mov	rsi, r14
-	call	r12
-	test	rax, rax
-	jle	.LBB3_35
-	mov	qword ptr [rsp + 1176], rax
-	mov	qword ptr [rsp + 1184], 0
-	xor	ecx, ecx
-.LBB3_5:
-	cmp	rax, rcx
-	jb	.LBB3_34
-	cmp	rax, 1024
-	ja	.LBB3_34
-	cmp	rcx, rax
-	je	.LBB3_2
-	inc	rcx
-	mov	rdx, rax
-	neg	rdx
-.LBB3_9:
-	mov	sil, byte ptr [rsp + rcx + 151]
-	mov	qword ptr [rsp + 1184], rcx
-	cmp	sil, 10
-	je	.LBB3_10
-	cmp	r13, 127
-	ja	.LBB3_14
-	mov	byte ptr [rsp + r13 + 16], sil
+.LBB21_4:
+	cmp	byte ptr [r12 + r13], 59
+	je	.LBB21_7
 	inc	r13
-.LBB3_14:
-	lea	rsi, [rdx + rcx]
-	inc	rsi
-	inc	rcx
-	cmp	rsi, 1
-	jne	.LBB3_9
-	mov	rcx, rax
-	jmp	.LBB3_2
-.LBB3_35:
-	lea	rax, [r13 - 129]
-	cmp	rax, -128
-	jb	.LBB3_34
-	jmp	.LBB3_36
-.LBB3_10:
-	cmp	r13, 128
-	ja	.LBB3_34
-	test	r13, r13
-	je	.LBB3_1
-.LBB3_36:
-	cmp	r13, 1
-	jne	.LBB3_16
-	cmp	byte ptr [rsp + 16], 112
-	jne	.LBB3_30
-	mov	rdi, rbx
-	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::get_state
-	lea	rdi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.4]
-	mov	rsi, rax
-	xor	eax, eax
-	call	qword ptr [rip + printf@GOTPCREL]
-	jmp	.LBB3_32
-.LBB3_16:
-	cmp	word ptr [rsp + 16], 8307
-	jne	.LBB3_30
-	mov	rcx, r13
-	add	rcx, -2
-	je	.LBB3_30
-	movzx	eax, byte ptr [rsp + 18]
-	cmp	eax, 45
-	je	.LBB3_19
-	cmp	eax, 43
-	jne	.LBB3_21
-	xor	eax, eax
-	jmp	.LBB3_23
-.LBB3_19:
+	cmp	rbx, r13
+	jne	.LBB21_4
 	mov	al, 1
-.LBB3_23:
-	lea	rdx, [rsp + 19]
-	add	r13, -3
-	mov	rcx, r13
-	jne	.LBB3_24
-	jmp	.LBB3_30
-.LBB3_21:
-	xor	eax, eax
-	mov	rdx, r15
-.LBB3_24:
-	xor	edi, edi
-	xor	esi, esi
-.LBB3_25:
-	cmp	rcx, rdi
-	je	.LBB3_29
-	mov	r8b, byte ptr [rdx + rdi]
-	add	r8b, -48
-	cmp	r8b, 9
-	ja	.LBB3_30
-	imul	rsi, rsi, 10
-	jo	.LBB3_30
-	movzx	r8d, r8b
-	inc	rdi
-	add	rsi, r8
-	jno	.LBB3_25
-	jmp	.LBB3_30
-.LBB3_29:
-	movzx	eax, al
-	neg	rax
-	or	rax, 1
-	imul	rsi, rax
-	jno	.LBB3_31
-.LBB3_30:
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 24], r12
+	mov	r13, rbx
+	jmp	.LBB21_8
+.LBB21_2:
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	xor	ebx, ebx
+	mov	qword ptr [rsp + 24], r12
+	xor	r13d, r13d
+	jmp	.LBB21_8
+.LBB21_7:
+	lea	rax, [r13 + 1]
+	sub	rbx, rax
+	lea	rax, [r12 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 24], rax
+	mov	dword ptr [rsp + 12], 0
+.LBB21_8:
+	mov	edx, 144
+	lea	rbp, [rsp + 56]
+	mov	rdi, rbp
+	lea	rsi, [rip + .Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.6]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r14d, 6
+.LBB21_9:
+	cmp	qword ptr [rbp + 16], r13
+	jne	.LBB21_11
+	mov	rdi, qword ptr [rbp + 8]
+	mov	rsi, r12
+	mov	rdx, r13
+	call	r15
+	test	eax, eax
+	je	.LBB21_12
+.LBB21_11:
+	add	rbp, 24
+	dec	r14
+	jne	.LBB21_9
+	jmp	.LBB21_13
+.LBB21_12:
+	mov	rax, qword ptr [rsp + 16]
+	or	al, byte ptr [rbp]
+	mov	qword ptr [rsp + 16], rax
+.LBB21_13:
+	mov	r12, qword ptr [rsp + 24]
+	cmp	byte ptr [rsp + 12], 0
+	je	.LBB21_1
+	mov	rax, qword ptr [rsp + 32]
+	mov	rcx, qword ptr [rsp + 16]
+	mov	byte ptr [rax], cl
+	mov	byte ptr [rax + 8], -1
+	add	rsp, 200
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end21:
+
+<optional_trait_methods::connection::Listener>::tcp_loopback:
+.Lfunc_begin22:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	qword ptr [rsp + 20], 0
+	mov	word ptr [rsp + 12], 2
+	rol	di, 8
+	mov	word ptr [rsp + 14], di
+	mov	dword ptr [rsp + 16], 16777343
+	mov	edi, 2
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebx, edx
+	mov	ebp, 1
+	test	al, 1
+	jne	.LBB22_5
+	lea	rcx, [rsp + 28]
+	mov	dword ptr [rcx], 1
+	mov	edi, ebx
+	mov	esi, 1
+	mov	edx, 2
+	mov	r8d, 4
+	call	qword ptr [rip + setsockopt@GOTPCREL]
+	lea	rsi, [rsp + 12]
+	mov	edi, ebx
 	mov	edx, 16
-	mov	edi, 1
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.2]
-	call	rbp
-	mov	edx, 1
-	mov	edi, 1
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.3]
-	call	rbp
-.LBB3_32:
-	mov	rax, qword ptr [rsp + 1176]
-	mov	rcx, qword ptr [rsp + 1184]
-	jmp	.LBB3_1
-.LBB3_31:
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB22_3
+	mov	edi, ebx
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB22_3
+	xor	ebp, ebp
+	jmp	.LBB22_5
+.LBB22_3:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebx, r14d
+.LBB22_5:
+	mov	eax, ebp
+	mov	edx, ebx
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end22:
+
+<optional_trait_methods::connection::Listener>::socket:
+.Lfunc_begin23:
+	push	rbx
+	xor	ebx, ebx
+	mov	esi, 1
+	xor	edx, edx
+	call	qword ptr [rip + socket@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB23_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB23_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end23:
+
+<optional_trait_methods::connection::Listener>::unix:
+.Lfunc_begin24:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 112
+	mov	rdx, rsi
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [rsp + 96], xmm0
+	movups	xmmword ptr [rsp + 84], xmm0
+	movups	xmmword ptr [rsp + 68], xmm0
+	movups	xmmword ptr [rsp + 52], xmm0
+	movups	xmmword ptr [rsp + 36], xmm0
+	movups	xmmword ptr [rsp + 20], xmm0
+	movups	xmmword ptr [rsp + 4], xmm0
+	mov	word ptr [rsp + 2], 1
+	lea	rax, [rsi - 108]
+	cmp	rax, -107
+	jae	.LBB24_2
+	mov	ebx, 1
+	mov	ebp, 36
+	jmp	.LBB24_7
+.LBB24_2:
+	mov	rsi, rdi
+	lea	rbx, [rsp + 4]
 	mov	rdi, rbx
-	call	<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::set_state
-	test	rax, rax
-	je	.LBB3_32
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	rdi, rbx
+	call	qword ptr [rip + unlink@GOTPCREL]
+	mov	ebx, 1
 	mov	edi, 1
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebp, edx
+	test	al, 1
+	jne	.LBB24_7
+	lea	rsi, [rsp + 2]
+	mov	edi, ebp
+	mov	edx, 110
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB24_6
+	mov	edi, ebp
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB24_6
+	xor	ebx, ebx
+	jmp	.LBB24_7
+.LBB24_6:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebp, r14d
+.LBB24_7:
+	mov	eax, ebx
+	mov	edx, ebp
+	add	rsp, 112
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end24:
+
+<optional_trait_methods::connection::Listener>::accept:
+.Lfunc_begin25:
+	push	rbx
+	xor	ebx, ebx
+	xor	esi, esi
+	xor	edx, edx
+	call	qword ptr [rip + accept@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB25_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB25_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end25:
+
+optional_trait_methods::commands::unrecognized:
+.Lfunc_begin26:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	xor	r14d, r14d
+.LBB26_1:
+	cmp	byte ptr [rbx + r14], 32
+	je	.LBB26_4
+	inc	r14
+	cmp	rsi, r14
+	jne	.LBB26_1
+	mov	r14, rsi
+.LBB26_4:
+	mov	r15d, 8
+	lea	r12, [rip + .Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.33]
+	mov	r13, qword ptr [rip + bcmp@GOTPCREL]
+.LBB26_5:
+	cmp	qword ptr [r15 + r12], r14
+	jne	.LBB26_8
+	mov	rdi, qword ptr [r15 + r12 - 8]
+	mov	rsi, rbx
+	mov	rdx, r14
+	call	r13
+	test	eax, eax
+	je	.LBB26_7
+.LBB26_8:
+	add	r15, 16
+	cmp	r15, 152
+	jne	.LBB26_5
+	xor	eax, eax
+	jmp	.LBB26_10
+.LBB26_7:
+	mov	al, 1
+.LBB26_10:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end26:
+
+optional_trait_methods::connection::read_fd:
+.Lfunc_begin27:
+	push	rbx
+	mov	rax, rdx
+	mov	rbx, rdi
+	mov	edx, 1024
+	mov	edi, esi
 	mov	rsi, rax
-	call	rbp
-	lea	rsi, [rip + .Lanon.cabb224323f7f456a7965482e4c09287.3]
-	mov	edx, 1
-	mov	edi, 1
-	call	rbp
-.LBB3_34:
+	call	qword ptr [rip + read@GOTPCREL]
+	test	rax, rax
+	js	.LBB27_2
+	mov	qword ptr [rbx + 8], rax
 	xor	eax, eax
-	add	rsp, 1192
+	jmp	.LBB27_3
+.LBB27_2:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	eax, dword ptr [rax]
+	mov	dword ptr [rbx + 4], eax
+	mov	eax, 1
+.LBB27_3:
+	mov	dword ptr [rbx], eax
+	pop	rbx
+	ret
+.Lfunc_end27:
+
+optional_trait_methods::connection::write_fd:
+.Lfunc_begin28:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	test	rdx, rdx
+	je	.LBB28_8
+	mov	ebp, ecx
+	mov	rbx, rdx
+	mov	r14, rsi
+	mov	r15d, edi
+	mov	r12, qword ptr [rip + send@GOTPCREL]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+.LBB28_2:
+	mov	edi, r15d
+	mov	rsi, r14
+	mov	rdx, rbx
+	test	bpl, bpl
+	je	.LBB28_3
+	mov	ecx, 16384
+	call	r12
+	jmp	.LBB28_5
+.LBB28_3:
+	call	r13
+.LBB28_5:
+	test	rax, rax
+	js	.LBB28_10
+	cmp	rbx, rax
+	jb	.LBB28_9
+	add	r14, rax
+	sub	rbx, rax
+	jne	.LBB28_2
+.LBB28_8:
+	add	rsp, 8
 	pop	rbx
 	pop	r12
 	pop	r13
@@ -192,25 +3361,501 @@ main:
 	pop	r15
 	pop	rbp
 	ret
-.Lfunc_end3:
+.LBB28_10:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB28_9:
+	mov	rdi, rax
+	mov	rsi, rbx
+	mov	rdx, rbx
+	call	core::slice::index::slice_index_fail
+.Lfunc_end28:
 
-rust_eh_personality:
+<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::read_reg:
+.Lfunc_begin29:
+	test	rsi, rsi
+	je	.LBB29_3
+	mov	rax, rsi
+	cmp	rsi, 4
+	jae	.LBB29_4
+	lea	rdi, [rdi + 8*rax]
+.LBB29_3:
+	mov	rax, qword ptr [rdi]
 	ret
-.Lfunc_end4:
+.LBB29_4:
+	push	rax
+	dec	rax
+	mov	esi, 3
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end29:
+
+<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::set_state:
+.Lfunc_begin30:
+	mov	qword ptr [rdi], rsi
+	ret
+.Lfunc_end30:
 
-.Lanon.cabb224323f7f456a7965482e4c09287.0:
+<optional_trait_methods::using_traits::targets::basic::BasicTarget as optional_trait_methods::using_traits::target::TargetBase>::write_reg:
+.Lfunc_begin31:
+	test	rsi, rsi
+	je	.LBB31_1
+	mov	rax, rsi
+	cmp	rsi, 4
+	jae	.LBB31_5
+	mov	qword ptr [rdi + 8*rax], rdx
+	ret
+.LBB31_1:
+	mov	qword ptr [rdi], rdx
+	ret
+.LBB31_5:
+	push	rax
+	dec	rax
+	mov	esi, 3
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end31:
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.0:
+	.byte	10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.1:
+	.byte	59
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.2:
+	.ascii	"E 01"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.3:
+	.ascii	"E 02"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.4:
+	.ascii	"E."
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.5:
+	.ascii	" at "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.6:
+	.ascii	"E 05"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.7:
+	.ascii	"unknown option: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.8:
+	.ascii	"missing value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.9:
+	.ascii	"invalid value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.10:
+	.ascii	": "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.11:
+	.ascii	" doesn't take a value"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.12:
+	.ascii	"only one of --unix / --tcp may be given"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.14:
+	.ascii	"0123456789abcdef"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.17:
+	.ascii	"--unix"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.18:
+	.ascii	"--tcp"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.19:
+	.ascii	"--initial-state"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.20:
+	.ascii	"--on-error"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.21:
+	.ascii	"--echo"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.22:
+	.ascii	"--repl"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.23:
+	.ascii	"--help"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.24:
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.17
+	.asciz	"\006\000\000\000\000\000\000\000"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.18
+	.ascii	"\005\000\000\000\000\000\000\000\001"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.19
+	.ascii	"\017\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.20
+	.ascii	"\n\000\000\000\000\000\000\000\003"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.21
+	.ascii	"\006\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.22
+	.ascii	"\006\000\000\000\000\000\000\000\005"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.23
+	.ascii	"\006\000\000\000\000\000\000\000\006"
+	.zero	7
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.28:
+	.ascii	"Invalid implementation!"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.29:
+	.ascii	"OK"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.30:
+	.ascii	"listening on 127.0.0.1:"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.31:
+	.ascii	"error: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.32:
+	.zero	2,10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.33:
+	.ascii	"usage: optional-trait-methods [options]\n\n  --unix <path>              serve the first client to connect to a Unix domain socket\n  --tcp <port>               serve the first client to connect to 127.0.0.1:<port>\n                             (port 0 picks any free port, printed to stderr)\n                             (by default, serve stdin / stdout)\n  --initial-state <n>        start the target off in state <n> (by default, 0)\n  --on-error=continue|exit   whether to keep serving after a command fails\n  --echo                     repeat every command before its reply\n  --repl                     expect a human instead of a client (prompt, aliases, help)\n                             (needs the `repl` feature)\n  --help                     print this message\n\n  (`--name <value>` may also be written `--name=<value>`)"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.34:
 	.ascii	"panic!\n"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.1:
-	.ascii	"s "
+.Lanon.62fb3b87f511695ce61fb365a19fad11.42:
+	.ascii	"unknown command"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.2:
-	.ascii	"Unsupported cmd!"
+.Lanon.62fb3b87f511695ce61fb365a19fad11.43:
+	.ascii	"unsupported command"
 
-.Lanon.cabb224323f7f456a7965482e4c09287.3:
-	.byte	10
+.Lanon.62fb3b87f511695ce61fb365a19fad11.44:
+	.ascii	"bad number"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.45:
+	.ascii	"trailing garbage"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.46:
+	.ascii	"line too long"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.50:
+	.ascii	"failed to accept a connection"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.51:
+	.ascii	"--repl needs the `repl` feature"
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.0:
+	.ascii	"incdec"
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.1:
+	.ascii	"mul"
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.2:
+	.ascii	"mul.scale_factor"
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.3:
+	.ascii	"mul.scale_factor.scale_bounds"
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.4:
+	.ascii	"div"
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.5:
+	.ascii	"undo"
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.6:
+	.byte	1
+	.zero	7
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.0
+	.ascii	"\006\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.1
+	.ascii	"\003\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.2
+	.ascii	"\020\000\000\000\000\000\000\000\b"
+	.zero	7
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.3
+	.ascii	"\035\000\000\000\000\000\000\000\020"
+	.zero	7
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.4
+	.ascii	"\003\000\000\000\000\000\000\000 "
+	.zero	7
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.5
+	.asciz	"\004\000\000\000\000\000\000"
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.15:
+	.byte	43
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.17:
+	.byte	45
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.18:
+	.byte	42
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.20:
+	.ascii	"*~"
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.28:
+	.ascii	"+-"
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.29:
+	.ascii	"*~~"
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.30:
+	.byte	47
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.31:
+	.byte	37
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.32:
+	.byte	117
+
+.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.33:
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.15
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.17
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.28
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.18
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.20
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.29
+	.asciz	"\003\000\000\000\000\000\000"
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.30
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.31
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.b645b67eef6acc36b2ae6a3a8b5a1bd1.32
+	.asciz	"\001\000\000\000\000\000\000"
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.63.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.42-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.63.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.43-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.63.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.44-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.63.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.45-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.63.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.46-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.63.rel
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.64.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.42-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.64.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.43-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.64.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.44-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.64.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.45-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.64.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.46-.Lswitch.table.optional_trait_methods::response::reply::<isize, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.64.rel
+
+	.byte	1
+	.byte	17
+	.byte	1
+	.byte	37
+	.byte	14
+	.byte	19
+	.byte	5
+	.byte	3
+	.byte	14
+	.byte	16
+	.byte	23
+	.byte	27
+	.byte	14
+	.byte	17
+	.byte	1
+	.byte	85
+	.byte	23
+	.byte	0
+	.byte	0
+	.byte	2
+	.byte	57
+	.byte	1
+	.byte	3
+	.byte	14
+	.byte	0
+	.byte	0
+	.byte	3
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	11
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	4
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	5
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	0
+.Lcu_begin0:
+	.long	.Ldebug_info_end0-.Ldebug_info_start0
+.Ldebug_info_start0:
+	.short	4
+	.long	.debug_abbrev
+	.byte	8
+	.byte	1
+	.long	.Linfo_string0
+	.short	28
+	.long	.Linfo_string1
+	.long	.Lline_table_start0
+	.long	.Linfo_string2
+	.quad	0
+	.long	.Ldebug_ranges0
+	.byte	2
+	.long	.Linfo_string3
+	.byte	2
+	.long	.Linfo_string4
+	.byte	3
+	.quad	.Lfunc_begin15
+	.long	.Lfunc_end15-.Lfunc_begin15
+	.byte	1
+	.byte	86
+	.long	.Linfo_string7
+	.long	.Linfo_string8
+	.byte	1
+	.byte	60
+	.byte	3
+
+
+	.byte	4
+	.quad	.Lfunc_begin16
+	.long	.Lfunc_end16-.Lfunc_begin16
+	.byte	1
+	.byte	86
+	.long	.Linfo_string9
+	.long	.Linfo_string10
+	.byte	1
+	.short	266
+	.byte	3
+
+
+	.byte	0
+	.byte	2
+	.long	.Linfo_string5
+	.byte	2
+	.long	.Linfo_string6
+	.byte	3
+	.quad	.Lfunc_begin17
+	.long	.Lfunc_end17-.Lfunc_begin17
+	.byte	1
+	.byte	86
+	.long	.Linfo_string11
+	.long	.Linfo_string12
+	.byte	2
+	.byte	38
+	.byte	3
 
-.Lanon.cabb224323f7f456a7965482e4c09287.4:
-	.asciz	"%d\n"
 
-	.ident	"rustc version 1.99.0-nightly (6f72b5dd5 2026-07-22)"
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+.Ldebug_info_end0:
+.Lsec_end0:
+.Lsec_end1:
+.Lsec_end2:
+	.long	76
+	.short	2
+	.long	.Lcu_begin0
+	.byte	8
+	.byte	0
+	.zero	4,255
+	.quad	.Lfunc_begin15
+	.quad	.Lsec_end0-.Lfunc_begin15
+	.quad	.Lfunc_begin16
+	.quad	.Lsec_end1-.Lfunc_begin16
+	.quad	.Lfunc_begin17
+	.quad	.Lsec_end2-.Lfunc_begin17
+	.quad	0
+	.quad	0
+.Ldebug_ranges0:
+	.quad	.Lfunc_begin15
+	.quad	.Lfunc_end15
+	.quad	.Lfunc_begin16
+	.quad	.Lfunc_end16
+	.quad	.Lfunc_begin17
+	.quad	.Lfunc_end17
+	.quad	0
+	.quad	0
+.Linfo_string0:
+	.asciz	"clang LLVM (rustc version 1.97.0-nightly (e50aa6fba 2026-05-19))"
+.Linfo_string1:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a/library/core/src/lib.rs/@/core.667c7a611d73a360-cgu.0"
+.Linfo_string2:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a"
+.Linfo_string3:
+	.asciz	"core"
+.Linfo_string4:
+	.asciz	"panicking"
+.Linfo_string5:
+	.asciz	"slice"
+.Linfo_string6:
+	.asciz	"index"
+.Linfo_string7:
+	.asciz	"core::panicking::panic_fmt"
+.Linfo_string8:
+	.asciz	"panic_fmt"
+.Linfo_string9:
+	.asciz	"core::panicking::panic_bounds_check"
+.Linfo_string10:
+	.asciz	"panic_bounds_check"
+.Linfo_string11:
+	.asciz	"core::slice::index::slice_index_fail"
+.Linfo_string12:
+	.asciz	"slice_index_fail"
+	.ident	"rustc version 1.97.0-nightly (e50aa6fba 2026-05-19)"
+.Lline_table_start0:
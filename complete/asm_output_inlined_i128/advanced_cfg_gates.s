@@ -0,0 +1,5333 @@
+<optional_trait_methods::commands::Args>::num::<i128>:
+.Lfunc_begin0:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rsi, qword ptr [r14 + 8]
+	mov	rdx, qword ptr [r14 + 16]
+	mov	r14, rsp
+	mov	rdi, r14
+	call	optional_trait_methods::num::parse_num::<i128>
+	test	byte ptr [r14], 1
+	je	.LBB0_2
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	qword ptr [rbx + 16], rax
+	mov	qword ptr [rbx + 24], rcx
+	xor	eax, eax
+	jmp	.LBB0_3
+.LBB0_2:
+	mov	qword ptr [rbx + 8], r15
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB0_3:
+	mov	qword ptr [rbx], rax
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end0:
+
+<optional_trait_methods::commands::Args>::regs::<i128>:
+.Lfunc_begin1:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	sub	rsp, 168
+	mov	rbx, rdi
+	lea	r14, [rsp + 16]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	cmp	qword ptr [r14 + 16], 256
+	jne	.LBB1_20
+	lea	rdi, [rsp + 16]
+	mov	r12, qword ptr [rdi + 8]
+	xor	r14d, r14d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	eax, 256
+	xorps	xmm0, xmm0
+.LBB1_2:
+	mov	rcx, r12
+	add	r12, 32
+	add	rax, -32
+	movaps	xmmword ptr [rsp], xmm0
+	xor	edx, edx
+.LBB1_3:
+	mov	dil, byte ptr [rcx + 2*rdx]
+	lea	esi, [rdi - 48]
+	cmp	sil, 10
+	jb	.LBB1_13
+	lea	esi, [rdi - 97]
+	cmp	sil, 5
+	ja	.LBB1_10
+	add	dil, -87
+	jmp	.LBB1_12
+.LBB1_10:
+	lea	esi, [rdi - 71]
+	cmp	sil, -6
+	jb	.LBB1_20
+	add	dil, -55
+.LBB1_12:
+	mov	esi, edi
+.LBB1_13:
+	mov	dil, byte ptr [rcx + 2*rdx + 1]
+	lea	r8d, [rdi - 48]
+	cmp	r8b, 10
+	jb	.LBB1_19
+	lea	r8d, [rdi - 97]
+	cmp	r8b, 5
+	ja	.LBB1_16
+	add	dil, -87
+	jmp	.LBB1_18
+.LBB1_16:
+	lea	r8d, [rdi - 71]
+	cmp	r8b, -6
+	jb	.LBB1_20
+	add	dil, -55
+.LBB1_18:
+	mov	r8d, edi
+.LBB1_19:
+	shl	sil, 4
+	or	sil, r8b
+	mov	byte ptr [rsp + rdx], sil
+	inc	rdx
+	cmp	rdx, 16
+	jne	.LBB1_3
+	cmp	r14, 7
+	ja	.LBB1_8
+	movaps	xmm1, xmmword ptr [rsp]
+	shl	r14, 4
+	movaps	xmmword ptr [rsp + r14 + 16], xmm1
+	mov	r14, qword ptr [rsp + 144]
+	inc	r14
+	mov	qword ptr [rsp + 144], r14
+.LBB1_8:
+	test	rax, rax
+	jne	.LBB1_2
+	lea	rdi, [rbx + 16]
+	lea	rsi, [rsp + 16]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	xor	eax, eax
+	jmp	.LBB1_21
+.LBB1_20:
+	mov	qword ptr [rbx + 8], r15
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB1_21:
+	mov	qword ptr [rbx], rax
+	add	rsp, 168
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end1:
+
+<optional_trait_methods::commands::Args>::num_or::<usize>:
+.Lfunc_begin2:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	cmp	qword ptr [rsi + 8], 0
+	je	.LBB2_1
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	and	al, 1
+	cmove	rdx, r15
+	neg	al
+	or	al, 2
+	jmp	.LBB2_3
+.LBB2_1:
+	mov	al, -1
+	mov	edx, 1
+.LBB2_3:
+	mov	qword ptr [rbx], rdx
+	mov	byte ptr [rbx + 8], al
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end2:
+
+<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>:
+.Lfunc_begin3:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rax, qword ptr [rdi + 128]
+	mov	rcx, rax
+	shl	rcx, 4
+	xor	r15d, r15d
+	cmp	rax, 9
+	mov	r12d, 16
+	cmovb	r12, rdi
+	cmovb	r15, rcx
+	test	r15, r15
+	je	.LBB3_3
+	mov	rbx, rsi
+	xor	r14d, r14d
+	xor	r13d, r13d
+.LBB3_2:
+	mov	rdx, qword ptr [r12 + r13]
+	mov	rcx, qword ptr [r12 + r13 + 8]
+	mov	rdi, rbx
+	mov	rsi, r14
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	inc	r14
+	add	r13, 16
+	cmp	r15, r13
+	jne	.LBB3_2
+.LBB3_3:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end3:
+
+optional_trait_methods::num::format_decimal::<usize>:
+.Lfunc_begin4:
+	mov	eax, 39
+	movabs	r8, -3689348814741910323
+.LBB4_1:
+	cmp	rax, 39
+	ja	.LBB4_4
+	mov	rcx, rax
+	mov	rax, rdi
+	mul	r8
+	shr	rdx, 3
+	lea	eax, [rdx + rdx]
+	lea	eax, [rax + 4*rax]
+	mov	r9d, edi
+	sub	r9d, eax
+	or	r9b, 48
+	mov	byte ptr [rsi + rcx], r9b
+	lea	rax, [rcx - 1]
+	cmp	rdi, 10
+	mov	rdi, rdx
+	jae	.LBB4_1
+	add	rsi, rcx
+	inc	rcx
+	mov	edx, 41
+	sub	rdx, rcx
+	mov	rax, rsi
+	ret
+.LBB4_4:
+	push	rax
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end4:
+
+optional_trait_methods::num::format_decimal::<i128>:
+.Lfunc_begin5:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r12, rsi
+	mov	r15, rdi
+	mov	eax, 39
+	mov	ebp, 10
+	mov	r14, qword ptr [rip + __divti3@GOTPCREL]
+	mov	qword ptr [rsp], rsi
+.LBB5_1:
+	cmp	rax, 39
+	ja	.LBB5_9
+	mov	r13, rax
+	mov	edx, 10
+	mov	rdi, r15
+	mov	rsi, r12
+	xor	ecx, ecx
+	call	r14
+	mov	rcx, rax
+	mov	rsi, rdx
+	mul	rbp
+	lea	rdi, [rsi + 4*rsi]
+	lea	rdx, [rdx + 2*rdi]
+	mov	rdi, r15
+	sub	rdi, rax
+	mov	rax, r12
+	sbb	rax, rdx
+	mov	edx, edi
+	neg	dl
+	test	rax, rax
+	movzx	eax, dl
+	cmovns	eax, edi
+	mov	edx, 48
+	cmp	al, 10
+	jb	.LBB5_4
+	mov	edx, 87
+.LBB5_4:
+	add	dl, al
+	mov	byte ptr [rbx + r13], dl
+	add	r15, 9
+	adc	r12, 0
+	lea	rax, [r13 - 1]
+	cmp	r15, 19
+	sbb	r12, 0
+	mov	r15, rcx
+	mov	r12, rsi
+	jae	.LBB5_1
+	cmp	qword ptr [rsp], 0
+	jns	.LBB5_8
+	inc	r13
+	add	r13, -2
+	cmp	r13, 39
+	ja	.LBB5_9
+	mov	byte ptr [rbx + r13], 45
+.LBB5_8:
+	mov	edx, 40
+	sub	rdx, r13
+	add	rbx, r13
+	mov	rax, rbx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB5_9:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end5:
+
+optional_trait_methods::num::parse_num::<i128>:
+.Lfunc_begin6:
+	push	rbp
+	push	r15
+	push	r14
+	push	rbx
+	test	rdx, rdx
+	je	.LBB6_1
+	mov	rcx, rdx
+	lea	rax, [rsi + 1]
+	dec	rdx
+	movzx	r8d, byte ptr [rsi]
+	cmp	r8d, 43
+	je	.LBB6_6
+	cmp	r8d, 45
+	jne	.LBB6_4
+	mov	r11b, 1
+	jmp	.LBB6_7
+.LBB6_6:
+	xor	r11d, r11d
+.LBB6_7:
+	mov	rcx, rdx
+	mov	rsi, rax
+	jmp	.LBB6_8
+.LBB6_4:
+	xor	r11d, r11d
+.LBB6_8:
+	cmp	rcx, 1
+	jbe	.LBB6_9
+	mov	al, byte ptr [rsi]
+	cmp	al, 48
+	jne	.LBB6_11
+	movzx	eax, byte ptr [rsi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB6_12
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB6_31
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB6_30
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB6_12
+	mov	r10b, 16
+	mov	bl, 1
+	jmp	.LBB6_33
+.LBB6_9:
+	test	rcx, rcx
+	je	.LBB6_1
+	mov	al, byte ptr [rsi]
+.LBB6_11:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB6_1
+.LBB6_12:
+	add	rcx, rsi
+	mov	rax, rcx
+	dec	rax
+	je	.LBB6_14
+	cmp	byte ptr [rax], 95
+	je	.LBB6_1
+.LBB6_14:
+	mov	ebx, 10
+	xor	edx, edx
+	xor	r8d, r8d
+	xor	r9d, r9d
+.LBB6_15:
+	cmp	rsi, rcx
+	je	.LBB6_55
+	mov	bpl, byte ptr [rsi]
+	inc	rsi
+	cmp	bpl, 95
+	jne	.LBB6_18
+	xor	eax, eax
+	test	dl, 1
+	mov	edx, 0
+	jne	.LBB6_15
+	jmp	.LBB6_56
+.LBB6_18:
+	add	bpl, -48
+	cmp	bpl, 9
+	ja	.LBB6_1
+	mov	rax, r8
+	mul	rbx
+	mov	r10, rdx
+	mov	r8, rax
+	mov	rax, r9
+	imul	rbx
+	mov	r9, rax
+	add	r9, r10
+	adc	rdx, 0
+	mov	rax, r9
+	sar	rax, 63
+	mov	r10, rdx
+	xor	r10, rax
+	sar	rdx, 63
+	xor	rdx, rax
+	or	rdx, r10
+	jne	.LBB6_1
+	movzx	eax, bpl
+	test	r11b, r11b
+	je	.LBB6_21
+	sub	r8, rax
+	sbb	r9, 0
+	jo	.LBB6_1
+	jmp	.LBB6_23
+.LBB6_21:
+	add	r8, rax
+	adc	r9, 0
+	jo	.LBB6_1
+.LBB6_23:
+	mov	dl, 1
+	jmp	.LBB6_15
+.LBB6_30:
+	mov	r10b, 8
+	jmp	.LBB6_32
+.LBB6_31:
+	mov	r10b, 2
+.LBB6_32:
+	xor	ebx, ebx
+.LBB6_33:
+	cmp	rcx, 2
+	je	.LBB6_1
+	lea	r14, [rsi + 2]
+	mov	al, byte ptr [r14]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB6_35
+.LBB6_38:
+	cmp	dl, r10b
+	jae	.LBB6_1
+	add	rsi, rcx
+	mov	rax, rsi
+	dec	rax
+	je	.LBB6_41
+	cmp	byte ptr [rax], 95
+	je	.LBB6_1
+.LBB6_41:
+	movzx	r15d, r10b
+	xor	ecx, ecx
+	xor	r8d, r8d
+	xor	r9d, r9d
+.LBB6_42:
+	cmp	r14, rsi
+	je	.LBB6_55
+	mov	al, byte ptr [r14]
+	inc	r14
+	cmp	al, 95
+	jne	.LBB6_45
+	xor	eax, eax
+	test	cl, 1
+	mov	ecx, 0
+	jne	.LBB6_42
+	jmp	.LBB6_56
+.LBB6_45:
+	lea	ebp, [rax - 48]
+	cmp	bpl, 10
+	jb	.LBB6_49
+	test	bl, bl
+	je	.LBB6_1
+	or	al, 32
+	lea	ecx, [rax - 97]
+	cmp	cl, 5
+	ja	.LBB6_1
+	add	al, -87
+	mov	ebp, eax
+.LBB6_49:
+	cmp	bpl, r10b
+	jae	.LBB6_1
+	mov	rax, r8
+	mul	r15
+	mov	rcx, rdx
+	mov	r8, rax
+	mov	rax, r9
+	imul	r15
+	mov	r9, rax
+	add	r9, rcx
+	adc	rdx, 0
+	mov	rax, r9
+	sar	rax, 63
+	mov	rcx, rdx
+	xor	rcx, rax
+	sar	rdx, 63
+	xor	rdx, rax
+	or	rdx, rcx
+	jne	.LBB6_1
+	movzx	eax, bpl
+	test	r11b, r11b
+	je	.LBB6_52
+	sub	r8, rax
+	sbb	r9, 0
+	jo	.LBB6_1
+	jmp	.LBB6_54
+.LBB6_52:
+	add	r8, rax
+	adc	r9, 0
+	jo	.LBB6_1
+.LBB6_54:
+	mov	cl, 1
+	jmp	.LBB6_42
+.LBB6_55:
+	mov	qword ptr [rdi + 16], r8
+	mov	qword ptr [rdi + 24], r9
+	mov	eax, 1
+	jmp	.LBB6_56
+.LBB6_35:
+	test	bl, bl
+	je	.LBB6_1
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB6_1
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB6_38
+.LBB6_1:
+	xor	eax, eax
+.LBB6_56:
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], 0
+	pop	rbx
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end6:
+
+optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin7:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB7_2
+	lea	rcx, [rip + .LJTI7_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB7_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.21]
+	mov	edx, 2
+	jmp	.LBB7_5
+.LBB7_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB7_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB7_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB7_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB7_18
+.LBB7_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB7_15
+	jmp	.LBB7_28
+.LBB7_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB7_14
+	jmp	.LBB7_28
+.LBB7_19:
+	lea	r14, [rbx + 16]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 144]
+	mov	rax, rcx
+	shl	rax, 4
+	cmp	rcx, 9
+	mov	ecx, 16
+	cmovb	rcx, r14
+	cmovae	rax, r12
+	test	rax, rax
+	je	.LBB7_26
+	add	rax, rcx
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+.LBB7_21:
+	movaps	xmm0, xmmword ptr [rcx]
+	add	rcx, 16
+	movaps	xmmword ptr [rsp + 16], xmm0
+	xor	esi, esi
+.LBB7_22:
+	cmp	r12, 256
+	jae	.LBB7_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB7_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 16
+	jne	.LBB7_22
+	cmp	rcx, rax
+	jne	.LBB7_21
+.LBB7_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB7_27
+.LBB7_11:
+	mov	rdi, qword ptr [rbx + 16]
+	mov	rsi, qword ptr [rbx + 24]
+	xorps	xmm0, xmm0
+	lea	rdx, [rsp + 32]
+	movaps	xmmword ptr [rdx + 16], xmm0
+	movaps	xmmword ptr [rdx], xmm0
+	mov	qword ptr [rdx + 32], 0
+	call	optional_trait_methods::num::format_decimal::<i128>
+.LBB7_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB7_27
+.LBB7_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI7_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB7_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB7_4
+.LBB7_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB7_4
+.LBB7_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB7_27
+.LBB7_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB7_12
+.LBB7_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB7_4:
+	mov	edx, 4
+.LBB7_5:
+	mov	rdi, r15
+.LBB7_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB7_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 160
+	mov	rdi, rbp
+	mov	rsi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB7_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB7_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end7:
+.LJTI7_0:
+	.long	.LBB7_10-.LJTI7_0
+	.long	.LBB7_11-.LJTI7_0
+	.long	.LBB7_13-.LJTI7_0
+	.long	.LBB7_19-.LJTI7_0
+.LJTI7_1:
+	.long	.LBB7_3-.LJTI7_1
+	.long	.LBB7_6-.LJTI7_1
+	.long	.LBB7_7-.LJTI7_1
+	.long	.LBB7_8-.LJTI7_1
+	.long	.LBB7_9-.LJTI7_1
+
+optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin8:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB8_2
+	lea	rcx, [rip + .LJTI8_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB8_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.21]
+	mov	edx, 2
+	jmp	.LBB8_5
+.LBB8_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB8_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB8_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB8_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB8_18
+.LBB8_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB8_15
+	jmp	.LBB8_28
+.LBB8_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB8_14
+	jmp	.LBB8_28
+.LBB8_19:
+	lea	r14, [rbx + 16]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 144]
+	mov	rax, rcx
+	shl	rax, 4
+	cmp	rcx, 9
+	mov	ecx, 16
+	cmovb	rcx, r14
+	cmovae	rax, r12
+	test	rax, rax
+	je	.LBB8_26
+	add	rax, rcx
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+.LBB8_21:
+	movaps	xmm0, xmmword ptr [rcx]
+	add	rcx, 16
+	movaps	xmmword ptr [rsp + 16], xmm0
+	xor	esi, esi
+.LBB8_22:
+	cmp	r12, 256
+	jae	.LBB8_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB8_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 16
+	jne	.LBB8_22
+	cmp	rcx, rax
+	jne	.LBB8_21
+.LBB8_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB8_27
+.LBB8_11:
+	mov	rdi, qword ptr [rbx + 16]
+	mov	rsi, qword ptr [rbx + 24]
+	xorps	xmm0, xmm0
+	lea	rdx, [rsp + 32]
+	movaps	xmmword ptr [rdx + 16], xmm0
+	movaps	xmmword ptr [rdx], xmm0
+	mov	qword ptr [rdx + 32], 0
+	call	optional_trait_methods::num::format_decimal::<i128>
+.LBB8_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB8_27
+.LBB8_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI8_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB8_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB8_4
+.LBB8_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB8_4
+.LBB8_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB8_27
+.LBB8_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB8_12
+.LBB8_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB8_4:
+	mov	edx, 4
+.LBB8_5:
+	mov	rdi, r15
+.LBB8_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB8_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 160
+	mov	rdi, rbp
+	mov	rsi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB8_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB8_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end8:
+.LJTI8_0:
+	.long	.LBB8_10-.LJTI8_0
+	.long	.LBB8_11-.LJTI8_0
+	.long	.LBB8_13-.LJTI8_0
+	.long	.LBB8_19-.LJTI8_0
+.LJTI8_1:
+	.long	.LBB8_3-.LJTI8_1
+	.long	.LBB8_6-.LJTI8_1
+	.long	.LBB8_7-.LJTI8_1
+	.long	.LBB8_8-.LJTI8_1
+	.long	.LBB8_9-.LJTI8_1
+
+__rustc::rust_begin_unwind:
+.Lfunc_begin9:
+	push	rax
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.30]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	call	qword ptr [rip + abort@GOTPCREL]
+.Lfunc_end9:
+
+optional_trait_methods::report_port:
+.Lfunc_begin10:
+	push	rbx
+	sub	rsp, 64
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 16]
+	movaps	xmmword ptr [rsi], xmm0
+	lea	rdx, [rsp + 12]
+	mov	dword ptr [rdx], 16
+	call	qword ptr [rip + getsockname@GOTPCREL]
+	test	eax, eax
+	js	.LBB10_1
+	xor	eax, eax
+	test	al, al
+	jne	.LBB10_7
+	movzx	ebx, word ptr [rsp + 18]
+	rol	bx, 8
+	shl	ebx, 16
+	shr	ebx, 16
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 48], 0
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.31]
+	mov	edx, 23
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	ecx, 39
+.LBB10_4:
+	cmp	rcx, 39
+	ja	.LBB10_8
+	mov	rax, rcx
+	movzx	edx, bx
+	imul	esi, edx, 52429
+	shr	esi, 19
+	lea	ecx, [rsi + rsi]
+	lea	ecx, [rcx + 4*rcx]
+	sub	ebx, ecx
+	or	bl, 48
+	mov	byte ptr [rsp + rax + 16], bl
+	lea	rcx, [rax - 1]
+	mov	ebx, esi
+	cmp	dx, 10
+	jae	.LBB10_4
+	lea	rsi, [rsp + rax]
+	add	rsi, 16
+	inc	rax
+	mov	edx, 41
+	sub	rdx, rax
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB10_7
+.LBB10_1:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB10_7:
+	add	rsp, 64
+	pop	rbx
+	ret
+.LBB10_8:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end10:
+
+optional_trait_methods::usage_error:
+.Lfunc_begin11:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.32]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	rax, qword ptr [rbx]
+	lea	rcx, [rip + .LJTI11_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB11_1:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.11]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 16
+	jmp	.LBB11_2
+.LBB11_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.16]
+	mov	edx, 39
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	jmp	.LBB11_7
+.LBB11_4:
+	mov	r14, qword ptr [rbx + 8]
+	mov	r15, qword ptr [rbx + 16]
+	mov	r12, qword ptr [rbx + 24]
+	mov	rbx, qword ptr [rbx + 32]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.13]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, r15
+	call	r13
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+	mov	edx, 2
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r12
+	mov	rdx, rbx
+	call	r13
+	jmp	.LBB11_7
+.LBB11_5:
+	mov	rsi, qword ptr [rbx + 8]
+	mov	rdx, qword ptr [rbx + 16]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.15]
+	mov	edx, 21
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB11_7
+.LBB11_3:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.12]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+.LBB11_2:
+	mov	edi, 2
+	call	r15
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, rbx
+	call	r15
+.LBB11_7:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 2
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	mov	edx, 823
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	mov	rax, rbx
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	jmp	rax
+.Lfunc_end11:
+.LJTI11_0:
+	.long	.LBB11_1-.LJTI11_0
+	.long	.LBB11_3-.LJTI11_0
+	.long	.LBB11_4-.LJTI11_0
+	.long	.LBB11_5-.LJTI11_0
+	.long	.LBB11_6-.LJTI11_0
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin12:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB12_1
+	xor	eax, eax
+	mov	qword ptr [r14 + 1024], rax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	cmovae	rdx, rax
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB12_3
+	mov	edi, 1
+	mov	rsi, r15
+	mov	rdx, rbx
+	xor	ecx, ecx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	jmp	optional_trait_methods::connection::write_fd
+.LBB12_1:
+	cmp	rax, rdx
+	jb	.LBB12_4
+.LBB12_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB12_4:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end12:
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin13:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB13_1
+	xor	eax, eax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	mov	qword ptr [r14 + 1024], rax
+	cmovae	rdx, rax
+	mov	ebp, dword ptr [r14 + 1032]
+	mov	edi, ebp
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB13_3
+	mov	edi, ebp
+	mov	rsi, r15
+	mov	rdx, rbx
+	mov	ecx, 1
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	optional_trait_methods::connection::write_fd
+.LBB13_1:
+	cmp	rax, rdx
+	jb	.LBB13_4
+.LBB13_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB13_4:
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end13:
+
+<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget> as optional_trait_methods::state_machine::Controller>::parse_command:
+.Lfunc_begin14:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	sub	rsp, 336
+	mov	rbx, rdi
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.35]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 22
+	#APP
+	#NO_APP
+	lea	rax, [rsi + 1]
+	movzx	ecx, byte ptr [rsi]
+	cmp	ecx, 45
+	je	.LBB14_4
+	cmp	ecx, 43
+	jne	.LBB14_9
+	cmp	rdx, 1
+	jne	.LBB14_6
+	mov	byte ptr [rbx + 1], 0
+	jmp	.LBB14_31
+.LBB14_4:
+	cmp	rdx, 1
+	jne	.LBB14_6
+	mov	byte ptr [rbx + 1], 1
+	jmp	.LBB14_31
+.LBB14_6:
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_10
+.LBB14_7:
+	mov	qword ptr [rbx + 8], 1
+.LBB14_8:
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+	jmp	.LBB14_32
+.LBB14_9:
+	cmp	rdx, 2
+	jb	.LBB14_11
+.LBB14_10:
+	cmp	word ptr [rsi], 11563
+	je	.LBB14_29
+.LBB14_11:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.39]
+	mov	r8, rsp
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	ecx, 42
+	jne	.LBB14_17
+	mov	rdi, rdx
+	dec	rdi
+	je	.LBB14_14
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_17
+.LBB14_14:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rdi
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB14_106
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB14_98
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 8
+	jmp	.LBB14_28
+.LBB14_17:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.41]
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 2
+	jb	.LBB14_19
+	cmp	word ptr [rsi], 32298
+	je	.LBB14_45
+.LBB14_19:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.43]
+	mov	r8, rsp
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 3
+	jb	.LBB14_21
+	movzx	edi, word ptr [rsi]
+	xor	edi, 32298
+	movzx	r8d, byte ptr [rsi + 2]
+	xor	r8d, 126
+	or	r8w, di
+	je	.LBB14_52
+.LBB14_21:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.45]
+	mov	r8, rsp
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	ecx, 37
+	je	.LBB14_33
+	cmp	ecx, 47
+	jne	.LBB14_38
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_25
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_58
+.LBB14_25:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB14_106
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB14_98
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 9
+.LBB14_28:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rbx + 16], xmm0
+	jmp	.LBB14_51
+.LBB14_29:
+	cmp	rdx, 2
+	jne	.LBB14_43
+	mov	byte ptr [rbx + 1], 2
+.LBB14_31:
+	mov	al, 7
+.LBB14_32:
+	mov	byte ptr [rbx], al
+	jmp	.LBB14_109
+.LBB14_33:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_35
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_58
+.LBB14_35:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB14_106
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB14_98
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 9
+	jmp	.LBB14_50
+.LBB14_38:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 20
+	#APP
+	#NO_APP
+	cmp	ecx, 111
+	jle	.LBB14_61
+	lea	edi, [rcx - 112]
+	cmp	edi, 7
+	ja	.LBB14_59
+	lea	r8, [rip + .LJTI14_0]
+	movsxd	rdi, dword ptr [r8 + 4*rdi]
+	add	rdi, r8
+	jmp	rdi
+.LBB14_41:
+	cmp	rdx, 1
+	jne	.LBB14_90
+	xor	eax, eax
+	jmp	.LBB14_92
+.LBB14_43:
+	cmp	byte ptr [rsi + 2], 32
+	jne	.LBB14_11
+	mov	qword ptr [rbx + 8], 2
+	jmp	.LBB14_8
+.LBB14_45:
+	lea	rdi, [rsi + 2]
+	mov	r8, rdx
+	sub	r8, 2
+	je	.LBB14_47
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_19
+.LBB14_47:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 2
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB14_106
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB14_98
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 8
+.LBB14_50:
+	mov	qword ptr [rbx + 24], 0
+	mov	qword ptr [rbx + 16], 1
+.LBB14_51:
+	mov	qword ptr [rbx + 32], rax
+	mov	qword ptr [rbx + 40], rcx
+	jmp	.LBB14_109
+.LBB14_52:
+	lea	rdi, [rsi + 3]
+	mov	r8, rdx
+	sub	r8, 3
+	je	.LBB14_54
+	cmp	byte ptr [rdi], 32
+	jne	.LBB14_21
+.LBB14_54:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 3
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB14_106
+	mov	r14, rsp
+	mov	r15, qword ptr [r14 + 16]
+	mov	r12, qword ptr [r14 + 24]
+	lea	rsi, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB14_106
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB14_98
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 8
+	mov	qword ptr [rbx + 24], 0
+	mov	qword ptr [rbx + 16], 2
+	mov	qword ptr [rbx + 32], r15
+	mov	qword ptr [rbx + 40], r12
+	mov	qword ptr [rbx + 48], rax
+	mov	qword ptr [rbx + 56], rcx
+	jmp	.LBB14_109
+.LBB14_58:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 20
+	#APP
+	#NO_APP
+.LBB14_59:
+	mov	rdi, rsi
+	mov	rsi, rdx
+	call	optional_trait_methods::commands::unrecognized
+	mov	qword ptr [rbx + 8], 0
+.LBB14_60:
+	mov	byte ptr [rbx + 16], al
+	jmp	.LBB14_108
+.LBB14_61:
+	cmp	ecx, 63
+	je	.LBB14_86
+	cmp	ecx, 71
+	je	.LBB14_89
+	cmp	ecx, 103
+	jne	.LBB14_59
+	cmp	rdx, 1
+	jne	.LBB14_93
+	mov	al, 5
+	jmp	.LBB14_32
+.LBB14_66:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_68
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_59
+.LBB14_68:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num_or::<usize>
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_107
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_118
+	mov	byte ptr [rbx], 10
+	jmp	.LBB14_71
+.LBB14_72:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_74
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_59
+.LBB14_74:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB14_106
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB14_98
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 1
+	jmp	.LBB14_120
+.LBB14_77:
+	mov	rdi, rdx
+	dec	rdi
+	je	.LBB14_79
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_100
+.LBB14_79:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rdi
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB14_107
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB14_118
+	mov	byte ptr [rbx], 3
+.LBB14_71:
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB14_109
+.LBB14_82:
+	mov	rdi, rdx
+	dec	rdi
+	je	.LBB14_84
+.LBB14_83:
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_94
+.LBB14_84:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rdi
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	je	.LBB14_96
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB14_60
+.LBB14_86:
+	mov	rcx, rdx
+	dec	rcx
+	jne	.LBB14_113
+	mov	qword ptr [rsp], rax
+	mov	qword ptr [rsp + 8], rcx
+	mov	qword ptr [rsp + 16], 1
+	xor	ebp, ebp
+.LBB14_88:
+	mov	byte ptr [rbx], 2
+	mov	byte ptr [rbx + 1], bpl
+	mov	byte ptr [rbx + 2], al
+	jmp	.LBB14_109
+.LBB14_89:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB14_105
+	jmp	.LBB14_104
+.LBB14_90:
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_59
+	mov	qword ptr [rbx + 8], 1
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+.LBB14_92:
+	mov	byte ptr [rbx], al
+	jmp	.LBB14_109
+.LBB14_93:
+	cmp	byte ptr [rax], 32
+	je	.LBB14_7
+.LBB14_94:
+	cmp	ecx, 63
+	je	.LBB14_112
+	cmp	ecx, 71
+	jne	.LBB14_59
+	jmp	.LBB14_103
+.LBB14_96:
+	mov	r14, rsp
+	lea	rsi, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB14_106
+	cmp	qword ptr [rsp + 176], 0
+	je	.LBB14_119
+.LBB14_98:
+	mov	rax, qword ptr [rsp + 184]
+.LBB14_99:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], 3
+	jmp	.LBB14_108
+.LBB14_100:
+	mov	r8d, ecx
+	add	r8b, -63
+	je	.LBB14_112
+	rol	r8b, 5
+	movzx	r8d, r8b
+	cmp	r8d, 1
+	je	.LBB14_103
+	cmp	r8d, 7
+	jne	.LBB14_59
+	jmp	.LBB14_83
+.LBB14_103:
+	lea	rcx, [rdx - 1]
+.LBB14_104:
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_59
+.LBB14_105:
+	lea	rsi, [rsp + 312]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::regs::<i128>
+	cmp	byte ptr [r14], 0
+	je	.LBB14_110
+.LBB14_106:
+	mov	rax, qword ptr [rsp + 8]
+	mov	cl, byte ptr [rsp + 16]
+.LBB14_107:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], cl
+.LBB14_108:
+	mov	byte ptr [rbx], -1
+.LBB14_109:
+	add	rsp, 336
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB14_110:
+	lea	rsi, [rsp + 17]
+	mov	bpl, byte ptr [rsi - 1]
+	lea	rdi, [rsp + 168]
+	mov	edx, 143
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	cmp	qword ptr [rsp + 320], 0
+	je	.LBB14_116
+	mov	rax, qword ptr [rsp + 328]
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB14_8
+.LBB14_112:
+	lea	rcx, [rdx - 1]
+.LBB14_113:
+	cmp	byte ptr [rax], 32
+	jne	.LBB14_59
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	test	rcx, rcx
+	setne	bpl
+	lea	r14, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::names
+	mov	al, byte ptr [r14 + 8]
+	cmp	al, -1
+	je	.LBB14_117
+	mov	rcx, qword ptr [rsp + 168]
+	mov	qword ptr [rbx + 8], rcx
+	jmp	.LBB14_60
+.LBB14_116:
+	mov	byte ptr [rbx + 16], bpl
+	lea	rdi, [rbx + 17]
+	lea	rsi, [rsp + 168]
+	mov	edx, 143
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	al, 6
+	jmp	.LBB14_32
+.LBB14_117:
+	cmp	qword ptr [rsp + 8], 0
+	je	.LBB14_121
+.LBB14_118:
+	mov	rax, qword ptr [rsp + 16]
+	jmp	.LBB14_99
+.LBB14_119:
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 4
+	mov	qword ptr [rbx + 8], r15
+.LBB14_120:
+	mov	qword ptr [rbx + 16], rax
+	mov	qword ptr [rbx + 24], rcx
+	jmp	.LBB14_109
+.LBB14_121:
+	mov	al, byte ptr [rsp + 168]
+	jmp	.LBB14_88
+.Lfunc_end14:
+.LJTI14_0:
+	.long	.LBB14_41-.LJTI14_0
+	.long	.LBB14_59-.LJTI14_0
+	.long	.LBB14_77-.LJTI14_0
+	.long	.LBB14_72-.LJTI14_0
+	.long	.LBB14_59-.LJTI14_0
+	.long	.LBB14_66-.LJTI14_0
+	.long	.LBB14_59-.LJTI14_0
+	.long	.LBB14_82-.LJTI14_0
+
+.LCPI15_0:
+	.long	2
+	.long	0
+	.long	0
+	.long	0
+main:
+.Lfunc_begin15:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 4096
+	mov	qword ptr [rsp], 0
+	sub	rsp, 344
+	cmp	rdi, 2
+	jl	.LBB15_104
+	mov	rbx, rsi
+	mov	ebp, 1
+	lea	r12, [rsp + 1856]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 40], 0
+	mov	qword ptr [rsp + 568], 0
+	mov	qword ptr [rsp + 376], 0
+	mov	qword ptr [rsp + 384], 0
+	mov	qword ptr [rsp + 584], 0
+	mov	qword ptr [rsp + 576], 0
+	mov	qword ptr [rsp + 24], rsi
+	mov	qword ptr [rsp + 32], rdi
+.LBB15_2:
+	mov	r13, qword ptr [rbx + 8*rbp]
+	mov	rdi, r13
+	call	rcx
+	mov	rbx, rax
+	test	rax, rax
+	je	.LBB15_7
+	xor	r15d, r15d
+.LBB15_4:
+	cmp	byte ptr [r13 + r15], 61
+	je	.LBB15_9
+	inc	r15
+	cmp	rbx, r15
+	jne	.LBB15_4
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, rbx
+	jmp	.LBB15_8
+.LBB15_7:
+	mov	qword ptr [rsp + 16], 0
+	xor	r15d, r15d
+.LBB15_8:
+	jmp	.LBB15_10
+.LBB15_9:
+	lea	rax, [r15 + 1]
+	mov	rcx, rbx
+	sub	rcx, rax
+	mov	qword ptr [rsp + 616], rcx
+	lea	rax, [r15 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 16], rax
+.LBB15_10:
+	mov	qword ptr [rsp + 392], rbp
+	lea	rax, [rbp + 1]
+	mov	qword ptr [rsp], rax
+	mov	qword ptr [rsp + 1840], 0
+	mov	qword ptr [rsp + 1848], 7
+	mov	edx, 168
+	mov	rdi, r12
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	call	r14
+	mov	r14d, 32
+.LBB15_11:
+	cmp	qword ptr [rsp + r14 + 1832], r15
+	jne	.LBB15_14
+	movzx	r12d, byte ptr [rsp + r14 + 1840]
+	mov	rbp, qword ptr [rsp + r14 + 1824]
+	mov	rdi, rbp
+	mov	rsi, r13
+	mov	rdx, r15
+	call	qword ptr [rip + bcmp@GOTPCREL]
+	test	eax, eax
+	jne	.LBB15_14
+	cmp	r12d, 255
+	jne	.LBB15_15
+.LBB15_14:
+	add	r14, 24
+	cmp	r14, 200
+	jne	.LBB15_11
+	jmp	.LBB15_212
+.LBB15_15:
+	cmp	r12, 255
+	je	.LBB15_212
+	cmp	r12b, 4
+	jae	.LBB15_19
+	mov	r11, qword ptr [rsp + 16]
+	test	r11, r11
+	mov	rbx, qword ptr [rsp + 24]
+	mov	r13, qword ptr [rsp + 32]
+	je	.LBB15_21
+	mov	r9, qword ptr [rsp]
+	mov	r10, qword ptr [rsp + 616]
+	jmp	.LBB15_24
+.LBB15_19:
+	cmp	qword ptr [rsp + 16], 0
+	mov	rbx, qword ptr [rsp + 24]
+	mov	r13, qword ptr [rsp + 32]
+	jne	.LBB15_221
+	mov	r11d, 1
+	mov	r9, qword ptr [rsp]
+	xor	r10d, r10d
+	jmp	.LBB15_24
+.LBB15_21:
+	cmp	qword ptr [rsp], r13
+	mov	r14, qword ptr [rsp + 392]
+	jge	.LBB15_222
+	mov	rdi, qword ptr [rbx + 8*r14 + 8]
+	test	rdi, rdi
+	je	.LBB15_222
+	mov	qword ptr [rsp + 16], rdi
+	call	qword ptr [rip + strlen@GOTPCREL]
+	mov	r11, qword ptr [rsp + 16]
+	mov	r10, rax
+	add	r14, 2
+	mov	r9, r14
+.LBB15_24:
+	cmp	r12b, 5
+	jne	.LBB15_26
+	mov	al, 1
+	mov	qword ptr [rsp + 584], rax
+	mov	rbp, r9
+	cmp	r9, r13
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 1856]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB15_2
+	jmp	.LBB15_224
+.LBB15_26:
+	movzx	eax, r12b
+	mov	eax, eax
+	lea	rcx, [rip + .LJTI15_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB15_27:
+	test	r10, r10
+	je	.LBB15_239
+	mov	qword ptr [rsp + 592], r11
+	mov	qword ptr [rsp + 600], r10
+	mov	dword ptr [rsp + 12], eax
+	cmp	word ptr [rsp + 40], 0
+	mov	ax, 1
+	jmp	.LBB15_77
+.LBB15_29:
+	mov	al, 1
+	mov	qword ptr [rsp + 384], rax
+	jmp	.LBB15_51
+.LBB15_30:
+	mov	qword ptr [rsp + 608], r10
+	mov	qword ptr [rsp + 568], r11
+	jmp	.LBB15_51
+.LBB15_31:
+	cmp	r10, 8
+	je	.LBB15_42
+	cmp	r10, 4
+	jne	.LBB15_240
+	mov	eax, 4
+	cmp	byte ptr [r11], 101
+	jne	.LBB15_241
+	cmp	byte ptr [r11 + 1], 120
+	jne	.LBB15_241
+	cmp	byte ptr [r11 + 2], 105
+	jne	.LBB15_241
+	cmp	byte ptr [r11 + 3], 116
+	jne	.LBB15_241
+	jmp	.LBB15_50
+.LBB15_37:
+	test	r10, r10
+	je	.LBB15_238
+	lea	rcx, [r11 + 1]
+	lea	rdi, [r10 - 1]
+	movzx	eax, byte ptr [r11]
+	cmp	eax, 43
+	je	.LBB15_52
+	cmp	eax, 45
+	jne	.LBB15_53
+	mov	sil, 1
+	jmp	.LBB15_54
+.LBB15_41:
+	mov	al, 1
+	mov	qword ptr [rsp + 576], rax
+	jmp	.LBB15_51
+.LBB15_42:
+	mov	eax, 8
+	cmp	byte ptr [r11], 99
+	jne	.LBB15_241
+	cmp	byte ptr [r11 + 1], 111
+	jne	.LBB15_241
+	cmp	byte ptr [r11 + 2], 110
+	jne	.LBB15_241
+	cmp	byte ptr [r11 + 3], 116
+	jne	.LBB15_241
+	cmp	byte ptr [r11 + 4], 105
+	jne	.LBB15_241
+	cmp	byte ptr [r11 + 5], 110
+	jne	.LBB15_241
+	cmp	byte ptr [r11 + 6], 117
+	jne	.LBB15_241
+	cmp	byte ptr [r11 + 7], 101
+	jne	.LBB15_241
+.LBB15_50:
+	cmp	r10, 8
+	setne	al
+	mov	qword ptr [rsp + 376], rax
+.LBB15_51:
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 1856]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	rbp, r9
+	jmp	.LBB15_78
+.LBB15_52:
+	xor	esi, esi
+	jmp	.LBB15_54
+.LBB15_53:
+	xor	esi, esi
+	mov	rdi, r10
+	mov	rcx, r11
+.LBB15_54:
+	cmp	rdi, 1
+	jbe	.LBB15_61
+	mov	al, byte ptr [rcx]
+	cmp	al, 48
+	jne	.LBB15_63
+	movzx	eax, byte ptr [rcx + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB15_64
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB15_80
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB15_79
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB15_64
+	mov	r8b, 16
+	mov	r12b, 1
+	jmp	.LBB15_82
+.LBB15_61:
+	test	rdi, rdi
+	je	.LBB15_253
+	mov	al, byte ptr [rcx]
+.LBB15_63:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB15_253
+.LBB15_64:
+	add	rdi, rcx
+	mov	rax, rdi
+	dec	rax
+	je	.LBB15_66
+	cmp	byte ptr [rax], 95
+	je	.LBB15_253
+.LBB15_66:
+	xor	r14d, r14d
+	xor	eax, eax
+.LBB15_67:
+	cmp	rcx, rdi
+	je	.LBB15_75
+	mov	r8b, byte ptr [rcx]
+	inc	rcx
+	cmp	r8b, 95
+	jne	.LBB15_70
+	test	r14b, 1
+	mov	r14d, 0
+	je	.LBB15_253
+	jmp	.LBB15_67
+.LBB15_70:
+	add	r8b, -48
+	cmp	r8b, 9
+	ja	.LBB15_253
+	mov	dx, 10
+	mul	dx
+	jo	.LBB15_253
+	movzx	edx, r8b
+	mov	r14b, 1
+	test	sil, sil
+	je	.LBB15_74
+	sub	ax, dx
+	jb	.LBB15_253
+	jmp	.LBB15_67
+.LBB15_74:
+	add	ax, dx
+	jb	.LBB15_253
+	jmp	.LBB15_67
+.LBB15_75:
+	mov	dword ptr [rsp + 12], eax
+.LBB15_76:
+	cmp	word ptr [rsp + 40], 0
+	mov	ax, 2
+.LBB15_77:
+	mov	qword ptr [rsp + 40], rax
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 1856]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	rbp, r9
+	jne	.LBB15_234
+.LBB15_78:
+	cmp	rbp, r13
+	jl	.LBB15_2
+	jmp	.LBB15_223
+.LBB15_79:
+	mov	r8b, 8
+	jmp	.LBB15_81
+.LBB15_80:
+	mov	r8b, 2
+.LBB15_81:
+	xor	r12d, r12d
+.LBB15_82:
+	mov	r14, r10
+	cmp	rdi, 2
+	je	.LBB15_252
+	lea	r10, [rcx + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jb	.LBB15_87
+	test	r12b, r12b
+	je	.LBB15_252
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB15_252
+	add	al, -87
+	mov	edx, eax
+.LBB15_87:
+	cmp	dl, r8b
+	jae	.LBB15_252
+	add	rcx, rdi
+	mov	rax, rcx
+	dec	rax
+	je	.LBB15_90
+	cmp	byte ptr [rax], 95
+	je	.LBB15_252
+.LBB15_90:
+	movzx	edi, r8b
+	xor	eax, eax
+	mov	dword ptr [rsp + 12], 0
+.LBB15_91:
+	cmp	r10, rcx
+	je	.LBB15_76
+	mov	dl, byte ptr [r10]
+	inc	r10
+	cmp	dl, 95
+	jne	.LBB15_94
+	test	al, 1
+	mov	eax, 0
+	je	.LBB15_252
+	jmp	.LBB15_91
+.LBB15_94:
+	mov	qword ptr [rsp + 16], r11
+	lea	r11d, [rdx - 48]
+	cmp	r11b, 10
+	jb	.LBB15_98
+	test	r12b, r12b
+	je	.LBB15_251
+	or	dl, 32
+	lea	eax, [rdx - 97]
+	cmp	al, 5
+	ja	.LBB15_251
+	add	dl, -87
+	mov	r11d, edx
+.LBB15_98:
+	cmp	r11b, r8b
+	jae	.LBB15_251
+	mov	eax, dword ptr [rsp + 12]
+	mul	di
+	mov	dword ptr [rsp + 12], eax
+	jo	.LBB15_251
+	movzx	edx, r11b
+	mov	al, 1
+	test	sil, sil
+	je	.LBB15_102
+	mov	r11d, dword ptr [rsp + 12]
+	sub	r11w, dx
+	jmp	.LBB15_103
+.LBB15_102:
+	mov	r11d, dword ptr [rsp + 12]
+	add	r11w, dx
+.LBB15_103:
+	mov	dword ptr [rsp + 12], r11d
+	mov	r11, qword ptr [rsp + 16]
+	jb	.LBB15_252
+	jmp	.LBB15_91
+.LBB15_104:
+	xor	r15d, r15d
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB15_105:
+	pxor	xmm0, xmm0
+	lea	r14, [rsp + 944]
+	movdqa	xmmword ptr [r14 + 672], xmm0
+	movdqa	xmmword ptr [r14 + 656], xmm0
+	movdqa	xmmword ptr [r14 + 640], xmm0
+	movdqa	xmmword ptr [r14 + 624], xmm0
+	movdqa	xmmword ptr [r14 + 608], xmm0
+	movdqa	xmmword ptr [r14 + 592], xmm0
+	movdqa	xmmword ptr [r14 + 576], xmm0
+	lea	rdi, [rsp + 1872]
+	xor	ebx, ebx
+	mov	r13, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 528
+	xor	esi, esi
+	call	r13
+	mov	qword ptr [r14 + 568], r12
+	mov	qword ptr [r14 + 560], r15
+	pxor	xmm0, xmm0
+	movdqu	xmmword ptr [r14 + 696], xmm0
+	mov	qword ptr [r14 + 688], 1
+	movabs	rax, -9223372036854775808
+	mov	qword ptr [r14 + 712], rax
+	movabs	rax, 9223372036854775807
+	mov	qword ptr [r14 + 728], rax
+	mov	qword ptr [r14 + 720], -1
+	movdqa	xmmword ptr [r14 - 16], xmm0
+	lea	r15, [rsp + 1840]
+	mov	edx, 560
+	mov	rdi, r14
+	mov	rsi, r15
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 2880]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 1840]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 3928]
+	mov	edx, 512
+	xor	esi, esi
+	call	r13
+	mov	dword ptr [rsp + 392], ebp
+	shr	ebp, 8
+	mov	dword ptr [rsp + 16], ebp
+	mov	r13, qword ptr [rip + read@GOTPCREL]
+	xor	ecx, ecx
+	mov	dword ptr [rsp], eax
+.LBB15_106:
+	mov	rdi, qword ptr [rsp + 2864]
+	mov	rax, qword ptr [rsp + 2872]
+	xor	r12d, r12d
+	mov	rdx, rcx
+	xor	ebp, ebp
+.LBB15_107:
+	mov	rsi, rax
+	mov	rax, rdi
+.LBB15_108:
+	cmp	rsi, rax
+	jb	.LBB15_112
+	mov	qword ptr [rsp + 3904], rbx
+	cmp	rdx, 1025
+	mov	rsi, r14
+	mov	eax, 1
+	cmovae	rsi, rax
+	cmovae	rdx, rbx
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 1024
+	xor	edi, edi
+	mov	rsi, r15
+	call	r13
+	test	rax, rax
+	js	.LBB15_122
+	je	.LBB15_123
+	mov	qword ptr [rsp + 2864], rax
+	mov	qword ptr [rsp + 2872], 0
+	xor	ecx, ecx
+	xor	edx, edx
+	xor	esi, esi
+.LBB15_112:
+	cmp	rax, rsi
+	jb	.LBB15_216
+	cmp	rax, 1024
+	ja	.LBB15_216
+	cmp	rsi, rax
+	je	.LBB15_108
+	inc	rsi
+	mov	rdi, rax
+	neg	rdi
+.LBB15_116:
+	mov	r8b, byte ptr [rsp + rsi + 1839]
+	mov	qword ptr [rsp + 2872], rsi
+	cmp	r8b, 10
+	je	.LBB15_126
+	cmp	rbp, 511
+	jbe	.LBB15_119
+	mov	r12b, 1
+	jmp	.LBB15_120
+.LBB15_119:
+	mov	byte ptr [rsp + rbp + 3928], r8b
+	inc	rbp
+.LBB15_120:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB15_116
+	mov	rdi, rax
+	jmp	.LBB15_107
+.LBB15_122:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB15_123:
+	test	rbp, rbp
+	setne	al
+	or	al, r12b
+	test	al, 1
+	je	.LBB15_220
+	test	r12b, 1
+	jne	.LBB15_130
+	mov	ecx, 0
+	mov	edx, 0
+	cmp	rbp, 512
+	ja	.LBB15_217
+	jmp	.LBB15_128
+.LBB15_126:
+	test	r12b, 1
+	jne	.LBB15_130
+	cmp	rbp, 512
+	ja	.LBB15_216
+.LBB15_128:
+	test	rbp, rbp
+	setne	al
+	test	byte ptr [rsp + 16], al
+	jne	.LBB15_131
+	test	rbp, rbp
+	je	.LBB15_106
+	jmp	.LBB15_132
+.LBB15_130:
+	mov	qword ptr [rsp + 216], 3
+	mov	qword ptr [rsp + 224], 512
+	mov	byte ptr [rsp + 232], 4
+	mov	byte ptr [rsp + 208], -1
+	lea	rdi, [rsp + 768]
+	mov	rsi, r14
+	lea	rdx, [rsp + 208]
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	mov	r12d, dword ptr [rsp]
+	mov	rbp, qword ptr [rsp + 24]
+	jmp	.LBB15_207
+.LBB15_131:
+	mov	rdi, r14
+	lea	rsi, [rsp + 3928]
+	mov	rdx, rbp
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB15_132:
+	lea	r12, [rsp + 1680]
+	mov	rdi, r12
+	lea	rsi, [rsp + 3928]
+	mov	rdx, rbp
+	call	<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget> as optional_trait_methods::state_machine::Controller>::parse_command
+	cmp	byte ptr [rsp + 1680], -1
+	je	.LBB15_137
+	mov	edx, 160
+	lea	rdi, [rsp + 400]
+	mov	rsi, r12
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	movzx	r12d, byte ptr [rsp + 400]
+	lea	rbp, [r12 - 6]
+	cmp	r12, 7
+	cmovb	rbp, rbx
+	lea	rcx, [rip + .LJTI15_5]
+	movsxd	rax, dword ptr [rcx + 4*rbp]
+	add	rax, rcx
+	jmp	rax
+.LBB15_134:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 15
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+	lea	rcx, [rip + .LJTI15_6]
+	movsxd	rax, dword ptr [rcx + 4*rbp]
+	add	rax, rcx
+	jmp	rax
+.LBB15_135:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI15_7]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB15_136:
+	mov	rbp, qword ptr [rsp + 1504]
+	mov	rdx, qword ptr [rsp + 1512]
+	jmp	.LBB15_189
+.LBB15_137:
+	mov	eax, dword ptr [rsp + 1696]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rbp, qword ptr [rsp + 24]
+	cmovne	rbp, qword ptr [rsp + 1688]
+	sete	cl
+	mov	r12d, dword ptr [rsp]
+	movzx	r12d, r12b
+	cmovne	r12d, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 216], rcx
+	mov	qword ptr [rsp + 224], rbp
+	mov	byte ptr [rsp + 232], r12b
+	mov	byte ptr [rsp + 208], -1
+	jmp	.LBB15_206
+.LBB15_138:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 14
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 408]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::undo
+	test	rax, rax
+	mov	rbp, qword ptr [rsp + 24]
+	je	.LBB15_184
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 224], rax
+	mov	qword ptr [rsp + 232], rdx
+	jmp	.LBB15_145
+.LBB15_140:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI15_8]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB15_141:
+	movdqa	xmm0, xmmword ptr [rsp + 1504]
+	mov	byte ptr [rsp + 208], 1
+	movdqa	xmmword ptr [rsp + 224], xmm0
+	jmp	.LBB15_177
+.LBB15_142:
+	movdqa	xmm0, xmmword ptr [rsp + 416]
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI15_0]
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	jne	.LBB15_147
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 21
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	mov	r8, qword ptr [rsp + 456]
+	mov	rcx, qword ptr [rsp + 448]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds
+	test	rax, rax
+	mov	rbp, qword ptr [rsp + 24]
+	je	.LBB15_184
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 224], rax
+	mov	qword ptr [rsp + 232], 26
+.LBB15_145:
+	mov	byte ptr [rsp + 208], -1
+	jmp	.LBB15_185
+.LBB15_146:
+	mov	r12, qword ptr [rsp + 416]
+	jmp	.LBB15_148
+.LBB15_147:
+	mov	r12, qword ptr [rsp + 416]
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 15
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+.LBB15_148:
+	test	r12, r12
+	je	.LBB15_156
+	cmp	r12, 1
+	lea	rax, [rsp + 48]
+	jne	.LBB15_157
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	qword ptr [rsp + 48], rcx
+	mov	qword ptr [rsp + 56], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_factor
+	jmp	.LBB15_201
+.LBB15_151:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 13
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	test	byte ptr [rsp + 416], 1
+	je	.LBB15_158
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rem
+	jmp	.LBB15_159
+.LBB15_153:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 16
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [rsp + 401]
+	test	eax, eax
+	je	.LBB15_164
+	cmp	eax, 1
+	jne	.LBB15_165
+	lea	rdi, [rsp + 928]
+	jmp	.LBB15_166
+.LBB15_156:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 13
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::mul
+	jmp	.LBB15_159
+.LBB15_157:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 48], rcx
+	mov	qword ptr [rsp + 56], 21
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	mov	r8, qword ptr [rsp + 456]
+	mov	rcx, qword ptr [rsp + 448]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds
+	jmp	.LBB15_159
+.LBB15_158:
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::div
+.LBB15_159:
+	test	rax, rax
+	mov	r12d, dword ptr [rsp]
+	je	.LBB15_162
+	mov	rbp, rax
+	mov	qword ptr [rsp + 32], rdx
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rollback
+	test	rax, rax
+	je	.LBB15_163
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 224], rax
+	mov	qword ptr [rsp + 232], 21
+	mov	byte ptr [rsp + 208], -1
+	jmp	.LBB15_205
+.LBB15_162:
+	xor	eax, eax
+	jmp	.LBB15_204
+.LBB15_163:
+	mov	al, -1
+	mov	rdx, qword ptr [rsp + 32]
+	jmp	.LBB15_204
+.LBB15_164:
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
+	jmp	.LBB15_201
+.LBB15_165:
+	lea	r12, [rsp + 928]
+	mov	rdi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
+	mov	rdi, r12
+.LBB15_166:
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::dec
+	jmp	.LBB15_201
+.LBB15_167:
+	mov	rcx, qword ptr [rsp + 424]
+	mov	rsi, qword ptr [rsp + 408]
+	mov	rdx, qword ptr [rsp + 416]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	jmp	.LBB15_183
+.LBB15_168:
+	mov	al, byte ptr [rsp + 402]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [rsp + 401], 0
+	mov	ecx, 63
+	cmove	eax, ecx
+	mov	byte ptr [rsp + 208], 2
+	mov	byte ptr [rsp + 209], al
+	jmp	.LBB15_177
+.LBB15_169:
+	mov	rsi, qword ptr [rsp + 408]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	mov	byte ptr [rsp + 208], 1
+	mov	qword ptr [rsp + 232], rdx
+	mov	qword ptr [rsp + 224], rax
+	jmp	.LBB15_177
+.LBB15_170:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 15
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+.LBB15_171:
+	mov	rsi, qword ptr [rsp + 416]
+	mov	rdx, qword ptr [rsp + 424]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::set_state
+	jmp	.LBB15_201
+.LBB15_172:
+	mov	edx, 136
+	lea	rdi, [rsp + 624]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB15_173:
+	lea	rdi, [rsp + 928]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r12, 7
+	ja	.LBB15_175
+	shl	r12, 4
+	mov	qword ptr [rsp + r12 + 624], rax
+	mov	qword ptr [rsp + r12 + 632], rdx
+	mov	r12, qword ptr [rsp + 752]
+	inc	r12
+	mov	qword ptr [rsp + 752], r12
+.LBB15_175:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB15_173
+	mov	edx, 144
+	lea	rdi, [rsp + 63]
+	lea	rsi, [rsp + 624]
+	mov	r12, qword ptr [rip + memcpy@GOTPCREL]
+	call	r12
+	mov	byte ptr [rsp + 208], 3
+	mov	edx, 159
+	lea	rdi, [rsp + 209]
+	lea	rsi, [rsp + 48]
+	call	r12
+.LBB15_177:
+	mov	r12d, dword ptr [rsp]
+	jmp	.LBB15_205
+.LBB15_178:
+	mov	edx, 136
+	lea	rdi, [rsp + 48]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB15_179:
+	lea	rdi, [rsp + 928]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r12, 7
+	ja	.LBB15_181
+	shl	r12, 4
+	mov	qword ptr [rsp + r12 + 48], rax
+	mov	qword ptr [rsp + r12 + 56], rdx
+	mov	r12, qword ptr [rsp + 176]
+	inc	r12
+	mov	qword ptr [rsp + 176], r12
+.LBB15_181:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB15_179
+	lea	rdi, [rsp + 416]
+	lea	rsi, [rsp + 928]
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB15_183:
+	mov	rbp, qword ptr [rsp + 24]
+.LBB15_184:
+	mov	byte ptr [rsp + 208], 0
+.LBB15_185:
+	mov	r12d, dword ptr [rsp]
+	jmp	.LBB15_206
+.LBB15_186:
+	mov	rcx, qword ptr [rsp + 424]
+	mov	rsi, qword ptr [rsp + 408]
+	mov	rdx, qword ptr [rsp + 416]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	jmp	.LBB15_201
+.LBB15_187:
+	mov	al, byte ptr [rsp + 402]
+	and	al, 63
+	cmp	byte ptr [rsp + 401], 0
+	movzx	ecx, al
+	mov	eax, 63
+	cmove	ecx, eax
+	mov	al, 2
+	jmp	.LBB15_202
+.LBB15_188:
+	mov	rsi, qword ptr [rsp + 408]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	mov	rbp, rax
+.LBB15_189:
+	mov	al, 1
+	jmp	.LBB15_195
+.LBB15_190:
+	mov	edx, 136
+	lea	rdi, [rsp + 48]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB15_191:
+	lea	rdi, [rsp + 928]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r12, 7
+	ja	.LBB15_193
+	shl	r12, 4
+	mov	qword ptr [rsp + r12 + 48], rax
+	mov	qword ptr [rsp + r12 + 56], rdx
+	mov	r12, qword ptr [rsp + 176]
+	inc	r12
+	mov	qword ptr [rsp + 176], r12
+.LBB15_193:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB15_191
+	mov	rbp, qword ptr [rsp + 48]
+	mov	rdx, qword ptr [rsp + 56]
+	mov	ecx, 16
+	lea	rdi, [rsp + 624]
+	lea	rsi, [rsp + 64]
+	rep movsq es:[rdi], [rsi]
+	mov	al, 3
+.LBB15_195:
+	jmp	.LBB15_203
+.LBB15_196:
+	mov	edx, 136
+	lea	rdi, [rsp + 48]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB15_197:
+	lea	rdi, [rsp + 928]
+	mov	rsi, rbp
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r12, 7
+	ja	.LBB15_199
+	shl	r12, 4
+	mov	qword ptr [rsp + r12 + 48], rax
+	mov	qword ptr [rsp + r12 + 56], rdx
+	mov	r12, qword ptr [rsp + 176]
+	inc	r12
+	mov	qword ptr [rsp + 176], r12
+.LBB15_199:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB15_197
+	lea	rdi, [rsp + 416]
+	lea	rsi, [rsp + 928]
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB15_201:
+	xor	eax, eax
+.LBB15_202:
+.LBB15_203:
+	mov	r12d, dword ptr [rsp]
+.LBB15_204:
+	mov	byte ptr [rsp + 208], al
+	mov	byte ptr [rsp + 209], cl
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 232], rdx
+	mov	qword ptr [rsp + 224], rbp
+	mov	ecx, 16
+	lea	rdi, [rsp + 240]
+	lea	rsi, [rsp + 624]
+	rep movsq es:[rdi], [rsi]
+.LBB15_205:
+	mov	rbp, qword ptr [rsp + 24]
+.LBB15_206:
+	lea	rdi, [rsp + 768]
+	mov	rsi, r14
+	lea	rdx, [rsp + 208]
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB15_207:
+	cmp	byte ptr [rsp + 768], -1
+	jne	.LBB15_211
+	mov	rax, qword ptr [rsp + 776]
+	cmp	byte ptr [rsp + 392], 0
+	jne	.LBB15_226
+	cmp	eax, 1
+	je	.LBB15_229
+	cmp	eax, 4
+	je	.LBB15_228
+.LBB15_211:
+	mov	qword ptr [rsp + 24], rbp
+	mov	dword ptr [rsp], r12d
+	mov	rcx, qword ptr [rsp + 3904]
+	jmp	.LBB15_106
+.LBB15_212:
+	xor	ecx, ecx
+	mov	rbp, r13
+	mov	r15, rbx
+.LBB15_213:
+.LBB15_214:
+	lea	rdi, [rsp + 1840]
+	mov	qword ptr [rdi], rcx
+	mov	qword ptr [rdi + 8], rbp
+	mov	qword ptr [rdi + 16], r15
+	mov	qword ptr [rdi + 24], r11
+	mov	qword ptr [rdi + 32], r10
+.LBB15_215:
+	call	optional_trait_methods::usage_error
+	mov	ebx, 1
+	jmp	.LBB15_219
+.LBB15_216:
+	mov	rdx, rcx
+.LBB15_217:
+	xor	eax, eax
+	mov	qword ptr [rsp + 3904], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, 1
+	mov	rsi, r14
+.LBB15_218:
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+.LBB15_219:
+	mov	rax, rbx
+	add	rsp, 4440
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB15_220:
+	xor	edx, edx
+	jmp	.LBB15_217
+.LBB15_221:
+	mov	ecx, 3
+	jmp	.LBB15_213
+.LBB15_222:
+	mov	ecx, 1
+	jmp	.LBB15_213
+.LBB15_223:
+	test	byte ptr [rsp + 584], 1
+	je	.LBB15_232
+.LBB15_224:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.73]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 31
+.LBB15_225:
+	mov	edi, 2
+	call	r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	ebx, 1
+	mov	edx, 1
+	mov	edi, 2
+	call	r14
+	jmp	.LBB15_219
+.LBB15_226:
+	cmp	eax, 1
+	je	.LBB15_229
+	cmp	eax, 4
+	jne	.LBB15_231
+.LBB15_228:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.20]
+	lea	rdi, [rsp + 2880]
+	mov	edx, 23
+	jmp	.LBB15_230
+.LBB15_229:
+	mov	rsi, qword ptr [rsp + 784]
+	mov	rdx, qword ptr [rsp + 792]
+	lea	rdi, [rsp + 2880]
+.LBB15_230:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 2880]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB15_231:
+	mov	ebx, 1
+	mov	rdx, qword ptr [rsp + 3904]
+	jmp	.LBB15_217
+.LBB15_232:
+	test	byte ptr [rsp + 576], 1
+	je	.LBB15_235
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	xor	ebx, ebx
+	mov	edx, 823
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 1
+	jmp	.LBB15_218
+.LBB15_234:
+	mov	ecx, 4
+	jmp	.LBB15_213
+.LBB15_235:
+	mov	r15, qword ptr [rsp + 568]
+	test	r15, r15
+	je	.LBB15_242
+	lea	rbx, [rsp + 2880]
+	mov	rdi, rbx
+	mov	rsi, r15
+	mov	r14, qword ptr [rsp + 608]
+	mov	rdx, r14
+	call	optional_trait_methods::num::parse_num::<i128>
+	test	byte ptr [rbx], 1
+	je	.LBB15_246
+	mov	r15, qword ptr [rsp + 2896]
+	mov	r12, qword ptr [rsp + 2904]
+	jmp	.LBB15_243
+.LBB15_238:
+	xor	r10d, r10d
+	jmp	.LBB15_253
+.LBB15_239:
+	mov	ecx, 2
+	xor	r10d, r10d
+	jmp	.LBB15_214
+.LBB15_240:
+	mov	rax, r10
+.LBB15_241:
+	mov	ecx, 2
+	mov	r10, rax
+	jmp	.LBB15_214
+.LBB15_242:
+	xor	r15d, r15d
+	xor	r12d, r12d
+.LBB15_243:
+	mov	rax, qword ptr [rsp + 40]
+	test	ax, ax
+	je	.LBB15_247
+	movzx	eax, ax
+	cmp	eax, 1
+	jne	.LBB15_248
+	mov	rdi, qword ptr [rsp + 592]
+	mov	rsi, qword ptr [rsp + 600]
+	call	<optional_trait_methods::connection::Listener>::unix
+	mov	ebx, edx
+	jmp	.LBB15_249
+.LBB15_246:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.24]
+	lea	rdi, [rsp + 1840]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 15
+	mov	qword ptr [rdi + 24], r15
+	mov	qword ptr [rdi + 32], r14
+	mov	qword ptr [rdi], 2
+	jmp	.LBB15_215
+.LBB15_247:
+	movzx	eax, byte ptr [rsp + 384]
+	shl	eax, 8
+	movzx	ebp, byte ptr [rsp + 376]
+	or	ebp, eax
+	jmp	.LBB15_105
+.LBB15_248:
+	mov	ebp, dword ptr [rsp + 12]
+	mov	edi, ebp
+	call	<optional_trait_methods::connection::Listener>::tcp_loopback
+	mov	ebx, edx
+	test	bp, bp
+	setne	cl
+	or	cl, al
+	test	cl, 1
+	je	.LBB15_254
+.LBB15_249:
+	cmp	eax, 1
+	jne	.LBB15_255
+	mov	r14d, 1
+	mov	ebp, ebx
+	jmp	.LBB15_256
+.LBB15_251:
+	mov	r11, qword ptr [rsp + 16]
+.LBB15_252:
+	mov	r10, r14
+.LBB15_253:
+	mov	ecx, 2
+	jmp	.LBB15_214
+.LBB15_254:
+	mov	edi, ebx
+	call	optional_trait_methods::report_port
+.LBB15_255:
+	mov	edi, ebx
+	call	<optional_trait_methods::connection::Listener>::accept
+	mov	r14d, eax
+	mov	ebp, edx
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+.LBB15_256:
+	test	r14b, 1
+	je	.LBB15_258
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.72]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 29
+	jmp	.LBB15_225
+.LBB15_258:
+	pxor	xmm0, xmm0
+	lea	r14, [rsp + 944]
+	movdqa	xmmword ptr [r14 + 672], xmm0
+	movdqa	xmmword ptr [r14 + 656], xmm0
+	movdqa	xmmword ptr [r14 + 640], xmm0
+	movdqa	xmmword ptr [r14 + 624], xmm0
+	movdqa	xmmword ptr [r14 + 608], xmm0
+	movdqa	xmmword ptr [r14 + 592], xmm0
+	movdqa	xmmword ptr [r14 + 576], xmm0
+	lea	rdi, [rsp + 1872]
+	xor	ebx, ebx
+	mov	r13, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 528
+	xor	esi, esi
+	call	r13
+	mov	qword ptr [r14 + 568], r12
+	mov	qword ptr [r14 + 560], r15
+	pxor	xmm0, xmm0
+	movdqu	xmmword ptr [r14 + 696], xmm0
+	mov	qword ptr [r14 + 688], 1
+	movabs	rax, -9223372036854775808
+	mov	qword ptr [r14 + 712], rax
+	movabs	rax, 9223372036854775807
+	mov	qword ptr [r14 + 728], rax
+	mov	qword ptr [r14 + 720], -1
+	movdqa	xmmword ptr [r14 - 16], xmm0
+	lea	r15, [rsp + 1840]
+	mov	edx, 560
+	mov	rdi, r14
+	mov	rsi, r15
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 2880]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r13
+	mov	dword ptr [r14 + 1032], ebp
+	lea	rdi, [rsp + 1840]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 3928]
+	mov	edx, 512
+	xor	esi, esi
+	call	r13
+	xor	edx, edx
+	mov	dword ptr [rsp + 16], eax
+.LBB15_259:
+	mov	rdi, qword ptr [rsp + 2864]
+	mov	rcx, qword ptr [rsp + 2872]
+	xor	r13d, r13d
+	mov	rax, rdx
+	xor	r12d, r12d
+.LBB15_260:
+	mov	rsi, rcx
+	mov	rcx, rdi
+.LBB15_261:
+	cmp	rsi, rcx
+	jb	.LBB15_265
+	mov	qword ptr [rsp + 3904], rbx
+	cmp	rax, 1025
+	mov	rsi, r14
+	mov	ecx, 1
+	cmovae	rsi, rcx
+	cmovae	rax, rbx
+	mov	edi, ebp
+	mov	rdx, rax
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	lea	rdi, [rsp + 208]
+	mov	esi, ebp
+	mov	rdx, r15
+	call	optional_trait_methods::connection::read_fd
+	cmp	byte ptr [rsp + 208], 0
+	jne	.LBB15_275
+	mov	rcx, qword ptr [rsp + 216]
+	test	rcx, rcx
+	je	.LBB15_275
+	mov	qword ptr [rsp + 2864], rcx
+	mov	qword ptr [rsp + 2872], 0
+	xor	edx, edx
+	xor	eax, eax
+	xor	esi, esi
+.LBB15_265:
+	cmp	rcx, rsi
+	jb	.LBB15_358
+	cmp	rcx, 1024
+	ja	.LBB15_358
+	cmp	rsi, rcx
+	je	.LBB15_261
+	inc	rsi
+	mov	rdi, rcx
+	neg	rdi
+.LBB15_269:
+	mov	r8b, byte ptr [rsp + rsi + 1839]
+	mov	qword ptr [rsp + 2872], rsi
+	cmp	r8b, 10
+	je	.LBB15_278
+	cmp	r12, 511
+	jbe	.LBB15_272
+	mov	r13b, 1
+	jmp	.LBB15_273
+.LBB15_272:
+	mov	byte ptr [rsp + r12 + 3928], r8b
+	inc	r12
+.LBB15_273:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB15_269
+	mov	rdi, rcx
+	jmp	.LBB15_260
+.LBB15_275:
+	test	r12, r12
+	setne	al
+	or	al, r13b
+	test	al, 1
+	je	.LBB15_357
+	test	r13b, 1
+	jne	.LBB15_282
+	mov	edx, 0
+	cmp	r12, 512
+	jbe	.LBB15_280
+	jmp	.LBB15_357
+.LBB15_278:
+	test	r13b, 1
+	jne	.LBB15_282
+	cmp	r12, 512
+	ja	.LBB15_358
+.LBB15_280:
+	test	r12, r12
+	setne	al
+	test	byte ptr [rsp + 384], al
+	jne	.LBB15_284
+	test	r12, r12
+	je	.LBB15_259
+	jmp	.LBB15_285
+.LBB15_282:
+	mov	qword ptr [rsp + 216], 3
+	mov	qword ptr [rsp + 224], 512
+	mov	byte ptr [rsp + 232], 4
+	jmp	.LBB15_283
+.LBB15_284:
+	mov	rdi, r14
+	lea	rsi, [rsp + 3928]
+	mov	rdx, r12
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB15_285:
+	lea	rdi, [rsp + 1680]
+	lea	rsi, [rsp + 3928]
+	mov	rdx, r12
+	call	<optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget> as optional_trait_methods::state_machine::Controller>::parse_command
+	cmp	byte ptr [rsp + 1680], -1
+	je	.LBB15_290
+	mov	edx, 160
+	lea	rdi, [rsp + 400]
+	lea	rsi, [rsp + 1680]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	movzx	r12d, byte ptr [rsp + 400]
+	lea	r13, [r12 - 6]
+	cmp	r12, 7
+	cmovb	r13, rbx
+	lea	rcx, [rip + .LJTI15_1]
+	movsxd	rax, dword ptr [rcx + 4*r13]
+	add	rax, rcx
+	jmp	rax
+.LBB15_287:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 15
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+	lea	rcx, [rip + .LJTI15_2]
+	movsxd	rax, dword ptr [rcx + 4*r13]
+	add	rax, rcx
+	jmp	rax
+.LBB15_288:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI15_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB15_289:
+	mov	r12, qword ptr [rsp + 1504]
+	mov	rbp, qword ptr [rsp + 1512]
+	jmp	.LBB15_336
+.LBB15_290:
+	mov	eax, dword ptr [rsp + 1696]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rdx, qword ptr [rsp]
+	cmovne	rdx, qword ptr [rsp + 1688]
+	sete	cl
+	mov	esi, dword ptr [rsp + 16]
+	movzx	esi, sil
+	cmovne	esi, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 216], rcx
+	mov	qword ptr [rsp], rdx
+	mov	qword ptr [rsp + 224], rdx
+	mov	dword ptr [rsp + 16], esi
+	mov	byte ptr [rsp + 232], sil
+.LBB15_283:
+	mov	byte ptr [rsp + 208], -1
+	jmp	.LBB15_352
+.LBB15_291:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI15_4]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB15_292:
+	movdqa	xmm0, xmmword ptr [rsp + 1504]
+	mov	byte ptr [rsp + 208], 1
+	movdqa	xmmword ptr [rsp + 224], xmm0
+	jmp	.LBB15_352
+.LBB15_293:
+	movdqa	xmm0, xmmword ptr [rsp + 416]
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI15_0]
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	jne	.LBB15_304
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 21
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	mov	r8, qword ptr [rsp + 456]
+	mov	rcx, qword ptr [rsp + 448]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds
+	test	rax, rax
+	je	.LBB15_333
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 224], rax
+	mov	qword ptr [rsp + 232], 26
+	jmp	.LBB15_283
+.LBB15_296:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 14
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 408]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::undo
+	test	rax, rax
+	je	.LBB15_333
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 224], rax
+	mov	qword ptr [rsp + 232], rdx
+	jmp	.LBB15_283
+.LBB15_298:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 16
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [rsp + 401]
+	test	eax, eax
+	je	.LBB15_315
+	cmp	eax, 1
+	jne	.LBB15_316
+	lea	rdi, [rsp + 928]
+	jmp	.LBB15_317
+.LBB15_301:
+	mov	r12, qword ptr [rsp + 416]
+	jmp	.LBB15_305
+.LBB15_302:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 13
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	test	byte ptr [rsp + 416], 1
+	je	.LBB15_310
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rem
+	jmp	.LBB15_311
+.LBB15_304:
+	mov	r12, qword ptr [rsp + 416]
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 15
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+.LBB15_305:
+	test	r12, r12
+	je	.LBB15_308
+	cmp	r12, 1
+	jne	.LBB15_309
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 21
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_factor
+	jmp	.LBB15_349
+.LBB15_308:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 13
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::mul
+	jmp	.LBB15_311
+.LBB15_309:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 21
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	mov	r8, qword ptr [rsp + 456]
+	mov	rcx, qword ptr [rsp + 448]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds
+	jmp	.LBB15_311
+.LBB15_310:
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::div
+.LBB15_311:
+	test	rax, rax
+	je	.LBB15_349
+	mov	r12, rax
+	mov	rbp, rdx
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rollback
+	test	rax, rax
+	je	.LBB15_314
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 224], rax
+	mov	qword ptr [rsp + 232], 21
+	jmp	.LBB15_283
+.LBB15_314:
+	mov	al, -1
+	jmp	.LBB15_343
+.LBB15_315:
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
+	jmp	.LBB15_349
+.LBB15_316:
+	lea	r12, [rsp + 928]
+	mov	rdi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc
+	mov	rdi, r12
+.LBB15_317:
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::dec
+	jmp	.LBB15_349
+.LBB15_318:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 15
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint
+.LBB15_319:
+	mov	rsi, qword ptr [rsp + 416]
+	mov	rdx, qword ptr [rsp + 424]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::set_state
+	jmp	.LBB15_349
+.LBB15_320:
+	mov	al, byte ptr [rsp + 402]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [rsp + 401], 0
+	mov	ecx, 63
+	cmove	eax, ecx
+	mov	byte ptr [rsp + 208], 2
+	mov	byte ptr [rsp + 209], al
+	jmp	.LBB15_352
+.LBB15_321:
+	mov	rsi, qword ptr [rsp + 408]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	mov	byte ptr [rsp + 208], 1
+	mov	qword ptr [rsp + 232], rdx
+	mov	qword ptr [rsp + 224], rax
+	jmp	.LBB15_352
+.LBB15_322:
+	mov	rcx, qword ptr [rsp + 424]
+	mov	rsi, qword ptr [rsp + 408]
+	mov	rdx, qword ptr [rsp + 416]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	jmp	.LBB15_333
+.LBB15_323:
+	mov	edx, 136
+	lea	rdi, [rsp + 624]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB15_324:
+	lea	rdi, [rsp + 928]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB15_326
+	shl	r13, 4
+	mov	qword ptr [rsp + r13 + 624], rax
+	mov	qword ptr [rsp + r13 + 632], rdx
+	mov	r13, qword ptr [rsp + 752]
+	inc	r13
+	mov	qword ptr [rsp + 752], r13
+.LBB15_326:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB15_324
+	mov	edx, 144
+	lea	rdi, [rsp + 63]
+	lea	rsi, [rsp + 624]
+	mov	r12, qword ptr [rip + memcpy@GOTPCREL]
+	call	r12
+	mov	byte ptr [rsp + 208], 3
+	mov	edx, 159
+	lea	rdi, [rsp + 209]
+	lea	rsi, [rsp + 48]
+	call	r12
+	jmp	.LBB15_352
+.LBB15_328:
+	mov	edx, 136
+	lea	rdi, [rsp + 48]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB15_329:
+	lea	rdi, [rsp + 928]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB15_331
+	shl	r13, 4
+	mov	qword ptr [rsp + r13 + 48], rax
+	mov	qword ptr [rsp + r13 + 56], rdx
+	mov	r13, qword ptr [rsp + 176]
+	inc	r13
+	mov	qword ptr [rsp + 176], r13
+.LBB15_331:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB15_329
+	lea	rdi, [rsp + 416]
+	lea	rsi, [rsp + 928]
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB15_333:
+	mov	byte ptr [rsp + 208], 0
+	jmp	.LBB15_352
+.LBB15_334:
+	mov	al, byte ptr [rsp + 402]
+	and	al, 63
+	cmp	byte ptr [rsp + 401], 0
+	movzx	ecx, al
+	mov	eax, 63
+	cmove	ecx, eax
+	mov	al, 2
+	jmp	.LBB15_350
+.LBB15_335:
+	mov	rsi, qword ptr [rsp + 408]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	mov	r12, rax
+	mov	rbp, rdx
+.LBB15_336:
+	mov	al, 1
+	jmp	.LBB15_343
+.LBB15_337:
+	mov	rcx, qword ptr [rsp + 424]
+	mov	rsi, qword ptr [rsp + 408]
+	mov	rdx, qword ptr [rsp + 416]
+	lea	rdi, [rsp + 928]
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg
+	jmp	.LBB15_349
+.LBB15_338:
+	mov	edx, 136
+	lea	rdi, [rsp + 48]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB15_339:
+	lea	rdi, [rsp + 928]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB15_341
+	shl	r13, 4
+	mov	qword ptr [rsp + r13 + 48], rax
+	mov	qword ptr [rsp + r13 + 56], rdx
+	mov	r13, qword ptr [rsp + 176]
+	inc	r13
+	mov	qword ptr [rsp + 176], r13
+.LBB15_341:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB15_339
+	mov	r12, qword ptr [rsp + 48]
+	mov	rbp, qword ptr [rsp + 56]
+	mov	ecx, 16
+	lea	rdi, [rsp + 624]
+	lea	rsi, [rsp + 64]
+	rep movsq es:[rdi], [rsi]
+	mov	al, 3
+.LBB15_343:
+	jmp	.LBB15_351
+.LBB15_344:
+	mov	edx, 136
+	lea	rdi, [rsp + 48]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB15_345:
+	lea	rdi, [rsp + 928]
+	mov	rsi, r12
+	call	<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg
+	cmp	r13, 7
+	ja	.LBB15_347
+	shl	r13, 4
+	mov	qword ptr [rsp + r13 + 48], rax
+	mov	qword ptr [rsp + r13 + 56], rdx
+	mov	r13, qword ptr [rsp + 176]
+	inc	r13
+	mov	qword ptr [rsp + 176], r13
+.LBB15_347:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB15_345
+	lea	rdi, [rsp + 416]
+	lea	rsi, [rsp + 928]
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_cfg_gates::controller::TargetController<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB15_349:
+	xor	eax, eax
+.LBB15_350:
+.LBB15_351:
+	mov	byte ptr [rsp + 208], al
+	mov	byte ptr [rsp + 209], cl
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 232], rbp
+	mov	qword ptr [rsp + 224], r12
+	mov	ecx, 16
+	lea	rdi, [rsp + 240]
+	lea	rsi, [rsp + 624]
+	rep movsq es:[rdi], [rsi]
+.LBB15_352:
+	lea	rdi, [rsp + 768]
+	mov	rsi, r14
+	lea	rdx, [rsp + 208]
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	cmp	byte ptr [rsp + 768], -1
+	jne	.LBB15_356
+	mov	rax, qword ptr [rsp + 776]
+	cmp	byte ptr [rsp + 376], 0
+	jne	.LBB15_359
+	cmp	eax, 1
+	je	.LBB15_362
+	cmp	eax, 4
+	je	.LBB15_361
+.LBB15_356:
+	mov	ebp, dword ptr [rsp + 3912]
+	mov	rdx, qword ptr [rsp + 3904]
+	jmp	.LBB15_259
+.LBB15_357:
+	xor	edx, edx
+.LBB15_358:
+	xor	eax, eax
+	mov	qword ptr [rsp + 3904], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, ebp
+	mov	rsi, r14
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	jmp	.LBB15_219
+.LBB15_359:
+	cmp	eax, 1
+	je	.LBB15_362
+	cmp	eax, 4
+	jne	.LBB15_364
+.LBB15_361:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.20]
+	lea	rdi, [rsp + 2880]
+	mov	edx, 23
+	jmp	.LBB15_363
+.LBB15_362:
+	mov	rsi, qword ptr [rsp + 784]
+	mov	rdx, qword ptr [rsp + 792]
+	lea	rdi, [rsp + 2880]
+.LBB15_363:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 2880]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB15_364:
+	mov	rdx, qword ptr [rsp + 3904]
+	mov	ebx, 1
+	mov	ebp, dword ptr [rsp + 3912]
+	jmp	.LBB15_358
+.LBB15_365:
+	ud2
+.Lfunc_end15:
+.LJTI15_0:
+	.long	.LBB15_27-.LJTI15_0
+	.long	.LBB15_37-.LJTI15_0
+	.long	.LBB15_30-.LJTI15_0
+	.long	.LBB15_31-.LJTI15_0
+	.long	.LBB15_29-.LJTI15_0
+	.long	.LBB15_365-.LJTI15_0
+	.long	.LBB15_41-.LJTI15_0
+.LJTI15_1:
+	.long	.LBB15_291-.LJTI15_1
+	.long	.LBB15_287-.LJTI15_1
+	.long	.LBB15_293-.LJTI15_1
+	.long	.LBB15_287-.LJTI15_1
+	.long	.LBB15_296-.LJTI15_1
+.LJTI15_2:
+	.long	.LBB15_288-.LJTI15_2
+	.long	.LBB15_298-.LJTI15_2
+	.long	.LBB15_301-.LJTI15_2
+	.long	.LBB15_302-.LJTI15_2
+.LJTI15_3:
+	.long	.LBB15_289-.LJTI15_3
+	.long	.LBB15_319-.LJTI15_3
+	.long	.LBB15_334-.LJTI15_3
+	.long	.LBB15_335-.LJTI15_3
+	.long	.LBB15_337-.LJTI15_3
+	.long	.LBB15_338-.LJTI15_3
+	.long	.LBB15_344-.LJTI15_3
+.LJTI15_4:
+	.long	.LBB15_292-.LJTI15_4
+	.long	.LBB15_318-.LJTI15_4
+	.long	.LBB15_320-.LJTI15_4
+	.long	.LBB15_321-.LJTI15_4
+	.long	.LBB15_322-.LJTI15_4
+	.long	.LBB15_323-.LJTI15_4
+	.long	.LBB15_328-.LJTI15_4
+.LJTI15_5:
+	.long	.LBB15_140-.LJTI15_5
+	.long	.LBB15_134-.LJTI15_5
+	.long	.LBB15_142-.LJTI15_5
+	.long	.LBB15_134-.LJTI15_5
+	.long	.LBB15_138-.LJTI15_5
+.LJTI15_6:
+	.long	.LBB15_135-.LJTI15_6
+	.long	.LBB15_153-.LJTI15_6
+	.long	.LBB15_146-.LJTI15_6
+	.long	.LBB15_151-.LJTI15_6
+.LJTI15_7:
+	.long	.LBB15_136-.LJTI15_7
+	.long	.LBB15_171-.LJTI15_7
+	.long	.LBB15_187-.LJTI15_7
+	.long	.LBB15_188-.LJTI15_7
+	.long	.LBB15_186-.LJTI15_7
+	.long	.LBB15_190-.LJTI15_7
+	.long	.LBB15_196-.LJTI15_7
+.LJTI15_8:
+	.long	.LBB15_141-.LJTI15_8
+	.long	.LBB15_170-.LJTI15_8
+	.long	.LBB15_168-.LJTI15_8
+	.long	.LBB15_169-.LJTI15_8
+	.long	.LBB15_167-.LJTI15_8
+	.long	.LBB15_172-.LJTI15_8
+	.long	.LBB15_178-.LJTI15_8
+
+rust_eh_personality:
+.Lfunc_begin16:
+	ret
+.Lfunc_end16:
+
+core::panicking::panic_fmt:
+.Lfunc_begin17:
+	.loc	1 60 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp0:
+	.loc	1 80 14 prologue_end
+	call	__rustc::rust_begin_unwind
+.Ltmp1:
+.Lfunc_end17:
+
+core::panicking::panic_bounds_check:
+.Lfunc_begin18:
+	.loc	1 266 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp2:
+	.loc	1 271 5 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp3:
+.Lfunc_end18:
+
+core::slice::index::slice_index_fail:
+.Lfunc_begin19:
+	.loc	2 38 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp4:
+	.loc	3 177 9 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp5:
+.Lfunc_end19:
+
+optional_trait_methods::num::parse_num::<usize>:
+.Lfunc_begin20:
+	test	rsi, rsi
+	je	.LBB20_55
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB20_4
+	cmp	ecx, 45
+	jne	.LBB20_6
+	mov	cl, 1
+	jmp	.LBB20_5
+.LBB20_4:
+	xor	ecx, ecx
+.LBB20_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB20_7
+.LBB20_6:
+	xor	ecx, ecx
+.LBB20_7:
+	cmp	rsi, 1
+	jbe	.LBB20_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB20_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB20_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB20_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB20_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB20_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB20_32
+.LBB20_14:
+	test	rsi, rsi
+	je	.LBB20_55
+	mov	al, byte ptr [rdi]
+.LBB20_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB20_55
+.LBB20_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB20_19
+	cmp	byte ptr [rax], 95
+	je	.LBB20_55
+.LBB20_19:
+	mov	r8d, 10
+	xor	r9d, r9d
+	xor	edx, edx
+.LBB20_20:
+	cmp	rdi, rsi
+	je	.LBB20_50
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB20_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB20_20
+	jmp	.LBB20_51
+.LBB20_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB20_28
+	mov	rax, rdx
+	mul	r8
+	jo	.LBB20_28
+	mov	rdx, rax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB20_27
+	sub	rdx, rax
+	jae	.LBB20_20
+	jmp	.LBB20_28
+.LBB20_27:
+	add	rdx, rax
+	jae	.LBB20_20
+.LBB20_28:
+	xor	eax, eax
+	ret
+.LBB20_29:
+	mov	r8b, 8
+	jmp	.LBB20_31
+.LBB20_30:
+	mov	r8b, 2
+.LBB20_31:
+	xor	r9d, r9d
+.LBB20_32:
+	cmp	rsi, 2
+	je	.LBB20_55
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB20_52
+.LBB20_34:
+	cmp	dl, r8b
+	jae	.LBB20_55
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB20_37
+	cmp	byte ptr [rax], 95
+	je	.LBB20_55
+.LBB20_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB20_38:
+	cmp	r10, rdi
+	je	.LBB20_50
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB20_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB20_51
+	jmp	.LBB20_38
+.LBB20_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB20_45
+	test	r9b, r9b
+	je	.LBB20_28
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB20_28
+	add	al, -87
+	mov	r11d, eax
+.LBB20_45:
+	cmp	r11b, r8b
+	jae	.LBB20_28
+	mov	rax, rdx
+	mul	rsi
+	jo	.LBB20_28
+	mov	rdx, rax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB20_49
+	sub	rdx, rax
+	jae	.LBB20_38
+	jmp	.LBB20_28
+.LBB20_49:
+	add	rdx, rax
+	jae	.LBB20_38
+	jmp	.LBB20_28
+.LBB20_50:
+	mov	eax, 1
+	ret
+.LBB20_52:
+	test	r9b, r9b
+	je	.LBB20_55
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB20_55
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB20_34
+.LBB20_55:
+	xor	eax, eax
+.LBB20_51:
+	ret
+.Lfunc_end20:
+
+<optional_trait_methods::commands::Args>::reg:
+.Lfunc_begin21:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	cmp	rdx, 8
+	setb	cl
+	and	cl, al
+	cmovne	r15, rdx
+	neg	cl
+	or	cl, 2
+	mov	qword ptr [rbx], r15
+	mov	byte ptr [rbx + 8], cl
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end21:
+
+<optional_trait_methods::commands::Args>::next:
+.Lfunc_begin22:
+	mov	r10, qword ptr [rsi + 8]
+	mov	rax, qword ptr [rsi + 16]
+	xor	edx, edx
+	test	r10, r10
+	setne	dl
+	cmp	r10, 1
+	mov	rcx, r10
+	adc	rcx, -1
+	mov	r8, qword ptr [rsi]
+	add	r8, rdx
+	mov	r9, rcx
+	cmp	r10, 2
+	jb	.LBB22_5
+	xor	r9d, r9d
+.LBB22_2:
+	cmp	byte ptr [r8 + r9], 32
+	je	.LBB22_5
+	inc	r9
+	cmp	rcx, r9
+	jne	.LBB22_2
+	mov	r9, rcx
+.LBB22_5:
+	add	rax, rdx
+	sub	rcx, r9
+	lea	rdx, [r8 + r9]
+	mov	qword ptr [rsi], rdx
+	mov	qword ptr [rsi + 8], rcx
+	lea	rcx, [r9 + rax]
+	mov	qword ptr [rsi + 16], rcx
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], r8
+	mov	qword ptr [rdi + 16], r9
+	ret
+.Lfunc_end22:
+
+<optional_trait_methods::commands::Args>::names:
+.Lfunc_begin23:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 200
+	mov	qword ptr [rsp + 32], rdi
+	lea	r14, [rsp + 40]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r12, qword ptr [r14 + 8]
+	mov	rbx, qword ptr [r14 + 16]
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, qword ptr [rip + bcmp@GOTPCREL]
+.LBB23_1:
+	test	rbx, rbx
+	je	.LBB23_2
+	xor	r13d, r13d
+.LBB23_4:
+	cmp	byte ptr [r12 + r13], 59
+	je	.LBB23_7
+	inc	r13
+	cmp	rbx, r13
+	jne	.LBB23_4
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 24], r12
+	mov	r13, rbx
+	jmp	.LBB23_8
+.LBB23_2:
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	xor	ebx, ebx
+	mov	qword ptr [rsp + 24], r12
+	xor	r13d, r13d
+	jmp	.LBB23_8
+.LBB23_7:
+	lea	rax, [r13 + 1]
+	sub	rbx, rax
+	lea	rax, [r12 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 24], rax
+	mov	dword ptr [rsp + 12], 0
+.LBB23_8:
+	mov	edx, 144
+	lea	rbp, [rsp + 56]
+	mov	rdi, rbp
+	lea	rsi, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.6]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r14d, 6
+.LBB23_9:
+	cmp	qword ptr [rbp + 16], r13
+	jne	.LBB23_11
+	mov	rdi, qword ptr [rbp + 8]
+	mov	rsi, r12
+	mov	rdx, r13
+	call	r15
+	test	eax, eax
+	je	.LBB23_12
+.LBB23_11:
+	add	rbp, 24
+	dec	r14
+	jne	.LBB23_9
+	jmp	.LBB23_13
+.LBB23_12:
+	mov	rax, qword ptr [rsp + 16]
+	or	al, byte ptr [rbp]
+	mov	qword ptr [rsp + 16], rax
+.LBB23_13:
+	mov	r12, qword ptr [rsp + 24]
+	cmp	byte ptr [rsp + 12], 0
+	je	.LBB23_1
+	mov	rax, qword ptr [rsp + 32]
+	mov	rcx, qword ptr [rsp + 16]
+	mov	byte ptr [rax], cl
+	mov	byte ptr [rax + 8], -1
+	add	rsp, 200
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end23:
+
+<optional_trait_methods::connection::Listener>::tcp_loopback:
+.Lfunc_begin24:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	qword ptr [rsp + 20], 0
+	mov	word ptr [rsp + 12], 2
+	rol	di, 8
+	mov	word ptr [rsp + 14], di
+	mov	dword ptr [rsp + 16], 16777343
+	mov	edi, 2
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebx, edx
+	mov	ebp, 1
+	test	al, 1
+	jne	.LBB24_5
+	lea	rcx, [rsp + 28]
+	mov	dword ptr [rcx], 1
+	mov	edi, ebx
+	mov	esi, 1
+	mov	edx, 2
+	mov	r8d, 4
+	call	qword ptr [rip + setsockopt@GOTPCREL]
+	lea	rsi, [rsp + 12]
+	mov	edi, ebx
+	mov	edx, 16
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB24_3
+	mov	edi, ebx
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB24_3
+	xor	ebp, ebp
+	jmp	.LBB24_5
+.LBB24_3:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebx, r14d
+.LBB24_5:
+	mov	eax, ebp
+	mov	edx, ebx
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end24:
+
+<optional_trait_methods::connection::Listener>::socket:
+.Lfunc_begin25:
+	push	rbx
+	xor	ebx, ebx
+	mov	esi, 1
+	xor	edx, edx
+	call	qword ptr [rip + socket@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB25_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB25_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end25:
+
+<optional_trait_methods::connection::Listener>::unix:
+.Lfunc_begin26:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 112
+	mov	rdx, rsi
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [rsp + 96], xmm0
+	movups	xmmword ptr [rsp + 84], xmm0
+	movups	xmmword ptr [rsp + 68], xmm0
+	movups	xmmword ptr [rsp + 52], xmm0
+	movups	xmmword ptr [rsp + 36], xmm0
+	movups	xmmword ptr [rsp + 20], xmm0
+	movups	xmmword ptr [rsp + 4], xmm0
+	mov	word ptr [rsp + 2], 1
+	lea	rax, [rsi - 108]
+	cmp	rax, -107
+	jae	.LBB26_2
+	mov	ebx, 1
+	mov	ebp, 36
+	jmp	.LBB26_7
+.LBB26_2:
+	mov	rsi, rdi
+	lea	rbx, [rsp + 4]
+	mov	rdi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	rdi, rbx
+	call	qword ptr [rip + unlink@GOTPCREL]
+	mov	ebx, 1
+	mov	edi, 1
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebp, edx
+	test	al, 1
+	jne	.LBB26_7
+	lea	rsi, [rsp + 2]
+	mov	edi, ebp
+	mov	edx, 110
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB26_6
+	mov	edi, ebp
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB26_6
+	xor	ebx, ebx
+	jmp	.LBB26_7
+.LBB26_6:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebp, r14d
+.LBB26_7:
+	mov	eax, ebx
+	mov	edx, ebp
+	add	rsp, 112
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end26:
+
+<optional_trait_methods::connection::Listener>::accept:
+.Lfunc_begin27:
+	push	rbx
+	xor	ebx, ebx
+	xor	esi, esi
+	xor	edx, edx
+	call	qword ptr [rip + accept@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB27_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB27_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end27:
+
+optional_trait_methods::commands::unrecognized:
+.Lfunc_begin28:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	xor	r14d, r14d
+.LBB28_1:
+	cmp	byte ptr [rbx + r14], 32
+	je	.LBB28_4
+	inc	r14
+	cmp	rsi, r14
+	jne	.LBB28_1
+	mov	r14, rsi
+.LBB28_4:
+	mov	r15d, 8
+	lea	r12, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.33]
+	mov	r13, qword ptr [rip + bcmp@GOTPCREL]
+.LBB28_5:
+	cmp	qword ptr [r15 + r12], r14
+	jne	.LBB28_8
+	mov	rdi, qword ptr [r15 + r12 - 8]
+	mov	rsi, rbx
+	mov	rdx, r14
+	call	r13
+	test	eax, eax
+	je	.LBB28_7
+.LBB28_8:
+	add	r15, 16
+	cmp	r15, 152
+	jne	.LBB28_5
+	xor	eax, eax
+	jmp	.LBB28_10
+.LBB28_7:
+	mov	al, 1
+.LBB28_10:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end28:
+
+optional_trait_methods::connection::read_fd:
+.Lfunc_begin29:
+	push	rbx
+	mov	rax, rdx
+	mov	rbx, rdi
+	mov	edx, 1024
+	mov	edi, esi
+	mov	rsi, rax
+	call	qword ptr [rip + read@GOTPCREL]
+	test	rax, rax
+	js	.LBB29_2
+	mov	qword ptr [rbx + 8], rax
+	xor	eax, eax
+	jmp	.LBB29_3
+.LBB29_2:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	eax, dword ptr [rax]
+	mov	dword ptr [rbx + 4], eax
+	mov	eax, 1
+.LBB29_3:
+	mov	dword ptr [rbx], eax
+	pop	rbx
+	ret
+.Lfunc_end29:
+
+optional_trait_methods::connection::write_fd:
+.Lfunc_begin30:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	test	rdx, rdx
+	je	.LBB30_8
+	mov	ebp, ecx
+	mov	rbx, rdx
+	mov	r14, rsi
+	mov	r15d, edi
+	mov	r12, qword ptr [rip + send@GOTPCREL]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+.LBB30_2:
+	mov	edi, r15d
+	mov	rsi, r14
+	mov	rdx, rbx
+	test	bpl, bpl
+	je	.LBB30_3
+	mov	ecx, 16384
+	call	r12
+	jmp	.LBB30_5
+.LBB30_3:
+	call	r13
+.LBB30_5:
+	test	rax, rax
+	js	.LBB30_10
+	cmp	rbx, rax
+	jb	.LBB30_9
+	add	r14, rax
+	sub	rbx, rax
+	jne	.LBB30_2
+.LBB30_8:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB30_10:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB30_9:
+	mov	rdi, rax
+	mov	rsi, rbx
+	mov	rdx, rbx
+	call	core::slice::index::slice_index_fail
+.Lfunc_end30:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::checkpoint:
+.Lfunc_begin31:
+	push	r14
+	push	rbx
+	push	rax
+	mov	rdx, qword ptr [rdi + 584]
+	mov	rcx, qword ptr [rdi + 576]
+	mov	r8, qword ptr [rdi + 712]
+	mov	rsi, qword ptr [rdi + 704]
+	lea	r9, [rdi + 48]
+	cmp	qword ptr [rdi + 568], 16
+	jne	.LBB31_3
+	mov	rax, qword ptr [rdi + 560]
+	cmp	rax, 16
+	jae	.LBB31_5
+	mov	r10, rax
+	shl	r10, 5
+	mov	r11, qword ptr [r9 + r10]
+	mov	rbx, qword ptr [r9 + r10 + 8]
+	mov	r14, qword ptr [r9 + r10 + 24]
+	mov	r10, qword ptr [r9 + r10 + 16]
+	mov	qword ptr [rdi], 1
+	mov	qword ptr [rdi + 8], 0
+	mov	qword ptr [rdi + 16], r11
+	mov	qword ptr [rdi + 24], rbx
+	mov	qword ptr [rdi + 32], r10
+	mov	qword ptr [rdi + 40], r14
+	jmp	.LBB31_4
+.LBB31_3:
+	mov	rax, qword ptr [rdi + 560]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rdi], xmm0
+	cmp	rax, 16
+	jae	.LBB31_5
+.LBB31_4:
+	shl	rax, 5
+	mov	qword ptr [r9 + rax + 8], rdx
+	mov	qword ptr [r9 + rax], rcx
+	mov	qword ptr [r9 + rax + 24], r8
+	mov	qword ptr [r9 + rax + 16], rsi
+	mov	eax, dword ptr [rdi + 560]
+	inc	eax
+	and	eax, 15
+	mov	qword ptr [rdi + 560], rax
+	mov	rax, qword ptr [rdi + 568]
+	inc	rax
+	cmp	rax, 16
+	mov	ecx, 16
+	cmovb	rcx, rax
+	mov	qword ptr [rdi + 568], rcx
+	add	rsp, 8
+	pop	rbx
+	pop	r14
+	ret
+.LBB31_5:
+	mov	esi, 16
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end31:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_bounds:
+.Lfunc_begin32:
+	cmp	rcx, rsi
+	mov	rax, r8
+	sbb	rax, rdx
+	jge	.LBB32_2
+	lea	rax, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.42]
+	jmp	.LBB32_3
+.LBB32_2:
+	mov	qword ptr [rdi + 720], rsi
+	mov	qword ptr [rdi + 728], rdx
+	mov	qword ptr [rdi + 736], rcx
+	mov	qword ptr [rdi + 744], r8
+	mov	rax, qword ptr [rdi + 704]
+	mov	r9, qword ptr [rdi + 712]
+	cmp	rax, rsi
+	mov	r10, r9
+	sbb	r10, rdx
+	cmovl	r9, rdx
+	cmovl	rax, rsi
+	cmp	rcx, rax
+	mov	rdx, r8
+	sbb	rdx, r9
+	cmovl	r9, r8
+	cmovl	rax, rcx
+	mov	qword ptr [rdi + 704], rax
+	mov	qword ptr [rdi + 712], r9
+	xor	eax, eax
+.LBB32_3:
+	mov	edx, 26
+	ret
+.Lfunc_end32:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::scale_factor:
+.Lfunc_begin33:
+	mov	rax, qword ptr [rdi + 720]
+	mov	rcx, qword ptr [rdi + 728]
+	mov	r8, qword ptr [rdi + 744]
+	mov	r9, qword ptr [rdi + 736]
+	cmp	rsi, rax
+	mov	r10, rdx
+	sbb	r10, rcx
+	cmovl	rdx, rcx
+	cmovl	rsi, rax
+	cmp	r9, rsi
+	mov	rax, r8
+	sbb	rax, rdx
+	cmovl	rsi, r9
+	cmovl	rdx, r8
+	mov	qword ptr [rdi + 712], rdx
+	mov	qword ptr [rdi + 704], rsi
+	ret
+.Lfunc_end33:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::dec:
+.Lfunc_begin34:
+	add	qword ptr [rdi + 576], -1
+	adc	qword ptr [rdi + 584], -1
+	ret
+.Lfunc_end34:
+
+.LCPI35_0:
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	128
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::div:
+.Lfunc_begin35:
+	mov	rcx, rdx
+	mov	rax, rsi
+	or	rax, rdx
+	je	.LBB35_1
+	push	rbx
+	mov	rdx, rsi
+	mov	rbx, rdi
+	mov	rdi, qword ptr [rdi + 576]
+	mov	rsi, qword ptr [rbx + 584]
+	movq	xmm0, rdi
+	movq	xmm1, rsi
+	punpcklqdq	xmm0, xmm1
+	movq	xmm1, rdx
+	movq	xmm2, rcx
+	punpcklqdq	xmm1, xmm2
+	pcmpeqd	xmm2, xmm2
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI35_0]
+	pcmpeqb	xmm2, xmm1
+	pand	xmm0, xmm2
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	je	.LBB35_4
+	call	qword ptr [rip + __divti3@GOTPCREL]
+	mov	qword ptr [rbx + 584], rdx
+	mov	qword ptr [rbx + 576], rax
+	xor	eax, eax
+.LBB35_6:
+	pop	rbx
+	ret
+.LBB35_1:
+	lea	rax, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.43]
+	mov	edx, 17
+	ret
+.LBB35_4:
+	lea	rax, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.44]
+	mov	edx, 29
+	jmp	.LBB35_6
+.Lfunc_end35:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::inc:
+.Lfunc_begin36:
+	add	qword ptr [rdi + 576], 1
+	adc	qword ptr [rdi + 584], 0
+	ret
+.Lfunc_end36:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::mul:
+.Lfunc_begin37:
+	mov	rax, rsi
+	xor	rax, 7
+	or	rax, rdx
+	jne	.LBB37_2
+	lea	rax, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.45]
+	jmp	.LBB37_3
+.LBB37_2:
+	mov	rcx, rdx
+	mov	rax, qword ptr [rdi + 704]
+	imul	rcx, rax
+	mul	rsi
+	mov	r8, qword ptr [rdi + 576]
+	add	rdx, rcx
+	mov	rcx, qword ptr [rdi + 712]
+	imul	rcx, rsi
+	add	rcx, rdx
+	mov	rsi, qword ptr [rdi + 584]
+	imul	rsi, rax
+	mul	r8
+	imul	rcx, r8
+	add	rcx, rdx
+	add	rcx, rsi
+	mov	qword ptr [rdi + 576], rax
+	mov	qword ptr [rdi + 584], rcx
+	xor	eax, eax
+.LBB37_3:
+	mov	edx, 28
+	ret
+.Lfunc_end37:
+
+.LCPI38_0:
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	128
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rem:
+.Lfunc_begin38:
+	mov	rcx, rdx
+	mov	rax, rsi
+	or	rax, rdx
+	je	.LBB38_1
+	push	rbx
+	mov	rdx, rsi
+	mov	rbx, rdi
+	mov	rdi, qword ptr [rdi + 576]
+	mov	rsi, qword ptr [rbx + 584]
+	movq	xmm0, rdi
+	movq	xmm1, rsi
+	punpcklqdq	xmm0, xmm1
+	movq	xmm1, rdx
+	movq	xmm2, rcx
+	punpcklqdq	xmm1, xmm2
+	pcmpeqd	xmm2, xmm2
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI38_0]
+	pcmpeqb	xmm2, xmm1
+	pand	xmm0, xmm2
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	je	.LBB38_4
+	call	qword ptr [rip + __modti3@GOTPCREL]
+	mov	qword ptr [rbx + 584], rdx
+	mov	qword ptr [rbx + 576], rax
+	xor	eax, eax
+.LBB38_6:
+	pop	rbx
+	ret
+.LBB38_1:
+	lea	rax, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.43]
+	mov	edx, 17
+	ret
+.LBB38_4:
+	lea	rax, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.44]
+	mov	edx, 29
+	jmp	.LBB38_6
+.Lfunc_end38:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::undo:
+.Lfunc_begin39:
+	mov	rax, qword ptr [rdi + 568]
+	lea	rcx, [rsi - 1]
+	cmp	rcx, rax
+	jae	.LBB39_2
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rdi], xmm0
+	mov	ecx, dword ptr [rdi + 560]
+	sub	ecx, esi
+	and	ecx, 15
+	mov	qword ptr [rdi + 560], rcx
+	sub	rax, rsi
+	mov	qword ptr [rdi + 568], rax
+	shl	ecx, 5
+	mov	rax, qword ptr [rdi + rcx + 56]
+	mov	rdx, qword ptr [rdi + rcx + 72]
+	mov	rsi, qword ptr [rdi + rcx + 48]
+	mov	rcx, qword ptr [rdi + rcx + 64]
+	mov	r8, qword ptr [rdi + 728]
+	mov	r9, qword ptr [rdi + 720]
+	mov	r10, qword ptr [rdi + 744]
+	mov	r11, qword ptr [rdi + 736]
+	mov	qword ptr [rdi + 576], rsi
+	cmp	rcx, r9
+	mov	rsi, rdx
+	sbb	rsi, r8
+	cmovl	rdx, r8
+	cmovl	rcx, r9
+	cmp	r11, rcx
+	mov	rsi, r10
+	sbb	rsi, rdx
+	cmovl	rcx, r11
+	cmovl	rdx, r10
+	mov	qword ptr [rdi + 584], rax
+	mov	qword ptr [rdi + 712], rdx
+	mov	qword ptr [rdi + 704], rcx
+	jmp	.LBB39_3
+.LBB39_2:
+	test	rsi, rsi
+	je	.LBB39_3
+	xor	ecx, ecx
+	test	rax, rax
+	setne	cl
+	lea	rdx, [rcx + 2*rcx + 16]
+	lea	rcx, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.47]
+	lea	rax, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.46]
+	cmove	rax, rcx
+	ret
+.LBB39_3:
+	xor	eax, eax
+	ret
+.Lfunc_end39:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::read_reg:
+.Lfunc_begin40:
+	test	rsi, rsi
+	je	.LBB40_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB40_5
+	shl	rax, 4
+	add	rdi, rax
+	add	rdi, 592
+	jmp	.LBB40_2
+.LBB40_1:
+	add	rdi, 576
+.LBB40_2:
+	mov	rax, qword ptr [rdi]
+	mov	rdx, qword ptr [rdi + 8]
+	ret
+.LBB40_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end40:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::rollback:
+.Lfunc_begin41:
+	mov	r9, qword ptr [rdi]
+	mov	rax, qword ptr [rdi + 24]
+	mov	rdx, qword ptr [rdi + 16]
+	mov	rcx, qword ptr [rdi + 40]
+	mov	rsi, qword ptr [rdi + 32]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rdi], xmm0
+	mov	r8, qword ptr [rdi + 568]
+	test	r8, r8
+	je	.LBB41_1
+	push	r15
+	push	r14
+	push	rbx
+	mov	r10d, dword ptr [rdi + 560]
+	dec	r10d
+	and	r10d, 15
+	mov	qword ptr [rdi + 560], r10
+	lea	r11, [r8 - 1]
+	mov	qword ptr [rdi + 568], r11
+	shl	r10d, 5
+	mov	rbx, qword ptr [rdi + r10 + 56]
+	mov	r11, qword ptr [rdi + r10 + 72]
+	mov	r15, qword ptr [rdi + r10 + 48]
+	mov	r14, qword ptr [rdi + r10 + 64]
+	test	r9b, 1
+	je	.LBB41_4
+	lea	r9, [rdi + r10]
+	add	r9, 48
+	mov	qword ptr [r9], rdx
+	mov	qword ptr [r9 + 8], rax
+	mov	qword ptr [r9 + 16], rsi
+	mov	qword ptr [r9 + 24], rcx
+	mov	qword ptr [rdi + 568], r8
+.LBB41_4:
+	mov	qword ptr [rdi + 576], r15
+	mov	qword ptr [rdi + 584], rbx
+	mov	qword ptr [rdi + 704], r14
+	mov	qword ptr [rdi + 712], r11
+	xor	eax, eax
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.LBB41_1:
+	lea	rax, [rip + .Lanon.0211fe648df1a3fda61446a87e75205b.50]
+	ret
+.Lfunc_end41:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::set_state:
+.Lfunc_begin42:
+	mov	qword ptr [rdi + 584], rdx
+	mov	qword ptr [rdi + 576], rsi
+	ret
+.Lfunc_end42:
+
+<optional_trait_methods::using_cfg_gates::targets::advanced::AdvancedTarget as optional_trait_methods::using_cfg_gates::target::Target>::write_reg:
+.Lfunc_begin43:
+	test	rsi, rsi
+	je	.LBB43_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB43_5
+	shl	rax, 4
+	mov	qword ptr [rdi + rax + 600], rcx
+	mov	qword ptr [rdi + rax + 592], rdx
+	ret
+.LBB43_1:
+	mov	qword ptr [rdi + 576], rdx
+	mov	qword ptr [rdi + 584], rcx
+	ret
+.LBB43_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end43:
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.0:
+	.byte	10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.1:
+	.byte	59
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.2:
+	.ascii	"E 01"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.3:
+	.ascii	"E 02"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.4:
+	.ascii	"E."
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.5:
+	.ascii	" at "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.6:
+	.ascii	"E 05"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.8:
+	.ascii	"0123456789abcdef"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.11:
+	.ascii	"unknown option: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.12:
+	.ascii	"missing value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.13:
+	.ascii	"invalid value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.14:
+	.ascii	": "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.15:
+	.ascii	" doesn't take a value"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.16:
+	.ascii	"only one of --unix / --tcp may be given"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.20:
+	.ascii	"Invalid implementation!"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.21:
+	.ascii	"OK"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.22:
+	.ascii	"--unix"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.23:
+	.ascii	"--tcp"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.24:
+	.ascii	"--initial-state"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.25:
+	.ascii	"--on-error"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.26:
+	.ascii	"--echo"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.27:
+	.ascii	"--repl"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.28:
+	.ascii	"--help"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.29:
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.22
+	.asciz	"\006\000\000\000\000\000\000\000"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.23
+	.ascii	"\005\000\000\000\000\000\000\000\001"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.24
+	.ascii	"\017\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.25
+	.ascii	"\n\000\000\000\000\000\000\000\003"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.26
+	.ascii	"\006\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.27
+	.ascii	"\006\000\000\000\000\000\000\000\005"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.28
+	.ascii	"\006\000\000\000\000\000\000\000\006"
+	.zero	7
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.30:
+	.ascii	"panic!\n"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.31:
+	.ascii	"listening on 127.0.0.1:"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.32:
+	.ascii	"error: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.33:
+	.zero	2,10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.34:
+	.ascii	"usage: optional-trait-methods [options]\n\n  --unix <path>              serve the first client to connect to a Unix domain socket\n  --tcp <port>               serve the first client to connect to 127.0.0.1:<port>\n                             (port 0 picks any free port, printed to stderr)\n                             (by default, serve stdin / stdout)\n  --initial-state <n>        start the target off in state <n> (by default, 0)\n  --on-error=continue|exit   whether to keep serving after a command fails\n  --echo                     repeat every command before its reply\n  --repl                     expect a human instead of a client (prompt, aliases, help)\n                             (needs the `repl` feature)\n  --help                     print this message\n\n  (`--name <value>` may also be written `--name=<value>`)"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.35:
+	.ascii	"Parse IncDec extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.39:
+	.ascii	"Parse Mul extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.41:
+	.ascii	"Parse ScaleFactor extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.43:
+	.ascii	"Parse ScaleBounds extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.45:
+	.ascii	"Parse Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.48:
+	.ascii	"Parse Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.57:
+	.ascii	"IncDec extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.58:
+	.ascii	"Mul extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.59:
+	.ascii	"ScaleFactor extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.60:
+	.ascii	"ScaleBounds extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.61:
+	.ascii	"Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.62:
+	.ascii	"Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.63:
+	.ascii	"Undo checkpoint"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.64:
+	.ascii	"unknown command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.65:
+	.ascii	"unsupported command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.66:
+	.ascii	"bad number"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.67:
+	.ascii	"trailing garbage"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.68:
+	.ascii	"line too long"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.72:
+	.ascii	"failed to accept a connection"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.73:
+	.ascii	"--repl needs the `repl` feature"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.0:
+	.ascii	"incdec"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.1:
+	.ascii	"mul"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.2:
+	.ascii	"mul.scale_factor"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.3:
+	.ascii	"mul.scale_factor.scale_bounds"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.4:
+	.ascii	"div"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.5:
+	.ascii	"undo"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.6:
+	.byte	1
+	.zero	7
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.0
+	.ascii	"\006\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.1
+	.ascii	"\003\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.2
+	.ascii	"\020\000\000\000\000\000\000\000\b"
+	.zero	7
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.3
+	.ascii	"\035\000\000\000\000\000\000\000\020"
+	.zero	7
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.4
+	.ascii	"\003\000\000\000\000\000\000\000 "
+	.zero	7
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.5
+	.asciz	"\004\000\000\000\000\000\000"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.15:
+	.byte	43
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.17:
+	.byte	45
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.18:
+	.byte	42
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.20:
+	.ascii	"*~"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.28:
+	.ascii	"+-"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.29:
+	.ascii	"*~~"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.30:
+	.byte	47
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.31:
+	.byte	37
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.32:
+	.byte	117
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.33:
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.15
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.17
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.28
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.18
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.20
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.29
+	.asciz	"\003\000\000\000\000\000\000"
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.30
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.31
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.0211fe648df1a3fda61446a87e75205b.32
+	.asciz	"\001\000\000\000\000\000\000"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.42:
+	.ascii	"scale bounds are inverted!"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.43:
+	.ascii	"dividing by zero!"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.44:
+	.ascii	"dividing MIN by -1 overflows!"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.45:
+	.ascii	"multiplying by 7 is unlucky!"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.46:
+	.ascii	"not enough history!"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.47:
+	.ascii	"nothing to undo!"
+
+.Lanon.0211fe648df1a3fda61446a87e75205b.50:
+	.ascii	"nothing to roll back!"
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+
+	.byte	1
+	.byte	17
+	.byte	1
+	.byte	37
+	.byte	14
+	.byte	19
+	.byte	5
+	.byte	3
+	.byte	14
+	.byte	16
+	.byte	23
+	.byte	27
+	.byte	14
+	.byte	17
+	.byte	1
+	.byte	85
+	.byte	23
+	.byte	0
+	.byte	0
+	.byte	2
+	.byte	57
+	.byte	1
+	.byte	3
+	.byte	14
+	.byte	0
+	.byte	0
+	.byte	3
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	11
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	4
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	5
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	0
+.Lcu_begin0:
+	.long	.Ldebug_info_end0-.Ldebug_info_start0
+.Ldebug_info_start0:
+	.short	4
+	.long	.debug_abbrev
+	.byte	8
+	.byte	1
+	.long	.Linfo_string0
+	.short	28
+	.long	.Linfo_string1
+	.long	.Lline_table_start0
+	.long	.Linfo_string2
+	.quad	0
+	.long	.Ldebug_ranges0
+	.byte	2
+	.long	.Linfo_string3
+	.byte	2
+	.long	.Linfo_string4
+	.byte	3
+	.quad	.Lfunc_begin17
+	.long	.Lfunc_end17-.Lfunc_begin17
+	.byte	1
+	.byte	86
+	.long	.Linfo_string7
+	.long	.Linfo_string8
+	.byte	1
+	.byte	60
+	.byte	3
+
+
+	.byte	4
+	.quad	.Lfunc_begin18
+	.long	.Lfunc_end18-.Lfunc_begin18
+	.byte	1
+	.byte	86
+	.long	.Linfo_string9
+	.long	.Linfo_string10
+	.byte	1
+	.short	266
+	.byte	3
+
+
+	.byte	0
+	.byte	2
+	.long	.Linfo_string5
+	.byte	2
+	.long	.Linfo_string6
+	.byte	3
+	.quad	.Lfunc_begin19
+	.long	.Lfunc_end19-.Lfunc_begin19
+	.byte	1
+	.byte	86
+	.long	.Linfo_string11
+	.long	.Linfo_string12
+	.byte	2
+	.byte	38
+	.byte	3
+
+
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+.Ldebug_info_end0:
+.Lsec_end0:
+.Lsec_end1:
+.Lsec_end2:
+	.long	76
+	.short	2
+	.long	.Lcu_begin0
+	.byte	8
+	.byte	0
+	.zero	4,255
+	.quad	.Lfunc_begin17
+	.quad	.Lsec_end0-.Lfunc_begin17
+	.quad	.Lfunc_begin18
+	.quad	.Lsec_end1-.Lfunc_begin18
+	.quad	.Lfunc_begin19
+	.quad	.Lsec_end2-.Lfunc_begin19
+	.quad	0
+	.quad	0
+.Ldebug_ranges0:
+	.quad	.Lfunc_begin17
+	.quad	.Lfunc_end17
+	.quad	.Lfunc_begin18
+	.quad	.Lfunc_end18
+	.quad	.Lfunc_begin19
+	.quad	.Lfunc_end19
+	.quad	0
+	.quad	0
+.Linfo_string0:
+	.asciz	"clang LLVM (rustc version 1.97.0-nightly (e50aa6fba 2026-05-19))"
+.Linfo_string1:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a/library/core/src/lib.rs/@/core.667c7a611d73a360-cgu.0"
+.Linfo_string2:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a"
+.Linfo_string3:
+	.asciz	"core"
+.Linfo_string4:
+	.asciz	"panicking"
+.Linfo_string5:
+	.asciz	"slice"
+.Linfo_string6:
+	.asciz	"index"
+.Linfo_string7:
+	.asciz	"core::panicking::panic_fmt"
+.Linfo_string8:
+	.asciz	"panic_fmt"
+.Linfo_string9:
+	.asciz	"core::panicking::panic_bounds_check"
+.Linfo_string10:
+	.asciz	"panic_bounds_check"
+.Linfo_string11:
+	.asciz	"core::slice::index::slice_index_fail"
+.Linfo_string12:
+	.asciz	"slice_index_fail"
+	.ident	"rustc version 1.97.0-nightly (e50aa6fba 2026-05-19)"
+.Lline_table_start0:
@@ -0,0 +1,5318 @@
+<optional_trait_methods::commands::Args>::num::<i128>:
+.Lfunc_begin0:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rsi, qword ptr [r14 + 8]
+	mov	rdx, qword ptr [r14 + 16]
+	mov	r14, rsp
+	mov	rdi, r14
+	call	optional_trait_methods::num::parse_num::<i128>
+	test	byte ptr [r14], 1
+	je	.LBB0_2
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	qword ptr [rbx + 16], rax
+	mov	qword ptr [rbx + 24], rcx
+	xor	eax, eax
+	jmp	.LBB0_3
+.LBB0_2:
+	mov	qword ptr [rbx + 8], r15
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB0_3:
+	mov	qword ptr [rbx], rax
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end0:
+
+<optional_trait_methods::commands::Args>::regs::<i128>:
+.Lfunc_begin1:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	sub	rsp, 168
+	mov	rbx, rdi
+	lea	r14, [rsp + 16]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	cmp	qword ptr [r14 + 16], 256
+	jne	.LBB1_20
+	lea	rdi, [rsp + 16]
+	mov	r12, qword ptr [rdi + 8]
+	xor	r14d, r14d
+	mov	edx, 136
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	eax, 256
+	xorps	xmm0, xmm0
+.LBB1_2:
+	mov	rcx, r12
+	add	r12, 32
+	add	rax, -32
+	movaps	xmmword ptr [rsp], xmm0
+	xor	edx, edx
+.LBB1_3:
+	mov	dil, byte ptr [rcx + 2*rdx]
+	lea	esi, [rdi - 48]
+	cmp	sil, 10
+	jb	.LBB1_13
+	lea	esi, [rdi - 97]
+	cmp	sil, 5
+	ja	.LBB1_10
+	add	dil, -87
+	jmp	.LBB1_12
+.LBB1_10:
+	lea	esi, [rdi - 71]
+	cmp	sil, -6
+	jb	.LBB1_20
+	add	dil, -55
+.LBB1_12:
+	mov	esi, edi
+.LBB1_13:
+	mov	dil, byte ptr [rcx + 2*rdx + 1]
+	lea	r8d, [rdi - 48]
+	cmp	r8b, 10
+	jb	.LBB1_19
+	lea	r8d, [rdi - 97]
+	cmp	r8b, 5
+	ja	.LBB1_16
+	add	dil, -87
+	jmp	.LBB1_18
+.LBB1_16:
+	lea	r8d, [rdi - 71]
+	cmp	r8b, -6
+	jb	.LBB1_20
+	add	dil, -55
+.LBB1_18:
+	mov	r8d, edi
+.LBB1_19:
+	shl	sil, 4
+	or	sil, r8b
+	mov	byte ptr [rsp + rdx], sil
+	inc	rdx
+	cmp	rdx, 16
+	jne	.LBB1_3
+	cmp	r14, 7
+	ja	.LBB1_8
+	movaps	xmm1, xmmword ptr [rsp]
+	shl	r14, 4
+	movaps	xmmword ptr [rsp + r14 + 16], xmm1
+	mov	r14, qword ptr [rsp + 144]
+	inc	r14
+	mov	qword ptr [rsp + 144], r14
+.LBB1_8:
+	test	rax, rax
+	jne	.LBB1_2
+	lea	rdi, [rbx + 16]
+	lea	rsi, [rsp + 16]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	xor	eax, eax
+	jmp	.LBB1_21
+.LBB1_20:
+	mov	qword ptr [rbx + 8], r15
+	mov	byte ptr [rbx + 16], 2
+	mov	eax, 1
+.LBB1_21:
+	mov	qword ptr [rbx], rax
+	add	rsp, 168
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end1:
+
+<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>:
+.Lfunc_begin2:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rax, qword ptr [rdi + 128]
+	mov	rcx, rax
+	shl	rcx, 4
+	xor	r15d, r15d
+	cmp	rax, 9
+	mov	r12d, 16
+	cmovb	r12, rdi
+	cmovb	r15, rcx
+	test	r15, r15
+	je	.LBB2_3
+	mov	rbx, rsi
+	xor	r14d, r14d
+	xor	r13d, r13d
+.LBB2_2:
+	mov	rdx, qword ptr [r12 + r13]
+	mov	rcx, qword ptr [r12 + r13 + 8]
+	mov	rdi, rbx
+	mov	rsi, r14
+	call	optional_trait_methods::using_fn::targets::advanced::write_reg
+	inc	r14
+	add	r13, 16
+	cmp	r15, r13
+	jne	.LBB2_2
+.LBB2_3:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end2:
+
+optional_trait_methods::num::format_decimal::<usize>:
+.Lfunc_begin3:
+	mov	eax, 39
+	movabs	r8, -3689348814741910323
+.LBB3_1:
+	cmp	rax, 39
+	ja	.LBB3_4
+	mov	rcx, rax
+	mov	rax, rdi
+	mul	r8
+	shr	rdx, 3
+	lea	eax, [rdx + rdx]
+	lea	eax, [rax + 4*rax]
+	mov	r9d, edi
+	sub	r9d, eax
+	or	r9b, 48
+	mov	byte ptr [rsi + rcx], r9b
+	lea	rax, [rcx - 1]
+	cmp	rdi, 10
+	mov	rdi, rdx
+	jae	.LBB3_1
+	add	rsi, rcx
+	inc	rcx
+	mov	edx, 41
+	sub	rdx, rcx
+	mov	rax, rsi
+	ret
+.LBB3_4:
+	push	rax
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end3:
+
+optional_trait_methods::num::format_decimal::<i128>:
+.Lfunc_begin4:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r12, rsi
+	mov	r15, rdi
+	mov	eax, 39
+	mov	ebp, 10
+	mov	r14, qword ptr [rip + __divti3@GOTPCREL]
+	mov	qword ptr [rsp], rsi
+.LBB4_1:
+	cmp	rax, 39
+	ja	.LBB4_9
+	mov	r13, rax
+	mov	edx, 10
+	mov	rdi, r15
+	mov	rsi, r12
+	xor	ecx, ecx
+	call	r14
+	mov	rcx, rax
+	mov	rsi, rdx
+	mul	rbp
+	lea	rdi, [rsi + 4*rsi]
+	lea	rdx, [rdx + 2*rdi]
+	mov	rdi, r15
+	sub	rdi, rax
+	mov	rax, r12
+	sbb	rax, rdx
+	mov	edx, edi
+	neg	dl
+	test	rax, rax
+	movzx	eax, dl
+	cmovns	eax, edi
+	mov	edx, 48
+	cmp	al, 10
+	jb	.LBB4_4
+	mov	edx, 87
+.LBB4_4:
+	add	dl, al
+	mov	byte ptr [rbx + r13], dl
+	add	r15, 9
+	adc	r12, 0
+	lea	rax, [r13 - 1]
+	cmp	r15, 19
+	sbb	r12, 0
+	mov	r15, rcx
+	mov	r12, rsi
+	jae	.LBB4_1
+	cmp	qword ptr [rsp], 0
+	jns	.LBB4_8
+	inc	r13
+	add	r13, -2
+	cmp	r13, 39
+	ja	.LBB4_9
+	mov	byte ptr [rbx + r13], 45
+.LBB4_8:
+	mov	edx, 40
+	sub	rdx, r13
+	add	rbx, r13
+	mov	rax, rbx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB4_9:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end4:
+
+optional_trait_methods::num::parse_num::<i128>:
+.Lfunc_begin5:
+	push	rbp
+	push	r15
+	push	r14
+	push	rbx
+	test	rdx, rdx
+	je	.LBB5_1
+	mov	rcx, rdx
+	lea	rax, [rsi + 1]
+	dec	rdx
+	movzx	r8d, byte ptr [rsi]
+	cmp	r8d, 43
+	je	.LBB5_6
+	cmp	r8d, 45
+	jne	.LBB5_4
+	mov	r11b, 1
+	jmp	.LBB5_7
+.LBB5_6:
+	xor	r11d, r11d
+.LBB5_7:
+	mov	rcx, rdx
+	mov	rsi, rax
+	jmp	.LBB5_8
+.LBB5_4:
+	xor	r11d, r11d
+.LBB5_8:
+	cmp	rcx, 1
+	jbe	.LBB5_9
+	mov	al, byte ptr [rsi]
+	cmp	al, 48
+	jne	.LBB5_11
+	movzx	eax, byte ptr [rsi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB5_12
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB5_31
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB5_30
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB5_12
+	mov	r10b, 16
+	mov	bl, 1
+	jmp	.LBB5_33
+.LBB5_9:
+	test	rcx, rcx
+	je	.LBB5_1
+	mov	al, byte ptr [rsi]
+.LBB5_11:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB5_1
+.LBB5_12:
+	add	rcx, rsi
+	mov	rax, rcx
+	dec	rax
+	je	.LBB5_14
+	cmp	byte ptr [rax], 95
+	je	.LBB5_1
+.LBB5_14:
+	mov	ebx, 10
+	xor	edx, edx
+	xor	r8d, r8d
+	xor	r9d, r9d
+.LBB5_15:
+	cmp	rsi, rcx
+	je	.LBB5_55
+	mov	bpl, byte ptr [rsi]
+	inc	rsi
+	cmp	bpl, 95
+	jne	.LBB5_18
+	xor	eax, eax
+	test	dl, 1
+	mov	edx, 0
+	jne	.LBB5_15
+	jmp	.LBB5_56
+.LBB5_18:
+	add	bpl, -48
+	cmp	bpl, 9
+	ja	.LBB5_1
+	mov	rax, r8
+	mul	rbx
+	mov	r10, rdx
+	mov	r8, rax
+	mov	rax, r9
+	imul	rbx
+	mov	r9, rax
+	add	r9, r10
+	adc	rdx, 0
+	mov	rax, r9
+	sar	rax, 63
+	mov	r10, rdx
+	xor	r10, rax
+	sar	rdx, 63
+	xor	rdx, rax
+	or	rdx, r10
+	jne	.LBB5_1
+	movzx	eax, bpl
+	test	r11b, r11b
+	je	.LBB5_21
+	sub	r8, rax
+	sbb	r9, 0
+	jo	.LBB5_1
+	jmp	.LBB5_23
+.LBB5_21:
+	add	r8, rax
+	adc	r9, 0
+	jo	.LBB5_1
+.LBB5_23:
+	mov	dl, 1
+	jmp	.LBB5_15
+.LBB5_30:
+	mov	r10b, 8
+	jmp	.LBB5_32
+.LBB5_31:
+	mov	r10b, 2
+.LBB5_32:
+	xor	ebx, ebx
+.LBB5_33:
+	cmp	rcx, 2
+	je	.LBB5_1
+	lea	r14, [rsi + 2]
+	mov	al, byte ptr [r14]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB5_35
+.LBB5_38:
+	cmp	dl, r10b
+	jae	.LBB5_1
+	add	rsi, rcx
+	mov	rax, rsi
+	dec	rax
+	je	.LBB5_41
+	cmp	byte ptr [rax], 95
+	je	.LBB5_1
+.LBB5_41:
+	movzx	r15d, r10b
+	xor	ecx, ecx
+	xor	r8d, r8d
+	xor	r9d, r9d
+.LBB5_42:
+	cmp	r14, rsi
+	je	.LBB5_55
+	mov	al, byte ptr [r14]
+	inc	r14
+	cmp	al, 95
+	jne	.LBB5_45
+	xor	eax, eax
+	test	cl, 1
+	mov	ecx, 0
+	jne	.LBB5_42
+	jmp	.LBB5_56
+.LBB5_45:
+	lea	ebp, [rax - 48]
+	cmp	bpl, 10
+	jb	.LBB5_49
+	test	bl, bl
+	je	.LBB5_1
+	or	al, 32
+	lea	ecx, [rax - 97]
+	cmp	cl, 5
+	ja	.LBB5_1
+	add	al, -87
+	mov	ebp, eax
+.LBB5_49:
+	cmp	bpl, r10b
+	jae	.LBB5_1
+	mov	rax, r8
+	mul	r15
+	mov	rcx, rdx
+	mov	r8, rax
+	mov	rax, r9
+	imul	r15
+	mov	r9, rax
+	add	r9, rcx
+	adc	rdx, 0
+	mov	rax, r9
+	sar	rax, 63
+	mov	rcx, rdx
+	xor	rcx, rax
+	sar	rdx, 63
+	xor	rdx, rax
+	or	rdx, rcx
+	jne	.LBB5_1
+	movzx	eax, bpl
+	test	r11b, r11b
+	je	.LBB5_52
+	sub	r8, rax
+	sbb	r9, 0
+	jo	.LBB5_1
+	jmp	.LBB5_54
+.LBB5_52:
+	add	r8, rax
+	adc	r9, 0
+	jo	.LBB5_1
+.LBB5_54:
+	mov	cl, 1
+	jmp	.LBB5_42
+.LBB5_55:
+	mov	qword ptr [rdi + 16], r8
+	mov	qword ptr [rdi + 24], r9
+	mov	eax, 1
+	jmp	.LBB5_56
+.LBB5_35:
+	test	bl, bl
+	je	.LBB5_1
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB5_1
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB5_38
+.LBB5_1:
+	xor	eax, eax
+.LBB5_56:
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], 0
+	pop	rbx
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end5:
+
+optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+.Lfunc_begin6:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB6_2
+	lea	rcx, [rip + .LJTI6_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB6_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB6_5
+.LBB6_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB6_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB6_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB6_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB6_18
+.LBB6_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB6_15
+	jmp	.LBB6_28
+.LBB6_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB6_14
+	jmp	.LBB6_28
+.LBB6_19:
+	lea	r14, [rbx + 16]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 144]
+	mov	rax, rcx
+	shl	rax, 4
+	cmp	rcx, 9
+	mov	ecx, 16
+	cmovb	rcx, r14
+	cmovae	rax, r12
+	test	rax, rax
+	je	.LBB6_26
+	add	rax, rcx
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB6_21:
+	movaps	xmm0, xmmword ptr [rcx]
+	add	rcx, 16
+	movaps	xmmword ptr [rsp + 16], xmm0
+	xor	esi, esi
+.LBB6_22:
+	cmp	r12, 256
+	jae	.LBB6_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB6_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 16
+	jne	.LBB6_22
+	cmp	rcx, rax
+	jne	.LBB6_21
+.LBB6_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB6_27
+.LBB6_11:
+	mov	rdi, qword ptr [rbx + 16]
+	mov	rsi, qword ptr [rbx + 24]
+	xorps	xmm0, xmm0
+	lea	rdx, [rsp + 32]
+	movaps	xmmword ptr [rdx + 16], xmm0
+	movaps	xmmword ptr [rdx], xmm0
+	mov	qword ptr [rdx + 32], 0
+	call	optional_trait_methods::num::format_decimal::<i128>
+.LBB6_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB6_27
+.LBB6_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI6_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB6_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB6_4
+.LBB6_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB6_4
+.LBB6_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB6_27
+.LBB6_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB6_12
+.LBB6_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB6_4:
+	mov	edx, 4
+.LBB6_5:
+	mov	rdi, r15
+.LBB6_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB6_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 160
+	mov	rdi, rbp
+	mov	rsi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB6_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB6_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end6:
+.LJTI6_0:
+	.long	.LBB6_10-.LJTI6_0
+	.long	.LBB6_11-.LJTI6_0
+	.long	.LBB6_13-.LJTI6_0
+	.long	.LBB6_19-.LJTI6_0
+.LJTI6_1:
+	.long	.LBB6_3-.LJTI6_1
+	.long	.LBB6_6-.LJTI6_1
+	.long	.LBB6_7-.LJTI6_1
+	.long	.LBB6_8-.LJTI6_1
+	.long	.LBB6_9-.LJTI6_1
+
+optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+.Lfunc_begin7:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 296
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	rbp, rdi
+	movzx	eax, byte ptr [rdx]
+	cmp	rax, 3
+	ja	.LBB7_2
+	lea	rcx, [rip + .LJTI7_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB7_10:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.29]
+	mov	edx, 2
+	jmp	.LBB7_5
+.LBB7_13:
+	mov	r12b, byte ptr [rbx + 1]
+	lea	rdi, [rsp + 48]
+	lea	rsi, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.6]
+	mov	edx, 144
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	esi, 1
+	xor	edx, edx
+	not	r12b
+	xor	r14d, r14d
+.LBB7_14:
+	lea	rax, [r14 + 2*r14]
+	lea	rcx, [rsp + 48]
+	lea	rax, [rcx + 8*rax]
+.LBB7_15:
+	inc	r14
+	test	byte ptr [rax], r12b
+	jne	.LBB7_17
+	mov	r13, qword ptr [rax + 8]
+	test	r13, r13
+	jne	.LBB7_18
+.LBB7_17:
+	add	rax, 24
+	cmp	r14, 6
+	jne	.LBB7_15
+	jmp	.LBB7_28
+.LBB7_18:
+	mov	qword ptr [rsp + 8], rbp
+	mov	rbp, qword ptr [rax + 16]
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, r15
+	mov	rsi, r13
+	mov	rdx, rbp
+	mov	rbp, qword ptr [rsp + 8]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.1]
+	mov	edx, 1
+	cmp	r14, 6
+	jne	.LBB7_14
+	jmp	.LBB7_28
+.LBB7_19:
+	lea	r14, [rbx + 16]
+	lea	rdi, [rsp + 32]
+	xor	r12d, r12d
+	mov	edx, 256
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	mov	rcx, qword ptr [rbx + 144]
+	mov	rax, rcx
+	shl	rax, 4
+	cmp	rcx, 9
+	mov	ecx, 16
+	cmovb	rcx, r14
+	cmovae	rax, r12
+	test	rax, rax
+	je	.LBB7_26
+	add	rax, rcx
+	xor	r12d, r12d
+	lea	rdx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.14]
+.LBB7_21:
+	movaps	xmm0, xmmword ptr [rcx]
+	add	rcx, 16
+	movaps	xmmword ptr [rsp + 16], xmm0
+	xor	esi, esi
+.LBB7_22:
+	cmp	r12, 256
+	jae	.LBB7_29
+	movzx	edi, byte ptr [rsp + rsi + 16]
+	mov	r8d, edi
+	shr	r8d, 4
+	mov	r8b, byte ptr [r8 + rdx]
+	mov	byte ptr [rsp + r12 + 32], r8b
+	cmp	r12, 255
+	je	.LBB7_30
+	inc	rsi
+	and	edi, 15
+	mov	dil, byte ptr [rdi + rdx]
+	mov	byte ptr [rsp + r12 + 33], dil
+	add	r12, 2
+	cmp	rsi, 16
+	jne	.LBB7_22
+	cmp	rcx, rax
+	jne	.LBB7_21
+.LBB7_26:
+	lea	rsi, [rsp + 32]
+	mov	rdi, r15
+	mov	rdx, r12
+	jmp	.LBB7_27
+.LBB7_11:
+	mov	rdi, qword ptr [rbx + 16]
+	mov	rsi, qword ptr [rbx + 24]
+	xorps	xmm0, xmm0
+	lea	rdx, [rsp + 32]
+	movaps	xmmword ptr [rdx + 16], xmm0
+	movaps	xmmword ptr [rdx], xmm0
+	mov	qword ptr [rdx + 32], 0
+	call	optional_trait_methods::num::format_decimal::<i128>
+.LBB7_12:
+	mov	rdi, r15
+	mov	rsi, rax
+	jmp	.LBB7_27
+.LBB7_2:
+	mov	rax, qword ptr [rbx + 8]
+	lea	rcx, [rip + .LJTI7_1]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB7_3:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.2]
+	jmp	.LBB7_4
+.LBB7_9:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.6]
+	jmp	.LBB7_4
+.LBB7_7:
+	mov	esi, 1
+	mov	rdi, r15
+	xor	edx, edx
+	jmp	.LBB7_27
+.LBB7_8:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.4]
+	mov	edx, 2
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	movzx	eax, byte ptr [rbx + 24]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>]
+	mov	rdx, qword ptr [rcx + 8*rax]
+	lea	rcx, [rip + .Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel]
+	movsxd	rsi, dword ptr [rcx + 4*rax]
+	add	rsi, rcx
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.5]
+	mov	edx, 4
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	rdi, qword ptr [rbx + 16]
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 32]
+	movaps	xmmword ptr [rsi + 16], xmm0
+	movaps	xmmword ptr [rsi], xmm0
+	mov	qword ptr [rsi + 32], 0
+	call	optional_trait_methods::num::format_decimal::<usize>
+	jmp	.LBB7_12
+.LBB7_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.3]
+.LBB7_4:
+	mov	edx, 4
+.LBB7_5:
+	mov	rdi, r15
+.LBB7_27:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB7_28:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	rdi, r15
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 160
+	mov	rdi, rbp
+	mov	rsi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	rsp, 296
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB7_29:
+	mov	esi, 256
+	mov	rdi, r12
+	call	core::panicking::panic_bounds_check
+.LBB7_30:
+	mov	edi, 256
+	mov	esi, 256
+	call	core::panicking::panic_bounds_check
+.Lfunc_end7:
+.LJTI7_0:
+	.long	.LBB7_10-.LJTI7_0
+	.long	.LBB7_11-.LJTI7_0
+	.long	.LBB7_13-.LJTI7_0
+	.long	.LBB7_19-.LJTI7_0
+.LJTI7_1:
+	.long	.LBB7_3-.LJTI7_1
+	.long	.LBB7_6-.LJTI7_1
+	.long	.LBB7_7-.LJTI7_1
+	.long	.LBB7_8-.LJTI7_1
+	.long	.LBB7_9-.LJTI7_1
+
+__rustc::rust_begin_unwind:
+.Lfunc_begin8:
+	push	rax
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.30]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	call	qword ptr [rip + abort@GOTPCREL]
+.Lfunc_end8:
+
+optional_trait_methods::report_port:
+.Lfunc_begin9:
+	push	rbx
+	sub	rsp, 64
+	xorps	xmm0, xmm0
+	lea	rsi, [rsp + 16]
+	movaps	xmmword ptr [rsi], xmm0
+	lea	rdx, [rsp + 12]
+	mov	dword ptr [rdx], 16
+	call	qword ptr [rip + getsockname@GOTPCREL]
+	test	eax, eax
+	js	.LBB9_1
+	xor	eax, eax
+	test	al, al
+	jne	.LBB9_7
+	movzx	ebx, word ptr [rsp + 18]
+	rol	bx, 8
+	shl	ebx, 16
+	shr	ebx, 16
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rsp + 32], xmm0
+	movaps	xmmword ptr [rsp + 16], xmm0
+	mov	qword ptr [rsp + 48], 0
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.31]
+	mov	edx, 23
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	ecx, 39
+.LBB9_4:
+	cmp	rcx, 39
+	ja	.LBB9_8
+	mov	rax, rcx
+	movzx	edx, bx
+	imul	esi, edx, 52429
+	shr	esi, 19
+	lea	ecx, [rsi + rsi]
+	lea	ecx, [rcx + 4*rcx]
+	sub	ebx, ecx
+	or	bl, 48
+	mov	byte ptr [rsp + rax + 16], bl
+	lea	rcx, [rax - 1]
+	mov	ebx, esi
+	cmp	dx, 10
+	jae	.LBB9_4
+	lea	rsi, [rsp + rax]
+	add	rsi, 16
+	inc	rax
+	mov	edx, 41
+	sub	rdx, rax
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB9_7
+.LBB9_1:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB9_7:
+	add	rsp, 64
+	pop	rbx
+	ret
+.LBB9_8:
+	mov	esi, 40
+	mov	rdi, -1
+	call	core::panicking::panic_bounds_check
+.Lfunc_end9:
+
+optional_trait_methods::usage_error:
+.Lfunc_begin10:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.32]
+	mov	edx, 7
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	mov	rax, qword ptr [rbx]
+	lea	rcx, [rip + .LJTI10_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB10_1:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.7]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 16
+	jmp	.LBB10_2
+.LBB10_6:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.12]
+	mov	edx, 39
+	mov	edi, 2
+	call	qword ptr [rip + write@GOTPCREL]
+	jmp	.LBB10_7
+.LBB10_4:
+	mov	r14, qword ptr [rbx + 8]
+	mov	r15, qword ptr [rbx + 16]
+	mov	r12, qword ptr [rbx + 24]
+	mov	rbx, qword ptr [rbx + 32]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.9]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, r15
+	call	r13
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.10]
+	mov	edx, 2
+	mov	edi, 2
+	call	r13
+	mov	edi, 2
+	mov	rsi, r12
+	mov	rdx, rbx
+	call	r13
+	jmp	.LBB10_7
+.LBB10_5:
+	mov	rsi, qword ptr [rbx + 8]
+	mov	rdx, qword ptr [rbx + 16]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.11]
+	mov	edx, 21
+	mov	edi, 2
+	call	rbx
+	jmp	.LBB10_7
+.LBB10_3:
+	mov	r14, qword ptr [rbx + 8]
+	mov	rbx, qword ptr [rbx + 16]
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.8]
+	mov	r15, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 18
+.LBB10_2:
+	mov	edi, 2
+	call	r15
+	mov	edi, 2
+	mov	rsi, r14
+	mov	rdx, rbx
+	call	r15
+.LBB10_7:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.33]
+	mov	rbx, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 2
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	mov	edx, 823
+	mov	edi, 2
+	call	rbx
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 2
+	mov	rax, rbx
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	jmp	rax
+.Lfunc_end10:
+.LJTI10_0:
+	.long	.LBB10_1-.LJTI10_0
+	.long	.LBB10_3-.LJTI10_0
+	.long	.LBB10_4-.LJTI10_0
+	.long	.LBB10_5-.LJTI10_0
+	.long	.LBB10_6-.LJTI10_0
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin11:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB11_1
+	xor	eax, eax
+	mov	qword ptr [r14 + 1024], rax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	cmovae	rdx, rax
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB11_3
+	mov	edi, 1
+	mov	rsi, r15
+	mov	rdx, rbx
+	xor	ecx, ecx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	jmp	optional_trait_methods::connection::write_fd
+.LBB11_1:
+	cmp	rax, rdx
+	jb	.LBB11_4
+.LBB11_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB11_4:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end11:
+
+<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write:
+.Lfunc_begin12:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	mov	rbx, rdx
+	mov	r15, rsi
+	mov	r14, rdi
+	mov	rdx, qword ptr [rdi + 1024]
+	lea	rax, [rdx + rbx]
+	cmp	rax, 1024
+	jbe	.LBB12_1
+	xor	eax, eax
+	mov	r12d, 1025
+	cmp	rdx, r12
+	mov	esi, 1
+	cmovb	rsi, r14
+	mov	qword ptr [r14 + 1024], rax
+	cmovae	rdx, rax
+	mov	ebp, dword ptr [r14 + 1032]
+	mov	edi, ebp
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 0
+	cmp	rbx, r12
+	jb	.LBB12_3
+	mov	edi, ebp
+	mov	rsi, r15
+	mov	rdx, rbx
+	mov	ecx, 1
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	optional_trait_methods::connection::write_fd
+.LBB12_1:
+	cmp	rax, rdx
+	jb	.LBB12_4
+.LBB12_3:
+	add	rdx, r14
+	mov	rdi, rdx
+	mov	rsi, r15
+	mov	rdx, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	add	qword ptr [r14 + 1024], rbx
+.LBB12_4:
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end12:
+
+<optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget> as optional_trait_methods::state_machine::Controller>::parse_command:
+.Lfunc_begin13:
+	push	rbp
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	sub	rsp, 336
+	mov	rbx, rdi
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.35]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 22
+	#APP
+	#NO_APP
+	lea	rax, [rsi + 1]
+	movzx	ecx, byte ptr [rsi]
+	cmp	ecx, 45
+	je	.LBB13_4
+	cmp	ecx, 43
+	jne	.LBB13_9
+	cmp	rdx, 1
+	jne	.LBB13_6
+	mov	byte ptr [rbx + 1], 0
+	jmp	.LBB13_31
+.LBB13_4:
+	cmp	rdx, 1
+	jne	.LBB13_6
+	mov	byte ptr [rbx + 1], 1
+	jmp	.LBB13_31
+.LBB13_6:
+	cmp	byte ptr [rax], 32
+	jne	.LBB13_10
+.LBB13_7:
+	mov	qword ptr [rbx + 8], 1
+.LBB13_8:
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+	jmp	.LBB13_32
+.LBB13_9:
+	cmp	rdx, 2
+	jb	.LBB13_11
+.LBB13_10:
+	cmp	word ptr [rsi], 11563
+	je	.LBB13_29
+.LBB13_11:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.39]
+	mov	r8, rsp
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	ecx, 42
+	jne	.LBB13_17
+	mov	rdi, rdx
+	dec	rdi
+	je	.LBB13_14
+	cmp	byte ptr [rax], 32
+	jne	.LBB13_17
+.LBB13_14:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rdi
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB13_113
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB13_104
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 8
+	jmp	.LBB13_28
+.LBB13_17:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.41]
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 2
+	jb	.LBB13_19
+	cmp	word ptr [rsi], 32298
+	je	.LBB13_45
+.LBB13_19:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.43]
+	mov	r8, rsp
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 27
+	#APP
+	#NO_APP
+	cmp	rdx, 3
+	jb	.LBB13_21
+	movzx	edi, word ptr [rsi]
+	xor	edi, 32298
+	movzx	r8d, byte ptr [rsi + 2]
+	xor	r8d, 126
+	or	r8w, di
+	je	.LBB13_52
+.LBB13_21:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.45]
+	mov	r8, rsp
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 19
+	#APP
+	#NO_APP
+	cmp	ecx, 37
+	je	.LBB13_33
+	cmp	ecx, 47
+	jne	.LBB13_38
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB13_25
+	cmp	byte ptr [rax], 32
+	jne	.LBB13_58
+.LBB13_25:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB13_113
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB13_104
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 9
+.LBB13_28:
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rbx + 16], xmm0
+	jmp	.LBB13_51
+.LBB13_29:
+	cmp	rdx, 2
+	jne	.LBB13_43
+	mov	byte ptr [rbx + 1], 2
+.LBB13_31:
+	mov	al, 7
+.LBB13_32:
+	mov	byte ptr [rbx], al
+	jmp	.LBB13_116
+.LBB13_33:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB13_35
+	cmp	byte ptr [rax], 32
+	jne	.LBB13_58
+.LBB13_35:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB13_113
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB13_104
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 9
+	jmp	.LBB13_50
+.LBB13_38:
+	lea	rdi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	qword ptr [r8], rdi
+	mov	qword ptr [r8 + 8], 20
+	#APP
+	#NO_APP
+	cmp	ecx, 111
+	jle	.LBB13_61
+	lea	edi, [rcx - 112]
+	cmp	edi, 7
+	ja	.LBB13_59
+	lea	r8, [rip + .LJTI13_0]
+	movsxd	rdi, dword ptr [r8 + 4*rdi]
+	add	rdi, r8
+	jmp	rdi
+.LBB13_41:
+	cmp	rdx, 1
+	jne	.LBB13_96
+	xor	eax, eax
+	jmp	.LBB13_98
+.LBB13_43:
+	cmp	byte ptr [rsi + 2], 32
+	jne	.LBB13_11
+	mov	qword ptr [rbx + 8], 2
+	jmp	.LBB13_8
+.LBB13_45:
+	lea	rdi, [rsi + 2]
+	mov	r8, rdx
+	sub	r8, 2
+	je	.LBB13_47
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_19
+.LBB13_47:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 2
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB13_113
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB13_104
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 8
+.LBB13_50:
+	mov	qword ptr [rbx + 24], 0
+	mov	qword ptr [rbx + 16], 1
+.LBB13_51:
+	mov	qword ptr [rbx + 32], rax
+	mov	qword ptr [rbx + 40], rcx
+	jmp	.LBB13_116
+.LBB13_52:
+	lea	rdi, [rsi + 3]
+	mov	r8, rdx
+	sub	r8, 3
+	je	.LBB13_54
+	cmp	byte ptr [rdi], 32
+	jne	.LBB13_21
+.LBB13_54:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rdi
+	mov	qword ptr [rsi + 8], r8
+	mov	qword ptr [rsi + 16], 3
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB13_113
+	mov	r14, rsp
+	mov	r15, qword ptr [r14 + 16]
+	mov	r12, qword ptr [r14 + 24]
+	lea	rsi, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB13_113
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB13_104
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 8
+	mov	qword ptr [rbx + 24], 0
+	mov	qword ptr [rbx + 16], 2
+	mov	qword ptr [rbx + 32], r15
+	mov	qword ptr [rbx + 40], r12
+	mov	qword ptr [rbx + 48], rax
+	mov	qword ptr [rbx + 56], rcx
+	jmp	.LBB13_116
+.LBB13_58:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.48]
+	mov	rcx, rsp
+	mov	qword ptr [rcx], rax
+	mov	qword ptr [rcx + 8], 20
+	#APP
+	#NO_APP
+.LBB13_59:
+	mov	rdi, rsi
+	mov	rsi, rdx
+	call	optional_trait_methods::commands::unrecognized
+	mov	qword ptr [rbx + 8], 0
+.LBB13_60:
+	mov	byte ptr [rbx + 16], al
+	jmp	.LBB13_115
+.LBB13_61:
+	cmp	ecx, 63
+	je	.LBB13_83
+	cmp	ecx, 71
+	je	.LBB13_86
+	cmp	ecx, 103
+	jne	.LBB13_59
+	cmp	rdx, 1
+	jne	.LBB13_99
+	mov	al, 5
+	jmp	.LBB13_32
+.LBB13_66:
+	mov	rcx, rdx
+	dec	rcx
+	jne	.LBB13_87
+	mov	edx, 1
+.LBB13_68:
+	mov	byte ptr [rbx], 10
+	mov	qword ptr [rbx + 8], rdx
+	jmp	.LBB13_116
+.LBB13_69:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB13_71
+	cmp	byte ptr [rax], 32
+	jne	.LBB13_59
+.LBB13_71:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	byte ptr [r14], 0
+	jne	.LBB13_113
+	cmp	qword ptr [rsp + 176], 0
+	jne	.LBB13_104
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 1
+	jmp	.LBB13_128
+.LBB13_74:
+	mov	rdi, rdx
+	dec	rdi
+	je	.LBB13_76
+	cmp	byte ptr [rax], 32
+	jne	.LBB13_107
+.LBB13_76:
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rdi
+	mov	qword ptr [rsi + 16], 1
+	lea	r14, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	cl, byte ptr [r14 + 8]
+	mov	rax, qword ptr [r14]
+	cmp	cl, -1
+	jne	.LBB13_114
+	cmp	qword ptr [rsp + 8], 0
+	jne	.LBB13_125
+	mov	byte ptr [rbx], 3
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB13_116
+.LBB13_79:
+	mov	rdi, rdx
+	dec	rdi
+	je	.LBB13_81
+.LBB13_80:
+	cmp	byte ptr [rax], 32
+	jne	.LBB13_100
+.LBB13_81:
+	lea	rsi, [rsp + 168]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rdi
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::reg
+	mov	al, byte ptr [r14 + 8]
+	mov	r15, qword ptr [r14]
+	cmp	al, -1
+	je	.LBB13_102
+	mov	qword ptr [rbx + 8], r15
+	jmp	.LBB13_60
+.LBB13_83:
+	mov	rcx, rdx
+	dec	rcx
+	jne	.LBB13_120
+	mov	qword ptr [rsp], rax
+	mov	qword ptr [rsp + 8], rcx
+	mov	qword ptr [rsp + 16], 1
+	xor	ebp, ebp
+.LBB13_85:
+	mov	byte ptr [rbx], 2
+	mov	byte ptr [rbx + 1], bpl
+	mov	byte ptr [rbx + 2], al
+	jmp	.LBB13_116
+.LBB13_86:
+	mov	rcx, rdx
+	dec	rcx
+	je	.LBB13_112
+	jmp	.LBB13_111
+.LBB13_87:
+	cmp	byte ptr [rax], 32
+	jne	.LBB13_59
+	lea	r15, [rdx - 2]
+	add	rsi, 2
+	mov	r14, r15
+	cmp	rcx, 1
+	je	.LBB13_93
+	add	rdx, -2
+	xor	r14d, r14d
+.LBB13_90:
+	cmp	byte ptr [rsi + r14], 32
+	je	.LBB13_93
+	inc	r14
+	cmp	rdx, r14
+	jne	.LBB13_90
+	mov	r14, r15
+.LBB13_93:
+	mov	rdi, rsi
+	mov	rsi, r14
+	call	optional_trait_methods::num::parse_num::<usize>
+	test	al, 1
+	je	.LBB13_126
+	sub	r15, r14
+	je	.LBB13_68
+	add	r14, 2
+	mov	qword ptr [rbx + 8], r14
+	jmp	.LBB13_106
+.LBB13_96:
+	cmp	byte ptr [rax], 32
+	jne	.LBB13_59
+	mov	qword ptr [rbx + 8], 1
+	mov	byte ptr [rbx + 16], 3
+	mov	al, -1
+.LBB13_98:
+	mov	byte ptr [rbx], al
+	jmp	.LBB13_116
+.LBB13_99:
+	cmp	byte ptr [rax], 32
+	je	.LBB13_7
+.LBB13_100:
+	cmp	ecx, 63
+	je	.LBB13_119
+	cmp	ecx, 71
+	jne	.LBB13_59
+	jmp	.LBB13_110
+.LBB13_102:
+	mov	r14, rsp
+	lea	rsi, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::num::<i128>
+	cmp	dword ptr [r14], 1
+	je	.LBB13_113
+	cmp	qword ptr [rsp + 176], 0
+	je	.LBB13_127
+.LBB13_104:
+	mov	rax, qword ptr [rsp + 184]
+.LBB13_105:
+	mov	qword ptr [rbx + 8], rax
+.LBB13_106:
+	mov	byte ptr [rbx + 16], 3
+	jmp	.LBB13_115
+.LBB13_107:
+	mov	r8d, ecx
+	add	r8b, -63
+	je	.LBB13_119
+	rol	r8b, 5
+	movzx	r8d, r8b
+	cmp	r8d, 1
+	je	.LBB13_110
+	cmp	r8d, 7
+	jne	.LBB13_59
+	jmp	.LBB13_80
+.LBB13_110:
+	lea	rcx, [rdx - 1]
+.LBB13_111:
+	cmp	byte ptr [rax], 32
+	jne	.LBB13_59
+.LBB13_112:
+	lea	rsi, [rsp + 312]
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	mov	r14, rsp
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::regs::<i128>
+	cmp	byte ptr [r14], 0
+	je	.LBB13_117
+.LBB13_113:
+	mov	rax, qword ptr [rsp + 8]
+	mov	cl, byte ptr [rsp + 16]
+.LBB13_114:
+	mov	qword ptr [rbx + 8], rax
+	mov	byte ptr [rbx + 16], cl
+.LBB13_115:
+	mov	byte ptr [rbx], -1
+.LBB13_116:
+	add	rsp, 336
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB13_117:
+	lea	rsi, [rsp + 17]
+	mov	bpl, byte ptr [rsi - 1]
+	lea	rdi, [rsp + 168]
+	mov	edx, 143
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	cmp	qword ptr [rsp + 320], 0
+	je	.LBB13_123
+	mov	rax, qword ptr [rsp + 328]
+	mov	qword ptr [rbx + 8], rax
+	jmp	.LBB13_8
+.LBB13_119:
+	lea	rcx, [rdx - 1]
+.LBB13_120:
+	cmp	byte ptr [rax], 32
+	jne	.LBB13_59
+	mov	rsi, rsp
+	mov	qword ptr [rsi], rax
+	mov	qword ptr [rsi + 8], rcx
+	mov	qword ptr [rsi + 16], 1
+	test	rcx, rcx
+	setne	bpl
+	lea	r14, [rsp + 168]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::names
+	mov	al, byte ptr [r14 + 8]
+	cmp	al, -1
+	je	.LBB13_124
+	mov	rcx, qword ptr [rsp + 168]
+	mov	qword ptr [rbx + 8], rcx
+	jmp	.LBB13_60
+.LBB13_123:
+	mov	byte ptr [rbx + 16], bpl
+	lea	rdi, [rbx + 17]
+	lea	rsi, [rsp + 168]
+	mov	edx, 143
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	al, 6
+	jmp	.LBB13_32
+.LBB13_124:
+	cmp	qword ptr [rsp + 8], 0
+	je	.LBB13_129
+.LBB13_125:
+	mov	rax, qword ptr [rsp + 16]
+	jmp	.LBB13_105
+.LBB13_126:
+	mov	qword ptr [rbx + 8], 2
+	mov	byte ptr [rbx + 16], 2
+	jmp	.LBB13_115
+.LBB13_127:
+	mov	rax, qword ptr [rsp + 16]
+	mov	rcx, qword ptr [rsp + 24]
+	mov	byte ptr [rbx], 4
+	mov	qword ptr [rbx + 8], r15
+.LBB13_128:
+	mov	qword ptr [rbx + 16], rax
+	mov	qword ptr [rbx + 24], rcx
+	jmp	.LBB13_116
+.LBB13_129:
+	mov	al, byte ptr [rsp + 168]
+	jmp	.LBB13_85
+.Lfunc_end13:
+.LJTI13_0:
+	.long	.LBB13_41-.LJTI13_0
+	.long	.LBB13_59-.LJTI13_0
+	.long	.LBB13_74-.LJTI13_0
+	.long	.LBB13_69-.LJTI13_0
+	.long	.LBB13_59-.LJTI13_0
+	.long	.LBB13_66-.LJTI13_0
+	.long	.LBB13_59-.LJTI13_0
+	.long	.LBB13_79-.LJTI13_0
+
+.LCPI14_0:
+	.long	2
+	.long	0
+	.long	0
+	.long	0
+main:
+.Lfunc_begin14:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 4096
+	mov	qword ptr [rsp], 0
+	sub	rsp, 344
+	cmp	rdi, 2
+	jl	.LBB14_104
+	mov	rbx, rsi
+	mov	ebp, 1
+	lea	r12, [rsp + 1856]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 40], 0
+	mov	qword ptr [rsp + 568], 0
+	mov	qword ptr [rsp + 376], 0
+	mov	qword ptr [rsp + 384], 0
+	mov	qword ptr [rsp + 584], 0
+	mov	qword ptr [rsp + 576], 0
+	mov	qword ptr [rsp + 24], rsi
+	mov	qword ptr [rsp + 32], rdi
+.LBB14_2:
+	mov	r13, qword ptr [rbx + 8*rbp]
+	mov	rdi, r13
+	call	rcx
+	mov	rbx, rax
+	test	rax, rax
+	je	.LBB14_7
+	xor	r15d, r15d
+.LBB14_4:
+	cmp	byte ptr [r13 + r15], 61
+	je	.LBB14_9
+	inc	r15
+	cmp	rbx, r15
+	jne	.LBB14_4
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, rbx
+	jmp	.LBB14_8
+.LBB14_7:
+	mov	qword ptr [rsp + 16], 0
+	xor	r15d, r15d
+.LBB14_8:
+	jmp	.LBB14_10
+.LBB14_9:
+	lea	rax, [r15 + 1]
+	mov	rcx, rbx
+	sub	rcx, rax
+	mov	qword ptr [rsp + 616], rcx
+	lea	rax, [r15 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 16], rax
+.LBB14_10:
+	mov	qword ptr [rsp + 392], rbp
+	lea	rax, [rbp + 1]
+	mov	qword ptr [rsp], rax
+	mov	qword ptr [rsp + 1840], 0
+	mov	qword ptr [rsp + 1848], 7
+	mov	edx, 168
+	mov	rdi, r12
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.24]
+	call	r14
+	mov	r14d, 32
+.LBB14_11:
+	cmp	qword ptr [rsp + r14 + 1832], r15
+	jne	.LBB14_14
+	movzx	r12d, byte ptr [rsp + r14 + 1840]
+	mov	rbp, qword ptr [rsp + r14 + 1824]
+	mov	rdi, rbp
+	mov	rsi, r13
+	mov	rdx, r15
+	call	qword ptr [rip + bcmp@GOTPCREL]
+	test	eax, eax
+	jne	.LBB14_14
+	cmp	r12d, 255
+	jne	.LBB14_15
+.LBB14_14:
+	add	r14, 24
+	cmp	r14, 200
+	jne	.LBB14_11
+	jmp	.LBB14_212
+.LBB14_15:
+	cmp	r12, 255
+	je	.LBB14_212
+	cmp	r12b, 4
+	jae	.LBB14_19
+	mov	r11, qword ptr [rsp + 16]
+	test	r11, r11
+	mov	rbx, qword ptr [rsp + 24]
+	mov	r13, qword ptr [rsp + 32]
+	je	.LBB14_21
+	mov	r9, qword ptr [rsp]
+	mov	r10, qword ptr [rsp + 616]
+	jmp	.LBB14_24
+.LBB14_19:
+	cmp	qword ptr [rsp + 16], 0
+	mov	rbx, qword ptr [rsp + 24]
+	mov	r13, qword ptr [rsp + 32]
+	jne	.LBB14_221
+	mov	r11d, 1
+	mov	r9, qword ptr [rsp]
+	xor	r10d, r10d
+	jmp	.LBB14_24
+.LBB14_21:
+	cmp	qword ptr [rsp], r13
+	mov	r14, qword ptr [rsp + 392]
+	jge	.LBB14_222
+	mov	rdi, qword ptr [rbx + 8*r14 + 8]
+	test	rdi, rdi
+	je	.LBB14_222
+	mov	qword ptr [rsp + 16], rdi
+	call	qword ptr [rip + strlen@GOTPCREL]
+	mov	r11, qword ptr [rsp + 16]
+	mov	r10, rax
+	add	r14, 2
+	mov	r9, r14
+.LBB14_24:
+	cmp	r12b, 5
+	jne	.LBB14_26
+	mov	al, 1
+	mov	qword ptr [rsp + 584], rax
+	mov	rbp, r9
+	cmp	r9, r13
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 1856]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	jl	.LBB14_2
+	jmp	.LBB14_224
+.LBB14_26:
+	movzx	eax, r12b
+	mov	eax, eax
+	lea	rcx, [rip + .LJTI14_0]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB14_27:
+	test	r10, r10
+	je	.LBB14_239
+	mov	qword ptr [rsp + 592], r11
+	mov	qword ptr [rsp + 600], r10
+	mov	dword ptr [rsp + 12], eax
+	cmp	word ptr [rsp + 40], 0
+	mov	ax, 1
+	jmp	.LBB14_77
+.LBB14_29:
+	mov	al, 1
+	mov	qword ptr [rsp + 384], rax
+	jmp	.LBB14_51
+.LBB14_30:
+	mov	qword ptr [rsp + 608], r10
+	mov	qword ptr [rsp + 568], r11
+	jmp	.LBB14_51
+.LBB14_31:
+	cmp	r10, 8
+	je	.LBB14_42
+	cmp	r10, 4
+	jne	.LBB14_240
+	mov	eax, 4
+	cmp	byte ptr [r11], 101
+	jne	.LBB14_241
+	cmp	byte ptr [r11 + 1], 120
+	jne	.LBB14_241
+	cmp	byte ptr [r11 + 2], 105
+	jne	.LBB14_241
+	cmp	byte ptr [r11 + 3], 116
+	jne	.LBB14_241
+	jmp	.LBB14_50
+.LBB14_37:
+	test	r10, r10
+	je	.LBB14_238
+	lea	rcx, [r11 + 1]
+	lea	rdi, [r10 - 1]
+	movzx	eax, byte ptr [r11]
+	cmp	eax, 43
+	je	.LBB14_52
+	cmp	eax, 45
+	jne	.LBB14_53
+	mov	sil, 1
+	jmp	.LBB14_54
+.LBB14_41:
+	mov	al, 1
+	mov	qword ptr [rsp + 576], rax
+	jmp	.LBB14_51
+.LBB14_42:
+	mov	eax, 8
+	cmp	byte ptr [r11], 99
+	jne	.LBB14_241
+	cmp	byte ptr [r11 + 1], 111
+	jne	.LBB14_241
+	cmp	byte ptr [r11 + 2], 110
+	jne	.LBB14_241
+	cmp	byte ptr [r11 + 3], 116
+	jne	.LBB14_241
+	cmp	byte ptr [r11 + 4], 105
+	jne	.LBB14_241
+	cmp	byte ptr [r11 + 5], 110
+	jne	.LBB14_241
+	cmp	byte ptr [r11 + 6], 117
+	jne	.LBB14_241
+	cmp	byte ptr [r11 + 7], 101
+	jne	.LBB14_241
+.LBB14_50:
+	cmp	r10, 8
+	setne	al
+	mov	qword ptr [rsp + 376], rax
+.LBB14_51:
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 1856]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	rbp, r9
+	jmp	.LBB14_78
+.LBB14_52:
+	xor	esi, esi
+	jmp	.LBB14_54
+.LBB14_53:
+	xor	esi, esi
+	mov	rdi, r10
+	mov	rcx, r11
+.LBB14_54:
+	cmp	rdi, 1
+	jbe	.LBB14_61
+	mov	al, byte ptr [rcx]
+	cmp	al, 48
+	jne	.LBB14_63
+	movzx	eax, byte ptr [rcx + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB14_64
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB14_80
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB14_79
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB14_64
+	mov	r8b, 16
+	mov	r12b, 1
+	jmp	.LBB14_82
+.LBB14_61:
+	test	rdi, rdi
+	je	.LBB14_253
+	mov	al, byte ptr [rcx]
+.LBB14_63:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB14_253
+.LBB14_64:
+	add	rdi, rcx
+	mov	rax, rdi
+	dec	rax
+	je	.LBB14_66
+	cmp	byte ptr [rax], 95
+	je	.LBB14_253
+.LBB14_66:
+	xor	r14d, r14d
+	xor	eax, eax
+.LBB14_67:
+	cmp	rcx, rdi
+	je	.LBB14_75
+	mov	r8b, byte ptr [rcx]
+	inc	rcx
+	cmp	r8b, 95
+	jne	.LBB14_70
+	test	r14b, 1
+	mov	r14d, 0
+	je	.LBB14_253
+	jmp	.LBB14_67
+.LBB14_70:
+	add	r8b, -48
+	cmp	r8b, 9
+	ja	.LBB14_253
+	mov	dx, 10
+	mul	dx
+	jo	.LBB14_253
+	movzx	edx, r8b
+	mov	r14b, 1
+	test	sil, sil
+	je	.LBB14_74
+	sub	ax, dx
+	jb	.LBB14_253
+	jmp	.LBB14_67
+.LBB14_74:
+	add	ax, dx
+	jb	.LBB14_253
+	jmp	.LBB14_67
+.LBB14_75:
+	mov	dword ptr [rsp + 12], eax
+.LBB14_76:
+	cmp	word ptr [rsp + 40], 0
+	mov	ax, 2
+.LBB14_77:
+	mov	qword ptr [rsp + 40], rax
+	mov	r14, qword ptr [rip + memcpy@GOTPCREL]
+	lea	r12, [rsp + 1856]
+	mov	rcx, qword ptr [rip + strlen@GOTPCREL]
+	mov	rbp, r9
+	jne	.LBB14_234
+.LBB14_78:
+	cmp	rbp, r13
+	jl	.LBB14_2
+	jmp	.LBB14_223
+.LBB14_79:
+	mov	r8b, 8
+	jmp	.LBB14_81
+.LBB14_80:
+	mov	r8b, 2
+.LBB14_81:
+	xor	r12d, r12d
+.LBB14_82:
+	mov	r14, r10
+	cmp	rdi, 2
+	je	.LBB14_252
+	lea	r10, [rcx + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jb	.LBB14_87
+	test	r12b, r12b
+	je	.LBB14_252
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB14_252
+	add	al, -87
+	mov	edx, eax
+.LBB14_87:
+	cmp	dl, r8b
+	jae	.LBB14_252
+	add	rcx, rdi
+	mov	rax, rcx
+	dec	rax
+	je	.LBB14_90
+	cmp	byte ptr [rax], 95
+	je	.LBB14_252
+.LBB14_90:
+	movzx	edi, r8b
+	xor	eax, eax
+	mov	dword ptr [rsp + 12], 0
+.LBB14_91:
+	cmp	r10, rcx
+	je	.LBB14_76
+	mov	dl, byte ptr [r10]
+	inc	r10
+	cmp	dl, 95
+	jne	.LBB14_94
+	test	al, 1
+	mov	eax, 0
+	je	.LBB14_252
+	jmp	.LBB14_91
+.LBB14_94:
+	mov	qword ptr [rsp + 16], r11
+	lea	r11d, [rdx - 48]
+	cmp	r11b, 10
+	jb	.LBB14_98
+	test	r12b, r12b
+	je	.LBB14_251
+	or	dl, 32
+	lea	eax, [rdx - 97]
+	cmp	al, 5
+	ja	.LBB14_251
+	add	dl, -87
+	mov	r11d, edx
+.LBB14_98:
+	cmp	r11b, r8b
+	jae	.LBB14_251
+	mov	eax, dword ptr [rsp + 12]
+	mul	di
+	mov	dword ptr [rsp + 12], eax
+	jo	.LBB14_251
+	movzx	edx, r11b
+	mov	al, 1
+	test	sil, sil
+	je	.LBB14_102
+	mov	r11d, dword ptr [rsp + 12]
+	sub	r11w, dx
+	jmp	.LBB14_103
+.LBB14_102:
+	mov	r11d, dword ptr [rsp + 12]
+	add	r11w, dx
+.LBB14_103:
+	mov	dword ptr [rsp + 12], r11d
+	mov	r11, qword ptr [rsp + 16]
+	jb	.LBB14_252
+	jmp	.LBB14_91
+.LBB14_104:
+	xor	r15d, r15d
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB14_105:
+	pxor	xmm0, xmm0
+	lea	r14, [rsp + 944]
+	movdqa	xmmword ptr [r14 + 672], xmm0
+	movdqa	xmmword ptr [r14 + 656], xmm0
+	movdqa	xmmword ptr [r14 + 640], xmm0
+	movdqa	xmmword ptr [r14 + 624], xmm0
+	movdqa	xmmword ptr [r14 + 608], xmm0
+	movdqa	xmmword ptr [r14 + 592], xmm0
+	movdqa	xmmword ptr [r14 + 576], xmm0
+	lea	rdi, [rsp + 1872]
+	xor	ebx, ebx
+	mov	r13, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 528
+	xor	esi, esi
+	call	r13
+	mov	qword ptr [r14 + 568], r12
+	mov	qword ptr [r14 + 560], r15
+	pxor	xmm0, xmm0
+	movdqu	xmmword ptr [r14 + 696], xmm0
+	mov	qword ptr [r14 + 688], 1
+	movabs	rax, -9223372036854775808
+	mov	qword ptr [r14 + 712], rax
+	movabs	rax, 9223372036854775807
+	mov	qword ptr [r14 + 728], rax
+	mov	qword ptr [r14 + 720], -1
+	movdqa	xmmword ptr [r14 - 16], xmm0
+	lea	r15, [rsp + 1840]
+	mov	edx, 560
+	mov	rdi, r14
+	mov	rsi, r15
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 2880]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 1840]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 3928]
+	mov	edx, 512
+	xor	esi, esi
+	call	r13
+	mov	dword ptr [rsp + 392], ebp
+	shr	ebp, 8
+	mov	dword ptr [rsp + 16], ebp
+	mov	r13, qword ptr [rip + read@GOTPCREL]
+	xor	ecx, ecx
+	mov	dword ptr [rsp], eax
+.LBB14_106:
+	mov	rdi, qword ptr [rsp + 2864]
+	mov	rax, qword ptr [rsp + 2872]
+	xor	r12d, r12d
+	mov	rdx, rcx
+	xor	ebp, ebp
+.LBB14_107:
+	mov	rsi, rax
+	mov	rax, rdi
+.LBB14_108:
+	cmp	rsi, rax
+	jb	.LBB14_112
+	mov	qword ptr [rsp + 3904], rbx
+	cmp	rdx, 1025
+	mov	rsi, r14
+	mov	eax, 1
+	cmovae	rsi, rax
+	cmovae	rdx, rbx
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	mov	edx, 1024
+	xor	edi, edi
+	mov	rsi, r15
+	call	r13
+	test	rax, rax
+	js	.LBB14_122
+	je	.LBB14_123
+	mov	qword ptr [rsp + 2864], rax
+	mov	qword ptr [rsp + 2872], 0
+	xor	ecx, ecx
+	xor	edx, edx
+	xor	esi, esi
+.LBB14_112:
+	cmp	rax, rsi
+	jb	.LBB14_216
+	cmp	rax, 1024
+	ja	.LBB14_216
+	cmp	rsi, rax
+	je	.LBB14_108
+	inc	rsi
+	mov	rdi, rax
+	neg	rdi
+.LBB14_116:
+	mov	r8b, byte ptr [rsp + rsi + 1839]
+	mov	qword ptr [rsp + 2872], rsi
+	cmp	r8b, 10
+	je	.LBB14_126
+	cmp	rbp, 511
+	jbe	.LBB14_119
+	mov	r12b, 1
+	jmp	.LBB14_120
+.LBB14_119:
+	mov	byte ptr [rsp + rbp + 3928], r8b
+	inc	rbp
+.LBB14_120:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB14_116
+	mov	rdi, rax
+	jmp	.LBB14_107
+.LBB14_122:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB14_123:
+	test	rbp, rbp
+	setne	al
+	or	al, r12b
+	test	al, 1
+	je	.LBB14_220
+	test	r12b, 1
+	jne	.LBB14_130
+	mov	ecx, 0
+	mov	edx, 0
+	cmp	rbp, 512
+	ja	.LBB14_217
+	jmp	.LBB14_128
+.LBB14_126:
+	test	r12b, 1
+	jne	.LBB14_130
+	cmp	rbp, 512
+	ja	.LBB14_216
+.LBB14_128:
+	test	rbp, rbp
+	setne	al
+	test	byte ptr [rsp + 16], al
+	jne	.LBB14_131
+	test	rbp, rbp
+	je	.LBB14_106
+	jmp	.LBB14_132
+.LBB14_130:
+	mov	qword ptr [rsp + 216], 3
+	mov	qword ptr [rsp + 224], 512
+	mov	byte ptr [rsp + 232], 4
+	mov	byte ptr [rsp + 208], -1
+	lea	rdi, [rsp + 768]
+	mov	rsi, r14
+	lea	rdx, [rsp + 208]
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+	mov	r12d, dword ptr [rsp]
+	mov	rbp, qword ptr [rsp + 24]
+	jmp	.LBB14_207
+.LBB14_131:
+	mov	rdi, r14
+	lea	rsi, [rsp + 3928]
+	mov	rdx, rbp
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB14_132:
+	lea	r12, [rsp + 1680]
+	mov	rdi, r12
+	lea	rsi, [rsp + 3928]
+	mov	rdx, rbp
+	call	<optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget> as optional_trait_methods::state_machine::Controller>::parse_command
+	cmp	byte ptr [rsp + 1680], -1
+	je	.LBB14_137
+	mov	edx, 160
+	lea	rdi, [rsp + 400]
+	mov	rsi, r12
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	movzx	r12d, byte ptr [rsp + 400]
+	lea	rbp, [r12 - 6]
+	cmp	r12, 7
+	cmovb	rbp, rbx
+	lea	rcx, [rip + .LJTI14_5]
+	movsxd	rax, dword ptr [rcx + 4*rbp]
+	add	rax, rcx
+	jmp	rax
+.LBB14_134:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 15
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::checkpoint
+	lea	rcx, [rip + .LJTI14_6]
+	movsxd	rax, dword ptr [rcx + 4*rbp]
+	add	rax, rcx
+	jmp	rax
+.LBB14_135:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI14_7]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB14_136:
+	mov	rbp, qword ptr [rsp + 1504]
+	mov	rdx, qword ptr [rsp + 1512]
+	jmp	.LBB14_189
+.LBB14_137:
+	mov	eax, dword ptr [rsp + 1696]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rbp, qword ptr [rsp + 24]
+	cmovne	rbp, qword ptr [rsp + 1688]
+	sete	cl
+	mov	r12d, dword ptr [rsp]
+	movzx	r12d, r12b
+	cmovne	r12d, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 216], rcx
+	mov	qword ptr [rsp + 224], rbp
+	mov	byte ptr [rsp + 232], r12b
+	mov	byte ptr [rsp + 208], -1
+	jmp	.LBB14_206
+.LBB14_138:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 14
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 408]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::undo
+	test	rax, rax
+	mov	rbp, qword ptr [rsp + 24]
+	je	.LBB14_184
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 224], rax
+	mov	qword ptr [rsp + 232], rdx
+	jmp	.LBB14_145
+.LBB14_140:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI14_8]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB14_141:
+	movdqa	xmm0, xmmword ptr [rsp + 1504]
+	mov	byte ptr [rsp + 208], 1
+	movdqa	xmmword ptr [rsp + 224], xmm0
+	jmp	.LBB14_177
+.LBB14_142:
+	movdqa	xmm0, xmmword ptr [rsp + 416]
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI14_0]
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	jne	.LBB14_147
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 28
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	mov	r8, qword ptr [rsp + 456]
+	mov	rcx, qword ptr [rsp + 448]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::scale_bounds
+	test	rax, rax
+	mov	rbp, qword ptr [rsp + 24]
+	je	.LBB14_184
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 224], rax
+	mov	qword ptr [rsp + 232], 26
+.LBB14_145:
+	mov	byte ptr [rsp + 208], -1
+	jmp	.LBB14_185
+.LBB14_146:
+	mov	r12, qword ptr [rsp + 416]
+	jmp	.LBB14_148
+.LBB14_147:
+	mov	r12, qword ptr [rsp + 416]
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 15
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::checkpoint
+.LBB14_148:
+	test	r12, r12
+	je	.LBB14_156
+	cmp	r12, 1
+	lea	rax, [rsp + 48]
+	jne	.LBB14_157
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	qword ptr [rsp + 48], rcx
+	mov	qword ptr [rsp + 56], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::scale_factor
+	jmp	.LBB14_201
+.LBB14_151:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 13
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	test	byte ptr [rsp + 416], 1
+	je	.LBB14_158
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::rem
+	jmp	.LBB14_159
+.LBB14_153:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 16
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [rsp + 401]
+	test	eax, eax
+	je	.LBB14_164
+	cmp	eax, 1
+	jne	.LBB14_165
+	lea	rdi, [rsp + 928]
+	jmp	.LBB14_166
+.LBB14_156:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 13
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::mul
+	jmp	.LBB14_159
+.LBB14_157:
+	lea	rcx, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 48], rcx
+	mov	qword ptr [rsp + 56], 28
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	mov	r8, qword ptr [rsp + 456]
+	mov	rcx, qword ptr [rsp + 448]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::scale_bounds
+	jmp	.LBB14_159
+.LBB14_158:
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::div
+.LBB14_159:
+	test	rax, rax
+	mov	r12d, dword ptr [rsp]
+	je	.LBB14_162
+	mov	rbp, rax
+	mov	qword ptr [rsp + 32], rdx
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::rollback
+	test	rax, rax
+	je	.LBB14_163
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 224], rax
+	mov	qword ptr [rsp + 232], 21
+	mov	byte ptr [rsp + 208], -1
+	jmp	.LBB14_205
+.LBB14_162:
+	xor	eax, eax
+	jmp	.LBB14_204
+.LBB14_163:
+	mov	al, -1
+	mov	rdx, qword ptr [rsp + 32]
+	jmp	.LBB14_204
+.LBB14_164:
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::inc
+	jmp	.LBB14_201
+.LBB14_165:
+	lea	r12, [rsp + 928]
+	mov	rdi, r12
+	call	optional_trait_methods::using_fn::targets::advanced::inc
+	mov	rdi, r12
+.LBB14_166:
+	call	optional_trait_methods::using_fn::targets::advanced::dec
+	jmp	.LBB14_201
+.LBB14_167:
+	mov	rcx, qword ptr [rsp + 424]
+	mov	rsi, qword ptr [rsp + 408]
+	mov	rdx, qword ptr [rsp + 416]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::write_reg
+	jmp	.LBB14_183
+.LBB14_168:
+	mov	al, byte ptr [rsp + 402]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [rsp + 401], 0
+	mov	ecx, 63
+	cmove	eax, ecx
+	mov	byte ptr [rsp + 208], 2
+	mov	byte ptr [rsp + 209], al
+	jmp	.LBB14_177
+.LBB14_169:
+	mov	rsi, qword ptr [rsp + 408]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	mov	byte ptr [rsp + 208], 1
+	mov	qword ptr [rsp + 232], rdx
+	mov	qword ptr [rsp + 224], rax
+	jmp	.LBB14_177
+.LBB14_170:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 15
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::checkpoint
+.LBB14_171:
+	mov	rsi, qword ptr [rsp + 416]
+	mov	rdx, qword ptr [rsp + 424]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::set_state
+	jmp	.LBB14_201
+.LBB14_172:
+	mov	edx, 136
+	lea	rdi, [rsp + 624]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB14_173:
+	lea	rdi, [rsp + 928]
+	mov	rsi, rbp
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	r12, 7
+	ja	.LBB14_175
+	shl	r12, 4
+	mov	qword ptr [rsp + r12 + 624], rax
+	mov	qword ptr [rsp + r12 + 632], rdx
+	mov	r12, qword ptr [rsp + 752]
+	inc	r12
+	mov	qword ptr [rsp + 752], r12
+.LBB14_175:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB14_173
+	mov	edx, 144
+	lea	rdi, [rsp + 63]
+	lea	rsi, [rsp + 624]
+	mov	r12, qword ptr [rip + memcpy@GOTPCREL]
+	call	r12
+	mov	byte ptr [rsp + 208], 3
+	mov	edx, 159
+	lea	rdi, [rsp + 209]
+	lea	rsi, [rsp + 48]
+	call	r12
+.LBB14_177:
+	mov	r12d, dword ptr [rsp]
+	jmp	.LBB14_205
+.LBB14_178:
+	mov	edx, 136
+	lea	rdi, [rsp + 48]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB14_179:
+	lea	rdi, [rsp + 928]
+	mov	rsi, rbp
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	r12, 7
+	ja	.LBB14_181
+	shl	r12, 4
+	mov	qword ptr [rsp + r12 + 48], rax
+	mov	qword ptr [rsp + r12 + 56], rdx
+	mov	r12, qword ptr [rsp + 176]
+	inc	r12
+	mov	qword ptr [rsp + 176], r12
+.LBB14_181:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB14_179
+	lea	rdi, [rsp + 416]
+	lea	rsi, [rsp + 928]
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB14_183:
+	mov	rbp, qword ptr [rsp + 24]
+.LBB14_184:
+	mov	byte ptr [rsp + 208], 0
+.LBB14_185:
+	mov	r12d, dword ptr [rsp]
+	jmp	.LBB14_206
+.LBB14_186:
+	mov	rcx, qword ptr [rsp + 424]
+	mov	rsi, qword ptr [rsp + 408]
+	mov	rdx, qword ptr [rsp + 416]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::write_reg
+	jmp	.LBB14_201
+.LBB14_187:
+	mov	al, byte ptr [rsp + 402]
+	and	al, 63
+	cmp	byte ptr [rsp + 401], 0
+	movzx	ecx, al
+	mov	eax, 63
+	cmove	ecx, eax
+	mov	al, 2
+	jmp	.LBB14_202
+.LBB14_188:
+	mov	rsi, qword ptr [rsp + 408]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	mov	rbp, rax
+.LBB14_189:
+	mov	al, 1
+	jmp	.LBB14_195
+.LBB14_190:
+	mov	edx, 136
+	lea	rdi, [rsp + 48]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB14_191:
+	lea	rdi, [rsp + 928]
+	mov	rsi, rbp
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	r12, 7
+	ja	.LBB14_193
+	shl	r12, 4
+	mov	qword ptr [rsp + r12 + 48], rax
+	mov	qword ptr [rsp + r12 + 56], rdx
+	mov	r12, qword ptr [rsp + 176]
+	inc	r12
+	mov	qword ptr [rsp + 176], r12
+.LBB14_193:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB14_191
+	mov	rbp, qword ptr [rsp + 48]
+	mov	rdx, qword ptr [rsp + 56]
+	mov	ecx, 16
+	lea	rdi, [rsp + 624]
+	lea	rsi, [rsp + 64]
+	rep movsq es:[rdi], [rsi]
+	mov	al, 3
+.LBB14_195:
+	jmp	.LBB14_203
+.LBB14_196:
+	mov	edx, 136
+	lea	rdi, [rsp + 48]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r12d, r12d
+	xor	ebp, ebp
+.LBB14_197:
+	lea	rdi, [rsp + 928]
+	mov	rsi, rbp
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	r12, 7
+	ja	.LBB14_199
+	shl	r12, 4
+	mov	qword ptr [rsp + r12 + 48], rax
+	mov	qword ptr [rsp + r12 + 56], rdx
+	mov	r12, qword ptr [rsp + 176]
+	inc	r12
+	mov	qword ptr [rsp + 176], r12
+.LBB14_199:
+	inc	rbp
+	cmp	rbp, 8
+	jne	.LBB14_197
+	lea	rdi, [rsp + 416]
+	lea	rsi, [rsp + 928]
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB14_201:
+	xor	eax, eax
+.LBB14_202:
+.LBB14_203:
+	mov	r12d, dword ptr [rsp]
+.LBB14_204:
+	mov	byte ptr [rsp + 208], al
+	mov	byte ptr [rsp + 209], cl
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 232], rdx
+	mov	qword ptr [rsp + 224], rbp
+	mov	ecx, 16
+	lea	rdi, [rsp + 240]
+	lea	rsi, [rsp + 624]
+	rep movsq es:[rdi], [rsi]
+.LBB14_205:
+	mov	rbp, qword ptr [rsp + 24]
+.LBB14_206:
+	lea	rdi, [rsp + 768]
+	mov	rsi, r14
+	lea	rdx, [rsp + 208]
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>
+.LBB14_207:
+	cmp	byte ptr [rsp + 768], -1
+	jne	.LBB14_211
+	mov	rax, qword ptr [rsp + 776]
+	cmp	byte ptr [rsp + 392], 0
+	jne	.LBB14_226
+	cmp	eax, 1
+	je	.LBB14_229
+	cmp	eax, 4
+	je	.LBB14_228
+.LBB14_211:
+	mov	qword ptr [rsp + 24], rbp
+	mov	dword ptr [rsp], r12d
+	mov	rcx, qword ptr [rsp + 3904]
+	jmp	.LBB14_106
+.LBB14_212:
+	xor	ecx, ecx
+	mov	rbp, r13
+	mov	r15, rbx
+.LBB14_213:
+.LBB14_214:
+	lea	rdi, [rsp + 1840]
+	mov	qword ptr [rdi], rcx
+	mov	qword ptr [rdi + 8], rbp
+	mov	qword ptr [rdi + 16], r15
+	mov	qword ptr [rdi + 24], r11
+	mov	qword ptr [rdi + 32], r10
+.LBB14_215:
+	call	optional_trait_methods::usage_error
+	mov	ebx, 1
+	jmp	.LBB14_219
+.LBB14_216:
+	mov	rdx, rcx
+.LBB14_217:
+	xor	eax, eax
+	mov	qword ptr [rsp + 3904], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, 1
+	mov	rsi, r14
+.LBB14_218:
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+.LBB14_219:
+	mov	rax, rbx
+	add	rsp, 4440
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB14_220:
+	xor	edx, edx
+	jmp	.LBB14_217
+.LBB14_221:
+	mov	ecx, 3
+	jmp	.LBB14_213
+.LBB14_222:
+	mov	ecx, 1
+	jmp	.LBB14_213
+.LBB14_223:
+	test	byte ptr [rsp + 584], 1
+	je	.LBB14_232
+.LBB14_224:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.73]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 31
+.LBB14_225:
+	mov	edi, 2
+	call	r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	ebx, 1
+	mov	edx, 1
+	mov	edi, 2
+	call	r14
+	jmp	.LBB14_219
+.LBB14_226:
+	cmp	eax, 1
+	je	.LBB14_229
+	cmp	eax, 4
+	jne	.LBB14_231
+.LBB14_228:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 2880]
+	mov	edx, 23
+	jmp	.LBB14_230
+.LBB14_229:
+	mov	rsi, qword ptr [rsp + 784]
+	mov	rdx, qword ptr [rsp + 792]
+	lea	rdi, [rsp + 2880]
+.LBB14_230:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 2880]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB14_231:
+	mov	ebx, 1
+	mov	rdx, qword ptr [rsp + 3904]
+	jmp	.LBB14_217
+.LBB14_232:
+	test	byte ptr [rsp + 576], 1
+	je	.LBB14_235
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.34]
+	xor	ebx, ebx
+	mov	edx, 823
+	mov	edi, 1
+	xor	ecx, ecx
+	call	optional_trait_methods::connection::write_fd
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	mov	edx, 1
+	mov	edi, 1
+	jmp	.LBB14_218
+.LBB14_234:
+	mov	ecx, 4
+	jmp	.LBB14_213
+.LBB14_235:
+	mov	r15, qword ptr [rsp + 568]
+	test	r15, r15
+	je	.LBB14_242
+	lea	rbx, [rsp + 2880]
+	mov	rdi, rbx
+	mov	rsi, r15
+	mov	r14, qword ptr [rsp + 608]
+	mov	rdx, r14
+	call	optional_trait_methods::num::parse_num::<i128>
+	test	byte ptr [rbx], 1
+	je	.LBB14_246
+	mov	r15, qword ptr [rsp + 2896]
+	mov	r12, qword ptr [rsp + 2904]
+	jmp	.LBB14_243
+.LBB14_238:
+	xor	r10d, r10d
+	jmp	.LBB14_253
+.LBB14_239:
+	mov	ecx, 2
+	xor	r10d, r10d
+	jmp	.LBB14_214
+.LBB14_240:
+	mov	rax, r10
+.LBB14_241:
+	mov	ecx, 2
+	mov	r10, rax
+	jmp	.LBB14_214
+.LBB14_242:
+	xor	r15d, r15d
+	xor	r12d, r12d
+.LBB14_243:
+	mov	rax, qword ptr [rsp + 40]
+	test	ax, ax
+	je	.LBB14_247
+	movzx	eax, ax
+	cmp	eax, 1
+	jne	.LBB14_248
+	mov	rdi, qword ptr [rsp + 592]
+	mov	rsi, qword ptr [rsp + 600]
+	call	<optional_trait_methods::connection::Listener>::unix
+	mov	ebx, edx
+	jmp	.LBB14_249
+.LBB14_246:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.19]
+	lea	rdi, [rsp + 1840]
+	mov	qword ptr [rdi + 8], rax
+	mov	qword ptr [rdi + 16], 15
+	mov	qword ptr [rdi + 24], r15
+	mov	qword ptr [rdi + 32], r14
+	mov	qword ptr [rdi], 2
+	jmp	.LBB14_215
+.LBB14_247:
+	movzx	eax, byte ptr [rsp + 384]
+	shl	eax, 8
+	movzx	ebp, byte ptr [rsp + 376]
+	or	ebp, eax
+	jmp	.LBB14_105
+.LBB14_248:
+	mov	ebp, dword ptr [rsp + 12]
+	mov	edi, ebp
+	call	<optional_trait_methods::connection::Listener>::tcp_loopback
+	mov	ebx, edx
+	test	bp, bp
+	setne	cl
+	or	cl, al
+	test	cl, 1
+	je	.LBB14_254
+.LBB14_249:
+	cmp	eax, 1
+	jne	.LBB14_255
+	mov	r14d, 1
+	mov	ebp, ebx
+	jmp	.LBB14_256
+.LBB14_251:
+	mov	r11, qword ptr [rsp + 16]
+.LBB14_252:
+	mov	r10, r14
+.LBB14_253:
+	mov	ecx, 2
+	jmp	.LBB14_214
+.LBB14_254:
+	mov	edi, ebx
+	call	optional_trait_methods::report_port
+.LBB14_255:
+	mov	edi, ebx
+	call	<optional_trait_methods::connection::Listener>::accept
+	mov	r14d, eax
+	mov	ebp, edx
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+.LBB14_256:
+	test	r14b, 1
+	je	.LBB14_258
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.72]
+	mov	r14, qword ptr [rip + write@GOTPCREL]
+	mov	edx, 29
+	jmp	.LBB14_225
+.LBB14_258:
+	pxor	xmm0, xmm0
+	lea	r14, [rsp + 944]
+	movdqa	xmmword ptr [r14 + 672], xmm0
+	movdqa	xmmword ptr [r14 + 656], xmm0
+	movdqa	xmmword ptr [r14 + 640], xmm0
+	movdqa	xmmword ptr [r14 + 624], xmm0
+	movdqa	xmmword ptr [r14 + 608], xmm0
+	movdqa	xmmword ptr [r14 + 592], xmm0
+	movdqa	xmmword ptr [r14 + 576], xmm0
+	lea	rdi, [rsp + 1872]
+	xor	ebx, ebx
+	mov	r13, qword ptr [rip + memset@GOTPCREL]
+	mov	edx, 528
+	xor	esi, esi
+	call	r13
+	mov	qword ptr [r14 + 568], r12
+	mov	qword ptr [r14 + 560], r15
+	pxor	xmm0, xmm0
+	movdqu	xmmword ptr [r14 + 696], xmm0
+	mov	qword ptr [r14 + 688], 1
+	movabs	rax, -9223372036854775808
+	mov	qword ptr [r14 + 712], rax
+	movabs	rax, 9223372036854775807
+	mov	qword ptr [r14 + 728], rax
+	mov	qword ptr [r14 + 720], -1
+	movdqa	xmmword ptr [r14 - 16], xmm0
+	lea	r15, [rsp + 1840]
+	mov	edx, 560
+	mov	rdi, r14
+	mov	rsi, r15
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	lea	r14, [rsp + 2880]
+	mov	edx, 1032
+	mov	rdi, r14
+	xor	esi, esi
+	call	r13
+	mov	dword ptr [r14 + 1032], ebp
+	lea	rdi, [rsp + 1840]
+	mov	edx, 1040
+	xor	esi, esi
+	call	r13
+	lea	rdi, [rsp + 3928]
+	mov	edx, 512
+	xor	esi, esi
+	call	r13
+	xor	edx, edx
+	mov	dword ptr [rsp + 16], eax
+.LBB14_259:
+	mov	rdi, qword ptr [rsp + 2864]
+	mov	rcx, qword ptr [rsp + 2872]
+	xor	r13d, r13d
+	mov	rax, rdx
+	xor	r12d, r12d
+.LBB14_260:
+	mov	rsi, rcx
+	mov	rcx, rdi
+.LBB14_261:
+	cmp	rsi, rcx
+	jb	.LBB14_265
+	mov	qword ptr [rsp + 3904], rbx
+	cmp	rax, 1025
+	mov	rsi, r14
+	mov	ecx, 1
+	cmovae	rsi, rcx
+	cmovae	rax, rbx
+	mov	edi, ebp
+	mov	rdx, rax
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	lea	rdi, [rsp + 208]
+	mov	esi, ebp
+	mov	rdx, r15
+	call	optional_trait_methods::connection::read_fd
+	cmp	byte ptr [rsp + 208], 0
+	jne	.LBB14_275
+	mov	rcx, qword ptr [rsp + 216]
+	test	rcx, rcx
+	je	.LBB14_275
+	mov	qword ptr [rsp + 2864], rcx
+	mov	qword ptr [rsp + 2872], 0
+	xor	edx, edx
+	xor	eax, eax
+	xor	esi, esi
+.LBB14_265:
+	cmp	rcx, rsi
+	jb	.LBB14_358
+	cmp	rcx, 1024
+	ja	.LBB14_358
+	cmp	rsi, rcx
+	je	.LBB14_261
+	inc	rsi
+	mov	rdi, rcx
+	neg	rdi
+.LBB14_269:
+	mov	r8b, byte ptr [rsp + rsi + 1839]
+	mov	qword ptr [rsp + 2872], rsi
+	cmp	r8b, 10
+	je	.LBB14_278
+	cmp	r12, 511
+	jbe	.LBB14_272
+	mov	r13b, 1
+	jmp	.LBB14_273
+.LBB14_272:
+	mov	byte ptr [rsp + r12 + 3928], r8b
+	inc	r12
+.LBB14_273:
+	lea	r8, [rdi + rsi]
+	inc	r8
+	inc	rsi
+	cmp	r8, 1
+	jne	.LBB14_269
+	mov	rdi, rcx
+	jmp	.LBB14_260
+.LBB14_275:
+	test	r12, r12
+	setne	al
+	or	al, r13b
+	test	al, 1
+	je	.LBB14_357
+	test	r13b, 1
+	jne	.LBB14_282
+	mov	edx, 0
+	cmp	r12, 512
+	jbe	.LBB14_280
+	jmp	.LBB14_357
+.LBB14_278:
+	test	r13b, 1
+	jne	.LBB14_282
+	cmp	r12, 512
+	ja	.LBB14_358
+.LBB14_280:
+	test	r12, r12
+	setne	al
+	test	byte ptr [rsp + 384], al
+	jne	.LBB14_284
+	test	r12, r12
+	je	.LBB14_259
+	jmp	.LBB14_285
+.LBB14_282:
+	mov	qword ptr [rsp + 216], 3
+	mov	qword ptr [rsp + 224], 512
+	mov	byte ptr [rsp + 232], 4
+	jmp	.LBB14_283
+.LBB14_284:
+	mov	rdi, r14
+	lea	rsi, [rsp + 3928]
+	mov	rdx, r12
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	mov	edx, 1
+	mov	rdi, r14
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB14_285:
+	lea	rdi, [rsp + 1680]
+	lea	rsi, [rsp + 3928]
+	mov	rdx, r12
+	call	<optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget> as optional_trait_methods::state_machine::Controller>::parse_command
+	cmp	byte ptr [rsp + 1680], -1
+	je	.LBB14_290
+	mov	edx, 160
+	lea	rdi, [rsp + 400]
+	lea	rsi, [rsp + 1680]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	movzx	r12d, byte ptr [rsp + 400]
+	lea	r13, [r12 - 6]
+	cmp	r12, 7
+	cmovb	r13, rbx
+	lea	rcx, [rip + .LJTI14_1]
+	movsxd	rax, dword ptr [rcx + 4*r13]
+	add	rax, rcx
+	jmp	rax
+.LBB14_287:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 15
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::checkpoint
+	lea	rcx, [rip + .LJTI14_2]
+	movsxd	rax, dword ptr [rcx + 4*r13]
+	add	rax, rcx
+	jmp	rax
+.LBB14_288:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI14_3]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB14_289:
+	mov	r12, qword ptr [rsp + 1504]
+	mov	rbp, qword ptr [rsp + 1512]
+	jmp	.LBB14_336
+.LBB14_290:
+	mov	eax, dword ptr [rsp + 1696]
+	xor	ecx, ecx
+	cmp	al, 1
+	mov	rdx, qword ptr [rsp]
+	cmovne	rdx, qword ptr [rsp + 1688]
+	sete	cl
+	mov	esi, dword ptr [rsp + 16]
+	movzx	esi, sil
+	cmovne	esi, eax
+	xor	rcx, 3
+	mov	qword ptr [rsp + 216], rcx
+	mov	qword ptr [rsp], rdx
+	mov	qword ptr [rsp + 224], rdx
+	mov	dword ptr [rsp + 16], esi
+	mov	byte ptr [rsp + 232], sil
+.LBB14_283:
+	mov	byte ptr [rsp + 208], -1
+	jmp	.LBB14_352
+.LBB14_291:
+	mov	eax, r12d
+	lea	rcx, [rip + .LJTI14_4]
+	movsxd	rax, dword ptr [rcx + 4*rax]
+	add	rax, rcx
+	jmp	rax
+.LBB14_292:
+	movdqa	xmm0, xmmword ptr [rsp + 1504]
+	mov	byte ptr [rsp + 208], 1
+	movdqa	xmmword ptr [rsp + 224], xmm0
+	jmp	.LBB14_352
+.LBB14_293:
+	movdqa	xmm0, xmmword ptr [rsp + 416]
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI14_0]
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	jne	.LBB14_304
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 28
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	mov	r8, qword ptr [rsp + 456]
+	mov	rcx, qword ptr [rsp + 448]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::scale_bounds
+	test	rax, rax
+	je	.LBB14_333
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 224], rax
+	mov	qword ptr [rsp + 232], 26
+	jmp	.LBB14_283
+.LBB14_296:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.62]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 14
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 408]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::undo
+	test	rax, rax
+	je	.LBB14_333
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 224], rax
+	mov	qword ptr [rsp + 232], rdx
+	jmp	.LBB14_283
+.LBB14_298:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.57]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 16
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	movzx	eax, byte ptr [rsp + 401]
+	test	eax, eax
+	je	.LBB14_315
+	cmp	eax, 1
+	jne	.LBB14_316
+	lea	rdi, [rsp + 928]
+	jmp	.LBB14_317
+.LBB14_301:
+	mov	r12, qword ptr [rsp + 416]
+	jmp	.LBB14_305
+.LBB14_302:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.61]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 13
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	test	byte ptr [rsp + 416], 1
+	je	.LBB14_310
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::rem
+	jmp	.LBB14_311
+.LBB14_304:
+	mov	r12, qword ptr [rsp + 416]
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 15
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::checkpoint
+.LBB14_305:
+	test	r12, r12
+	je	.LBB14_308
+	cmp	r12, 1
+	jne	.LBB14_309
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.59]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 28
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::scale_factor
+	jmp	.LBB14_349
+.LBB14_308:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.58]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 13
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::mul
+	jmp	.LBB14_311
+.LBB14_309:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.60]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 28
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	mov	rsi, qword ptr [rsp + 432]
+	mov	rdx, qword ptr [rsp + 440]
+	mov	r8, qword ptr [rsp + 456]
+	mov	rcx, qword ptr [rsp + 448]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::scale_bounds
+	jmp	.LBB14_311
+.LBB14_310:
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::div
+.LBB14_311:
+	test	rax, rax
+	je	.LBB14_349
+	mov	r12, rax
+	mov	rbp, rdx
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::rollback
+	test	rax, rax
+	je	.LBB14_314
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 224], rax
+	mov	qword ptr [rsp + 232], 21
+	jmp	.LBB14_283
+.LBB14_314:
+	mov	al, -1
+	jmp	.LBB14_343
+.LBB14_315:
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::inc
+	jmp	.LBB14_349
+.LBB14_316:
+	lea	r12, [rsp + 928]
+	mov	rdi, r12
+	call	optional_trait_methods::using_fn::targets::advanced::inc
+	mov	rdi, r12
+.LBB14_317:
+	call	optional_trait_methods::using_fn::targets::advanced::dec
+	jmp	.LBB14_349
+.LBB14_318:
+	lea	rax, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.63]
+	mov	qword ptr [rsp + 48], rax
+	mov	qword ptr [rsp + 56], 15
+	lea	rax, [rsp + 48]
+	#APP
+	#NO_APP
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::checkpoint
+.LBB14_319:
+	mov	rsi, qword ptr [rsp + 416]
+	mov	rdx, qword ptr [rsp + 424]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::set_state
+	jmp	.LBB14_349
+.LBB14_320:
+	mov	al, byte ptr [rsp + 402]
+	and	al, 63
+	movzx	eax, al
+	cmp	byte ptr [rsp + 401], 0
+	mov	ecx, 63
+	cmove	eax, ecx
+	mov	byte ptr [rsp + 208], 2
+	mov	byte ptr [rsp + 209], al
+	jmp	.LBB14_352
+.LBB14_321:
+	mov	rsi, qword ptr [rsp + 408]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	mov	byte ptr [rsp + 208], 1
+	mov	qword ptr [rsp + 232], rdx
+	mov	qword ptr [rsp + 224], rax
+	jmp	.LBB14_352
+.LBB14_322:
+	mov	rcx, qword ptr [rsp + 424]
+	mov	rsi, qword ptr [rsp + 408]
+	mov	rdx, qword ptr [rsp + 416]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::write_reg
+	jmp	.LBB14_333
+.LBB14_323:
+	mov	edx, 136
+	lea	rdi, [rsp + 624]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB14_324:
+	lea	rdi, [rsp + 928]
+	mov	rsi, r12
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	r13, 7
+	ja	.LBB14_326
+	shl	r13, 4
+	mov	qword ptr [rsp + r13 + 624], rax
+	mov	qword ptr [rsp + r13 + 632], rdx
+	mov	r13, qword ptr [rsp + 752]
+	inc	r13
+	mov	qword ptr [rsp + 752], r13
+.LBB14_326:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB14_324
+	mov	edx, 144
+	lea	rdi, [rsp + 63]
+	lea	rsi, [rsp + 624]
+	mov	r12, qword ptr [rip + memcpy@GOTPCREL]
+	call	r12
+	mov	byte ptr [rsp + 208], 3
+	mov	edx, 159
+	lea	rdi, [rsp + 209]
+	lea	rsi, [rsp + 48]
+	call	r12
+	jmp	.LBB14_352
+.LBB14_328:
+	mov	edx, 136
+	lea	rdi, [rsp + 48]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB14_329:
+	lea	rdi, [rsp + 928]
+	mov	rsi, r12
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	r13, 7
+	ja	.LBB14_331
+	shl	r13, 4
+	mov	qword ptr [rsp + r13 + 48], rax
+	mov	qword ptr [rsp + r13 + 56], rdx
+	mov	r13, qword ptr [rsp + 176]
+	inc	r13
+	mov	qword ptr [rsp + 176], r13
+.LBB14_331:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB14_329
+	lea	rdi, [rsp + 416]
+	lea	rsi, [rsp + 928]
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB14_333:
+	mov	byte ptr [rsp + 208], 0
+	jmp	.LBB14_352
+.LBB14_334:
+	mov	al, byte ptr [rsp + 402]
+	and	al, 63
+	cmp	byte ptr [rsp + 401], 0
+	movzx	ecx, al
+	mov	eax, 63
+	cmove	ecx, eax
+	mov	al, 2
+	jmp	.LBB14_350
+.LBB14_335:
+	mov	rsi, qword ptr [rsp + 408]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	mov	r12, rax
+	mov	rbp, rdx
+.LBB14_336:
+	mov	al, 1
+	jmp	.LBB14_343
+.LBB14_337:
+	mov	rcx, qword ptr [rsp + 424]
+	mov	rsi, qword ptr [rsp + 408]
+	mov	rdx, qword ptr [rsp + 416]
+	lea	rdi, [rsp + 928]
+	call	optional_trait_methods::using_fn::targets::advanced::write_reg
+	jmp	.LBB14_349
+.LBB14_338:
+	mov	edx, 136
+	lea	rdi, [rsp + 48]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB14_339:
+	lea	rdi, [rsp + 928]
+	mov	rsi, r12
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	r13, 7
+	ja	.LBB14_341
+	shl	r13, 4
+	mov	qword ptr [rsp + r13 + 48], rax
+	mov	qword ptr [rsp + r13 + 56], rdx
+	mov	r13, qword ptr [rsp + 176]
+	inc	r13
+	mov	qword ptr [rsp + 176], r13
+.LBB14_341:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB14_339
+	mov	r12, qword ptr [rsp + 48]
+	mov	rbp, qword ptr [rsp + 56]
+	mov	ecx, 16
+	lea	rdi, [rsp + 624]
+	lea	rsi, [rsp + 64]
+	rep movsq es:[rdi], [rsi]
+	mov	al, 3
+.LBB14_343:
+	jmp	.LBB14_351
+.LBB14_344:
+	mov	edx, 136
+	lea	rdi, [rsp + 48]
+	xor	esi, esi
+	call	qword ptr [rip + memset@GOTPCREL]
+	xor	r13d, r13d
+	xor	r12d, r12d
+.LBB14_345:
+	lea	rdi, [rsp + 928]
+	mov	rsi, r12
+	call	optional_trait_methods::using_fn::targets::advanced::read_reg
+	cmp	r13, 7
+	ja	.LBB14_347
+	shl	r13, 4
+	mov	qword ptr [rsp + r13 + 48], rax
+	mov	qword ptr [rsp + r13 + 56], rdx
+	mov	r13, qword ptr [rsp + 176]
+	inc	r13
+	mov	qword ptr [rsp + 176], r13
+.LBB14_347:
+	inc	r12
+	cmp	r12, 8
+	jne	.LBB14_345
+	lea	rdi, [rsp + 416]
+	lea	rsi, [rsp + 928]
+	call	<optional_trait_methods::registers::Registers<i128>>::write_all::<&str, <optional_trait_methods::using_fn::controller::TargetController<optional_trait_methods::using_fn::targets::advanced::AdvancedTarget>>::apply::{closure#0}>
+.LBB14_349:
+	xor	eax, eax
+.LBB14_350:
+.LBB14_351:
+	mov	byte ptr [rsp + 208], al
+	mov	byte ptr [rsp + 209], cl
+	mov	qword ptr [rsp + 216], 0
+	mov	qword ptr [rsp + 232], rbp
+	mov	qword ptr [rsp + 224], r12
+	mov	ecx, 16
+	lea	rdi, [rsp + 240]
+	lea	rsi, [rsp + 624]
+	rep movsq es:[rdi], [rsi]
+.LBB14_352:
+	lea	rdi, [rsp + 768]
+	mov	rsi, r14
+	lea	rdx, [rsp + 208]
+	call	optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>
+	cmp	byte ptr [rsp + 768], -1
+	jne	.LBB14_356
+	mov	rax, qword ptr [rsp + 776]
+	cmp	byte ptr [rsp + 376], 0
+	jne	.LBB14_359
+	cmp	eax, 1
+	je	.LBB14_362
+	cmp	eax, 4
+	je	.LBB14_361
+.LBB14_356:
+	mov	ebp, dword ptr [rsp + 3912]
+	mov	rdx, qword ptr [rsp + 3904]
+	jmp	.LBB14_259
+.LBB14_357:
+	xor	edx, edx
+.LBB14_358:
+	xor	eax, eax
+	mov	qword ptr [rsp + 3904], rax
+	cmp	rdx, 1025
+	mov	ecx, 1
+	cmovae	r14, rcx
+	cmovae	rdx, rax
+	mov	edi, ebp
+	mov	rsi, r14
+	mov	ecx, 1
+	call	optional_trait_methods::connection::write_fd
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	jmp	.LBB14_219
+.LBB14_359:
+	cmp	eax, 1
+	je	.LBB14_362
+	cmp	eax, 4
+	jne	.LBB14_364
+.LBB14_361:
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.28]
+	lea	rdi, [rsp + 2880]
+	mov	edx, 23
+	jmp	.LBB14_363
+.LBB14_362:
+	mov	rsi, qword ptr [rsp + 784]
+	mov	rdx, qword ptr [rsp + 792]
+	lea	rdi, [rsp + 2880]
+.LBB14_363:
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+	lea	rsi, [rip + .Lanon.62fb3b87f511695ce61fb365a19fad11.0]
+	lea	rdi, [rsp + 2880]
+	mov	edx, 1
+	call	<optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket> as optional_trait_methods::writer::ResponseWriter>::write
+.LBB14_364:
+	mov	rdx, qword ptr [rsp + 3904]
+	mov	ebx, 1
+	mov	ebp, dword ptr [rsp + 3912]
+	jmp	.LBB14_358
+.LBB14_365:
+	ud2
+.Lfunc_end14:
+.LJTI14_0:
+	.long	.LBB14_27-.LJTI14_0
+	.long	.LBB14_37-.LJTI14_0
+	.long	.LBB14_30-.LJTI14_0
+	.long	.LBB14_31-.LJTI14_0
+	.long	.LBB14_29-.LJTI14_0
+	.long	.LBB14_365-.LJTI14_0
+	.long	.LBB14_41-.LJTI14_0
+.LJTI14_1:
+	.long	.LBB14_291-.LJTI14_1
+	.long	.LBB14_287-.LJTI14_1
+	.long	.LBB14_293-.LJTI14_1
+	.long	.LBB14_287-.LJTI14_1
+	.long	.LBB14_296-.LJTI14_1
+.LJTI14_2:
+	.long	.LBB14_288-.LJTI14_2
+	.long	.LBB14_298-.LJTI14_2
+	.long	.LBB14_301-.LJTI14_2
+	.long	.LBB14_302-.LJTI14_2
+.LJTI14_3:
+	.long	.LBB14_289-.LJTI14_3
+	.long	.LBB14_319-.LJTI14_3
+	.long	.LBB14_334-.LJTI14_3
+	.long	.LBB14_335-.LJTI14_3
+	.long	.LBB14_337-.LJTI14_3
+	.long	.LBB14_338-.LJTI14_3
+	.long	.LBB14_344-.LJTI14_3
+.LJTI14_4:
+	.long	.LBB14_292-.LJTI14_4
+	.long	.LBB14_318-.LJTI14_4
+	.long	.LBB14_320-.LJTI14_4
+	.long	.LBB14_321-.LJTI14_4
+	.long	.LBB14_322-.LJTI14_4
+	.long	.LBB14_323-.LJTI14_4
+	.long	.LBB14_328-.LJTI14_4
+.LJTI14_5:
+	.long	.LBB14_140-.LJTI14_5
+	.long	.LBB14_134-.LJTI14_5
+	.long	.LBB14_142-.LJTI14_5
+	.long	.LBB14_134-.LJTI14_5
+	.long	.LBB14_138-.LJTI14_5
+.LJTI14_6:
+	.long	.LBB14_135-.LJTI14_6
+	.long	.LBB14_153-.LJTI14_6
+	.long	.LBB14_146-.LJTI14_6
+	.long	.LBB14_151-.LJTI14_6
+.LJTI14_7:
+	.long	.LBB14_136-.LJTI14_7
+	.long	.LBB14_171-.LJTI14_7
+	.long	.LBB14_187-.LJTI14_7
+	.long	.LBB14_188-.LJTI14_7
+	.long	.LBB14_186-.LJTI14_7
+	.long	.LBB14_190-.LJTI14_7
+	.long	.LBB14_196-.LJTI14_7
+.LJTI14_8:
+	.long	.LBB14_141-.LJTI14_8
+	.long	.LBB14_170-.LJTI14_8
+	.long	.LBB14_168-.LJTI14_8
+	.long	.LBB14_169-.LJTI14_8
+	.long	.LBB14_167-.LJTI14_8
+	.long	.LBB14_172-.LJTI14_8
+	.long	.LBB14_178-.LJTI14_8
+
+rust_eh_personality:
+.Lfunc_begin15:
+	ret
+.Lfunc_end15:
+
+core::panicking::panic_fmt:
+.Lfunc_begin16:
+	.loc	1 60 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp0:
+	.loc	1 80 14 prologue_end
+	call	__rustc::rust_begin_unwind
+.Ltmp1:
+.Lfunc_end16:
+
+core::panicking::panic_bounds_check:
+.Lfunc_begin17:
+	.loc	1 266 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp2:
+	.loc	1 271 5 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp3:
+.Lfunc_end17:
+
+core::slice::index::slice_index_fail:
+.Lfunc_begin18:
+	.loc	2 38 0
+	push	rbp
+	mov	rbp, rsp
+.Ltmp4:
+	.loc	3 177 9 prologue_end
+	call	core::panicking::panic_fmt
+.Ltmp5:
+.Lfunc_end18:
+
+optional_trait_methods::num::parse_num::<usize>:
+.Lfunc_begin19:
+	test	rsi, rsi
+	je	.LBB19_55
+	lea	rax, [rdi + 1]
+	lea	rdx, [rsi - 1]
+	movzx	ecx, byte ptr [rdi]
+	cmp	ecx, 43
+	je	.LBB19_4
+	cmp	ecx, 45
+	jne	.LBB19_6
+	mov	cl, 1
+	jmp	.LBB19_5
+.LBB19_4:
+	xor	ecx, ecx
+.LBB19_5:
+	mov	rsi, rdx
+	mov	rdi, rax
+	jmp	.LBB19_7
+.LBB19_6:
+	xor	ecx, ecx
+.LBB19_7:
+	cmp	rsi, 1
+	jbe	.LBB19_14
+	mov	al, byte ptr [rdi]
+	cmp	al, 48
+	jne	.LBB19_16
+	movzx	eax, byte ptr [rdi + 1]
+	add	eax, -66
+	cmp	eax, 54
+	ja	.LBB19_17
+	movabs	rdx, 4294967297
+	bt	rdx, rax
+	jb	.LBB19_30
+	movabs	rdx, 35184372097024
+	bt	rdx, rax
+	jb	.LBB19_29
+	movabs	rdx, 18014398513676288
+	bt	rdx, rax
+	jae	.LBB19_17
+	mov	r8b, 16
+	mov	r9b, 1
+	jmp	.LBB19_32
+.LBB19_14:
+	test	rsi, rsi
+	je	.LBB19_55
+	mov	al, byte ptr [rdi]
+.LBB19_16:
+	add	al, -48
+	cmp	al, 9
+	ja	.LBB19_55
+.LBB19_17:
+	add	rsi, rdi
+	mov	rax, rsi
+	dec	rax
+	je	.LBB19_19
+	cmp	byte ptr [rax], 95
+	je	.LBB19_55
+.LBB19_19:
+	mov	r8d, 10
+	xor	r9d, r9d
+	xor	edx, edx
+.LBB19_20:
+	cmp	rdi, rsi
+	je	.LBB19_50
+	mov	r10b, byte ptr [rdi]
+	inc	rdi
+	cmp	r10b, 95
+	jne	.LBB19_23
+	xor	eax, eax
+	test	r9b, 1
+	mov	r9d, 0
+	jne	.LBB19_20
+	jmp	.LBB19_51
+.LBB19_23:
+	add	r10b, -48
+	cmp	r10b, 9
+	ja	.LBB19_28
+	mov	rax, rdx
+	mul	r8
+	jo	.LBB19_28
+	mov	rdx, rax
+	movzx	eax, r10b
+	mov	r9b, 1
+	test	cl, cl
+	je	.LBB19_27
+	sub	rdx, rax
+	jae	.LBB19_20
+	jmp	.LBB19_28
+.LBB19_27:
+	add	rdx, rax
+	jae	.LBB19_20
+.LBB19_28:
+	xor	eax, eax
+	ret
+.LBB19_29:
+	mov	r8b, 8
+	jmp	.LBB19_31
+.LBB19_30:
+	mov	r8b, 2
+.LBB19_31:
+	xor	r9d, r9d
+.LBB19_32:
+	cmp	rsi, 2
+	je	.LBB19_55
+	lea	r10, [rdi + 2]
+	mov	al, byte ptr [r10]
+	lea	edx, [rax - 48]
+	cmp	dl, 10
+	jae	.LBB19_52
+.LBB19_34:
+	cmp	dl, r8b
+	jae	.LBB19_55
+	add	rdi, rsi
+	mov	rax, rdi
+	dec	rax
+	je	.LBB19_37
+	cmp	byte ptr [rax], 95
+	je	.LBB19_55
+.LBB19_37:
+	movzx	esi, r8b
+	xor	r11d, r11d
+	xor	edx, edx
+.LBB19_38:
+	cmp	r10, rdi
+	je	.LBB19_50
+	mov	al, byte ptr [r10]
+	inc	r10
+	cmp	al, 95
+	jne	.LBB19_41
+	xor	eax, eax
+	test	r11b, 1
+	mov	r11d, 0
+	je	.LBB19_51
+	jmp	.LBB19_38
+.LBB19_41:
+	lea	r11d, [rax - 48]
+	cmp	r11b, 10
+	jb	.LBB19_45
+	test	r9b, r9b
+	je	.LBB19_28
+	or	al, 32
+	lea	r11d, [rax - 97]
+	cmp	r11b, 5
+	ja	.LBB19_28
+	add	al, -87
+	mov	r11d, eax
+.LBB19_45:
+	cmp	r11b, r8b
+	jae	.LBB19_28
+	mov	rax, rdx
+	mul	rsi
+	jo	.LBB19_28
+	mov	rdx, rax
+	movzx	eax, r11b
+	mov	r11b, 1
+	test	cl, cl
+	je	.LBB19_49
+	sub	rdx, rax
+	jae	.LBB19_38
+	jmp	.LBB19_28
+.LBB19_49:
+	add	rdx, rax
+	jae	.LBB19_38
+	jmp	.LBB19_28
+.LBB19_50:
+	mov	eax, 1
+	ret
+.LBB19_52:
+	test	r9b, r9b
+	je	.LBB19_55
+	or	al, 32
+	lea	edx, [rax - 97]
+	cmp	dl, 5
+	ja	.LBB19_55
+	add	al, -87
+	mov	edx, eax
+	jmp	.LBB19_34
+.LBB19_55:
+	xor	eax, eax
+.LBB19_51:
+	ret
+.Lfunc_end19:
+
+<optional_trait_methods::commands::Args>::reg:
+.Lfunc_begin20:
+	push	r15
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	rbx, rdi
+	lea	r14, [rsp + 8]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r15, qword ptr [r14]
+	mov	rdi, qword ptr [r14 + 8]
+	mov	rsi, qword ptr [r14 + 16]
+	call	optional_trait_methods::num::parse_num::<usize>
+	cmp	rdx, 8
+	setb	cl
+	and	cl, al
+	cmovne	r15, rdx
+	neg	cl
+	or	cl, 2
+	mov	qword ptr [rbx], r15
+	mov	byte ptr [rbx + 8], cl
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end20:
+
+<optional_trait_methods::commands::Args>::next:
+.Lfunc_begin21:
+	mov	r10, qword ptr [rsi + 8]
+	mov	rax, qword ptr [rsi + 16]
+	xor	edx, edx
+	test	r10, r10
+	setne	dl
+	cmp	r10, 1
+	mov	rcx, r10
+	adc	rcx, -1
+	mov	r8, qword ptr [rsi]
+	add	r8, rdx
+	mov	r9, rcx
+	cmp	r10, 2
+	jb	.LBB21_5
+	xor	r9d, r9d
+.LBB21_2:
+	cmp	byte ptr [r8 + r9], 32
+	je	.LBB21_5
+	inc	r9
+	cmp	rcx, r9
+	jne	.LBB21_2
+	mov	r9, rcx
+.LBB21_5:
+	add	rax, rdx
+	sub	rcx, r9
+	lea	rdx, [r8 + r9]
+	mov	qword ptr [rsi], rdx
+	mov	qword ptr [rsi + 8], rcx
+	lea	rcx, [r9 + rax]
+	mov	qword ptr [rsi + 16], rcx
+	mov	qword ptr [rdi], rax
+	mov	qword ptr [rdi + 8], r8
+	mov	qword ptr [rdi + 16], r9
+	ret
+.Lfunc_end21:
+
+<optional_trait_methods::commands::Args>::names:
+.Lfunc_begin22:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	sub	rsp, 200
+	mov	qword ptr [rsp + 32], rdi
+	lea	r14, [rsp + 40]
+	mov	rdi, r14
+	call	<optional_trait_methods::commands::Args>::next
+	mov	r12, qword ptr [r14 + 8]
+	mov	rbx, qword ptr [r14 + 16]
+	mov	qword ptr [rsp + 16], 0
+	mov	r15, qword ptr [rip + bcmp@GOTPCREL]
+.LBB22_1:
+	test	rbx, rbx
+	je	.LBB22_2
+	xor	r13d, r13d
+.LBB22_4:
+	cmp	byte ptr [r12 + r13], 59
+	je	.LBB22_7
+	inc	r13
+	cmp	rbx, r13
+	jne	.LBB22_4
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	mov	qword ptr [rsp + 24], r12
+	mov	r13, rbx
+	jmp	.LBB22_8
+.LBB22_2:
+	mov	al, 1
+	mov	dword ptr [rsp + 12], eax
+	xor	ebx, ebx
+	mov	qword ptr [rsp + 24], r12
+	xor	r13d, r13d
+	jmp	.LBB22_8
+.LBB22_7:
+	lea	rax, [r13 + 1]
+	sub	rbx, rax
+	lea	rax, [r12 + r13]
+	inc	rax
+	mov	qword ptr [rsp + 24], rax
+	mov	dword ptr [rsp + 12], 0
+.LBB22_8:
+	mov	edx, 144
+	lea	rbp, [rsp + 56]
+	mov	rdi, rbp
+	lea	rsi, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.6]
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	r14d, 6
+.LBB22_9:
+	cmp	qword ptr [rbp + 16], r13
+	jne	.LBB22_11
+	mov	rdi, qword ptr [rbp + 8]
+	mov	rsi, r12
+	mov	rdx, r13
+	call	r15
+	test	eax, eax
+	je	.LBB22_12
+.LBB22_11:
+	add	rbp, 24
+	dec	r14
+	jne	.LBB22_9
+	jmp	.LBB22_13
+.LBB22_12:
+	mov	rax, qword ptr [rsp + 16]
+	or	al, byte ptr [rbp]
+	mov	qword ptr [rsp + 16], rax
+.LBB22_13:
+	mov	r12, qword ptr [rsp + 24]
+	cmp	byte ptr [rsp + 12], 0
+	je	.LBB22_1
+	mov	rax, qword ptr [rsp + 32]
+	mov	rcx, qword ptr [rsp + 16]
+	mov	byte ptr [rax], cl
+	mov	byte ptr [rax + 8], -1
+	add	rsp, 200
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.Lfunc_end22:
+
+<optional_trait_methods::connection::Listener>::tcp_loopback:
+.Lfunc_begin23:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 32
+	mov	qword ptr [rsp + 20], 0
+	mov	word ptr [rsp + 12], 2
+	rol	di, 8
+	mov	word ptr [rsp + 14], di
+	mov	dword ptr [rsp + 16], 16777343
+	mov	edi, 2
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebx, edx
+	mov	ebp, 1
+	test	al, 1
+	jne	.LBB23_5
+	lea	rcx, [rsp + 28]
+	mov	dword ptr [rcx], 1
+	mov	edi, ebx
+	mov	esi, 1
+	mov	edx, 2
+	mov	r8d, 4
+	call	qword ptr [rip + setsockopt@GOTPCREL]
+	lea	rsi, [rsp + 12]
+	mov	edi, ebx
+	mov	edx, 16
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB23_3
+	mov	edi, ebx
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB23_3
+	xor	ebp, ebp
+	jmp	.LBB23_5
+.LBB23_3:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebx
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebx, r14d
+.LBB23_5:
+	mov	eax, ebp
+	mov	edx, ebx
+	add	rsp, 32
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end23:
+
+<optional_trait_methods::connection::Listener>::socket:
+.Lfunc_begin24:
+	push	rbx
+	xor	ebx, ebx
+	mov	esi, 1
+	xor	edx, edx
+	call	qword ptr [rip + socket@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB24_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB24_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end24:
+
+<optional_trait_methods::connection::Listener>::unix:
+.Lfunc_begin25:
+	push	rbp
+	push	r14
+	push	rbx
+	sub	rsp, 112
+	mov	rdx, rsi
+	xorps	xmm0, xmm0
+	movups	xmmword ptr [rsp + 96], xmm0
+	movups	xmmword ptr [rsp + 84], xmm0
+	movups	xmmword ptr [rsp + 68], xmm0
+	movups	xmmword ptr [rsp + 52], xmm0
+	movups	xmmword ptr [rsp + 36], xmm0
+	movups	xmmword ptr [rsp + 20], xmm0
+	movups	xmmword ptr [rsp + 4], xmm0
+	mov	word ptr [rsp + 2], 1
+	lea	rax, [rsi - 108]
+	cmp	rax, -107
+	jae	.LBB25_2
+	mov	ebx, 1
+	mov	ebp, 36
+	jmp	.LBB25_7
+.LBB25_2:
+	mov	rsi, rdi
+	lea	rbx, [rsp + 4]
+	mov	rdi, rbx
+	call	qword ptr [rip + memcpy@GOTPCREL]
+	mov	rdi, rbx
+	call	qword ptr [rip + unlink@GOTPCREL]
+	mov	ebx, 1
+	mov	edi, 1
+	call	<optional_trait_methods::connection::Listener>::socket
+	mov	ebp, edx
+	test	al, 1
+	jne	.LBB25_7
+	lea	rsi, [rsp + 2]
+	mov	edi, ebp
+	mov	edx, 110
+	call	qword ptr [rip + bind@GOTPCREL]
+	test	eax, eax
+	js	.LBB25_6
+	mov	edi, ebp
+	mov	esi, 1
+	call	qword ptr [rip + listen@GOTPCREL]
+	test	eax, eax
+	js	.LBB25_6
+	xor	ebx, ebx
+	jmp	.LBB25_7
+.LBB25_6:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	r14d, dword ptr [rax]
+	mov	edi, ebp
+	call	qword ptr [rip + close@GOTPCREL]
+	mov	ebp, r14d
+.LBB25_7:
+	mov	eax, ebx
+	mov	edx, ebp
+	add	rsp, 112
+	pop	rbx
+	pop	r14
+	pop	rbp
+	ret
+.Lfunc_end25:
+
+<optional_trait_methods::connection::Listener>::accept:
+.Lfunc_begin26:
+	push	rbx
+	xor	ebx, ebx
+	xor	esi, esi
+	xor	edx, edx
+	call	qword ptr [rip + accept@GOTPCREL]
+	mov	edx, eax
+	test	eax, eax
+	jns	.LBB26_2
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	edx, dword ptr [rax]
+	mov	ebx, 1
+.LBB26_2:
+	mov	eax, ebx
+	pop	rbx
+	ret
+.Lfunc_end26:
+
+optional_trait_methods::commands::unrecognized:
+.Lfunc_begin27:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	mov	rbx, rdi
+	xor	r14d, r14d
+.LBB27_1:
+	cmp	byte ptr [rbx + r14], 32
+	je	.LBB27_4
+	inc	r14
+	cmp	rsi, r14
+	jne	.LBB27_1
+	mov	r14, rsi
+.LBB27_4:
+	mov	r15d, 8
+	lea	r12, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.33]
+	mov	r13, qword ptr [rip + bcmp@GOTPCREL]
+.LBB27_5:
+	cmp	qword ptr [r15 + r12], r14
+	jne	.LBB27_8
+	mov	rdi, qword ptr [r15 + r12 - 8]
+	mov	rsi, rbx
+	mov	rdx, r14
+	call	r13
+	test	eax, eax
+	je	.LBB27_7
+.LBB27_8:
+	add	r15, 16
+	cmp	r15, 152
+	jne	.LBB27_5
+	xor	eax, eax
+	jmp	.LBB27_10
+.LBB27_7:
+	mov	al, 1
+.LBB27_10:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end27:
+
+optional_trait_methods::using_fn::targets::advanced::checkpoint:
+.Lfunc_begin28:
+	push	r14
+	push	rbx
+	push	rax
+	mov	rdx, qword ptr [rdi + 584]
+	mov	rcx, qword ptr [rdi + 576]
+	mov	r8, qword ptr [rdi + 712]
+	mov	rsi, qword ptr [rdi + 704]
+	lea	r9, [rdi + 48]
+	cmp	qword ptr [rdi + 568], 16
+	jne	.LBB28_3
+	mov	rax, qword ptr [rdi + 560]
+	cmp	rax, 16
+	jae	.LBB28_5
+	mov	r10, rax
+	shl	r10, 5
+	mov	r11, qword ptr [r9 + r10]
+	mov	rbx, qword ptr [r9 + r10 + 8]
+	mov	r14, qword ptr [r9 + r10 + 24]
+	mov	r10, qword ptr [r9 + r10 + 16]
+	mov	qword ptr [rdi], 1
+	mov	qword ptr [rdi + 8], 0
+	mov	qword ptr [rdi + 16], r11
+	mov	qword ptr [rdi + 24], rbx
+	mov	qword ptr [rdi + 32], r10
+	mov	qword ptr [rdi + 40], r14
+	jmp	.LBB28_4
+.LBB28_3:
+	mov	rax, qword ptr [rdi + 560]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rdi], xmm0
+	cmp	rax, 16
+	jae	.LBB28_5
+.LBB28_4:
+	shl	rax, 5
+	mov	qword ptr [r9 + rax + 8], rdx
+	mov	qword ptr [r9 + rax], rcx
+	mov	qword ptr [r9 + rax + 24], r8
+	mov	qword ptr [r9 + rax + 16], rsi
+	mov	eax, dword ptr [rdi + 560]
+	inc	eax
+	and	eax, 15
+	mov	qword ptr [rdi + 560], rax
+	mov	rax, qword ptr [rdi + 568]
+	inc	rax
+	cmp	rax, 16
+	mov	ecx, 16
+	cmovb	rcx, rax
+	mov	qword ptr [rdi + 568], rcx
+	add	rsp, 8
+	pop	rbx
+	pop	r14
+	ret
+.LBB28_5:
+	mov	esi, 16
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end28:
+
+optional_trait_methods::using_fn::targets::advanced::scale_bounds:
+.Lfunc_begin29:
+	cmp	rcx, rsi
+	mov	rax, r8
+	sbb	rax, rdx
+	jge	.LBB29_2
+	lea	rax, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.42]
+	jmp	.LBB29_3
+.LBB29_2:
+	mov	qword ptr [rdi + 720], rsi
+	mov	qword ptr [rdi + 728], rdx
+	mov	qword ptr [rdi + 736], rcx
+	mov	qword ptr [rdi + 744], r8
+	mov	rax, qword ptr [rdi + 704]
+	mov	r9, qword ptr [rdi + 712]
+	cmp	rax, rsi
+	mov	r10, r9
+	sbb	r10, rdx
+	cmovl	r9, rdx
+	cmovl	rax, rsi
+	cmp	rcx, rax
+	mov	rdx, r8
+	sbb	rdx, r9
+	cmovl	r9, r8
+	cmovl	rax, rcx
+	mov	qword ptr [rdi + 704], rax
+	mov	qword ptr [rdi + 712], r9
+	xor	eax, eax
+.LBB29_3:
+	mov	edx, 26
+	ret
+.Lfunc_end29:
+
+optional_trait_methods::using_fn::targets::advanced::scale_factor:
+.Lfunc_begin30:
+	mov	rax, qword ptr [rdi + 720]
+	mov	rcx, qword ptr [rdi + 728]
+	mov	r8, qword ptr [rdi + 744]
+	mov	r9, qword ptr [rdi + 736]
+	cmp	rsi, rax
+	mov	r10, rdx
+	sbb	r10, rcx
+	cmovl	rdx, rcx
+	cmovl	rsi, rax
+	cmp	r9, rsi
+	mov	rax, r8
+	sbb	rax, rdx
+	cmovl	rsi, r9
+	cmovl	rdx, r8
+	mov	qword ptr [rdi + 712], rdx
+	mov	qword ptr [rdi + 704], rsi
+	ret
+.Lfunc_end30:
+
+optional_trait_methods::using_fn::targets::advanced::dec:
+.Lfunc_begin31:
+	add	qword ptr [rdi + 576], -1
+	adc	qword ptr [rdi + 584], -1
+	ret
+.Lfunc_end31:
+
+.LCPI32_0:
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	128
+optional_trait_methods::using_fn::targets::advanced::div:
+.Lfunc_begin32:
+	mov	rcx, rdx
+	mov	rax, rsi
+	or	rax, rdx
+	je	.LBB32_1
+	push	rbx
+	mov	rdx, rsi
+	mov	rbx, rdi
+	mov	rdi, qword ptr [rdi + 576]
+	mov	rsi, qword ptr [rbx + 584]
+	movq	xmm0, rdi
+	movq	xmm1, rsi
+	punpcklqdq	xmm0, xmm1
+	movq	xmm1, rdx
+	movq	xmm2, rcx
+	punpcklqdq	xmm1, xmm2
+	pcmpeqd	xmm2, xmm2
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI32_0]
+	pcmpeqb	xmm2, xmm1
+	pand	xmm0, xmm2
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	je	.LBB32_4
+	call	qword ptr [rip + __divti3@GOTPCREL]
+	mov	qword ptr [rbx + 584], rdx
+	mov	qword ptr [rbx + 576], rax
+	xor	eax, eax
+.LBB32_6:
+	pop	rbx
+	ret
+.LBB32_1:
+	lea	rax, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.43]
+	mov	edx, 17
+	ret
+.LBB32_4:
+	lea	rax, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.44]
+	mov	edx, 29
+	jmp	.LBB32_6
+.Lfunc_end32:
+
+optional_trait_methods::using_fn::targets::advanced::inc:
+.Lfunc_begin33:
+	add	qword ptr [rdi + 576], 1
+	adc	qword ptr [rdi + 584], 0
+	ret
+.Lfunc_end33:
+
+optional_trait_methods::using_fn::targets::advanced::mul:
+.Lfunc_begin34:
+	mov	rax, rsi
+	xor	rax, 7
+	or	rax, rdx
+	jne	.LBB34_2
+	lea	rax, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.45]
+	jmp	.LBB34_3
+.LBB34_2:
+	mov	rcx, rdx
+	mov	rax, qword ptr [rdi + 704]
+	imul	rcx, rax
+	mul	rsi
+	mov	r8, qword ptr [rdi + 576]
+	add	rdx, rcx
+	mov	rcx, qword ptr [rdi + 712]
+	imul	rcx, rsi
+	add	rcx, rdx
+	mov	rsi, qword ptr [rdi + 584]
+	imul	rsi, rax
+	mul	r8
+	imul	rcx, r8
+	add	rcx, rdx
+	add	rcx, rsi
+	mov	qword ptr [rdi + 576], rax
+	mov	qword ptr [rdi + 584], rcx
+	xor	eax, eax
+.LBB34_3:
+	mov	edx, 28
+	ret
+.Lfunc_end34:
+
+.LCPI35_0:
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	128
+optional_trait_methods::using_fn::targets::advanced::rem:
+.Lfunc_begin35:
+	mov	rcx, rdx
+	mov	rax, rsi
+	or	rax, rdx
+	je	.LBB35_1
+	push	rbx
+	mov	rdx, rsi
+	mov	rbx, rdi
+	mov	rdi, qword ptr [rdi + 576]
+	mov	rsi, qword ptr [rbx + 584]
+	movq	xmm0, rdi
+	movq	xmm1, rsi
+	punpcklqdq	xmm0, xmm1
+	movq	xmm1, rdx
+	movq	xmm2, rcx
+	punpcklqdq	xmm1, xmm2
+	pcmpeqd	xmm2, xmm2
+	pcmpeqb	xmm0, xmmword ptr [rip + .LCPI35_0]
+	pcmpeqb	xmm2, xmm1
+	pand	xmm0, xmm2
+	pmovmskb	eax, xmm0
+	cmp	eax, 65535
+	je	.LBB35_4
+	call	qword ptr [rip + __modti3@GOTPCREL]
+	mov	qword ptr [rbx + 584], rdx
+	mov	qword ptr [rbx + 576], rax
+	xor	eax, eax
+.LBB35_6:
+	pop	rbx
+	ret
+.LBB35_1:
+	lea	rax, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.43]
+	mov	edx, 17
+	ret
+.LBB35_4:
+	lea	rax, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.44]
+	mov	edx, 29
+	jmp	.LBB35_6
+.Lfunc_end35:
+
+optional_trait_methods::using_fn::targets::advanced::undo:
+.Lfunc_begin36:
+	mov	rax, qword ptr [rdi + 568]
+	lea	rcx, [rsi - 1]
+	cmp	rcx, rax
+	jae	.LBB36_2
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rdi], xmm0
+	mov	ecx, dword ptr [rdi + 560]
+	sub	ecx, esi
+	and	ecx, 15
+	mov	qword ptr [rdi + 560], rcx
+	sub	rax, rsi
+	mov	qword ptr [rdi + 568], rax
+	shl	ecx, 5
+	mov	rax, qword ptr [rdi + rcx + 56]
+	mov	rdx, qword ptr [rdi + rcx + 72]
+	mov	rsi, qword ptr [rdi + rcx + 48]
+	mov	rcx, qword ptr [rdi + rcx + 64]
+	mov	r8, qword ptr [rdi + 728]
+	mov	r9, qword ptr [rdi + 720]
+	mov	r10, qword ptr [rdi + 744]
+	mov	r11, qword ptr [rdi + 736]
+	mov	qword ptr [rdi + 576], rsi
+	cmp	rcx, r9
+	mov	rsi, rdx
+	sbb	rsi, r8
+	cmovl	rdx, r8
+	cmovl	rcx, r9
+	cmp	r11, rcx
+	mov	rsi, r10
+	sbb	rsi, rdx
+	cmovl	rcx, r11
+	cmovl	rdx, r10
+	mov	qword ptr [rdi + 584], rax
+	mov	qword ptr [rdi + 712], rdx
+	mov	qword ptr [rdi + 704], rcx
+	jmp	.LBB36_3
+.LBB36_2:
+	test	rsi, rsi
+	je	.LBB36_3
+	xor	ecx, ecx
+	test	rax, rax
+	setne	cl
+	lea	rdx, [rcx + 2*rcx + 16]
+	lea	rcx, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.47]
+	lea	rax, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.46]
+	cmove	rax, rcx
+	ret
+.LBB36_3:
+	xor	eax, eax
+	ret
+.Lfunc_end36:
+
+optional_trait_methods::using_fn::targets::advanced::read_reg:
+.Lfunc_begin37:
+	test	rsi, rsi
+	je	.LBB37_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB37_5
+	shl	rax, 4
+	add	rdi, rax
+	add	rdi, 592
+	jmp	.LBB37_2
+.LBB37_1:
+	add	rdi, 576
+.LBB37_2:
+	mov	rax, qword ptr [rdi]
+	mov	rdx, qword ptr [rdi + 8]
+	ret
+.LBB37_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end37:
+
+optional_trait_methods::using_fn::targets::advanced::rollback:
+.Lfunc_begin38:
+	mov	r9, qword ptr [rdi]
+	mov	rax, qword ptr [rdi + 24]
+	mov	rdx, qword ptr [rdi + 16]
+	mov	rcx, qword ptr [rdi + 40]
+	mov	rsi, qword ptr [rdi + 32]
+	xorps	xmm0, xmm0
+	movaps	xmmword ptr [rdi], xmm0
+	mov	r8, qword ptr [rdi + 568]
+	test	r8, r8
+	je	.LBB38_1
+	push	r15
+	push	r14
+	push	rbx
+	mov	r10d, dword ptr [rdi + 560]
+	dec	r10d
+	and	r10d, 15
+	mov	qword ptr [rdi + 560], r10
+	lea	r11, [r8 - 1]
+	mov	qword ptr [rdi + 568], r11
+	shl	r10d, 5
+	mov	rbx, qword ptr [rdi + r10 + 56]
+	mov	r11, qword ptr [rdi + r10 + 72]
+	mov	r15, qword ptr [rdi + r10 + 48]
+	mov	r14, qword ptr [rdi + r10 + 64]
+	test	r9b, 1
+	je	.LBB38_4
+	lea	r9, [rdi + r10]
+	add	r9, 48
+	mov	qword ptr [r9], rdx
+	mov	qword ptr [r9 + 8], rax
+	mov	qword ptr [r9 + 16], rsi
+	mov	qword ptr [r9 + 24], rcx
+	mov	qword ptr [rdi + 568], r8
+.LBB38_4:
+	mov	qword ptr [rdi + 576], r15
+	mov	qword ptr [rdi + 584], rbx
+	mov	qword ptr [rdi + 704], r14
+	mov	qword ptr [rdi + 712], r11
+	xor	eax, eax
+	pop	rbx
+	pop	r14
+	pop	r15
+	ret
+.LBB38_1:
+	lea	rax, [rip + .Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.50]
+	ret
+.Lfunc_end38:
+
+optional_trait_methods::using_fn::targets::advanced::set_state:
+.Lfunc_begin39:
+	mov	qword ptr [rdi + 584], rdx
+	mov	qword ptr [rdi + 576], rsi
+	ret
+.Lfunc_end39:
+
+optional_trait_methods::using_fn::targets::advanced::write_reg:
+.Lfunc_begin40:
+	test	rsi, rsi
+	je	.LBB40_1
+	lea	rax, [rsi - 1]
+	cmp	rsi, 8
+	jae	.LBB40_5
+	shl	rax, 4
+	mov	qword ptr [rdi + rax + 600], rcx
+	mov	qword ptr [rdi + rax + 592], rdx
+	ret
+.LBB40_1:
+	mov	qword ptr [rdi + 576], rdx
+	mov	qword ptr [rdi + 584], rcx
+	ret
+.LBB40_5:
+	push	rax
+	mov	esi, 7
+	mov	rdi, rax
+	call	core::panicking::panic_bounds_check
+.Lfunc_end40:
+
+optional_trait_methods::connection::read_fd:
+.Lfunc_begin41:
+	push	rbx
+	mov	rax, rdx
+	mov	rbx, rdi
+	mov	edx, 1024
+	mov	edi, esi
+	mov	rsi, rax
+	call	qword ptr [rip + read@GOTPCREL]
+	test	rax, rax
+	js	.LBB41_2
+	mov	qword ptr [rbx + 8], rax
+	xor	eax, eax
+	jmp	.LBB41_3
+.LBB41_2:
+	call	qword ptr [rip + __errno_location@GOTPCREL]
+	mov	eax, dword ptr [rax]
+	mov	dword ptr [rbx + 4], eax
+	mov	eax, 1
+.LBB41_3:
+	mov	dword ptr [rbx], eax
+	pop	rbx
+	ret
+.Lfunc_end41:
+
+optional_trait_methods::connection::write_fd:
+.Lfunc_begin42:
+	push	rbp
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	push	rax
+	test	rdx, rdx
+	je	.LBB42_8
+	mov	ebp, ecx
+	mov	rbx, rdx
+	mov	r14, rsi
+	mov	r15d, edi
+	mov	r12, qword ptr [rip + send@GOTPCREL]
+	mov	r13, qword ptr [rip + write@GOTPCREL]
+.LBB42_2:
+	mov	edi, r15d
+	mov	rsi, r14
+	mov	rdx, rbx
+	test	bpl, bpl
+	je	.LBB42_3
+	mov	ecx, 16384
+	call	r12
+	jmp	.LBB42_5
+.LBB42_3:
+	call	r13
+.LBB42_5:
+	test	rax, rax
+	js	.LBB42_10
+	cmp	rbx, rax
+	jb	.LBB42_9
+	add	r14, rax
+	sub	rbx, rax
+	jne	.LBB42_2
+.LBB42_8:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+.LBB42_10:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	pop	rbp
+	jmp	qword ptr [rip + __errno_location@GOTPCREL]
+.LBB42_9:
+	mov	rdi, rax
+	mov	rsi, rbx
+	mov	rdx, rbx
+	call	core::slice::index::slice_index_fail
+.Lfunc_end42:
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.0:
+	.byte	10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.1:
+	.byte	59
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.2:
+	.ascii	"E 01"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.3:
+	.ascii	"E 02"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.4:
+	.ascii	"E."
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.5:
+	.ascii	" at "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.6:
+	.ascii	"E 05"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.7:
+	.ascii	"unknown option: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.8:
+	.ascii	"missing value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.9:
+	.ascii	"invalid value for "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.10:
+	.ascii	": "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.11:
+	.ascii	" doesn't take a value"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.12:
+	.ascii	"only one of --unix / --tcp may be given"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.14:
+	.ascii	"0123456789abcdef"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.17:
+	.ascii	"--unix"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.18:
+	.ascii	"--tcp"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.19:
+	.ascii	"--initial-state"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.20:
+	.ascii	"--on-error"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.21:
+	.ascii	"--echo"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.22:
+	.ascii	"--repl"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.23:
+	.ascii	"--help"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.24:
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.17
+	.asciz	"\006\000\000\000\000\000\000\000"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.18
+	.ascii	"\005\000\000\000\000\000\000\000\001"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.19
+	.ascii	"\017\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.20
+	.ascii	"\n\000\000\000\000\000\000\000\003"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.21
+	.ascii	"\006\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.22
+	.ascii	"\006\000\000\000\000\000\000\000\005"
+	.zero	7
+	.quad	.Lanon.62fb3b87f511695ce61fb365a19fad11.23
+	.ascii	"\006\000\000\000\000\000\000\000\006"
+	.zero	7
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.28:
+	.ascii	"Invalid implementation!"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.29:
+	.ascii	"OK"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.30:
+	.ascii	"panic!\n"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.31:
+	.ascii	"listening on 127.0.0.1:"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.32:
+	.ascii	"error: "
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.33:
+	.zero	2,10
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.34:
+	.ascii	"usage: optional-trait-methods [options]\n\n  --unix <path>              serve the first client to connect to a Unix domain socket\n  --tcp <port>               serve the first client to connect to 127.0.0.1:<port>\n                             (port 0 picks any free port, printed to stderr)\n                             (by default, serve stdin / stdout)\n  --initial-state <n>        start the target off in state <n> (by default, 0)\n  --on-error=continue|exit   whether to keep serving after a command fails\n  --echo                     repeat every command before its reply\n  --repl                     expect a human instead of a client (prompt, aliases, help)\n                             (needs the `repl` feature)\n  --help                     print this message\n\n  (`--name <value>` may also be written `--name=<value>`)"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.35:
+	.ascii	"Parse IncDec extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.39:
+	.ascii	"Parse Mul extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.41:
+	.ascii	"Parse ScaleFactor extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.43:
+	.ascii	"Parse ScaleBounds extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.45:
+	.ascii	"Parse Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.48:
+	.ascii	"Parse Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.57:
+	.ascii	"IncDec extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.58:
+	.ascii	"Mul extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.59:
+	.ascii	"ScaleFactor nested extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.60:
+	.ascii	"ScaleBounds nested extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.61:
+	.ascii	"Div extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.62:
+	.ascii	"Undo extension"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.63:
+	.ascii	"Undo checkpoint"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.64:
+	.ascii	"unknown command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.65:
+	.ascii	"unsupported command"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.66:
+	.ascii	"bad number"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.67:
+	.ascii	"trailing garbage"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.68:
+	.ascii	"line too long"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.72:
+	.ascii	"failed to accept a connection"
+
+.Lanon.62fb3b87f511695ce61fb365a19fad11.73:
+	.ascii	"--repl needs the `repl` feature"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.0:
+	.ascii	"incdec"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.1:
+	.ascii	"mul"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.2:
+	.ascii	"mul.scale_factor"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.3:
+	.ascii	"mul.scale_factor.scale_bounds"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.4:
+	.ascii	"div"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.5:
+	.ascii	"undo"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.6:
+	.byte	1
+	.zero	7
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.0
+	.ascii	"\006\000\000\000\000\000\000\000\002"
+	.zero	7
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.1
+	.ascii	"\003\000\000\000\000\000\000\000\004"
+	.zero	7
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.2
+	.ascii	"\020\000\000\000\000\000\000\000\b"
+	.zero	7
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.3
+	.ascii	"\035\000\000\000\000\000\000\000\020"
+	.zero	7
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.4
+	.ascii	"\003\000\000\000\000\000\000\000 "
+	.zero	7
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.5
+	.asciz	"\004\000\000\000\000\000\000"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.15:
+	.byte	43
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.17:
+	.byte	45
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.18:
+	.byte	42
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.20:
+	.ascii	"*~"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.28:
+	.ascii	"+-"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.29:
+	.ascii	"*~~"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.30:
+	.byte	47
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.31:
+	.byte	37
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.32:
+	.byte	117
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.33:
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.15
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.17
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.28
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.18
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.20
+	.asciz	"\002\000\000\000\000\000\000"
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.29
+	.asciz	"\003\000\000\000\000\000\000"
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.30
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.31
+	.asciz	"\001\000\000\000\000\000\000"
+	.quad	.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.32
+	.asciz	"\001\000\000\000\000\000\000"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.42:
+	.ascii	"scale bounds are inverted!"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.43:
+	.ascii	"dividing by zero!"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.44:
+	.ascii	"dividing MIN by -1 overflows!"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.45:
+	.ascii	"multiplying by 7 is unlucky!"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.46:
+	.ascii	"not enough history!"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.47:
+	.ascii	"nothing to undo!"
+
+.Lanon.00fcc60eea88d89aa507c5a8ec6a1efb.50:
+	.ascii	"nothing to roll back!"
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Stdio>>.85.rel
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>:
+	.quad	15
+	.quad	19
+	.quad	10
+	.quad	16
+	.quad	13
+
+.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel:
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.64-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.65-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.66-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.67-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+	.long	.Lanon.62fb3b87f511695ce61fb365a19fad11.68-.Lswitch.table.optional_trait_methods::response::reply::<i128, &str, optional_trait_methods::writer::Buffered<optional_trait_methods::connection::Socket>>.86.rel
+
+	.byte	1
+	.byte	17
+	.byte	1
+	.byte	37
+	.byte	14
+	.byte	19
+	.byte	5
+	.byte	3
+	.byte	14
+	.byte	16
+	.byte	23
+	.byte	27
+	.byte	14
+	.byte	17
+	.byte	1
+	.byte	85
+	.byte	23
+	.byte	0
+	.byte	0
+	.byte	2
+	.byte	57
+	.byte	1
+	.byte	3
+	.byte	14
+	.byte	0
+	.byte	0
+	.byte	3
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	11
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	4
+	.byte	46
+	.byte	0
+	.byte	17
+	.byte	1
+	.byte	18
+	.byte	6
+	.byte	64
+	.byte	24
+	.byte	110
+	.byte	14
+	.byte	3
+	.byte	14
+	.byte	58
+	.byte	11
+	.byte	59
+	.byte	5
+	.byte	54
+	.byte	11
+	.byte	63
+	.byte	25
+	.ascii	"\207\001"
+	.byte	25
+	.byte	0
+	.byte	0
+	.byte	0
+.Lcu_begin0:
+	.long	.Ldebug_info_end0-.Ldebug_info_start0
+.Ldebug_info_start0:
+	.short	4
+	.long	.debug_abbrev
+	.byte	8
+	.byte	1
+	.long	.Linfo_string0
+	.short	28
+	.long	.Linfo_string1
+	.long	.Lline_table_start0
+	.long	.Linfo_string2
+	.quad	0
+	.long	.Ldebug_ranges0
+	.byte	2
+	.long	.Linfo_string3
+	.byte	2
+	.long	.Linfo_string4
+	.byte	3
+	.quad	.Lfunc_begin16
+	.long	.Lfunc_end16-.Lfunc_begin16
+	.byte	1
+	.byte	86
+	.long	.Linfo_string7
+	.long	.Linfo_string8
+	.byte	1
+	.byte	60
+	.byte	3
+
+
+	.byte	4
+	.quad	.Lfunc_begin17
+	.long	.Lfunc_end17-.Lfunc_begin17
+	.byte	1
+	.byte	86
+	.long	.Linfo_string9
+	.long	.Linfo_string10
+	.byte	1
+	.short	266
+	.byte	3
+
+
+	.byte	0
+	.byte	2
+	.long	.Linfo_string5
+	.byte	2
+	.long	.Linfo_string6
+	.byte	3
+	.quad	.Lfunc_begin18
+	.long	.Lfunc_end18-.Lfunc_begin18
+	.byte	1
+	.byte	86
+	.long	.Linfo_string11
+	.long	.Linfo_string12
+	.byte	2
+	.byte	38
+	.byte	3
+
+
+	.byte	0
+	.byte	0
+	.byte	0
+	.byte	0
+.Ldebug_info_end0:
+.Lsec_end0:
+.Lsec_end1:
+.Lsec_end2:
+	.long	76
+	.short	2
+	.long	.Lcu_begin0
+	.byte	8
+	.byte	0
+	.zero	4,255
+	.quad	.Lfunc_begin16
+	.quad	.Lsec_end0-.Lfunc_begin16
+	.quad	.Lfunc_begin17
+	.quad	.Lsec_end1-.Lfunc_begin17
+	.quad	.Lfunc_begin18
+	.quad	.Lsec_end2-.Lfunc_begin18
+	.quad	0
+	.quad	0
+.Ldebug_ranges0:
+	.quad	.Lfunc_begin16
+	.quad	.Lfunc_end16
+	.quad	.Lfunc_begin17
+	.quad	.Lfunc_end17
+	.quad	.Lfunc_begin18
+	.quad	.Lfunc_end18
+	.quad	0
+	.quad	0
+.Linfo_string0:
+	.asciz	"clang LLVM (rustc version 1.97.0-nightly (e50aa6fba 2026-05-19))"
+.Linfo_string1:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a/library/core/src/lib.rs/@/core.667c7a611d73a360-cgu.0"
+.Linfo_string2:
+	.asciz	"/rustc/e50aa6fba4e63ab34c72bf9acfd2c307c1155d1a"
+.Linfo_string3:
+	.asciz	"core"
+.Linfo_string4:
+	.asciz	"panicking"
+.Linfo_string5:
+	.asciz	"slice"
+.Linfo_string6:
+	.asciz	"index"
+.Linfo_string7:
+	.asciz	"core::panicking::panic_fmt"
+.Linfo_string8:
+	.asciz	"panic_fmt"
+.Linfo_string9:
+	.asciz	"core::panicking::panic_bounds_check"
+.Linfo_string10:
+	.asciz	"panic_bounds_check"
+.Linfo_string11:
+	.asciz	"core::slice::index::slice_index_fail"
+.Linfo_string12:
+	.asciz	"slice_index_fail"
+	.ident	"rustc version 1.97.0-nightly (e50aa6fba 2026-05-19)"
+.Lline_table_start0:
@@ -8,6 +8,23 @@ import glob
 import os
 import sys
 
+def strip_generic_args(label):
+    """
+    Drops the generic arguments a function's label ends with (e.g: `reply::<&str>` -> `reply`), so
+    that every instantiation of a generic function is counted under its name.
+    """
+    if not label.endswith('>'):
+        return label
+    depth = 0
+    for i in range(len(label) - 1, -1, -1):
+        if label[i] == '>' and label[i - 1] != '-':
+            depth += 1
+        elif label[i] == '<':
+            depth -= 1
+            if depth == 0:
+                return label[:i - 2] if label[:i].endswith('::') else label
+    return label
+
 def parse_asm_file(filepath):
     """
     Parses a demangled .s assembly file and extracts metrics for each function symbol.
@@ -28,8 +45,9 @@ def parse_asm_file(filepath):
             if line.endswith(':\n') and not line.startswith('.') and not line.startswith('#'):
                 label = raw_line[:-1]
                 # Extract short symbol name
-                if '::' in label:
-                    short_name = label.rsplit('::', 1)[-1]
+                name = strip_generic_args(label)
+                if '::' in name:
+                    short_name = name.rsplit('::', 1)[-1]
                     if '>' in short_name:
                         short_name = short_name.split('>')[-1].strip(':').strip()
                     if short_name.startswith('parse_command'):
@@ -50,11 +68,14 @@ def parse_asm_file(filepath):
             # Check for end of function marker (.Lfunc_endN:)
             if raw_line.startswith('.Lfunc_end'):
                 if current_symbol:
-                    functions[current_symbol] = {
-                        'instructions': instr_count,
-                        'total_lines': line_count,
+                    # (summed over every instantiation of a generic function)
+                    metrics = functions.setdefault(current_symbol, {
+                        'instructions': 0,
+                        'total_lines': 0,
                         'full_name': current_full_name,
-                    }
+                    })
+                    metrics['instructions'] += instr_count
+                    metrics['total_lines'] += line_count
                     current_symbol = None
                 continue
 
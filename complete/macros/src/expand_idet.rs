@@ -0,0 +1,114 @@
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use quote::quote_spanned;
+use syn::Ident;
+use syn::ImplItem;
+use syn::ItemImpl;
+use syn::meta::ParseNestedMeta;
+
+use crate::groups::Args;
+use crate::groups::camel_case;
+use crate::groups::listed_ident;
+use crate::groups::sibling_path;
+
+/// A single `#[idet(...)]` entry: `mul`, or `incdec = IncDec`.
+struct Listed {
+    ident: Ident,
+    name: Option<Ident>,
+}
+
+/// Arguments passed to `#[idet(...)]`.
+#[derive(Default)]
+pub(crate) struct IdetArgs {
+    args: Args,
+    listed: Vec<Listed>,
+}
+
+impl IdetArgs {
+    pub fn parse_meta(&mut self, meta: ParseNestedMeta<'_>) -> syn::Result<()> {
+        if meta.path.is_ident("ext_attr") {
+            return self.args.parse_meta(meta);
+        }
+
+        let ident = meta.path.require_ident()?.clone();
+        if self.listed.iter().any(|l| l.ident == ident) {
+            return Err(meta.error(format!("`{}` is listed more than once", ident)));
+        }
+        let name = if meta.input.peek(syn::Token![=]) {
+            Some(meta.value()?.parse()?)
+        } else {
+            None
+        };
+        self.listed.push(Listed { ident, name });
+        Ok(())
+    }
+}
+
+/// `TargetExtMul` -> `Target`
+fn root_ident(trait_ident: &Ident) -> Ident {
+    let s = trait_ident.to_string();
+    for (i, _) in s.match_indices("Ext") {
+        if i > 0 && s.as_bytes().get(i + 3).is_some_and(u8::is_ascii_uppercase) {
+            return Ident::new(&s[..i], trait_ident.span());
+        }
+    }
+    trait_ident.clone()
+}
+
+pub(crate) fn expand(args: &IdetArgs, mut item: ItemImpl) -> syn::Result<TokenStream> {
+    let trait_path = match &item.trait_ {
+        Some((None, path, _)) => path.clone(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &item.self_ty,
+                "expected an `impl Trait for Type` block",
+            ));
+        }
+    };
+    let root = root_ident(&trait_path.segments.last().unwrap().ident);
+
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+    let self_ty = &item.self_ty;
+    let ext_attrs = args.args.ext_attrs();
+
+    let mut ext_fns = Vec::new();
+    let mut out = TokenStream::new();
+    for Listed { ident, name } in &args.listed {
+        let camel = match name {
+            Some(name) => name.to_string(),
+            None => camel_case(&ident.to_string()),
+        };
+        let trait_ident = format_ident!("{}Ext{}", root, camel, span = ident.span());
+        let ops_path = sibling_path(&trait_path, format_ident!("{}Ops", trait_ident));
+        let listed_path = sibling_path(&trait_path, listed_ident(&trait_ident));
+        let ext_fn_ident = format_ident!("ext_{}", ident, span = ident.span());
+
+        // targets may provide their own `ext_*` implementation (e.g: to toggle
+        // an extension at runtime), in which case it's left untouched.
+        let has_ext_fn = item
+            .items
+            .iter()
+            .any(|i| matches!(i, ImplItem::Fn(f) if f.sig.ident == ext_fn_ident));
+        if !has_ext_fn {
+            // spanned so that a missing extension trait impl points back at
+            // the offending entry in `#[idet(...)]`
+            ext_fns.push(syn::parse2::<ImplItem>(quote_spanned! {ident.span()=>
+                #ext_attrs
+                fn #ext_fn_ident(&mut self) -> ::core::option::Option<#ops_path<'_, Self>> {
+                    ::core::option::Option::Some(self)
+                }
+            })?);
+        }
+
+        out.extend(quote! {
+            impl #impl_generics #listed_path for #self_ty #where_clause {}
+        });
+    }
+
+    item.items.extend(ext_fns);
+    Ok(quote! {
+        #item
+        #out
+    })
+}
@@ -0,0 +1,99 @@
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use syn::ImplItem;
+use syn::ImplItemFn;
+use syn::ItemImpl;
+
+use crate::groups::Args;
+use crate::groups::Group;
+use crate::groups::Groups;
+use crate::groups::OptionalAttr;
+use crate::groups::sibling_path;
+
+pub(crate) fn expand(args: &Args, mut item: ItemImpl) -> syn::Result<TokenStream> {
+    let trait_path = match &item.trait_ {
+        Some((None, path, _)) => path.clone(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &item.self_ty,
+                "expected an `impl Trait for Type` block",
+            ));
+        }
+    };
+    let root = trait_path.segments.last().unwrap().ident.clone();
+
+    let mut root_items = Vec::new();
+    let mut base_items = Vec::new();
+    let mut groups = Groups::new();
+    for impl_item in item.items.drain(..) {
+        match impl_item {
+            ImplItem::Fn(mut f) => match OptionalAttr::take(&mut f.attrs)? {
+                Some(attr) => groups.insert(attr, f)?,
+                None => base_items.push(f),
+            },
+            other => root_items.push(other),
+        }
+    }
+    groups.validate(&root)?;
+
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+    let self_ty = &item.self_ty;
+
+    let ext_attrs = args.ext_attrs();
+    let ext_fn = |group: &Group<ImplItemFn>| {
+        let ext_fn_ident = group.ext_fn_ident();
+        let ops_path = sibling_path(&trait_path, group.ops_ident(&root));
+        quote! {
+            #ext_attrs
+            fn #ext_fn_ident(&mut self) -> ::core::option::Option<#ops_path<'_, Self>> {
+                ::core::option::Option::Some(self)
+            }
+        }
+    };
+
+    let mut out = TokenStream::new();
+
+    if !base_items.is_empty() {
+        let base_path = sibling_path(&trait_path, format_ident!("{}Base", root));
+        let base_ops_path = sibling_path(&trait_path, format_ident!("{}BaseOps", root));
+        root_items.push(syn::parse_quote! {
+            #ext_attrs
+            fn base(&mut self) -> #base_ops_path<'_, Self> {
+                self
+            }
+        });
+
+        out.extend(quote! {
+            impl #impl_generics #base_path for #self_ty #where_clause {
+                #(#base_items)*
+            }
+        });
+    }
+
+    for group in groups.top_level() {
+        root_items.push(syn::parse2(ext_fn(group))?);
+    }
+
+    for group in &groups.groups {
+        let group_trait_path = sibling_path(&trait_path, group.trait_ident(&root));
+        let listed_path = sibling_path(&trait_path, group.listed_ident(&root));
+        let items = &group.items;
+        let child_ext_fns = groups.children(group).map(&ext_fn);
+
+        out.extend(quote! {
+            impl #impl_generics #group_trait_path for #self_ty #where_clause {
+                #(#items)*
+                #(#child_ext_fns)*
+            }
+
+            impl #impl_generics #listed_path for #self_ty #where_clause {}
+        });
+    }
+
+    item.items = root_items;
+    Ok(quote! {
+        #item
+        #out
+    })
+}
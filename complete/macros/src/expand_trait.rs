@@ -0,0 +1,140 @@
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use syn::ItemTrait;
+use syn::TraitItem;
+use syn::TraitItemFn;
+
+use crate::groups::Args;
+use crate::groups::Group;
+use crate::groups::Groups;
+use crate::groups::OptionalAttr;
+
+pub(crate) fn expand(args: &Args, mut item: ItemTrait) -> syn::Result<TokenStream> {
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &item.generics,
+            "generic traits are not supported",
+        ));
+    }
+
+    let root = item.ident.clone();
+    let vis = item.vis.clone();
+
+    let mut root_items = Vec::new();
+    let mut base_items = Vec::new();
+    let mut assoc_types = Vec::new();
+    let mut groups = Groups::new();
+    for trait_item in item.items.drain(..) {
+        match trait_item {
+            TraitItem::Fn(mut f) => match OptionalAttr::take(&mut f.attrs)? {
+                Some(attr) => groups.insert(attr, f)?,
+                None if f.default.is_some() => root_items.push(TraitItem::Fn(f)),
+                None => base_items.push(f),
+            },
+            TraitItem::Type(ty) => {
+                assoc_types.push(ty.ident.clone());
+                root_items.push(TraitItem::Type(ty));
+            }
+            other => root_items.push(other),
+        }
+    }
+    groups.validate(&root)?;
+
+    // every trait object needs to name the root trait's associated types
+    let bindings = if assoc_types.is_empty() {
+        quote!()
+    } else {
+        quote!(<#(#assoc_types = <T as #root>::#assoc_types),*>)
+    };
+    let ops_alias = |trait_ident: &syn::Ident, ops_ident: &syn::Ident, doc: String| {
+        quote! {
+            #[doc = #doc]
+            #vis type #ops_ident<'a, T> = &'a mut dyn #trait_ident #bindings;
+        }
+    };
+
+    let ext_attrs = args.ext_attrs();
+    let ext_fn = |group: &Group<TraitItemFn>| {
+        let ext_fn_ident = group.ext_fn_ident();
+        let ops_ident = group.ops_ident(&root);
+        quote! {
+            #ext_attrs
+            fn #ext_fn_ident(&mut self) -> ::core::option::Option<#ops_ident<'_, Self>> {
+                ::core::option::Option::None
+            }
+        }
+    };
+
+    let mut out = TokenStream::new();
+
+    if !base_items.is_empty() {
+        let base_ident = format_ident!("{}Base", root);
+        let base_ops_ident = format_ident!("{}BaseOps", root);
+        root_items.push(syn::parse_quote! {
+            fn base(&mut self) -> #base_ops_ident<'_, Self>;
+        });
+
+        let doc = format!("Base protocol methods of [`{}`].", root);
+        out.extend(quote! {
+            #[doc = #doc]
+            #vis trait #base_ident: #root {
+                #(#base_items)*
+            }
+        });
+        out.extend(ops_alias(
+            &base_ident,
+            &base_ops_ident,
+            format!("Handle to [`{}`].", base_ident),
+        ));
+    }
+
+    for group in groups.top_level() {
+        root_items.push(syn::parse2(ext_fn(group))?);
+    }
+
+    for group in &groups.groups {
+        let trait_ident = group.trait_ident(&root);
+        let items = &group.items;
+        let child_ext_fns = groups.children(group).map(&ext_fn);
+
+        let listed_ident = group.listed_ident(&root);
+
+        let doc = format!("Methods of {} of [`{}`].", group.description(), root);
+        out.extend(quote! {
+            #[doc = #doc]
+            #vis trait #trait_ident: #root + #listed_ident {
+                #(#items)*
+                #(#child_ext_fns)*
+            }
+        });
+
+        // implemented by `#[idet(...)]`, ensuring a type can't implement an
+        // extension trait without also overriding its `ext_*` method.
+        let doc = format!(
+            "Marker for types which list `{}` in their `#[idet(...)]` attribute.",
+            group.last_segment()
+        );
+        let message = format!(
+            "`{{Self}}` implements `{}`, but doesn't list `{}` in its `#[idet(...)]` attribute",
+            trait_ident,
+            group.last_segment()
+        );
+        out.extend(quote! {
+            #[doc = #doc]
+            #[diagnostic::on_unimplemented(message = #message)]
+            #vis trait #listed_ident {}
+        });
+        out.extend(ops_alias(
+            &trait_ident,
+            &group.ops_ident(&root),
+            format!("Handle to [`{}`].", trait_ident),
+        ));
+    }
+
+    item.items = root_items;
+    Ok(quote! {
+        #item
+        #out
+    })
+}
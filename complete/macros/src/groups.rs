@@ -0,0 +1,256 @@
+use proc_macro2::Span;
+use proc_macro2::TokenStream;
+use quote::format_ident;
+use quote::quote;
+use syn::Attribute;
+use syn::Ident;
+use syn::LitStr;
+use syn::Meta;
+use syn::Path;
+use syn::meta::ParseNestedMeta;
+
+/// `scale_factor` -> `ScaleFactor`
+pub(crate) fn camel_case(s: &str) -> String {
+    s.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(c) => c.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// `TargetExtIncDec` -> `TargetExtIncDecListed`
+pub(crate) fn listed_ident(trait_ident: &Ident) -> Ident {
+    format_ident!("{}Listed", trait_ident)
+}
+
+/// `path::to::Target` -> `path::to::<ident>`
+pub(crate) fn sibling_path(path: &Path, ident: Ident) -> Path {
+    let mut path = path.clone();
+    let last = path.segments.last_mut().expect("paths are non-empty");
+    last.ident = ident;
+    last.arguments = syn::PathArguments::None;
+    path
+}
+
+/// Arguments passed to `#[optional_trait_methods(...)]`.
+#[derive(Default)]
+pub(crate) struct Args {
+    /// Attributes forwarded to every generated `base()` / `ext_*()` method.
+    pub ext_attrs: Vec<Meta>,
+}
+
+impl Args {
+    pub fn parse_meta(&mut self, meta: ParseNestedMeta<'_>) -> syn::Result<()> {
+        if meta.path.is_ident("ext_attr") {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            self.ext_attrs.push(content.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported argument (expected `ext_attr(...)`)"))
+        }
+    }
+
+    pub fn ext_attrs(&self) -> TokenStream {
+        let attrs = &self.ext_attrs;
+        quote!(#(#[#attrs])*)
+    }
+}
+
+/// A parsed `#[optional(group = "...", name = "...")]` annotation.
+pub(crate) struct OptionalAttr {
+    group: LitStr,
+    name: Option<LitStr>,
+}
+
+impl OptionalAttr {
+    /// Removes the `#[optional(...)]` annotation from `attrs` (if present).
+    pub fn take(attrs: &mut Vec<Attribute>) -> syn::Result<Option<OptionalAttr>> {
+        let Some(idx) = attrs.iter().position(|a| a.path().is_ident("optional")) else {
+            return Ok(None);
+        };
+        let attr = attrs.remove(idx);
+        if let Some(dup) = attrs.iter().find(|a| a.path().is_ident("optional")) {
+            return Err(syn::Error::new_spanned(
+                dup,
+                "duplicate `#[optional]` annotation",
+            ));
+        }
+
+        let mut group = None;
+        let mut name = None;
+        attr.parse_nested_meta(|meta: ParseNestedMeta<'_>| {
+            if meta.path.is_ident("group") {
+                group = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("unsupported argument (expected `group` or `name`)"));
+            }
+            Ok(())
+        })?;
+
+        let Some(group) = group else {
+            return Err(syn::Error::new_spanned(attr, "missing `group = \"...\"`"));
+        };
+        Ok(Some(OptionalAttr { group, name }))
+    }
+}
+
+/// A group of optional methods, which maps onto a single extension trait.
+pub(crate) struct Group<T> {
+    /// Dotted path (e.g: `["mul", "scale_factor"]`).
+    path: Vec<String>,
+    name: Option<LitStr>,
+    span: Span,
+    pub items: Vec<T>,
+}
+
+impl<T> Group<T> {
+    pub fn key(&self) -> String {
+        self.path.join(".")
+    }
+
+    pub fn is_top_level(&self) -> bool {
+        self.path.len() == 1
+    }
+
+    pub fn is_child_of(&self, parent: &Group<T>) -> bool {
+        self.path.len() == parent.path.len() + 1 && self.path.starts_with(&parent.path)
+    }
+
+    fn camel_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.value(),
+            None => camel_case(self.last_segment()),
+        }
+    }
+
+    /// `TargetExtIncDec`
+    pub fn trait_ident(&self, root: &Ident) -> Ident {
+        format_ident!("{}Ext{}", root, self.camel_name(), span = self.span)
+    }
+
+    /// `TargetExtIncDecOps`
+    pub fn ops_ident(&self, root: &Ident) -> Ident {
+        format_ident!("{}Ext{}Ops", root, self.camel_name(), span = self.span)
+    }
+
+    /// `TargetExtIncDecListed`
+    pub fn listed_ident(&self, root: &Ident) -> Ident {
+        listed_ident(&self.trait_ident(root))
+    }
+
+    /// `scale_factor` (for `mul.scale_factor`)
+    pub fn last_segment(&self) -> &str {
+        self.path.last().expect("group paths are non-empty")
+    }
+
+    /// `ext_incdec`
+    pub fn ext_fn_ident(&self) -> Ident {
+        format_ident!("ext_{}", self.last_segment(), span = self.span)
+    }
+
+    pub fn description(&self) -> String {
+        format!("the `{}` extension", self.key())
+    }
+}
+
+/// Optional methods, bucketed by group (in declaration order).
+pub(crate) struct Groups<T> {
+    pub groups: Vec<Group<T>>,
+}
+
+impl<T> Groups<T> {
+    pub fn new() -> Groups<T> {
+        Groups { groups: Vec::new() }
+    }
+
+    pub fn insert(&mut self, attr: OptionalAttr, item: T) -> syn::Result<()> {
+        let key = attr.group.value();
+        let path: Vec<String> = key.split('.').map(str::to_owned).collect();
+        for segment in &path {
+            if syn::parse_str::<Ident>(segment).is_err() {
+                return Err(syn::Error::new_spanned(
+                    &attr.group,
+                    format!("`{}` is not a valid group name", key),
+                ));
+            }
+        }
+
+        let group = match self.groups.iter_mut().find(|g| g.path == path) {
+            Some(group) => group,
+            None => {
+                self.groups.push(Group {
+                    path,
+                    name: None,
+                    span: attr.group.span(),
+                    items: Vec::new(),
+                });
+                self.groups.last_mut().unwrap()
+            }
+        };
+
+        if let Some(name) = attr.name {
+            match &group.name {
+                Some(existing) if existing.value() != name.value() => {
+                    return Err(syn::Error::new_spanned(
+                        name,
+                        format!("group `{}` was already named `{}`", key, existing.value()),
+                    ));
+                }
+                _ => group.name = Some(name),
+            }
+        }
+
+        group.items.push(item);
+        Ok(())
+    }
+
+    /// Ensures every nested group has a parent, and that no two groups map
+    /// onto the same extension trait.
+    pub fn validate(&self, root: &Ident) -> syn::Result<()> {
+        for (i, group) in self.groups.iter().enumerate() {
+            if !group.is_top_level() && !self.groups.iter().any(|p| group.is_child_of(p)) {
+                let parent = group.path[..group.path.len() - 1].join(".");
+                return Err(syn::Error::new(
+                    group.span,
+                    format!(
+                        "nested group `{}` requires at least one method in its parent group `{}`",
+                        group.key(),
+                        parent
+                    ),
+                ));
+            }
+
+            let trait_ident = group.trait_ident(root);
+            if let Some(other) = self.groups[..i]
+                .iter()
+                .find(|other| other.trait_ident(root) == trait_ident)
+            {
+                return Err(syn::Error::new(
+                    group.span,
+                    format!(
+                        "groups `{}` and `{}` would both generate `{}` (use `name = \"...\"` to disambiguate)",
+                        other.key(),
+                        group.key(),
+                        trait_ident
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn top_level(&self) -> impl Iterator<Item = &Group<T>> {
+        self.groups.iter().filter(|g| g.is_top_level())
+    }
+
+    pub fn children<'a>(&'a self, parent: &'a Group<T>) -> impl Iterator<Item = &'a Group<T>> {
+        self.groups.iter().filter(move |g| g.is_child_of(parent))
+    }
+}
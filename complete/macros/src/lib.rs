@@ -0,0 +1,150 @@
+//! Proc macros that generate the boilerplate behind Inlineable Dyn Extension
+//! Traits (IDETs).
+//!
+//! Hand-rolling an IDET hierarchy means keeping several pieces in sync for
+//! every extension: the `ext_*` default method on the parent trait, the
+//! `TargetExt*` trait itself, its `*Ops` alias, and (on the implementation
+//! side) an `ext_*` override returning `Some(self)`. Forgetting any one of them
+//! silently disables an extension. `#[optional_trait_methods]` derives all of
+//! them from a single flat trait definition, and `#[idet(...)]` generates the
+//! `ext_*` overrides for hand-written implementations.
+
+use proc_macro::TokenStream;
+use syn::Item;
+use syn::parse_macro_input;
+
+mod expand_idet;
+mod expand_impl;
+mod expand_trait;
+mod groups;
+
+use expand_idet::IdetArgs;
+use groups::Args;
+
+/// Expands a flat trait (or an implementation of one) into an IDET hierarchy.
+///
+/// Methods are sorted into traits as follows:
+///
+/// - `#[optional(group = "incdec")]` methods are moved into a
+///   `<Trait>Ext<Group>` extension trait, which is exposed via an
+///   `ext_incdec()` method on the root trait.
+/// - Nested groups are written as dotted paths (e.g: `"mul.scale_factor"`), and
+///   expose their `ext_*()` method on the parent group's trait.
+/// - Required methods without an `#[optional]` annotation are moved into a
+///   `<Trait>Base` trait, exposed via a (required) `base()` method.
+/// - Associated types, consts, and provided methods stay on the root trait.
+///
+/// Extension trait names are derived from the last segment of the group path
+/// (`scale_factor` -> `ScaleFactor`). Use `name = "..."` on any one method of
+/// the group to override this (e.g: `#[optional(group = "incdec", name =
+/// "IncDec")]`).
+///
+/// Every extension trait also gets a `<Trait>Ext<Group>Ops<'a, T>` alias for
+/// the `&'a mut dyn` trait object returned from its `ext_*()` method.
+///
+/// ```ignore
+/// #[optional_trait_methods]
+/// pub trait Target {
+///     type Error;
+///
+///     fn get_state(&self) -> isize;
+///     fn set_state(&mut self, n: isize) -> Result<(), Self::Error>;
+///
+///     #[optional(group = "incdec", name = "IncDec")]
+///     fn inc(&mut self) -> Result<(), Self::Error>;
+///     #[optional(group = "incdec")]
+///     fn dec(&mut self) -> Result<(), Self::Error>;
+///
+///     #[optional(group = "mul")]
+///     fn mul(&mut self, n: isize) -> Result<(), Self::Error>;
+///
+///     #[optional(group = "mul.scale_factor")]
+///     fn scale_factor(&mut self, factor: isize) -> Result<(), Self::Error>;
+/// }
+/// ```
+///
+/// When applied to an `impl Trait for Type` block, the same annotations are
+/// used to split the block into one impl per generated trait, and to emit
+/// `base() -> self` / `ext_*() -> Some(self)` for every group that has at least
+/// one method in the block.
+///
+/// Every extension trait requires a `<Trait>Ext<Group>Listed` marker, which is
+/// implemented alongside the `ext_*` override (see [`macro@idet`]). As such,
+/// it's a compile error to implement an extension trait without also
+/// advertising it. The `name = "..."` overrides must match the ones
+/// used on the trait.
+///
+/// Extra attributes can be attached to every generated `base()` / `ext_*()`
+/// method using `ext_attr(...)`:
+///
+/// ```ignore
+/// #[optional_trait_methods(ext_attr(inline(always)))]
+/// ```
+#[proc_macro_attribute]
+pub fn optional_trait_methods(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut parsed_args = Args::default();
+    let args_parser = syn::meta::parser(|meta| parsed_args.parse_meta(meta));
+    parse_macro_input!(args with args_parser);
+    let args = parsed_args;
+
+    let res = match parse_macro_input!(item as Item) {
+        Item::Trait(item) => expand_trait::expand(&args, item),
+        Item::Impl(item) => expand_impl::expand(&args, item),
+        item => Err(syn::Error::new_spanned(
+            item,
+            "`#[optional_trait_methods]` can only be applied to traits and trait impls",
+        )),
+    };
+
+    res.unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Generates the `ext_*() -> Some(self)` overrides for a hand-written
+/// implementation of a trait declared with `#[optional_trait_methods]`.
+///
+/// Each entry lists one extension implemented by the type, and is placed on
+/// the impl of the trait that declares its `ext_*` method (i.e: top-level
+/// groups are listed on `impl Target`, while nested groups are listed on the
+/// impl of their parent's extension trait):
+///
+/// ```ignore
+/// #[idet(incdec = IncDec, mul)]
+/// impl Target for AdvancedTarget {
+///     type Error = &'static str;
+///
+///     fn base(&mut self) -> TargetBaseOps<'_, Self> {
+///         self
+///     }
+/// }
+///
+/// #[idet(scale_factor)]
+/// impl TargetExtMul for AdvancedTarget {
+///     fn mul(&mut self, n: isize) -> Result<(), Self::Error> { /* ... */ }
+/// }
+/// ```
+///
+/// Entries are named after their `ext_*` method, and map onto extension traits
+/// the same way as `#[optional(group = ...)]` (`incdec = IncDec` mirrors `name
+/// = "IncDec"`). As with `#[optional_trait_methods]`, the generated code refers
+/// to the `*Ops` aliases and `*Listed` markers relative to the implemented
+/// trait's path, so they must be in scope.
+///
+/// Listing an extension whose trait isn't implemented is a compile error, as
+/// is implementing an extension trait without listing it. If the impl block
+/// already contains the corresponding `ext_*` method, it is kept as-is (e.g: to
+/// enable an extension at runtime), and only the marker is implemented.
+///
+/// `ext_attr(...)` is supported, and behaves the same as it does in
+/// `#[optional_trait_methods]`.
+#[proc_macro_attribute]
+pub fn idet(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut parsed_args = IdetArgs::default();
+    let args_parser = syn::meta::parser(|meta| parsed_args.parse_meta(meta));
+    parse_macro_input!(args with args_parser);
+    let args = parsed_args;
+
+    let item = parse_macro_input!(item as syn::ItemImpl);
+    expand_idet::expand(&args, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,219 @@
+use optional_trait_methods_macros::idet;
+use optional_trait_methods_macros::optional_trait_methods;
+
+#[optional_trait_methods(ext_attr(inline(always)))]
+pub trait Target {
+    type Error;
+
+    fn get_state(&self) -> isize;
+    fn set_state(&mut self, n: isize) -> Result<(), Self::Error>;
+
+    fn describe(&self) -> &'static str {
+        "target"
+    }
+
+    #[optional(group = "incdec", name = "IncDec")]
+    fn inc(&mut self) -> Result<(), Self::Error>;
+    #[optional(group = "incdec")]
+    fn dec(&mut self) -> Result<(), Self::Error>;
+
+    #[optional(group = "mul")]
+    fn mul(&mut self, n: isize) -> Result<(), Self::Error>;
+
+    #[optional(group = "mul.scale_factor")]
+    fn scale_factor(&mut self, factor: isize) -> Result<(), Self::Error>;
+}
+
+struct BasicTarget {
+    state: isize,
+}
+
+#[optional_trait_methods]
+impl Target for BasicTarget {
+    type Error = &'static str;
+
+    fn get_state(&self) -> isize {
+        self.state
+    }
+
+    fn set_state(&mut self, n: isize) -> Result<(), Self::Error> {
+        self.state = n;
+        Ok(())
+    }
+}
+
+struct AdvancedTarget {
+    state: isize,
+    scale: isize,
+}
+
+#[optional_trait_methods(ext_attr(inline(always)))]
+impl Target for AdvancedTarget {
+    type Error = &'static str;
+
+    fn get_state(&self) -> isize {
+        self.state
+    }
+
+    fn set_state(&mut self, n: isize) -> Result<(), Self::Error> {
+        self.state = n;
+        Ok(())
+    }
+
+    #[optional(group = "incdec", name = "IncDec")]
+    fn inc(&mut self) -> Result<(), Self::Error> {
+        self.state += 1;
+        Ok(())
+    }
+
+    #[optional(group = "incdec")]
+    fn dec(&mut self) -> Result<(), Self::Error> {
+        Err("`dec` operations are not supported yet")
+    }
+
+    #[optional(group = "mul")]
+    fn mul(&mut self, n: isize) -> Result<(), Self::Error> {
+        self.state *= n * self.scale;
+        Ok(())
+    }
+
+    #[optional(group = "mul.scale_factor")]
+    fn scale_factor(&mut self, factor: isize) -> Result<(), Self::Error> {
+        self.scale = factor;
+        Ok(())
+    }
+}
+
+// extension traits can also be implemented by hand, using `#[idet]` to
+// generate the `ext_*` overrides
+struct HandWrittenTarget;
+
+#[idet(mul)]
+impl Target for HandWrittenTarget {
+    type Error = ();
+
+    fn base(&mut self) -> TargetBaseOps<'_, Self> {
+        self
+    }
+}
+
+impl TargetBase for HandWrittenTarget {
+    fn get_state(&self) -> isize {
+        42
+    }
+
+    fn set_state(&mut self, _n: isize) -> Result<(), Self::Error> {
+        Err(())
+    }
+}
+
+impl TargetExtMul for HandWrittenTarget {
+    fn mul(&mut self, _n: isize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+// ...while keeping any `ext_*` overrides the impl already provides
+struct ToggledTarget {
+    mul_enabled: bool,
+}
+
+#[idet(mul, incdec = IncDec)]
+impl Target for ToggledTarget {
+    type Error = ();
+
+    fn base(&mut self) -> TargetBaseOps<'_, Self> {
+        self
+    }
+
+    fn ext_mul(&mut self) -> Option<TargetExtMulOps<'_, Self>> {
+        if self.mul_enabled { Some(self) } else { None }
+    }
+}
+
+impl TargetBase for ToggledTarget {
+    fn get_state(&self) -> isize {
+        0
+    }
+
+    fn set_state(&mut self, _n: isize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl TargetExtIncDec for ToggledTarget {
+    fn inc(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn dec(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[idet(scale_factor)]
+impl TargetExtMul for ToggledTarget {
+    fn mul(&mut self, _n: isize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl TargetExtScaleFactor for ToggledTarget {
+    fn scale_factor(&mut self, _factor: isize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+fn supported<T: Target>(target: &mut T) -> (bool, bool, bool) {
+    (
+        target.ext_incdec().is_some(),
+        target.ext_mul().is_some(),
+        target
+            .ext_mul()
+            .and_then(|ops| ops.ext_scale_factor())
+            .is_some(),
+    )
+}
+
+#[test]
+fn test_probes() {
+    assert_eq!(
+        supported(&mut BasicTarget { state: 0 }),
+        (false, false, false)
+    );
+    assert_eq!(
+        supported(&mut AdvancedTarget { state: 0, scale: 1 }),
+        (true, true, true)
+    );
+    assert_eq!(supported(&mut HandWrittenTarget), (false, true, false));
+    assert_eq!(
+        supported(&mut ToggledTarget { mul_enabled: true }),
+        (true, true, true)
+    );
+    assert_eq!(
+        supported(&mut ToggledTarget { mul_enabled: false }),
+        (true, false, false)
+    );
+}
+
+#[test]
+fn test_dispatch() {
+    let mut target = AdvancedTarget { state: 0, scale: 1 };
+
+    target.base().set_state(3).unwrap();
+    target.ext_incdec().unwrap().inc().unwrap();
+    assert_eq!(
+        target.ext_incdec().unwrap().dec(),
+        Err("`dec` operations are not supported yet")
+    );
+    target
+        .ext_mul()
+        .and_then(|ops| ops.ext_scale_factor())
+        .unwrap()
+        .scale_factor(2)
+        .unwrap();
+    target.ext_mul().unwrap().mul(5).unwrap();
+
+    assert_eq!(target.base().get_state(), 40);
+    assert_eq!(target.describe(), "target");
+}
@@ -0,0 +1,39 @@
+use optional_trait_methods_macros::idet;
+use optional_trait_methods_macros::optional_trait_methods;
+
+#[optional_trait_methods]
+pub trait Target {
+    type Error;
+
+    fn get_state(&self) -> isize;
+
+    #[optional(group = "mul")]
+    fn mul(&mut self, n: isize) -> Result<(), Self::Error>;
+}
+
+struct MyTarget;
+
+// `TargetExtMul` is implemented, but `mul` is missing from `#[idet(...)]` (so
+// `ext_mul` would never return it)
+#[idet()]
+impl Target for MyTarget {
+    type Error = ();
+
+    fn base(&mut self) -> TargetBaseOps<'_, Self> {
+        self
+    }
+}
+
+impl TargetBase for MyTarget {
+    fn get_state(&self) -> isize {
+        0
+    }
+}
+
+impl TargetExtMul for MyTarget {
+    fn mul(&mut self, _n: isize) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+use optional_trait_methods_macros::idet;
+use optional_trait_methods_macros::optional_trait_methods;
+
+#[optional_trait_methods]
+pub trait Target {
+    type Error;
+
+    fn get_state(&self) -> isize;
+
+    #[optional(group = "mul")]
+    fn mul(&mut self, n: isize) -> Result<(), Self::Error>;
+}
+
+struct MyTarget;
+
+// `mul` is advertised, but `TargetExtMul` is never implemented
+#[idet(mul)]
+impl Target for MyTarget {
+    type Error = ();
+
+    fn base(&mut self) -> TargetBaseOps<'_, Self> {
+        self
+    }
+}
+
+impl TargetBase for MyTarget {
+    fn get_state(&self) -> isize {
+        0
+    }
+}
+
+fn main() {}
@@ -4,6 +4,15 @@ set -e
 
 ITERATIONS_DEBUG=${ITERATIONS_DEBUG:-131072}
 ITERATIONS_RELEASE=${ITERATIONS_RELEASE:-262144}
+# `TRANSPORT=rsp` benchmarks GDB RSP-style packets instead of plain lines
+TRANSPORT=${TRANSPORT:-lines}
+
+TRANSPORT_FEATURE=""
+HARNESS_ARGS=""
+if [ "$TRANSPORT" = "rsp" ]; then
+    TRANSPORT_FEATURE="transport_rsp"
+    HARNESS_ARGS="rsp"
+fi
 
 if [ ! -f "Cargo.toml" ]; then
     echo "Error: Please run this script from the 'complete' directory."
@@ -23,7 +32,7 @@ build_bin() {
     fi
 
     touch src/main.rs
-    cargo +nightly build --bin optional-trait-methods --no-default-features --features="target_advanced using_${impl} always_inline" $release_flag >/dev/null 2>&1
+    cargo +nightly build --bin optional-trait-methods --no-default-features --features="target_advanced using_${impl} always_inline ${TRANSPORT_FEATURE}" $release_flag >/dev/null 2>&1
 
     cp target/${mode}/optional-trait-methods target/${mode}/bench-${impl}
 }
@@ -35,21 +44,21 @@ done
 
 echo "Running hyperfine benchmark for Debug Mode ($ITERATIONS_DEBUG iterations)..."
 hyperfine --warmup 3 \
-    "./target/release/harness $ITERATIONS_DEBUG | ./target/debug/bench-cfg_gates" \
-    "./target/release/harness $ITERATIONS_DEBUG | ./target/debug/bench-is_supported" \
-    "./target/release/harness $ITERATIONS_DEBUG | ./target/debug/bench-options" \
-    "./target/release/harness $ITERATIONS_DEBUG | ./target/debug/bench-fn" \
-    "./target/release/harness $ITERATIONS_DEBUG | ./target/debug/bench-traits" \
-    "./target/release/harness $ITERATIONS_DEBUG | ./target/debug/bench-try_as_dyn"
+    "./target/release/harness $ITERATIONS_DEBUG $HARNESS_ARGS | ./target/debug/bench-cfg_gates" \
+    "./target/release/harness $ITERATIONS_DEBUG $HARNESS_ARGS | ./target/debug/bench-is_supported" \
+    "./target/release/harness $ITERATIONS_DEBUG $HARNESS_ARGS | ./target/debug/bench-options" \
+    "./target/release/harness $ITERATIONS_DEBUG $HARNESS_ARGS | ./target/debug/bench-fn" \
+    "./target/release/harness $ITERATIONS_DEBUG $HARNESS_ARGS | ./target/debug/bench-traits" \
+    "./target/release/harness $ITERATIONS_DEBUG $HARNESS_ARGS | ./target/debug/bench-try_as_dyn"
 
 echo ""
 echo "Running hyperfine benchmark for Release Mode ($ITERATIONS_RELEASE iterations)..."
 hyperfine --warmup 3 \
-    "./target/release/harness $ITERATIONS_RELEASE | ./target/release/bench-cfg_gates" \
-    "./target/release/harness $ITERATIONS_RELEASE | ./target/release/bench-is_supported" \
-    "./target/release/harness $ITERATIONS_RELEASE | ./target/release/bench-options" \
-    "./target/release/harness $ITERATIONS_RELEASE | ./target/release/bench-fn" \
-    "./target/release/harness $ITERATIONS_RELEASE | ./target/release/bench-traits" \
-    "./target/release/harness $ITERATIONS_RELEASE | ./target/release/bench-try_as_dyn"
+    "./target/release/harness $ITERATIONS_RELEASE $HARNESS_ARGS | ./target/release/bench-cfg_gates" \
+    "./target/release/harness $ITERATIONS_RELEASE $HARNESS_ARGS | ./target/release/bench-is_supported" \
+    "./target/release/harness $ITERATIONS_RELEASE $HARNESS_ARGS | ./target/release/bench-options" \
+    "./target/release/harness $ITERATIONS_RELEASE $HARNESS_ARGS | ./target/release/bench-fn" \
+    "./target/release/harness $ITERATIONS_RELEASE $HARNESS_ARGS | ./target/release/bench-traits" \
+    "./target/release/harness $ITERATIONS_RELEASE $HARNESS_ARGS | ./target/release/bench-try_as_dyn"
 
 
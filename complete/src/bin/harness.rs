@@ -1,44 +1,252 @@
 use rand_chacha::ChaCha8Rng;
 use rand_core::RngCore;
 use rand_core::SeedableRng;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
 use std::io::Write;
 use std::io::{self};
+use std::process::Command;
+use std::process::Stdio;
 
+// usage: harness [num_commands] [rsp] [--check <controller binary>]
+//
+// Streams random commands to stdout, or (with `--check`) pipes them into the
+// given controller binary, and checks that every command gets a well-formed
+// reply.
 fn main() {
-    let num_commands = std::env::args()
-        .nth(1)
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let num_commands = args
+        .first()
         .and_then(|arg| arg.parse::<usize>().ok())
         .unwrap_or(100);
+    // frame each command as a GDB RSP packet (for `transport_rsp`)
+    let rsp = args.iter().any(|arg| arg == "rsp");
+    let check = args
+        .iter()
+        .position(|arg| arg == "--check")
+        .and_then(|i| args.get(i + 1));
 
     let mut rng = ChaCha8Rng::from_entropy();
-    let stdout = io::stdout();
-    let mut handle = stdout.lock();
-
-    for _ in 0..num_commands {
-        let res = match rng.next_u32() % 7 {
-            0 => writeln!(handle, "p"),
-            1 => writeln!(handle, "s {}", (rng.next_u32() % 100) as isize),
-            2 => writeln!(handle, "+"),
-            3 => writeln!(handle, "-"),
-            4 => writeln!(handle, "+-"),
-            5 => {
-                let mut val = (rng.next_u32() % 9) as isize;
-                if val >= 7 {
-                    val += 1;
+    let commands = std::iter::repeat_with(|| random_command(&mut rng)).take(num_commands);
+
+    match check {
+        Some(bin) => check_replies(bin, commands.collect(), rsp),
+        None => {
+            let stdout = io::stdout();
+            let mut handle = stdout.lock();
+            for cmd in commands {
+                let res = emit(&mut handle, rsp, &cmd);
+                if matches!(res, Err(ref e) if e.kind() == io::ErrorKind::BrokenPipe) {
+                    break;
                 }
-                writeln!(handle, "* {}", val)
             }
-            _ => {
-                let mut val = (rng.next_u32() % 5) as isize;
-                if val == 0 {
-                    val = 1;
-                }
-                writeln!(handle, "*~ {}", val)
+        }
+    }
+}
+
+/// A `SetState` which overflows any target's state (up to `i128` / `u128`).
+const OVERFLOW: &str = "s 999999999999999999999999999999999999999999";
+
+fn random_command(rng: &mut ChaCha8Rng) -> String {
+    match rng.next_u32() % 16 {
+        0 => "p".to_string(),
+        1 => {
+            let val = (rng.next_u32() % 100) as isize;
+            match rng.next_u32() % 4 {
+                0 => format!("s {:#x}", val),
+                1 => format!("s {:#b}", val),
+                _ => format!("s {}", val),
+            }
+        }
+        2 => "+".to_string(),
+        3 => "-".to_string(),
+        4 => "+-".to_string(),
+        5 => {
+            let mut val = (rng.next_u32() % 9) as isize;
+            if val >= 7 {
+                val += 1;
             }
+            format!("* {}", val)
+        }
+        6 => {
+            let min = (rng.next_u32() % 3) as isize + 1;
+            let max = min + (rng.next_u32() % 3) as isize;
+            format!("*~~ {} {}", min, max)
+        }
+        7 => format!("/ {}", (rng.next_u32() % 4) as isize - 1),
+        8 => format!("% {}", (rng.next_u32() % 4) as isize - 1),
+        // (register counts vary by target, so some indices are out of range)
+        9 => format!("r {}", rng.next_u32() % 10),
+        10 => format!(
+            "w {} {}",
+            rng.next_u32() % 10,
+            (rng.next_u32() % 100) as isize
+        ),
+        11 => "g".to_string(),
+        12 => {
+            // (register counts and widths vary by target, so only some match)
+            let num_regs = [4, 8][(rng.next_u32() % 2) as usize];
+            let width = [4, 8, 16][(rng.next_u32() % 3) as usize];
+            let hex: String = (0..num_regs)
+                .flat_map(|_| ((rng.next_u32() % 100) as u128).to_le_bytes()[..width].to_vec())
+                .map(|byte| format!("{:02x}", byte))
+                .collect();
+            format!("G {}", hex)
+        }
+        // (sometimes reaching further back than the target remembers)
+        13 => match rng.next_u32() % 4 {
+            0 => "u".to_string(),
+            n => format!("u {}", n * 8 - 7),
+        },
+        // (malformed commands, which are rejected with an `E.` reply)
+        14 => {
+            let bad = ["frob", "p 1", "+x", OVERFLOW, "* abc", "*~~ 1"];
+            bad[(rng.next_u32() % bad.len() as u32) as usize].to_string()
+        }
+        _ => {
+            let mut val = (rng.next_u32() % 5) as isize;
+            if val == 0 {
+                val = 1;
+            }
+            format!("*~ {}", val)
+        }
+    }
+}
+
+fn emit(handle: &mut impl Write, rsp: bool, cmd: &str) -> io::Result<()> {
+    if !rsp {
+        return writeln!(handle, "{}", cmd);
+    }
+    let checksum = cmd.bytes().fold(0u8, |sum, b| sum.wrapping_add(b));
+    write!(handle, "${}#{:02x}", cmd, checksum)
+}
+
+/// Reads every reply packet's payload, skipping the acks between them.
+///
+/// Packets with a bad checksum are kept (so they're reported as bad replies),
+/// as `<corrupted: ...>`.
+fn read_packets(mut stdout: impl Read) -> Vec<String> {
+    let mut bytes = Vec::new();
+    stdout.read_to_end(&mut bytes).unwrap();
+    let mut replies = Vec::new();
+    for packet in bytes.split(|&b| b == b'$').skip(1) {
+        let Some(end) = packet.iter().position(|&b| b == b'#') else {
+            replies.push(format!(
+                "<corrupted: {:?}>",
+                String::from_utf8_lossy(packet)
+            ));
+            continue;
         };
+        let (payload, checksum) = (&packet[..end], &packet[end + 1..]);
+        let sum = payload.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+        let mut unescaped = Vec::new();
+        let mut bytes = payload.iter();
+        while let Some(&b) = bytes.next() {
+            match b {
+                b'}' => unescaped.extend(bytes.next().map(|b| b ^ 0x20)),
+                _ => unescaped.push(b),
+            }
+        }
+        let reply = String::from_utf8_lossy(&unescaped).into_owned();
+        match checksum.get(..2) == Some(format!("{:02x}", sum).as_bytes()) {
+            true => replies.push(reply),
+            false => replies.push(format!("<corrupted: {:?}>", reply)),
+        }
+    }
+    replies
+}
+
+/// Whether `reply` is a valid reply to `cmd`.
+fn is_valid_reply(cmd: &str, reply: &str) -> bool {
+    let is_error = |reply: &str| {
+        reply
+            .strip_prefix("E ")
+            .is_some_and(|code| code.len() == 2 && u8::from_str_radix(code, 16).is_ok())
+    };
+    let is_parse_error = |reply: &str| {
+        reply
+            .strip_prefix("E.")
+            .and_then(|msg| msg.rsplit_once(" at "))
+            .is_some_and(|(msg, offset)| !msg.is_empty() && offset.parse::<usize>().is_ok())
+    };
+
+    // (any target's state, up to `i128` / `u128`)
+    let is_value = |reply: &str| reply.parse::<i128>().is_ok() || reply.parse::<u128>().is_ok();
+
+    match cmd {
+        "frob" | "p 1" | "+x" | OVERFLOW => is_parse_error(reply),
+        // (unless the target doesn't support the extension at all)
+        "* abc" | "*~~ 1" => is_parse_error(reply) || reply.is_empty(),
+        "p" => is_value(reply),
+        "g" => !reply.is_empty() && reply.bytes().all(|b| b.is_ascii_hexdigit()),
+        // (out of range registers are rejected)
+        _ if cmd.starts_with("r ") => is_parse_error(reply) || is_value(reply),
+        _ if cmd.starts_with("s ") => reply == "OK",
+        // extensions may fail, or not be supported at all
+        _ => reply == "OK" || reply.is_empty() || is_error(reply) || is_parse_error(reply),
+    }
+}
+
+fn check_replies(bin: &str, commands: Vec<String>, rsp: bool) {
+    let mut child = Command::new(bin)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to spawn controller");
 
-        if matches!(res, Err(ref e) if e.kind() == io::ErrorKind::BrokenPipe) {
-            break;
+    let mut stdin = child.stdin.take().unwrap();
+    let input = commands.clone();
+    let writer = std::thread::spawn(move || {
+        for cmd in &input {
+            match emit(&mut stdin, rsp, cmd) {
+                Ok(()) => {}
+                // (the controller stops reading once a fatal error ends the session)
+                Err(e) if e.kind() == io::ErrorKind::BrokenPipe => break,
+                Err(e) => panic!("failed to write command: {}", e),
+            }
+        }
+    });
+
+    let stdout = BufReader::new(child.stdout.take().unwrap());
+    let replies: Vec<String> = match rsp {
+        true => read_packets(stdout),
+        false => stdout.lines().map(|line| line.unwrap()).collect(),
+    };
+    writer.join().unwrap();
+    let status = child.wait().unwrap();
+
+    // a fatal error (`E 02` / `E 05`) ends the session, followed by a message
+    let fatal = replies
+        .iter()
+        .position(|reply| reply == "E 02" || reply == "E 05");
+    let expected = match fatal {
+        Some(idx) => {
+            eprintln!("session ended by {:?} ({:?})", commands[idx], replies[idx]);
+            idx + 1
+        }
+        None => commands.len(),
+    };
+
+    let mut bad = 0;
+    for (cmd, reply) in commands.iter().zip(&replies).take(expected) {
+        if !is_valid_reply(cmd, reply) {
+            eprintln!("bad reply to {:?}: {:?}", cmd, reply);
+            bad += 1;
         }
     }
+    if replies.len() != expected + fatal.map_or(0, |_| 1) {
+        eprintln!("sent {} commands, got {} replies", expected, replies.len());
+        bad += 1;
+    }
+    // (and only a fatal error makes the controller exit with a failure)
+    if status.success() == fatal.is_some() {
+        eprintln!("controller exited with {}", status);
+        bad += 1;
+    }
+
+    eprintln!("checked {} replies ({} bad)", expected, bad);
+    if bad != 0 {
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,115 @@
+use rand_chacha::ChaCha8Rng;
+use rand_core::RngCore;
+use rand_core::SeedableRng;
+use std::hint::black_box;
+use std::time::Instant;
+
+use optional_trait_methods::num::parse_hex;
+use optional_trait_methods::num::parse_num;
+
+// usage: parse_bench [num_inputs] [rounds]
+//
+// Measures `num::parse_num` / `num::parse_hex` throughput (on `isize`s),
+// against the decimal-only parser they replaced.
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let arg = |idx: usize, default: usize| {
+        args.get(idx)
+            .and_then(|arg| arg.parse::<usize>().ok())
+            .unwrap_or(default)
+    };
+    let num_inputs = arg(0, 1 << 16);
+    let rounds = arg(1, 64);
+
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let vals: Vec<isize> = (0..num_inputs)
+        .map(|_| {
+            // (spread across every magnitude, rather than mostly huge numbers)
+            let shift = rng.next_u32() % u64::BITS;
+            (rng.next_u64() >> shift) as isize
+        })
+        .collect();
+    let encode = |f: fn(isize) -> String| vals.iter().map(|&val| f(val)).collect::<Vec<_>>();
+
+    let decimal = encode(|val| val.to_string());
+    let prefixed = encode(|val| match val < 0 {
+        true => format!("-{:#x}", val.unsigned_abs()),
+        false => format!("{:#x}", val),
+    });
+    let separated = encode(|val| {
+        let digits = val.unsigned_abs().to_string();
+        let mut out = String::from(if val < 0 { "-" } else { "" });
+        for (i, c) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i) % 3 == 0 {
+                out.push('_');
+            }
+            out.push(c);
+        }
+        out
+    });
+    let bare_hex = encode(|val| match val < 0 {
+        true => format!("-{:x}", val.unsigned_abs()),
+        false => format!("{:x}", val),
+    });
+
+    bench("baseline (decimal)", &decimal, &vals, rounds, parse_decimal);
+    bench("parse_num (decimal)", &decimal, &vals, rounds, parse_num);
+    bench("parse_num (0x)", &prefixed, &vals, rounds, parse_num);
+    bench("parse_num (1_000)", &separated, &vals, rounds, parse_num);
+    bench("parse_hex", &bare_hex, &vals, rounds, parse_hex);
+}
+
+fn bench(
+    name: &str,
+    inputs: &[String],
+    expected: &[isize],
+    rounds: usize,
+    parse: fn(&[u8]) -> Option<isize>,
+) {
+    for (input, &val) in inputs.iter().zip(expected) {
+        assert_eq!(parse(input.as_bytes()), Some(val), "{}: {:?}", name, input);
+    }
+
+    let bytes: usize = inputs.iter().map(|input| input.len()).sum();
+    let start = Instant::now();
+    for _ in 0..rounds {
+        for input in inputs {
+            black_box(parse(black_box(input.as_bytes())));
+        }
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+    let count = (inputs.len() * rounds) as f64;
+    println!(
+        "{:<20} {:>7.2} ns/number {:>8.1} MB/s",
+        name,
+        elapsed / count * 1e9,
+        (bytes * rounds) as f64 / elapsed / 1e6
+    );
+}
+
+/// `num::parse_num` as it was before it learned radix prefixes and `_`
+/// separators.
+#[inline(never)]
+fn parse_decimal(bytes: &[u8]) -> Option<isize> {
+    let (is_neg, digits) = match bytes.split_first()? {
+        (b'-', rest) => (true, rest),
+        (b'+', rest) => (false, rest),
+        _ => (false, bytes),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+    let mut val: isize = 0;
+    for &b in digits {
+        let d = b.wrapping_sub(b'0');
+        if d > 9 {
+            return None;
+        }
+        val = val.checked_mul(10)?;
+        val = match is_neg {
+            true => val.checked_sub(d as isize)?,
+            false => val.checked_add(d as isize)?,
+        };
+    }
+    Some(val)
+}
@@ -0,0 +1,182 @@
+use crate::writer::ResponseWriter;
+
+/// A set of optional protocol extensions, as reported by
+/// `Target::capabilities()`.
+///
+/// All methods are `const fn`s over a plain bitset, so a set built from
+/// inlined `ext_*` probes constant-folds just as well as the probes themselves.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Capabilities(u8);
+
+impl Capabilities {
+    pub const NONE: Capabilities = Capabilities(0);
+    pub const INCDEC: Capabilities = Capabilities(1 << 0);
+    pub const MUL: Capabilities = Capabilities(1 << 1);
+    pub const SCALE_FACTOR: Capabilities = Capabilities(1 << 2);
+    pub const SCALE_BOUNDS: Capabilities = Capabilities(1 << 3);
+    pub const DIV: Capabilities = Capabilities(1 << 4);
+    pub const UNDO: Capabilities = Capabilities(1 << 5);
+    pub const ALL: Capabilities = Capabilities(0b111111);
+
+    /// Every capability, alongside its protocol name.
+    const NAMED: [(Capabilities, &'static str); 6] = [
+        (Capabilities::INCDEC, "incdec"),
+        (Capabilities::MUL, "mul"),
+        (Capabilities::SCALE_FACTOR, "mul.scale_factor"),
+        (Capabilities::SCALE_BOUNDS, "mul.scale_factor.scale_bounds"),
+        (Capabilities::DIV, "div"),
+        (Capabilities::UNDO, "undo"),
+    ];
+
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(self, other: Capabilities) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn union(self, other: Capabilities) -> Capabilities {
+        Capabilities(self.0 | other.0)
+    }
+
+    pub const fn difference(self, other: Capabilities) -> Capabilities {
+        Capabilities(self.0 & !other.0)
+    }
+
+    pub const fn intersection(self, other: Capabilities) -> Capabilities {
+        Capabilities(self.0 & other.0)
+    }
+
+    /// Adds `other` to the set if `supported` is true.
+    ///
+    /// Used to build up a set from a sequence of `ext_*` probes.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    pub const fn with(self, other: Capabilities, supported: bool) -> Capabilities {
+        if supported { self.union(other) } else { self }
+    }
+
+    /// Returns the protocol names of every capability in the set (e.g:
+    /// `"incdec"`, `"mul.scale_factor"`).
+    pub fn names(self) -> impl Iterator<Item = &'static str> {
+        Capabilities::NAMED
+            .into_iter()
+            .filter(move |(cap, _)| self.contains(*cap))
+            .map(|(_, name)| name)
+    }
+
+    /// Parses a `;`-separated list of protocol names (e.g: `incdec;mul`).
+    ///
+    /// Like gdb's `qSupported`, unknown names are silently ignored.
+    pub fn from_names(list: &[u8]) -> Capabilities {
+        list.split(|&b| b == b';')
+            .filter_map(|name| {
+                Capabilities::NAMED
+                    .into_iter()
+                    .find(|(_, known)| known.as_bytes() == name)
+            })
+            .fold(Capabilities::NONE, |caps, (cap, _)| caps.union(cap))
+    }
+
+    /// Limits the set to the capabilities a client claims to understand (if
+    /// it sent a list at all).
+    pub fn negotiate(self, client: Option<Capabilities>) -> Capabilities {
+        match client {
+            Some(client) => self.intersection(client),
+            None => self,
+        }
+    }
+
+    /// Writes the set as a single `;`-separated line of protocol names.
+    pub fn write_to<W: ResponseWriter>(self, out: &mut W) {
+        let mut sep = "";
+        for name in self.names() {
+            out.write(sep.as_bytes());
+            out.write(name.as_bytes());
+            sep = ";";
+        }
+        out.write(b"\n");
+    }
+
+    /// Compares two sets, returning `(added, removed)` relative to `self`.
+    pub const fn diff(self, other: Capabilities) -> (Capabilities, Capabilities) {
+        (other.difference(self), self.difference(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with() {
+        let caps = Capabilities::NONE
+            .with(Capabilities::INCDEC, true)
+            .with(Capabilities::MUL, false)
+            .with(Capabilities::SCALE_FACTOR, true);
+        assert!(caps.contains(Capabilities::INCDEC));
+        assert!(!caps.contains(Capabilities::MUL));
+        assert!(caps.contains(Capabilities::INCDEC.union(Capabilities::SCALE_FACTOR)));
+        assert!(Capabilities::NONE.is_empty());
+    }
+
+    #[test]
+    fn test_names() {
+        let mut names = Capabilities::ALL.names();
+        assert_eq!(names.next(), Some("incdec"));
+        assert_eq!(names.next(), Some("mul"));
+        assert_eq!(names.next(), Some("mul.scale_factor"));
+        assert_eq!(names.next(), Some("mul.scale_factor.scale_bounds"));
+        assert_eq!(names.next(), Some("div"));
+        assert_eq!(names.next(), Some("undo"));
+        assert_eq!(names.next(), None);
+        assert_eq!(Capabilities::NONE.names().next(), None);
+    }
+
+    #[test]
+    fn test_from_names() {
+        assert_eq!(Capabilities::from_names(b""), Capabilities::NONE);
+        assert_eq!(Capabilities::from_names(b"mul"), Capabilities::MUL);
+        assert_eq!(
+            Capabilities::from_names(
+                b"incdec;mul;mul.scale_factor;mul.scale_factor.scale_bounds;div;undo"
+            ),
+            Capabilities::ALL
+        );
+        assert_eq!(
+            Capabilities::from_names(b"swbreak;mul.scale_factor;;incdec"),
+            Capabilities::INCDEC.union(Capabilities::SCALE_FACTOR)
+        );
+    }
+
+    #[test]
+    fn test_diff() {
+        let basic = Capabilities::NONE;
+        let faulty = Capabilities::INCDEC;
+        let advanced = Capabilities::ALL;
+
+        assert_eq!(
+            faulty.diff(advanced),
+            (
+                Capabilities::MUL
+                    .union(Capabilities::SCALE_FACTOR)
+                    .union(Capabilities::SCALE_BOUNDS)
+                    .union(Capabilities::DIV)
+                    .union(Capabilities::UNDO),
+                Capabilities::NONE
+            )
+        );
+        assert_eq!(
+            faulty.diff(basic),
+            (Capabilities::NONE, Capabilities::INCDEC)
+        );
+        assert_eq!(
+            advanced.diff(advanced),
+            (Capabilities::NONE, Capabilities::NONE)
+        );
+    }
+}
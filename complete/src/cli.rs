@@ -0,0 +1,276 @@
+//! The binary's command line, parsed without `std::env` (straight from the
+//! `argv` handed to `main`).
+
+use optional_trait_methods::num::parse_num;
+
+/// Printed for `--help`, and after any `CliError`.
+pub const USAGE: &str = "usage: optional-trait-methods [options]
+
+  --unix <path>              serve the first client to connect to a Unix domain socket
+  --tcp <port>               serve the first client to connect to 127.0.0.1:<port>
+                             (port 0 picks any free port, printed to stderr)
+                             (by default, serve stdin / stdout)
+  --initial-state <n>        start the target off in state <n> (by default, 0)
+  --on-error=continue|exit   whether to keep serving after a command fails
+  --echo                     repeat every command before its reply
+  --repl                     expect a human instead of a client (prompt, aliases, help)
+                             (needs the `repl` feature)
+  --help                     print this message
+
+  (`--name <value>` may also be written `--name=<value>`)";
+
+/// Where the client is served.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transport<'a> {
+    Stdio,
+    /// A Unix domain socket, at the given path.
+    Unix(&'a [u8]),
+    /// A loopback TCP port (where `0` picks any free port, which `main`
+    /// reports).
+    Tcp(u16),
+}
+
+/// What to do once a command fails (i.e: is replied to with an error, or an
+/// empty line if it isn't supported).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OnError {
+    /// Keep serving (as a client expects).
+    #[default]
+    Continue,
+    /// Stop serving, and exit with a non-zero status (e.g: for scripts).
+    Exit,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Options<'a> {
+    pub transport: Transport<'a>,
+    /// The target's initial state, left unparsed (as its width depends on the
+    /// target, see `num::parse_num`).
+    pub initial_state: Option<&'a [u8]>,
+    pub on_error: OnError,
+    pub echo: bool,
+    pub repl: bool,
+    pub help: bool,
+}
+
+impl Default for Options<'_> {
+    fn default() -> Self {
+        Options {
+            transport: Transport::Stdio,
+            initial_state: None,
+            on_error: OnError::Continue,
+            echo: false,
+            repl: false,
+            help: false,
+        }
+    }
+}
+
+/// Why `parse` rejected the command line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CliError<'a> {
+    /// An argument which isn't an option (or isn't one of ours).
+    UnknownOption(&'a [u8]),
+    /// An option's value is missing.
+    MissingValue(&'static str),
+    /// An option's value isn't one it takes.
+    InvalidValue(&'static str, &'a [u8]),
+    /// A flag was given a value (e.g: `--echo=yes`).
+    UnexpectedValue(&'static str),
+    /// More than one transport was selected.
+    ConflictingTransports,
+}
+
+impl CliError<'_> {
+    /// Writes the error as a single line (without its `\n`) through `write`
+    /// (e.g: to stderr).
+    pub fn write_to(&self, mut write: impl FnMut(&[u8])) {
+        match *self {
+            CliError::UnknownOption(arg) => {
+                write(b"unknown option: ");
+                write(arg);
+            }
+            CliError::MissingValue(name) => {
+                write(b"missing value for ");
+                write(name.as_bytes());
+            }
+            CliError::InvalidValue(name, value) => {
+                write(b"invalid value for ");
+                write(name.as_bytes());
+                write(b": ");
+                write(value);
+            }
+            CliError::UnexpectedValue(name) => {
+                write(name.as_bytes());
+                write(b" doesn't take a value");
+            }
+            CliError::ConflictingTransports => write(b"only one of --unix / --tcp may be given"),
+        }
+    }
+}
+
+/// Parses the arguments following the program's name.
+pub fn parse<'a>(args: impl IntoIterator<Item = &'a [u8]>) -> Result<Options<'a>, CliError<'a>> {
+    let mut args = args.into_iter();
+    let mut opts = Options::default();
+    while let Some(arg) = args.next() {
+        // (`--name=value`, or `--name` with its value in the next argument)
+        let (name, inline_value) = match arg.iter().position(|&b| b == b'=') {
+            Some(idx) => (&arg[..idx], Some(&arg[idx + 1..])),
+            None => (arg, None),
+        };
+        let Some((name, opt)) = OPTIONS
+            .into_iter()
+            .find(|(known, _)| known.as_bytes() == name)
+        else {
+            return Err(CliError::UnknownOption(arg));
+        };
+        let value = match (opt.takes_value(), inline_value) {
+            (true, Some(value)) => value,
+            (true, None) => args.next().ok_or(CliError::MissingValue(name))?,
+            (false, Some(_)) => return Err(CliError::UnexpectedValue(name)),
+            (false, None) => &[],
+        };
+        let invalid = || CliError::InvalidValue(name, value);
+
+        match opt {
+            Opt::Unix if value.is_empty() => return Err(invalid()),
+            Opt::Unix => opts.set_transport(Transport::Unix(value))?,
+            Opt::Tcp => {
+                let port = parse_num(value).ok_or_else(invalid)?;
+                opts.set_transport(Transport::Tcp(port))?
+            }
+            Opt::InitialState => opts.initial_state = Some(value),
+            Opt::OnError => {
+                opts.on_error = match value {
+                    b"continue" => OnError::Continue,
+                    b"exit" => OnError::Exit,
+                    _ => return Err(invalid()),
+                }
+            }
+            Opt::Echo => opts.echo = true,
+            Opt::Repl => opts.repl = true,
+            Opt::Help => opts.help = true,
+        }
+    }
+    Ok(opts)
+}
+
+impl<'a> Options<'a> {
+    /// Serves the client over `transport`, unless `--unix` / `--tcp` has
+    /// already picked one.
+    fn set_transport(&mut self, transport: Transport<'a>) -> Result<(), CliError<'a>> {
+        match self.transport {
+            Transport::Stdio => {
+                self.transport = transport;
+                Ok(())
+            }
+            _ => Err(CliError::ConflictingTransports),
+        }
+    }
+}
+
+/// An option `parse` knows of (see `OPTIONS` for its name).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Opt {
+    Unix,
+    Tcp,
+    InitialState,
+    OnError,
+    Echo,
+    Repl,
+    Help,
+}
+
+impl Opt {
+    fn takes_value(self) -> bool {
+        match self {
+            Opt::Unix | Opt::Tcp | Opt::InitialState | Opt::OnError => true,
+            Opt::Echo | Opt::Repl | Opt::Help => false,
+        }
+    }
+}
+
+/// Every option, by name.
+const OPTIONS: [(&str, Opt); 7] = [
+    ("--unix", Opt::Unix),
+    ("--tcp", Opt::Tcp),
+    ("--initial-state", Opt::InitialState),
+    ("--on-error", Opt::OnError),
+    ("--echo", Opt::Echo),
+    ("--repl", Opt::Repl),
+    ("--help", Opt::Help),
+];
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+
+    fn parse_str(args: &str) -> Result<Options<'_>, CliError<'_>> {
+        parse(args.split_whitespace().map(str::as_bytes))
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_str(""), Ok(Options::default()));
+        assert_eq!(
+            parse_str("--tcp 1234 --initial-state -0x10 --on-error=exit --echo"),
+            Ok(Options {
+                transport: Transport::Tcp(1234),
+                initial_state: Some(b"-0x10"),
+                on_error: OnError::Exit,
+                echo: true,
+                ..Options::default()
+            })
+        );
+        assert_eq!(
+            parse_str("--repl --unix=/tmp/otm.sock --on-error continue --initial-state=5"),
+            Ok(Options {
+                transport: Transport::Unix(b"/tmp/otm.sock"),
+                initial_state: Some(b"5"),
+                repl: true,
+                ..Options::default()
+            })
+        );
+        assert!(parse_str("--help").is_ok_and(|opts| opts.help));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse_str("tcp 1234"), Err(CliError::UnknownOption(b"tcp")));
+        assert_eq!(
+            parse_str("--echoes"),
+            Err(CliError::UnknownOption(b"--echoes"))
+        );
+        assert_eq!(
+            parse_str("--initial-state"),
+            Err(CliError::MissingValue("--initial-state"))
+        );
+        assert_eq!(
+            parse_str("--tcp 65536"),
+            Err(CliError::InvalidValue("--tcp", b"65536"))
+        );
+        assert_eq!(
+            parse_str("--unix="),
+            Err(CliError::InvalidValue("--unix", b""))
+        );
+        assert_eq!(
+            parse_str("--on-error=abort"),
+            Err(CliError::InvalidValue("--on-error", b"abort"))
+        );
+        assert_eq!(
+            parse_str("--echo=yes"),
+            Err(CliError::UnexpectedValue("--echo"))
+        );
+        assert_eq!(
+            parse_str("--tcp 1 --unix /tmp/otm.sock"),
+            Err(CliError::ConflictingTransports)
+        );
+
+        let mut msg = Vec::<u8>::new();
+        CliError::InvalidValue("--tcp", b"x").write_to(|bytes| msg.extend(bytes));
+        assert_eq!(msg, b"invalid value for --tcp: x");
+    }
+}
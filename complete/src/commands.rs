@@ -1,11 +1,33 @@
+use crate::LineTooLong;
+use crate::capabilities::Capabilities;
+use crate::num::Num;
+use crate::num::parse_hex;
+use crate::num::parse_num;
+use crate::registers::Registers;
+
 pub mod ext {
+    use crate::capabilities::Capabilities;
+    use crate::registers::Registers;
+
     #[derive(Clone, Copy, Debug)]
-    pub enum BaseCommand {
+    pub enum BaseCommand<S> {
         PrintState,
-        SetState(isize),
+        SetState(S),
+        /// `?` / `? incdec;mul;...`
+        ///
+        /// Reports the supported extensions, optionally limited to those the
+        /// client says it understands.
+        QuerySupported(Option<Capabilities>),
+        /// `r <idx>`
+        ReadReg(usize),
+        /// `w <idx> <val>`
+        WriteReg(usize, S),
+        /// `g`
+        ReadRegs,
+        /// `G <hex>` (see `Registers::from_hex`)
+        WriteRegs(Registers<S>),
     }
 
-    #[cfg(cmd_incdec)] // (only used in using_cfg_gates case)
     #[derive(Clone, Copy, Debug)]
     pub enum IncDecCommand {
         Inc,
@@ -13,49 +35,230 @@ pub mod ext {
         IncDec,
     }
 
-    #[cfg(cmd_mul)] // (only used in using_cfg_gates case)
     #[derive(Clone, Copy, Debug)]
-    pub enum MulCommand {
-        Mul(isize),
-        ScaleFactor(isize),
+    pub enum MulCommand<S> {
+        Mul(S),
+        ScaleFactor(S),
+        /// `*~~ <min> <max>`
+        ScaleBounds(S, S),
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    pub enum DivCommand<S> {
+        /// `/ <n>`
+        Div(S),
+        /// `% <n>`
+        Rem(S),
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    pub enum UndoCommand {
+        /// `u` / `u <n>`
+        ///
+        /// Reverts the last `n` (by default, 1) mutations.
+        Undo(usize),
     }
 }
 
+/// A parsed command, whose operands are the target's `State`.
+///
+/// Every strategy but `using_cfg_gates` (which has its own, see
+/// `using_cfg_gates::commands`) parses into this, and rejects the extensions
+/// its target doesn't support while parsing or handling them.
 #[derive(Clone, Copy, Debug)]
-pub enum Command {
-    Base(ext::BaseCommand),
-    #[cfg(cmd_incdec)] // (only used in using_cfg_gates case)
+pub enum Command<S> {
+    Base(ext::BaseCommand<S>),
     IncDec(ext::IncDecCommand),
-    #[cfg(cmd_mul)] // (only used in using_cfg_gates case)
-    Mul(ext::MulCommand),
+    Mul(ext::MulCommand<S>),
+    Div(ext::DivCommand<S>),
+    Undo(ext::UndoCommand),
 }
 
-#[cfg_attr(feature = "interpretable_asm", inline(never))]
-pub fn parse_isize(bytes: &[u8]) -> Option<isize> {
-    let (is_neg, digits) = match bytes.split_first()? {
-        (b'-', rest) => (true, rest),
-        (b'+', rest) => (false, rest),
-        _ => (false, bytes),
-    };
-    if digits.is_empty() {
-        return None;
-    }
-    let mut val: isize = 0;
-    for &b in digits {
-        let d = b.wrapping_sub(b'0');
-        if d > 9 {
-            return None;
+impl<S> Command<S> {
+    /// Whether `u` can revert this command (i.e: whether the controller
+    /// checkpoints the target before carrying it out).
+    ///
+    /// Register writes (including `r0`'s) and `*~~` aren't recorded.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    pub fn is_undoable(&self) -> bool {
+        matches!(
+            self,
+            Command::Base(ext::BaseCommand::SetState(_))
+                | Command::IncDec(_)
+                | Command::Mul(ext::MulCommand::Mul(_) | ext::MulCommand::ScaleFactor(_))
+                | Command::Div(_)
+        )
+    }
+}
+
+/// Why `parse_command` rejected a command line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    /// Where (in bytes, from the start of the line) the problem starts.
+    pub offset: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The verb isn't part of the protocol.
+    UnknownVerb,
+    /// The verb belongs to an extension the target doesn't support.
+    Unsupported,
+    /// An argument is missing, isn't a number, or is out of range (e.g: it
+    /// overflows the target's state, or names a register the target lacks).
+    BadNumber,
+    /// The command is followed by something it doesn't take.
+    TrailingGarbage,
+    /// The line didn't fit in the line buffer (see `LineTooLong`).
+    LineTooLong,
+}
+
+impl ParseErrorKind {
+    /// A short description (see `Error::write_reply`).
+    pub fn message(self) -> &'static str {
+        match self {
+            ParseErrorKind::UnknownVerb => "unknown command",
+            ParseErrorKind::Unsupported => "unsupported command",
+            ParseErrorKind::BadNumber => "bad number",
+            ParseErrorKind::TrailingGarbage => "trailing garbage",
+            ParseErrorKind::LineTooLong => "line too long",
         }
-        val = val.checked_mul(10)?;
-        val = val.checked_add(d as isize)?;
     }
-    let sign = if is_neg { -1 } else { 1 };
-    val.checked_mul(sign)
+}
+
+impl From<LineTooLong> for ParseError {
+    /// (reported at the first byte that didn't fit)
+    fn from(err: LineTooLong) -> ParseError {
+        ParseError {
+            kind: ParseErrorKind::LineTooLong,
+            offset: err.max_len,
+        }
+    }
+}
+
+/// The arguments following a verb (see `strip_verb`), each of which is
+/// preceded by a single space.
+#[derive(Clone, Copy, Debug)]
+pub struct Args<'a> {
+    rest: &'a [u8],
+    /// `rest`'s offset into the line.
+    offset: usize,
+}
+
+impl<'a> Args<'a> {
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// Takes the next argument (which is empty if it's missing), along with
+    /// its offset into the line.
+    fn next(&mut self) -> (usize, &'a [u8]) {
+        let (offset, rest) = match self.rest.split_first() {
+            // (skipping the separating space)
+            Some((_, rest)) => (self.offset + 1, rest),
+            None => (self.offset, self.rest),
+        };
+        let len = rest.iter().position(|&b| b == b' ').unwrap_or(rest.len());
+        self.rest = &rest[len..];
+        self.offset = offset + len;
+        (offset, &rest[..len])
+    }
+
+    fn bad_number(offset: usize) -> ParseError {
+        ParseError {
+            kind: ParseErrorKind::BadNumber,
+            offset,
+        }
+    }
+
+    pub fn num<N: Num>(&mut self) -> Result<N, ParseError> {
+        let (offset, arg) = self.next();
+        parse_num(arg).ok_or(Args::bad_number(offset))
+    }
+
+    /// Like `num`, but the argument is optional.
+    pub fn num_or<N: Num>(&mut self, default: N) -> Result<N, ParseError> {
+        match self.is_empty() {
+            true => Ok(default),
+            false => self.num(),
+        }
+    }
+
+    /// A register index, which must be below `num_regs`.
+    pub fn reg(&mut self, num_regs: usize) -> Result<usize, ParseError> {
+        let (offset, arg) = self.next();
+        match parse_num::<usize>(arg) {
+            Some(idx) if idx < num_regs => Ok(idx),
+            _ => Err(Args::bad_number(offset)),
+        }
+    }
+
+    /// A whole register file (see `Registers::from_hex`).
+    pub fn regs<S: Num>(&mut self, num_regs: usize) -> Result<Registers<S>, ParseError> {
+        let (offset, hex) = self.next();
+        Registers::from_hex(hex, num_regs).ok_or(Args::bad_number(offset))
+    }
+
+    /// A list of extension names (see `Capabilities::from_names`).
+    pub fn names(&mut self) -> Result<Capabilities, ParseError> {
+        let (_, list) = self.next();
+        Ok(Capabilities::from_names(list))
+    }
+
+    /// Returns `cmd`, as long as nothing follows the arguments taken so far.
+    pub fn finish<C>(self, cmd: C) -> Result<C, ParseError> {
+        match self.rest.is_empty() {
+            true => Ok(cmd),
+            false => Err(ParseError {
+                kind: ParseErrorKind::TrailingGarbage,
+                offset: self.offset,
+            }),
+        }
+    }
+}
+
+/// Matches the verb of `line` (i.e: everything up to the first space) against
+/// `verb`, returning the arguments which follow it.
+pub fn strip_verb<'a>(line: &'a [u8], verb: &[u8]) -> Option<Args<'a>> {
+    let rest = line.strip_prefix(verb)?;
+    matches!(rest.first(), None | Some(b' ')).then_some(Args {
+        rest,
+        offset: verb.len(),
+    })
+}
+
+/// Every extension's verbs (the base protocol's are always recognized).
+const EXT_VERBS: [&[u8]; 9] = [b"+", b"-", b"+-", b"*", b"*~", b"*~~", b"/", b"%", b"u"];
+
+/// Explains why none of the target's (supported) commands matched `line`.
+pub fn unrecognized(line: &[u8]) -> ParseError {
+    let verb = line.split(|&b| b == b' ').next().unwrap_or(line);
+    let kind = match EXT_VERBS.contains(&verb) {
+        true => ParseErrorKind::Unsupported,
+        false => ParseErrorKind::UnknownVerb,
+    };
+    ParseError { kind, offset: 0 }
+}
+
+/// Parses an `isize`, in decimal or with a `0x` / `0o` / `0b` prefix (see
+/// `num::parse_num` for any other width).
+pub fn parse_isize(bytes: &[u8]) -> Option<isize> {
+    parse_num(bytes)
+}
+
+/// Parses an `isize` in bare hex (see `num::parse_hex` for any other width).
+pub fn parse_isize_hex(bytes: &[u8]) -> Option<isize> {
+    parse_hex(bytes)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::num::MAX_DECIMAL_LEN;
+    use crate::num::MAX_HEX_LEN;
+    use crate::num::format_decimal;
+    use crate::num::format_hex;
 
     #[test]
     fn test_parse_isize() {
@@ -68,5 +271,152 @@ mod tests {
         assert_eq!(parse_isize(b"-"), None);
         assert_eq!(parse_isize(b"+"), None);
         assert_eq!(parse_isize(b"abc"), None);
+        let min = isize::MIN.to_string();
+        assert_eq!(parse_isize(min.as_bytes()), Some(isize::MIN));
+        assert_eq!(parse_isize(&min.as_bytes()[1..]), None);
+
+        assert_eq!(parse_isize(b"0x2a"), Some(42));
+        assert_eq!(parse_isize(b"-0b10_1010"), Some(-42));
+        assert_eq!(parse_isize(b"0o52"), Some(42));
+        assert_eq!(parse_isize(b"4_2"), Some(42));
+        let min = format!("-{:#x}", isize::MIN.unsigned_abs());
+        assert_eq!(parse_isize(min.as_bytes()), Some(isize::MIN));
+        assert_eq!(parse_isize(&min.as_bytes()[1..]), None);
+
+        assert_eq!(parse_isize_hex(b"2a"), Some(42));
+        assert_eq!(parse_isize_hex(b"-2A"), Some(-42));
+        assert_eq!(parse_isize_hex(b"0x2a"), None);
+        let max = format!("{:x}", isize::MAX);
+        assert_eq!(parse_isize_hex(max.as_bytes()), Some(isize::MAX));
+        assert_eq!(parse_isize_hex(format!("{}0", max).as_bytes()), None);
+    }
+
+    #[test]
+    fn test_isize_round_trip() {
+        for n in [isize::MIN, isize::MIN + 1, -1, 0, 1, isize::MAX] {
+            let mut buf = [0; MAX_DECIMAL_LEN];
+            let decimal = format_decimal(n, &mut buf);
+            assert_eq!(decimal, n.to_string().as_bytes());
+            assert_eq!(parse_isize(decimal), Some(n));
+
+            let mut buf = [0; MAX_HEX_LEN];
+            let hex = format_hex(n, &mut buf);
+            assert_eq!(parse_isize_hex(hex), Some(n));
+            // (or, with a prefix)
+            let prefixed = match hex.split_first() {
+                Some((b'-', digits)) => [b"-0x", digits].concat(),
+                _ => [b"0x", hex].concat(),
+            };
+            assert_eq!(parse_isize(&prefixed), Some(n));
+        }
+    }
+
+    fn parse_all<S: Num>(line: &[u8], verb: &[u8]) -> Result<(S, S), ParseError> {
+        let mut args = strip_verb(line, verb).unwrap();
+        let nums = (args.num()?, args.num()?);
+        args.finish(nums)
+    }
+
+    fn err(kind: ParseErrorKind, offset: usize) -> ParseError {
+        ParseError { kind, offset }
+    }
+
+    #[test]
+    fn test_strip_verb() {
+        assert!(strip_verb(b"*", b"*").is_some_and(|args| args.is_empty()));
+        assert!(strip_verb(b"* 2", b"*").is_some());
+        assert!(strip_verb(b"*~ 2", b"*").is_none());
+        assert!(strip_verb(b"*2", b"*").is_none());
+        assert!(strip_verb(b"+", b"+-").is_none());
+
+        assert_eq!(unrecognized(b"*~ 2"), err(ParseErrorKind::Unsupported, 0));
+        assert_eq!(unrecognized(b"u"), err(ParseErrorKind::Unsupported, 0));
+        assert_eq!(unrecognized(b"*2"), err(ParseErrorKind::UnknownVerb, 0));
+        assert_eq!(unrecognized(b"x 1"), err(ParseErrorKind::UnknownVerb, 0));
+    }
+
+    #[test]
+    fn test_args() {
+        assert_eq!(parse_all(b"*~~ -3 5", b"*~~"), Ok((-3isize, 5)));
+        assert_eq!(
+            parse_all::<isize>(b"*~~ 1", b"*~~"),
+            Err(err(ParseErrorKind::BadNumber, 5))
+        );
+        assert_eq!(
+            parse_all::<isize>(b"*~~ 1 ", b"*~~"),
+            Err(err(ParseErrorKind::BadNumber, 6))
+        );
+        assert_eq!(
+            parse_all::<isize>(b"*~~  1", b"*~~"),
+            Err(err(ParseErrorKind::BadNumber, 4))
+        );
+        assert_eq!(
+            parse_all::<isize>(b"*~~ 1 2 3", b"*~~"),
+            Err(err(ParseErrorKind::TrailingGarbage, 7))
+        );
+        assert_eq!(
+            parse_all::<u8>(b"*~~ 1 256", b"*~~"),
+            Err(err(ParseErrorKind::BadNumber, 6))
+        );
+
+        let overflow = b"s 99999999999999999999";
+        let mut args = strip_verb(overflow, b"s").unwrap();
+        assert_eq!(args.num::<isize>(), Err(err(ParseErrorKind::BadNumber, 2)));
+
+        let mut args = strip_verb(b"u", b"u").unwrap();
+        assert_eq!(args.num_or(1usize), Ok(1));
+        let mut args = strip_verb(b"u 3", b"u").unwrap();
+        assert_eq!(args.num_or(1usize), Ok(3));
+        let mut args = strip_verb(b"u -1", b"u").unwrap();
+        assert_eq!(args.num_or(1usize), Err(err(ParseErrorKind::BadNumber, 2)));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        // (every controller parses its commands through `strip_verb` / `Args`,
+        // so these are the offsets `parse_command` reports)
+        assert_eq!(unrecognized(b"frob 1"), err(ParseErrorKind::UnknownVerb, 0));
+        let mut args = strip_verb(b"* abc", b"*").unwrap();
+        assert_eq!(args.num::<isize>(), Err(err(ParseErrorKind::BadNumber, 2)));
+
+        // a missing argument is reported where it should have started...
+        let mut args = strip_verb(b"w 1", b"w").unwrap();
+        assert_eq!(args.reg(4), Ok(1));
+        assert_eq!(args.num::<isize>(), Err(err(ParseErrorKind::BadNumber, 3)));
+
+        // ...and an extra one at the space before it
+        let args = strip_verb(b"p 1", b"p").unwrap();
+        assert_eq!(
+            args.finish(()),
+            Err(err(ParseErrorKind::TrailingGarbage, 1))
+        );
+        let mut args = strip_verb(b"u 2 1", b"u").unwrap();
+        assert_eq!(args.num_or(1usize), Ok(2));
+        assert_eq!(
+            args.finish(()),
+            Err(err(ParseErrorKind::TrailingGarbage, 3))
+        );
+    }
+
+    #[test]
+    fn test_parse_reg() {
+        let reg = |line: &[u8], num_regs| strip_verb(line, b"r").unwrap().reg(num_regs);
+        assert_eq!(reg(b"r 0", 4), Ok(0));
+        assert_eq!(reg(b"r 3", 4), Ok(3));
+        assert_eq!(reg(b"r 4", 4), Err(err(ParseErrorKind::BadNumber, 2)));
+        assert_eq!(reg(b"r -1", 4), Err(err(ParseErrorKind::BadNumber, 2)));
+        assert_eq!(reg(b"r 0", 0), Err(err(ParseErrorKind::BadNumber, 2)));
+        assert_eq!(reg(b"r", 4), Err(err(ParseErrorKind::BadNumber, 1)));
+
+        let mut args = strip_verb(b"G 0100ff", b"G").unwrap();
+        assert!(
+            args.regs::<u8>(3)
+                .is_ok_and(|regs| regs.as_slice() == [1, 0, 255])
+        );
+        let mut args = strip_verb(b"G 0100ff", b"G").unwrap();
+        assert_eq!(
+            args.regs::<u8>(2).map(|_| ()),
+            Err(err(ParseErrorKind::BadNumber, 2))
+        );
     }
 }
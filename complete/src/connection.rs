@@ -0,0 +1,360 @@
+//! Byte-stream transports for the controller (as in gdbstub's `Connection`).
+//!
+//! `LineReader` / `PacketReader` read commands from a connection, and replies
+//! are written back to it, so the same controller can serve stdin / stdout, a
+//! socket, or an in-memory buffer (e.g: in tests).
+
+/// A bidirectional byte stream to the client.
+pub trait Connection {
+    type Error;
+
+    /// Reads at most `buf.len()` bytes, returning how many were read (`0` once
+    /// the client has hung up).
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error>;
+    /// Writes all of `bytes`.
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
+    /// Sends anything buffered by `write_all`.
+    fn flush(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<C: Connection + ?Sized> Connection for &mut C {
+    type Error = C::Error;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, C::Error> {
+        (**self).read(buf)
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), C::Error> {
+        (**self).write_all(bytes)
+    }
+
+    fn flush(&mut self) -> Result<(), C::Error> {
+        (**self).flush()
+    }
+}
+
+/// An error returned by the OS (i.e: `errno`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Errno(pub i32);
+
+impl Errno {
+    fn last() -> Errno {
+        core::cfg_select! {
+            target_vendor = "apple" => Errno(unsafe { *libc::__error() }),
+            _ => Errno(unsafe { *libc::__errno_location() }),
+        }
+    }
+}
+
+fn read_fd(fd: i32, buf: &mut [u8]) -> Result<usize, Errno> {
+    let n = unsafe { libc::read(fd, buf.as_mut_ptr() as *mut _, buf.len()) };
+    match n {
+        ..0 => Err(Errno::last()),
+        n => Ok(n as usize),
+    }
+}
+
+/// (so that writing to a client which has hung up fails with `EPIPE`, instead
+/// of killing the process)
+const SEND_FLAGS: libc::c_int = core::cfg_select! {
+    any(target_os = "linux", target_os = "android") => libc::MSG_NOSIGNAL,
+    _ => 0,
+};
+
+fn write_fd(fd: i32, mut bytes: &[u8], is_socket: bool) -> Result<(), Errno> {
+    while !bytes.is_empty() {
+        let (ptr, len) = (bytes.as_ptr() as *const _, bytes.len());
+        let n = match is_socket {
+            true => unsafe { libc::send(fd, ptr, len, SEND_FLAGS) },
+            false => unsafe { libc::write(fd, ptr, len) },
+        };
+        match n {
+            ..0 => return Err(Errno::last()),
+            n => bytes = &bytes[n as usize..],
+        }
+    }
+    Ok(())
+}
+
+/// Reads from stdin, and writes to stdout.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Stdio;
+
+impl Connection for Stdio {
+    type Error = Errno;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Errno> {
+        read_fd(0, buf)
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), Errno> {
+        write_fd(1, bytes, false)
+    }
+
+    fn flush(&mut self) -> Result<(), Errno> {
+        Ok(())
+    }
+}
+
+/// Returned once a `Memory` connection's output buffer is full.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OutputFull;
+
+/// Reads from a byte slice, and writes into another one.
+pub struct Memory<'a> {
+    input: &'a [u8],
+    output: &'a mut [u8],
+    written: usize,
+}
+
+impl<'a> Memory<'a> {
+    pub fn new(input: &'a [u8], output: &'a mut [u8]) -> Memory<'a> {
+        Memory {
+            input,
+            output,
+            written: 0,
+        }
+    }
+
+    /// Everything written so far.
+    pub fn output(&self) -> &[u8] {
+        &self.output[..self.written]
+    }
+
+    /// Forgets everything written so far.
+    pub fn clear_output(&mut self) {
+        self.written = 0;
+    }
+}
+
+impl Connection for Memory<'_> {
+    type Error = OutputFull;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, OutputFull> {
+        let n = buf.len().min(self.input.len());
+        let (head, tail) = self.input.split_at(n);
+        buf[..n].copy_from_slice(head);
+        self.input = tail;
+        Ok(n)
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), OutputFull> {
+        let end = self.written + bytes.len();
+        self.output
+            .get_mut(self.written..end)
+            .ok_or(OutputFull)?
+            .copy_from_slice(bytes);
+        self.written = end;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), OutputFull> {
+        Ok(())
+    }
+}
+
+/// A connected stream socket (see `Listener`), closed on drop.
+#[derive(Debug)]
+pub struct Socket {
+    fd: i32,
+}
+
+impl Connection for Socket {
+    type Error = Errno;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Errno> {
+        read_fd(self.fd, buf)
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), Errno> {
+        write_fd(self.fd, bytes, true)
+    }
+
+    fn flush(&mut self) -> Result<(), Errno> {
+        Ok(())
+    }
+}
+
+impl Drop for Socket {
+    fn drop(&mut self) {
+        unsafe { libc::close(self.fd) };
+    }
+}
+
+/// A listening Unix domain or loopback TCP socket, closed on drop.
+#[derive(Debug)]
+pub struct Listener {
+    fd: i32,
+}
+
+impl Listener {
+    /// Listens on the Unix domain socket at `path`, replacing any stale
+    /// socket left there.
+    pub fn unix(path: &[u8]) -> Result<Listener, Errno> {
+        let mut addr: libc::sockaddr_un = unsafe { core::mem::zeroed() };
+        addr.sun_family = libc::AF_UNIX as libc::sa_family_t;
+        // (leaving room for the terminating nul)
+        if path.is_empty() || path.len() >= addr.sun_path.len() {
+            return Err(Errno(libc::ENAMETOOLONG));
+        }
+        for (slot, &byte) in addr.sun_path.iter_mut().zip(path) {
+            *slot = byte as libc::c_char;
+        }
+        unsafe { libc::unlink(addr.sun_path.as_ptr()) };
+
+        let listener = Listener::socket(libc::AF_UNIX)?;
+        listener.bind_and_listen(&addr)?;
+        Ok(listener)
+    }
+
+    /// Listens on `127.0.0.1:<port>` (where port `0` picks any free port, see
+    /// `port`).
+    pub fn tcp_loopback(port: u16) -> Result<Listener, Errno> {
+        let mut addr: libc::sockaddr_in = unsafe { core::mem::zeroed() };
+        addr.sin_family = libc::AF_INET as libc::sa_family_t;
+        addr.sin_port = port.to_be();
+        addr.sin_addr.s_addr = u32::from_be_bytes([127, 0, 0, 1]).to_be();
+
+        let listener = Listener::socket(libc::AF_INET)?;
+        let reuse: libc::c_int = 1;
+        unsafe {
+            libc::setsockopt(
+                listener.fd,
+                libc::SOL_SOCKET,
+                libc::SO_REUSEADDR,
+                &reuse as *const _ as *const _,
+                size_of::<libc::c_int>() as libc::socklen_t,
+            )
+        };
+        listener.bind_and_listen(&addr)?;
+        Ok(listener)
+    }
+
+    fn socket(domain: libc::c_int) -> Result<Listener, Errno> {
+        match unsafe { libc::socket(domain, libc::SOCK_STREAM, 0) } {
+            ..0 => Err(Errno::last()),
+            fd => Ok(Listener { fd }),
+        }
+    }
+
+    fn bind_and_listen<A>(&self, addr: &A) -> Result<(), Errno> {
+        let len = size_of::<A>() as libc::socklen_t;
+        if unsafe { libc::bind(self.fd, addr as *const A as *const libc::sockaddr, len) } < 0
+            || unsafe { libc::listen(self.fd, 1) } < 0
+        {
+            return Err(Errno::last());
+        }
+        Ok(())
+    }
+
+    /// The local port of a TCP listener.
+    pub fn port(&self) -> Result<u16, Errno> {
+        let mut addr: libc::sockaddr_in = unsafe { core::mem::zeroed() };
+        let mut len = size_of::<libc::sockaddr_in>() as libc::socklen_t;
+        let ret = unsafe {
+            libc::getsockname(
+                self.fd,
+                &mut addr as *mut _ as *mut libc::sockaddr,
+                &mut len,
+            )
+        };
+        match ret {
+            ..0 => Err(Errno::last()),
+            _ => Ok(u16::from_be(addr.sin_port)),
+        }
+    }
+
+    /// Waits for the next client to connect.
+    pub fn accept(&self) -> Result<Socket, Errno> {
+        let fd = unsafe { libc::accept(self.fd, core::ptr::null_mut(), core::ptr::null_mut()) };
+        match fd {
+            ..0 => Err(Errno::last()),
+            fd => Ok(Socket { fd }),
+        }
+    }
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        unsafe { libc::close(self.fd) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::io::Write;
+    use std::thread;
+
+    use super::*;
+
+    /// Has `client` send `hello`, and then wait for `bye`.
+    fn greet<S: Read + Write + Send + 'static>(mut client: S) -> thread::JoinHandle<()> {
+        thread::spawn(move || {
+            client.write_all(b"hello").unwrap();
+            let mut buf = [0u8; 3];
+            client.read_exact(&mut buf).unwrap();
+            assert_eq!(&buf, b"bye");
+        })
+    }
+
+    /// Answers `greet`.
+    fn serve(mut socket: Socket) {
+        let mut buf = [0u8; 5];
+        let mut len = 0;
+        while len < buf.len() {
+            let n = socket.read(&mut buf[len..]).unwrap();
+            assert_ne!(n, 0);
+            len += n;
+        }
+        assert_eq!(&buf, b"hello");
+        socket.write_all(b"bye").unwrap();
+        socket.flush().unwrap();
+    }
+
+    #[test]
+    fn test_memory() {
+        let mut output = [0u8; 4];
+        let mut conn = Memory::new(b"abc", &mut output);
+
+        let mut buf = [0u8; 2];
+        assert_eq!(conn.read(&mut buf), Ok(2));
+        assert_eq!(&buf, b"ab");
+        assert_eq!(conn.read(&mut buf), Ok(1));
+        assert_eq!(conn.read(&mut buf), Ok(0));
+
+        assert_eq!(conn.write_all(b"OK\n"), Ok(()));
+        assert_eq!(conn.write_all(b"OK\n"), Err(OutputFull));
+        assert_eq!(conn.output(), b"OK\n");
+        conn.clear_output();
+        assert_eq!(conn.write_all(b"5\n"), Ok(()));
+        assert_eq!(conn.output(), b"5\n");
+    }
+
+    #[test]
+    fn test_unix_socket() {
+        let path = std::env::temp_dir().join(format!("otm-{}.sock", std::process::id()));
+        let listener = Listener::unix(path.as_os_str().as_encoded_bytes()).unwrap();
+
+        let client = greet(std::os::unix::net::UnixStream::connect(&path).unwrap());
+        serve(listener.accept().unwrap());
+        client.join().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            Listener::unix(&[b'x'; 4096]).unwrap_err(),
+            Errno(libc::ENAMETOOLONG)
+        );
+    }
+
+    #[test]
+    fn test_tcp_loopback() {
+        let listener = Listener::tcp_loopback(0).unwrap();
+        let port = listener.port().unwrap();
+        assert_ne!(port, 0);
+
+        let client = greet(std::net::TcpStream::connect(("127.0.0.1", port)).unwrap());
+        serve(listener.accept().unwrap());
+        client.join().unwrap();
+    }
+}
@@ -0,0 +1,95 @@
+use crate::commands::ParseError;
+use crate::commands::ParseErrorKind;
+use crate::writer::ResponseWriter;
+
+/// Errors returned by `TargetController::handle` (shared by every strategy).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error<E> {
+    /// The target failed to carry out the command, but can keep serving.
+    NonFatal(E),
+    /// The target failed in a way it can't recover from.
+    Fatal(E),
+    /// The target doesn't support the command.
+    Unsupported,
+    /// The command couldn't be parsed.
+    Parse(ParseError),
+    /// The target's implementation is inconsistent (e.g: `inc` without `dec`).
+    ///
+    /// (only detectable at runtime by `using_options`)
+    InvalidImpl,
+}
+
+impl<E> Error<E> {
+    /// Wraps an error returned by the target, as classified by the target's
+    /// `is_fatal`.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    pub fn target(err: E, fatal: bool) -> Error<E> {
+        match fatal {
+            true => Error::Fatal(err),
+            false => Error::NonFatal(err),
+        }
+    }
+
+    /// Whether the session has to end.
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, Error::Fatal(_) | Error::InvalidImpl)
+    }
+
+    /// Writes the reply sent to the client in place of a result (see
+    /// `response::reply`): `E NN`, an empty line if the command isn't
+    /// supported, or `E.<message> at <offset>` if it couldn't be parsed.
+    pub fn write_reply<W: ResponseWriter>(&self, out: &mut W) {
+        match self {
+            Error::NonFatal(_) => out.write_line(b"E 01"),
+            Error::Fatal(_) => out.write_line(b"E 02"),
+            Error::Unsupported => out.write_line(b""),
+            Error::Parse(err) => {
+                out.write(b"E.");
+                out.write(err.kind.message().as_bytes());
+                out.write(b" at ");
+                out.write_num(err.offset);
+                out.write(b"\n");
+            }
+            Error::InvalidImpl => out.write_line(b"E 05"),
+        }
+    }
+}
+
+impl<E> From<ParseError> for Error<E> {
+    /// (a command the target doesn't support is rejected the same way whether
+    /// it's caught while parsing or while handling it)
+    fn from(err: ParseError) -> Error<E> {
+        match err.kind {
+            ParseErrorKind::Unsupported => Error::Unsupported,
+            _ => Error::Parse(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classification() {
+        assert_eq!(Error::target("oops", false), Error::NonFatal("oops"));
+        assert_eq!(Error::target("oops", true), Error::Fatal("oops"));
+
+        assert!(!Error::NonFatal(()).is_fatal());
+        assert!(!Error::<()>::Unsupported.is_fatal());
+
+        let unsupported = ParseError {
+            kind: ParseErrorKind::Unsupported,
+            offset: 0,
+        };
+        let garbage = ParseError {
+            kind: ParseErrorKind::TrailingGarbage,
+            offset: 1,
+        };
+        assert_eq!(Error::<()>::from(unsupported), Error::Unsupported);
+        assert_eq!(Error::<()>::from(garbage), Error::Parse(garbage));
+        assert!(!Error::<()>::Parse(garbage).is_fatal());
+        assert!(Error::Fatal(()).is_fatal());
+        assert!(Error::<()>::InvalidImpl.is_fatal());
+    }
+}
@@ -0,0 +1,194 @@
+//! A fixed-capacity, allocation-free history (e.g: for `TargetExtUndo`).
+
+/// The (at most) `N` most recently pushed snapshots, where pushing onto a full
+/// history overwrites the oldest one.
+#[derive(Clone, Debug)]
+pub struct History<S, const N: usize> {
+    buf: [S; N],
+    /// The slot the next snapshot is written to.
+    head: usize,
+    len: usize,
+    /// The snapshot the last `push` overwrote, if any (see `unpush`).
+    evicted: Option<S>,
+}
+
+impl<S: Copy + Default, const N: usize> Default for History<S, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Copy + Default, const N: usize> History<S, N> {
+    pub fn new() -> History<S, N> {
+        History {
+            buf: [S::default(); N],
+            head: 0,
+            len: 0,
+            evicted: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.evicted = None;
+    }
+
+    /// Records `snapshot` as the most recent one.
+    pub fn push(&mut self, snapshot: S) {
+        if N == 0 {
+            return;
+        }
+        self.evicted = (self.len == N).then(|| self.buf[self.head]);
+        self.buf[self.head] = snapshot;
+        self.head = (self.head + 1) % N;
+        self.len = (self.len + 1).min(N);
+    }
+
+    /// Removes the most recent snapshot.
+    pub fn pop(&mut self) -> Option<S> {
+        if self.len == 0 {
+            return None;
+        }
+        self.evicted = None;
+        self.head = (self.head + N - 1) % N;
+        self.len -= 1;
+        Some(self.buf[self.head])
+    }
+
+    /// Removes the `n` most recent snapshots, returning the oldest of them
+    /// (i.e: the one to restore in order to revert all `n`).
+    ///
+    /// Leaves the history untouched if it holds fewer than `n` snapshots.
+    pub fn rewind(&mut self, n: usize) -> Option<S> {
+        if n == 0 || n > self.len {
+            return None;
+        }
+        self.evicted = None;
+        self.head = (self.head + N - n) % N;
+        self.len -= n;
+        Some(self.buf[self.head])
+    }
+
+    /// Takes back the most recent snapshot, as if it had never been pushed:
+    /// unlike `pop`, the oldest snapshot is restored if pushing it overwrote
+    /// one (as long as nothing else was pushed or removed since).
+    pub fn unpush(&mut self) -> Option<S> {
+        let evicted = self.evicted.take();
+        let snapshot = self.pop()?;
+        if let Some(evicted) = evicted {
+            // (the slot of the snapshot just removed is where the oldest one was)
+            self.buf[self.head] = evicted;
+            self.len += 1;
+        }
+        Some(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_pop() {
+        let mut history = History::<u8, 4>::new();
+        assert_eq!(history.pop(), None);
+
+        history.push(1);
+        history.push(2);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.pop(), Some(2));
+        assert_eq!(history.pop(), Some(1));
+        assert_eq!(history.pop(), None);
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn test_overwrites_oldest() {
+        let mut history = History::<u8, 3>::new();
+        for snapshot in 1..=5 {
+            history.push(snapshot);
+        }
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.pop(), Some(5));
+        assert_eq!(history.pop(), Some(4));
+        assert_eq!(history.pop(), Some(3));
+        assert_eq!(history.pop(), None);
+    }
+
+    #[test]
+    fn test_rewind() {
+        let mut history = History::<u8, 4>::new();
+        for snapshot in 1..=6 {
+            history.push(snapshot);
+        }
+
+        // (only 3..=6 are left, wrapped around the end of the buffer)
+        assert_eq!(history.rewind(5), None);
+        assert_eq!(history.len(), 4);
+        assert_eq!(history.rewind(0), None);
+        assert_eq!(history.rewind(3), Some(4));
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.rewind(1), Some(3));
+        assert!(history.is_empty());
+
+        history.push(7);
+        history.push(8);
+        history.clear();
+        assert_eq!(history.pop(), None);
+    }
+
+    #[test]
+    fn test_unpush() {
+        let mut history = History::<u8, 3>::new();
+        for snapshot in 1..=4 {
+            history.push(snapshot);
+        }
+        // (pushing 4 overwrote 1, which is brought back)
+        assert_eq!(history.unpush(), Some(4));
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.rewind(3), Some(1));
+
+        history.push(5);
+        history.push(6);
+        assert_eq!(history.unpush(), Some(6));
+        assert_eq!(history.unpush(), Some(5));
+        assert_eq!(history.unpush(), None);
+    }
+
+    #[test]
+    fn test_failed_checkpoints() {
+        let mut history = History::<u8, 3>::new();
+        for snapshot in 1..=3 {
+            history.push(snapshot);
+        }
+
+        // (as a controller does for a command which fails: a checkpoint taken
+        // and then taken back costs none of the earlier snapshots)
+        for snapshot in 4..=8 {
+            history.push(snapshot);
+            assert_eq!(history.unpush(), Some(snapshot));
+        }
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.rewind(4), None);
+        assert_eq!(history.rewind(3), Some(1));
+        assert_eq!(history.unpush(), None);
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let mut history = History::<u8, 0>::new();
+        history.push(1);
+        assert!(history.is_empty());
+        assert_eq!(history.pop(), None);
+        assert_eq!(history.rewind(1), None);
+        assert_eq!(history.unpush(), None);
+    }
+}
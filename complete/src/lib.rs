@@ -0,0 +1,48 @@
+//! Every optional trait method strategy, side by side.
+//!
+//! The `optional-trait-methods` binary picks a single strategy / target pair
+//! via the `using_*` / `target_*` features (and serves it with
+//! `session::serve`), but the library exposes all of them at once, whatever
+//! the features, except for `using_try_as_dyn`, which requires nightly, and is
+//! only included when its feature is enabled.
+//!
+//! (`using_cfg_gates` still trims its own targets and `Command` down to the
+//! `target_*` feature's extensions, as that's the strategy being shown off.)
+#![cfg_attr(not(test), no_std)]
+#![cfg_attr(feature = "using_try_as_dyn", feature(try_as_dyn))]
+
+pub mod capabilities;
+pub mod commands;
+pub mod connection;
+pub mod error;
+pub mod history;
+mod line_reader;
+pub mod num;
+pub(crate) mod packet_reader;
+pub mod registers;
+pub mod repl;
+pub mod response;
+pub mod session;
+pub mod state_machine;
+pub mod writer;
+
+pub use line_reader::LineReader;
+pub use line_reader::LineTooLong;
+pub use packet_reader::PacketReader;
+pub use packet_reader::PacketWriter;
+
+pub mod using_cfg_gates;
+pub mod using_fn;
+pub mod using_is_supported;
+pub mod using_options;
+pub mod using_traits;
+#[cfg(feature = "using_try_as_dyn")]
+pub mod using_try_as_dyn;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dead_code_marker {
+    ($marker:literal) => {{
+        core::hint::black_box($marker);
+    }};
+}
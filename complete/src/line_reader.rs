@@ -1,3 +1,13 @@
+use crate::connection::Connection;
+
+/// A line which didn't fit in `read_line`'s `line_buf` (the whole line is
+/// discarded, rather than truncated).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LineTooLong {
+    /// How many bytes fit (i.e: `line_buf.len()`).
+    pub max_len: usize,
+}
+
 pub struct LineReader {
     buf: [u8; 1024],
     len: usize,
@@ -21,18 +31,28 @@ impl Default for LineReader {
 }
 
 impl LineReader {
-    pub fn read_line<'a>(&'a mut self, line_buf: &'a mut [u8]) -> Option<&'a [u8]> {
+    /// Returns the next line read from `conn` (without its `\n`), or `None`
+    /// once `conn` hangs up (or fails).
+    ///
+    /// Lines which don't fit in `line_buf` are skipped up to (and including)
+    /// their `\n`, and reported as `LineTooLong`.
+    pub fn read_line<'a, C: Connection>(
+        &'a mut self,
+        conn: &mut C,
+        line_buf: &'a mut [u8],
+    ) -> Option<Result<&'a [u8], LineTooLong>> {
         let mut out_len = 0;
+        let mut overlong = false;
         loop {
             if self.pos >= self.len {
-                let n = unsafe { libc::read(0, self.buf.as_mut_ptr() as *mut _, self.buf.len()) };
-                if n <= 0 {
-                    if out_len > 0 {
-                        return line_buf.get(..out_len);
+                let n = conn.read(&mut self.buf).unwrap_or(0);
+                if n == 0 {
+                    if out_len > 0 || overlong {
+                        return finish(line_buf, out_len, overlong);
                     }
                     return None;
                 }
-                self.len = n as usize;
+                self.len = n;
                 self.pos = 0;
             }
 
@@ -40,13 +60,104 @@ impl LineReader {
             for &byte in slice {
                 self.pos += 1;
                 if byte == b'\n' {
-                    return line_buf.get(..out_len);
+                    return finish(line_buf, out_len, overlong);
                 }
-                if let Some(slot) = line_buf.get_mut(out_len) {
-                    *slot = byte;
-                    out_len += 1;
+                match line_buf.get_mut(out_len) {
+                    Some(slot) => {
+                        *slot = byte;
+                        out_len += 1;
+                    }
+                    None => overlong = true,
+                }
+            }
+        }
+    }
+}
+
+#[cfg_attr(feature = "always_inline", inline(always))]
+fn finish(line_buf: &[u8], len: usize, overlong: bool) -> Option<Result<&[u8], LineTooLong>> {
+    match overlong {
+        true => Some(Err(LineTooLong {
+            max_len: line_buf.len(),
+        })),
+        false => line_buf.get(..len).map(Ok),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::connection::Memory;
+
+    /// Every line read from `input`, where `None` stands for a `LineTooLong`.
+    fn read_all(input: &[u8], line_len: usize) -> Vec<Option<Vec<u8>>> {
+        let mut conn = Memory::new(input, &mut []);
+        let mut reader = LineReader::new();
+        let mut line_buf = std::vec![0u8; line_len];
+        let mut lines = Vec::new();
+        while let Some(line) = reader.read_line(&mut conn, &mut line_buf) {
+            match line {
+                Ok(line) => lines.push(Some(line.to_vec())),
+                Err(err) => {
+                    assert_eq!(err.max_len, line_len);
+                    lines.push(None);
                 }
             }
         }
+        lines
+    }
+
+    fn line(line: &[u8]) -> Option<Vec<u8>> {
+        Some(line.to_vec())
+    }
+
+    #[test]
+    fn test_read_line() {
+        assert_eq!(
+            read_all(b"p\ns 5\n\n+", 8),
+            [line(b"p"), line(b"s 5"), line(b""), line(b"+")]
+        );
+        assert_eq!(read_all(b"", 8), []);
+        assert_eq!(read_all(b"12345678\n", 8), [line(b"12345678")]);
+    }
+
+    #[test]
+    fn test_overlong_lines() {
+        // (the rest of the line is discarded, rather than parsed as a command)
+        assert_eq!(read_all(b"123456789\np\n", 8), [None, line(b"p")]);
+        assert_eq!(read_all(b"s 123456789", 8), [None]);
+        assert_eq!(
+            read_all(b"+\n123456789\n\n", 8),
+            [line(b"+"), None, line(b"")]
+        );
+    }
+
+    #[test]
+    fn test_buffer_boundary() {
+        // `LineReader` reads 1024 bytes at a time, so these lines are split
+        // across two reads
+        let mut input = std::vec![b'x'; 1000];
+        input.push(b'\n');
+        input.extend([b'y'; 48]);
+        input.push(b'\n');
+        let lines = read_all(&input, 64);
+        assert_eq!(lines, [None, line(&[b'y'; 48])]);
+
+        // overlong lines spanning several reads, ending right at (and right
+        // after) a read's last byte
+        for len in [1023, 1024, 3000, 3071] {
+            let mut input = std::vec![b'x'; len];
+            input.extend(b"\np\n");
+            assert_eq!(read_all(&input, 64), [None, line(b"p")], "{}", len);
+        }
+
+        // a line that fits, but only arrives in the next read
+        let mut input = std::vec![b'\n'; 1020];
+        input.extend(b"s 1234\n");
+        let lines = read_all(&input, 64);
+        assert_eq!(lines.len(), 1021);
+        assert_eq!(lines.last(), Some(&line(b"s 1234")));
     }
 }
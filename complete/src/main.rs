@@ -1,6 +1,5 @@
 #![cfg_attr(not(test), no_std)]
 #![cfg_attr(not(test), no_main)]
-#![cfg_attr(feature = "using_try_as_dyn", feature(try_as_dyn))]
 
 extern crate libc;
 
@@ -18,91 +17,169 @@ fn panic(_info: &core::panic::PanicInfo) -> ! {
 #[unsafe(no_mangle)]
 pub extern "C" fn rust_eh_personality() {}
 
-mod commands;
-mod line_reader;
-mod print_macros;
+mod cli;
 
-pub use line_reader::LineReader;
+use optional_trait_methods::*;
 
 core::cfg_select! {
     feature = "using_cfg_gates" => {
-        mod using_cfg_gates;
-        use using_cfg_gates::*;
+        use optional_trait_methods::using_cfg_gates::*;
     }
     feature = "using_is_supported" => {
-        mod using_is_supported;
-        use using_is_supported::*;
+        use optional_trait_methods::using_is_supported::*;
     }
     feature = "using_options" => {
-        mod using_options;
-        use using_options::*;
+        use optional_trait_methods::using_options::*;
     }
     feature = "using_fn" => {
-        mod using_fn;
-        use using_fn::*;
+        use optional_trait_methods::using_fn::*;
     }
     feature = "using_traits" => {
-        mod using_traits;
-        use using_traits::*;
+        use optional_trait_methods::using_traits::*;
     }
     feature = "using_try_as_dyn" => {
-        mod using_try_as_dyn;
-        use using_try_as_dyn::*;
+        use optional_trait_methods::using_try_as_dyn::*;
     }
     _ => {
         compile_error!("must select one of the `using_` features!");
     }
 }
 
-use controller::Error;
-use controller::TargetController;
+use cli::CliError;
+use cli::OnError;
+use cli::Options;
+use cli::Transport;
+use connection::Connection;
+use connection::Listener;
+use connection::Stdio;
+use num::Num;
 
-#[unsafe(no_mangle)]
-pub extern "C" fn main(_argc: isize, _argv: *const *const u8) -> isize {
-    let target = core::cfg_select! {
-        feature = "target_basic" => targets::BasicTarget::new(0),
-        feature = "target_advanced" => targets::AdvancedTarget::new(0),
-        feature = "target_faulty" => targets::FaultyTarget::new(0),
-        _ => compile_error!("must select one of the `target_` features!"),
-    };
+core::cfg_select! {
+    feature = "target_basic" => {
+        type SelectedTarget = targets::BasicTarget;
+    }
+    feature = "target_advanced" => {
+        type SelectedTarget = targets::AdvancedTarget;
+    }
+    feature = "target_faulty" => {
+        type SelectedTarget = targets::FaultyTarget;
+    }
+    _ => {
+        compile_error!("must select one of the `target_` features!");
+    }
+}
 
-    let mut controller = TargetController::new(target);
+/// The selected target's state (i.e: what `--initial-state` is parsed as).
+type State = <SelectedTarget as target::Target>::State;
 
-    let mut reader = LineReader::new();
-    let mut line_buf = [0u8; 128];
-    while let Some(line) = reader.read_line(&mut line_buf) {
-        if line.is_empty() {
-            continue;
+core::cfg_select! {
+    all(feature = "toggleable", feature = "using_traits") => {
+        /// What drives the selected target (through `Toggleable`, so that its
+        /// extensions can be switched off and on by `!-` / `!+`).
+        type ServedController = toggleable::ToggleController<SelectedTarget>;
+
+        fn new_controller(initial_state: State) -> ServedController {
+            let target = toggleable::Toggleable::new(SelectedTarget::new(initial_state));
+            toggleable::ToggleController::new(target)
         }
-        let res = match controller.parse_command(line) {
-            Some(cmd) => controller.handle(&cmd),
-            None => controller.unsupported_cmd(),
-        };
-
-        if let Err(e) = res {
-            core::cfg_select! {
-                feature = "using_options" => {
-                    match e {
-                        Error::Target(e) => crate::println_str!(e),
-                        Error::InvalidImpl => crate::println_str!("Invalid implementation!"),
-                    }
-                }
-                _ => {
-                    match e {
-                        Error::Target(e) => crate::println_str!(e),
-                    }
-                }
-            }
-            break;
+    }
+    feature = "toggleable" => {
+        compile_error!("`toggleable` is only supported by `using_traits`!");
+    }
+    _ => {
+        /// What drives the selected target.
+        type ServedController = controller::TargetController<SelectedTarget>;
+
+        fn new_controller(initial_state: State) -> ServedController {
+            controller::TargetController::new(SelectedTarget::new(initial_state))
         }
     }
+}
 
-    0
+/// usage: optional-trait-methods [options] (see `cli::USAGE`)
+///
+/// Serves a single client, over stdin / stdout by default, or over the first
+/// connection accepted on a Unix domain socket / loopback TCP port.
+///
+/// # Safety
+///
+/// `argv` must hold `argc` nul-terminated strings (as passed by the C runtime).
+#[cfg_attr(not(test), unsafe(no_mangle))]
+pub unsafe extern "C" fn main(argc: isize, argv: *const *const u8) -> isize {
+    let args = (1..argc)
+        .map(|idx| unsafe { core::ffi::CStr::from_ptr(*argv.offset(idx) as *const _) }.to_bytes());
+    let opts = match cli::parse(args) {
+        Ok(opts) => opts,
+        Err(err) => return usage_error(err),
+    };
+    if opts.repl && !cfg!(feature = "repl") {
+        return fail("--repl needs the `repl` feature");
+    }
+    if opts.help {
+        let _ = Stdio.write_all(cli::USAGE.as_bytes());
+        let _ = Stdio.write_all(b"\n");
+        return 0;
+    }
+    let initial_state = match opts.initial_state {
+        None => State::ZERO,
+        Some(n) => match num::parse_num(n) {
+            Some(n) => n,
+            None => return usage_error(CliError::InvalidValue("--initial-state", n)),
+        },
+    };
+
+    let listener = match opts.transport {
+        Transport::Stdio => return serve(Stdio, &opts, initial_state),
+        Transport::Unix(path) => Listener::unix(path),
+        Transport::Tcp(port) => Listener::tcp_loopback(port),
+    };
+    // (a port picked by the OS is reported, for the client to connect to)
+    if let (Transport::Tcp(0), Ok(listener)) = (opts.transport, &listener) {
+        report_port(listener);
+    }
+    match listener.and_then(|listener| listener.accept()) {
+        Ok(socket) => serve(socket, &opts, initial_state),
+        Err(_) => fail("failed to accept a connection"),
+    }
+}
+
+fn write_stderr(bytes: &[u8]) {
+    unsafe { libc::write(2, bytes.as_ptr() as *const _, bytes.len()) };
 }
 
-#[macro_export]
-macro_rules! __dead_code_marker {
-    ($marker:literal) => {{
-        core::hint::black_box($marker);
-    }};
+fn report_port(listener: &Listener) {
+    if let Ok(port) = listener.port() {
+        let mut buf = [0u8; num::MAX_DECIMAL_LEN];
+        write_stderr(b"listening on 127.0.0.1:");
+        write_stderr(num::format_decimal(port, &mut buf));
+        write_stderr(b"\n");
+    }
+}
+
+fn fail(msg: &str) -> isize {
+    write_stderr(msg.as_bytes());
+    write_stderr(b"\n");
+    1
+}
+
+fn usage_error(err: CliError) -> isize {
+    write_stderr(b"error: ");
+    err.write_to(write_stderr);
+    write_stderr(b"\n\n");
+    fail(cli::USAGE)
+}
+
+fn serve<C: Connection>(conn: C, opts: &Options, initial_state: State) -> isize {
+    let mut controller = new_controller(initial_state);
+    let opts = session::Options {
+        echo: opts.echo,
+        repl: opts.repl,
+        exit_on_error: opts.on_error == OnError::Exit,
+    };
+    match session::serve(conn, &mut controller, &opts) {
+        Ok(()) => 0,
+        // (whether the session ended on a fatal error, or on any error with
+        // `--on-error=exit`, it didn't end the way it should have)
+        Err(_) => 1,
+    }
 }
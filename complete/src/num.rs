@@ -0,0 +1,412 @@
+//! Integer types a target's state (and registers) can be made of.
+
+use core::fmt::Debug;
+
+/// Longest decimal representation of any `Num` (i.e: `i128::MIN`).
+pub const MAX_DECIMAL_LEN: usize = 40;
+
+/// Longest hex representation of any `Num` (i.e: `-` and `i128::MIN`'s 32
+/// digits).
+pub const MAX_HEX_LEN: usize = 33;
+
+/// Longest little-endian encoding of any `Num` (i.e: `u128`).
+pub const MAX_BYTES: usize = 16;
+
+/// The state width of every strategy's `AdvancedTarget`.
+///
+/// (`i128` with the `state_i128` feature, to compare the asm of a 128-bit
+/// target against the usual native width)
+#[cfg(not(feature = "state_i128"))]
+pub type AdvancedState = isize;
+#[cfg(feature = "state_i128")]
+pub type AdvancedState = i128;
+
+/// The integer operations the protocol needs from a target's `State`.
+///
+/// Implemented for `i8..=i128`, `u8..=u128`, `isize` and `usize`, each
+/// forwarding to the primitive's own (inherent) methods.
+pub trait Num: Copy + Debug + Default + Eq + Ord + 'static {
+    const ZERO: Self;
+    const ONE: Self;
+    const TEN: Self;
+    const MIN: Self;
+    const MAX: Self;
+    /// Size of the little-endian encoding used by `g` / `G`.
+    const BYTES: usize;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn is_negative(self) -> bool;
+
+    /// Converts a single digit (`0..=15`, i.e: up to hex) or radix.
+    fn from_digit(d: u8) -> Self;
+    /// Splits off the last decimal digit, returning `(self / 10, |self % 10|)`.
+    fn div_rem_10(self) -> (Self, u8);
+    /// Splits off the last hex digit, returning `(self / 16, |self % 16|)`.
+    fn div_rem_16(self) -> (Self, u8);
+
+    /// Writes `Self::BYTES` little-endian bytes to the start of `out`.
+    fn write_le_bytes(self, out: &mut [u8; MAX_BYTES]);
+    /// Reads `Self::BYTES` little-endian bytes.
+    fn read_le_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_num {
+    ($($ty:ty),*) => {$(
+        impl Num for $ty {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            const TEN: Self = 10;
+            const MIN: Self = <$ty>::MIN;
+            const MAX: Self = <$ty>::MAX;
+            const BYTES: usize = size_of::<$ty>();
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_add(self, rhs)
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_sub(self, rhs)
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_mul(self, rhs)
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_div(self, rhs)
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_rem(self, rhs)
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$ty>::wrapping_add(self, rhs)
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$ty>::wrapping_sub(self, rhs)
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$ty>::wrapping_mul(self, rhs)
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            #[allow(unused_comparisons)] // (unsigned types)
+            fn is_negative(self) -> bool {
+                self < 0
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            fn from_digit(d: u8) -> Self {
+                d as Self
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            #[allow(unused_comparisons)] // (unsigned types)
+            fn div_rem_10(self) -> (Self, u8) {
+                let rem = self % 10;
+                let digit = if rem < 0 { 0 - rem } else { rem };
+                (self / 10, digit as u8)
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            #[allow(unused_comparisons)] // (unsigned types)
+            fn div_rem_16(self) -> (Self, u8) {
+                let rem = self % 16;
+                let digit = if rem < 0 { 0 - rem } else { rem };
+                (self / 16, digit as u8)
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            fn write_le_bytes(self, out: &mut [u8; MAX_BYTES]) {
+                out[..Self::BYTES].copy_from_slice(&self.to_le_bytes());
+            }
+
+            #[cfg_attr(feature = "always_inline", inline(always))]
+            fn read_le_bytes(bytes: &[u8]) -> Option<Self> {
+                Some(<$ty>::from_le_bytes(bytes.try_into().ok()?))
+            }
+        }
+    )*};
+}
+
+impl_num!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Parses an optionally-signed number, rejecting anything that doesn't fit in
+/// `N`.
+///
+/// Numbers are decimal, unless prefixed by `0x` (hex), `0o` (octal) or `0b`
+/// (binary), and may contain `_` separators between any two digits (e.g:
+/// `-0xdead_beef`, but neither `1__0`, `_1`, `1_` nor `0x_1`).
+#[cfg_attr(feature = "interpretable_asm", inline(never))]
+pub fn parse_num<N: Num>(bytes: &[u8]) -> Option<N> {
+    let (is_neg, rest) = split_sign(bytes)?;
+    let (radix, digits) = match rest {
+        [b'0', b'x' | b'X', digits @ ..] => (16, digits),
+        [b'0', b'o' | b'O', digits @ ..] => (8, digits),
+        [b'0', b'b' | b'B', digits @ ..] => (2, digits),
+        // (decimal gets a loop of its own, so that it multiplies by a constant)
+        _ => return accumulate::<N, true>(is_neg, 10, rest),
+    };
+    accumulate::<N, true>(is_neg, radix, digits)
+}
+
+/// Parses an optionally-signed hex number, without a prefix or separators
+/// (e.g: `-1f`, as in RSP payloads), rejecting anything that doesn't fit in
+/// `N`.
+#[cfg_attr(feature = "interpretable_asm", inline(never))]
+pub fn parse_hex<N: Num>(bytes: &[u8]) -> Option<N> {
+    let (is_neg, digits) = split_sign(bytes)?;
+    accumulate::<N, false>(is_neg, 16, digits)
+}
+
+#[cfg_attr(feature = "always_inline", inline(always))]
+fn split_sign(bytes: &[u8]) -> Option<(bool, &[u8])> {
+    Some(match bytes.split_first()? {
+        (b'-', rest) => (true, rest),
+        (b'+', rest) => (false, rest),
+        _ => (false, bytes),
+    })
+}
+
+/// The value of `b` as a digit in base `radix`.
+#[cfg_attr(feature = "always_inline", inline(always))]
+fn digit(radix: u8, b: u8) -> Option<u8> {
+    let d = match b {
+        b'0'..=b'9' => b - b'0',
+        _ if radix > 10 => match (b | 0x20).wrapping_sub(b'a') {
+            d @ 0..6 => d + 10,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    (d < radix).then_some(d)
+}
+
+#[cfg_attr(feature = "always_inline", inline(always))]
+fn accumulate<N: Num, const SEPARATORS: bool>(is_neg: bool, radix: u8, digits: &[u8]) -> Option<N> {
+    // (separators can only go between two digits: the first digit is checked
+    // here, the last one below, and every separator must follow a digit)
+    digit(radix, *digits.first()?)?;
+    if digits.last() == Some(&b'_') {
+        return None;
+    }
+    // (negative numbers are accumulated downwards, so that `N::MIN` fits)
+    let mut val = N::ZERO;
+    let mut after_digit = false;
+    for &b in digits {
+        if SEPARATORS && b == b'_' {
+            if !core::mem::replace(&mut after_digit, false) {
+                return None;
+            }
+            continue;
+        }
+        after_digit = true;
+        let d = N::from_digit(digit(radix, b)?);
+        val = val.checked_mul(N::from_digit(radix))?;
+        val = match is_neg {
+            true => val.checked_sub(d)?,
+            false => val.checked_add(d)?,
+        };
+    }
+    Some(val)
+}
+
+/// Formats `n` in decimal, returning the digits (written to the end of `buf`).
+pub fn format_decimal<N: Num>(n: N, buf: &mut [u8; MAX_DECIMAL_LEN]) -> &[u8] {
+    format_digits(n, buf, N::div_rem_10)
+}
+
+/// Formats `n` in lowercase hex, without a prefix (i.e: as `parse_hex` takes
+/// it, e.g: `-2a`), returning the digits (written to the end of `buf`).
+pub fn format_hex<N: Num>(n: N, buf: &mut [u8; MAX_HEX_LEN]) -> &[u8] {
+    format_digits(n, buf, N::div_rem_16)
+}
+
+/// Writes `n`'s digits backwards from the end of `buf`, as split off by
+/// `div_rem`.
+///
+/// (digits are split off `n` itself, rather than its absolute value, which
+/// wouldn't fit for `N::MIN`)
+#[cfg_attr(feature = "always_inline", inline(always))]
+fn format_digits<N: Num, const LEN: usize>(
+    n: N,
+    buf: &mut [u8; LEN],
+    div_rem: impl Fn(N) -> (N, u8),
+) -> &[u8] {
+    let mut pos = buf.len();
+    let mut rest = n;
+    loop {
+        let (quot, digit) = div_rem(rest);
+        pos -= 1;
+        buf[pos] = digit + if digit < 10 { b'0' } else { b'a' - 10 };
+        rest = quot;
+        if rest == N::ZERO {
+            break;
+        }
+    }
+    if n.is_negative() {
+        pos -= 1;
+        buf[pos] = b'-';
+    }
+    &buf[pos..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_num() {
+        assert_eq!(parse_num::<i8>(b"-128"), Some(i8::MIN));
+        assert_eq!(parse_num::<i8>(b"127"), Some(i8::MAX));
+        assert_eq!(parse_num::<i8>(b"128"), None);
+        assert_eq!(parse_num::<i8>(b"-129"), None);
+
+        assert_eq!(parse_num::<u8>(b"255"), Some(u8::MAX));
+        assert_eq!(parse_num::<u8>(b"256"), None);
+        assert_eq!(parse_num::<u8>(b"-1"), None);
+        assert_eq!(parse_num::<u8>(b"-0"), Some(0));
+
+        assert_eq!(
+            parse_num::<i128>(b"-170141183460469231731687303715884105728"),
+            Some(i128::MIN)
+        );
+        assert_eq!(
+            parse_num::<u128>(b"340282366920938463463374607431768211455"),
+            Some(u128::MAX)
+        );
+        assert_eq!(parse_num::<u32>(b"+42"), Some(42));
+        assert_eq!(parse_num::<u32>(b"4x"), None);
+        assert_eq!(parse_num::<u32>(b"ff"), None);
+    }
+
+    #[test]
+    fn test_parse_radix() {
+        assert_eq!(parse_num::<i32>(b"0x7fffffff"), Some(i32::MAX));
+        assert_eq!(parse_num::<i32>(b"-0X8000_0000"), Some(i32::MIN));
+        assert_eq!(parse_num::<i32>(b"0x8000_0000"), None);
+        assert_eq!(parse_num::<u8>(b"0o377"), Some(u8::MAX));
+        assert_eq!(parse_num::<u8>(b"0o400"), None);
+        assert_eq!(parse_num::<u8>(b"0o8"), None);
+        assert_eq!(parse_num::<u8>(b"0b1111_1111"), Some(u8::MAX));
+        assert_eq!(parse_num::<u8>(b"0b1_0000_0000"), None);
+        assert_eq!(parse_num::<u8>(b"0b2"), None);
+        assert_eq!(parse_num::<u8>(b"0b"), None);
+        assert_eq!(parse_num::<u8>(b"0"), Some(0));
+        assert_eq!(parse_num::<u8>(b"010"), Some(10));
+
+        assert_eq!(parse_num::<u32>(b"1_000_000"), Some(1_000_000));
+        assert_eq!(parse_num::<u32>(b"1_0"), Some(10));
+        assert_eq!(parse_num::<u32>(b"0x1_f"), Some(0x1f));
+        // (separators only go between two digits)
+        assert_eq!(parse_num::<u32>(b"1__0"), None);
+        assert_eq!(parse_num::<u32>(b"0x1__f"), None);
+        assert_eq!(parse_num::<u32>(b"0b1__0"), None);
+        assert_eq!(parse_num::<u32>(b"_1"), None);
+        assert_eq!(parse_num::<u32>(b"1_"), None);
+        assert_eq!(parse_num::<u32>(b"0x_1"), None);
+        assert_eq!(parse_num::<u32>(b"-_1"), None);
+        assert_eq!(parse_num::<u32>(b"_"), None);
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex::<u8>(b"ff"), Some(u8::MAX));
+        assert_eq!(parse_hex::<u8>(b"FF"), Some(u8::MAX));
+        assert_eq!(parse_hex::<u8>(b"100"), None);
+        assert_eq!(parse_hex::<i8>(b"-80"), Some(i8::MIN));
+        assert_eq!(parse_hex::<i8>(b"80"), None);
+        assert_eq!(parse_hex::<i64>(b"-1f"), Some(-31));
+        assert_eq!(
+            parse_hex::<u128>(b"ffffffffffffffffffffffffffffffff"),
+            Some(u128::MAX)
+        );
+
+        // (no prefixes or separators)
+        assert_eq!(parse_hex::<u32>(b"0x1f"), None);
+        assert_eq!(parse_hex::<u32>(b"1_f"), None);
+        assert_eq!(parse_hex::<u32>(b""), None);
+        assert_eq!(parse_hex::<u32>(b"-"), None);
+    }
+
+    #[test]
+    fn test_format_decimal() {
+        fn fmt<N: Num>(n: N) -> std::string::String {
+            let mut buf = [0; MAX_DECIMAL_LEN];
+            std::string::String::from_utf8(format_decimal(n, &mut buf).to_vec()).unwrap()
+        }
+
+        assert_eq!(fmt(0u8), "0");
+        assert_eq!(fmt(-7i8), "-7");
+        assert_eq!(fmt(i8::MIN), "-128");
+        assert_eq!(fmt(u16::MAX), "65535");
+        assert_eq!(fmt(isize::MIN), isize::MIN.to_string());
+        assert_eq!(fmt(i128::MIN), i128::MIN.to_string());
+        assert_eq!(fmt(u128::MAX), u128::MAX.to_string());
+    }
+
+    #[test]
+    fn test_format_hex() {
+        fn fmt<N: Num>(n: N) -> std::string::String {
+            let mut buf = [0; MAX_HEX_LEN];
+            std::string::String::from_utf8(format_hex(n, &mut buf).to_vec()).unwrap()
+        }
+
+        assert_eq!(fmt(0u8), "0");
+        assert_eq!(fmt(-42i8), "-2a");
+        assert_eq!(fmt(i8::MIN), "-80");
+        assert_eq!(fmt(u16::MAX), "ffff");
+        assert_eq!(fmt(isize::MAX), format!("{:x}", isize::MAX));
+        assert_eq!(fmt(i128::MIN), format!("-{:x}", i128::MIN.unsigned_abs()));
+        assert_eq!(fmt(u128::MAX), format!("{:x}", u128::MAX));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        fn round_trip<N: Num>(n: N) {
+            let mut buf = [0; MAX_DECIMAL_LEN];
+            assert_eq!(parse_num(format_decimal(n, &mut buf)), Some(n));
+            let mut buf = [0; MAX_HEX_LEN];
+            assert_eq!(parse_hex(format_hex(n, &mut buf)), Some(n));
+        }
+
+        for n in [i128::MIN, i128::MIN + 1, -1, 0, 1, i128::MAX] {
+            round_trip(n);
+        }
+        for n in [0, 1, u128::MAX] {
+            round_trip(n);
+        }
+        for n in [i8::MIN, i8::MAX] {
+            round_trip(n);
+        }
+    }
+
+    #[test]
+    fn test_le_bytes() {
+        let mut buf = [0; MAX_BYTES];
+        (-2i16).write_le_bytes(&mut buf);
+        assert_eq!(buf[..2], [0xfe, 0xff]);
+        assert_eq!(i16::read_le_bytes(&buf[..2]), Some(-2));
+        assert_eq!(i16::read_le_bytes(&buf[..3]), None);
+    }
+}
@@ -0,0 +1,382 @@
+//! GDB RSP-style packet framing, as an alternative to `LineReader`.
+//!
+//! Each command is sent as `$<payload>#<checksum>`, where `<checksum>` is the
+//! two-digit hex sum of the (escaped) payload bytes, modulo 256. Every packet
+//! is acknowledged with `+` (or `-` if it was corrupted, asking the client to
+//! retransmit it), until the client sends `QStartNoAckMode`.
+//!
+//! Replies are framed the same way by `PacketWriter`, which turns each line
+//! written by `response::reply` into a packet of its own, and the client acks
+//! them in turn: a `-` has the last one resent.
+
+use crate::connection::Connection;
+use crate::writer::ResponseWriter;
+
+/// Starts a packet.
+const START: u8 = b'$';
+/// Ends a packet's payload (followed by the checksum).
+const END: u8 = b'#';
+/// Escapes the following byte (XORed with `0x20`).
+const ESCAPE: u8 = b'}';
+
+/// Sent by the client to stop acknowledgements in both directions.
+const NO_ACK_MODE: &[u8] = b"QStartNoAckMode";
+/// The reply to `NO_ACK_MODE`.
+const NO_ACK_MODE_REPLY: &[u8] = b"OK";
+
+pub(crate) fn hex_digit(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+pub struct PacketReader {
+    buf: [u8; 1024],
+    len: usize,
+    pos: usize,
+    no_ack: bool,
+}
+
+impl PacketReader {
+    pub fn new() -> Self {
+        Self {
+            buf: [0; 1024],
+            len: 0,
+            pos: 0,
+            no_ack: false,
+        }
+    }
+}
+
+impl Default for PacketReader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PacketReader {
+    pub fn no_ack(&self) -> bool {
+        self.no_ack
+    }
+
+    fn next_byte<C: Connection>(&mut self, conn: &mut C) -> Option<u8> {
+        if self.pos >= self.len {
+            let n = conn.read(&mut self.buf).unwrap_or(0);
+            if n == 0 {
+                return None;
+            }
+            self.len = n;
+            self.pos = 0;
+        }
+
+        let byte = *self.buf.get(self.pos)?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    fn ack<C: Connection>(&self, conn: &mut C, ack: &[u8]) {
+        if !self.no_ack {
+            let _ = conn.write_all(ack);
+        }
+    }
+
+    /// Returns the next well-formed packet's (unescaped) payload read from
+    /// `conn`, which acks are written back to.
+    ///
+    /// Corrupted packets are `-`'d and skipped, as are any stray bytes between
+    /// packets (e.g: the client's `+` acks, or newlines), except for its `-`
+    /// nacks, which have `conn` resend the last reply (until no-ack mode).
+    /// Payloads which don't fit in `packet_buf` are treated as corrupted.
+    pub fn read_packet<'a, W: ResponseWriter + Connection, const N: usize>(
+        &'a mut self,
+        conn: &mut PacketWriter<W, N>,
+        packet_buf: &'a mut [u8],
+    ) -> Option<&'a [u8]> {
+        'packet: loop {
+            loop {
+                match self.next_byte(conn)? {
+                    START => break,
+                    b'-' if !self.no_ack => conn.resend(),
+                    _ => {}
+                }
+            }
+
+            let mut out_len = 0;
+            let mut sum: u8 = 0;
+            let mut overflow = false;
+            loop {
+                let mut byte = self.next_byte(conn)?;
+                match byte {
+                    END => break,
+                    // a new packet started before this one ended
+                    START => {
+                        self.ack(conn, b"-");
+                        self.pos -= 1;
+                        continue 'packet;
+                    }
+                    ESCAPE => {
+                        sum = sum.wrapping_add(byte);
+                        byte = self.next_byte(conn)?;
+                        sum = sum.wrapping_add(byte);
+                        byte ^= 0x20;
+                    }
+                    _ => sum = sum.wrapping_add(byte),
+                }
+                match packet_buf.get_mut(out_len) {
+                    Some(slot) => {
+                        *slot = byte;
+                        out_len += 1;
+                    }
+                    None => overflow = true,
+                }
+            }
+
+            let hi = hex_digit(self.next_byte(conn)?);
+            let lo = hex_digit(self.next_byte(conn)?);
+            let expected = hi.zip(lo).map(|(hi, lo)| (hi << 4) | lo);
+            if overflow || expected != Some(sum) {
+                self.ack(conn, b"-");
+                continue;
+            }
+            self.ack(conn, b"+");
+
+            if packet_buf.get(..out_len) == Some(NO_ACK_MODE) {
+                conn.write_line(NO_ACK_MODE_REPLY);
+                self.no_ack = true;
+                continue;
+            }
+
+            return packet_buf.get(..out_len);
+        }
+    }
+}
+
+/// Frames every line written to it as a packet (`$<payload>#<checksum>`, the
+/// `\n` itself being left out), escaping payload bytes the same way
+/// `PacketReader` unescapes them.
+///
+/// Anything written to it as a `Connection` (i.e: `PacketReader`'s acks) goes
+/// straight through, unframed.
+///
+/// The last packet is kept (in an `N`-byte buffer), to be resent if the client
+/// nacks it.
+pub struct PacketWriter<W, const N: usize = 1024> {
+    inner: W,
+    /// Whether a packet has been started, but not ended yet.
+    in_packet: bool,
+    /// The checksum of the packet's payload so far.
+    sum: u8,
+    /// The last packet, as framed.
+    last: [u8; N],
+    last_len: usize,
+    /// Whether the last packet didn't fit in `last` (so can't be resent).
+    last_overlong: bool,
+}
+
+impl<W: ResponseWriter, const N: usize> PacketWriter<W, N> {
+    pub fn new(inner: W) -> PacketWriter<W, N> {
+        PacketWriter {
+            inner,
+            in_packet: false,
+            sum: 0,
+            last: [0; N],
+            last_len: 0,
+            last_overlong: false,
+        }
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Resends the last (complete) packet, if any.
+    pub fn resend(&mut self) {
+        if !self.in_packet && !self.last_overlong {
+            self.inner
+                .write(self.last.get(..self.last_len).unwrap_or_default());
+        }
+    }
+
+    /// Writes part of a packet, keeping it in `last`.
+    fn write_framed(&mut self, bytes: &[u8]) {
+        self.inner.write(bytes);
+        let start = self.last_len;
+        match self.last.get_mut(start..start + bytes.len()) {
+            Some(slot) => {
+                slot.copy_from_slice(bytes);
+                self.last_len += bytes.len();
+            }
+            None => self.last_overlong = true,
+        }
+    }
+
+    /// Writes part of a payload, which has no bytes to escape.
+    fn write_payload(&mut self, bytes: &[u8]) {
+        if !core::mem::replace(&mut self.in_packet, true) {
+            self.last_len = 0;
+            self.last_overlong = false;
+            self.write_framed(&[START]);
+            self.sum = 0;
+        }
+        self.write_framed(bytes);
+        self.sum = bytes.iter().fold(self.sum, |sum, &b| sum.wrapping_add(b));
+    }
+
+    fn end_packet(&mut self) {
+        // (an empty reply still gets a packet of its own)
+        self.write_payload(&[]);
+        let hex = b"0123456789abcdef";
+        let sum = self.sum as usize;
+        self.write_framed(&[END, hex[sum >> 4], hex[sum & 0xf]]);
+        self.in_packet = false;
+    }
+}
+
+impl<W: ResponseWriter, const N: usize> ResponseWriter for PacketWriter<W, N> {
+    fn write(&mut self, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            // (`*` isn't special to `PacketReader`, but is to GDB, which reads
+            // it as run-length encoding)
+            let plain = bytes
+                .iter()
+                .position(|&b| matches!(b, b'\n' | START | END | ESCAPE | b'*'))
+                .unwrap_or(bytes.len());
+            let (run, rest) = bytes.split_at(plain);
+            if !run.is_empty() {
+                self.write_payload(run);
+            }
+            bytes = match rest.split_first() {
+                Some((b'\n', rest)) => {
+                    self.end_packet();
+                    rest
+                }
+                Some((&b, rest)) => {
+                    self.write_payload(&[ESCAPE, b ^ 0x20]);
+                    rest
+                }
+                None => &[],
+            };
+        }
+    }
+
+    fn end_batch(&mut self) {
+        self.inner.end_batch();
+    }
+}
+
+impl<W: ResponseWriter + Connection, const N: usize> Connection for PacketWriter<W, N> {
+    type Error = W::Error;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, W::Error> {
+        self.inner.read(buf)
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), W::Error> {
+        self.inner.write_all(bytes)
+    }
+
+    fn flush(&mut self) -> Result<(), W::Error> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::connection::Memory;
+    use crate::writer::Buffered;
+    use crate::writer::Capture;
+
+    /// Runs `input` through a `PacketReader`, after `reply` has been sent,
+    /// returning every packet it read, alongside everything written back.
+    fn read_replied(reply: &[u8], input: &[u8]) -> (Vec<Vec<u8>>, Vec<u8>) {
+        let mut acks = [0u8; 64];
+        let mut conn =
+            PacketWriter::<_, 16>::new(Buffered::<_>::new(Memory::new(input, &mut acks)));
+        conn.write(reply);
+        let mut reader = PacketReader::new();
+        let mut packet_buf = [0u8; 16];
+        let mut packets = Vec::new();
+        while let Some(packet) = reader.read_packet(&mut conn, &mut packet_buf) {
+            packets.push(packet.to_vec());
+        }
+        (packets, conn.get_ref().get_ref().output().to_vec())
+    }
+
+    /// Runs `input` through a `PacketReader`, returning every packet it read,
+    /// alongside the acks it sent.
+    fn read_all(input: &[u8]) -> (Vec<Vec<u8>>, Vec<u8>) {
+        read_replied(b"", input)
+    }
+
+    #[test]
+    fn test_read_packet() {
+        // stray bytes between packets are skipped
+        let (packets, acks) = read_all(b"$p#70\n+$s 5#c8+-$+-#58");
+        assert_eq!(packets, [&b"p"[..], b"s 5", b"+-"]);
+        assert_eq!(acks, b"+++");
+
+        // checksums are case-insensitive, and cover the escaped payload
+        let (packets, acks) = read_all(b"$*~ 2#FA$}]#da");
+        assert_eq!(packets, [&b"*~ 2"[..], b"}"]);
+        assert_eq!(acks, b"++");
+    }
+
+    #[test]
+    fn test_corrupted_packets() {
+        // bad checksum, non-hex checksum, restarted packet, overlong payload
+        let (packets, acks) = read_all(b"$p#71$p#zz$p$p#70$0123456789abcdefg#00$p#70");
+        assert_eq!(packets, [&b"p"[..], b"p"]);
+        assert_eq!(acks, b"---+-+");
+    }
+
+    #[test]
+    fn test_no_ack_mode() {
+        let (packets, acks) = read_all(b"$p#70$QStartNoAckMode#b0$p#70$p#71");
+        assert_eq!(packets, [&b"p"[..], b"p"]);
+        assert_eq!(acks, b"++$OK#9a");
+    }
+
+    #[test]
+    fn test_resend() {
+        // the last reply is resent for every `-`...
+        let (packets, out) = read_replied(b"42\n", b"-$p#70-+-");
+        assert_eq!(packets, [b"p"]);
+        assert_eq!(out, b"$42#66$42#66+$42#66$42#66");
+
+        // ...(with only the last packet of a multi-line reply resent)...
+        let (_, out) = read_replied(b"E 02\nr\n", b"-");
+        assert_eq!(out, b"$E 02#c7$r#72$r#72");
+
+        // ...unless it was too long to keep
+        let (_, out) = read_replied(b"0123456789abcdef\n", b"-");
+        assert_eq!(out, b"$0123456789abcdef#62");
+
+        // ...or acks have been turned off
+        let (_, out) = read_replied(b"", b"$QStartNoAckMode#b0-");
+        assert_eq!(out, b"+$OK#9a");
+    }
+
+    #[test]
+    fn test_write_packets() {
+        let mut out = PacketWriter::<_>::new(Capture::<128>::new());
+        out.write_line(b"OK");
+        // (replies may be written in pieces)
+        out.write(b"4");
+        out.write(b"2\n");
+        out.write_line(b"");
+        out.write_line(b"$#}*");
+        let framed = out.get_ref().output();
+        assert_eq!(framed, b"$OK#9a$42#66$#00$}\x04}\x03}]}\x0a#62");
+
+        // ...and read back as they were written
+        let (packets, acks) = read_all(framed);
+        assert_eq!(packets, [&b"OK"[..], b"42", b"", b"$#}*"]);
+        assert_eq!(acks, b"++++");
+    }
+}
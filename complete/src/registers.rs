@@ -0,0 +1,213 @@
+//! Bulk register transfers (`g` / `G`).
+//!
+//! As in gdb's RSP, the whole register file is sent as a single hex string:
+//! every register's bytes in little-endian order, two hex digits per byte.
+
+use crate::num::MAX_BYTES;
+use crate::num::Num;
+use crate::packet_reader::hex_digit;
+use crate::writer::ResponseWriter;
+
+/// The most registers any target may expose via `Target::num_regs`.
+pub const MAX_REGS: usize = 8;
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+/// A snapshot of a target's register file (shared by every strategy), where
+/// each register is `S::BYTES` wide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Registers<S> {
+    vals: [S; MAX_REGS],
+    len: usize,
+}
+
+impl<S: Num> Default for Registers<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Num> Registers<S> {
+    /// Hex digits per register.
+    const DIGITS: usize = S::BYTES * 2;
+
+    pub const fn new() -> Registers<S> {
+        Registers {
+            vals: [S::ZERO; MAX_REGS],
+            len: 0,
+        }
+    }
+
+    /// Appends the next register's value (ignored once `MAX_REGS` is reached).
+    pub fn push(&mut self, val: S) {
+        if let Some(slot) = self.vals.get_mut(self.len) {
+            *slot = val;
+            self.len += 1;
+        }
+    }
+
+    pub fn as_slice(&self) -> &[S] {
+        self.vals.get(..self.len).unwrap_or_default()
+    }
+
+    /// Writes every register through `write_reg` (i.e: for `G`).
+    ///
+    /// If any write fails, the registers written so far are written back to
+    /// their values in `prev` before returning the error, so that a failed `G`
+    /// leaves the register file as it was.
+    pub fn write_all<E>(
+        &self,
+        prev: &Registers<S>,
+        mut write_reg: impl FnMut(usize, S) -> Result<(), E>,
+    ) -> Result<(), E> {
+        for (idx, &val) in self.as_slice().iter().enumerate() {
+            if let Err(err) = write_reg(idx, val) {
+                for (idx, &val) in prev.as_slice().iter().enumerate().take(idx) {
+                    let _ = write_reg(idx, val);
+                }
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses the hex encoding of exactly `num_regs` registers.
+    pub fn from_hex(hex: &[u8], num_regs: usize) -> Option<Registers<S>> {
+        if num_regs > MAX_REGS || hex.len() != num_regs * Self::DIGITS {
+            return None;
+        }
+
+        let mut regs = Registers::new();
+        for digits in hex.chunks_exact(Self::DIGITS) {
+            let mut bytes = [0u8; MAX_BYTES];
+            for (byte, pair) in bytes.iter_mut().zip(digits.chunks_exact(2)) {
+                *byte = (hex_digit(pair[0])? << 4) | hex_digit(pair[1])?;
+            }
+            regs.push(S::read_le_bytes(&bytes[..S::BYTES])?);
+        }
+        Some(regs)
+    }
+
+    /// Writes every register as a single line of hex.
+    pub fn write_to<W: ResponseWriter>(&self, out: &mut W) {
+        let mut buf = [0u8; MAX_REGS * MAX_BYTES * 2];
+        let mut len = 0;
+        for val in self.as_slice() {
+            let mut bytes = [0u8; MAX_BYTES];
+            val.write_le_bytes(&mut bytes);
+            for &byte in &bytes[..S::BYTES] {
+                buf[len] = HEX[(byte >> 4) as usize];
+                buf[len + 1] = HEX[(byte & 0xf) as usize];
+                len += 2;
+            }
+        }
+        out.write_line(&buf[..len]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::Capture;
+
+    #[test]
+    fn test_from_hex() {
+        let hex = b"0500000000000000ffffffffffffffff";
+        let regs = Registers::<i64>::from_hex(hex, 2).unwrap();
+        assert_eq!(regs.as_slice(), [5, -1]);
+
+        // case-insensitive
+        let regs = Registers::<i64>::from_hex(b"FEFFFFFFFFFFFFFF", 1).unwrap();
+        assert_eq!(regs.as_slice(), [-2]);
+
+        // the width is the state's
+        let regs = Registers::<u16>::from_hex(hex, 8).unwrap();
+        assert_eq!(
+            regs.as_slice(),
+            [5, 0, 0, 0, 0xffff, 0xffff, 0xffff, 0xffff]
+        );
+        let regs = Registers::<i128>::from_hex(hex, 1).unwrap();
+        assert_eq!(regs.as_slice(), [-(1 << 64) + 5]);
+
+        // wrong length for the register count, or not hex at all
+        assert_eq!(Registers::<i64>::from_hex(hex, 1), None);
+        assert_eq!(Registers::<i64>::from_hex(&hex[1..], 2), None);
+        assert_eq!(Registers::<i64>::from_hex(b"zz00000000000000", 1), None);
+        assert_eq!(Registers::<i64>::from_hex(b"", MAX_REGS + 1), None);
+        assert_eq!(Registers::<i64>::from_hex(b"", 0), Some(Registers::new()));
+    }
+
+    #[test]
+    fn test_write_to() {
+        let mut regs = Registers::<i64>::new();
+        for val in [3, -2, 0, 0, 0, 0, 0, 7] {
+            regs.push(val);
+        }
+        let mut out = Capture::<256>::new();
+        regs.write_to(&mut out);
+        let (hex, newline) = out.output().split_at(out.output().len() - 1);
+        assert_eq!(newline, b"\n");
+        assert_eq!(&hex[..32], b"0300000000000000feffffffffffffff");
+        assert_eq!(&hex[hex.len() - 16..], b"0700000000000000");
+
+        // (what `g` sends, `G` takes back)
+        assert_eq!(Registers::<i64>::from_hex(hex, 8), Some(regs));
+        assert_eq!(Registers::<i64>::from_hex(&hex[..16], 8), None);
+    }
+
+    #[test]
+    fn test_push() {
+        let mut regs = Registers::new();
+        for val in 0..MAX_REGS as u8 + 2 {
+            regs.push(val);
+        }
+        assert_eq!(regs.as_slice().len(), MAX_REGS);
+        assert_eq!(regs.as_slice()[MAX_REGS - 1], MAX_REGS as u8 - 1);
+    }
+
+    #[test]
+    fn test_write_all() {
+        let prev = Registers::<u32>::from_hex(b"0a0000000b0000000c0000000d000000", 4).unwrap();
+        let regs = Registers::<u32>::from_hex(b"01000000020000000300000004000000", 4).unwrap();
+
+        // every write goes through
+        let mut file = [10, 11, 12, 13];
+        let res: Result<(), ()> = regs.write_all(&prev, |idx, val| {
+            file[idx] = val;
+            Ok(())
+        });
+        assert_eq!((res, file), (Ok(()), [1, 2, 3, 4]));
+
+        // `r2` is read-only: `r0` and `r1` are put back
+        let mut file = [10, 11, 12, 13];
+        let res = regs.write_all(&prev, |idx, val| {
+            if idx == 2 {
+                return Err("read-only");
+            }
+            file[idx] = val;
+            Ok(())
+        });
+        assert_eq!((res, file), (Err("read-only"), [10, 11, 12, 13]));
+
+        // a failure on the last register puts back every other one, in order,
+        // and doesn't retry the one which failed
+        let prev = Registers::<i8>::from_hex(b"0102030405060708", 8).unwrap();
+        let regs = Registers::<i8>::from_hex(b"fffefdfcfbfaf9f8", 8).unwrap();
+        let mut file = [1, 2, 3, 4, 5, 6, 7, 8];
+        let mut writes = Vec::new();
+        let res = regs.write_all(&prev, |idx, val| {
+            writes.push((idx, val));
+            if idx == 7 {
+                return Err("read-only");
+            }
+            file[idx] = val;
+            Ok(())
+        });
+        assert_eq!((res, file), (Err("read-only"), [1, 2, 3, 4, 5, 6, 7, 8]));
+        assert_eq!(writes.len(), 8 + 7);
+        assert_eq!(
+            writes[8..],
+            (0..7).map(|idx| (idx, idx as i8 + 1)).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! An interactive mode (see the binary's `--repl`), for a human at a terminal
+//! rather than a client.
+//!
+//! Every line is shown a prompt, may spell its verb out (e.g: `mul 3` for
+//! `* 3`), and `help` lists the commands the target supports. Anything else is
+//! passed to the controller as is, so the line protocol itself is unchanged.
+//!
+//! The binary only offers it with the `repl` feature: otherwise, nothing here
+//! is reachable from `main`, and the help text doesn't take up space in it.
+
+use crate::capabilities::Capabilities;
+use crate::commands::ParseError;
+use crate::writer::ResponseWriter;
+
+pub const PROMPT: &[u8] = b"> ";
+
+/// Every long alias, alongside the verb it stands for.
+const ALIASES: [(&[u8], &[u8]); 6] = [
+    (b"inc", b"+"),
+    (b"dec", b"-"),
+    (b"mul", b"*"),
+    (b"scale", b"*~"),
+    (b"print", b"p"),
+    (b"set", b"s"),
+];
+
+/// Every command, alongside the extension it belongs to (`NONE` for the base
+/// protocol).
+const HELP: [(Capabilities, &str); 17] = [
+    (Capabilities::NONE, "print, p            print the state"),
+    (Capabilities::NONE, "set <n>, s <n>      set the state"),
+    (
+        Capabilities::INCDEC,
+        "inc, +              increment the state",
+    ),
+    (
+        Capabilities::INCDEC,
+        "dec, -              decrement the state",
+    ),
+    (
+        Capabilities::INCDEC,
+        "+-                  increment, then decrement the state",
+    ),
+    (Capabilities::MUL, "mul <n>, * <n>      multiply the state"),
+    (
+        Capabilities::SCALE_FACTOR,
+        "scale <n>, *~ <n>   set the scale factor",
+    ),
+    (
+        Capabilities::SCALE_BOUNDS,
+        "*~~ <min> <max>     bound the scale factor",
+    ),
+    (Capabilities::DIV, "/ <n>               divide the state"),
+    (
+        Capabilities::DIV,
+        "% <n>               replace the state with its remainder",
+    ),
+    (
+        Capabilities::UNDO,
+        "u [<n>]             undo the last n (or 1) changes",
+    ),
+    (Capabilities::NONE, "r <idx>             read a register"),
+    (Capabilities::NONE, "w <idx> <n>         write a register"),
+    (
+        Capabilities::NONE,
+        "g, G <hex>          read / write every register",
+    ),
+    (
+        Capabilities::NONE,
+        "? [<names>]         list the supported extensions",
+    ),
+    (
+        Capabilities::NONE,
+        "help                list the supported commands",
+    ),
+    (
+        Capabilities::NONE,
+        "(numbers may be prefixed with 0x / 0o / 0b, and contain _ between digits)",
+    ),
+];
+
+/// A line whose alias (if any) has been replaced by its verb.
+#[derive(Clone, Copy, Debug)]
+pub struct Expanded<'a> {
+    pub line: &'a [u8],
+    /// The length of the verb in `line`.
+    verb_len: usize,
+    /// How much shorter the verb is than its alias.
+    shift: usize,
+}
+
+impl Expanded<'_> {
+    /// Points `err` (which was found in `line`) back at the line the user
+    /// actually typed.
+    pub fn locate(&self, err: ParseError) -> ParseError {
+        match err.offset < self.verb_len {
+            true => err,
+            false => ParseError {
+                offset: err.offset + self.shift,
+                ..err
+            },
+        }
+    }
+}
+
+/// Replaces the long alias `line` starts with (e.g: `mul 3`) with its verb
+/// (`* 3`), using `buf` for the new line.
+///
+/// Lines without an alias (or which don't fit in `buf`) are left alone.
+pub fn expand<'a>(line: &'a [u8], buf: &'a mut [u8]) -> Expanded<'a> {
+    let alias = line.split(|&b| b == b' ').next().unwrap_or(line);
+    let unchanged = Expanded {
+        line,
+        verb_len: 0,
+        shift: 0,
+    };
+    let Some((_, verb)) = ALIASES.into_iter().find(|(name, _)| *name == alias) else {
+        return unchanged;
+    };
+    let args = &line[alias.len()..];
+    let Some(expanded) = buf.get_mut(..verb.len() + args.len()) else {
+        return unchanged;
+    };
+    let (head, tail) = expanded.split_at_mut(verb.len());
+    head.copy_from_slice(verb);
+    tail.copy_from_slice(args);
+    Expanded {
+        line: expanded,
+        verb_len: verb.len(),
+        shift: alias.len() - verb.len(),
+    }
+}
+
+/// Whether `line` asks for `help`.
+pub fn is_help(line: &[u8]) -> bool {
+    line == b"help"
+}
+
+/// Writes one line per command supported by a target with `caps` (see
+/// `TargetController::capabilities`).
+pub fn write_help<W: ResponseWriter>(caps: Capabilities, out: &mut W) {
+    for (cap, line) in HELP {
+        if caps.contains(cap) {
+            out.write_line(line.as_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::ParseErrorKind;
+    use crate::writer::Capture;
+
+    fn expand_to_vec(line: &[u8]) -> Vec<u8> {
+        let mut buf = [0u8; 16];
+        expand(line, &mut buf).line.to_vec()
+    }
+
+    #[test]
+    fn test_expand() {
+        assert_eq!(expand_to_vec(b"inc"), b"+");
+        assert_eq!(expand_to_vec(b"dec"), b"-");
+        assert_eq!(expand_to_vec(b"mul 3"), b"* 3");
+        assert_eq!(expand_to_vec(b"scale 2"), b"*~ 2");
+        assert_eq!(expand_to_vec(b"print"), b"p");
+        assert_eq!(expand_to_vec(b"set 0x10"), b"s 0x10");
+        // (trailing garbage is left for the controller to reject)
+        assert_eq!(expand_to_vec(b"inc 1"), b"+ 1");
+
+        assert_eq!(expand_to_vec(b"* 3"), b"* 3");
+        assert_eq!(expand_to_vec(b"setx 5"), b"setx 5");
+        assert_eq!(expand_to_vec(b"help"), b"help");
+        assert_eq!(expand_to_vec(b""), b"");
+        let mut buf = [0u8; 4];
+        assert_eq!(expand(b"set 12345", &mut buf).line, b"set 12345");
+    }
+
+    #[test]
+    fn test_locate() {
+        let err = |kind, offset| ParseError { kind, offset };
+        let mut buf = [0u8; 16];
+        let expanded = expand(b"scale x", &mut buf);
+        assert_eq!(
+            expanded.locate(err(ParseErrorKind::BadNumber, 3)),
+            err(ParseErrorKind::BadNumber, 6)
+        );
+        assert_eq!(
+            expanded.locate(err(ParseErrorKind::Unsupported, 0)),
+            err(ParseErrorKind::Unsupported, 0)
+        );
+
+        let expanded = expand(b"s x", &mut buf);
+        assert_eq!(
+            expanded.locate(err(ParseErrorKind::BadNumber, 2)),
+            err(ParseErrorKind::BadNumber, 2)
+        );
+    }
+
+    #[test]
+    fn test_help() {
+        let mut out = Capture::<2048>::new();
+        write_help(Capabilities::NONE, &mut out);
+        let basic = String::from_utf8(out.output().to_vec()).unwrap();
+        assert!(basic.lines().any(|line| line.starts_with("set <n>, s <n>")));
+        assert!(basic.lines().any(|line| line.starts_with("help")));
+        assert!(!basic.contains("inc"));
+        assert!(!basic.contains("mul"));
+
+        out.clear();
+        write_help(Capabilities::INCDEC, &mut out);
+        let faulty = String::from_utf8(out.output().to_vec()).unwrap();
+        assert!(faulty.contains("inc, +"));
+        assert!(faulty.contains("dec, -"));
+        assert!(!faulty.contains("mul"));
+
+        out.clear();
+        write_help(Capabilities::ALL, &mut out);
+        assert!(!out.is_truncated());
+        assert_eq!(out.output().split(|&b| b == b'\n').count() - 1, HELP.len());
+    }
+}
@@ -0,0 +1,106 @@
+use crate::capabilities::Capabilities;
+use crate::error::Error;
+use crate::num::Num;
+use crate::registers::Registers;
+use crate::writer::ResponseWriter;
+
+/// A successful reply to a command (shared by every strategy).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Response<S> {
+    /// The command was carried out.
+    Ok,
+    /// The command produced a value (e.g: `PrintState`).
+    Value(S),
+    /// The (negotiated) extensions supported by the target.
+    Supported(Capabilities),
+    /// The target's whole register file (e.g: `ReadRegs`).
+    Registers(Registers<S>),
+}
+
+/// Writes the reply to a command to `out`, and passes `res` through.
+///
+/// As in RSP, every command gets exactly one reply: `OK`, a value, `E NN` if
+/// it failed, or an empty line if it isn't supported. Commands which couldn't
+/// be parsed get a GDB-style `E.<message>` instead (e.g: `E.bad number at 2`).
+pub fn reply<S: Num, E, W: ResponseWriter>(
+    out: &mut W,
+    res: Result<Response<S>, Error<E>>,
+) -> Result<Response<S>, Error<E>> {
+    match &res {
+        Ok(Response::Ok) => out.write_line(b"OK"),
+        Ok(Response::Value(n)) => {
+            out.write_num(*n);
+            out.write(b"\n");
+        }
+        Ok(Response::Supported(caps)) => caps.write_to(out),
+        Ok(Response::Registers(regs)) => regs.write_to(out),
+        Err(e) => e.write_reply(out),
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LineTooLong;
+    use crate::commands::ParseError;
+    use crate::commands::ParseErrorKind;
+    use crate::writer::Capture;
+
+    /// What `reply` writes for `res` (which it should pass through).
+    fn replied(res: Result<Response<i16>, Error<&'static str>>) -> String {
+        let mut out = Capture::<64>::new();
+        assert_eq!(reply(&mut out, res), res);
+        String::from_utf8(out.output().to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_replies() {
+        assert_eq!(replied(Ok(Response::Ok)), "OK\n");
+        assert_eq!(replied(Ok(Response::Value(-42))), "-42\n");
+        assert_eq!(
+            replied(Ok(Response::Supported(
+                Capabilities::INCDEC.union(Capabilities::MUL)
+            ))),
+            "incdec;mul\n"
+        );
+        let mut regs = Registers::new();
+        regs.push(0x2a);
+        regs.push(-2);
+        assert_eq!(replied(Ok(Response::Registers(regs))), "2a00feff\n");
+    }
+
+    #[test]
+    fn test_error_replies() {
+        assert_eq!(replied(Err(Error::NonFatal("oops"))), "E 01\n");
+        assert_eq!(replied(Err(Error::Fatal("oops"))), "E 02\n");
+        assert_eq!(replied(Err(Error::InvalidImpl)), "E 05\n");
+        // (as in RSP, an unsupported command gets an empty reply, whether it's
+        // rejected while parsing or while handling it)
+        assert_eq!(replied(Err(Error::Unsupported)), "\n");
+        let unsupported = ParseError {
+            kind: ParseErrorKind::Unsupported,
+            offset: 0,
+        };
+        assert_eq!(replied(Err(unsupported.into())), "\n");
+
+        // parse errors are spelled out instead
+        let unknown = ParseError {
+            kind: ParseErrorKind::UnknownVerb,
+            offset: 0,
+        };
+        assert_eq!(replied(Err(unknown.into())), "E.unknown command at 0\n");
+        let bad_number = ParseError {
+            kind: ParseErrorKind::BadNumber,
+            offset: 2,
+        };
+        assert_eq!(replied(Err(bad_number.into())), "E.bad number at 2\n");
+        let garbage = ParseError {
+            kind: ParseErrorKind::TrailingGarbage,
+            offset: 1,
+        };
+        assert_eq!(replied(Err(garbage.into())), "E.trailing garbage at 1\n");
+        let too_long = ParseError::from(LineTooLong { max_len: 16 });
+        assert_eq!(replied(Err(too_long.into())), "E.line too long at 16\n");
+    }
+}
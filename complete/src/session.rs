@@ -0,0 +1,168 @@
+//! The blocking loop the binary serves a client with (see `state_machine` for
+//! a push-based alternative).
+//!
+//! Commands are read a line (or, with `transport_rsp`, a packet) at a time,
+//! handed to the controller, and replied to, until the client hangs up or a
+//! command ends the session.
+
+use crate::commands::ParseError;
+use crate::connection::Connection;
+use crate::error::Error;
+use crate::repl;
+use crate::response;
+use crate::state_machine::Controller;
+use crate::writer;
+use crate::writer::ResponseWriter;
+
+/// How `serve` treats the client's commands (see the binary's flags).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Echo every command before its reply.
+    pub echo: bool,
+    /// Show a prompt, and accept `repl`'s aliases and `help` (ignored without
+    /// the `repl` feature).
+    pub repl: bool,
+    /// End the session on the first failed command, not only on fatal errors.
+    pub exit_on_error: bool,
+}
+
+/// Serves the client on `conn` with `controller`, until the client hangs up
+/// (`Ok`), or a command fails in a way that ends the session (`Err`).
+///
+/// A fatal error (or `InvalidImpl`) is spelled out to the client after its
+/// reply, as the session ends right after.
+pub fn serve<Ctl, C>(conn: C, controller: &mut Ctl, opts: &Options) -> Result<(), Error<Ctl::Error>>
+where
+    Ctl: Controller,
+    Ctl::Error: AsRef<str>,
+    C: Connection,
+{
+    // (replies are buffered until the client's next batch of commands has to
+    // be read)
+    let conn = core::cfg_select! {
+        feature = "bench" => writer::CountingSink::new(conn),
+        _ => writer::Buffered::<C>::new(conn),
+    };
+    // (with RSP framing, replies are sent as packets too)
+    let mut conn = core::cfg_select! {
+        feature = "transport_rsp" => crate::PacketWriter::<_>::new(conn),
+        _ => conn,
+    };
+    let conn = &mut conn;
+
+    let mut reader = core::cfg_select! {
+        feature = "transport_rsp" => crate::PacketReader::new(),
+        _ => crate::LineReader::new(),
+    };
+    let mut line_buf = [0u8; 512];
+    // (for lines using `repl`'s aliases)
+    let mut alias_buf = [0u8; 512];
+    // (a constant `false` without the `repl` feature, so none of `repl`'s
+    // aliases or help text end up in the binary)
+    let repl = cfg!(feature = "repl") && opts.repl;
+    let mut ended = Ok(());
+    loop {
+        if repl {
+            conn.write(repl::PROMPT);
+        }
+        let line = core::cfg_select! {
+            // (overlong packets are treated as corrupted, and never get this far)
+            feature = "transport_rsp" => {
+                reader.read_packet(conn, &mut line_buf).map(Ok::<_, crate::LineTooLong>)
+            }
+            _ => reader.read_line(conn, &mut line_buf),
+        };
+        let Some(line) = line else {
+            break;
+        };
+        if opts.echo
+            && let Ok(line @ [_, ..]) = line
+        {
+            conn.write_line(line);
+        }
+        let res = match line {
+            Ok([]) => continue,
+            Ok(line) if repl && repl::is_help(line) => {
+                repl::write_help(controller.capabilities(), conn);
+                continue;
+            }
+            Ok(line) if repl => {
+                let line = repl::expand(line, &mut alias_buf);
+                match controller.parse_command(line.line) {
+                    Ok(cmd) => controller.handle(conn, &cmd),
+                    Err(err) => response::reply(conn, Err(line.locate(err).into())),
+                }
+            }
+            Ok(line) => match controller.parse_command(line) {
+                Ok(cmd) => controller.handle(conn, &cmd),
+                Err(err) => response::reply(conn, Err(err.into())),
+            },
+            Err(err) => response::reply(conn, Err(ParseError::from(err).into())),
+        };
+
+        // (`handle` has already replied)
+        match res {
+            Err(e) if e.is_fatal() || opts.exit_on_error => {
+                match &e {
+                    Error::Fatal(e) => conn.write_line(e.as_ref().as_bytes()),
+                    Error::InvalidImpl => conn.write_line(b"Invalid implementation!"),
+                    _ => {}
+                }
+                ended = Err(e);
+                break;
+            }
+            _ => {}
+        }
+    }
+    conn.end_batch();
+
+    ended
+}
+
+// (the replies are checked as sent over a plain line transport)
+#[cfg(all(test, not(any(feature = "bench", feature = "transport_rsp"))))]
+mod tests {
+    use super::*;
+    use crate::connection::Memory;
+    use crate::using_traits::controller::TargetController;
+    use crate::using_traits::targets::AdvancedTarget;
+    use crate::using_traits::targets::FaultyTarget;
+
+    const INPUT: &[u8] = b"s 5\n+\n\nfrob\np\n";
+
+    #[test]
+    fn test_serve() {
+        let mut output = [0u8; 256];
+        let mut conn = Memory::new(INPUT, &mut output);
+        let mut controller = TargetController::new(AdvancedTarget::new(0));
+        let opts = Options::default();
+        assert_eq!(serve(&mut conn, &mut controller, &opts), Ok(()));
+        assert_eq!(conn.output(), b"OK\nOK\nE.unknown command at 0\n6\n");
+
+        let mut output = [0u8; 256];
+        let mut conn = Memory::new(INPUT, &mut output);
+        let mut controller = TargetController::new(AdvancedTarget::new(0));
+        let opts = Options {
+            echo: true,
+            exit_on_error: true,
+            ..Options::default()
+        };
+        assert!(serve(&mut conn, &mut controller, &opts).is_err_and(|e| !e.is_fatal()));
+        assert_eq!(
+            conn.output(),
+            b"s 5\nOK\n+\nOK\nfrob\nE.unknown command at 0\n"
+        );
+    }
+
+    #[test]
+    fn test_fatal() {
+        let mut output = [0u8; 256];
+        let mut conn = Memory::new(b"s 5\nw 3 1\np\n", &mut output);
+        let mut controller = TargetController::new(FaultyTarget::new(0));
+        assert_eq!(
+            serve(&mut conn, &mut controller, &Options::default()),
+            Err(Error::Fatal("`r3` is read-only"))
+        );
+        assert_eq!(conn.output(), b"OK\nE 02\n`r3` is read-only\n");
+    }
+}
@@ -0,0 +1,304 @@
+//! A push-based alternative to the blocking loop in `session::serve` (as in
+//! gdbstub's `GdbStubStateMachine`).
+//!
+//! Instead of pulling lines out of a `Connection`, the caller hands the bytes
+//! over as they arrive, one at a time or in chunks (e.g: from an interrupt
+//! handler, a poll loop, or an async task), and the state machine assembles it
+//! into lines, and has the controller handle every complete one. It never
+//! blocks, so when (and how) the next byte shows up is entirely up to the
+//! caller.
+
+use crate::LineTooLong;
+use crate::capabilities::Capabilities;
+use crate::commands::ParseError;
+use crate::error::Error;
+use crate::num::Num;
+use crate::response;
+use crate::response::Response;
+use crate::writer::ResponseWriter;
+
+/// The part of a `TargetController` the state machine (and `session::serve`)
+/// drives (implemented by every strategy's controller).
+pub trait Controller {
+    type State: Num;
+    type Error;
+    /// What `parse_command` parses a line into (`commands::Command`, except for
+    /// `using_cfg_gates`).
+    type Command;
+
+    /// The extensions supported by the target (i.e: what `?` reports).
+    fn capabilities(&mut self) -> Capabilities;
+    fn parse_command(&mut self, line: &[u8]) -> Result<Self::Command, ParseError>;
+    fn handle<W: ResponseWriter>(
+        &mut self,
+        out: &mut W,
+        cmd: &Self::Command,
+    ) -> Result<Response<Self::State>, Error<Self::Error>>;
+}
+
+/// What became of the bytes fed to `ControllerStateMachine::feed` /
+/// `feed_chunk`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event<S, E> {
+    /// The bytes completed a command, which has been replied to.
+    Replied(Result<Response<S>, Error<E>>),
+    /// The bytes completed a command which failed fatally (see
+    /// `Error::is_fatal`): it has been replied to, but the session is over,
+    /// and any bytes fed from now on are ignored.
+    Stopped(Error<E>),
+}
+
+/// The `Event` a `ControllerStateMachine` over `Ctl` reports.
+pub type CtlEvent<Ctl> = Event<<Ctl as Controller>::State, <Ctl as Controller>::Error>;
+
+/// Assembles commands a byte (or a chunk) at a time, in an `N`-byte line
+/// buffer, and has `Ctl` handle them, replying through `W`.
+///
+/// Lines are framed as by `LineReader`: empty lines are skipped, and lines
+/// which don't fit are discarded whole, and reported as `LineTooLong`.
+pub struct ControllerStateMachine<Ctl, W, const N: usize = 512> {
+    controller: Ctl,
+    out: W,
+    line_buf: [u8; N],
+    len: usize,
+    overlong: bool,
+    stopped: bool,
+}
+
+impl<Ctl: Controller, W: ResponseWriter, const N: usize> ControllerStateMachine<Ctl, W, N> {
+    pub fn new(controller: Ctl, out: W) -> ControllerStateMachine<Ctl, W, N> {
+        ControllerStateMachine {
+            controller,
+            out,
+            line_buf: [0; N],
+            len: 0,
+            overlong: false,
+            stopped: false,
+        }
+    }
+
+    pub fn controller(&self) -> &Ctl {
+        &self.controller
+    }
+
+    /// Where replies are written (e.g: to `end_batch` it once the caller has
+    /// run out of bytes for now).
+    pub fn writer(&mut self) -> &mut W {
+        &mut self.out
+    }
+
+    /// Whether a fatal error has ended the session (see `Event::Stopped`).
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    pub fn into_parts(self) -> (Ctl, W) {
+        (self.controller, self.out)
+    }
+
+    /// Feeds the next byte received from the client, returning what happened
+    /// if it completed a (non-empty) line.
+    pub fn feed(&mut self, byte: u8) -> Option<CtlEvent<Ctl>> {
+        self.feed_chunk(&[byte]).1
+    }
+
+    /// Feeds the next bytes received from the client, up to and including the
+    /// first newline: returns how many of them were consumed, along with what
+    /// happened if they completed a (non-empty) line.
+    ///
+    /// The rest of `chunk` is left for the next call, so that the caller gets
+    /// to see every event (and can stop once the session is over).
+    pub fn feed_chunk(&mut self, chunk: &[u8]) -> (usize, Option<CtlEvent<Ctl>>) {
+        if self.stopped {
+            return (chunk.len(), None);
+        }
+        let (bytes, consumed) = match chunk.iter().position(|&b| b == b'\n') {
+            Some(pos) => (&chunk[..pos], pos + 1),
+            None => (chunk, chunk.len()),
+        };
+        // (whatever doesn't fit is dropped, and the line reported as too long)
+        let free = &mut self.line_buf[self.len..];
+        let fits = bytes.len().min(free.len());
+        free[..fits].copy_from_slice(&bytes[..fits]);
+        self.len += fits;
+        self.overlong |= fits < bytes.len();
+
+        match consumed > bytes.len() {
+            true => (consumed, self.end_line()),
+            false => (consumed, None),
+        }
+    }
+
+    /// Handles the line completed by a newline.
+    fn end_line(&mut self) -> Option<CtlEvent<Ctl>> {
+        let len = core::mem::take(&mut self.len);
+        let res = match core::mem::take(&mut self.overlong) {
+            true => {
+                let err = ParseError::from(LineTooLong { max_len: N });
+                response::reply(&mut self.out, Err(err.into()))
+            }
+            false => match self.line_buf.get(..len)? {
+                [] => return None,
+                line => match self.controller.parse_command(line) {
+                    Ok(cmd) => self.controller.handle(&mut self.out, &cmd),
+                    Err(err) => response::reply(&mut self.out, Err(err.into())),
+                },
+            },
+        };
+        match res {
+            Err(err) if err.is_fatal() => {
+                self.stopped = true;
+                Some(Event::Stopped(err))
+            }
+            res => Some(Event::Replied(res)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use rand_chacha::ChaCha8Rng;
+    use rand_core::RngCore;
+    use rand_core::SeedableRng;
+
+    use super::*;
+    use crate::LineReader;
+    use crate::connection::Memory;
+    use crate::using_traits::controller::TargetController;
+    use crate::using_traits::targets::AdvancedTarget;
+    use crate::using_traits::targets::FaultyTarget;
+    use crate::writer::Capture;
+
+    const INPUT: &[u8] =
+        b"s 0x2a\np\n\n+\n* 3\n*~ 2\n* 2\np\n/ 0\nfrob\ns 12345678901234567890123\n\
+        u\np\n?\nw 1 7\ng\n*~~ 5 1\n% 5\np\n";
+
+    fn state_machine() -> ControllerStateMachine<TargetController<AdvancedTarget>, Capture<1024>, 16>
+    {
+        ControllerStateMachine::new(
+            TargetController::new(AdvancedTarget::new(0)),
+            Capture::new(),
+        )
+    }
+
+    /// The replies to `INPUT` from the blocking `LineReader` loop.
+    fn expected_replies() -> Vec<u8> {
+        let mut controller = TargetController::new(AdvancedTarget::new(0));
+        let mut conn = Memory::new(INPUT, &mut []);
+        let mut out = Capture::<1024>::new();
+        let mut reader = LineReader::new();
+        let mut line_buf = [0u8; 16];
+        while let Some(line) = reader.read_line(&mut conn, &mut line_buf) {
+            let _ = match line {
+                Ok([]) => continue,
+                Ok(line) => match controller.parse_command(line) {
+                    Ok(cmd) => controller.handle(&mut out, &cmd),
+                    Err(err) => response::reply(&mut out, Err(err.into())),
+                },
+                Err(err) => response::reply(&mut out, Err(ParseError::from(err).into())),
+            };
+        }
+        out.output().to_vec()
+    }
+
+    #[test]
+    fn test_feed() {
+        let mut sm = state_machine();
+        assert_eq!(sm.feed(b's'), None);
+        assert_eq!(sm.feed(b' '), None);
+        assert_eq!(sm.feed(b'5'), None);
+        assert_eq!(sm.feed(b'\n'), Some(Event::Replied(Ok(Response::Ok))));
+        assert_eq!(sm.feed(b'\n'), None);
+        assert_eq!(sm.feed(b'p'), None);
+        assert_eq!(sm.writer().output(), b"OK\n");
+        assert_eq!(sm.feed(b'\n'), Some(Event::Replied(Ok(Response::Value(5)))));
+        assert_eq!(sm.writer().output(), b"OK\n5\n");
+
+        // (discarded whole, like `LineReader` does)
+        for &byte in b"s 12345678901234567890\n" {
+            let event = sm.feed(byte);
+            assert_eq!(event.is_some(), byte == b'\n');
+        }
+        assert_eq!(sm.writer().output(), b"OK\n5\nE.line too long at 16\n");
+        assert!(!sm.is_stopped());
+    }
+
+    #[test]
+    fn test_feed_chunk() {
+        let mut sm = state_machine();
+        // (a chunk is consumed up to the end of the first line it completes)
+        assert_eq!(sm.feed_chunk(b"s 4"), (3, None));
+        assert_eq!(
+            sm.feed_chunk(b"2\n\np"),
+            (2, Some(Event::Replied(Ok(Response::Ok))))
+        );
+        assert_eq!(sm.feed_chunk(b"\np"), (1, None));
+        assert_eq!(
+            sm.feed_chunk(b"p\n+\n"),
+            (2, Some(Event::Replied(Ok(Response::Value(42)))))
+        );
+        assert_eq!(sm.feed_chunk(b""), (0, None));
+
+        // a line can overflow the buffer partway through a chunk...
+        assert_eq!(sm.feed_chunk(b"s 1234567890"), (12, None));
+        assert_eq!(sm.feed_chunk(b"1234567890"), (10, None));
+        // ...or with the chunk holding its newline
+        let too_long = ParseError::from(LineTooLong { max_len: 16 });
+        assert_eq!(
+            sm.feed_chunk(b"0\np\n"),
+            (2, Some(Event::Replied(Err(too_long.into()))))
+        );
+        assert_eq!(sm.writer().output(), b"OK\n42\nE.line too long at 16\n");
+    }
+
+    #[test]
+    fn test_random_chunks() {
+        let expected = expected_replies();
+        let mut rng = ChaCha8Rng::seed_from_u64(0);
+        for _ in 0..256 {
+            let mut sm = state_machine();
+            let mut replies = 0;
+            let mut rest = INPUT;
+            while !rest.is_empty() {
+                let len = 1 + rng.next_u32() as usize % rest.len().min(24);
+                let (mut chunk, tail) = rest.split_at(len);
+                while !chunk.is_empty() {
+                    let (consumed, event) = sm.feed_chunk(chunk);
+                    replies += usize::from(event.is_some());
+                    chunk = &chunk[consumed..];
+                }
+                rest = tail;
+            }
+            let (_, out) = sm.into_parts();
+            assert_eq!(out.output(), expected);
+            assert_eq!(replies, expected.iter().filter(|&&b| b == b'\n').count());
+        }
+    }
+
+    #[test]
+    fn test_stops() {
+        let controller = TargetController::new(FaultyTarget::new(0));
+        let mut sm = ControllerStateMachine::<_, _>::new(controller, Capture::<64>::new());
+
+        let mut events = Vec::new();
+        let mut rest: &[u8] = b"s 5\nw 3 1\np\n";
+        while !rest.is_empty() {
+            let (consumed, event) = sm.feed_chunk(rest);
+            events.extend(event);
+            rest = &rest[consumed..];
+        }
+        // (`p` is never handled)
+        assert_eq!(
+            events,
+            [
+                Event::Replied(Ok(Response::Ok)),
+                Event::Stopped(Error::Fatal("`r3` is read-only"))
+            ]
+        );
+        assert!(sm.is_stopped());
+        assert_eq!(sm.feed(b'\n'), None);
+        assert_eq!(sm.writer().output(), b"OK\nE 02\n");
+    }
+}
@@ -0,0 +1,41 @@
+//! `using_cfg_gates`' own `Command`, whose extension variants only exist when
+//! the selected target supports the extension (see the `ext_*` cfgs set by
+//! `build.rs`).
+//!
+//! Keeping it apart from `crate::commands::Command` means the rest of the
+//! library (and every other strategy) sees the same command set whatever the
+//! features, while this strategy's `parse_command` / `handle` never even name
+//! the extensions the target lacks.
+
+use crate::commands::ext;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Command<S> {
+    Base(ext::BaseCommand<S>),
+    #[cfg(ext_incdec)]
+    IncDec(ext::IncDecCommand),
+    #[cfg(ext_mul)]
+    Mul(ext::MulCommand<S>),
+    #[cfg(ext_div)]
+    Div(ext::DivCommand<S>),
+    #[cfg(ext_undo)]
+    Undo(ext::UndoCommand),
+}
+
+impl<S> Command<S> {
+    /// Whether `u` can revert this command (see
+    /// `crate::commands::Command::is_undoable`).
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    pub fn is_undoable(&self) -> bool {
+        match self {
+            Command::Base(ext::BaseCommand::SetState(_)) => true,
+            #[cfg(ext_incdec)]
+            Command::IncDec(_) => true,
+            #[cfg(ext_mul)]
+            Command::Mul(ext::MulCommand::Mul(_) | ext::MulCommand::ScaleFactor(_)) => true,
+            #[cfg(ext_div)]
+            Command::Div(_) => true,
+            _ => false,
+        }
+    }
+}
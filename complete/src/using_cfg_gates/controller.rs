@@ -1,12 +1,18 @@
-use crate::commands::Command;
+use crate::capabilities::Capabilities;
+use crate::commands::ParseError;
 use crate::commands::ext;
-use crate::commands::parse_isize;
+use crate::commands::strip_verb;
+use crate::commands::unrecognized;
+use crate::registers::Registers;
+use crate::response;
+use crate::response::Response;
+use crate::state_machine;
+use crate::writer::ResponseWriter;
 
+use super::commands::Command;
 use super::target::Target;
 
-pub enum Error<E> {
-    Target(E),
-}
+pub use crate::error::Error;
 
 pub struct TargetController<T: Target> {
     target: T,
@@ -17,28 +23,39 @@ impl<T: Target> TargetController<T> {
         TargetController { target }
     }
 
-    pub fn unsupported_cmd(&self) -> Result<(), Error<T::Error>> {
-        crate::println_str!("Unsupported cmd!");
-        Ok(())
+    /// The extensions supported by the target (i.e: what `?` reports).
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    pub fn capabilities(&mut self) -> Capabilities {
+        self.target.capabilities()
+    }
+
+    pub fn unsupported_cmd(&self) -> Result<Response<T::State>, Error<T::Error>> {
+        Err(Error::Unsupported)
+    }
+
+    /// Classifies an error returned by the target (see `Target::is_fatal`).
+    fn target_error(err: T::Error) -> Error<T::Error> {
+        let fatal = T::is_fatal(&err);
+        Error::target(err, fatal)
     }
 
     // NOTE: `#[inline(never)]` is used here specifically for pedagogical/assembly
     // inspection purposes, ensuring `parse_command` is emitted as a standalone
     // symbol in `asm_output/`.
     #[cfg_attr(feature = "interpretable_asm", inline(never))]
-    pub fn parse_command(&mut self, buf: &[u8]) -> Option<Command> {
+    pub fn parse_command(&mut self, buf: &[u8]) -> Result<Command<T::State>, ParseError> {
         /* IncDec extension parsing - gated at compile time */
         #[cfg(ext_incdec)]
         {
             crate::__dead_code_marker!("Parse IncDec extension");
-            if buf == b"+" {
-                return Some(Command::IncDec(ext::IncDecCommand::Inc));
+            if let Some(args) = strip_verb(buf, b"+") {
+                return args.finish(Command::IncDec(ext::IncDecCommand::Inc));
             }
-            if buf == b"-" {
-                return Some(Command::IncDec(ext::IncDecCommand::Dec));
+            if let Some(args) = strip_verb(buf, b"-") {
+                return args.finish(Command::IncDec(ext::IncDecCommand::Dec));
             }
-            if buf == b"+-" {
-                return Some(Command::IncDec(ext::IncDecCommand::IncDec));
+            if let Some(args) = strip_verb(buf, b"+-") {
+                return args.finish(Command::IncDec(ext::IncDecCommand::IncDec));
             }
         }
 
@@ -46,8 +63,9 @@ impl<T: Target> TargetController<T> {
         #[cfg(ext_mul)]
         {
             crate::__dead_code_marker!("Parse Mul extension");
-            if let Some(n) = buf.strip_prefix(b"* ").and_then(parse_isize) {
-                return Some(Command::Mul(ext::MulCommand::Mul(n)));
+            if let Some(mut args) = strip_verb(buf, b"*") {
+                let n = args.num()?;
+                return args.finish(Command::Mul(ext::MulCommand::Mul(n)));
             }
         }
 
@@ -55,33 +73,152 @@ impl<T: Target> TargetController<T> {
         #[cfg(ext_mul)]
         {
             crate::__dead_code_marker!("Parse ScaleFactor extension");
-            if let Some(n) = buf.strip_prefix(b"*~ ").and_then(parse_isize) {
-                return Some(Command::Mul(ext::MulCommand::ScaleFactor(n)));
+            if let Some(mut args) = strip_verb(buf, b"*~") {
+                let n = args.num()?;
+                return args.finish(Command::Mul(ext::MulCommand::ScaleFactor(n)));
+            }
+        }
+
+        /* ScaleBounds extension parsing - gated at compile time */
+        #[cfg(ext_mul)]
+        {
+            crate::__dead_code_marker!("Parse ScaleBounds extension");
+            if let Some(mut args) = strip_verb(buf, b"*~~") {
+                let (min, max) = (args.num()?, args.num()?);
+                return args.finish(Command::Mul(ext::MulCommand::ScaleBounds(min, max)));
+            }
+        }
+
+        /* Div extension parsing - gated at compile time */
+        #[cfg(ext_div)]
+        {
+            crate::__dead_code_marker!("Parse Div extension");
+            if let Some(mut args) = strip_verb(buf, b"/") {
+                let n = args.num()?;
+                return args.finish(Command::Div(ext::DivCommand::Div(n)));
+            }
+            if let Some(mut args) = strip_verb(buf, b"%") {
+                let n = args.num()?;
+                return args.finish(Command::Div(ext::DivCommand::Rem(n)));
+            }
+        }
+
+        /* Undo extension parsing - gated at compile time */
+        #[cfg(ext_undo)]
+        {
+            crate::__dead_code_marker!("Parse Undo extension");
+            if let Some(mut args) = strip_verb(buf, b"u") {
+                let n = args.num_or(1)?;
+                return args.finish(Command::Undo(ext::UndoCommand::Undo(n)));
             }
         }
 
         /* Base protocol parsing */
-        if buf == b"p" {
-            return Some(Command::Base(ext::BaseCommand::PrintState));
+        if let Some(args) = strip_verb(buf, b"p") {
+            return args.finish(Command::Base(ext::BaseCommand::PrintState));
+        }
+        if let Some(mut args) = strip_verb(buf, b"s") {
+            let n = args.num()?;
+            return args.finish(Command::Base(ext::BaseCommand::SetState(n)));
+        }
+        let num_regs = self.target.num_regs();
+        if let Some(mut args) = strip_verb(buf, b"r") {
+            let idx = args.reg(num_regs)?;
+            return args.finish(Command::Base(ext::BaseCommand::ReadReg(idx)));
         }
-        if let Some(n) = buf.strip_prefix(b"s ").and_then(parse_isize) {
-            return Some(Command::Base(ext::BaseCommand::SetState(n)));
+        if let Some(mut args) = strip_verb(buf, b"w") {
+            let (idx, val) = (args.reg(num_regs)?, args.num()?);
+            return args.finish(Command::Base(ext::BaseCommand::WriteReg(idx, val)));
+        }
+        if let Some(args) = strip_verb(buf, b"g") {
+            return args.finish(Command::Base(ext::BaseCommand::ReadRegs));
+        }
+        if let Some(mut args) = strip_verb(buf, b"G") {
+            let regs = args.regs(num_regs)?;
+            return args.finish(Command::Base(ext::BaseCommand::WriteRegs(regs)));
+        }
+        if let Some(mut args) = strip_verb(buf, b"?") {
+            let client = if args.is_empty() {
+                None
+            } else {
+                Some(args.names()?)
+            };
+            return args.finish(Command::Base(ext::BaseCommand::QuerySupported(client)));
         }
 
-        None
+        Err(unrecognized(buf))
     }
 
     // NOTE: `#[inline(never)]` is used here specifically for pedagogical/assembly
     // inspection purposes, ensuring `handle` is emitted as a standalone symbol in
     // `asm_output/`.
     #[cfg_attr(feature = "interpretable_asm", inline(never))]
-    pub fn handle(&mut self, cmd: &Command) -> Result<(), Error<T::Error>> {
+    pub fn handle<W: ResponseWriter>(
+        &mut self,
+        out: &mut W,
+        cmd: &Command<T::State>,
+    ) -> Result<Response<T::State>, Error<T::Error>> {
+        response::reply(out, self.execute(cmd))
+    }
+
+    /// Carries out `cmd`, leaving the reply to `handle`.
+    ///
+    /// Mutations are checkpointed first, but only if the target supports `u`.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn execute(&mut self, cmd: &Command<T::State>) -> Result<Response<T::State>, Error<T::Error>> {
+        #[cfg(ext_undo)]
+        if cmd.is_undoable() {
+            crate::__dead_code_marker!("Undo checkpoint");
+            self.target.checkpoint().map_err(Self::target_error)?;
+            let res = self.apply(cmd);
+            // (a failed command leaves nothing behind to undo, nor pushes the oldest
+            // snapshot out of a full history)
+            if res.is_err() {
+                self.target.rollback().map_err(Self::target_error)?;
+            }
+            return res;
+        }
+        self.apply(cmd)
+    }
+
+    /// Reads every register (for `g`, or to put them back after a failed `G`).
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn read_regs(&mut self) -> Registers<T::State> {
+        let mut regs = Registers::new();
+        for idx in 0..self.target.num_regs() {
+            regs.push(self.target.read_reg(idx));
+        }
+        regs
+    }
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn apply(&mut self, cmd: &Command<T::State>) -> Result<Response<T::State>, Error<T::Error>> {
         match cmd {
             /* Base protocol */
             Command::Base(base_cmd) => match base_cmd {
-                ext::BaseCommand::PrintState => crate::println_isize!(self.target.get_state()),
+                ext::BaseCommand::PrintState => {
+                    return Ok(Response::Value(self.target.get_state()));
+                }
                 ext::BaseCommand::SetState(n) => {
-                    self.target.set_state(*n).map_err(Error::Target)?
+                    self.target.set_state(*n).map_err(Self::target_error)?
+                }
+                ext::BaseCommand::QuerySupported(client) => {
+                    return Ok(Response::Supported(self.capabilities().negotiate(*client)));
+                }
+                ext::BaseCommand::ReadReg(idx) => {
+                    return Ok(Response::Value(self.target.read_reg(*idx)));
+                }
+                ext::BaseCommand::WriteReg(idx, val) => self
+                    .target
+                    .write_reg(*idx, *val)
+                    .map_err(Self::target_error)?,
+                ext::BaseCommand::ReadRegs => {
+                    return Ok(Response::Registers(self.read_regs()));
+                }
+                ext::BaseCommand::WriteRegs(regs) => {
+                    let prev = self.read_regs();
+                    regs.write_all(&prev, |idx, val| self.target.write_reg(idx, val))
+                        .map_err(Self::target_error)?;
                 }
             },
 
@@ -90,11 +227,11 @@ impl<T: Target> TargetController<T> {
             Command::IncDec(incdec_cmd) => {
                 crate::__dead_code_marker!("IncDec extension");
                 match incdec_cmd {
-                    ext::IncDecCommand::Inc => self.target.inc().map_err(Error::Target)?,
-                    ext::IncDecCommand::Dec => self.target.dec().map_err(Error::Target)?,
+                    ext::IncDecCommand::Inc => self.target.inc().map_err(Self::target_error)?,
+                    ext::IncDecCommand::Dec => self.target.dec().map_err(Self::target_error)?,
                     ext::IncDecCommand::IncDec => {
-                        self.target.inc().map_err(Error::Target)?;
-                        self.target.dec().map_err(Error::Target)?;
+                        self.target.inc().map_err(Self::target_error)?;
+                        self.target.dec().map_err(Self::target_error)?;
                     }
                 }
             }
@@ -104,15 +241,162 @@ impl<T: Target> TargetController<T> {
             Command::Mul(mul_cmd) => match mul_cmd {
                 ext::MulCommand::Mul(n) => {
                     crate::__dead_code_marker!("Mul extension");
-                    self.target.mul(*n).map_err(Error::Target)?;
+                    self.target.mul(*n).map_err(Self::target_error)?;
                 }
                 ext::MulCommand::ScaleFactor(n) => {
                     crate::__dead_code_marker!("ScaleFactor extension");
-                    self.target.scale_factor(*n).map_err(Error::Target)?;
+                    self.target.scale_factor(*n).map_err(Self::target_error)?;
+                }
+                ext::MulCommand::ScaleBounds(min, max) => {
+                    crate::__dead_code_marker!("ScaleBounds extension");
+                    self.target
+                        .scale_bounds(*min, *max)
+                        .map_err(Self::target_error)?;
                 }
             },
+
+            /* Div extension */
+            #[cfg(ext_div)]
+            Command::Div(div_cmd) => {
+                crate::__dead_code_marker!("Div extension");
+                match div_cmd {
+                    ext::DivCommand::Div(n) => self.target.div(*n).map_err(Self::target_error)?,
+                    ext::DivCommand::Rem(n) => self.target.rem(*n).map_err(Self::target_error)?,
+                }
+            }
+
+            /* Undo extension */
+            #[cfg(ext_undo)]
+            Command::Undo(ext::UndoCommand::Undo(n)) => {
+                crate::__dead_code_marker!("Undo extension");
+                self.target.undo(*n).map_err(Self::target_error)?
+            }
         }
 
-        Ok(())
+        Ok(Response::Ok)
+    }
+}
+
+impl<T: Target> state_machine::Controller for TargetController<T> {
+    type State = T::State;
+    type Error = T::Error;
+    type Command = Command<T::State>;
+
+    fn capabilities(&mut self) -> Capabilities {
+        TargetController::capabilities(self)
+    }
+
+    fn parse_command(&mut self, line: &[u8]) -> Result<Command<T::State>, ParseError> {
+        TargetController::parse_command(self, line)
+    }
+
+    fn handle<W: ResponseWriter>(
+        &mut self,
+        out: &mut W,
+        cmd: &Command<T::State>,
+    ) -> Result<Response<T::State>, Error<T::Error>> {
+        TargetController::handle(self, out, cmd)
+    }
+}
+
+// (the target, and so which of its extensions exist, is picked at build time,
+// in the same order as `targets` picks it)
+#[cfg(all(test, any(feature = "target_advanced", feature = "target_faulty")))]
+mod tests {
+    #[cfg(feature = "target_advanced")]
+    use super::super::targets::AdvancedTarget;
+    #[cfg(all(feature = "target_faulty", not(feature = "target_advanced")))]
+    use super::super::targets::FaultyTarget;
+    use super::*;
+    #[cfg(feature = "target_advanced")]
+    use crate::num::AdvancedState;
+    use crate::writer::Capture;
+
+    fn run<T: Target>(
+        controller: &mut TargetController<T>,
+        line: &[u8],
+    ) -> Result<Response<T::State>, Error<T::Error>> {
+        let mut out = Capture::<64>::new();
+        let cmd = controller.parse_command(line)?;
+        controller.handle(&mut out, &cmd)
+    }
+
+    #[cfg(feature = "target_advanced")]
+    #[test]
+    fn test_div() {
+        let mut controller = TargetController::new(AdvancedTarget::new(-7));
+        assert_eq!(run(&mut controller, b"% 4"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(-3)));
+        assert_eq!(
+            run(&mut controller, b"/ 0"),
+            Err(Error::NonFatal("dividing by zero!"))
+        );
+        assert_eq!(
+            run(&mut controller, b"% 0"),
+            Err(Error::NonFatal("dividing by zero!"))
+        );
+
+        // (neither `MIN / -1` nor `MIN % -1` fit in the state)
+        let min = format!("s {}", AdvancedState::MIN);
+        assert_eq!(run(&mut controller, min.as_bytes()), Ok(Response::Ok));
+        assert_eq!(
+            run(&mut controller, b"/ -1"),
+            Err(Error::NonFatal("dividing MIN by -1 overflows!"))
+        );
+        assert_eq!(
+            run(&mut controller, b"% -1"),
+            Err(Error::NonFatal("dividing MIN by -1 overflows!"))
+        );
+        assert_eq!(run(&mut controller, b"/ 2"), Ok(Response::Ok));
+        assert_eq!(
+            run(&mut controller, b"p"),
+            Ok(Response::Value(AdvancedState::MIN / 2))
+        );
+    }
+
+    #[cfg(all(feature = "target_faulty", not(feature = "target_advanced")))]
+    #[test]
+    fn test_fatal_errors() {
+        let mut controller = TargetController::new(FaultyTarget::new(isize::MAX));
+        assert_eq!(run(&mut controller, b"+"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(isize::MIN)));
+        let err = run(&mut controller, b"-").unwrap_err();
+        assert_eq!(
+            err,
+            Error::NonFatal("`dec` operations are not supported yet")
+        );
+        assert!(!err.is_fatal());
+
+        let err = run(&mut controller, b"w 3 1").unwrap_err();
+        assert_eq!(err, Error::Fatal("`r3` is read-only"));
+        assert!(err.is_fatal());
+    }
+
+    #[cfg(feature = "target_advanced")]
+    #[test]
+    fn test_undo() {
+        let mut controller = TargetController::new(AdvancedTarget::new(0));
+        assert_eq!(run(&mut controller, b"s 5"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"* 3"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"+"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"u"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(15)));
+
+        // (a failed command is rolled back, and isn't undone)
+        assert_eq!(
+            run(&mut controller, b"% 0"),
+            Err(Error::NonFatal("dividing by zero!"))
+        );
+        assert_eq!(
+            run(&mut controller, b"u 3"),
+            Err(Error::NonFatal("not enough history!"))
+        );
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(15)));
+        assert_eq!(run(&mut controller, b"u 2"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(0)));
+        assert_eq!(
+            run(&mut controller, b"u"),
+            Err(Error::NonFatal("nothing to undo!"))
+        );
     }
 }
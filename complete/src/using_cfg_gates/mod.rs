@@ -1,3 +1,4 @@
+pub mod commands;
 pub mod controller;
 pub mod target;
 pub mod targets;
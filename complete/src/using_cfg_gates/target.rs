@@ -1,8 +1,19 @@
+use crate::capabilities::Capabilities;
+use crate::num::Num;
+
 pub trait Target {
     type Error;
+    /// The target's state (and register) width.
+    type State: Num;
+
+    fn get_state(&self) -> Self::State;
+    fn set_state(&mut self, n: Self::State) -> Result<(), Self::Error>;
 
-    fn get_state(&self) -> isize;
-    fn set_state(&mut self, n: isize) -> Result<(), Self::Error>;
+    /// The size of the register file (at most `registers::MAX_REGS`), where
+    /// `r0` is the state itself.
+    fn num_regs(&self) -> usize;
+    fn read_reg(&self, idx: usize) -> Self::State;
+    fn write_reg(&mut self, idx: usize, val: Self::State) -> Result<(), Self::Error>;
 
     #[cfg(ext_incdec)]
     fn inc(&mut self) -> Result<(), Self::Error>;
@@ -11,8 +22,58 @@ pub trait Target {
     fn dec(&mut self) -> Result<(), Self::Error>;
 
     #[cfg(ext_mul)]
-    fn mul(&mut self, n: isize) -> Result<(), Self::Error>;
+    fn mul(&mut self, n: Self::State) -> Result<(), Self::Error>;
+
+    #[cfg(ext_mul)]
+    fn scale_factor(&mut self, factor: Self::State) -> Result<(), Self::Error>;
 
     #[cfg(ext_mul)]
-    fn scale_factor(&mut self, factor: isize) -> Result<(), Self::Error>;
+    fn scale_bounds(&mut self, min: Self::State, max: Self::State) -> Result<(), Self::Error>;
+
+    #[cfg(ext_div)]
+    fn div(&mut self, n: Self::State) -> Result<(), Self::Error>;
+
+    #[cfg(ext_div)]
+    fn rem(&mut self, n: Self::State) -> Result<(), Self::Error>;
+
+    /// Snapshots whatever the next mutation may change (see
+    /// `Command::is_undoable`).
+    #[cfg(ext_undo)]
+    fn checkpoint(&mut self) -> Result<(), Self::Error>;
+
+    /// Reverts the last `n` checkpointed mutations.
+    #[cfg(ext_undo)]
+    fn undo(&mut self, n: usize) -> Result<(), Self::Error>;
+
+    /// Reverts the last checkpoint, and forgets it as if it had never been
+    /// taken (e.g: once the mutation it was taken for has failed).
+    #[cfg(ext_undo)]
+    fn rollback(&mut self) -> Result<(), Self::Error>;
+
+    /// Whether `err` leaves the target unusable, ending the session.
+    ///
+    /// By default, every error is non-fatal: the controller replies with an
+    /// error code, and keeps serving.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn is_fatal(err: &Self::Error) -> bool
+    where
+        Self: Sized,
+    {
+        let _ = err;
+        false
+    }
+
+    /// Report every extension supported by the target.
+    ///
+    /// (the same for every target, since support is fixed at compile time)
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::NONE
+            .with(Capabilities::INCDEC, cfg!(ext_incdec))
+            .with(Capabilities::MUL, cfg!(ext_mul))
+            .with(Capabilities::SCALE_FACTOR, cfg!(ext_mul))
+            .with(Capabilities::SCALE_BOUNDS, cfg!(ext_mul))
+            .with(Capabilities::DIV, cfg!(ext_div))
+            .with(Capabilities::UNDO, cfg!(ext_undo))
+    }
 }
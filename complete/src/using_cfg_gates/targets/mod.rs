@@ -11,7 +11,7 @@ core::cfg_select! {
         mod faulty;
         pub use self::faulty::FaultyTarget;
     }
-    _ => {
-        compile_error!("must select one of the `target_` features!");
-    }
+    // extension support is baked in at compile time, so there's no way to
+    // offer every target at once (like the other strategies do)
+    _ => {}
 }
@@ -1,5 +1,10 @@
+use crate::capabilities::Capabilities;
+use crate::num::Num;
+
 pub trait Target {
     type Error;
+    /// The target's state (and register) width.
+    type State: Num;
 
     fn base(&mut self) -> &'static TargetBaseOps<Self>;
 
@@ -12,23 +17,101 @@ pub trait Target {
     fn ext_mul(&mut self) -> Option<&'static TargetExtMulOps<Self>> {
         None
     }
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn ext_div(&mut self) -> Option<&'static TargetExtDivOps<Self>> {
+        None
+    }
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn ext_undo(&mut self) -> Option<&'static TargetExtUndoOps<Self>> {
+        None
+    }
+
+    /// Whether `err` leaves the target unusable, ending the session.
+    ///
+    /// By default, every error is non-fatal: the controller replies with an
+    /// error code, and keeps serving.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn is_fatal(err: &Self::Error) -> bool
+    where
+        Self: Sized,
+    {
+        let _ = err;
+        false
+    }
+
+    /// Report every extension supported by the target.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn capabilities(&mut self) -> Capabilities
+    where
+        Self: Sized + 'static,
+    {
+        Capabilities::NONE
+            .with(Capabilities::INCDEC, self.ext_incdec().is_some())
+            .with(Capabilities::MUL, self.ext_mul().is_some())
+            .with(
+                Capabilities::SCALE_FACTOR,
+                self.ext_mul()
+                    .and_then(|ops| (ops.ext_scale_factor)(self))
+                    .is_some(),
+            )
+            .with(
+                Capabilities::SCALE_BOUNDS,
+                self.ext_mul()
+                    .and_then(|ops| (ops.ext_scale_factor)(self))
+                    .and_then(|ops| (ops.ext_scale_bounds)(self))
+                    .is_some(),
+            )
+            .with(Capabilities::DIV, self.ext_div().is_some())
+            .with(Capabilities::UNDO, self.ext_undo().is_some())
+    }
 }
 
+/// (the result of every fallible op below)
+type OpResult<T> = Result<(), <T as Target>::Error>;
+
 pub struct TargetBaseOps<T: Target + ?Sized> {
-    pub get_state: fn(&T) -> isize,
-    pub set_state: fn(&mut T, n: isize) -> Result<(), T::Error>,
+    pub get_state: fn(&T) -> T::State,
+    pub set_state: fn(&mut T, n: T::State) -> OpResult<T>,
+    /// The size of the register file (at most `registers::MAX_REGS`), where
+    /// `r0` is the state itself.
+    pub num_regs: fn(&T) -> usize,
+    pub read_reg: fn(&T, idx: usize) -> T::State,
+    pub write_reg: fn(&mut T, idx: usize, val: T::State) -> OpResult<T>,
 }
 
 pub struct TargetExtIncDecOps<T: Target + ?Sized> {
-    pub inc: fn(&mut T) -> Result<(), T::Error>,
-    pub dec: fn(&mut T) -> Result<(), T::Error>,
+    pub inc: fn(&mut T) -> OpResult<T>,
+    pub dec: fn(&mut T) -> OpResult<T>,
 }
 
 pub struct TargetExtMulOps<T: Target + ?Sized + 'static> {
-    pub mul: fn(&mut T, n: isize) -> Result<(), T::Error>,
+    pub mul: fn(&mut T, n: T::State) -> OpResult<T>,
     pub ext_scale_factor: fn(&T) -> Option<&'static TargetExtScaleFactorOps<T>>,
 }
 
 pub struct TargetExtScaleFactorOps<T: Target + ?Sized + 'static> {
-    pub scale_factor: fn(&mut T, factor: isize) -> Result<(), T::Error>,
+    pub scale_factor: fn(&mut T, factor: T::State) -> OpResult<T>,
+    pub ext_scale_bounds: fn(&T) -> Option<&'static TargetExtScaleBoundsOps<T>>,
+}
+
+pub struct TargetExtScaleBoundsOps<T: Target + ?Sized + 'static> {
+    pub scale_bounds: fn(&mut T, min: T::State, max: T::State) -> OpResult<T>,
+}
+
+pub struct TargetExtDivOps<T: Target + ?Sized> {
+    pub div: fn(&mut T, n: T::State) -> OpResult<T>,
+    pub rem: fn(&mut T, n: T::State) -> OpResult<T>,
+}
+
+pub struct TargetExtUndoOps<T: Target + ?Sized> {
+    /// Snapshots whatever the next mutation may change (see
+    /// `Command::is_undoable`).
+    pub checkpoint: fn(&mut T) -> OpResult<T>,
+    /// Reverts the last `n` checkpointed mutations.
+    pub undo: fn(&mut T, n: usize) -> OpResult<T>,
+    /// Reverts the last checkpoint, and forgets it as if it had never been
+    /// taken (e.g: once the mutation it was taken for has failed).
+    pub rollback: fn(&mut T) -> OpResult<T>,
 }
@@ -1,27 +1,45 @@
 use core::num::Wrapping;
 
+use crate::history::History;
+use crate::num::AdvancedState;
+
 use super::super::target::Target;
 use super::super::target::TargetBaseOps;
+use super::super::target::TargetExtDivOps;
 use super::super::target::TargetExtIncDecOps;
 use super::super::target::TargetExtMulOps;
+use super::super::target::TargetExtScaleBoundsOps;
 use super::super::target::TargetExtScaleFactorOps;
+use super::super::target::TargetExtUndoOps;
+
+/// How many mutations `u` can revert.
+const UNDO_DEPTH: usize = 16;
 
 pub struct AdvancedTarget {
-    state: Wrapping<isize>,
-    scale: Wrapping<isize>,
+    state: Wrapping<AdvancedState>,
+    // `r1`.. (`r0` is `state` itself)
+    regs: [AdvancedState; 7],
+    scale: Wrapping<AdvancedState>,
+    scale_bounds: (AdvancedState, AdvancedState),
+    // `(state, scale)` before each of the most recent mutations
+    history: History<(Wrapping<AdvancedState>, Wrapping<AdvancedState>), UNDO_DEPTH>,
 }
 
 impl AdvancedTarget {
-    pub fn new(state: isize) -> AdvancedTarget {
+    pub fn new(state: AdvancedState) -> AdvancedTarget {
         AdvancedTarget {
             state: Wrapping(state),
+            regs: [0; 7],
             scale: Wrapping(1),
+            scale_bounds: (AdvancedState::MIN, AdvancedState::MAX),
+            history: History::new(),
         }
     }
 }
 
 impl Target for AdvancedTarget {
     type Error = &'static str;
+    type State = AdvancedState;
 
     #[cfg_attr(feature = "always_inline", inline(always))]
     fn base(&mut self) -> &'static TargetBaseOps<Self> {
@@ -37,19 +55,56 @@ impl Target for AdvancedTarget {
     fn ext_mul(&mut self) -> Option<&'static TargetExtMulOps<Self>> {
         Some(&EXT_MUL_OPS)
     }
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn ext_div(&mut self) -> Option<&'static TargetExtDivOps<Self>> {
+        Some(&EXT_DIV_OPS)
+    }
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn ext_undo(&mut self) -> Option<&'static TargetExtUndoOps<Self>> {
+        Some(&EXT_UNDO_OPS)
+    }
 }
 
 #[inline(never)]
-fn get_state(this: &AdvancedTarget) -> isize {
+fn get_state(this: &AdvancedTarget) -> AdvancedState {
     this.state.0
 }
 
 #[inline(never)]
-fn set_state(this: &mut AdvancedTarget, n: isize) -> Result<(), &'static str> {
+fn set_state(this: &mut AdvancedTarget, n: AdvancedState) -> Result<(), &'static str> {
     this.state = Wrapping(n);
     Ok(())
 }
 
+#[cfg_attr(feature = "always_inline", inline(always))]
+fn num_regs(this: &AdvancedTarget) -> usize {
+    let _ = this;
+    8
+}
+
+#[inline(never)]
+fn read_reg(this: &AdvancedTarget, idx: usize) -> AdvancedState {
+    match idx {
+        0 => this.state.0,
+        _ => this.regs[idx - 1],
+    }
+}
+
+#[inline(never)]
+fn write_reg(
+    this: &mut AdvancedTarget,
+    idx: usize,
+    val: AdvancedState,
+) -> Result<(), &'static str> {
+    match idx {
+        0 => this.state = Wrapping(val),
+        _ => this.regs[idx - 1] = val,
+    }
+    Ok(())
+}
+
 #[inline(never)]
 fn inc(this: &mut AdvancedTarget) -> Result<(), &'static str> {
     this.state += 1;
@@ -63,7 +118,7 @@ fn dec(this: &mut AdvancedTarget) -> Result<(), &'static str> {
 }
 
 #[inline(never)]
-fn mul(this: &mut AdvancedTarget, n: isize) -> Result<(), &'static str> {
+fn mul(this: &mut AdvancedTarget, n: AdvancedState) -> Result<(), &'static str> {
     match n {
         7 => Err("multiplying by 7 is unlucky!"),
         _ => {
@@ -74,11 +129,85 @@ fn mul(this: &mut AdvancedTarget, n: isize) -> Result<(), &'static str> {
 }
 
 #[inline(never)]
-fn scale_factor(this: &mut AdvancedTarget, factor: isize) -> Result<(), &'static str> {
-    this.scale = Wrapping(factor);
+fn scale_factor(this: &mut AdvancedTarget, factor: AdvancedState) -> Result<(), &'static str> {
+    let (min, max) = this.scale_bounds;
+    this.scale = Wrapping(factor.max(min).min(max));
+    Ok(())
+}
+
+#[inline(never)]
+fn scale_bounds(
+    this: &mut AdvancedTarget,
+    min: AdvancedState,
+    max: AdvancedState,
+) -> Result<(), &'static str> {
+    if min > max {
+        return Err("scale bounds are inverted!");
+    }
+    this.scale_bounds = (min, max);
+    this.scale = Wrapping(this.scale.0.max(min).min(max));
+    Ok(())
+}
+
+#[inline(never)]
+fn div(this: &mut AdvancedTarget, n: AdvancedState) -> Result<(), &'static str> {
+    match this.state.0.checked_div(n) {
+        Some(state) => {
+            this.state = Wrapping(state);
+            Ok(())
+        }
+        None if n == 0 => Err("dividing by zero!"),
+        None => Err("dividing MIN by -1 overflows!"),
+    }
+}
+
+#[inline(never)]
+fn rem(this: &mut AdvancedTarget, n: AdvancedState) -> Result<(), &'static str> {
+    match this.state.0.checked_rem(n) {
+        Some(state) => {
+            this.state = Wrapping(state);
+            Ok(())
+        }
+        None if n == 0 => Err("dividing by zero!"),
+        None => Err("dividing MIN by -1 overflows!"),
+    }
+}
+
+#[inline(never)]
+fn checkpoint(this: &mut AdvancedTarget) -> Result<(), &'static str> {
+    this.history.push((this.state, this.scale));
     Ok(())
 }
 
+#[inline(never)]
+fn undo(this: &mut AdvancedTarget, n: usize) -> Result<(), &'static str> {
+    match this.history.rewind(n) {
+        Some((state, scale)) => {
+            // (the scale bounds may have been narrowed since)
+            let (min, max) = this.scale_bounds;
+            this.state = state;
+            this.scale = Wrapping(scale.0.max(min).min(max));
+            Ok(())
+        }
+        None if n == 0 => Ok(()),
+        None if this.history.is_empty() => Err("nothing to undo!"),
+        // (rather than undoing only some of the `n` mutations)
+        None => Err("not enough history!"),
+    }
+}
+
+#[inline(never)]
+fn rollback(this: &mut AdvancedTarget) -> Result<(), &'static str> {
+    match this.history.unpush() {
+        Some((state, scale)) => {
+            this.state = state;
+            this.scale = scale;
+            Ok(())
+        }
+        None => Err("nothing to roll back!"),
+    }
+}
+
 #[cfg_attr(feature = "always_inline", inline(always))]
 fn ext_scale_factor(
     this: &AdvancedTarget,
@@ -87,9 +216,20 @@ fn ext_scale_factor(
     Some(&EXT_SCALE_FACTOR_OPS)
 }
 
+#[cfg_attr(feature = "always_inline", inline(always))]
+fn ext_scale_bounds(
+    this: &AdvancedTarget,
+) -> Option<&'static TargetExtScaleBoundsOps<AdvancedTarget>> {
+    let _ = this;
+    Some(&EXT_SCALE_BOUNDS_OPS)
+}
+
 const BASE_OPS: TargetBaseOps<AdvancedTarget> = TargetBaseOps {
     get_state,
     set_state,
+    num_regs,
+    read_reg,
+    write_reg,
 };
 
 const EXT_INC_DEC_OPS: TargetExtIncDecOps<AdvancedTarget> = TargetExtIncDecOps { inc, dec };
@@ -99,5 +239,18 @@ const EXT_MUL_OPS: TargetExtMulOps<AdvancedTarget> = TargetExtMulOps {
     ext_scale_factor,
 };
 
-const EXT_SCALE_FACTOR_OPS: TargetExtScaleFactorOps<AdvancedTarget> =
-    TargetExtScaleFactorOps { scale_factor };
+const EXT_SCALE_FACTOR_OPS: TargetExtScaleFactorOps<AdvancedTarget> = TargetExtScaleFactorOps {
+    scale_factor,
+    ext_scale_bounds,
+};
+
+const EXT_SCALE_BOUNDS_OPS: TargetExtScaleBoundsOps<AdvancedTarget> =
+    TargetExtScaleBoundsOps { scale_bounds };
+
+const EXT_DIV_OPS: TargetExtDivOps<AdvancedTarget> = TargetExtDivOps { div, rem };
+
+const EXT_UNDO_OPS: TargetExtUndoOps<AdvancedTarget> = TargetExtUndoOps {
+    checkpoint,
+    undo,
+    rollback,
+};
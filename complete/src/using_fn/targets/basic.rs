@@ -3,16 +3,22 @@ use super::super::target::TargetBaseOps;
 
 pub struct BasicTarget {
     state: isize,
+    // `r1`.. (`r0` is `state` itself)
+    regs: [isize; 3],
 }
 
 impl BasicTarget {
     pub fn new(state: isize) -> BasicTarget {
-        BasicTarget { state }
+        BasicTarget {
+            state,
+            regs: [0; 3],
+        }
     }
 }
 
 impl Target for BasicTarget {
     type Error = &'static str;
+    type State = isize;
 
     #[cfg_attr(feature = "always_inline", inline(always))]
     fn base(&mut self) -> &'static TargetBaseOps<Self> {
@@ -31,7 +37,33 @@ fn set_state(this: &mut BasicTarget, n: isize) -> Result<(), &'static str> {
     Ok(())
 }
 
+#[cfg_attr(feature = "always_inline", inline(always))]
+fn num_regs(this: &BasicTarget) -> usize {
+    let _ = this;
+    4
+}
+
+#[inline(never)]
+fn read_reg(this: &BasicTarget, idx: usize) -> isize {
+    match idx {
+        0 => this.state,
+        _ => this.regs[idx - 1],
+    }
+}
+
+#[inline(never)]
+fn write_reg(this: &mut BasicTarget, idx: usize, val: isize) -> Result<(), &'static str> {
+    match idx {
+        0 => this.state = val,
+        _ => this.regs[idx - 1] = val,
+    }
+    Ok(())
+}
+
 const BASE_OPS: TargetBaseOps<BasicTarget> = TargetBaseOps {
     get_state,
     set_state,
+    num_regs,
+    read_reg,
+    write_reg,
 };
@@ -6,18 +6,31 @@ use super::super::target::TargetExtIncDecOps;
 
 pub struct FaultyTarget {
     state: Wrapping<isize>,
+    // `r1`.. (`r0` is `state` itself)
+    regs: [isize; 3],
 }
 
 impl FaultyTarget {
     pub fn new(state: isize) -> FaultyTarget {
         FaultyTarget {
             state: Wrapping(state),
+            regs: [0; 3],
         }
     }
 }
 
+/// What writes to `r3` fail with: unlike `dec`'s, this target treats it as an
+/// error it can't recover from (so the session has to end).
+const READ_ONLY: &str = "`r3` is read-only";
+
 impl Target for FaultyTarget {
     type Error = &'static str;
+    type State = isize;
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn is_fatal(err: &Self::Error) -> bool {
+        *err == READ_ONLY
+    }
 
     #[cfg_attr(feature = "always_inline", inline(always))]
     fn base(&mut self) -> &'static TargetBaseOps<Self> {
@@ -41,6 +54,30 @@ fn set_state(this: &mut FaultyTarget, n: isize) -> Result<(), &'static str> {
     Ok(())
 }
 
+#[cfg_attr(feature = "always_inline", inline(always))]
+fn num_regs(this: &FaultyTarget) -> usize {
+    let _ = this;
+    4
+}
+
+#[inline(never)]
+fn read_reg(this: &FaultyTarget, idx: usize) -> isize {
+    match idx {
+        0 => this.state.0,
+        _ => this.regs[idx - 1],
+    }
+}
+
+#[inline(never)]
+fn write_reg(this: &mut FaultyTarget, idx: usize, val: isize) -> Result<(), &'static str> {
+    match idx {
+        0 => this.state = Wrapping(val),
+        3 => return Err(READ_ONLY),
+        _ => this.regs[idx - 1] = val,
+    }
+    Ok(())
+}
+
 #[inline(never)]
 fn inc(this: &mut FaultyTarget) -> Result<(), &'static str> {
     this.state += 1;
@@ -55,6 +92,9 @@ fn dec(_this: &mut FaultyTarget) -> Result<(), &'static str> {
 const BASE_OPS: TargetBaseOps<FaultyTarget> = TargetBaseOps {
     get_state,
     set_state,
+    num_regs,
+    read_reg,
+    write_reg,
 };
 
 const EXT_INC_DEC_OPS: TargetExtIncDecOps<FaultyTarget> = TargetExtIncDecOps { inc, dec };
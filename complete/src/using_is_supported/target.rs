@@ -1,8 +1,19 @@
+use crate::capabilities::Capabilities;
+use crate::num::Num;
+
 pub trait Target {
     type Error;
+    /// The target's state (and register) width.
+    type State: Num;
+
+    fn get_state(&self) -> Self::State;
+    fn set_state(&mut self, n: Self::State) -> Result<(), Self::Error>;
 
-    fn get_state(&self) -> isize;
-    fn set_state(&mut self, n: isize) -> Result<(), Self::Error>;
+    /// The size of the register file (at most `registers::MAX_REGS`), where
+    /// `r0` is the state itself.
+    fn num_regs(&self) -> usize;
+    fn read_reg(&self, idx: usize) -> Self::State;
+    fn write_reg(&mut self, idx: usize, val: Self::State) -> Result<(), Self::Error>;
 
     fn ext_incdec_supported(&self) -> bool {
         false
@@ -20,7 +31,7 @@ pub trait Target {
         false
     }
 
-    fn mul(&mut self, n: isize) -> Result<(), Self::Error> {
+    fn mul(&mut self, n: Self::State) -> Result<(), Self::Error> {
         let _ = n;
         unimplemented!()
     }
@@ -29,8 +40,86 @@ pub trait Target {
         false
     }
 
-    fn scale_factor(&mut self, factor: isize) -> Result<(), Self::Error> {
+    fn scale_factor(&mut self, factor: Self::State) -> Result<(), Self::Error> {
         let _ = factor;
         unimplemented!()
     }
+
+    fn ext_scale_bounds_supported(&self) -> bool {
+        false
+    }
+
+    fn scale_bounds(&mut self, min: Self::State, max: Self::State) -> Result<(), Self::Error> {
+        let _ = (min, max);
+        unimplemented!()
+    }
+
+    fn ext_div_supported(&self) -> bool {
+        false
+    }
+
+    fn div(&mut self, n: Self::State) -> Result<(), Self::Error> {
+        let _ = n;
+        unimplemented!()
+    }
+
+    fn rem(&mut self, n: Self::State) -> Result<(), Self::Error> {
+        let _ = n;
+        unimplemented!()
+    }
+
+    fn ext_undo_supported(&self) -> bool {
+        false
+    }
+
+    /// Snapshots whatever the next mutation may change (see
+    /// `Command::is_undoable`).
+    fn checkpoint(&mut self) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+
+    /// Reverts the last `n` checkpointed mutations.
+    fn undo(&mut self, n: usize) -> Result<(), Self::Error> {
+        let _ = n;
+        unimplemented!()
+    }
+
+    /// Reverts the last checkpoint, and forgets it as if it had never been
+    /// taken (e.g: once the mutation it was taken for has failed).
+    fn rollback(&mut self) -> Result<(), Self::Error> {
+        unimplemented!()
+    }
+
+    /// Whether `err` leaves the target unusable, ending the session.
+    ///
+    /// By default, every error is non-fatal: the controller replies with an
+    /// error code, and keeps serving.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn is_fatal(err: &Self::Error) -> bool
+    where
+        Self: Sized,
+    {
+        let _ = err;
+        false
+    }
+
+    /// Report every extension supported by the target.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::NONE
+            .with(Capabilities::INCDEC, self.ext_incdec_supported())
+            .with(Capabilities::MUL, self.ext_mul_supported())
+            .with(
+                Capabilities::SCALE_FACTOR,
+                self.ext_mul_supported() && self.ext_scale_factor_supported(),
+            )
+            .with(
+                Capabilities::SCALE_BOUNDS,
+                self.ext_mul_supported()
+                    && self.ext_scale_factor_supported()
+                    && self.ext_scale_bounds_supported(),
+            )
+            .with(Capabilities::DIV, self.ext_div_supported())
+            .with(Capabilities::UNDO, self.ext_undo_supported())
+    }
 }
@@ -4,18 +4,31 @@ use super::super::target::Target;
 
 pub struct FaultyTarget {
     state: Wrapping<isize>,
+    // `r1`.. (`r0` is `state` itself)
+    regs: [isize; 3],
 }
 
 impl FaultyTarget {
     pub fn new(state: isize) -> FaultyTarget {
         FaultyTarget {
             state: Wrapping(state),
+            regs: [0; 3],
         }
     }
 }
 
+/// What writes to `r3` fail with: unlike `dec`'s, this target treats it as an
+/// error it can't recover from (so the session has to end).
+const READ_ONLY: &str = "`r3` is read-only";
+
 impl Target for FaultyTarget {
     type Error = &'static str;
+    type State = isize;
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn is_fatal(err: &Self::Error) -> bool {
+        *err == READ_ONLY
+    }
 
     #[inline(never)]
     fn get_state(&self) -> isize {
@@ -28,6 +41,29 @@ impl Target for FaultyTarget {
         Ok(())
     }
 
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn num_regs(&self) -> usize {
+        4
+    }
+
+    #[inline(never)]
+    fn read_reg(&self, idx: usize) -> isize {
+        match idx {
+            0 => self.state.0,
+            _ => self.regs[idx - 1],
+        }
+    }
+
+    #[inline(never)]
+    fn write_reg(&mut self, idx: usize, val: isize) -> Result<(), Self::Error> {
+        match idx {
+            0 => self.state = Wrapping(val),
+            3 => return Err(READ_ONLY),
+            _ => self.regs[idx - 1] = val,
+        }
+        Ok(())
+    }
+
     #[cfg_attr(feature = "always_inline", inline(always))]
     fn ext_incdec_supported(&self) -> bool {
         true
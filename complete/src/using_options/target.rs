@@ -1,11 +1,22 @@
 pub use super::opt_result::MaybeUnimpl;
 pub use super::opt_result::OptResult;
 
+use crate::capabilities::Capabilities;
+use crate::num::Num;
+
 pub trait Target {
     type Error;
+    /// The target's state (and register) width.
+    type State: Num;
+
+    fn get_state(&self) -> Self::State;
+    fn set_state(&mut self, n: Self::State) -> Result<(), Self::Error>;
 
-    fn get_state(&self) -> isize;
-    fn set_state(&mut self, n: isize) -> Result<(), Self::Error>;
+    /// The size of the register file (at most `registers::MAX_REGS`), where
+    /// `r0` is the state itself.
+    fn num_regs(&self) -> usize;
+    fn read_reg(&self, idx: usize) -> Self::State;
+    fn write_reg(&mut self, idx: usize, val: Self::State) -> Result<(), Self::Error>;
 
     /// (feat: incdec)
     fn inc(&mut self) -> OptResult<(), Self::Error> {
@@ -18,14 +29,74 @@ pub trait Target {
     }
 
     /// (feat: mul)
-    fn mul(&mut self, n: isize) -> OptResult<(), Self::Error> {
+    fn mul(&mut self, n: Self::State) -> OptResult<(), Self::Error> {
         let _ = n;
         Err(MaybeUnimpl::unimplemented())
     }
 
     /// (feat: scale_factor)
-    fn scale_factor(&mut self, factor: isize) -> OptResult<(), Self::Error> {
+    fn scale_factor(&mut self, factor: Self::State) -> OptResult<(), Self::Error> {
         let _ = factor;
         Err(MaybeUnimpl::unimplemented())
     }
+
+    /// (feat: scale_bounds)
+    fn scale_bounds(&mut self, min: Self::State, max: Self::State) -> OptResult<(), Self::Error> {
+        let _ = (min, max);
+        Err(MaybeUnimpl::unimplemented())
+    }
+
+    /// (feat: div)
+    fn div(&mut self, n: Self::State) -> OptResult<(), Self::Error> {
+        let _ = n;
+        Err(MaybeUnimpl::unimplemented())
+    }
+
+    /// (feat: div)
+    fn rem(&mut self, n: Self::State) -> OptResult<(), Self::Error> {
+        let _ = n;
+        Err(MaybeUnimpl::unimplemented())
+    }
+
+    /// (feat: undo) Snapshots whatever the next mutation may change (see
+    /// `Command::is_undoable`).
+    fn checkpoint(&mut self) -> OptResult<(), Self::Error> {
+        Err(MaybeUnimpl::unimplemented())
+    }
+
+    /// (feat: undo) Reverts the last `n` checkpointed mutations.
+    fn undo(&mut self, n: usize) -> OptResult<(), Self::Error> {
+        let _ = n;
+        Err(MaybeUnimpl::unimplemented())
+    }
+
+    /// (feat: undo) Reverts the last checkpoint, and forgets it as if it had
+    /// never been taken (e.g: once the mutation it was taken for has failed).
+    fn rollback(&mut self) -> OptResult<(), Self::Error> {
+        Err(MaybeUnimpl::unimplemented())
+    }
+
+    /// Whether `err` leaves the target unusable, ending the session.
+    ///
+    /// By default, every error is non-fatal: the controller replies with an
+    /// error code, and keeps serving.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn is_fatal(err: &Self::Error) -> bool
+    where
+        Self: Sized,
+    {
+        let _ = err;
+        false
+    }
+
+    /// Report every extension supported by the target.
+    ///
+    /// Since there's no way to check whether an `OptResult` method is
+    /// implemented without invoking it, targets have to list their extensions
+    /// by hand (and keep the list in sync with the methods they override). By
+    /// default, no extension is reported.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::NONE
+    }
 }
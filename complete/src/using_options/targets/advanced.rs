@@ -1,36 +1,74 @@
 use core::num::Wrapping;
 
+use crate::capabilities::Capabilities;
+use crate::history::History;
+use crate::num::AdvancedState;
+
 use super::super::target::OptResult;
 use super::super::target::Target;
 
+/// How many mutations `u` can revert.
+const UNDO_DEPTH: usize = 16;
+
 pub struct AdvancedTarget {
-    state: Wrapping<isize>,
-    scale: Wrapping<isize>,
+    state: Wrapping<AdvancedState>,
+    // `r1`.. (`r0` is `state` itself)
+    regs: [AdvancedState; 7],
+    scale: Wrapping<AdvancedState>,
+    scale_bounds: (AdvancedState, AdvancedState),
+    // `(state, scale)` before each of the most recent mutations
+    history: History<(Wrapping<AdvancedState>, Wrapping<AdvancedState>), UNDO_DEPTH>,
 }
 
 impl AdvancedTarget {
-    pub fn new(state: isize) -> AdvancedTarget {
+    pub fn new(state: AdvancedState) -> AdvancedTarget {
         AdvancedTarget {
             state: Wrapping(state),
+            regs: [0; 7],
             scale: Wrapping(1),
+            scale_bounds: (AdvancedState::MIN, AdvancedState::MAX),
+            history: History::new(),
         }
     }
 }
 
 impl Target for AdvancedTarget {
     type Error = &'static str;
+    type State = AdvancedState;
 
     #[inline(never)]
-    fn get_state(&self) -> isize {
+    fn get_state(&self) -> AdvancedState {
         self.state.0
     }
 
     #[inline(never)]
-    fn set_state(&mut self, n: isize) -> Result<(), Self::Error> {
+    fn set_state(&mut self, n: AdvancedState) -> Result<(), Self::Error> {
         self.state = Wrapping(n);
         Ok(())
     }
 
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn num_regs(&self) -> usize {
+        8
+    }
+
+    #[inline(never)]
+    fn read_reg(&self, idx: usize) -> AdvancedState {
+        match idx {
+            0 => self.state.0,
+            _ => self.regs[idx - 1],
+        }
+    }
+
+    #[inline(never)]
+    fn write_reg(&mut self, idx: usize, val: AdvancedState) -> Result<(), Self::Error> {
+        match idx {
+            0 => self.state = Wrapping(val),
+            _ => self.regs[idx - 1] = val,
+        }
+        Ok(())
+    }
+
     #[inline(never)]
     fn inc(&mut self) -> OptResult<(), Self::Error> {
         self.state += 1;
@@ -44,7 +82,7 @@ impl Target for AdvancedTarget {
     }
 
     #[inline(never)]
-    fn mul(&mut self, n: isize) -> OptResult<(), Self::Error> {
+    fn mul(&mut self, n: AdvancedState) -> OptResult<(), Self::Error> {
         match n {
             7 => Err("multiplying by 7 is unlucky!".into()),
             _ => {
@@ -55,8 +93,87 @@ impl Target for AdvancedTarget {
     }
 
     #[inline(never)]
-    fn scale_factor(&mut self, factor: isize) -> OptResult<(), Self::Error> {
-        self.scale = Wrapping(factor);
+    fn scale_factor(&mut self, factor: AdvancedState) -> OptResult<(), Self::Error> {
+        let (min, max) = self.scale_bounds;
+        self.scale = Wrapping(factor.max(min).min(max));
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn scale_bounds(
+        &mut self,
+        min: AdvancedState,
+        max: AdvancedState,
+    ) -> OptResult<(), Self::Error> {
+        if min > max {
+            return Err("scale bounds are inverted!".into());
+        }
+        self.scale_bounds = (min, max);
+        self.scale = Wrapping(self.scale.0.max(min).min(max));
+        Ok(())
+    }
+
+    #[inline(never)]
+    fn div(&mut self, n: AdvancedState) -> OptResult<(), Self::Error> {
+        match self.state.0.checked_div(n) {
+            Some(state) => {
+                self.state = Wrapping(state);
+                Ok(())
+            }
+            None if n == 0 => Err("dividing by zero!".into()),
+            None => Err("dividing MIN by -1 overflows!".into()),
+        }
+    }
+
+    #[inline(never)]
+    fn rem(&mut self, n: AdvancedState) -> OptResult<(), Self::Error> {
+        match self.state.0.checked_rem(n) {
+            Some(state) => {
+                self.state = Wrapping(state);
+                Ok(())
+            }
+            None if n == 0 => Err("dividing by zero!".into()),
+            None => Err("dividing MIN by -1 overflows!".into()),
+        }
+    }
+
+    #[inline(never)]
+    fn checkpoint(&mut self) -> OptResult<(), Self::Error> {
+        self.history.push((self.state, self.scale));
         Ok(())
     }
+
+    #[inline(never)]
+    fn undo(&mut self, n: usize) -> OptResult<(), Self::Error> {
+        match self.history.rewind(n) {
+            Some((state, scale)) => {
+                // (the scale bounds may have been narrowed since)
+                let (min, max) = self.scale_bounds;
+                self.state = state;
+                self.scale = Wrapping(scale.0.max(min).min(max));
+                Ok(())
+            }
+            None if n == 0 => Ok(()),
+            None if self.history.is_empty() => Err("nothing to undo!".into()),
+            // (rather than undoing only some of the `n` mutations)
+            None => Err("not enough history!".into()),
+        }
+    }
+
+    #[inline(never)]
+    fn rollback(&mut self) -> OptResult<(), Self::Error> {
+        match self.history.unpush() {
+            Some((state, scale)) => {
+                self.state = state;
+                self.scale = scale;
+                Ok(())
+            }
+            None => Err("nothing to roll back!".into()),
+        }
+    }
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::ALL
+    }
 }
@@ -2,16 +2,22 @@ use super::super::target::Target;
 
 pub struct BasicTarget {
     state: isize,
+    // `r1`.. (`r0` is `state` itself)
+    regs: [isize; 3],
 }
 
 impl BasicTarget {
     pub fn new(state: isize) -> BasicTarget {
-        BasicTarget { state }
+        BasicTarget {
+            state,
+            regs: [0; 3],
+        }
     }
 }
 
 impl Target for BasicTarget {
     type Error = &'static str;
+    type State = isize;
 
     #[inline(never)]
     fn get_state(&self) -> isize {
@@ -23,4 +29,26 @@ impl Target for BasicTarget {
         self.state = n;
         Ok(())
     }
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn num_regs(&self) -> usize {
+        4
+    }
+
+    #[inline(never)]
+    fn read_reg(&self, idx: usize) -> isize {
+        match idx {
+            0 => self.state,
+            _ => self.regs[idx - 1],
+        }
+    }
+
+    #[inline(never)]
+    fn write_reg(&mut self, idx: usize, val: isize) -> Result<(), Self::Error> {
+        match idx {
+            0 => self.state = val,
+            _ => self.regs[idx - 1] = val,
+        }
+        Ok(())
+    }
 }
@@ -1,22 +1,37 @@
 use core::num::Wrapping;
 
+use crate::capabilities::Capabilities;
+
 use super::super::target::OptResult;
 use super::super::target::Target;
 
 pub struct FaultyTarget {
     state: Wrapping<isize>,
+    // `r1`.. (`r0` is `state` itself)
+    regs: [isize; 3],
 }
 
 impl FaultyTarget {
     pub fn new(state: isize) -> FaultyTarget {
         FaultyTarget {
             state: Wrapping(state),
+            regs: [0; 3],
         }
     }
 }
 
+/// What writes to `r3` fail with: unlike `dec`'s, this target treats it as an
+/// error it can't recover from (so the session has to end).
+const READ_ONLY: &str = "`r3` is read-only";
+
 impl Target for FaultyTarget {
     type Error = &'static str;
+    type State = isize;
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn is_fatal(err: &Self::Error) -> bool {
+        *err == READ_ONLY
+    }
 
     #[inline(never)]
     fn get_state(&self) -> isize {
@@ -29,6 +44,29 @@ impl Target for FaultyTarget {
         Ok(())
     }
 
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn num_regs(&self) -> usize {
+        4
+    }
+
+    #[inline(never)]
+    fn read_reg(&self, idx: usize) -> isize {
+        match idx {
+            0 => self.state.0,
+            _ => self.regs[idx - 1],
+        }
+    }
+
+    #[inline(never)]
+    fn write_reg(&mut self, idx: usize, val: isize) -> Result<(), Self::Error> {
+        match idx {
+            0 => self.state = Wrapping(val),
+            3 => return Err(READ_ONLY),
+            _ => self.regs[idx - 1] = val,
+        }
+        Ok(())
+    }
+
     #[inline(never)]
     fn inc(&mut self) -> OptResult<(), Self::Error> {
         self.state += 1;
@@ -37,4 +75,9 @@ impl Target for FaultyTarget {
 
     // uh oh, this target forgot to implement `dec`, resulting in a runtime protocol
     // error!
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::INCDEC
+    }
 }
@@ -0,0 +1,227 @@
+//! Checks for adversarial (or just buggy) IDET implementations.
+//!
+//! Nothing stops an `ext_*` method from returning something other than `self`,
+//! or from flip-flopping between `Some` and `None`. Neither is UB, but both
+//! break the assumptions a controller makes (e.g: that `parse_command` and
+//! `handle` agree on what's supported).
+
+use core::mem::size_of;
+
+use super::target::Target;
+
+/// How many times each `ext_*` method is called.
+const ROUNDS: usize = 4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConformanceError {
+    /// `ext` returned `Some` on some calls, and `None` on others.
+    Inconsistent { ext: &'static str },
+    /// `ext` returned trait objects pointing at different addresses.
+    Unstable { ext: &'static str },
+    /// `ext` returned a trait object pointing outside of the target.
+    Foreign { ext: &'static str },
+}
+
+fn data_ptr<U: ?Sized>(ops: &mut U) -> *const () {
+    ops as *mut U as *const ()
+}
+
+fn check_ext<T>(
+    target: &mut T,
+    ext: &'static str,
+    mut probe: impl FnMut(&mut T) -> Option<*const ()>,
+) -> Result<(), ConformanceError> {
+    let first = probe(target);
+    for _ in 1..ROUNDS {
+        match (first, probe(target)) {
+            (Some(a), Some(b)) if a != b => return Err(ConformanceError::Unstable { ext }),
+            (Some(_), None) | (None, Some(_)) => {
+                return Err(ConformanceError::Inconsistent { ext });
+            }
+            _ => {}
+        }
+    }
+
+    // wrappers (e.g: `Toggleable`) may hand out one of their fields, so
+    // anything within the target's footprint counts as aliasing it (as does
+    // `start` itself, for zero-sized targets, but not `end`, which is already
+    // past it)
+    if let Some(ptr) = first {
+        let (ptr, start) = (ptr as usize, target as *mut T as usize);
+        let end = start + size_of::<T>();
+        if ptr != start && !(start..end).contains(&ptr) {
+            return Err(ConformanceError::Foreign { ext });
+        }
+    }
+
+    Ok(())
+}
+
+/// Calls every `ext_*` method on `target` several times, ensuring each one
+/// consistently returns (a reference into) the target itself, or `None`.
+///
+/// Note that a target toggling its extensions _between_ calls to this
+/// function is perfectly fine.
+pub fn check_conformance<T: Target>(target: &mut T) -> Result<(), ConformanceError> {
+    check_ext(target, "base", |t| Some(data_ptr(t.base())))?;
+    check_ext(target, "incdec", |t| t.ext_incdec().map(data_ptr))?;
+    check_ext(target, "mul", |t| t.ext_mul().map(data_ptr))?;
+    check_ext(target, "mul.scale_factor", |t| {
+        t.ext_mul()
+            .and_then(|ops| ops.ext_scale_factor())
+            .map(data_ptr)
+    })?;
+    check_ext(target, "mul.scale_factor.scale_bounds", |t| {
+        t.ext_mul()
+            .and_then(|ops| ops.ext_scale_factor())
+            .and_then(|ops| ops.ext_scale_bounds())
+            .map(data_ptr)
+    })?;
+    check_ext(target, "div", |t| t.ext_div().map(data_ptr))?;
+    check_ext(target, "undo", |t| t.ext_undo().map(data_ptr))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::boxed::Box;
+
+    use super::super::target::TargetBaseOps;
+    use super::super::target::TargetExtIncDecOps;
+    use super::super::targets::AdvancedTarget;
+    use super::super::targets::BasicTarget;
+    use super::super::targets::FaultyTarget;
+    use super::super::toggleable::Toggleable;
+    use super::*;
+    use crate::capabilities::Capabilities;
+
+    /// Support for `incdec` flips on every call.
+    struct FlakyTarget {
+        inner: FaultyTarget,
+        calls: usize,
+    }
+
+    impl Target for FlakyTarget {
+        type Error = &'static str;
+        type State = isize;
+
+        fn base(&mut self) -> TargetBaseOps<'_, Self> {
+            self.inner.base()
+        }
+
+        fn ext_incdec(&mut self) -> Option<TargetExtIncDecOps<'_, Self>> {
+            self.calls += 1;
+            if self.calls.is_multiple_of(2) {
+                return None;
+            }
+            self.inner.ext_incdec()
+        }
+    }
+
+    /// `incdec` alternates between two different targets.
+    struct ShuffledTarget {
+        inner: [FaultyTarget; 2],
+        calls: usize,
+    }
+
+    impl Target for ShuffledTarget {
+        type Error = &'static str;
+        type State = isize;
+
+        fn base(&mut self) -> TargetBaseOps<'_, Self> {
+            self.inner[0].base()
+        }
+
+        fn ext_incdec(&mut self) -> Option<TargetExtIncDecOps<'_, Self>> {
+            self.calls += 1;
+            self.inner[self.calls % 2].ext_incdec()
+        }
+    }
+
+    /// `incdec` is forwarded to a target living somewhere else entirely.
+    struct ForeignTarget {
+        inner: FaultyTarget,
+        other: Box<FaultyTarget>,
+    }
+
+    impl Target for ForeignTarget {
+        type Error = &'static str;
+        type State = isize;
+
+        fn base(&mut self) -> TargetBaseOps<'_, Self> {
+            self.inner.base()
+        }
+
+        fn ext_incdec(&mut self) -> Option<TargetExtIncDecOps<'_, Self>> {
+            self.other.ext_incdec()
+        }
+    }
+
+    #[test]
+    fn test_shipped_targets() {
+        assert_eq!(check_conformance(&mut BasicTarget::new(0)), Ok(()));
+        assert_eq!(check_conformance(&mut AdvancedTarget::new(0)), Ok(()));
+        assert_eq!(check_conformance(&mut FaultyTarget::new(0)), Ok(()));
+
+        // (wrappers handing out their fields conform too, whatever they enable)
+        let mut target = Toggleable::new(AdvancedTarget::new(0));
+        assert_eq!(check_conformance(&mut target), Ok(()));
+        target.set_enabled(Capabilities::MUL.union(Capabilities::UNDO), false);
+        assert_eq!(check_conformance(&mut target), Ok(()));
+        let mut target = Toggleable::new(FaultyTarget::new(0));
+        target.set_enabled(Capabilities::INCDEC, false);
+        assert_eq!(check_conformance(&mut target), Ok(()));
+    }
+
+    #[test]
+    fn test_footprint() {
+        let mut target = FaultyTarget::new(0);
+        let start = &mut target as *mut FaultyTarget as *const u8;
+        let at = |offset: usize| {
+            move |_: &mut FaultyTarget| Some(start.wrapping_add(offset) as *const ())
+        };
+
+        assert_eq!(check_ext(&mut target, "incdec", at(0)), Ok(()));
+        assert_eq!(
+            check_ext(&mut target, "incdec", at(size_of::<FaultyTarget>() - 1)),
+            Ok(())
+        );
+        // (one past the end is the next object's, not the target's)
+        assert_eq!(
+            check_ext(&mut target, "incdec", at(size_of::<FaultyTarget>())),
+            Err(ConformanceError::Foreign { ext: "incdec" })
+        );
+
+        // zero-sized targets live at their own address
+        let mut unit = ();
+        let start = &mut unit as *mut () as *const ();
+        assert_eq!(check_ext(&mut unit, "incdec", |_| Some(start)), Ok(()));
+    }
+
+    #[test]
+    fn test_violations() {
+        let honest = || FaultyTarget::new(0);
+
+        assert_eq!(
+            check_conformance(&mut FlakyTarget {
+                inner: honest(),
+                calls: 0
+            }),
+            Err(ConformanceError::Inconsistent { ext: "incdec" })
+        );
+        assert_eq!(
+            check_conformance(&mut ShuffledTarget {
+                inner: [honest(), honest()],
+                calls: 0
+            }),
+            Err(ConformanceError::Unstable { ext: "incdec" })
+        );
+        assert_eq!(
+            check_conformance(&mut ForeignTarget {
+                inner: honest(),
+                other: Box::new(honest())
+            }),
+            Err(ConformanceError::Foreign { ext: "incdec" })
+        );
+    }
+}
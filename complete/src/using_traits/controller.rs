@@ -1,12 +1,21 @@
+use crate::capabilities::Capabilities;
 use crate::commands::Command;
+use crate::commands::ParseError;
 use crate::commands::ext;
-use crate::commands::parse_isize;
+use crate::commands::strip_verb;
+use crate::commands::unrecognized;
+use crate::num::Num;
+use crate::registers::Registers;
+use crate::response;
+use crate::response::Response;
+use crate::state_machine;
+use crate::writer::ResponseWriter;
 
+use super::polyfill;
+use super::polyfill::POLYFILLS;
 use super::target::Target;
 
-pub enum Error<E> {
-    Target(E),
-}
+pub use crate::error::Error;
 
 pub struct TargetController<T: Target> {
     target: T,
@@ -17,9 +26,29 @@ impl<T: Target> TargetController<T> {
         TargetController { target }
     }
 
-    pub fn unsupported_cmd(&self) -> Result<(), Error<T::Error>> {
-        crate::println_str!("Unsupported cmd!");
-        Ok(())
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    pub fn target_mut(&mut self) -> &mut T {
+        &mut self.target
+    }
+
+    /// The extensions supported by the target, or polyfilled for it (i.e: what
+    /// `?` reports).
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    pub fn capabilities(&mut self) -> Capabilities {
+        self.target.capabilities().union(POLYFILLS)
+    }
+
+    pub fn unsupported_cmd(&self) -> Result<Response<T::State>, Error<T::Error>> {
+        Err(Error::Unsupported)
+    }
+
+    /// Classifies an error returned by the target (see `Target::is_fatal`).
+    fn target_error(err: T::Error) -> Error<T::Error> {
+        let fatal = T::is_fatal(&err);
+        Error::target(err, fatal)
     }
 
     // NOTE: `#[inline(never)]` is used here specifically for pedagogical/assembly
@@ -32,26 +61,27 @@ impl<T: Target> TargetController<T> {
     // to inline `parse_command` into `run()`, eliminating `Command` enum
     // discriminant overhead across the parsing and handling pipeline.
     #[cfg_attr(feature = "interpretable_asm", inline(never))]
-    pub fn parse_command(&mut self, buf: &[u8]) -> Option<Command> {
+    pub fn parse_command(&mut self, buf: &[u8]) -> Result<Command<T::State>, ParseError> {
         /* IncDec extension parsing */
-        if self.target.ext_incdec().is_some() {
+        if self.target.ext_incdec().is_some() || POLYFILLS.contains(Capabilities::INCDEC) {
             crate::__dead_code_marker!("Parse IncDec extension");
-            if buf == b"+" {
-                return Some(Command::IncDec(ext::IncDecCommand::Inc));
+            if let Some(args) = strip_verb(buf, b"+") {
+                return args.finish(Command::IncDec(ext::IncDecCommand::Inc));
             }
-            if buf == b"-" {
-                return Some(Command::IncDec(ext::IncDecCommand::Dec));
+            if let Some(args) = strip_verb(buf, b"-") {
+                return args.finish(Command::IncDec(ext::IncDecCommand::Dec));
             }
-            if buf == b"+-" {
-                return Some(Command::IncDec(ext::IncDecCommand::IncDec));
+            if let Some(args) = strip_verb(buf, b"+-") {
+                return args.finish(Command::IncDec(ext::IncDecCommand::IncDec));
             }
         }
 
         /* Mul extension parsing */
-        if self.target.ext_mul().is_some() {
+        if self.target.ext_mul().is_some() || POLYFILLS.contains(Capabilities::MUL) {
             crate::__dead_code_marker!("Parse Mul extension");
-            if let Some(n) = buf.strip_prefix(b"* ").and_then(parse_isize) {
-                return Some(Command::Mul(ext::MulCommand::Mul(n)));
+            if let Some(mut args) = strip_verb(buf, b"*") {
+                let n = args.num()?;
+                return args.finish(Command::Mul(ext::MulCommand::Mul(n)));
             }
         }
 
@@ -63,20 +93,83 @@ impl<T: Target> TargetController<T> {
             .is_some()
         {
             crate::__dead_code_marker!("Parse ScaleFactor extension");
-            if let Some(n) = buf.strip_prefix(b"*~ ").and_then(parse_isize) {
-                return Some(Command::Mul(ext::MulCommand::ScaleFactor(n)));
+            if let Some(mut args) = strip_verb(buf, b"*~") {
+                let n = args.num()?;
+                return args.finish(Command::Mul(ext::MulCommand::ScaleFactor(n)));
+            }
+        }
+
+        /* ScaleBounds nested extension parsing */
+        if self
+            .target
+            .ext_mul()
+            .and_then(|ops| ops.ext_scale_factor())
+            .and_then(|ops| ops.ext_scale_bounds())
+            .is_some()
+        {
+            crate::__dead_code_marker!("Parse ScaleBounds extension");
+            if let Some(mut args) = strip_verb(buf, b"*~~") {
+                let (min, max) = (args.num()?, args.num()?);
+                return args.finish(Command::Mul(ext::MulCommand::ScaleBounds(min, max)));
+            }
+        }
+
+        /* Div extension parsing */
+        if self.target.ext_div().is_some() {
+            crate::__dead_code_marker!("Parse Div extension");
+            if let Some(mut args) = strip_verb(buf, b"/") {
+                let n = args.num()?;
+                return args.finish(Command::Div(ext::DivCommand::Div(n)));
+            }
+            if let Some(mut args) = strip_verb(buf, b"%") {
+                let n = args.num()?;
+                return args.finish(Command::Div(ext::DivCommand::Rem(n)));
+            }
+        }
+
+        /* Undo extension parsing */
+        if self.target.ext_undo().is_some() {
+            crate::__dead_code_marker!("Parse Undo extension");
+            if let Some(mut args) = strip_verb(buf, b"u") {
+                let n = args.num_or(1)?;
+                return args.finish(Command::Undo(ext::UndoCommand::Undo(n)));
             }
         }
 
         /* Base protocol parsing */
-        if buf == b"p" {
-            return Some(Command::Base(ext::BaseCommand::PrintState));
+        if let Some(args) = strip_verb(buf, b"p") {
+            return args.finish(Command::Base(ext::BaseCommand::PrintState));
         }
-        if let Some(n) = buf.strip_prefix(b"s ").and_then(parse_isize) {
-            return Some(Command::Base(ext::BaseCommand::SetState(n)));
+        if let Some(mut args) = strip_verb(buf, b"s") {
+            let n = args.num()?;
+            return args.finish(Command::Base(ext::BaseCommand::SetState(n)));
+        }
+        let num_regs = self.target.base().num_regs();
+        if let Some(mut args) = strip_verb(buf, b"r") {
+            let idx = args.reg(num_regs)?;
+            return args.finish(Command::Base(ext::BaseCommand::ReadReg(idx)));
+        }
+        if let Some(mut args) = strip_verb(buf, b"w") {
+            let (idx, val) = (args.reg(num_regs)?, args.num()?);
+            return args.finish(Command::Base(ext::BaseCommand::WriteReg(idx, val)));
+        }
+        if let Some(args) = strip_verb(buf, b"g") {
+            return args.finish(Command::Base(ext::BaseCommand::ReadRegs));
+        }
+        if let Some(mut args) = strip_verb(buf, b"G") {
+            let regs = args.regs(num_regs)?;
+            return args.finish(Command::Base(ext::BaseCommand::WriteRegs(regs)));
+        }
+        if let Some(mut args) = strip_verb(buf, b"?") {
+            let client = if args.is_empty() {
+                None
+            } else {
+                Some(args.names()?)
+            };
+            return args.finish(Command::Base(ext::BaseCommand::QuerySupported(client)));
         }
 
-        None
+        Err(unrecognized(buf))
     }
 
     // NOTE: `#[inline(never)]` is used here specifically for pedagogical/assembly
@@ -86,15 +179,78 @@ impl<T: Target> TargetController<T> {
     // Monomorphization still inlines target capability checks into this function,
     // preserving dead-code elimination of unsupported extension handlers.
     #[cfg_attr(feature = "interpretable_asm", inline(never))]
-    pub fn handle(&mut self, cmd: &Command) -> Result<(), Error<T::Error>> {
+    pub fn handle<W: ResponseWriter>(
+        &mut self,
+        out: &mut W,
+        cmd: &Command<T::State>,
+    ) -> Result<Response<T::State>, Error<T::Error>> {
+        response::reply(out, self.execute(cmd))
+    }
+
+    /// Carries out `cmd`, leaving the reply to `handle`.
+    ///
+    /// Mutations are checkpointed first, but only if the target supports `u`.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn execute(&mut self, cmd: &Command<T::State>) -> Result<Response<T::State>, Error<T::Error>> {
+        if cmd.is_undoable()
+            && let Some(ops) = self.target.ext_undo()
+        {
+            crate::__dead_code_marker!("Undo checkpoint");
+            ops.checkpoint().map_err(Self::target_error)?;
+            let res = self.apply(cmd);
+            // (a failed command leaves nothing behind to undo, nor pushes the oldest
+            // snapshot out of a full history)
+            if res.is_err()
+                && let Some(ops) = self.target.ext_undo()
+            {
+                ops.rollback().map_err(Self::target_error)?;
+            }
+            return res;
+        }
+        self.apply(cmd)
+    }
+
+    /// Reads every register (for `g`, or to put them back after a failed `G`).
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn read_regs(&mut self) -> Registers<T::State> {
+        let mut regs = Registers::new();
+        for idx in 0..self.target.base().num_regs() {
+            regs.push(self.target.base().read_reg(idx));
+        }
+        regs
+    }
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn apply(&mut self, cmd: &Command<T::State>) -> Result<Response<T::State>, Error<T::Error>> {
         match cmd {
             /* Base protocol */
             Command::Base(base_cmd) => match base_cmd {
                 ext::BaseCommand::PrintState => {
-                    crate::println_isize!(self.target.base().get_state())
+                    return Ok(Response::Value(self.target.base().get_state()));
+                }
+                ext::BaseCommand::SetState(n) => self
+                    .target
+                    .base()
+                    .set_state(*n)
+                    .map_err(Self::target_error)?,
+                ext::BaseCommand::QuerySupported(client) => {
+                    return Ok(Response::Supported(self.capabilities().negotiate(*client)));
+                }
+                ext::BaseCommand::ReadReg(idx) => {
+                    return Ok(Response::Value(self.target.base().read_reg(*idx)));
+                }
+                ext::BaseCommand::WriteReg(idx, val) => self
+                    .target
+                    .base()
+                    .write_reg(*idx, *val)
+                    .map_err(Self::target_error)?,
+                ext::BaseCommand::ReadRegs => {
+                    return Ok(Response::Registers(self.read_regs()));
                 }
-                ext::BaseCommand::SetState(n) => {
-                    self.target.base().set_state(*n).map_err(Error::Target)?
+                ext::BaseCommand::WriteRegs(regs) => {
+                    let prev = self.read_regs();
+                    regs.write_all(&prev, |idx, val| self.target.base().write_reg(idx, val))
+                        .map_err(Self::target_error)?;
                 }
             },
 
@@ -103,15 +259,30 @@ impl<T: Target> TargetController<T> {
                 if let Some(ops) = self.target.ext_incdec() {
                     crate::__dead_code_marker!("IncDec extension");
                     match incdec_cmd {
-                        ext::IncDecCommand::Inc => ops.inc().map_err(Error::Target)?,
-                        ext::IncDecCommand::Dec => ops.dec().map_err(Error::Target)?,
+                        ext::IncDecCommand::Inc => ops.inc().map_err(Self::target_error)?,
+                        ext::IncDecCommand::Dec => ops.dec().map_err(Self::target_error)?,
+                        ext::IncDecCommand::IncDec => {
+                            ops.inc().map_err(Self::target_error)?;
+                            ops.dec().map_err(Self::target_error)?;
+                        }
+                    }
+                } else if POLYFILLS.contains(Capabilities::INCDEC) {
+                    crate::__dead_code_marker!("IncDec polyfill");
+                    let base = self.target.base();
+                    match incdec_cmd {
+                        ext::IncDecCommand::Inc => {
+                            polyfill::add(base, T::State::ONE).map_err(Self::target_error)?
+                        }
+                        ext::IncDecCommand::Dec => {
+                            polyfill::sub(base, T::State::ONE).map_err(Self::target_error)?
+                        }
                         ext::IncDecCommand::IncDec => {
-                            ops.inc().map_err(Error::Target)?;
-                            ops.dec().map_err(Error::Target)?;
+                            polyfill::add(base, T::State::ONE).map_err(Self::target_error)?;
+                            polyfill::sub(base, T::State::ONE).map_err(Self::target_error)?;
                         }
                     }
                 } else {
-                    self.unsupported_cmd()?;
+                    return self.unsupported_cmd();
                 }
             }
 
@@ -120,9 +291,12 @@ impl<T: Target> TargetController<T> {
                 ext::MulCommand::Mul(n) => {
                     if let Some(ops) = self.target.ext_mul() {
                         crate::__dead_code_marker!("Mul extension");
-                        ops.mul(*n).map_err(Error::Target)?;
+                        ops.mul(*n).map_err(Self::target_error)?;
+                    } else if POLYFILLS.contains(Capabilities::MUL) {
+                        crate::__dead_code_marker!("Mul polyfill");
+                        polyfill::mul(self.target.base(), *n).map_err(Self::target_error)?;
                     } else {
-                        self.unsupported_cmd()?;
+                        return self.unsupported_cmd();
                     }
                 }
                 ext::MulCommand::ScaleFactor(n) => {
@@ -130,14 +304,254 @@ impl<T: Target> TargetController<T> {
                         self.target.ext_mul().and_then(|ops| ops.ext_scale_factor())
                     {
                         crate::__dead_code_marker!("ScaleFactor nested extension");
-                        scale_ops.scale_factor(*n).map_err(Error::Target)?;
+                        scale_ops.scale_factor(*n).map_err(Self::target_error)?;
                     } else {
-                        self.unsupported_cmd()?;
+                        return self.unsupported_cmd();
+                    }
+                }
+                ext::MulCommand::ScaleBounds(min, max) => {
+                    if let Some(bounds_ops) = self
+                        .target
+                        .ext_mul()
+                        .and_then(|ops| ops.ext_scale_factor())
+                        .and_then(|ops| ops.ext_scale_bounds())
+                    {
+                        crate::__dead_code_marker!("ScaleBounds nested extension");
+                        bounds_ops
+                            .scale_bounds(*min, *max)
+                            .map_err(Self::target_error)?;
+                    } else {
+                        return self.unsupported_cmd();
                     }
                 }
             },
+
+            /* Div extension */
+            Command::Div(div_cmd) => {
+                if let Some(ops) = self.target.ext_div() {
+                    crate::__dead_code_marker!("Div extension");
+                    match div_cmd {
+                        ext::DivCommand::Div(n) => ops.div(*n).map_err(Self::target_error)?,
+                        ext::DivCommand::Rem(n) => ops.rem(*n).map_err(Self::target_error)?,
+                    }
+                } else {
+                    return self.unsupported_cmd();
+                }
+            }
+
+            /* Undo extension */
+            Command::Undo(ext::UndoCommand::Undo(n)) => {
+                if let Some(ops) = self.target.ext_undo() {
+                    crate::__dead_code_marker!("Undo extension");
+                    ops.undo(*n).map_err(Self::target_error)?;
+                } else {
+                    return self.unsupported_cmd();
+                }
+            }
+        }
+
+        Ok(Response::Ok)
+    }
+}
+
+impl<T: Target> state_machine::Controller for TargetController<T> {
+    type State = T::State;
+    type Error = T::Error;
+    type Command = Command<T::State>;
+
+    fn capabilities(&mut self) -> Capabilities {
+        TargetController::capabilities(self)
+    }
+
+    fn parse_command(&mut self, line: &[u8]) -> Result<Command<T::State>, ParseError> {
+        TargetController::parse_command(self, line)
+    }
+
+    fn handle<W: ResponseWriter>(
+        &mut self,
+        out: &mut W,
+        cmd: &Command<T::State>,
+    ) -> Result<Response<T::State>, Error<T::Error>> {
+        TargetController::handle(self, out, cmd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::num::AdvancedState;
+    use crate::using_traits::targets::AdvancedTarget;
+    use crate::using_traits::targets::BasicTarget;
+    use crate::using_traits::targets::FaultyTarget;
+    use crate::writer::Capture;
+
+    fn run<T: Target>(
+        controller: &mut TargetController<T>,
+        line: &[u8],
+    ) -> Result<Response<T::State>, Error<T::Error>> {
+        let mut out = Capture::<64>::new();
+        let cmd = controller.parse_command(line)?;
+        controller.handle(&mut out, &cmd)
+    }
+
+    /// What the controller replies to `line`.
+    fn reply<T: Target>(controller: &mut TargetController<T>, line: &[u8]) -> String {
+        let mut out = Capture::<64>::new();
+        let _ = match controller.parse_command(line) {
+            Ok(cmd) => controller.handle(&mut out, &cmd),
+            Err(err) => response::reply(&mut out, Err(err.into())),
+        };
+        String::from_utf8(out.output().to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_failed_write_regs() {
+        let mut controller = TargetController::new(FaultyTarget::new(7));
+        assert_eq!(run(&mut controller, b"w 1 8"), Ok(Response::Ok));
+
+        // `r3` is read-only, so none of the registers may change
+        let hex: String = [1isize, 2, 3, 4]
+            .iter()
+            .flat_map(|reg| reg.to_le_bytes())
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        assert_eq!(
+            run(&mut controller, format!("G {}", hex).as_bytes()),
+            Err(Error::Fatal("`r3` is read-only"))
+        );
+        let Ok(Response::Registers(regs)) = run(&mut controller, b"g") else {
+            panic!("expected a register dump");
+        };
+        assert_eq!(regs.as_slice(), [7, 8, 0, 0]);
+    }
+
+    #[test]
+    fn test_div() {
+        let mut controller = TargetController::new(AdvancedTarget::new(-7));
+        assert_eq!(run(&mut controller, b"% 4"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(-3)));
+        assert_eq!(
+            run(&mut controller, b"/ 0"),
+            Err(Error::NonFatal("dividing by zero!"))
+        );
+        assert_eq!(
+            run(&mut controller, b"% 0"),
+            Err(Error::NonFatal("dividing by zero!"))
+        );
+
+        // (neither `MIN / -1` nor `MIN % -1` fit in the state)
+        let min = format!("s {}", AdvancedState::MIN);
+        assert_eq!(run(&mut controller, min.as_bytes()), Ok(Response::Ok));
+        assert_eq!(
+            run(&mut controller, b"/ -1"),
+            Err(Error::NonFatal("dividing MIN by -1 overflows!"))
+        );
+        assert_eq!(
+            run(&mut controller, b"% -1"),
+            Err(Error::NonFatal("dividing MIN by -1 overflows!"))
+        );
+        assert_eq!(run(&mut controller, b"/ 2"), Ok(Response::Ok));
+        assert_eq!(
+            run(&mut controller, b"p"),
+            Ok(Response::Value(AdvancedState::MIN / 2))
+        );
+    }
+
+    #[test]
+    fn test_fatal_errors() {
+        let mut controller = TargetController::new(FaultyTarget::new(isize::MAX));
+        assert_eq!(run(&mut controller, b"+"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(isize::MIN)));
+        assert_eq!(
+            run(&mut controller, b"-"),
+            Err(Error::NonFatal("`dec` operations are not supported yet"))
+        );
+
+        // `FaultyTarget` can't recover from a write to its read-only `r3`
+        let err = run(&mut controller, b"w 3 1").unwrap_err();
+        assert_eq!(err, Error::Fatal("`r3` is read-only"));
+        assert!(err.is_fatal());
+    }
+
+    #[test]
+    fn test_undo() {
+        let mut controller = TargetController::new(AdvancedTarget::new(0));
+        assert_eq!(run(&mut controller, b"s 5"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"+"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"* 2"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"u"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(6)));
+
+        // a failed command is rolled back, leaving nothing behind to undo...
+        assert_eq!(
+            run(&mut controller, b"/ 0"),
+            Err(Error::NonFatal("dividing by zero!"))
+        );
+        assert_eq!(run(&mut controller, b"u 2"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(0)));
+
+        // ...even once the history is full (`AdvancedTarget` keeps 16 snapshots)
+        for _ in 0..16 {
+            assert_eq!(run(&mut controller, b"+"), Ok(Response::Ok));
         }
+        assert_eq!(
+            run(&mut controller, b"/ 0"),
+            Err(Error::NonFatal("dividing by zero!"))
+        );
+        assert_eq!(run(&mut controller, b"u 16"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(0)));
+
+        // ...and neither does anything that isn't a mutation
+        assert_eq!(run(&mut controller, b"w 1 4"), Ok(Response::Ok));
+        assert_eq!(
+            run(&mut controller, b"u"),
+            Err(Error::NonFatal("nothing to undo!"))
+        );
+
+        // asking for more than the history holds undoes nothing at all
+        assert_eq!(run(&mut controller, b"s 1"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"s 2"), Ok(Response::Ok));
+        assert_eq!(
+            run(&mut controller, b"u 3"),
+            Err(Error::NonFatal("not enough history!"))
+        );
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(2)));
+        assert_eq!(run(&mut controller, b"u 2"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(0)));
+    }
+
+    #[test]
+    fn test_polyfills() {
+        let incdec = POLYFILLS.contains(Capabilities::INCDEC);
+        let mul = POLYFILLS.contains(Capabilities::MUL);
+        let ok_if = |polyfilled| if polyfilled { "OK\n" } else { "\n" };
+
+        // `BasicTarget` has neither `incdec` nor `mul`, so it supports exactly
+        // what's polyfilled
+        let mut controller = TargetController::new(BasicTarget::new(3));
+        assert_eq!(controller.capabilities(), POLYFILLS);
+        assert_eq!(reply(&mut controller, b"+"), ok_if(incdec));
+        assert_eq!(reply(&mut controller, b"* -5"), ok_if(mul));
+        let state = (3 + incdec as isize) * if mul { -5 } else { 1 };
+        assert_eq!(reply(&mut controller, b"p"), format!("{}\n", state));
+
+        // native implementations always win over the polyfills: `FaultyTarget`'s
+        // `dec` still fails...
+        let mut controller = TargetController::new(FaultyTarget::new(3));
+        assert_eq!(
+            run(&mut controller, b"-"),
+            Err(Error::NonFatal("`dec` operations are not supported yet"))
+        );
 
-        Ok(())
+        // ...and `AdvancedTarget`'s `mul` still applies the scale factor, and
+        // still refuses to multiply by 7
+        let mut controller = TargetController::new(AdvancedTarget::new(3));
+        assert_eq!(reply(&mut controller, b"*~ 2"), "OK\n");
+        assert_eq!(reply(&mut controller, b"* 5"), "OK\n");
+        assert_eq!(reply(&mut controller, b"p"), "30\n");
+        assert_eq!(
+            run(&mut controller, b"* 7"),
+            Err(Error::NonFatal("multiplying by 7 is unlucky!"))
+        );
     }
 }
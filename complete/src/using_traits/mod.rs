@@ -1,5 +1,9 @@
 #![allow(dead_code)]
 
+#[cfg(any(debug_assertions, feature = "conformance"))]
+pub mod conformance;
 pub mod controller;
+pub mod polyfill;
 pub mod target;
 pub mod targets;
+pub mod toggleable;
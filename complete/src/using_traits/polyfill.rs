@@ -0,0 +1,60 @@
+//! Emulates missing extensions using nothing but `TargetBase`.
+//!
+//! Each polyfill is opt-in via its own `polyfill_*` feature, and is only ever
+//! used when the target doesn't support the extension natively.
+
+use crate::capabilities::Capabilities;
+use crate::num::Num;
+
+use super::target::TargetBase;
+
+/// Extensions the controller emulates when the target doesn't support them.
+///
+/// (`mul.scale_factor` can't be polyfilled, as the scale factor is state that
+/// `TargetBase` knows nothing about)
+pub const POLYFILLS: Capabilities = Capabilities::NONE
+    .with(Capabilities::INCDEC, cfg!(feature = "polyfill_incdec"))
+    .with(Capabilities::MUL, cfg!(feature = "polyfill_mul"));
+
+pub fn add<E, S: Num>(base: &mut dyn TargetBase<Error = E, State = S>, n: S) -> Result<(), E> {
+    let state = base.get_state();
+    base.set_state(state.wrapping_add(n))
+}
+
+pub fn sub<E, S: Num>(base: &mut dyn TargetBase<Error = E, State = S>, n: S) -> Result<(), E> {
+    let state = base.get_state();
+    base.set_state(state.wrapping_sub(n))
+}
+
+pub fn mul<E, S: Num>(base: &mut dyn TargetBase<Error = E, State = S>, n: S) -> Result<(), E> {
+    let state = base.get_state();
+    base.set_state(state.wrapping_mul(n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::target::Target;
+    use super::super::targets::BasicTarget;
+    use super::*;
+
+    #[test]
+    fn test_polyfills() {
+        let mut target = BasicTarget::new(3);
+
+        add(target.base(), 1).unwrap();
+        assert_eq!(target.base().get_state(), 4);
+        sub(target.base(), 1).unwrap();
+        assert_eq!(target.base().get_state(), 3);
+        mul(target.base(), -5).unwrap();
+        assert_eq!(target.base().get_state(), -15);
+
+        // wraps, just like the native `AdvancedTarget` implementations
+        target.base().set_state(isize::MAX).unwrap();
+        add(target.base(), 1).unwrap();
+        assert_eq!(target.base().get_state(), isize::MIN);
+        mul(target.base(), 2).unwrap();
+        assert_eq!(target.base().get_state(), 0);
+        sub(target.base(), 1).unwrap();
+        assert_eq!(target.base().get_state(), -1);
+    }
+}
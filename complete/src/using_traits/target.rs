@@ -1,50 +1,91 @@
+use optional_trait_methods_macros::optional_trait_methods;
+
+use crate::capabilities::Capabilities;
+use crate::num::Num;
+
+// Expands into `Target`, `TargetBase`, `TargetExtIncDec`, `TargetExtMul`,
+// `TargetExtScaleFactor`, `TargetExtScaleBounds`, `TargetExtDiv`,
+// `TargetExtUndo`, and their corresponding `*Ops` aliases. See
+// `macros/src/lib.rs` for the exact rules.
+#[optional_trait_methods(ext_attr(cfg_attr(feature = "always_inline", inline(always))))]
 pub trait Target {
     type Error;
+    /// The target's state (and register) width.
+    type State: Num;
 
-    fn base(&mut self) -> TargetBaseOps<'_, Self>;
+    fn get_state(&self) -> Self::State;
+    fn set_state(&mut self, n: Self::State) -> Result<(), Self::Error>;
 
+    /// The size of the register file (at most `registers::MAX_REGS`), where
+    /// `r0` is the state itself.
+    fn num_regs(&self) -> usize;
+    fn read_reg(&self, idx: usize) -> Self::State;
+    fn write_reg(&mut self, idx: usize, val: Self::State) -> Result<(), Self::Error>;
+
+    /// Whether `err` leaves the target unusable, ending the session.
+    ///
+    /// By default, every error is non-fatal: the controller replies with an
+    /// error code, and keeps serving.
     #[cfg_attr(feature = "always_inline", inline(always))]
-    fn ext_incdec(&mut self) -> Option<TargetExtIncDecOps<'_, Self>> {
-        None
+    fn is_fatal(err: &Self::Error) -> bool
+    where
+        Self: Sized,
+    {
+        let _ = err;
+        false
     }
 
+    /// Report every extension supported by the target.
     #[cfg_attr(feature = "always_inline", inline(always))]
-    fn ext_mul(&mut self) -> Option<TargetExtMulOps<'_, Self>> {
-        None
+    fn capabilities(&mut self) -> Capabilities {
+        Capabilities::NONE
+            .with(Capabilities::INCDEC, self.ext_incdec().is_some())
+            .with(Capabilities::MUL, self.ext_mul().is_some())
+            .with(
+                Capabilities::SCALE_FACTOR,
+                self.ext_mul()
+                    .and_then(|ops| ops.ext_scale_factor())
+                    .is_some(),
+            )
+            .with(
+                Capabilities::SCALE_BOUNDS,
+                self.ext_mul()
+                    .and_then(|ops| ops.ext_scale_factor())
+                    .and_then(|ops| ops.ext_scale_bounds())
+                    .is_some(),
+            )
+            .with(Capabilities::DIV, self.ext_div().is_some())
+            .with(Capabilities::UNDO, self.ext_undo().is_some())
     }
-}
 
-pub trait TargetBase: Target {
-    fn get_state(&self) -> isize;
-    fn set_state(&mut self, n: isize) -> Result<(), Self::Error>;
-}
-
-pub trait TargetExtIncDec: Target {
+    #[optional(group = "incdec", name = "IncDec")]
     fn inc(&mut self) -> Result<(), Self::Error>;
+    #[optional(group = "incdec")]
     fn dec(&mut self) -> Result<(), Self::Error>;
-}
 
-pub trait TargetExtMul: Target {
-    fn mul(&mut self, n: isize) -> Result<(), Self::Error>;
+    #[optional(group = "mul")]
+    fn mul(&mut self, n: Self::State) -> Result<(), Self::Error>;
 
-    #[cfg_attr(feature = "always_inline", inline(always))]
-    fn ext_scale_factor(&mut self) -> Option<TargetExtScaleFactorOps<'_, Self>> {
-        None
-    }
-}
+    #[optional(group = "mul.scale_factor")]
+    fn scale_factor(&mut self, factor: Self::State) -> Result<(), Self::Error>;
 
-pub trait TargetExtScaleFactor: Target {
-    fn scale_factor(&mut self, factor: isize) -> Result<(), Self::Error>;
-}
+    #[optional(group = "mul.scale_factor.scale_bounds")]
+    fn scale_bounds(&mut self, min: Self::State, max: Self::State) -> Result<(), Self::Error>;
 
-macro_rules! define_ops {
-    ($exttrait:ident -> $extname:ident) => {
-        #[allow(missing_docs)]
-        pub type $extname<'a, T> = &'a mut dyn $exttrait<Error = <T as Target>::Error>;
-    };
-}
+    #[optional(group = "div")]
+    fn div(&mut self, n: Self::State) -> Result<(), Self::Error>;
+    #[optional(group = "div")]
+    fn rem(&mut self, n: Self::State) -> Result<(), Self::Error>;
 
-define_ops!(TargetBase -> TargetBaseOps);
-define_ops!(TargetExtIncDec -> TargetExtIncDecOps);
-define_ops!(TargetExtMul -> TargetExtMulOps);
-define_ops!(TargetExtScaleFactor -> TargetExtScaleFactorOps);
+    /// Snapshots whatever the next mutation may change (see
+    /// `Command::is_undoable`).
+    #[optional(group = "undo")]
+    fn checkpoint(&mut self) -> Result<(), Self::Error>;
+    /// Reverts the last `n` checkpointed mutations.
+    #[optional(group = "undo")]
+    fn undo(&mut self, n: usize) -> Result<(), Self::Error>;
+    /// Reverts the last checkpoint, and forgets it as if it had never been
+    /// taken (e.g: once the mutation it was taken for has failed).
+    #[optional(group = "undo")]
+    fn rollback(&mut self) -> Result<(), Self::Error>;
+}
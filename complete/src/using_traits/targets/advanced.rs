@@ -1,57 +1,105 @@
 use core::num::Wrapping;
 
+use optional_trait_methods_macros::idet;
+
+use crate::history::History;
+use crate::num::AdvancedState;
+
 use super::super::target::Target;
 use super::super::target::TargetBase;
 use super::super::target::TargetBaseOps;
+use super::super::target::TargetExtDiv;
+use super::super::target::TargetExtDivListed;
+use super::super::target::TargetExtDivOps;
 use super::super::target::TargetExtIncDec;
+use super::super::target::TargetExtIncDecListed;
 use super::super::target::TargetExtIncDecOps;
 use super::super::target::TargetExtMul;
+use super::super::target::TargetExtMulListed;
 use super::super::target::TargetExtMulOps;
+use super::super::target::TargetExtScaleBounds;
+use super::super::target::TargetExtScaleBoundsListed;
+use super::super::target::TargetExtScaleBoundsOps;
 use super::super::target::TargetExtScaleFactor;
+use super::super::target::TargetExtScaleFactorListed;
 use super::super::target::TargetExtScaleFactorOps;
+use super::super::target::TargetExtUndo;
+use super::super::target::TargetExtUndoListed;
+use super::super::target::TargetExtUndoOps;
+
+/// How many mutations `u` can revert.
+const UNDO_DEPTH: usize = 16;
 
 pub struct AdvancedTarget {
-    state: Wrapping<isize>,
-    scale: Wrapping<isize>,
+    state: Wrapping<AdvancedState>,
+    // `r1`.. (`r0` is `state` itself)
+    regs: [AdvancedState; 7],
+    scale: Wrapping<AdvancedState>,
+    scale_bounds: (AdvancedState, AdvancedState),
+    // `(state, scale)` before each of the most recent mutations
+    history: History<(Wrapping<AdvancedState>, Wrapping<AdvancedState>), UNDO_DEPTH>,
 }
 
 impl AdvancedTarget {
-    pub fn new(state: isize) -> AdvancedTarget {
+    pub fn new(state: AdvancedState) -> AdvancedTarget {
         AdvancedTarget {
             state: Wrapping(state),
+            regs: [0; 7],
             scale: Wrapping(1),
+            scale_bounds: (AdvancedState::MIN, AdvancedState::MAX),
+            history: History::new(),
         }
     }
 }
 
+#[idet(
+    ext_attr(cfg_attr(feature = "always_inline", inline(always))),
+    incdec = IncDec,
+    mul,
+    div,
+    undo,
+)]
 impl Target for AdvancedTarget {
     type Error = &'static str;
+    type State = AdvancedState;
 
     #[cfg_attr(feature = "always_inline", inline(always))]
     fn base(&mut self) -> TargetBaseOps<'_, Self> {
         self
     }
+}
 
-    #[cfg_attr(feature = "always_inline", inline(always))]
-    fn ext_incdec(&mut self) -> Option<TargetExtIncDecOps<'_, Self>> {
-        Some(self)
+impl TargetBase for AdvancedTarget {
+    #[inline(never)]
+    fn get_state(&self) -> AdvancedState {
+        self.state.0
+    }
+
+    #[inline(never)]
+    fn set_state(&mut self, n: AdvancedState) -> Result<(), Self::Error> {
+        self.state = Wrapping(n);
+        Ok(())
     }
 
     #[cfg_attr(feature = "always_inline", inline(always))]
-    fn ext_mul(&mut self) -> Option<TargetExtMulOps<'_, Self>> {
-        Some(self)
+    fn num_regs(&self) -> usize {
+        8
     }
-}
 
-impl TargetBase for AdvancedTarget {
     #[inline(never)]
-    fn get_state(&self) -> isize {
-        self.state.0
+    fn read_reg(&self, idx: usize) -> AdvancedState {
+        match idx {
+            0 => self.state.0,
+            _ => self.regs[idx - 1],
+        }
     }
 
     #[inline(never)]
-    fn set_state(&mut self, n: isize) -> Result<(), Self::Error> {
-        self.state = Wrapping(n);
+    fn write_reg(&mut self, idx: usize, val: AdvancedState) -> Result<(), Self::Error> {
+        match idx {
+            0 => self.state = Wrapping(val),
+            _ => self.regs[idx - 1] = val,
+        }
         Ok(())
     }
 }
@@ -70,9 +118,13 @@ impl TargetExtIncDec for AdvancedTarget {
     }
 }
 
+#[idet(
+    ext_attr(cfg_attr(feature = "always_inline", inline(always))),
+    scale_factor
+)]
 impl TargetExtMul for AdvancedTarget {
     #[inline(never)]
-    fn mul(&mut self, n: isize) -> Result<(), Self::Error> {
+    fn mul(&mut self, n: AdvancedState) -> Result<(), Self::Error> {
         match n {
             7 => Err("multiplying by 7 is unlucky!"),
             _ => {
@@ -81,17 +133,92 @@ impl TargetExtMul for AdvancedTarget {
             }
         }
     }
+}
 
-    #[cfg_attr(feature = "always_inline", inline(always))]
-    fn ext_scale_factor(&mut self) -> Option<TargetExtScaleFactorOps<'_, Self>> {
-        Some(self)
+#[idet(
+    ext_attr(cfg_attr(feature = "always_inline", inline(always))),
+    scale_bounds
+)]
+impl TargetExtScaleFactor for AdvancedTarget {
+    #[inline(never)]
+    fn scale_factor(&mut self, factor: AdvancedState) -> Result<(), Self::Error> {
+        let (min, max) = self.scale_bounds;
+        self.scale = Wrapping(factor.max(min).min(max));
+        Ok(())
     }
 }
 
-impl TargetExtScaleFactor for AdvancedTarget {
+impl TargetExtScaleBounds for AdvancedTarget {
+    #[inline(never)]
+    fn scale_bounds(&mut self, min: AdvancedState, max: AdvancedState) -> Result<(), Self::Error> {
+        if min > max {
+            return Err("scale bounds are inverted!");
+        }
+        self.scale_bounds = (min, max);
+        self.scale = Wrapping(self.scale.0.max(min).min(max));
+        Ok(())
+    }
+}
+
+impl TargetExtDiv for AdvancedTarget {
+    #[inline(never)]
+    fn div(&mut self, n: AdvancedState) -> Result<(), Self::Error> {
+        match self.state.0.checked_div(n) {
+            Some(state) => {
+                self.state = Wrapping(state);
+                Ok(())
+            }
+            None if n == 0 => Err("dividing by zero!"),
+            None => Err("dividing MIN by -1 overflows!"),
+        }
+    }
+
+    #[inline(never)]
+    fn rem(&mut self, n: AdvancedState) -> Result<(), Self::Error> {
+        match self.state.0.checked_rem(n) {
+            Some(state) => {
+                self.state = Wrapping(state);
+                Ok(())
+            }
+            None if n == 0 => Err("dividing by zero!"),
+            None => Err("dividing MIN by -1 overflows!"),
+        }
+    }
+}
+
+impl TargetExtUndo for AdvancedTarget {
     #[inline(never)]
-    fn scale_factor(&mut self, factor: isize) -> Result<(), Self::Error> {
-        self.scale = Wrapping(factor);
+    fn checkpoint(&mut self) -> Result<(), Self::Error> {
+        self.history.push((self.state, self.scale));
         Ok(())
     }
+
+    #[inline(never)]
+    fn undo(&mut self, n: usize) -> Result<(), Self::Error> {
+        match self.history.rewind(n) {
+            Some((state, scale)) => {
+                // (the scale bounds may have been narrowed since)
+                let (min, max) = self.scale_bounds;
+                self.state = state;
+                self.scale = Wrapping(scale.0.max(min).min(max));
+                Ok(())
+            }
+            None if n == 0 => Ok(()),
+            None if self.history.is_empty() => Err("nothing to undo!"),
+            // (rather than undoing only some of the `n` mutations)
+            None => Err("not enough history!"),
+        }
+    }
+
+    #[inline(never)]
+    fn rollback(&mut self) -> Result<(), Self::Error> {
+        match self.history.unpush() {
+            Some((state, scale)) => {
+                self.state = state;
+                self.scale = scale;
+                Ok(())
+            }
+            None => Err("nothing to roll back!"),
+        }
+    }
 }
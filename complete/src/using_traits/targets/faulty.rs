@@ -1,34 +1,49 @@
 use core::num::Wrapping;
 
+use optional_trait_methods_macros::idet;
+
 use super::super::target::Target;
 use super::super::target::TargetBase;
 use super::super::target::TargetBaseOps;
 use super::super::target::TargetExtIncDec;
+use super::super::target::TargetExtIncDecListed;
 use super::super::target::TargetExtIncDecOps;
 
 pub struct FaultyTarget {
     state: Wrapping<isize>,
+    // `r1`.. (`r0` is `state` itself)
+    regs: [isize; 3],
 }
 
 impl FaultyTarget {
     pub fn new(state: isize) -> FaultyTarget {
         FaultyTarget {
             state: Wrapping(state),
+            regs: [0; 3],
         }
     }
 }
 
+/// What writes to `r3` fail with: unlike `dec`'s, this target treats it as an
+/// error it can't recover from (so the session has to end).
+const READ_ONLY: &str = "`r3` is read-only";
+
+#[idet(
+    ext_attr(cfg_attr(feature = "always_inline", inline(always))),
+    incdec = IncDec,
+)]
 impl Target for FaultyTarget {
     type Error = &'static str;
+    type State = isize;
 
     #[cfg_attr(feature = "always_inline", inline(always))]
-    fn base(&mut self) -> TargetBaseOps<'_, Self> {
-        self
+    fn is_fatal(err: &Self::Error) -> bool {
+        *err == READ_ONLY
     }
 
     #[cfg_attr(feature = "always_inline", inline(always))]
-    fn ext_incdec(&mut self) -> Option<TargetExtIncDecOps<'_, Self>> {
-        Some(self)
+    fn base(&mut self) -> TargetBaseOps<'_, Self> {
+        self
     }
 }
 
@@ -43,6 +58,29 @@ impl TargetBase for FaultyTarget {
         self.state = Wrapping(n);
         Ok(())
     }
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn num_regs(&self) -> usize {
+        4
+    }
+
+    #[inline(never)]
+    fn read_reg(&self, idx: usize) -> isize {
+        match idx {
+            0 => self.state.0,
+            _ => self.regs[idx - 1],
+        }
+    }
+
+    #[inline(never)]
+    fn write_reg(&mut self, idx: usize, val: isize) -> Result<(), Self::Error> {
+        match idx {
+            0 => self.state = Wrapping(val),
+            3 => return Err(READ_ONLY),
+            _ => self.regs[idx - 1] = val,
+        }
+        Ok(())
+    }
 }
 
 impl TargetExtIncDec for FaultyTarget {
@@ -0,0 +1,496 @@
+use optional_trait_methods_macros::idet;
+
+use crate::capabilities::Capabilities;
+use crate::commands::Command;
+use crate::commands::ParseError;
+use crate::commands::strip_verb;
+use crate::response;
+use crate::response::Response;
+use crate::state_machine;
+use crate::writer::ResponseWriter;
+
+use super::controller::Error;
+use super::controller::TargetController;
+use super::target::Target;
+use super::target::TargetBaseOps;
+use super::target::TargetExtDivOps;
+use super::target::TargetExtIncDecOps;
+use super::target::TargetExtMul;
+use super::target::TargetExtMulListed;
+use super::target::TargetExtMulOps;
+use super::target::TargetExtScaleBoundsOps;
+use super::target::TargetExtScaleFactor;
+use super::target::TargetExtScaleFactorListed;
+use super::target::TargetExtScaleFactorOps;
+use super::target::TargetExtUndoOps;
+
+/// Wraps a `Target`, only exposing the extensions it supports while they're
+/// enabled at runtime (e.g: for hardware whose features are detected at boot).
+///
+/// This is something `try_as_dyn` can't do, as it only ever looks at the
+/// _type_ of the target, not the instance.
+///
+/// The inner target's errors have to be constructible from a message, for the
+/// (non-conforming, see `conformance`) case where it withdraws an extension
+/// between `Toggleable` probing it, and `Toggleable` calling into it.
+pub struct Toggleable<T: Target> {
+    inner: T,
+    enabled: Capabilities,
+}
+
+impl<T: Target> Toggleable<T> {
+    /// Wrap `inner`, with all of its extensions enabled.
+    pub fn new(inner: T) -> Toggleable<T> {
+        Toggleable {
+            inner,
+            enabled: Capabilities::ALL,
+        }
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn enabled(&self) -> Capabilities {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, caps: Capabilities, enabled: bool) {
+        self.enabled = match enabled {
+            true => self.enabled.union(caps),
+            false => self.enabled.difference(caps),
+        };
+    }
+}
+
+/// What `mul` / `scale_factor` fail with if the inner target no longer supports
+/// them, despite having done so when `ext_mul` / `ext_scale_factor` asked.
+const WITHDRAWN: &str = "the target withdrew the extension";
+
+// `ext_incdec` / `ext_div` / `ext_undo` hand out the inner target's ops
+// directly, whereas `ext_mul` (and `ext_scale_factor`) must hand out `self` in
+// order to intercept the next level of nested extensions.
+#[idet(mul)]
+impl<T: Target> Target for Toggleable<T>
+where
+    T::Error: From<&'static str>,
+{
+    type Error = T::Error;
+    type State = T::State;
+
+    fn is_fatal(err: &Self::Error) -> bool {
+        T::is_fatal(err)
+    }
+
+    fn base(&mut self) -> TargetBaseOps<'_, Self> {
+        self.inner.base()
+    }
+
+    fn ext_incdec(&mut self) -> Option<TargetExtIncDecOps<'_, Self>> {
+        if !self.enabled.contains(Capabilities::INCDEC) {
+            return None;
+        }
+        self.inner.ext_incdec()
+    }
+
+    fn ext_mul(&mut self) -> Option<TargetExtMulOps<'_, Self>> {
+        if !self.enabled.contains(Capabilities::MUL) {
+            return None;
+        }
+        self.inner.ext_mul()?;
+        Some(self)
+    }
+
+    fn ext_div(&mut self) -> Option<TargetExtDivOps<'_, Self>> {
+        if !self.enabled.contains(Capabilities::DIV) {
+            return None;
+        }
+        self.inner.ext_div()
+    }
+
+    fn ext_undo(&mut self) -> Option<TargetExtUndoOps<'_, Self>> {
+        if !self.enabled.contains(Capabilities::UNDO) {
+            return None;
+        }
+        self.inner.ext_undo()
+    }
+}
+
+#[idet(scale_factor)]
+impl<T: Target> TargetExtMul for Toggleable<T>
+where
+    T::Error: From<&'static str>,
+{
+    fn mul(&mut self, n: Self::State) -> Result<(), Self::Error> {
+        match self.inner.ext_mul() {
+            Some(ops) => ops.mul(n),
+            None => Err(WITHDRAWN.into()),
+        }
+    }
+
+    fn ext_scale_factor(&mut self) -> Option<TargetExtScaleFactorOps<'_, Self>> {
+        if !self.enabled.contains(Capabilities::SCALE_FACTOR) {
+            return None;
+        }
+        self.inner.ext_mul()?.ext_scale_factor()?;
+        Some(self)
+    }
+}
+
+impl<T: Target> TargetExtScaleFactor for Toggleable<T>
+where
+    T::Error: From<&'static str>,
+{
+    fn scale_factor(&mut self, factor: Self::State) -> Result<(), Self::Error> {
+        match self.inner.ext_mul().and_then(|ops| ops.ext_scale_factor()) {
+            Some(ops) => ops.scale_factor(factor),
+            None => Err(WITHDRAWN.into()),
+        }
+    }
+
+    fn ext_scale_bounds(&mut self) -> Option<TargetExtScaleBoundsOps<'_, Self>> {
+        if !self.enabled.contains(Capabilities::SCALE_BOUNDS) {
+            return None;
+        }
+        self.inner.ext_mul()?.ext_scale_factor()?.ext_scale_bounds()
+    }
+}
+
+/// What `ToggleController` parses a line into.
+#[derive(Clone, Copy, Debug)]
+pub enum ToggleCommand<S> {
+    /// Any of the wrapped target's commands.
+    Target(Command<S>),
+    /// `!+ incdec;mul`
+    Enable(Capabilities),
+    /// `!- mul.scale_factor`
+    Disable(Capabilities),
+}
+
+/// Drives a `Toggleable` target, adding `!+` / `!-` (which enable / disable
+/// extensions by name) to the commands its `TargetController` understands.
+///
+/// Toggling is the wrapper's business rather than the target's, so neither
+/// `Target` nor the shared `Command` know about it.
+pub struct ToggleController<T: Target>
+where
+    T::Error: From<&'static str>,
+{
+    controller: TargetController<Toggleable<T>>,
+}
+
+impl<T: Target> ToggleController<T>
+where
+    T::Error: From<&'static str>,
+{
+    pub fn new(target: Toggleable<T>) -> ToggleController<T> {
+        ToggleController {
+            controller: TargetController::new(target),
+        }
+    }
+
+    pub fn target(&self) -> &Toggleable<T> {
+        self.controller.target()
+    }
+
+    pub fn parse_command(&mut self, buf: &[u8]) -> Result<ToggleCommand<T::State>, ParseError> {
+        if let Some(mut args) = strip_verb(buf, b"!+") {
+            let caps = args.names()?;
+            return args.finish(ToggleCommand::Enable(caps));
+        }
+        if let Some(mut args) = strip_verb(buf, b"!-") {
+            let caps = args.names()?;
+            return args.finish(ToggleCommand::Disable(caps));
+        }
+        self.controller
+            .parse_command(buf)
+            .map(ToggleCommand::Target)
+    }
+
+    pub fn handle<W: ResponseWriter>(
+        &mut self,
+        out: &mut W,
+        cmd: &ToggleCommand<T::State>,
+    ) -> Result<Response<T::State>, Error<T::Error>> {
+        let (caps, enabled) = match cmd {
+            ToggleCommand::Target(cmd) => return self.controller.handle(out, cmd),
+            ToggleCommand::Enable(caps) => (*caps, true),
+            ToggleCommand::Disable(caps) => (*caps, false),
+        };
+        self.controller.target_mut().set_enabled(caps, enabled);
+        response::reply(out, Ok(Response::Ok))
+    }
+}
+
+impl<T: Target> state_machine::Controller for ToggleController<T>
+where
+    T::Error: From<&'static str>,
+{
+    type State = T::State;
+    type Error = T::Error;
+    type Command = ToggleCommand<T::State>;
+
+    fn capabilities(&mut self) -> Capabilities {
+        self.controller.capabilities()
+    }
+
+    fn parse_command(&mut self, line: &[u8]) -> Result<ToggleCommand<T::State>, ParseError> {
+        ToggleController::parse_command(self, line)
+    }
+
+    fn handle<W: ResponseWriter>(
+        &mut self,
+        out: &mut W,
+        cmd: &ToggleCommand<T::State>,
+    ) -> Result<Response<T::State>, Error<T::Error>> {
+        ToggleController::handle(self, out, cmd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::controller::Error;
+    use super::super::controller::TargetController;
+    use super::super::polyfill::POLYFILLS;
+    use super::super::targets::AdvancedTarget;
+    use super::super::targets::FaultyTarget;
+    use super::*;
+    use crate::commands::ParseError;
+    use crate::commands::ParseErrorKind;
+    use crate::num::AdvancedState;
+    use crate::response;
+    use crate::response::Response;
+    use crate::writer::Capture;
+
+    fn run<T: Target<Error = &'static str>>(
+        controller: &mut ToggleController<T>,
+        line: &[u8],
+    ) -> Result<Response<T::State>, Error<T::Error>> {
+        let mut out = Capture::<64>::new();
+        match controller.parse_command(line) {
+            Ok(cmd) => controller.handle(&mut out, &cmd),
+            Err(err) => response::reply(&mut out, Err(err.into())),
+        }
+    }
+
+    /// Why `line` was rejected, if it was.
+    fn rejected<T: Target<Error = &'static str>>(
+        controller: &mut ToggleController<T>,
+        line: &[u8],
+    ) -> Option<ParseErrorKind> {
+        controller.parse_command(line).err().map(|err| err.kind)
+    }
+
+    /// Supports `mul` on every other call (which `check_conformance` would
+    /// reject, but nothing stops a target from doing).
+    struct FlakyTarget {
+        inner: AdvancedTarget,
+        calls: usize,
+    }
+
+    impl Target for FlakyTarget {
+        type Error = &'static str;
+        type State = AdvancedState;
+
+        fn base(&mut self) -> TargetBaseOps<'_, Self> {
+            self.inner.base()
+        }
+
+        fn ext_mul(&mut self) -> Option<TargetExtMulOps<'_, Self>> {
+            self.calls += 1;
+            if self.calls.is_multiple_of(2) {
+                return None;
+            }
+            self.inner.ext_mul()
+        }
+    }
+
+    #[test]
+    fn test_withdrawn() {
+        let mut target = Toggleable::new(FlakyTarget {
+            inner: AdvancedTarget::new(3),
+            calls: 0,
+        });
+        let ops = target.ext_mul().unwrap();
+        assert_eq!(ops.mul(2), Err(WITHDRAWN));
+        assert_eq!(target.base().get_state(), 3);
+    }
+
+    #[test]
+    fn test_capabilities() {
+        let mut target = Toggleable::new(AdvancedTarget::new(0));
+        assert_eq!(target.capabilities(), Capabilities::ALL);
+        target.set_enabled(Capabilities::UNDO, false);
+
+        target.set_enabled(Capabilities::MUL, false);
+        assert_eq!(
+            target.capabilities(),
+            Capabilities::INCDEC.union(Capabilities::DIV)
+        );
+
+        // re-enabling `mul` brings back `mul.scale_factor`, which was never
+        // explicitly disabled
+        target.set_enabled(Capabilities::MUL, true);
+        target.set_enabled(Capabilities::INCDEC, false);
+        assert_eq!(
+            target.capabilities(),
+            Capabilities::ALL.difference(Capabilities::INCDEC.union(Capabilities::UNDO))
+        );
+
+        // ...which in turn gates `mul.scale_factor.scale_bounds`
+        target.set_enabled(Capabilities::DIV, false);
+        target.set_enabled(Capabilities::SCALE_FACTOR, false);
+        assert_eq!(target.capabilities(), Capabilities::MUL);
+        target.set_enabled(Capabilities::SCALE_FACTOR, true);
+        target.set_enabled(Capabilities::SCALE_BOUNDS, false);
+        assert_eq!(
+            target.capabilities(),
+            Capabilities::MUL.union(Capabilities::SCALE_FACTOR)
+        );
+    }
+
+    #[test]
+    fn test_toggle_commands() {
+        let mut controller = ToggleController::new(Toggleable::new(AdvancedTarget::new(0)));
+        // (polyfills take over extensions which are disabled, just as they
+        // would for extensions the target doesn't have at all)
+        let unless_polyfilled =
+            |ext| (!POLYFILLS.contains(ext)).then_some(ParseErrorKind::Unsupported);
+
+        assert_eq!(run(&mut controller, b"s 3"), Ok(Response::Ok));
+        assert!(controller.parse_command(b"+").is_ok());
+        assert_eq!(run(&mut controller, b"+"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(4)));
+
+        // `+` is unsupported once `incdec` is disabled...
+        assert_eq!(run(&mut controller, b"!- incdec"), Ok(Response::Ok));
+        assert_eq!(
+            rejected(&mut controller, b"+"),
+            unless_polyfilled(Capabilities::INCDEC)
+        );
+        let expected = match POLYFILLS.contains(Capabilities::INCDEC) {
+            true => Ok(Response::Ok),
+            false => Err(Error::Unsupported),
+        };
+        assert_eq!(run(&mut controller, b"+-"), expected);
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(4)));
+
+        // ...and is handled again once it's re-enabled
+        assert_eq!(run(&mut controller, b"!+ incdec"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"+"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(5)));
+
+        // nested extensions can be toggled independently of their parent
+        assert_eq!(
+            run(&mut controller, b"!- mul.scale_factor"),
+            Ok(Response::Ok)
+        );
+        assert_eq!(
+            rejected(&mut controller, b"*~ 2"),
+            Some(ParseErrorKind::Unsupported)
+        );
+        assert!(controller.parse_command(b"* 2").is_ok());
+        assert_eq!(run(&mut controller, b"* 2"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(10)));
+
+        // ...at any depth
+        assert_eq!(
+            run(&mut controller, b"!+ mul.scale_factor"),
+            Ok(Response::Ok)
+        );
+        assert_eq!(
+            run(&mut controller, b"!- mul.scale_factor.scale_bounds"),
+            Ok(Response::Ok)
+        );
+        assert_eq!(
+            rejected(&mut controller, b"*~~ 1 2"),
+            Some(ParseErrorKind::Unsupported)
+        );
+        assert!(controller.parse_command(b"*~ 2").is_ok());
+
+        // ...but disabling the parent also hides the nested extensions
+        assert_eq!(
+            run(&mut controller, b"!+ mul.scale_factor.scale_bounds"),
+            Ok(Response::Ok)
+        );
+        assert!(controller.parse_command(b"*~~ 1 2").is_ok());
+        assert_eq!(run(&mut controller, b"!- mul"), Ok(Response::Ok));
+        assert_eq!(
+            rejected(&mut controller, b"* 2"),
+            unless_polyfilled(Capabilities::MUL)
+        );
+        assert_eq!(
+            rejected(&mut controller, b"*~ 2"),
+            Some(ParseErrorKind::Unsupported)
+        );
+        assert_eq!(
+            rejected(&mut controller, b"*~~ 1 2"),
+            Some(ParseErrorKind::Unsupported)
+        );
+        assert_eq!(
+            controller.target().enabled(),
+            Capabilities::ALL.difference(Capabilities::MUL)
+        );
+        assert_eq!(
+            run(&mut controller, b"? incdec;mul"),
+            Ok(Response::Supported(Capabilities::INCDEC.union(POLYFILLS)))
+        );
+
+        // extensions which are disabled are rejected as such, whatever their
+        // arguments
+        assert_eq!(run(&mut controller, b"!- div"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"/ abc"), Err(Error::Unsupported));
+
+        // (the target's own controller doesn't know about `!+` / `!-`)
+        let mut controller = TargetController::new(Toggleable::new(AdvancedTarget::new(0)));
+        assert_eq!(
+            controller.parse_command(b"!- mul").map(|_| ()),
+            Err(ParseError {
+                kind: ParseErrorKind::UnknownVerb,
+                offset: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut controller = ToggleController::new(Toggleable::new(AdvancedTarget::new(0)));
+
+        // `Toggleable` forwards the inner target's error classification
+        assert_eq!(
+            run(&mut controller, b"*~~ 2 1"),
+            Err(Error::NonFatal("scale bounds are inverted!"))
+        );
+        assert_eq!(run(&mut controller, b"s 3"), Ok(Response::Ok));
+        assert_eq!(
+            run(&mut controller, b"/ 0"),
+            Err(Error::NonFatal("dividing by zero!"))
+        );
+        assert_eq!(run(&mut controller, b"% 2"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(1)));
+
+        let mut controller = ToggleController::new(Toggleable::new(FaultyTarget::new(5)));
+        assert_eq!(
+            run(&mut controller, b"w 3 1"),
+            Err(Error::Fatal("`r3` is read-only"))
+        );
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(5)));
+    }
+
+    #[test]
+    fn test_undo_disabled() {
+        let mut controller = ToggleController::new(Toggleable::new(AdvancedTarget::new(0)));
+
+        // mutations made while `undo` is disabled aren't recorded
+        assert_eq!(run(&mut controller, b"s 1"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"!- undo"), Ok(Response::Ok));
+        assert_eq!(
+            rejected(&mut controller, b"u"),
+            Some(ParseErrorKind::Unsupported)
+        );
+        assert_eq!(run(&mut controller, b"s 2"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"!+ undo"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"u"), Ok(Response::Ok));
+        assert_eq!(run(&mut controller, b"p"), Ok(Response::Value(0)));
+    }
+}
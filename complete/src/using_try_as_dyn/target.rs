@@ -1,12 +1,73 @@
+use core::any::try_as_dyn_mut;
+
+use crate::capabilities::Capabilities;
+use crate::num::Num;
+
 pub trait Target: 'static {
     type Error: 'static;
+    /// The target's state (and register) width.
+    type State: Num;
 
     fn base(&mut self) -> TargetBaseOps<'_, Self>;
+
+    /// Whether `err` leaves the target unusable, ending the session.
+    ///
+    /// By default, every error is non-fatal: the controller replies with an
+    /// error code, and keeps serving.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn is_fatal(err: &Self::Error) -> bool
+    where
+        Self: Sized,
+    {
+        let _ = err;
+        false
+    }
+
+    /// Report every extension supported by the target.
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn capabilities(&mut self) -> Capabilities
+    where
+        Self: Sized,
+    {
+        Capabilities::NONE
+            .with(
+                Capabilities::INCDEC,
+                try_as_dyn_mut::<Self, dyn TargetExtIncDec<Error = Self::Error, State = Self::State>>(self).is_some(),
+            )
+            .with(
+                Capabilities::MUL,
+                try_as_dyn_mut::<Self, dyn TargetExtMul<Error = Self::Error, State = Self::State>>(self).is_some(),
+            )
+            .with(
+                Capabilities::SCALE_FACTOR,
+                try_as_dyn_mut::<Self, dyn TargetExtScaleFactor<Error = Self::Error, State = Self::State>>(self)
+                    .is_some(),
+            )
+            .with(
+                Capabilities::SCALE_BOUNDS,
+                try_as_dyn_mut::<Self, dyn TargetExtScaleBounds<Error = Self::Error, State = Self::State>>(self)
+                    .is_some(),
+            )
+            .with(
+                Capabilities::DIV,
+                try_as_dyn_mut::<Self, dyn TargetExtDiv<Error = Self::Error, State = Self::State>>(self).is_some(),
+            )
+            .with(
+                Capabilities::UNDO,
+                try_as_dyn_mut::<Self, dyn TargetExtUndo<Error = Self::Error, State = Self::State>>(self).is_some(),
+            )
+    }
 }
 
 pub trait TargetBase: Target {
-    fn get_state(&self) -> isize;
-    fn set_state(&mut self, n: isize) -> Result<(), Self::Error>;
+    fn get_state(&self) -> Self::State;
+    fn set_state(&mut self, n: Self::State) -> Result<(), Self::Error>;
+
+    /// The size of the register file (at most `registers::MAX_REGS`), where
+    /// `r0` is the state itself.
+    fn num_regs(&self) -> usize;
+    fn read_reg(&self, idx: usize) -> Self::State;
+    fn write_reg(&mut self, idx: usize, val: Self::State) -> Result<(), Self::Error>;
 }
 
 pub trait TargetExtIncDec: Target {
@@ -15,17 +76,38 @@ pub trait TargetExtIncDec: Target {
 }
 
 pub trait TargetExtMul: Target {
-    fn mul(&mut self, n: isize) -> Result<(), Self::Error>;
+    fn mul(&mut self, n: Self::State) -> Result<(), Self::Error>;
 }
 
 pub trait TargetExtScaleFactor: Target {
-    fn scale_factor(&mut self, factor: isize) -> Result<(), Self::Error>;
+    fn scale_factor(&mut self, factor: Self::State) -> Result<(), Self::Error>;
+}
+
+pub trait TargetExtScaleBounds: Target {
+    fn scale_bounds(&mut self, min: Self::State, max: Self::State) -> Result<(), Self::Error>;
+}
+
+pub trait TargetExtDiv: Target {
+    fn div(&mut self, n: Self::State) -> Result<(), Self::Error>;
+    fn rem(&mut self, n: Self::State) -> Result<(), Self::Error>;
+}
+
+pub trait TargetExtUndo: Target {
+    /// Snapshots whatever the next mutation may change (see
+    /// `Command::is_undoable`).
+    fn checkpoint(&mut self) -> Result<(), Self::Error>;
+    /// Reverts the last `n` checkpointed mutations.
+    fn undo(&mut self, n: usize) -> Result<(), Self::Error>;
+    /// Reverts the last checkpoint, and forgets it as if it had never been
+    /// taken (e.g: once the mutation it was taken for has failed).
+    fn rollback(&mut self) -> Result<(), Self::Error>;
 }
 
 macro_rules! define_ops {
     ($exttrait:ident -> $extname:ident) => {
         #[allow(missing_docs)]
-        pub type $extname<'a, T> = &'a mut dyn $exttrait<Error = <T as Target>::Error>;
+        pub type $extname<'a, T> =
+            &'a mut dyn $exttrait<Error = <T as Target>::Error, State = <T as Target>::State>;
     };
 }
 
@@ -33,3 +115,6 @@ define_ops!(TargetBase -> TargetBaseOps);
 define_ops!(TargetExtIncDec -> TargetExtIncDecOps);
 define_ops!(TargetExtMul -> TargetExtMulOps);
 define_ops!(TargetExtScaleFactor -> TargetExtScaleFactorOps);
+define_ops!(TargetExtScaleBounds -> TargetExtScaleBoundsOps);
+define_ops!(TargetExtDiv -> TargetExtDivOps);
+define_ops!(TargetExtUndo -> TargetExtUndoOps);
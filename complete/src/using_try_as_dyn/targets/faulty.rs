@@ -7,18 +7,31 @@ use super::super::target::TargetExtIncDec;
 
 pub struct FaultyTarget {
     state: Wrapping<isize>,
+    // `r1`.. (`r0` is `state` itself)
+    regs: [isize; 3],
 }
 
 impl FaultyTarget {
     pub fn new(state: isize) -> FaultyTarget {
         FaultyTarget {
             state: Wrapping(state),
+            regs: [0; 3],
         }
     }
 }
 
+/// What writes to `r3` fail with: unlike `dec`'s, this target treats it as an
+/// error it can't recover from (so the session has to end).
+const READ_ONLY: &str = "`r3` is read-only";
+
 impl Target for FaultyTarget {
     type Error = &'static str;
+    type State = isize;
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn is_fatal(err: &Self::Error) -> bool {
+        *err == READ_ONLY
+    }
 
     #[cfg_attr(feature = "always_inline", inline(always))]
     fn base(&mut self) -> TargetBaseOps<'_, Self> {
@@ -37,6 +50,29 @@ impl TargetBase for FaultyTarget {
         self.state = Wrapping(n);
         Ok(())
     }
+
+    #[cfg_attr(feature = "always_inline", inline(always))]
+    fn num_regs(&self) -> usize {
+        4
+    }
+
+    #[inline(never)]
+    fn read_reg(&self, idx: usize) -> isize {
+        match idx {
+            0 => self.state.0,
+            _ => self.regs[idx - 1],
+        }
+    }
+
+    #[inline(never)]
+    fn write_reg(&mut self, idx: usize, val: isize) -> Result<(), Self::Error> {
+        match idx {
+            0 => self.state = Wrapping(val),
+            3 => return Err(READ_ONLY),
+            _ => self.regs[idx - 1] = val,
+        }
+        Ok(())
+    }
 }
 
 impl TargetExtIncDec for FaultyTarget {
@@ -1,17 +1,7 @@
-core::cfg_select! {
-    feature = "target_basic" => {
-        mod basic;
-        pub use basic::BasicTarget;
-    }
-    feature = "target_advanced" => {
-        mod advanced;
-        pub use advanced::AdvancedTarget;
-    }
-    feature = "target_faulty" => {
-        mod faulty;
-        pub use faulty::FaultyTarget;
-    }
-    _ => {
-        compile_error!("must select one of the `target_` features!");
-    }
-}
+mod advanced;
+mod basic;
+mod faulty;
+
+pub use self::advanced::AdvancedTarget;
+pub use self::basic::BasicTarget;
+pub use self::faulty::FaultyTarget;
@@ -0,0 +1,280 @@
+//! Where replies are written (see `response::reply`).
+//!
+//! Controllers write every reply through a `ResponseWriter` they're handed,
+//! instead of straight to a file descriptor, so the same replies can be
+//! batched up for a connection, captured by tests, or merely counted by
+//! benchmarks.
+
+use crate::connection::Connection;
+use crate::num::MAX_DECIMAL_LEN;
+use crate::num::Num;
+use crate::num::format_decimal;
+
+/// A sink for replies.
+pub trait ResponseWriter {
+    /// Writes part of a reply.
+    fn write(&mut self, bytes: &[u8]);
+    /// Sends everything written so far (i.e: at the end of a batch of
+    /// commands).
+    fn end_batch(&mut self);
+
+    /// Writes the rest of a reply, followed by its `\n`.
+    fn write_line(&mut self, bytes: &[u8]) {
+        self.write(bytes);
+        self.write(b"\n");
+    }
+
+    /// Writes `n` in decimal.
+    fn write_num<N: Num>(&mut self, n: N) {
+        // (formatted by hand, as printf has no conversion for every width)
+        let mut buf = [0u8; MAX_DECIMAL_LEN];
+        self.write(format_decimal(n, &mut buf));
+    }
+}
+
+/// Collects replies in an `N`-byte buffer, which is only written to the
+/// connection once it fills up, at the end of a batch, or right before more
+/// commands are read from the connection (so a client waiting on a reply is
+/// never left hanging).
+///
+/// Write errors are ignored (as the next read notices a client that has hung
+/// up), except by `Connection::flush`.
+pub struct Buffered<C, const N: usize = 1024> {
+    conn: C,
+    buf: [u8; N],
+    len: usize,
+}
+
+impl<C: Connection, const N: usize> Buffered<C, N> {
+    pub fn new(conn: C) -> Buffered<C, N> {
+        Buffered {
+            conn,
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    pub fn get_ref(&self) -> &C {
+        &self.conn
+    }
+
+    /// Writes out (and empties) the buffer.
+    fn send(&mut self) -> Result<(), C::Error> {
+        let len = core::mem::take(&mut self.len);
+        self.conn.write_all(self.buf.get(..len).unwrap_or_default())
+    }
+}
+
+impl<C: Connection, const N: usize> ResponseWriter for Buffered<C, N> {
+    fn write(&mut self, bytes: &[u8]) {
+        let end = self.len + bytes.len();
+        if end > N {
+            let _ = self.send();
+            // (too big to be buffered at all)
+            if bytes.len() > N {
+                let _ = self.conn.write_all(bytes);
+                return;
+            }
+        }
+        let start = self.len;
+        if let Some(slot) = self.buf.get_mut(start..start + bytes.len()) {
+            slot.copy_from_slice(bytes);
+            self.len += bytes.len();
+        }
+    }
+
+    fn end_batch(&mut self) {
+        let _ = Connection::flush(self);
+    }
+}
+
+impl<C: Connection, const N: usize> Connection for Buffered<C, N> {
+    type Error = C::Error;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, C::Error> {
+        // (there may be no more commands until the client has seen the
+        // replies to the last batch)
+        let _ = Connection::flush(self);
+        self.conn.read(buf)
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), C::Error> {
+        self.write(bytes);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), C::Error> {
+        self.send()?;
+        self.conn.flush()
+    }
+}
+
+/// Keeps replies in memory (e.g: for tests), dropping anything past the first
+/// `N` bytes.
+pub struct Capture<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+    truncated: bool,
+}
+
+impl<const N: usize> Default for Capture<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Capture<N> {
+    pub fn new() -> Capture<N> {
+        Capture {
+            buf: [0; N],
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    /// Everything written so far.
+    pub fn output(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Whether anything had to be dropped.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    pub fn clear(&mut self) {
+        self.len = 0;
+        self.truncated = false;
+    }
+}
+
+impl<const N: usize> ResponseWriter for Capture<N> {
+    fn write(&mut self, bytes: &[u8]) {
+        let n = bytes.len().min(N - self.len);
+        self.buf[self.len..self.len + n].copy_from_slice(&bytes[..n]);
+        self.len += n;
+        self.truncated |= n < bytes.len();
+    }
+
+    fn end_batch(&mut self) {}
+}
+
+/// Reads commands from `C`, but only counts the replies written back (e.g: to
+/// benchmark formatting replies without the cost of sending them).
+pub struct CountingSink<C> {
+    conn: C,
+    bytes: usize,
+    replies: usize,
+}
+
+impl<C: Connection> CountingSink<C> {
+    pub fn new(conn: C) -> CountingSink<C> {
+        CountingSink {
+            conn,
+            bytes: 0,
+            replies: 0,
+        }
+    }
+
+    /// How many bytes have been written (acks included).
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// How many lines have been written.
+    pub fn replies(&self) -> usize {
+        self.replies
+    }
+}
+
+impl<C: Connection> ResponseWriter for CountingSink<C> {
+    fn write(&mut self, bytes: &[u8]) {
+        // (so the reply still has to be formatted)
+        let bytes = core::hint::black_box(bytes);
+        self.bytes += bytes.len();
+        self.replies += bytes.iter().filter(|&&b| b == b'\n').count();
+    }
+
+    fn end_batch(&mut self) {}
+}
+
+impl<C: Connection> Connection for CountingSink<C> {
+    type Error = C::Error;
+
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, C::Error> {
+        self.conn.read(buf)
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), C::Error> {
+        self.write(bytes);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::Memory;
+
+    #[test]
+    fn test_buffered() {
+        let mut output = [0u8; 64];
+        let mut out = Buffered::<_, 8>::new(Memory::new(b"p\n", &mut output));
+
+        out.write_line(b"OK");
+        out.write_num(-42);
+        out.write(b"\n");
+        assert_eq!(out.get_ref().output(), b"");
+        // (the buffer fills up)
+        out.write_line(b"E 01");
+        assert_eq!(out.get_ref().output(), b"OK\n-42\n");
+        // (replies are sent before reading more commands)
+        let mut buf = [0u8; 4];
+        assert_eq!(Connection::read(&mut out, &mut buf), Ok(2));
+        assert_eq!(out.get_ref().output(), b"OK\n-42\nE 01\n");
+
+        // (too big to be buffered)
+        out.write(b"OK\n");
+        out.write(b"0123456789\n");
+        assert_eq!(out.get_ref().output(), b"OK\n-42\nE 01\nOK\n0123456789\n");
+        out.write_line(b"OK");
+        out.end_batch();
+        assert_eq!(
+            out.get_ref().output(),
+            b"OK\n-42\nE 01\nOK\n0123456789\nOK\n"
+        );
+    }
+
+    #[test]
+    fn test_capture() {
+        let mut out = Capture::<8>::new();
+        out.write_line(b"OK");
+        out.write_num(i8::MIN);
+        assert_eq!(out.output(), b"OK\n-128");
+        assert!(!out.is_truncated());
+
+        out.write_line(b"");
+        assert_eq!(out.output(), b"OK\n-128\n");
+        out.write(b"x");
+        assert!(out.is_truncated());
+
+        out.clear();
+        assert_eq!(out.output(), b"");
+        assert!(!out.is_truncated());
+    }
+
+    #[test]
+    fn test_counting_sink() {
+        let mut out = CountingSink::new(Memory::new(b"", &mut []));
+        out.write_line(b"OK");
+        out.write(b"E.");
+        out.write_num(12345);
+        out.write(b"\n");
+        assert_eq!(out.bytes(), 11);
+        assert_eq!(out.replies(), 2);
+    }
+}
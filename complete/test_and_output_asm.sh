@@ -2,19 +2,26 @@
 set -e
 
 ALWAYS_INLINE_FEATURE="always_inline"
+STATE_FEATURE=""
+# (kept apart from the default listings, so the two can be compared)
+OUT_SUFFIX=""
 
 for arg in "$@"; do
     case "$arg" in
         --no-inline-always)
             ALWAYS_INLINE_FEATURE=""
             ;;
+        --state-i128)
+            STATE_FEATURE="state_i128"
+            OUT_SUFFIX="_i128"
+            ;;
     esac
 done
 
 # Build the Rust command generator harness first
 cargo +nightly build --release --bin harness
 
-mkdir -p ./asm_output ./asm_output_inlined
+mkdir -p ./asm_output${OUT_SUFFIX} ./asm_output_inlined${OUT_SUFFIX}
 
 for target in "basic" "advanced" "faulty"
 do
@@ -23,27 +30,27 @@ do
         # 1. Generate interpretable asm listing (with inline(never))
         rm -f ./target/release/deps/optional_trait_methods-*
         rm -f ./target/release/optional-trait-methods
-        asmfile=./asm_output/${target}_${impl}.s
-        cargo +nightly rustc --bin optional-trait-methods --no-default-features --features="target_${target} using_${impl} interpretable_asm ${ALWAYS_INLINE_FEATURE}" --release -- --emit asm -C "llvm-args=-x86-asm-syntax=intel"
+        asmfile=./asm_output${OUT_SUFFIX}/${target}_${impl}.s
+        cargo +nightly rustc --bin optional-trait-methods --no-default-features --features="target_${target} using_${impl} interpretable_asm ${ALWAYS_INLINE_FEATURE} ${STATE_FEATURE}" --release -- --emit asm -C "llvm-args=-x86-asm-syntax=intel"
 
         rustfilt < ./target/release/deps/optional_trait_methods-*.s > $asmfile
         sed -i -E '/^\t\.(section|p2align|type|file|intel_|text|cfi|size|hidden|globl)/d' $asmfile
 
-        # Sanity-check harness
-        ./target/release/harness 1000 | ./target/release/optional-trait-methods > /dev/null
+        # Sanity-check the binary (every command must get a well-formed reply)
+        ./target/release/harness 1000 --check ./target/release/optional-trait-methods
 
         # 2. Generate fully-inlined asm listing (without inline(never))
         rm -f ./target/release/deps/optional_trait_methods-*
         rm -f ./target/release/optional-trait-methods
-        asmfile_inlined=./asm_output_inlined/${target}_${impl}.s
-        cargo +nightly rustc --bin optional-trait-methods --no-default-features --features="target_${target} using_${impl} ${ALWAYS_INLINE_FEATURE}" --release -- --emit asm -C "llvm-args=-x86-asm-syntax=intel"
+        asmfile_inlined=./asm_output_inlined${OUT_SUFFIX}/${target}_${impl}.s
+        cargo +nightly rustc --bin optional-trait-methods --no-default-features --features="target_${target} using_${impl} ${ALWAYS_INLINE_FEATURE} ${STATE_FEATURE}" --release -- --emit asm -C "llvm-args=-x86-asm-syntax=intel"
 
         rustfilt < ./target/release/deps/optional_trait_methods-*.s > $asmfile_inlined
         sed -i -E '/^\t\.(section|p2align|type|file|intel_|text|cfi|size|hidden|globl)/d' $asmfile_inlined
 
-        ./target/release/harness 1000 | ./target/release/optional-trait-methods > /dev/null
+        ./target/release/harness 1000 --check ./target/release/optional-trait-methods
     done
 done
 
-python3 ./asm_stats.py
+python3 ./asm_stats.py --dir ./asm_output${OUT_SUFFIX}
 